@@ -3,7 +3,7 @@
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::ffi::OsStr;
 use std::fs;
 use std::io::prelude::*;
@@ -30,6 +30,12 @@ lazy_static! {
     )
     .unwrap();
     static ref NL_ESCAPE_RE: Regex = Regex::new(r#"\\\n\s*"#).unwrap();
+    static ref LINT_ATTR_RE: Regex = Regex::new(r#"#!?\[\s*(?:allow|warn|deny|forbid)\s*\(([^\]]*)\)\s*\]"#).unwrap();
+    static ref CLIPPY_LINT_RE: Regex = Regex::new(r#"clippy::([a-z][a-z0-9_-]*)"#).unwrap();
+    static ref LEVEL_NOTE_RE: Regex =
+        Regex::new(r#"(?:-D clippy::([a-z0-9_-]+)` implied by|#\[\w+\(clippy::([a-z0-9_]+)\)\] on by default)"#)
+            .unwrap();
+    static ref RENAMED_RE: Regex = Regex::new(r#"register_renamed\("clippy::([a-z0-9_]+)""#).unwrap();
     pub static ref DOCS_LINK: String = "https://rust-lang.github.io/rust-clippy/master/index.html".to_string();
 }
 
@@ -291,6 +297,240 @@ where
     }
 }
 
+/// Returns a description of each unused alternative in a coverage file
+/// written by the `PatternCoverage` pass, see `utils/pattern_coverage.rs` in
+/// `clippy_lints`.
+pub fn unused_alternatives(contents: &str) -> Vec<String> {
+    let mut alternations = HashMap::new();
+    let mut used = HashMap::new();
+    for line in contents.lines() {
+        let fields: Vec<&str> = line.split(' ').collect();
+        if let [kind, pattern, alternation, n] = fields[..] {
+            let key = (pattern, alternation.parse::<usize>().unwrap_or(0));
+            let n = n.parse::<usize>().unwrap_or(0);
+            match kind {
+                "alternation" => {
+                    alternations.insert(key, n);
+                },
+                "used" => {
+                    used.entry(key).or_insert_with(Vec::new).push(n);
+                },
+                _ => (),
+            }
+        }
+    }
+    alternations
+        .into_iter()
+        .sorted()
+        .flat_map(|((pattern, alternation), branches)| {
+            let used = used.get(&(pattern, alternation)).cloned().unwrap_or_default();
+            (0..branches)
+                .filter(move |branch| !used.contains(branch))
+                .map(move |branch| {
+                    format!(
+                        "`{}`: branch {} of alternation {} is never used",
+                        pattern,
+                        branch + 1,
+                        alternation + 1
+                    )
+                })
+        })
+        .collect()
+}
+
+/// The lint groups that tests may refer to besides the lints themselves.
+const LINT_GROUPS: [&str; 10] = [
+    "all",
+    "cargo",
+    "complexity",
+    "correctness",
+    "internal",
+    "nursery",
+    "pedantic",
+    "perf",
+    "restriction",
+    "style",
+];
+
+/// Lints that no UI test can exercise, with the reason. Lints of the `cargo`
+/// group check `Cargo.toml` and are skipped as well.
+const UNTESTABLE_LINTS: [(&str, &str); 2] = [
+    (
+        "regex_macro",
+        "`regex!` requires the unmaintained `regex_macros` plugin",
+    ),
+    (
+        "wrong_pub_self_convention",
+        "the visibility of the `impl` block is checked, which is never `pub`",
+    ),
+];
+
+/// The lints a UI test refers to.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct TestLints {
+    /// The path of the test, relative to the `tests` directory.
+    pub path: String,
+    /// The lints named in lint level attributes of the test.
+    pub referenced: BTreeSet<String>,
+    /// The lints emitted in the expected output of the test.
+    pub exercised: BTreeSet<String>,
+}
+
+/// Parses the source and the expected output of a UI test. Custom lint groups
+/// defined in the `clippy.toml` of the test are not lints and skipped.
+pub fn parse_test(path: &str, source: &str, stderr: &str, custom_groups: &[String]) -> TestLints {
+    let referenced = LINT_ATTR_RE
+        .captures_iter(source)
+        .flat_map(|attr| {
+            CLIPPY_LINT_RE
+                .captures_iter(&attr[1])
+                .map(|name| name[1].replace('-', "_"))
+                .collect::<Vec<_>>()
+        })
+        .filter(|name| !LINT_GROUPS.contains(&name.as_str()) && !custom_groups.contains(name))
+        // the test checks that unknown lints are reported
+        .filter(|name| !stderr.contains(&format!("unknown clippy lint: clippy::{}\n", name)))
+        .collect();
+
+    let mut exercised: BTreeSet<String> = LEVEL_NOTE_RE
+        .captures_iter(stderr)
+        .filter_map(|note| note.get(1).or_else(|| note.get(2)))
+        .map(|name| name.as_str().replace('-', "_"))
+        .collect();
+    // With `#[deny(clippy::lint)]` in the test, the first error of each lint
+    // points to the attribute, with the lint name underlined:
+    // note: lint level defined here
+    //   --> $DIR/test.rs:1:9
+    //    |
+    // LL | #![deny(clippy::lint)]
+    //    |         ^^^^^^^^^^^^
+    let lines: Vec<&str> = stderr.lines().collect();
+    for (i, line) in lines.iter().enumerate() {
+        if !line.ends_with("note: lint level defined here") {
+            continue;
+        }
+        if let (Some(code), Some(marks)) = (lines.get(i + 3), lines.get(i + 4)) {
+            let start = marks.find('^').unwrap_or(0);
+            let end = marks.rfind('^').map_or(0, |end| end + 1);
+            if let Some(name) = code.get(start..end).and_then(|span| CLIPPY_LINT_RE.captures(span)) {
+                exercised.insert(name[1].to_string());
+            }
+        }
+    }
+    exercised.retain(|name| !LINT_GROUPS.contains(&name.as_str()));
+
+    TestLints {
+        path: path.to_string(),
+        referenced,
+        exercised,
+    }
+}
+
+/// Gathers the lints of all UI tests in `tests/ui` and `tests/ui-toml`.
+pub fn gather_tests() -> Vec<TestLints> {
+    let mut tests: Vec<TestLints> = ["../tests/ui", "../tests/ui-toml"]
+        .iter()
+        .flat_map(|dir| WalkDir::new(dir).into_iter().filter_map(std::result::Result::ok))
+        .filter(|f| f.path().extension() == Some(OsStr::new("rs")))
+        .map(|f| {
+            let path = f.path();
+            let source = fs::read_to_string(path).unwrap_or_default();
+            let stderr = fs::read_to_string(path.with_extension("stderr")).unwrap_or_default();
+            let conf = fs::read_to_string(path.with_file_name("clippy.toml")).unwrap_or_default();
+            let name = path.strip_prefix("../tests").unwrap_or(path).to_string_lossy();
+            parse_test(&name, &source, &stderr, &custom_groups(&conf))
+        })
+        .collect();
+    tests.sort_by(|a, b| a.path.cmp(&b.path));
+    tests
+}
+
+/// Returns the names of the custom lint groups in the `[groups]` table of a
+/// `clippy.toml`.
+fn custom_groups(conf: &str) -> Vec<String> {
+    conf.lines()
+        .map(str::trim)
+        .skip_while(|line| *line != "[groups]")
+        .skip(1)
+        .take_while(|line| !line.starts_with('['))
+        .filter_map(|line| line.split('=').next())
+        .map(|key| key.trim().trim_matches('"').replace('-', "_"))
+        .filter(|key| !key.is_empty())
+        .collect()
+}
+
+/// Gathers the old names of renamed lints from `clippy_lints/src/lib.rs`.
+pub fn gather_renamed() -> Vec<String> {
+    let content = fs::read_to_string("../clippy_lints/src/lib.rs").expect("cannot read clippy_lints/src/lib.rs");
+    RENAMED_RE.captures_iter(&content).map(|m| m[1].to_string()).collect()
+}
+
+/// Which tests exercise which lint, and what is missing.
+#[derive(PartialEq, Debug, Default)]
+pub struct LintTestReport {
+    /// The tests exercising each usable lint, by lint name.
+    pub tests: BTreeMap<String, Vec<String>>,
+    /// Usable lints that no test exercises.
+    pub untested: Vec<String>,
+    /// Tests referring to lints that don't exist (anymore), as `(test, lint)`.
+    pub orphans: Vec<(String, String)>,
+}
+
+impl LintTestReport {
+    pub fn new(lints: &[Lint], renamed: &[String], tests: &[TestLints]) -> Self {
+        let mut report = Self::default();
+        for lint in Lint::usable_lints(lints.iter().cloned()) {
+            let exercising: Vec<String> = tests
+                .iter()
+                .filter(|test| test.exercised.contains(&lint.name))
+                .map(|test| test.path.clone())
+                .collect();
+            let untestable = lint.group == "cargo" || UNTESTABLE_LINTS.iter().any(|&(name, _)| name == lint.name);
+            if exercising.is_empty() && !untestable {
+                report.untested.push(lint.name.clone());
+            }
+            report.tests.insert(lint.name, exercising);
+        }
+        report.untested.sort();
+        for test in tests {
+            for name in test.referenced.union(&test.exercised) {
+                if !lints.iter().any(|lint| lint.name == *name) && !renamed.contains(name) {
+                    report.orphans.push((test.path.clone(), name.clone()));
+                }
+            }
+        }
+        report
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.untested.is_empty() && self.orphans.is_empty()
+    }
+
+    /// Renders the report as JSON, for CI.
+    pub fn to_json(&self) -> String {
+        fn list<'a>(items: impl Iterator<Item = &'a String>) -> String {
+            format!("[{}]", items.map(|item| format!("{:?}", item)).join(", "))
+        }
+
+        let tests = self
+            .tests
+            .iter()
+            .map(|(lint, tests)| format!("    {:?}: {}", lint, list(tests.iter())))
+            .join(",\n");
+        let orphans = self
+            .orphans
+            .iter()
+            .map(|(test, lint)| format!("    {{\"test\": {:?}, \"lint\": {:?}}}", test, lint))
+            .join(",\n");
+        format!(
+            "{{\n  \"tests\": {{\n{}\n  }},\n  \"untested\": {},\n  \"orphans\": [\n{}\n  ]\n}}",
+            tests,
+            list(self.untested.iter()),
+            orphans
+        )
+    }
+}
+
 #[test]
 fn test_parse_contents() {
     let result: Vec<Lint> = parse_contents(
@@ -487,3 +727,97 @@ fn test_gen_lint_group_list() {
     ];
     assert_eq!(expected, gen_lint_group_list(lints));
 }
+
+#[test]
+fn test_unused_alternatives() {
+    let contents = "alternation collapsible_if::pat_a 0 2\n\
+                    alternation collapsible_if::pat_a 1 3\n\
+                    used collapsible_if::pat_a 0 1\n\
+                    used collapsible_if::pat_a 1 0\n\
+                    alternation collapsible_if::pat_a 0 2\n\
+                    alternation collapsible_if::pat_a 1 3\n\
+                    used collapsible_if::pat_a 0 0\n\
+                    used collapsible_if::pat_a 1 0\n";
+    assert_eq!(
+        unused_alternatives(contents),
+        vec![
+            "`collapsible_if::pat_a`: branch 2 of alternation 2 is never used",
+            "`collapsible_if::pat_a`: branch 3 of alternation 2 is never used",
+        ]
+    );
+}
+
+#[test]
+fn test_parse_test() {
+    let source = "#![warn(clippy::all, clippy::needless_return)]\n#![deny(clippy::my_group, clippy::if_not_else)]\n";
+    let stderr = r#"error: unneeded return statement
+  --> $DIR/test.rs:5:5
+   |
+LL |     return 1;
+   |     ^^^^^^^^^ help: remove `return`: `1`
+   |
+   = note: `-D clippy::needless-return` implied by `-D warnings`
+
+error: unnecessary `!=` operation
+  --> $DIR/test.rs:9:5
+   |
+note: lint level defined here
+  --> $DIR/test.rs:2:9
+   |
+LL | #![deny(clippy::my_group, clippy::if_not_else)]
+   |                           ^^^^^^^^^^^^^^^^^^^
+   = note: `#[deny(clippy::if_not_else)]` implied by `#[deny(clippy::pedantic)]`
+"#;
+    let test = parse_test("ui/test.rs", source, stderr, &["my_group".to_string()]);
+    assert_eq!(
+        vec!["if_not_else", "needless_return"],
+        test.referenced.iter().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec!["if_not_else", "needless_return"],
+        test.exercised.iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_parse_test_unknown_lint() {
+    let source = "#![warn(clippy::if_not_els)]\n";
+    let stderr = "error: unknown clippy lint: clippy::if_not_els\n";
+    let test = parse_test("ui/test.rs", source, stderr, &[]);
+    assert!(test.referenced.is_empty());
+    assert!(test.exercised.is_empty());
+}
+
+#[test]
+fn test_custom_groups() {
+    let conf = "cognitive-complexity-threshold = 10\n\n[groups]\nmy-group = [\"needless_return\"]\n\"other\" = []\n\n[third-party]\nfoo = 1\n";
+    assert_eq!(vec!["my_group", "other"], custom_groups(conf));
+    assert!(custom_groups("cognitive-complexity-threshold = 10\n").is_empty());
+}
+
+#[test]
+fn test_lint_test_report() {
+    let lints = vec![
+        Lint::new("tested", "style", "abc", None, "module_name"),
+        Lint::new("untested", "style", "abc", None, "module_name"),
+        Lint::new("cargo_lint", "cargo", "abc", None, "module_name"),
+        Lint::new("deprecated", "Deprecated", "abc", Some("Reason"), "module_name"),
+    ];
+    let test = |path: &str, referenced: &[&str], exercised: &[&str]| TestLints {
+        path: path.to_string(),
+        referenced: referenced.iter().map(|name| name.to_string()).collect(),
+        exercised: exercised.iter().map(|name| name.to_string()).collect(),
+    };
+    let tests = vec![
+        test("ui/tested.rs", &["tested", "old_name"], &["tested"]),
+        test("ui/orphan.rs", &["deprecated", "removed"], &[]),
+    ];
+    let report = LintTestReport::new(&lints, &["old_name".to_string()], &tests);
+    assert_eq!(vec!["untested"], report.untested);
+    assert_eq!(
+        vec![("ui/orphan.rs".to_string(), "removed".to_string())],
+        report.orphans
+    );
+    assert_eq!(Some(&vec!["ui/tested.rs".to_string()]), report.tests.get("tested"));
+    assert!(!report.is_ok());
+}
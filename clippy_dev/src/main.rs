@@ -34,6 +34,30 @@ fn main() {
                         .help("Checks that util/dev update_lints has been run. Used on CI."),
                 ),
         )
+        .subcommand(
+            SubCommand::with_name("pattern_coverage")
+                .about(
+                    "Reports the alternatives of `pattern!` definitions that the UI tests don't use. \
+                     The coverage file is written by running the tests with `CLIPPY_PATTERN_COVERAGE=<file>`.",
+                )
+                .arg(
+                    Arg::with_name("file")
+                        .required(true)
+                        .help("The coverage file written by the tests"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("lint_tests")
+                .about(
+                    "Checks that every lint is exercised by at least one UI test \
+                     and that no test refers to a lint that doesn't exist",
+                )
+                .arg(
+                    Arg::with_name("json")
+                        .long("json")
+                        .help("Print the lints with the tests exercising them as JSON, for CI"),
+                ),
+        )
         .get_matches();
 
     if let Some(matches) = matches.subcommand_matches("update_lints") {
@@ -45,6 +69,45 @@ fn main() {
             update_lints(&UpdateMode::Change);
         }
     }
+    if let Some(matches) = matches.subcommand_matches("pattern_coverage") {
+        pattern_coverage(matches.value_of("file").expect("the file is required"));
+    }
+    if let Some(matches) = matches.subcommand_matches("lint_tests") {
+        lint_tests(matches.is_present("json"));
+    }
+}
+
+fn lint_tests(json: bool) {
+    let lints: Vec<Lint> = gather_all().collect();
+    let report = LintTestReport::new(&lints, &gather_renamed(), &gather_tests());
+    if json {
+        println!("{}", report.to_json());
+    } else {
+        for lint in &report.untested {
+            println!("`{}` is not exercised by any UI test", lint);
+        }
+        for (test, lint) in &report.orphans {
+            println!("`{}` refers to the unknown lint `{}`", test, lint);
+        }
+        if report.is_ok() {
+            println!("all {} lints are exercised by UI tests", report.tests.len());
+        }
+    }
+    if !report.is_ok() {
+        std::process::exit(1);
+    }
+}
+
+fn pattern_coverage(path: &str) {
+    let contents = std::fs::read_to_string(path).unwrap_or_else(|e| panic!("cannot read `{}`: {}", path, e));
+    let unused = unused_alternatives(&contents);
+    for line in &unused {
+        println!("{}", line);
+    }
+    if !unused.is_empty() {
+        std::process::exit(1);
+    }
+    println!("all alternatives of `pattern!` definitions are used");
 }
 
 fn print_lints() {
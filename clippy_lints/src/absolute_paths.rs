@@ -0,0 +1,94 @@
+use crate::utils::{in_macro, span_lint};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashSet;
+use syntax::symbol::keywords;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for paths starting at a crate root, like
+    /// `std::env::var` or `crate::config::Config`, with more segments than
+    /// the `absolute-paths-max-segments` option allows (2 by default).
+    ///
+    /// Paths into the crates listed in `absolute-paths-allowed-crates` are
+    /// not linted, `crate` stands for the current crate. Paths in `use` items
+    /// are not linted either.
+    ///
+    /// **Why is this bad?** Long paths make the code harder to read, and
+    /// importing the item shows what a module depends on at its top.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let _ = std::env::var("HOME");
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::env;
+    ///
+    /// let _ = env::var("HOME");
+    /// ```
+    pub ABSOLUTE_PATHS,
+    restriction,
+    "paths starting at a crate root that could be imported"
+}
+
+pub struct AbsolutePaths {
+    max_segments: u64,
+    allowed_crates: FxHashSet<String>,
+}
+
+impl AbsolutePaths {
+    pub fn new(max_segments: u64, allowed_crates: &[String]) -> Self {
+        Self {
+            max_segments,
+            allowed_crates: allowed_crates.iter().cloned().collect(),
+        }
+    }
+}
+
+impl LintPass for AbsolutePaths {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(ABSOLUTE_PATHS)
+    }
+
+    fn name(&self) -> &'static str {
+        "AbsolutePaths"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for AbsolutePaths {
+    fn check_path(&mut self, cx: &LateContext<'a, 'tcx>, path: &'tcx Path, id: HirId) {
+        if in_macro(path.span) || path.def.opt_def_id().is_none() {
+            return;
+        }
+        // `use` items pass their own id
+        if let Some(Node::Item(item)) = cx.tcx.hir().find_by_hir_id(id) {
+            if let ItemKind::Use(..) = item.node {
+                return;
+            }
+        }
+        let global = path.is_global();
+        let segments: Vec<&PathSegment> = path
+            .segments
+            .iter()
+            .filter(|segment| segment.ident.name != keywords::PathRoot.name())
+            .collect();
+        let krate = match segments.first() {
+            Some(krate) => krate.ident.as_str(),
+            None => return,
+        };
+        let is_crate_root = global
+            || *krate == *keywords::Crate.name().as_str()
+            || cx.tcx.crates().iter().any(|&cnum| cx.tcx.crate_name(cnum) == &*krate);
+        if is_crate_root && segments.len() as u64 > self.max_segments && !self.allowed_crates.contains(&*krate) {
+            span_lint(
+                cx,
+                ABSOLUTE_PATHS,
+                path.span,
+                "consider bringing this path into scope with the `use` keyword",
+            );
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use crate::consts::{constant_simple, Constant};
+use crate::utils::{def_path_def_ids, get_def_path, in_constant, in_macro, sext, span_lint, walk_ptrs_ty};
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty::layout::LayoutOf;
+use rustc::ty::{self, Ty};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashSet;
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for arithmetic operations that can overflow or
+    /// panic at runtime, i.e. `+`, `-`, `*`, `/`, `%`, `<<`, `>>` and unary
+    /// `-` on integers and on types with overloaded operators.
+    ///
+    /// Operations on floats, `String`, `Wrapping<T>` and the types listed in
+    /// the `arithmetic-side-effects-allowed` option are not linted, e.g.
+    /// `arithmetic-side-effects-allowed = ["num_bigint::BigInt"]`. A type name
+    /// without a path matches any type with that name. Operations whose
+    /// outcome is bounded by a constant operand, like `x % 4`, `x / 2`,
+    /// `x * 1` or `x << 3` on `u32`, are not linted either.
+    ///
+    /// **Why is this bad?** Integer overflow panics in debug builds and wraps
+    /// around silently in release builds, and division by zero always panics.
+    /// Some applications want checked, wrapping or saturating arithmetic
+    /// everywhere.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let n = 1u32;
+    /// let next = n + 1;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let n = 1u32;
+    /// let next = n.checked_add(1);
+    /// ```
+    pub ARITHMETIC_SIDE_EFFECTS,
+    restriction,
+    "any arithmetic expression that can overflow or panic"
+}
+
+/// The types whose operators never overflow or panic.
+const ALLOWED_TYPES: [&str; 2] = ["std::num::Wrapping", "std::string::String"];
+
+pub struct ArithmeticSideEffects {
+    /// The paths of the allowed types, with the built-in ones.
+    allowed: Vec<String>,
+    /// The allowed types given by a path, resolved once per crate.
+    allowed_def_ids: FxHashSet<DefId>,
+    /// The outermost linted expression, nested operations are not linted again.
+    expr_span: Option<Span>,
+}
+
+impl ArithmeticSideEffects {
+    pub fn new(allowed: &[String]) -> Self {
+        Self {
+            allowed: ALLOWED_TYPES
+                .iter()
+                .map(|&path| path.to_string())
+                .chain(allowed.iter().cloned())
+                .collect(),
+            allowed_def_ids: FxHashSet::default(),
+            expr_span: None,
+        }
+    }
+
+    fn is_allowed_ty(&self, cx: &LateContext<'_, '_>, ty: Ty<'_>) -> bool {
+        match walk_ptrs_ty(ty).sty {
+            ty::Float(_) => true,
+            ty::Adt(def, _) => {
+                self.allowed_def_ids.contains(&def.did) || {
+                    let name = get_def_path(cx.tcx, def.did).last().cloned();
+                    self.allowed.iter().any(|path| Some(path.as_str()) == name)
+                }
+            },
+            _ => false,
+        }
+    }
+
+    fn issue_lint(&mut self, cx: &LateContext<'_, '_>, expr: &Expr) {
+        span_lint(
+            cx,
+            ARITHMETIC_SIDE_EFFECTS,
+            expr.span,
+            "arithmetic operation that can potentially result in unexpected side-effects",
+        );
+        self.expr_span = Some(expr.span);
+    }
+}
+
+impl LintPass for ArithmeticSideEffects {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(ARITHMETIC_SIDE_EFFECTS)
+    }
+
+    fn name(&self) -> &'static str {
+        "ArithmeticSideEffects"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ArithmeticSideEffects {
+    fn check_crate(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        for path in &self.allowed {
+            if path.contains("::") {
+                let path: Vec<&str> = path.split("::").collect();
+                self.allowed_def_ids.extend(def_path_def_ids(cx, &path));
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if self.expr_span.is_some() || in_macro(expr.span) {
+            return;
+        }
+        match expr.node {
+            ExprKind::Binary(op, ref lhs, ref rhs) | ExprKind::AssignOp(op, ref lhs, ref rhs) => {
+                if !is_arithmetic(op.node)
+                    || self.is_allowed_ty(cx, cx.tables.expr_ty(lhs))
+                    || self.is_allowed_ty(cx, cx.tables.expr_ty(rhs))
+                    || in_constant(cx, expr.hir_id)
+                {
+                    return;
+                }
+                let lhs_ty = walk_ptrs_ty(cx.tables.expr_ty(lhs));
+                if lhs_ty.is_integral() {
+                    if constant_simple(cx, cx.tables, expr).is_some()
+                        || is_bounded_by_const(cx, op.node, lhs, rhs, lhs_ty)
+                    {
+                        return;
+                    }
+                }
+                self.issue_lint(cx, expr);
+            },
+            ExprKind::Unary(UnNeg, ref arg) => {
+                let ty = cx.tables.expr_ty(arg);
+                if self.is_allowed_ty(cx, ty)
+                    || in_constant(cx, expr.hir_id)
+                    || constant_simple(cx, cx.tables, expr).is_some()
+                {
+                    return;
+                }
+                self.issue_lint(cx, expr);
+            },
+            _ => (),
+        }
+    }
+
+    fn check_expr_post(&mut self, _: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if Some(expr.span) == self.expr_span {
+            self.expr_span = None;
+        }
+    }
+}
+
+fn is_arithmetic(op: BinOpKind) -> bool {
+    match op {
+        BinOpKind::Add
+        | BinOpKind::Sub
+        | BinOpKind::Mul
+        | BinOpKind::Div
+        | BinOpKind::Rem
+        | BinOpKind::Shl
+        | BinOpKind::Shr => true,
+        _ => false,
+    }
+}
+
+/// Checks if a constant operand keeps the integer operation from overflowing
+/// or panicking, e.g. `x % 4`, `x / 2`, `x + 0`, `x * 1` or `x << 3`.
+fn is_bounded_by_const<'tcx>(cx: &LateContext<'_, 'tcx>, op: BinOpKind, lhs: &Expr, rhs: &Expr, ty: Ty<'tcx>) -> bool {
+    let int = |expr| match constant_simple(cx, cx.tables, expr) {
+        Some(Constant::Int(value)) => Some(value),
+        _ => None,
+    };
+    match op {
+        BinOpKind::Add => int(lhs) == Some(0) || int(rhs) == Some(0),
+        BinOpKind::Sub => int(rhs) == Some(0),
+        BinOpKind::Mul => [int(lhs), int(rhs)]
+            .iter()
+            .any(|value| *value == Some(0) || *value == Some(1)),
+        // `MIN / -1` and `MIN % -1` overflow
+        BinOpKind::Div | BinOpKind::Rem => match (int(rhs), &ty.sty) {
+            (Some(0), _) | (None, _) => false,
+            (Some(value), ty::Int(ity)) => sext(cx.tcx, value, *ity) != -1,
+            (Some(_), _) => true,
+        },
+        BinOpKind::Shl | BinOpKind::Shr => match (int(rhs), cx.layout_of(ty)) {
+            (Some(value), Ok(layout)) => value < u128::from(layout.size.bits()),
+            _ => false,
+        },
+        _ => false,
+    }
+}
@@ -1,9 +1,10 @@
 //! checks for attributes
 
 use crate::reexport::*;
+use crate::utils::comments::blank_lines;
 use crate::utils::{
     in_macro, last_line_of_span, match_def_path, opt_def_id, paths, snippet_opt, span_lint, span_lint_and_sugg,
-    span_lint_and_then, without_block_comments,
+    span_lint_and_then,
 };
 use if_chain::if_chain;
 use rustc::hir::*;
@@ -186,6 +187,52 @@ declare_clippy_lint! {
     "usage of `cfg_attr(rustfmt)` instead of `tool_attributes`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for `feature = "cargo-clippy"` in `cfg` and
+    /// `cfg_attr` attributes and suggests the `clippy` cfg instead.
+    ///
+    /// **Why is this bad?** `clippy` is set whenever Clippy checks a crate,
+    /// `feature = "cargo-clippy"` is a leftover from before tool cfgs and
+    /// looks like a feature of the crate.
+    ///
+    /// **Known problems:** Like `deprecated_cfg_attr`, crate level inner
+    /// attributes are not checked.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// #[cfg_attr(feature = "cargo-clippy", allow(clippy::needless_return))]
+    /// fn main() {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// #[cfg_attr(clippy, allow(clippy::needless_return))]
+    /// fn main() {}
+    /// ```
+    pub DEPRECATED_CLIPPY_CFG_ATTR,
+    style,
+    "usage of `feature = \"cargo-clippy\"` instead of the `clippy` cfg"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for items compiled only when Clippy is not
+    /// running, like `#[cfg(not(clippy))]`.
+    ///
+    /// **Why is this bad?** Clippy never sees these items, so they are not
+    /// checked at all. Often an alternative `#[cfg(clippy)]` item hides real
+    /// code behind a stub.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// #[cfg(not(clippy))]
+    /// fn checked_elsewhere() {}
+    /// ```
+    pub CFG_NOT_CLIPPY,
+    restriction,
+    "items hidden from Clippy with `#[cfg(not(clippy))]`"
+}
+
 #[derive(Copy, Clone)]
 pub struct AttrPass;
 
@@ -426,19 +473,14 @@ fn check_attrs(cx: &LateContext<'_, '_>, span: Span, name: Name, attrs: &[Attrib
             let begin_of_attr_to_item = Span::new(attr.span.lo(), span.lo(), span.ctxt());
             let end_of_attr_to_item = Span::new(attr.span.hi(), span.lo(), span.ctxt());
 
-            if let Some(snippet) = snippet_opt(cx, end_of_attr_to_item) {
-                let lines = snippet.split('\n').collect::<Vec<_>>();
-                let lines = without_block_comments(lines);
-
-                if lines.iter().filter(|l| l.trim().is_empty()).count() > 2 {
-                    span_lint(
-                        cx,
-                        EMPTY_LINE_AFTER_OUTER_ATTR,
-                        begin_of_attr_to_item,
-                        "Found an empty line after an outer attribute. \
-                         Perhaps you forgot to add a '!' to make it an inner attribute?",
-                    );
-                }
+            if !blank_lines(cx, end_of_attr_to_item).is_empty() {
+                span_lint(
+                    cx,
+                    EMPTY_LINE_AFTER_OUTER_ATTR,
+                    begin_of_attr_to_item,
+                    "Found an empty line after an outer attribute. \
+                     Perhaps you forgot to add a '!' to make it an inner attribute?",
+                );
             }
         }
 
@@ -502,7 +544,7 @@ pub struct CfgAttrPass;
 
 impl LintPass for CfgAttrPass {
     fn get_lints(&self) -> LintArray {
-        lint_array!(DEPRECATED_CFG_ATTR,)
+        lint_array!(DEPRECATED_CFG_ATTR, DEPRECATED_CLIPPY_CFG_ATTR, CFG_NOT_CLIPPY)
     }
 
     fn name(&self) -> &'static str {
@@ -538,5 +580,64 @@ impl EarlyLintPass for CfgAttrPass {
                 );
             }
         }
+
+        // the predicate of `cfg(..)` and `cfg_attr(.., ..)`
+        let items = match attr.meta_item_list() {
+            Some(items) if attr.name() == "cfg" || attr.name() == "cfg_attr" => items,
+            _ => return,
+        };
+        let predicate = match items.first() {
+            Some(predicate) => predicate,
+            None => return,
+        };
+        let mut cargo_clippy = Vec::new();
+        find_cargo_clippy(predicate, &mut cargo_clippy);
+        for span in cargo_clippy {
+            span_lint_and_sugg(
+                cx,
+                DEPRECATED_CLIPPY_CFG_ATTR,
+                span,
+                "`feature = \"cargo-clippy\"` was replaced by `clippy`",
+                "replace with",
+                "clippy".to_string(),
+                Applicability::MachineApplicable,
+            );
+        }
+        if attr.name() == "cfg" && hides_from_clippy(predicate) {
+            span_lint(cx, CFG_NOT_CLIPPY, attr.span, "this item is never checked by Clippy");
+        }
+    }
+}
+
+/// Whether `item` is the `clippy` cfg or its deprecated form.
+fn is_clippy_cfg(item: &NestedMetaItem) -> bool {
+    item.meta_item().map_or(false, |meta| {
+        (meta.is_word() && meta.name() == "clippy")
+            || (meta.name() == "feature" && meta.value_str().map_or(false, |value| value == "cargo-clippy"))
+    })
+}
+
+/// Collects the spans of `feature = "cargo-clippy"` in the cfg predicate
+/// `item`.
+fn find_cargo_clippy(item: &NestedMetaItem, spans: &mut Vec<Span>) {
+    if let Some(meta) = item.meta_item() {
+        if meta.name() == "feature" && is_clippy_cfg(item) {
+            spans.push(item.span);
+        } else if meta.name() == "any" || meta.name() == "all" || meta.name() == "not" {
+            for inner in meta.meta_item_list().unwrap_or(&[]) {
+                find_cargo_clippy(inner, spans);
+            }
+        }
+    }
+}
+
+/// Whether the cfg predicate `item` is false when Clippy runs.
+fn hides_from_clippy(item: &NestedMetaItem) -> bool {
+    match item.meta_item() {
+        Some(meta) if meta.name() == "not" => meta
+            .meta_item_list()
+            .map_or(false, |inner| inner.len() == 1 && is_clippy_cfg(&inner[0])),
+        Some(meta) if meta.name() == "all" => meta.meta_item_list().unwrap_or(&[]).iter().any(hides_from_clippy),
+        _ => false,
     }
 }
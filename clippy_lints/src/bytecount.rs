@@ -1,6 +1,6 @@
 use crate::utils::{
     contains_name, get_pat_name, match_type, paths, single_segment_path, snippet_with_applicability,
-    span_lint_and_sugg, walk_ptrs_ty,
+    span_lint_and_sugg_lazy, walk_ptrs_ty,
 };
 use if_chain::if_chain;
 use rustc::hir::*;
@@ -86,17 +86,18 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ByteCount {
                         } else {
                             &filter_args[0]
                         };
-                        let mut applicability = Applicability::MachineApplicable;
-                        span_lint_and_sugg(
+                        span_lint_and_sugg_lazy(
                             cx,
                             NAIVE_BYTECOUNT,
                             expr.span,
                             "You appear to be counting bytes the naive way",
                             "Consider using the bytecount crate",
-                            format!("bytecount::count({}, {})",
-                                    snippet_with_applicability(cx, haystack.span, "..", &mut applicability),
-                                    snippet_with_applicability(cx, needle.span, "..", &mut applicability)),
-                            applicability,
+                            Applicability::MachineApplicable,
+                            |applicability| format!(
+                                "bytecount::count({}, {})",
+                                snippet_with_applicability(cx, haystack.span, "..", applicability),
+                                snippet_with_applicability(cx, needle.span, "..", applicability)
+                            ),
                         );
                     }
                 };
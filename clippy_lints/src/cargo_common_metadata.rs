@@ -1,5 +1,6 @@
 //! lint on missing cargo common metadata
 
+use crate::utils::manifest::Manifest;
 use crate::utils::span_lint;
 use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
 use rustc::{declare_tool_lint, lint_array};
@@ -38,9 +39,11 @@ fn warning(cx: &EarlyContext<'_>, message: &str) {
     span_lint(cx, CARGO_COMMON_METADATA, DUMMY_SP, message);
 }
 
-fn missing_warning(cx: &EarlyContext<'_>, package: &cargo_metadata::Package, field: &str) {
+fn missing_warning(cx: &EarlyContext<'_>, manifest: Option<&Manifest>, package: &cargo_metadata::Package, field: &str) {
     let message = format!("package `{}` is missing `{}` metadata", package.name, field);
-    warning(cx, &message);
+    // point at the `[package]` table of the manifest
+    let span = manifest.map_or(DUMMY_SP, |manifest| manifest.span(manifest.table("package")));
+    span_lint(cx, CARGO_COMMON_METADATA, span, &message);
 }
 
 fn is_empty_str(value: &Option<String>) -> bool {
@@ -78,32 +81,34 @@ impl EarlyLintPass for Pass {
         };
 
         for package in metadata.packages {
+            let manifest = Manifest::load(cx, &package.manifest_path);
+            let manifest = manifest.as_ref();
             if is_empty_vec(&package.authors) {
-                missing_warning(cx, &package, "package.authors");
+                missing_warning(cx, manifest, &package, "package.authors");
             }
 
             if is_empty_str(&package.description) {
-                missing_warning(cx, &package, "package.description");
+                missing_warning(cx, manifest, &package, "package.description");
             }
 
             if is_empty_str(&package.license) {
-                missing_warning(cx, &package, "package.license");
+                missing_warning(cx, manifest, &package, "package.license");
             }
 
             if is_empty_str(&package.repository) {
-                missing_warning(cx, &package, "package.repository");
+                missing_warning(cx, manifest, &package, "package.repository");
             }
 
             if is_empty_str(&package.readme) {
-                missing_warning(cx, &package, "package.readme");
+                missing_warning(cx, manifest, &package, "package.readme");
             }
 
             if is_empty_vec(&package.keywords) {
-                missing_warning(cx, &package, "package.keywords");
+                missing_warning(cx, manifest, &package, "package.keywords");
             }
 
             if is_empty_vec(&package.categories) {
-                missing_warning(cx, &package, "package.categories");
+                missing_warning(cx, manifest, &package, "package.categories");
             }
         }
     }
@@ -0,0 +1,259 @@
+use crate::utils::{
+    get_enclosing_block, match_trait_method, match_type, paths, span_lint, span_lint_node, walk_ptrs_ty,
+};
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::intravisit::{walk_block, walk_expr, walk_stmt, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty::{self, Ty};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use syntax::ast::{Name, NodeId};
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for collections (local variables and private
+    /// struct fields) that are only ever inserted into, but never read.
+    ///
+    /// **Why is this bad?** A collection that is never read is likely a bug
+    /// (the data was meant to be used somewhere) or dead code that wastes time
+    /// and memory.
+    ///
+    /// **Known problems:** Only a fixed set of standard library collections and
+    /// methods is recognized. Any use of the collection that is not a known
+    /// insertion (e.g. passing it to a function) is considered a read.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let mut vec = Vec::new();
+    /// vec.push(1);
+    /// vec.push(2);
+    /// // `vec` is never read
+    /// ```
+    pub COLLECTION_IS_NEVER_READ,
+    nursery,
+    "a collection is never queried"
+}
+
+/// Collection types the lint knows about.
+const COLLECTIONS: [&[&str]; 9] = [
+    &paths::VEC,
+    &paths::VEC_DEQUE,
+    &paths::LINKED_LIST,
+    &paths::BINARY_HEAP,
+    &paths::HASHMAP,
+    &paths::HASHSET,
+    &paths::BTREEMAP,
+    &paths::BTREESET,
+    &paths::STRING,
+];
+
+/// Methods that only put data into a collection (or remove all of it) without
+/// reading anything, as long as their result is discarded.
+const WRITE_METHODS: [&str; 14] = [
+    "append",
+    "clear",
+    "extend",
+    "extend_from_slice",
+    "insert",
+    "push",
+    "push_back",
+    "push_front",
+    "push_str",
+    "reserve",
+    "reserve_exact",
+    "resize",
+    "shrink_to_fit",
+    "truncate",
+];
+
+#[derive(Default)]
+pub struct CollectionIsNeverRead {
+    /// Private collection fields of local structs, with their `HirId` and span.
+    fields: FxHashMap<(DefId, Name), (HirId, Span)>,
+    /// Fields that have been written to.
+    written_fields: FxHashSet<(DefId, Name)>,
+    /// Fields that have been read from.
+    read_fields: FxHashSet<(DefId, Name)>,
+    /// Field expressions that were already classified as writes.
+    write_exprs: FxHashSet<HirId>,
+}
+
+impl LintPass for CollectionIsNeverRead {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(COLLECTION_IS_NEVER_READ)
+    }
+
+    fn name(&self) -> &'static str {
+        "CollectionIsNeverRead"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CollectionIsNeverRead {
+    fn check_local(&mut self, cx: &LateContext<'a, 'tcx>, local: &'tcx Local) {
+        if_chain! {
+            if let PatKind::Binding(_, canonical_id, _, _, None) = local.pat.node;
+            if is_collection(cx, cx.tables.pat_ty(&local.pat));
+            if let Some(block) = get_enclosing_block(cx, local.hir_id);
+            then {
+                let mut visitor = LocalUseVisitor {
+                    cx,
+                    id: canonical_id,
+                    has_write: false,
+                    has_read: false,
+                };
+                walk_block(&mut visitor, block);
+                if visitor.has_write && !visitor.has_read {
+                    span_lint(cx, COLLECTION_IS_NEVER_READ, local.span, "collection is never read");
+                }
+            }
+        }
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if let ItemKind::Struct(ref data, _) = item.node {
+            let struct_def_id = cx.tcx.hir().local_def_id_from_hir_id(item.hir_id);
+            for field in data.fields() {
+                let field_def_id = cx.tcx.hir().local_def_id_from_hir_id(field.hir_id);
+                if !field.vis.node.is_pub() && is_collection(cx, cx.tcx.type_of(field_def_id)) {
+                    self.fields
+                        .insert((struct_def_id, field.ident.name), (field.hir_id, field.span));
+                }
+            }
+        }
+    }
+
+    fn check_stmt(&mut self, cx: &LateContext<'a, 'tcx>, stmt: &'tcx Stmt) {
+        if_chain! {
+            if let StmtKind::Semi(ref expr) = stmt.node;
+            if let ExprKind::MethodCall(ref path, _, ref args) = expr.node;
+            if is_write_method(cx, expr, path);
+            if let Some(key) = field_key(cx, &args[0]);
+            then {
+                self.written_fields.insert(key);
+                self.write_exprs.insert(args[0].hir_id);
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        match expr.node {
+            ExprKind::Assign(ref lhs, _) | ExprKind::AssignOp(_, ref lhs, _) => {
+                if let Some(key) = field_key(cx, lhs) {
+                    self.written_fields.insert(key);
+                    self.write_exprs.insert(lhs.hir_id);
+                }
+            },
+            ExprKind::Field(..) if !self.write_exprs.contains(&expr.hir_id) => {
+                if let Some(key) = field_key(cx, expr) {
+                    self.read_fields.insert(key);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn check_pat(&mut self, cx: &LateContext<'a, 'tcx>, pat: &'tcx Pat) {
+        if let PatKind::Struct(_, ref field_pats, _) = pat.node {
+            if let ty::Adt(adt, _) = cx.tables.pat_ty(pat).sty {
+                for field_pat in field_pats {
+                    self.read_fields.insert((adt.did, field_pat.node.ident.name));
+                }
+            }
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        for (key, &(hir_id, span)) in &self.fields {
+            if self.written_fields.contains(key) && !self.read_fields.contains(key) {
+                span_lint_node(cx, COLLECTION_IS_NEVER_READ, hir_id, span, "collection is never read");
+            }
+        }
+    }
+}
+
+/// Returns the key of the local struct field `expr` accesses, if any. The struct
+/// itself may not have been visited yet, so this does not check whether the
+/// field is tracked.
+fn field_key(cx: &LateContext<'_, '_>, expr: &Expr) -> Option<(DefId, Name)> {
+    if_chain! {
+        if let ExprKind::Field(ref base, ident) = expr.node;
+        if let ty::Adt(adt, _) = walk_ptrs_ty(cx.tables.expr_ty(base)).sty;
+        if adt.did.is_local();
+        then {
+            return Some((adt.did, ident.name));
+        }
+    }
+    None
+}
+
+fn is_collection(cx: &LateContext<'_, '_>, ty: Ty<'_>) -> bool {
+    COLLECTIONS.iter().any(|path| match_type(cx, ty, path))
+}
+
+/// Checks whether the method call `expr` only writes to its receiver. Inherent
+/// methods are matched by name, trait methods must come from `Extend`.
+fn is_write_method(cx: &LateContext<'_, '_>, expr: &Expr, path: &PathSegment) -> bool {
+    let def_id = cx.tables.type_dependent_defs()[expr.hir_id].def_id();
+    if cx.tcx.trait_of_item(def_id).is_some() {
+        return match_trait_method(cx, expr, &paths::EXTEND);
+    }
+    WRITE_METHODS.iter().any(|&name| path.ident.name == name)
+}
+
+struct LocalUseVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    id: NodeId,
+    has_write: bool,
+    has_read: bool,
+}
+
+impl<'a, 'tcx> LocalUseVisitor<'a, 'tcx> {
+    fn is_local(&self, expr: &Expr) -> bool {
+        if let ExprKind::Path(ref qpath) = expr.node {
+            if let Def::Local(id) = self.cx.tables.qpath_def(qpath, expr.hir_id) {
+                return id == self.id;
+            }
+        }
+        false
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for LocalUseVisitor<'a, 'tcx> {
+    fn visit_stmt(&mut self, stmt: &'tcx Stmt) {
+        if_chain! {
+            if let StmtKind::Semi(ref expr) = stmt.node;
+            if let ExprKind::MethodCall(ref path, _, ref args) = expr.node;
+            if self.is_local(&args[0]);
+            if is_write_method(self.cx, expr, path);
+            then {
+                self.has_write = true;
+                for arg in &args[1..] {
+                    self.visit_expr(arg);
+                }
+                return;
+            }
+        }
+        walk_stmt(self, stmt);
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if self.has_read {
+            return;
+        }
+        match expr.node {
+            ExprKind::Assign(ref lhs, ref rhs) | ExprKind::AssignOp(_, ref lhs, ref rhs) if self.is_local(lhs) => {
+                self.has_write = true;
+                self.visit_expr(rhs);
+            },
+            _ if self.is_local(expr) => self.has_read = true,
+            _ => walk_expr(self, expr),
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::All(&self.cx.tcx.hir())
+    }
+}
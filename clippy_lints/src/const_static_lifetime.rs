@@ -58,8 +58,6 @@ impl StaticConst {
                     match borrow_type.ty.node {
                         TyKind::Path(..) | TyKind::Slice(..) | TyKind::Array(..) | TyKind::Tup(..) => {
                             if lifetime.ident.name == "'static" {
-                                let snip = snippet(cx, borrow_type.ty.span, "<type>");
-                                let sugg = format!("&{}", snip);
                                 span_lint_and_then(
                                     cx,
                                     CONST_STATIC_LIFETIME,
@@ -69,7 +67,7 @@ impl StaticConst {
                                         db.span_suggestion(
                                             ty.span,
                                             "consider removing `'static`",
-                                            sugg,
+                                            format!("&{}", snippet(cx, borrow_type.ty.span, "<type>")),
                                             Applicability::MachineApplicable, //snippet
                                         );
                                     },
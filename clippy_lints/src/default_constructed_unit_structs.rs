@@ -0,0 +1,97 @@
+use crate::utils::{any_parent_is_automatically_derived, in_macro, match_def_path, paths, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc::hir::def::{CtorKind, Def};
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls of `default` on unit structs, like
+    /// `PhantomData::<T>::default()`.
+    ///
+    /// **Why is this bad?** A unit struct has only one value, which its name
+    /// already is. Calling `default` for it is more to read, and hides that
+    /// nothing is computed.
+    ///
+    /// **Known problems:** A manual `Default` implementation with side effects
+    /// is not called anymore.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::marker::PhantomData;
+    /// let marker = PhantomData::<u32>::default();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::marker::PhantomData;
+    /// let marker = PhantomData::<u32>;
+    /// ```
+    pub DEFAULT_CONSTRUCTED_UNIT_STRUCTS,
+    complexity,
+    "calling `default` for a unit struct instead of using the unit value"
+}
+
+pub struct DefaultConstructedUnitStructs;
+
+impl LintPass for DefaultConstructedUnitStructs {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(DEFAULT_CONSTRUCTED_UNIT_STRUCTS)
+    }
+
+    fn name(&self) -> &'static str {
+        "DefaultConstructedUnitStructs"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DefaultConstructedUnitStructs {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::Call(ref callee, ref args) = expr.node;
+            if args.is_empty();
+            if let ExprKind::Path(ref qpath) = callee.node;
+            if let QPath::TypeRelative(ref base, ref segment) = *qpath;
+            if segment.ident.name == "default";
+            // `<Foo>::default()` can't be shortened to `<Foo>`
+            if let TyKind::Path(QPath::Resolved(None, ref path)) = base.node;
+            // type aliases can't be used as values, `Self` can in the impls of unit structs
+            if match path.def {
+                Def::Struct(_) | Def::SelfTy(_, Some(_)) => true,
+                _ => false,
+            };
+            if base.span.lo() == callee.span.lo();
+            if !in_macro(expr.span) && !any_parent_is_automatically_derived(cx.tcx, expr.hir_id);
+            if let Def::Method(def_id) = cx.tables.qpath_def(qpath, callee.hir_id);
+            if is_default_method(cx, def_id);
+            if let ty::Adt(adt, _) = cx.tables.expr_ty(expr).sty;
+            if adt.is_struct() && adt.non_enum_variant().ctor_kind == CtorKind::Const;
+            // the unit value of a `#[non_exhaustive]` struct can't be named in other crates
+            if adt.did.is_local() || !cx.tcx.has_attr(adt.did, "non_exhaustive");
+            then {
+                span_lint_and_sugg(
+                    cx,
+                    DEFAULT_CONSTRUCTED_UNIT_STRUCTS,
+                    expr.span.with_lo(base.span.hi()),
+                    "use of `default` to create a unit struct",
+                    "remove this call to `default`",
+                    String::new(),
+                    Applicability::MachineApplicable,
+                );
+            }
+        }
+    }
+}
+
+/// Whether `def_id` is `Default::default`, or its implementation for a type.
+fn is_default_method(cx: &LateContext<'_, '_>, def_id: DefId) -> bool {
+    match_def_path(cx.tcx, def_id, &paths::DEFAULT_TRAIT_METHOD)
+        || cx
+            .tcx
+            .impl_of_method(def_id)
+            .and_then(|impl_id| cx.tcx.trait_id_of_impl(impl_id))
+            .map_or(false, |trait_id| {
+                match_def_path(cx.tcx, trait_id, &paths::DEFAULT_TRAIT)
+            })
+}
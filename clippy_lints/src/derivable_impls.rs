@@ -0,0 +1,210 @@
+use crate::utils::{in_macro, is_automatically_derived, match_def_path, msrvs, opt_def_id, paths, span_lint_and_then};
+use if_chain::if_chain;
+use matches::matches;
+use rustc::hir::def::{CtorKind, Def};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty::{self, AdtDef};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use semver::Version;
+use syntax::ast::LitKind;
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for manual `Default` implementations that are
+    /// equivalent to `#[derive(Default)]`.
+    ///
+    /// **Why is this bad?** It is less code to derive the trait, and the
+    /// derive keeps working when fields are added.
+    ///
+    /// **Known problems:** Generic types are not linted, since the derive adds
+    /// `Default` bounds on all type parameters. Deriving `Default` for an enum
+    /// requires the `#[default]` attribute on the default variant, stable since
+    /// Rust 1.62, so enums are only linted if the `msrv` is at least 1.62.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Foo {
+    ///     bar: bool,
+    ///     baz: Vec<u32>,
+    /// }
+    ///
+    /// impl Default for Foo {
+    ///     fn default() -> Self {
+    ///         Self {
+    ///             bar: false,
+    ///             baz: Default::default(),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Could be written as:
+    ///
+    /// ```rust
+    /// #[derive(Default)]
+    /// struct Foo {
+    ///     bar: bool,
+    ///     baz: Vec<u32>,
+    /// }
+    /// ```
+    pub DERIVABLE_IMPLS,
+    complexity,
+    "manual implementation of the `Default` trait which is equal to a derive"
+}
+
+pub struct DerivableImpls {
+    msrv: Option<Version>,
+}
+
+impl DerivableImpls {
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl LintPass for DerivableImpls {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(DERIVABLE_IMPLS)
+    }
+
+    fn name(&self) -> &'static str {
+        "DerivableImpls"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DerivableImpls {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if_chain! {
+            if let ItemKind::Impl(_, _, _, ref generics, Some(ref trait_ref), _, ref impl_items) = item.node;
+            if !in_macro(item.span) && !is_automatically_derived(&item.attrs);
+            if !generics.params.iter().any(|param| match param.kind {
+                GenericParamKind::Type { .. } => true,
+                _ => false,
+            });
+            if let Some(def_id) = trait_ref.path.def.opt_def_id();
+            if match_def_path(cx.tcx, def_id, &paths::DEFAULT_TRAIT);
+            if let [ref impl_item_ref] = **impl_items;
+            if let ImplItemKind::Method(_, body_id) = cx.tcx.hir().impl_item(impl_item_ref.id).node;
+            let impl_def_id = cx.tcx.hir().local_def_id_from_hir_id(item.hir_id);
+            if let ty::Adt(adt, substs) = cx.tcx.type_of(impl_def_id).sty;
+            // the derive would implement `Default` for all type arguments
+            if !adt.is_union() && substs.types().next().is_none();
+            if let Some(adt_span) = cx.tcx.hir().span_if_local(adt.did);
+            if !in_macro(adt_span);
+            if let Some(expr) = body_expr(&cx.tcx.hir().body(body_id).value);
+            if let Some(derive) = derive_equivalent(cx, adt, expr);
+            if !matches!(derive, Derive::Enum(_)) || msrvs::meets(self.msrv.as_ref(), msrvs::DEFAULT_ENUM_ATTRIBUTE);
+            then {
+                emit(cx, item.span, adt_span, derive);
+            }
+        }
+    }
+}
+
+/// What is needed to derive `Default` instead.
+enum Derive {
+    /// Annotate the struct.
+    Struct,
+    /// Annotate the enum and mark the variant with `#[default]`.
+    Enum(Span),
+}
+
+/// Returns the only expression of a function body without statements.
+fn body_expr(expr: &Expr) -> Option<&Expr> {
+    match expr.node {
+        ExprKind::Block(ref block, _) if block.stmts.is_empty() => block.expr.as_ref().map(|e| &**e),
+        _ => None,
+    }
+}
+
+/// Checks whether `expr` constructs the same value as the derived `Default`
+/// implementation of `adt` would.
+fn derive_equivalent(cx: &LateContext<'_, '_>, adt: &AdtDef, expr: &Expr) -> Option<Derive> {
+    match expr.node {
+        ExprKind::Struct(_, ref fields, None) if adt.is_struct() => {
+            if fields.iter().all(|field| is_default_equivalent(cx, &field.expr)) {
+                return Some(Derive::Struct);
+            }
+        },
+        ExprKind::Call(ref func, ref args) if adt.is_struct() => {
+            if_chain! {
+                if let ExprKind::Path(ref qpath) = func.node;
+                if let Def::StructCtor(..) = cx.tables.qpath_def(qpath, func.hir_id);
+                if args.iter().all(|arg| is_default_equivalent(cx, arg));
+                then {
+                    return Some(Derive::Struct);
+                }
+            }
+        },
+        ExprKind::Path(ref qpath) => match cx.tables.qpath_def(qpath, expr.hir_id) {
+            Def::StructCtor(_, CtorKind::Const) if adt.is_struct() => return Some(Derive::Struct),
+            Def::VariantCtor(did, CtorKind::Const) if adt.is_enum() => {
+                return cx.tcx.hir().span_if_local(did).map(Derive::Enum);
+            },
+            _ => {},
+        },
+        _ => {},
+    }
+    None
+}
+
+/// Checks whether `expr` evaluates to the `Default` value of its type.
+fn is_default_equivalent(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::Call(ref func, ref args) if args.is_empty() => {
+            if let ExprKind::Path(ref qpath) = func.node {
+                if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, func.hir_id)) {
+                    return match_def_path(cx.tcx, def_id, &paths::DEFAULT_TRAIT_METHOD);
+                }
+            }
+            false
+        },
+        ExprKind::Lit(ref lit) => match lit.node {
+            LitKind::Bool(value) => !value,
+            LitKind::Int(value, _) => value == 0,
+            LitKind::Float(ref value, _) | LitKind::FloatUnsuffixed(ref value) => {
+                value.as_str().parse::<f64>().ok() == Some(0.0)
+            },
+            LitKind::Str(ref value, _) => value.as_str().is_empty(),
+            LitKind::Char(value) => value == '\0',
+            _ => false,
+        },
+        ExprKind::Tup(ref exprs) => exprs.iter().all(|e| is_default_equivalent(cx, e)),
+        ExprKind::Path(ref qpath) => opt_def_id(cx.tables.qpath_def(qpath, expr.hir_id))
+            .map_or(false, |def_id| match_def_path(cx.tcx, def_id, &paths::OPTION_NONE)),
+        _ => false,
+    }
+}
+
+fn emit(cx: &LateContext<'_, '_>, impl_span: Span, adt_span: Span, derive: Derive) {
+    let indent = |span: Span| " ".repeat(cx.sess().source_map().lookup_char_pos(span.lo()).col.0);
+    span_lint_and_then(cx, DERIVABLE_IMPLS, impl_span, "this `impl` can be derived", |db| {
+        let mut sugg = vec![
+            (impl_span, String::new()),
+            (
+                adt_span.shrink_to_lo(),
+                format!("#[derive(Default)]\n{}", indent(adt_span)),
+            ),
+        ];
+        let (msg, applicability) = match derive {
+            Derive::Struct => (
+                "replace the manual implementation with a derive attribute",
+                Applicability::MachineApplicable,
+            ),
+            Derive::Enum(variant_span) => {
+                sugg.push((
+                    variant_span.shrink_to_lo(),
+                    format!("#[default]\n{}", indent(variant_span)),
+                ));
+                // the toolchain building the crate may predate `#[default]`
+                (
+                    "replace the manual implementation with a derive attribute and mark the default variant",
+                    Applicability::MaybeIncorrect,
+                )
+            },
+        };
+        db.multipart_suggestion(msg, sugg, applicability);
+    });
+}
@@ -0,0 +1,174 @@
+use crate::utils::ast_pattern::{ParseError, Pattern, Value};
+use crate::utils::conf::DisallowedPattern;
+use crate::utils::{in_macro, span_lint, span_lint_at_level};
+use rustc::lint::{EarlyContext, EarlyLintPass, Level, LintArray, LintContext, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast;
+use syntax::source_map::{BytePos, FileName, SourceMap, Span, DUMMY_SP};
+use syntax_pos::Pos;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for code matching one of the patterns given in
+    /// the `disallowed-patterns` configuration option.
+    ///
+    /// Patterns use the syntax of the `pattern!` macro and are matched against
+    /// every expression, block, statement and item. See `utils::ast_pattern` for the
+    /// supported subset.
+    ///
+    /// **Why is this bad?** Projects may want to ban certain shapes of code
+    /// without writing a new lint.
+    ///
+    /// A pattern can set its own `level`, which replaces the level of this lint
+    /// where the lint is enabled.
+    ///
+    /// **Known problems:** Code in macro expansions is not checked.
+    ///
+    /// **Example:**
+    /// ```toml
+    /// disallowed-patterns = [
+    ///     { pattern = "Loop(Block())", message = "empty loop, use `std::thread::park` instead" },
+    ///     { pattern = "IfLet(Block(), ())", message = "empty `if let`", level = "deny" },
+    /// ]
+    /// ```
+    pub DISALLOWED_PATTERNS,
+    restriction,
+    "code matching a pattern from the `disallowed-patterns` configuration"
+}
+
+pub struct DisallowedPatterns {
+    /// The patterns with their message and level, if they set one.
+    patterns: Vec<(Pattern, String, Option<Level>)>,
+    /// Patterns that failed to parse, reported once per crate.
+    errors: Vec<(String, ParseError)>,
+    /// Unknown levels and the patterns that set them.
+    level_errors: Vec<(String, String)>,
+}
+
+impl DisallowedPatterns {
+    pub fn new(config: &[DisallowedPattern]) -> Self {
+        let mut patterns = Vec::new();
+        let mut errors = Vec::new();
+        let mut level_errors = Vec::new();
+        for entry in config {
+            let level = match entry.level {
+                Some(ref name) => match Level::from_str(name) {
+                    Some(Level::Allow) => continue,
+                    Some(level) => Some(level),
+                    None => {
+                        level_errors.push((name.clone(), entry.pattern.clone()));
+                        continue;
+                    },
+                },
+                None => None,
+            };
+            match Pattern::parse(&entry.pattern) {
+                Ok(pat) => patterns.push((pat, entry.message.clone(), level)),
+                Err(e) => errors.push((entry.pattern.clone(), e)),
+            }
+        }
+        Self {
+            patterns,
+            errors,
+            level_errors,
+        }
+    }
+
+    fn check(&self, cx: &EarlyContext<'_>, value: Value<'_>, span: Span) {
+        if in_macro(span) {
+            return;
+        }
+        for (pat, msg, level) in &self.patterns {
+            if pat.matches(value) {
+                match *level {
+                    Some(level) => span_lint_at_level(cx, DISALLOWED_PATTERNS, span, msg, level),
+                    None => span_lint(cx, DISALLOWED_PATTERNS, span, msg),
+                }
+            }
+        }
+    }
+}
+
+impl LintPass for DisallowedPatterns {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(DISALLOWED_PATTERNS)
+    }
+
+    fn name(&self) -> &'static str {
+        "DisallowedPatterns"
+    }
+}
+
+impl EarlyLintPass for DisallowedPatterns {
+    fn check_crate(&mut self, cx: &EarlyContext<'_>, _: &ast::Crate) {
+        for (pattern, error) in &self.errors {
+            let span = match error_span(cx.sess().source_map(), pattern, error) {
+                Some(span) => span,
+                None => {
+                    cx.sess().err(&format!(
+                        "invalid pattern `{}` in `disallowed-patterns`: {}",
+                        pattern, error
+                    ));
+                    continue;
+                },
+            };
+            let mut db = cx.sess().struct_span_err(
+                span,
+                &format!("invalid pattern in `disallowed-patterns`: {}", error.msg),
+            );
+            if let Some(kind) = error.suggestion {
+                db.span_suggestion(
+                    span,
+                    "a node kind with a similar name exists",
+                    kind.to_string(),
+                    Applicability::MaybeIncorrect,
+                );
+            }
+            db.emit();
+        }
+        for (level, pattern) in &self.level_errors {
+            cx.sess().err(&format!(
+                "unknown level `{}` for the pattern `{}` in `disallowed-patterns`, expected one of `allow`, `warn`, \
+                 `deny` or `forbid`",
+                level, pattern
+            ));
+        }
+    }
+
+    fn check_expr(&mut self, cx: &EarlyContext<'_>, expr: &ast::Expr) {
+        self.check(cx, Value::Expr(expr), expr.span);
+    }
+
+    fn check_block(&mut self, cx: &EarlyContext<'_>, block: &ast::Block) {
+        self.check(cx, Value::Block(block), block.span);
+    }
+
+    fn check_stmt(&mut self, cx: &EarlyContext<'_>, stmt: &ast::Stmt) {
+        self.check(cx, Value::Stmt(stmt), stmt.span);
+    }
+
+    fn check_item(&mut self, cx: &EarlyContext<'_>, item: &ast::Item) {
+        self.check(cx, Value::Item(item), item.span);
+    }
+}
+
+/// The span of the offending token of `pattern` in the configuration file,
+/// if the pattern is written there without escapes.
+fn error_span(source_map: &SourceMap, pattern: &str, error: &ParseError) -> Option<Span> {
+    source_map.files().iter().find_map(|file| {
+        match file.name {
+            FileName::Real(ref path) if path.extension().map_or(false, |ext| ext == "toml") => {},
+            _ => return None,
+        }
+        let src = file.src.as_ref()?;
+        let start = ["\"", "'"].iter().find_map(|quote| {
+            let quoted = format!("{}{}{}", quote, pattern, quote);
+            src.find(&quoted).map(|pos| pos + quote.len())
+        })?;
+        Some(
+            DUMMY_SP
+                .with_lo(file.start_pos + BytePos::from_usize(start + error.start))
+                .with_hi(file.start_pos + BytePos::from_usize(start + error.end)),
+        )
+    })
+}
@@ -1,11 +1,17 @@
-use crate::utils::span_lint;
+use crate::utils::{
+    fn_sig_span, is_expn_of, match_def_path, match_type, opt_def_id, paths, return_ty, span_lint, span_note_and_lint,
+    walk_ptrs_ty,
+};
+use if_chain::if_chain;
 use itertools::Itertools;
 use pulldown_cmark;
-use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
+use rustc::hir;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::lint::{EarlyContext, EarlyLintPass, LateContext, LateLintPass, LintArray, LintPass};
 use rustc::{declare_tool_lint, lint_array};
 use rustc_data_structures::fx::FxHashSet;
 use syntax::ast;
-use syntax::source_map::{BytePos, Span};
+use syntax::source_map::{BytePos, Span, DUMMY_SP};
 use syntax_pos::Pos;
 use url::Url;
 
@@ -33,6 +39,52 @@ declare_clippy_lint! {
     "presence of `_`, `::` or camel-case outside backticks in documentation"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks the doc comments of publicly visible functions
+    /// that may panic for a `# Panics` section. Calls to `unwrap` and
+    /// `expect` on `Option` and `Result`, indexing, `panic!` and `assert!`
+    /// are considered possible panics, `unreachable!` and `debug_assert!` are
+    /// not.
+    ///
+    /// **Why is this bad?** Documenting the conditions under which a
+    /// function panics helps callers avoid them.
+    ///
+    /// **Known problems:** Panics in called functions are not found.
+    ///
+    /// **Examples:**
+    /// ```rust
+    /// /// Returns the first element.
+    /// pub fn first(v: &[u32]) -> u32 {
+    ///     v[0]
+    /// }
+    /// ```
+    pub MISSING_PANICS_DOC,
+    pedantic,
+    "`pub fn` may panic without `# Panics` in doc comment"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks the doc comments of publicly visible functions
+    /// that may return an `Err` for an `# Errors` section.
+    ///
+    /// **Why is this bad?** Documenting the type of errors that can be returned
+    /// from a function helps callers handle them.
+    ///
+    /// **Known problems:** A function is assumed to return an `Err` if its
+    /// body uses `Err`, `?` or calls another function returning a `Result`.
+    ///
+    /// **Examples:**
+    /// ```rust
+    /// use std::io;
+    /// pub fn read_u8() -> Result<u8, io::Error> {
+    ///     Err(io::Error::from(io::ErrorKind::Other))
+    /// }
+    /// ```
+    pub MISSING_ERRORS_DOC,
+    pedantic,
+    "`pub fn` may return `Result::Err` without `# Errors` in doc comment"
+}
+
 #[derive(Clone)]
 pub struct Doc {
     valid_idents: FxHashSet<String>,
@@ -64,6 +116,204 @@ impl EarlyLintPass for Doc {
     }
 }
 
+/// Checks public functions for missing `# Panics` and `# Errors` doc sections.
+#[derive(Copy, Clone)]
+pub struct DocSections;
+
+impl LintPass for DocSections {
+    fn get_lints(&self) -> LintArray {
+        lint_array![MISSING_PANICS_DOC, MISSING_ERRORS_DOC]
+    }
+
+    fn name(&self) -> &'static str {
+        "DocSections"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DocSections {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx hir::Item) {
+        if let hir::ItemKind::Fn(ref decl, _, _, body_id) = item.node {
+            check_sections(cx, item.hir_id, item.span, &item.attrs, decl, body_id);
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx hir::ImplItem) {
+        if let hir::ImplItemKind::Method(ref sig, body_id) = item.node {
+            // trait implementations are documented on the trait
+            let parent = cx.tcx.hir().get_parent_item(item.hir_id);
+            if let hir::ItemKind::Impl(_, _, _, _, None, _, _) = cx.tcx.hir().expect_item_by_hir_id(parent).node {
+                check_sections(cx, item.hir_id, item.span, &item.attrs, &sig.decl, body_id);
+            }
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx hir::TraitItem) {
+        if let hir::TraitItemKind::Method(ref sig, hir::TraitMethod::Provided(body_id)) = item.node {
+            check_sections(cx, item.hir_id, item.span, &item.attrs, &sig.decl, body_id);
+        }
+    }
+}
+
+fn check_sections(
+    cx: &LateContext<'_, '_>,
+    hir_id: hir::HirId,
+    span: Span,
+    attrs: &[ast::Attribute],
+    decl: &hir::FnDecl,
+    body_id: hir::BodyId,
+) {
+    if !cx.access_levels.is_exported(cx.tcx.hir().hir_to_node_id(hir_id)) {
+        return;
+    }
+    let sig_span = fn_sig_span(cx, span, decl);
+    let headers = doc_headers(attrs);
+    let body = cx.tcx.hir().body(body_id);
+    if !headers.panics {
+        let mut visitor = FindPanic { cx, span: None };
+        visitor.visit_expr(&body.value);
+        if let Some(panic_span) = visitor.span {
+            span_note_and_lint(
+                cx,
+                MISSING_PANICS_DOC,
+                sig_span,
+                "docs for function which may panic missing `# Panics` section",
+                panic_span,
+                "first possible panic found here",
+            );
+        }
+    }
+    if !headers.errors && match_type(cx, return_ty(cx, hir_id), &paths::RESULT) {
+        let mut visitor = FindErr { cx, found: false };
+        visitor.visit_expr(&body.value);
+        if visitor.found {
+            span_lint(
+                cx,
+                MISSING_ERRORS_DOC,
+                sig_span,
+                "docs for function returning `Result` missing `# Errors` section",
+            );
+        }
+    }
+}
+
+/// The sections of a doc comment checked by `DocSections`.
+#[derive(Default)]
+struct DocHeaders {
+    panics: bool,
+    errors: bool,
+}
+
+fn doc_headers(attrs: &[ast::Attribute]) -> DocHeaders {
+    use pulldown_cmark::Event::*;
+    use pulldown_cmark::Tag::*;
+
+    let doc: String = attrs
+        .iter()
+        .filter(|attr| attr.is_sugared_doc)
+        .filter_map(ast::Attribute::value_str)
+        .map(|doc| strip_doc_comment_decoration(&doc.as_str(), DUMMY_SP).0)
+        .collect();
+    let mut headers = DocHeaders::default();
+    let mut in_header = false;
+    for event in pulldown_cmark::Parser::new(&doc) {
+        match event {
+            Start(Header(_)) => in_header = true,
+            End(Header(_)) => in_header = false,
+            Text(ref text) if in_header => {
+                headers.panics |= text.trim() == "Panics";
+                headers.errors |= text.trim() == "Errors";
+            },
+            _ => {},
+        }
+    }
+    headers
+}
+
+/// Finds the first expression in a body that may panic.
+struct FindPanic<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    span: Option<Span>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for FindPanic<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr) {
+        if self.span.is_some() {
+            return;
+        }
+        if may_panic(self.cx, expr) {
+            self.span = Some(expr.span.source_callsite());
+        } else {
+            walk_expr(self, expr);
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir())
+    }
+}
+
+fn may_panic(cx: &LateContext<'_, '_>, expr: &hir::Expr) -> bool {
+    match expr.node {
+        hir::ExprKind::Call(ref func, _) => {
+            if_chain! {
+                if let hir::ExprKind::Path(ref qpath) = func.node;
+                if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, func.hir_id));
+                if match_def_path(cx.tcx, def_id, &paths::BEGIN_PANIC)
+                    || match_def_path(cx.tcx, def_id, &paths::BEGIN_PANIC_FMT);
+                then {
+                    return !["unreachable", "debug_assert", "debug_assert_eq", "debug_assert_ne"]
+                        .iter()
+                        .any(|name| is_expn_of(expr.span, name).is_some());
+                }
+            }
+            false
+        },
+        hir::ExprKind::MethodCall(ref path, _, ref args)
+            if path.ident.name == "unwrap" || path.ident.name == "expect" =>
+        {
+            let ty = walk_ptrs_ty(cx.tables.expr_ty(&args[0]));
+            match_type(cx, ty, &paths::OPTION) || match_type(cx, ty, &paths::RESULT)
+        },
+        hir::ExprKind::Index(..) => true,
+        _ => false,
+    }
+}
+
+/// Checks whether a body may evaluate to an `Err`.
+struct FindErr<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    found: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for FindErr<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr) {
+        if self.found {
+            return;
+        }
+        self.found = match expr.node {
+            hir::ExprKind::Match(_, _, hir::MatchSource::TryDesugar) => true,
+            hir::ExprKind::Path(ref qpath) => opt_def_id(self.cx.tables.qpath_def(qpath, expr.hir_id))
+                .map_or(false, |def_id| match_def_path(self.cx.tcx, def_id, &paths::RESULT_ERR)),
+            hir::ExprKind::MethodCall(..) => match_type(self.cx, self.cx.tables.expr_ty(expr), &paths::RESULT),
+            hir::ExprKind::Call(ref func, _) => {
+                let is_ok = if let hir::ExprKind::Path(ref qpath) = func.node {
+                    opt_def_id(self.cx.tables.qpath_def(qpath, func.hir_id))
+                        .map_or(false, |def_id| match_def_path(self.cx.tcx, def_id, &paths::RESULT_OK))
+                } else {
+                    false
+                };
+                !is_ok && match_type(self.cx, self.cx.tables.expr_ty(expr), &paths::RESULT)
+            },
+            _ => false,
+        };
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
+
 struct Parser<'a> {
     parser: pulldown_cmark::Parser<'a>,
 }
@@ -0,0 +1,374 @@
+use crate::utils::comments::{blank_lines, CommentCache, CommentKind};
+use crate::utils::{in_macro, span_lint_and_sugg, span_lint_and_then};
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast;
+use syntax::parse::token;
+use syntax::source_map::Span;
+use syntax::tokenstream::TokenTree;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for comments starting with four slashes
+    /// (`////`) directly before an item.
+    ///
+    /// **Why is this bad?** They look like doc comments, but are plain
+    /// comments, so they don't show up in the documentation.
+    ///
+    /// **Known problems:** Doc comments commented out with `//` look the
+    /// same.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// //// Returns the answer.
+    /// fn answer() -> u32 {
+    ///     42
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// /// Returns the answer.
+    /// fn answer() -> u32 {
+    ///     42
+    /// }
+    /// ```
+    pub FOUR_FORWARD_SLASHES,
+    style,
+    "comments with 4 forward slashes (`////`) that look like doc comments"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for headings in `///` and `//!` doc comments
+    /// that are only followed by blank lines up to the next heading of the
+    /// same or a higher level, or the end of the documentation.
+    ///
+    /// **Why is this bad?** An empty section, e.g. a `# Examples` heading
+    /// left over from a template, makes the documentation look unfinished.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// /// Returns the answer.
+    /// ///
+    /// /// # Examples
+    /// ///
+    /// fn answer() -> u32 {
+    ///     42
+    /// }
+    /// ```
+    pub EMPTY_DOC_SECTIONS,
+    pedantic,
+    "headings without content in doc comments"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for doc comments in the matchers of
+    /// `macro_rules!` arms.
+    ///
+    /// **Why is this bad?** A doc comment there doesn't document the arm,
+    /// it is part of the pattern: the arm then only matches input containing
+    /// the same doc comment.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// macro_rules! answer {
+    ///     (
+    ///         /// Returns the answer.
+    ///     ) => {
+    ///         42
+    ///     };
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// macro_rules! answer {
+    ///     (
+    ///         // Returns the answer.
+    ///     ) => {
+    ///         42
+    ///     };
+    /// }
+    /// ```
+    pub DOC_COMMENT_IN_MACRO_MATCHER,
+    style,
+    "doc comments in the matchers of `macro_rules!` arms"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for blank lines between a doc comment and the
+    /// item it documents.
+    ///
+    /// **Why is this bad?** The blank line makes the doc comment look
+    /// detached, e.g. like the documentation of the module that was meant to
+    /// be an inner `//!` comment, or of an item that was removed.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// /// Returns the answer.
+    ///
+    /// fn answer() -> u32 {
+    ///     42
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// /// Returns the answer.
+    /// fn answer() -> u32 {
+    ///     42
+    /// }
+    /// ```
+    pub EMPTY_LINE_AFTER_DOC_COMMENTS,
+    style,
+    "blank lines between a doc comment and the documented item"
+}
+
+#[derive(Default)]
+pub struct DocComments {
+    comments: CommentCache,
+}
+
+impl LintPass for DocComments {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(
+            FOUR_FORWARD_SLASHES,
+            EMPTY_DOC_SECTIONS,
+            DOC_COMMENT_IN_MACRO_MATCHER,
+            EMPTY_LINE_AFTER_DOC_COMMENTS
+        )
+    }
+
+    fn name(&self) -> &'static str {
+        "DocComments"
+    }
+}
+
+impl EarlyLintPass for DocComments {
+    fn check_crate(&mut self, cx: &EarlyContext<'_>, krate: &ast::Crate) {
+        check_empty_sections(cx, &krate.attrs);
+    }
+
+    fn check_item(&mut self, cx: &EarlyContext<'_>, item: &ast::Item) {
+        if in_macro(item.span) {
+            return;
+        }
+        self.check_four_slashes(cx, item.span, &item.attrs);
+        check_empty_sections(cx, &item.attrs);
+        check_empty_lines(cx, item.span, &item.attrs);
+        if let ast::ItemKind::MacroDef(ref def) = item.node {
+            if def.legacy {
+                self.check_macro_matchers(cx, def);
+            }
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &EarlyContext<'_>, item: &ast::ImplItem) {
+        if !in_macro(item.span) {
+            self.check_four_slashes(cx, item.span, &item.attrs);
+            check_empty_sections(cx, &item.attrs);
+            check_empty_lines(cx, item.span, &item.attrs);
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &EarlyContext<'_>, item: &ast::TraitItem) {
+        if !in_macro(item.span) {
+            self.check_four_slashes(cx, item.span, &item.attrs);
+            check_empty_sections(cx, &item.attrs);
+            check_empty_lines(cx, item.span, &item.attrs);
+        }
+    }
+
+    fn check_struct_field(&mut self, cx: &EarlyContext<'_>, field: &ast::StructField) {
+        if !in_macro(field.span) {
+            self.check_four_slashes(cx, field.span, &field.attrs);
+            check_empty_sections(cx, &field.attrs);
+            check_empty_lines(cx, field.span, &field.attrs);
+        }
+    }
+}
+
+impl DocComments {
+    fn check_four_slashes(&mut self, cx: &EarlyContext<'_>, span: Span, attrs: &[ast::Attribute]) {
+        let comments = match self.comments.get(cx, span) {
+            Some(comments) => comments,
+            None => return,
+        };
+        let attr_spans: Vec<_> = attrs.iter().map(|attr| attr.span).collect();
+        for comment in comments.preceding(span, &attr_spans).into_iter().rev() {
+            let text = comments.text(comment);
+            if comment.kind == CommentKind::Line && text.starts_with("////") && !text.starts_with("/////") {
+                span_lint_and_sugg(
+                    cx,
+                    FOUR_FORWARD_SLASHES,
+                    comments.span(comment),
+                    "this comment has 4 forward slashes (`////`), it looks like a doc comment but isn't one",
+                    "make it a doc comment by removing one `/`",
+                    text[1..].to_string(),
+                    Applicability::MachineApplicable,
+                );
+            }
+        }
+    }
+
+    fn check_macro_matchers(&mut self, cx: &EarlyContext<'_>, def: &ast::MacroDef) {
+        let trees: Vec<_> = def.stream().trees().collect();
+        let matchers = trees.windows(2).filter_map(|pair| match (&pair[0], &pair[1]) {
+            (TokenTree::Delimited(span, ..), TokenTree::Token(_, token::FatArrow)) => Some(span.entire()),
+            _ => None,
+        });
+        for matcher in matchers {
+            let comments = match self.comments.get(cx, matcher) {
+                Some(comments) => comments,
+                None => return,
+            };
+            for comment in comments.within(matcher).filter(|comment| comment.doc.is_some()) {
+                // `///` to `//`, `//!` to `// !`, `/**` to `/*` and `/*!` to `/* !`
+                let text = comments.text(comment);
+                let sugg = match (comment.kind, &text[2..3]) {
+                    (CommentKind::Line, "/") => text[1..].to_string(),
+                    (CommentKind::Line, _) => format!("// {}", &text[2..]),
+                    (CommentKind::Block, "*") => format!("/*{}", &text[3..]),
+                    (CommentKind::Block, _) => format!("/* {}", &text[2..]),
+                };
+                span_lint_and_sugg(
+                    cx,
+                    DOC_COMMENT_IN_MACRO_MATCHER,
+                    comments.span(comment),
+                    "this doc comment is part of the matcher, the arm only matches input containing it",
+                    "if it is meant as a comment, use a plain comment",
+                    sugg,
+                    Applicability::MaybeIncorrect,
+                );
+            }
+        }
+    }
+}
+
+/// Checks for blank lines after the outer doc comments in `attrs`, up to the
+/// next attribute or the item at `span`.
+fn check_empty_lines(cx: &EarlyContext<'_>, span: Span, attrs: &[ast::Attribute]) {
+    let outer: Vec<_> = attrs
+        .iter()
+        .filter(|attr| attr.style == ast::AttrStyle::Outer && !in_macro(attr.span))
+        .collect();
+    let first_doc = match outer.iter().find(|attr| attr.is_sugared_doc) {
+        Some(first_doc) => first_doc.span,
+        None => return,
+    };
+    let mut docs = None;
+    let mut blank = Vec::new();
+    for (i, attr) in outer.iter().enumerate().filter(|(_, attr)| attr.is_sugared_doc) {
+        let next = outer.get(i + 1).map_or(span.lo(), |next| next.span.lo());
+        let lines = blank_lines(cx, attr.span.with_lo(attr.span.hi()).with_hi(next));
+        if !lines.is_empty() {
+            docs = Some(first_doc.to(attr.span));
+            blank.extend(lines);
+        }
+    }
+    if let Some(docs) = docs {
+        let (msg, help) = if blank.len() == 1 {
+            (
+                "empty line after doc comment",
+                "if the empty line is unintentional, remove it",
+            )
+        } else {
+            (
+                "empty lines after doc comment",
+                "if the empty lines are unintentional, remove them",
+            )
+        };
+        span_lint_and_then(cx, EMPTY_LINE_AFTER_DOC_COMMENTS, docs, msg, |db| {
+            db.multipart_suggestion(
+                help,
+                blank.into_iter().map(|line| (line, String::new())).collect(),
+                Applicability::MaybeIncorrect,
+            );
+        });
+    }
+}
+
+/// Checks the `///` or `//!` doc comments in `attrs` for empty sections.
+fn check_empty_sections(cx: &EarlyContext<'_>, attrs: &[ast::Attribute]) {
+    for &style in &[ast::AttrStyle::Outer, ast::AttrStyle::Inner] {
+        let docs: Vec<_> = attrs
+            .iter()
+            .enumerate()
+            .filter(|(_, attr)| attr.style == style && attr.name() == "doc")
+            .collect();
+        let lines: Option<Vec<_>> = docs
+            .iter()
+            .map(|&(_, attr)| {
+                let text = attr.value_str()?.as_str().to_string();
+                if !attr.is_sugared_doc || !(text.starts_with("///") || text.starts_with("//!")) {
+                    return None;
+                }
+                Some((attr.span, text[3..].trim().to_string()))
+            })
+            .collect();
+        // the lines must be one `///` or `//!` comment, not interrupted by other attributes,
+        // for the removal of a section to be exact
+        let contiguous = docs.windows(2).all(|pair| pair[0].0 + 1 == pair[1].0);
+        if let (Some(lines), true) = (lines, contiguous) {
+            check_doc_lines(cx, &lines);
+        }
+    }
+}
+
+fn check_doc_lines(cx: &EarlyContext<'_>, lines: &[(Span, String)]) {
+    let mut headings = Vec::new();
+    let mut in_code = false;
+    for (i, (_, line)) in lines.iter().enumerate() {
+        if line.starts_with("```") || line.starts_with("~~~") {
+            in_code = !in_code;
+        } else if !in_code {
+            if let Some(level) = heading_level(line) {
+                headings.push((i, level));
+            }
+        }
+    }
+
+    for (n, &(start, level)) in headings.iter().enumerate() {
+        let end = headings[n + 1..]
+            .iter()
+            .find(|&&(_, next_level)| next_level <= level)
+            .map_or(lines.len(), |&(next, _)| next);
+        if !lines[start + 1..end].iter().all(|(_, line)| line.is_empty()) {
+            continue;
+        }
+        // remove the lines from the end of the preceding line, so that no blank line is left; for a
+        // section at the end, also remove the blank lines before it
+        let preceding = if end < lines.len() {
+            start.checked_sub(1)
+        } else {
+            lines[..start].iter().rposition(|(_, line)| !line.is_empty())
+        };
+        let span = match preceding {
+            Some(preceding) => lines[preceding].0.shrink_to_hi().to(lines[end - 1].0),
+            None if end < lines.len() => lines[start].0.to(lines[end].0.shrink_to_lo()),
+            None => lines[start].0.to(lines[end - 1].0),
+        };
+        span_lint_and_sugg(
+            cx,
+            EMPTY_DOC_SECTIONS,
+            span,
+            &format!("the `{}` section of the documentation is empty", lines[start].1),
+            "remove the section",
+            String::new(),
+            Applicability::MaybeIncorrect,
+        );
+    }
+}
+
+/// The level of a Markdown heading like `## Examples`.
+fn heading_level(line: &str) -> Option<usize> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    if 1 <= level && level <= 6 && line[level..].starts_with(' ') && !line[level..].trim().is_empty() {
+        Some(level)
+    } else {
+        None
+    }
+}
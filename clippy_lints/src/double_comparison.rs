@@ -6,7 +6,7 @@ use rustc::{declare_tool_lint, lint_array};
 use rustc_errors::Applicability;
 use syntax::source_map::Span;
 
-use crate::utils::{snippet_with_applicability, span_lint_and_sugg, SpanlessEq};
+use crate::utils::{snippet_with_applicability, span_lint_and_sugg_lazy, SpanlessEq};
 
 declare_clippy_lint! {
     /// **What it does:** Checks for double comparions that could be simplified to a single expression.
@@ -58,18 +58,18 @@ impl<'a, 'tcx> Pass {
         }
         macro_rules! lint_double_comparison {
             ($op:tt) => {{
-                let mut applicability = Applicability::MachineApplicable;
-                let lhs_str = snippet_with_applicability(cx, llhs.span, "", &mut applicability);
-                let rhs_str = snippet_with_applicability(cx, lrhs.span, "", &mut applicability);
-                let sugg = format!("{} {} {}", lhs_str, stringify!($op), rhs_str);
-                span_lint_and_sugg(
+                span_lint_and_sugg_lazy(
                     cx,
                     DOUBLE_COMPARISONS,
                     span,
                     "This binary expression can be simplified",
                     "try",
-                    sugg,
-                    applicability,
+                    Applicability::MachineApplicable,
+                    |applicability| {
+                        let lhs_str = snippet_with_applicability(cx, llhs.span, "", applicability);
+                        let rhs_str = snippet_with_applicability(cx, lrhs.span, "", applicability);
+                        format!("{} {} {}", lhs_str, stringify!($op), rhs_str)
+                    },
                 );
             }};
         }
@@ -0,0 +1,250 @@
+//! Running Clippy in-process, for tools like IDE back-ends and CI runners
+//! that want structured diagnostics instead of parsing the output of
+//! `cargo clippy`.
+//!
+//! `run` checks a crate with all Clippy lints and passes every diagnostic to a
+//! callback. `register_lints` and `controller` are the building blocks
+//! `clippy-driver` itself uses, for tools with their own compiler setup.
+//!
+//! The configuration is read on every run, so a long-running process picks up
+//! changes to `clippy.toml`. `conf_fingerprint` tells such a process whether
+//! the diagnostics of an earlier run are outdated because of them.
+//!
+//! This API is semi-stable: it follows the compiler interface of the
+//! toolchain Clippy is built with, but `run` and `Diagnostic` only change
+//! when the JSON diagnostics of rustc do.
+
+use crate::utils::conf::{self, Fingerprint};
+use rustc::session::Session;
+use rustc::util::common::ErrorReported;
+use rustc_driver::driver::CompileController;
+use rustc_driver::Compilation;
+use serde_derive::Deserialize;
+use std::fs;
+use std::io::{self, Write};
+use syntax::source_map::Span;
+
+/// A diagnostic emitted while checking a crate, in the format of rustc's
+/// `--error-format=json`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Diagnostic {
+    /// The primary message.
+    pub message: String,
+    /// The lint or error code, e.g. `clippy::needless_return` or `E0308`.
+    pub code: Option<DiagnosticCode>,
+    /// `error`, `warning`, `note` or `help`.
+    pub level: String,
+    /// The spans the diagnostic points to.
+    pub spans: Vec<DiagnosticSpan>,
+    /// Notes, help messages and suggestions attached to the diagnostic.
+    pub children: Vec<Diagnostic>,
+    /// The diagnostic as it would be printed on stderr.
+    pub rendered: Option<String>,
+}
+
+impl Diagnostic {
+    /// The name of the Clippy lint that emitted the diagnostic, without the
+    /// `clippy::` prefix.
+    pub fn lint(&self) -> Option<&str> {
+        let code = &self.code.as_ref()?.code;
+        if code.starts_with("clippy::") {
+            Some(&code["clippy::".len()..])
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DiagnosticCode {
+    /// The lint name or error code.
+    pub code: String,
+    /// The explanation of an error code, as shown by `rustc --explain`.
+    pub explanation: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DiagnosticSpan {
+    pub file_name: String,
+    pub byte_start: u32,
+    pub byte_end: u32,
+    /// 1-based.
+    pub line_start: usize,
+    pub line_end: usize,
+    /// 1-based, in characters.
+    pub column_start: usize,
+    pub column_end: usize,
+    /// Whether this is the span the diagnostic is about.
+    pub is_primary: bool,
+    pub label: Option<String>,
+    /// The replacement for the text of this span suggested by the diagnostic.
+    pub suggested_replacement: Option<String>,
+    /// `MachineApplicable`, `MaybeIncorrect`, `HasPlaceholders` or
+    /// `Unspecified`.
+    pub suggestion_applicability: Option<String>,
+}
+
+/// Registers all Clippy lints, lint groups and renamed lints with the lint
+/// store of the session, reading the `clippy.toml` of the crate.
+///
+/// Used in `./src/driver.rs`.
+pub fn register_lints(sess: &Session, krate_span: Span) {
+    let mut registry = rustc_plugin::registry::Registry::new(sess, krate_span);
+    registry.args_hidden = Some(Vec::new());
+
+    let conf = crate::read_conf(&registry);
+    crate::register_plugins(&mut registry, &conf);
+
+    let rustc_plugin::registry::Registry {
+        early_lint_passes,
+        late_lint_passes,
+        lint_groups,
+        llvm_passes,
+        attributes,
+        ..
+    } = registry;
+    let mut ls = sess.lint_store.borrow_mut();
+    for pass in early_lint_passes {
+        ls.register_early_pass(Some(sess), true, false, pass);
+    }
+    for pass in late_lint_passes {
+        ls.register_late_pass(Some(sess), true, pass);
+    }
+
+    for (name, (to, deprecated_name)) in lint_groups {
+        ls.register_group(Some(sess), true, name, deprecated_name, to);
+    }
+    crate::register_pre_expansion_lints(sess, &mut ls, &conf);
+    crate::register_renamed(&mut ls);
+
+    sess.plugin_llvm_passes.borrow_mut().extend(llvm_passes);
+    sess.plugin_attributes.borrow_mut().extend(attributes);
+}
+
+/// A compile controller registering the Clippy lints once the crate is parsed.
+///
+/// Used in `./src/driver.rs`.
+pub fn controller() -> CompileController<'static> {
+    let mut controller = CompileController::basic();
+    controller.after_parse.callback = Box::new(|state| {
+        let krate = state
+            .krate
+            .as_ref()
+            .expect("at this compilation stage the crate must be parsed");
+        register_lints(state.session, krate.span);
+    });
+    controller
+}
+
+/// Checks a crate with Clippy, passing each diagnostic to `on_diagnostic`
+/// instead of printing it.
+///
+/// `args` are the arguments of a `rustc` invocation, starting with the
+/// program name, which is ignored. Unlike `clippy-driver`, this doesn't look
+/// for a sysroot, so `args` must contain `--sysroot` unless the sysroot of
+/// the current executable is the right one. `--error-format=json` and the
+/// `clippy` and `feature="cargo-clippy"` cfgs are added.
+///
+/// The compiler may panic on internal errors, so tools wanting to survive
+/// those should call this in a separate thread, e.g. with `rustc_driver::run`.
+pub fn run<F>(args: &[String], on_diagnostic: F) -> Result<(), ErrorReported>
+where
+    F: FnMut(Diagnostic) + Send + 'static,
+{
+    let mut args = args.to_vec();
+    args.extend(
+        [
+            "--error-format=json",
+            "--cfg",
+            "clippy",
+            "--cfg",
+            r#"feature="cargo-clippy""#,
+        ]
+        .iter()
+        .map(|arg| (*arg).to_string()),
+    );
+    let mut controller = controller();
+    controller.compilation_done.stop = Compilation::Stop;
+
+    let sink = DiagnosticSink {
+        buf: Vec::new(),
+        on_diagnostic,
+    };
+    let (result, _) = rustc_driver::run_compiler(&args, Box::new(controller), None, Some(Box::new(sink)));
+    result.map_err(|_| ErrorReported)
+}
+
+/// The fingerprint of the `clippy.toml` that `run` would read now, looked up
+/// from the `CLIPPY_CONF_DIR` or `CARGO_MANIFEST_DIR` environment variables
+/// like `run` does.
+///
+/// Tools caching the diagnostics of `run` can compare the fingerprints taken
+/// at two runs: if they differ, the configuration changed in between, and
+/// cached diagnostics of unchanged files may be outdated.
+pub fn conf_fingerprint() -> io::Result<Fingerprint> {
+    match conf::lookup_conf_file()? {
+        Some(path) => {
+            let contents = fs::read_to_string(&path)?;
+            Ok(Fingerprint::new(Some((&path, &contents))))
+        },
+        None => Ok(Fingerprint::new(None)),
+    }
+}
+
+/// Receives the output of the JSON emitter, one diagnostic per line, and
+/// passes the parsed diagnostics to the callback.
+struct DiagnosticSink<F> {
+    buf: Vec<u8>,
+    on_diagnostic: F,
+}
+
+impl<F: FnMut(Diagnostic)> Write for DiagnosticSink<F> {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        self.buf.extend_from_slice(bytes);
+        while let Some(end) = self.buf.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.buf.drain(..=end).collect();
+            // other JSON messages, like artifact notifications, are skipped
+            if let Ok(diagnostic) = serde_json::from_slice(&line) {
+                (self.on_diagnostic)(diagnostic);
+            }
+        }
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Diagnostic, DiagnosticSink};
+    use std::io::Write;
+
+    const DIAGNOSTIC: &str = r#"{"message":"unneeded return statement","code":{"code":"clippy::needless_return","explanation":null},"level":"warning","spans":[{"file_name":"src/main.rs","byte_start":22,"byte_end":31,"line_start":2,"line_end":2,"column_start":5,"column_end":14,"is_primary":true,"text":[],"label":null,"suggested_replacement":null,"suggestion_applicability":null,"expansion":null}],"children":[{"message":"remove `return`","code":null,"level":"help","spans":[{"file_name":"src/main.rs","byte_start":22,"byte_end":31,"line_start":2,"line_end":2,"column_start":5,"column_end":14,"is_primary":true,"text":[],"label":null,"suggested_replacement":"1","suggestion_applicability":"MachineApplicable","expansion":null}],"children":[],"rendered":null}],"rendered":"warning: unneeded return statement\n"}"#;
+
+    #[test]
+    fn sink_parses_diagnostics_split_across_writes() {
+        let mut diagnostics = Vec::new();
+        {
+            let mut sink = DiagnosticSink {
+                buf: Vec::new(),
+                on_diagnostic: |diagnostic: Diagnostic| diagnostics.push(diagnostic),
+            };
+            let (first, second) = DIAGNOSTIC.split_at(100);
+            write!(sink, "{}", first).unwrap();
+            writeln!(sink, "{}", second).unwrap();
+            writeln!(sink, r#"{{"artifact":"libfoo.rmeta","emit":"metadata"}}"#).unwrap();
+            writeln!(sink, "{}", DIAGNOSTIC).unwrap();
+        }
+        assert_eq!(diagnostics.len(), 2);
+        let diagnostic = &diagnostics[0];
+        assert_eq!(diagnostic.lint(), Some("needless_return"));
+        assert_eq!(diagnostic.spans[0].line_start, 2);
+        assert_eq!(
+            diagnostic.children[0].spans[0].suggested_replacement,
+            Some("1".to_string())
+        );
+        assert_eq!(diagnostic.children[0].lint(), None);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::utils::{is_copy, match_def_path, opt_def_id, paths, span_note_and_lint};
+use crate::utils::{has_drop_glue, is_copy, match_def_path, opt_def_id, paths, span_note_and_lint};
 use if_chain::if_chain;
 use rustc::hir::*;
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
@@ -97,6 +97,55 @@ declare_clippy_lint! {
     "calls to `std::mem::forget` with a value that implements Copy"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `std::mem::drop` with a value
+    /// that has no drop glue, i.e. neither it nor any of its fields
+    /// implement `Drop`.
+    ///
+    /// **Why is this bad?** Dropping such a value does nothing, the call only
+    /// suggests that it does. It's still useful to end a borrow, which the
+    /// value may contain.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Foo {
+    ///     x: u8,
+    /// }
+    ///
+    /// let x = Foo { x: 42 };
+    /// std::mem::drop(x); // nothing happens
+    /// ```
+    pub DROP_NON_DROP,
+    complexity,
+    "calls to `std::mem::drop` with a value without drop glue"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `std::mem::forget` with a value
+    /// that has no drop glue, i.e. neither it nor any of its fields
+    /// implement `Drop`.
+    ///
+    /// **Why is this bad?** Forgetting such a value is the same as dropping
+    /// it, there is no destructor to skip.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Foo {
+    ///     x: u8,
+    /// }
+    ///
+    /// let x = Foo { x: 42 };
+    /// std::mem::forget(x); // same as dropping `x`
+    /// ```
+    pub FORGET_NON_DROP,
+    complexity,
+    "calls to `std::mem::forget` with a value without drop glue"
+}
+
 const DROP_REF_SUMMARY: &str = "calls to `std::mem::drop` with a reference instead of an owned value. \
                                 Dropping a reference does nothing.";
 const FORGET_REF_SUMMARY: &str = "calls to `std::mem::forget` with a reference instead of an owned value. \
@@ -105,12 +154,16 @@ const DROP_COPY_SUMMARY: &str = "calls to `std::mem::drop` with a value that imp
                                  Dropping a copy leaves the original intact.";
 const FORGET_COPY_SUMMARY: &str = "calls to `std::mem::forget` with a value that implements Copy. \
                                    Forgetting a copy leaves the original intact.";
+const DROP_NON_DROP_SUMMARY: &str = "calls to `std::mem::drop` with a value without drop glue. \
+                                     Dropping it does nothing.";
+const FORGET_NON_DROP_SUMMARY: &str = "calls to `std::mem::forget` with a value without drop glue. \
+                                       Forgetting it is the same as dropping it.";
 
 pub struct Pass;
 
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
-        lint_array!(DROP_REF, FORGET_REF, DROP_COPY, FORGET_COPY)
+        lint_array!(DROP_REF, FORGET_REF, DROP_COPY, FORGET_COPY, DROP_NON_DROP, FORGET_NON_DROP)
     }
 
     fn name(&self) -> &'static str {
@@ -163,6 +216,23 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                                        &msg,
                                        arg.span,
                                        &format!("argument has type {}", arg_ty));
+                } else if !has_drop_glue(cx, arg_ty) {
+                    if match_def_path(cx.tcx, def_id, &paths::DROP) {
+                        lint = DROP_NON_DROP;
+                        msg = DROP_NON_DROP_SUMMARY.to_string();
+                    } else if match_def_path(cx.tcx, def_id, &paths::MEM_FORGET) {
+                        lint = FORGET_NON_DROP;
+                        msg = FORGET_NON_DROP_SUMMARY.to_string();
+                    } else {
+                        return;
+                    }
+                    span_note_and_lint(cx,
+                                       lint,
+                                       expr.span,
+                                       &msg,
+                                       arg.span,
+                                       &format!("argument has type {}, which neither implements `Drop` nor has \
+                                                 fields that do", arg_ty));
                 }
             }
         }
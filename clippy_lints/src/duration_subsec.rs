@@ -7,7 +7,7 @@ use syntax::source_map::Spanned;
 
 use crate::consts::{constant, Constant};
 use crate::utils::paths;
-use crate::utils::{match_type, snippet_with_applicability, span_lint_and_sugg, walk_ptrs_ty};
+use crate::utils::{match_type, snippet_with_applicability, span_lint_and_sugg_lazy, walk_ptrs_ty};
 
 declare_clippy_lint! {
     /// **What it does:** Checks for calculation of subsecond microseconds or milliseconds
@@ -55,19 +55,18 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DurationSubsec {
                     ("subsec_nanos", 1_000) => "subsec_micros",
                     _ => return,
                 };
-                let mut applicability = Applicability::MachineApplicable;
-                span_lint_and_sugg(
+                span_lint_and_sugg_lazy(
                     cx,
                     DURATION_SUBSEC,
                     expr.span,
                     &format!("Calling `{}()` is more concise than this calculation", suggested_fn),
                     "try",
-                    format!(
+                    Applicability::MachineApplicable,
+                    |applicability| format!(
                         "{}.{}()",
-                        snippet_with_applicability(cx, args[0].span, "_", &mut applicability),
+                        snippet_with_applicability(cx, args[0].span, "_", applicability),
                         suggested_fn
                     ),
-                    applicability,
                 );
             }
         }
@@ -0,0 +1,86 @@
+use crate::utils::ast_pattern::{Pattern, Value};
+use crate::utils::{in_macro, snippet_opt, span_lint_and_sugg};
+use lazy_static::lazy_static;
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast;
+
+declare_clippy_lint! {
+    /// **What it does:** Finds structs without fields that are declared with
+    /// brackets, e.g. `struct Foo {}` or `struct Foo();`.
+    ///
+    /// **Why is this bad?** Empty brackets after a struct declaration can be
+    /// omitted.
+    ///
+    /// **Known problems:** Removing the parentheses of a tuple struct breaks
+    /// expressions like `Foo()`, so that suggestion is not applied
+    /// automatically.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Cookie {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// struct Cookie;
+    /// ```
+    pub EMPTY_STRUCTS_WITH_BRACKETS,
+    restriction,
+    "finds struct declarations with empty brackets"
+}
+
+lazy_static! {
+    static ref EMPTY_STRUCT: Pattern =
+        Pattern::parse("Struct(_, _, Brace() | Tuple()#tuple)").expect("invalid pattern");
+}
+
+#[derive(Copy, Clone)]
+pub struct EmptyStructsWithBrackets;
+
+impl LintPass for EmptyStructsWithBrackets {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(EMPTY_STRUCTS_WITH_BRACKETS)
+    }
+
+    fn name(&self) -> &'static str {
+        "EmptyStructsWithBrackets"
+    }
+}
+
+impl EarlyLintPass for EmptyStructsWithBrackets {
+    fn check_item(&mut self, cx: &EarlyContext<'_>, item: &ast::Item) {
+        if in_macro(item.span) {
+            return;
+        }
+        let captures = match EMPTY_STRUCT.captures(Value::Item(item)) {
+            Some(captures) => captures,
+            None => return,
+        };
+        // the brackets, and the `;` of a tuple struct
+        let span = item.span.with_lo(item.ident.span.hi());
+        // structs with generics, a `where` clause or comments in the brackets are not linted
+        let has_empty_brackets = snippet_opt(cx, span).map_or(false, |snip| {
+            let snip = snip.trim().trim_end_matches(';').trim_end();
+            ((snip.starts_with('{') && snip.ends_with('}')) || (snip.starts_with('(') && snip.ends_with(')')))
+                && snip[1..snip.len() - 1].trim().is_empty()
+        });
+        if !has_empty_brackets {
+            return;
+        }
+        let applicability = if captures.get("tuple").is_some() {
+            Applicability::MaybeIncorrect
+        } else {
+            Applicability::MachineApplicable
+        };
+        span_lint_and_sugg(
+            cx,
+            EMPTY_STRUCTS_WITH_BRACKETS,
+            span,
+            "found empty brackets on struct declaration",
+            "remove the brackets",
+            ";".to_string(),
+            applicability,
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::utils::SpanlessEq;
-use crate::utils::{get_item_name, match_type, paths, snippet, span_lint_and_then, walk_ptrs_ty};
+use crate::utils::{match_type, paths, snippet, span_lint_and_then, walk_ptrs_ty};
 use if_chain::if_chain;
 use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
 use rustc::hir::*;
@@ -37,12 +37,46 @@ declare_clippy_lint! {
     "use of `contains_key` followed by `insert` on a `HashMap` or `BTreeMap`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for uses of `contains` + `insert` on `HashSet`
+    /// or `BTreeSet`. The same on maps is linted by `map_entry`.
+    ///
+    /// **Why is this bad?** `insert` already does nothing if the value is
+    /// present, and returns whether it was inserted. Checking first looks up
+    /// the value twice.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::collections::HashSet;
+    /// # let mut set = HashSet::new();
+    /// # let value = 1;
+    /// if !set.contains(&value) {
+    ///     set.insert(value);
+    ///     println!("inserted {}", value);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::collections::HashSet;
+    /// # let mut set = HashSet::new();
+    /// # let value = 1;
+    /// if set.insert(value) {
+    ///     println!("inserted {}", value);
+    /// }
+    /// ```
+    pub SET_CONTAINS_OR_INSERT,
+    perf,
+    "use of `contains` followed by `insert` on a `HashSet` or `BTreeSet`"
+}
+
 #[derive(Copy, Clone)]
 pub struct HashMapLint;
 
 impl LintPass for HashMapLint {
     fn get_lints(&self) -> LintArray {
-        lint_array!(MAP_ENTRY)
+        lint_array!(MAP_ENTRY, SET_CONTAINS_OR_INSERT)
     }
 
     fn name(&self) -> &'static str {
@@ -79,6 +113,10 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for HashMapLint {
                 }
             } else if let Some(ref else_block) = *else_block {
                 if let Some((ty, map, key)) = check_cond(cx, check) {
+                    // `insert` on a set can't replace a branch that does something else
+                    if is_set(ty) {
+                        return;
+                    }
                     let mut visitor = InsertVisitor {
                         cx,
                         span: expr.span,
@@ -102,21 +140,21 @@ fn check_cond<'a, 'tcx, 'b>(
     if_chain! {
         if let ExprKind::MethodCall(ref path, _, ref params) = check.node;
         if params.len() >= 2;
-        if path.ident.name == "contains_key";
+        if path.ident.name == "contains_key" || path.ident.name == "contains";
         if let ExprKind::AddrOf(_, ref key) = params[1].node;
         then {
             let map = &params[0];
             let obj_ty = walk_ptrs_ty(cx.tables.expr_ty(map));
-
-            return if match_type(cx, obj_ty, &paths::BTREEMAP) {
-                Some(("BTreeMap", map, key))
-            }
-            else if match_type(cx, obj_ty, &paths::HASHMAP) {
-                Some(("HashMap", map, key))
-            }
-            else {
-                None
+            let types = if path.ident.name == "contains_key" {
+                [(&paths::BTREEMAP[..], "BTreeMap"), (&paths::HASHMAP[..], "HashMap")]
+            } else {
+                [(&paths::BTREESET[..], "BTreeSet"), (&paths::HASHSET[..], "HashSet")]
             };
+
+            return types
+                .iter()
+                .find(|&&(ty_path, _)| match_type(cx, obj_ty, ty_path))
+                .map(|&(_, ty)| (ty, map, key));
         }
     }
 
@@ -136,39 +174,16 @@ impl<'a, 'tcx, 'b> Visitor<'tcx> for InsertVisitor<'a, 'tcx, 'b> {
     fn visit_expr(&mut self, expr: &'tcx Expr) {
         if_chain! {
             if let ExprKind::MethodCall(ref path, _, ref params) = expr.node;
-            if params.len() == 3;
+            if params.len() == if is_set(self.ty) { 2 } else { 3 };
             if path.ident.name == "insert";
-            if get_item_name(self.cx, self.map) == get_item_name(self.cx, &params[0]);
+            if SpanlessEq::new(self.cx).eq_expr(self.map, &params[0]);
             if SpanlessEq::new(self.cx).eq_expr(self.key, &params[1]);
             then {
-                span_lint_and_then(self.cx, MAP_ENTRY, self.span,
-                                   &format!("usage of `contains_key` followed by `insert` on a `{}`", self.ty), |db| {
-                    if self.sole_expr {
-                        let help = format!("{}.entry({}).or_insert({})",
-                                           snippet(self.cx, self.map.span, "map"),
-                                           snippet(self.cx, params[1].span, ".."),
-                                           snippet(self.cx, params[2].span, ".."));
-
-                        db.span_suggestion(
-                            self.span,
-                            "consider using",
-                            help,
-                            Applicability::MachineApplicable, // snippet
-                        );
-                    }
-                    else {
-                        let help = format!("{}.entry({})",
-                                           snippet(self.cx, self.map.span, "map"),
-                                           snippet(self.cx, params[1].span, ".."));
-
-                        db.span_suggestion(
-                            self.span,
-                            "consider using",
-                            help,
-                            Applicability::MachineApplicable, // snippet
-                        );
-                    }
-                });
+                if is_set(self.ty) {
+                    self.lint_set(params);
+                } else {
+                    self.lint_map(params);
+                }
             }
         }
 
@@ -180,3 +195,74 @@ impl<'a, 'tcx, 'b> Visitor<'tcx> for InsertVisitor<'a, 'tcx, 'b> {
         NestedVisitorMap::None
     }
 }
+
+impl<'a, 'tcx, 'b> InsertVisitor<'a, 'tcx, 'b> {
+    fn lint_map(&self, params: &[Expr]) {
+        span_lint_and_then(
+            self.cx,
+            MAP_ENTRY,
+            self.span,
+            &format!("usage of `contains_key` followed by `insert` on a `{}`", self.ty),
+            |db| {
+                if self.sole_expr {
+                    let help = format!(
+                        "{}.entry({}).or_insert({})",
+                        snippet(self.cx, self.map.span, "map"),
+                        snippet(self.cx, params[1].span, ".."),
+                        snippet(self.cx, params[2].span, "..")
+                    );
+
+                    db.span_suggestion(
+                        self.span,
+                        "consider using",
+                        help,
+                        Applicability::MachineApplicable, // snippet
+                    );
+                } else {
+                    let help = format!(
+                        "{}.entry({})",
+                        snippet(self.cx, self.map.span, "map"),
+                        snippet(self.cx, params[1].span, "..")
+                    );
+
+                    db.span_suggestion(
+                        self.span,
+                        "consider using",
+                        help,
+                        Applicability::MachineApplicable, // snippet
+                    );
+                }
+            },
+        );
+    }
+
+    fn lint_set(&self, params: &[Expr]) {
+        let set = snippet(self.cx, self.map.span, "set");
+        let value = snippet(self.cx, params[1].span, "..");
+        span_lint_and_then(
+            self.cx,
+            SET_CONTAINS_OR_INSERT,
+            self.span,
+            &format!("usage of `contains` followed by `insert` on a `{}`", self.ty),
+            |db| {
+                if self.sole_expr {
+                    db.span_suggestion(
+                        self.span,
+                        "`insert` does nothing if the value is present",
+                        format!("{}.insert({});", set, value),
+                        Applicability::MachineApplicable,
+                    );
+                } else {
+                    db.help(&format!(
+                        "`insert` returns whether the value was inserted, use `if {}.insert({}) {{ .. }}`",
+                        set, value
+                    ));
+                }
+            },
+        );
+    }
+}
+
+fn is_set(ty: &str) -> bool {
+    ty.ends_with("Set")
+}
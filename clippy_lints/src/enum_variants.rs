@@ -1,5 +1,8 @@
-//! lint on enum variants that are prefixed or suffixed by the same characters
+//! lint on enum variants and struct fields that are prefixed or suffixed by the same characters
 
+use crate::utils::naming::{
+    common_snake_postfix, common_snake_prefix, partial_match, partial_rmatch, to_camel_case, to_snake_case,
+};
 use crate::utils::{camel_case, in_macro};
 use crate::utils::{span_help_and_lint, span_lint};
 use rustc::lint::{EarlyContext, EarlyLintPass, Lint, LintArray, LintPass};
@@ -101,16 +104,48 @@ declare_clippy_lint! {
     "modules that have the same name as their parent module"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Detects struct fields that are prefixed or suffixed
+    /// by the same words or by the struct name.
+    ///
+    /// **Why is this bad?** Field names should specify their field, not repeat
+    /// the struct name or a word that applies to every field.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Cake {
+    ///     cake_sugar: u8,
+    ///     cake_flour: u8,
+    ///     cake_eggs: u8,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// struct Cake {
+    ///     sugar: u8,
+    ///     flour: u8,
+    ///     eggs: u8,
+    /// }
+    /// ```
+    pub STRUCT_FIELD_NAMES,
+    pedantic,
+    "structs where all fields share a prefix/postfix or contain the name of the struct"
+}
+
 pub struct EnumVariantNames {
     modules: Vec<(InternedString, String)>,
     threshold: u64,
+    struct_threshold: u64,
 }
 
 impl EnumVariantNames {
-    pub fn new(threshold: u64) -> Self {
+    pub fn new(threshold: u64, struct_threshold: u64) -> Self {
         Self {
             modules: Vec::new(),
             threshold,
+            struct_threshold,
         }
     }
 }
@@ -121,7 +156,8 @@ impl LintPass for EnumVariantNames {
             ENUM_VARIANT_NAMES,
             PUB_ENUM_VARIANT_NAMES,
             MODULE_NAME_REPETITIONS,
-            MODULE_INCEPTION
+            MODULE_INCEPTION,
+            STRUCT_FIELD_NAMES
         )
     }
 
@@ -134,24 +170,6 @@ fn var2str(var: &Variant) -> LocalInternedString {
     var.node.ident.as_str()
 }
 
-/// Returns the number of chars that match from the start
-fn partial_match(pre: &str, name: &str) -> usize {
-    let mut name_iter = name.chars();
-    let _ = name_iter.next_back(); // make sure the name is never fully matched
-    pre.chars().zip(name_iter).take_while(|&(l, r)| l == r).count()
-}
-
-/// Returns the number of chars that match from the end
-fn partial_rmatch(post: &str, name: &str) -> usize {
-    let mut name_iter = name.chars();
-    let _ = name_iter.next(); // make sure the name is never fully matched
-    post.chars()
-        .rev()
-        .zip(name_iter.rev())
-        .take_while(|&(l, r)| l == r)
-        .count()
-}
-
 fn check_variant(
     cx: &EarlyContext<'_>,
     threshold: u64,
@@ -219,26 +237,53 @@ fn check_variant(
     );
 }
 
-fn to_camel_case(item_name: &str) -> String {
-    let mut s = String::new();
-    let mut up = true;
-    for c in item_name.chars() {
-        if c.is_uppercase() {
-            // we only turn snake case text into CamelCase
-            return item_name.to_string();
-        }
-        if c == '_' {
-            up = true;
-            continue;
+fn check_fields(cx: &EarlyContext<'_>, threshold: u64, fields: &[StructField], item_name: &str, span: Span) {
+    let named = fields
+        .iter()
+        .filter_map(|field| field.ident.map(|ident| (field, ident.as_str())))
+        .filter(|(_, name)| !name.starts_with('_'))
+        .collect::<Vec<_>>();
+    let item_snake = to_snake_case(item_name);
+    for (field, name) in &named {
+        if name.starts_with(&format!("{}_", item_snake)) {
+            span_lint(
+                cx,
+                STRUCT_FIELD_NAMES,
+                field.span,
+                "field name starts with the struct's name",
+            );
         }
-        if up {
-            up = false;
-            s.extend(c.to_uppercase());
-        } else {
-            s.push(c);
+        if name.ends_with(&format!("_{}", item_snake)) {
+            span_lint(
+                cx,
+                STRUCT_FIELD_NAMES,
+                field.span,
+                "field name ends with the struct's name",
+            );
         }
     }
-    s
+    if (named.len() as u64) < threshold {
+        return;
+    }
+    let names = named.iter().map(|(_, name)| &**name).collect::<Vec<_>>();
+    let pre = common_snake_prefix(&names);
+    let post = common_snake_postfix(&names);
+    let (what, value) = match (pre.is_empty(), post.is_empty()) {
+        (true, true) => return,
+        (false, _) => ("pre", pre),
+        (true, false) => ("post", post),
+    };
+    // the struct name itself is already reported for every field
+    if value == item_snake {
+        return;
+    }
+    span_help_and_lint(
+        cx,
+        STRUCT_FIELD_NAMES,
+        span,
+        &format!("all fields have the same {}fix: `{}`", what, value),
+        &format!("remove the {}fixes", what),
+    );
 }
 
 impl EarlyLintPass for EnumVariantNames {
@@ -303,6 +348,11 @@ impl EarlyLintPass for EnumVariantNames {
             };
             check_variant(cx, self.threshold, def, &item_name, item_name_chars, item.span, lint);
         }
+        if let ItemKind::Struct(VariantData::Struct(ref fields, ..), _) = item.node {
+            if !in_macro(item.span) {
+                check_fields(cx, self.struct_threshold, fields, &item_name, item.span);
+            }
+        }
         self.modules.push((item_name.as_interned_str(), item_camel));
     }
 }
@@ -27,7 +27,8 @@ declare_clippy_lint! {
     /// xs.map(|x| foo(x))
     /// ```
     /// where `foo(_)` is a plain function that takes the exact argument type of
-    /// `x`.
+    /// `x`. Closures calling a method on their argument, e.g. `|x| x.foo()`,
+    /// are replaced by the path of the method, e.g. `Foo::foo`.
     pub REDUNDANT_CLOSURE,
     style,
     "redundant closures, i.e. `|a| foo(a)` (which can be written as just `foo`)"
@@ -77,6 +78,9 @@ fn check_closure(cx: &LateContext<'_, '_>, expr: &Expr) {
             let fn_ty = cx.tables.expr_ty(caller);
             if !type_is_unsafe_function(cx, fn_ty);
 
+            // Type parameters that only appear in the output are inferred from the closure body
+            if generics_inferred_from_inputs(cx, fn_ty);
+
             if compare_inputs(&mut iter_input_pats(decl, body), &mut args.into_iter());
 
             then {
@@ -176,10 +180,33 @@ fn get_type_name(cx: &LateContext<'_, '_>, kind: &ty::TyKind<'_>) -> String {
     match kind {
         ty::Adt(t, _) => cx.tcx.item_path_str(t.did),
         ty::Ref(_, r, _) => get_type_name(cx, &r.sty),
-        _ => kind.to_string(),
+        ty::Bool | ty::Char | ty::Int(_) | ty::Uint(_) | ty::Float(_) | ty::Str | ty::Param(_) => kind.to_string(),
+        // types that aren't paths need a qualified path, e.g. `<[u8]>::len`
+        _ => format!("<{}>", kind),
     }
 }
 
+/// Checks if all type parameters of the called function appear in its
+/// inputs. Otherwise they are inferred from how the closure is used, which
+/// can change when the function is passed directly.
+fn generics_inferred_from_inputs(cx: &LateContext<'_, '_>, fn_ty: ty::Ty<'_>) -> bool {
+    let def_id = match fn_ty.sty {
+        ty::FnDef(def_id, _) => def_id,
+        _ => return true,
+    };
+    let sig = cx.tcx.fn_sig(def_id);
+    let inputs = sig.inputs().skip_binder();
+    cx.tcx.generics_of(def_id).params.iter().all(|param| match param.kind {
+        ty::GenericParamDefKind::Type { .. } => inputs.iter().any(|input| {
+            input.walk().any(|t| match t.sty {
+                ty::Param(p) => p.idx == param.index,
+                _ => false,
+            })
+        }),
+        _ => true,
+    })
+}
+
 fn compare_inputs(closure_inputs: &mut dyn Iterator<Item = &Arg>, call_args: &mut dyn Iterator<Item = &Expr>) -> bool {
     for (closure_input, function_arg) in closure_inputs.zip(call_args) {
         if let PatKind::Binding(_, _, _, ident, _) = closure_input.pat.node {
@@ -1,4 +1,4 @@
-use crate::utils::{get_parent_expr, span_lint, span_note_and_lint};
+use crate::utils::{get_parent_expr, hir_ancestors, span_lint, span_note_and_lint};
 use if_chain::if_chain;
 use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
 use rustc::hir::*;
@@ -185,18 +185,7 @@ impl<'a, 'tcx> Visitor<'tcx> for DivergenceVisitor<'a, 'tcx> {
 ///
 /// When such a read is found, the lint is triggered.
 fn check_for_unsequenced_reads(vis: &mut ReadVisitor<'_, '_>) {
-    let map = &vis.cx.tcx.hir();
-    let mut cur_id = vis.write_expr.hir_id;
-    loop {
-        let parent_id = map.get_parent_node_by_hir_id(cur_id);
-        if parent_id == cur_id {
-            break;
-        }
-        let parent_node = match map.find_by_hir_id(parent_id) {
-            Some(parent) => parent,
-            None => break,
-        };
-
+    for (_, parent_node) in hir_ancestors(vis.cx, vis.write_expr.hir_id) {
         let stop_early = match parent_node {
             Node::Expr(expr) => check_expr(vis, expr),
             Node::Stmt(stmt) => check_stmt(vis, stmt),
@@ -210,8 +199,6 @@ fn check_for_unsequenced_reads(vis: &mut ReadVisitor<'_, '_>) {
             StopEarly::Stop => break,
             StopEarly::KeepGoing => {},
         }
-
-        cur_id = parent_id;
     }
 }
 
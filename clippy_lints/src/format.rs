@@ -1,7 +1,7 @@
 use crate::utils::paths;
 use crate::utils::{
     in_macro, is_expn_of, last_path_segment, match_def_path, match_type, opt_def_id, resolve_node, snippet,
-    span_lint_and_then, walk_ptrs_ty,
+    snippet_ref, span_lint_and_then, walk_ptrs_ty, SnippetRef,
 };
 use if_chain::if_chain;
 use rustc::hir::*;
@@ -20,7 +20,7 @@ declare_clippy_lint! {
     /// be replaced by `"foo".to_owned()` if you really need a `String`. The even
     /// worse `&format!("foo")` is often encountered in the wild. `format!("{}",
     /// foo)` can be replaced by `foo.clone()` if `foo: String` or `foo.to_owned()`
-    /// if `foo: &str`.
+    /// if `foo: &str`. `format!("")` can be replaced by `String::new()`.
     ///
     /// **Known problems:** None.
     ///
@@ -71,19 +71,16 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                         if new_v1 || check_unformatted(&args[2]);
                         if let ExprKind::AddrOf(_, ref format_arg) = format_arg.node;
                         then {
-                            let (message, sugg) = if_chain! {
+                            let arg = snippet_ref(cx, format_arg.span, "<arg>");
+                            if_chain! {
                                 if let ExprKind::MethodCall(ref path, _, _) = format_arg.node;
                                 if path.ident.as_interned_str() == "to_string";
                                 then {
-                                    ("`to_string()` is enough",
-                                    snippet(cx, format_arg.span, "<arg>").to_string())
+                                    span_useless_format(cx, span, "`to_string()` is enough", arg, false);
                                 } else {
-                                    ("consider using .to_string()",
-                                    format!("{}.to_string()", snippet(cx, format_arg.span, "<arg>")))
+                                    span_useless_format(cx, span, "consider using .to_string()", arg, true);
                                 }
-                            };
-
-                            span_useless_format(cx, span, message, sugg);
+                            }
                         }
                     }
                 },
@@ -91,8 +88,8 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                 ExprKind::Match(ref matchee, _, _) => {
                     if let ExprKind::Tup(ref tup) = matchee.node {
                         if tup.is_empty() {
-                            let sugg = format!("{}.to_string()", snippet(cx, expr.span, "<expr>").into_owned());
-                            span_useless_format(cx, span, "consider using .to_string()", sugg);
+                            let sugg = snippet_ref(cx, expr.span, "<expr>");
+                            span_useless_format(cx, span, "consider using .to_string()", sugg, true);
                         }
                     }
                 },
@@ -102,16 +99,30 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
     }
 }
 
-fn span_useless_format<'a, 'tcx: 'a, T: LintContext<'tcx>>(cx: &'a T, span: Span, help: &str, mut sugg: String) {
+/// Suggests replacing the `format!` call with `arg`, followed by
+/// `.to_string()` if `to_string` is set. An empty literal is replaced by
+/// `String::new()`.
+fn span_useless_format<'a, 'tcx: 'a, T: LintContext<'tcx>>(
+    cx: &'a T,
+    span: Span,
+    help: &str,
+    arg: SnippetRef<'a, T>,
+    to_string: bool,
+) {
     let to_replace = span.source_callsite();
 
-    // The callsite span contains the statement semicolon for some reason.
-    let snippet = snippet(cx, to_replace, "..");
-    if snippet.ends_with(';') {
-        sugg.push(';');
-    }
-
     span_lint_and_then(cx, USELESS_FORMAT, span, "useless use of `format!`", |db| {
+        let (help, mut sugg) = if to_string && arg.get() == "\"\"" {
+            ("consider using", "String::new()".to_string())
+        } else if to_string {
+            (help, format!("{}.to_string()", arg))
+        } else {
+            (help, arg.get().into_owned())
+        };
+        // The callsite span contains the statement semicolon for some reason.
+        if snippet(cx, to_replace, "..").ends_with(';') {
+            sugg.push(';');
+        }
         db.span_suggestion(
             to_replace,
             help,
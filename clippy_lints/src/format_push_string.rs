@@ -0,0 +1,112 @@
+use crate::utils::{
+    in_macro, is_direct_expn_of, match_type, paths, snippet, snippet_with_applicability, span_help_and_lint,
+    span_lint_and_sugg_with_import, walk_ptrs_ty,
+};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `format!(..)` results appended to a
+    /// `String` with `+=` or `push_str`.
+    ///
+    /// **Why is this bad?** `format!` allocates a new `String` that is
+    /// immediately dropped again. `write!` formats directly into the existing
+    /// `String`.
+    ///
+    /// **Known problems:** `write!` returns a `Result` that has to be handled
+    /// even though writing to a `String` never fails.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let mut s = String::new();
+    /// s += &format!("0x{:X}", 1024);
+    /// s.push_str(&format!("0x{:X}", 1024));
+    /// ```
+    ///
+    /// Could be written as:
+    ///
+    /// ```rust
+    /// use std::fmt::Write;
+    /// let mut s = String::new();
+    /// let _ = write!(s, "0x{:X}", 1024);
+    /// ```
+    pub FORMAT_PUSH_STRING,
+    pedantic,
+    "`format!(..)` appended to an existing `String`"
+}
+
+#[derive(Copy, Clone)]
+pub struct FormatPushString;
+
+impl LintPass for FormatPushString {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(FORMAT_PUSH_STRING)
+    }
+
+    fn name(&self) -> &'static str {
+        "FormatPushString"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for FormatPushString {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        let (string, arg) = match expr.node {
+            ExprKind::AssignOp(op, ref lhs, ref rhs) if op.node == BinOpKind::Add => (&**lhs, &**rhs),
+            ExprKind::MethodCall(ref path, _, ref args) if path.ident.name == "push_str" && args.len() == 2 => {
+                (&args[0], &args[1])
+            },
+            _ => return,
+        };
+        if_chain! {
+            if match_type(cx, walk_ptrs_ty(cx.tables.expr_ty(string)), &paths::STRING);
+            if let ExprKind::AddrOf(_, ref format) = arg.node;
+            if let Some(format_span) = is_direct_expn_of(format.span, "format");
+            then {
+                emit(cx, expr, string, format_span);
+            }
+        }
+    }
+}
+
+fn emit(cx: &LateContext<'_, '_>, expr: &Expr, string: &Expr, format_span: Span) {
+    let msg = "`format!(..)` appended to existing `String`";
+    let help = "consider using `write!` to avoid the extra allocation";
+    let is_stmt = match cx
+        .tcx
+        .hir()
+        .find_by_hir_id(cx.tcx.hir().get_parent_node_by_hir_id(expr.hir_id))
+    {
+        Some(Node::Stmt(_)) => true,
+        _ => false,
+    };
+    // `format!(<args>)` with any kind of delimiter
+    let format_snippet = snippet(cx, format_span, "");
+    let format_args = format_snippet
+        .find(|c| c == '(' || c == '[' || c == '{')
+        .map(|start| format_snippet[start + 1..format_snippet.len() - 1].trim());
+    match format_args {
+        Some(format_args) if is_stmt && !format_args.is_empty() => {
+            let mut applicability = Applicability::MaybeIncorrect;
+            let string = snippet_with_applicability(cx, string.span, "..", &mut applicability);
+            span_lint_and_sugg_with_import(
+                cx,
+                FORMAT_PUSH_STRING,
+                expr.hir_id,
+                expr.span,
+                msg,
+                help,
+                format!("let _ = write!({}, {})", string, format_args),
+                &paths::FMT_WRITE,
+                applicability,
+            );
+        },
+        _ => span_help_and_lint(cx, FORMAT_PUSH_STRING, expr.span, msg, help),
+    }
+}
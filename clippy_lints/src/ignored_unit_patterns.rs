@@ -0,0 +1,106 @@
+use crate::utils::{in_macro, span_lint_and_sugg, walk_ptrs_ty};
+use rustc::hir::intravisit::FnKind;
+use rustc::hir::{Body, FnDecl, HirId, Pat, PatKind};
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `_` patterns matching a value of type
+    /// `()`, like in `Ok(_)` for a `Result<(), E>`.
+    ///
+    /// **Why is this bad?** If the type changes, e.g. because a function
+    /// returns data it used to drop, `_` silently ignores the new value, while
+    /// `()` fails to compile and makes the author look at it.
+    ///
+    /// **Known problems:** Parameters of functions and methods are not
+    /// linted, since their patterns are often dictated by a trait.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # fn run() -> Result<(), String> { Ok(()) }
+    /// match run() {
+    ///     Ok(_) => {},
+    ///     Err(e) => eprintln!("{}", e),
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # fn run() -> Result<(), String> { Ok(()) }
+    /// match run() {
+    ///     Ok(()) => {},
+    ///     Err(e) => eprintln!("{}", e),
+    /// }
+    /// ```
+    pub IGNORED_UNIT_PATTERNS,
+    pedantic,
+    "`_` patterns matching `()`"
+}
+
+#[derive(Default)]
+pub struct IgnoredUnitPatterns {
+    /// The patterns of the parameters of functions and methods.
+    params: FxHashSet<HirId>,
+}
+
+impl LintPass for IgnoredUnitPatterns {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(IGNORED_UNIT_PATTERNS)
+    }
+
+    fn name(&self) -> &'static str {
+        "IgnoredUnitPatterns"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for IgnoredUnitPatterns {
+    fn check_fn(
+        &mut self,
+        _: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        body: &'tcx Body,
+        _: Span,
+        _: HirId,
+    ) {
+        if let FnKind::Closure(_) = kind {
+            return;
+        }
+        self.params.extend(body.arguments.iter().map(|arg| arg.pat.hir_id));
+    }
+
+    fn check_fn_post(
+        &mut self,
+        _: &LateContext<'a, 'tcx>,
+        _: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        body: &'tcx Body,
+        _: Span,
+        _: HirId,
+    ) {
+        for arg in &body.arguments {
+            self.params.remove(&arg.pat.hir_id);
+        }
+    }
+
+    fn check_pat(&mut self, cx: &LateContext<'a, 'tcx>, pat: &'tcx Pat) {
+        if let PatKind::Wild = pat.node {
+            if !in_macro(pat.span)
+                && !self.params.contains(&pat.hir_id)
+                && walk_ptrs_ty(cx.tables.pat_ty(pat)).is_unit()
+            {
+                span_lint_and_sugg(
+                    cx,
+                    IGNORED_UNIT_PATTERNS,
+                    pat.span,
+                    "matching over `()` is more explicit",
+                    "use `()` instead of `_`",
+                    "()".to_string(),
+                    Applicability::MachineApplicable,
+                );
+            }
+        }
+    }
+}
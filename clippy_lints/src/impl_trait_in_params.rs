@@ -0,0 +1,220 @@
+use crate::utils::{in_macro, snippet_opt, span_lint_and_then};
+use matches::matches;
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::*;
+use syntax::source_map::Span;
+use syntax::visit::{walk_ty, Visitor};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `impl Trait` in the parameter types of
+    /// public functions and methods.
+    ///
+    /// **Why is this bad?** Callers can't name the type of such a parameter
+    /// with a turbofish (`f::<u32>(..)`), and as long as the function has
+    /// one, not even its other type parameters. An explicit type parameter
+    /// with the same bound doesn't have these restrictions.
+    ///
+    /// **Known problems:** Bounds with elided lifetimes, like
+    /// `impl Iterator<Item = &u8>`, need a named lifetime as the bound of a
+    /// type parameter.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// pub fn greet(name: impl std::fmt::Display) {
+    ///     println!("Hello, {}!", name);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// pub fn greet<T: std::fmt::Display>(name: T) {
+    ///     println!("Hello, {}!", name);
+    /// }
+    /// ```
+    pub IMPL_TRAIT_IN_PARAMS,
+    restriction,
+    "`impl Trait` in the parameter types of public functions"
+}
+
+#[derive(Default)]
+pub struct ImplTraitInParams {
+    /// The names of the type parameters of the impls the current item is
+    /// in, which the new type parameters of its methods must not shadow.
+    impl_generics: Vec<Vec<String>>,
+}
+
+impl LintPass for ImplTraitInParams {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(IMPL_TRAIT_IN_PARAMS)
+    }
+
+    fn name(&self) -> &'static str {
+        "ImplTraitInParams"
+    }
+}
+
+impl EarlyLintPass for ImplTraitInParams {
+    fn check_item(&mut self, cx: &EarlyContext<'_>, item: &Item) {
+        match item.node {
+            ItemKind::Fn(ref decl, _, ref generics, _) => {
+                if matches!(item.vis.node, VisibilityKind::Public) && !in_macro(item.span) {
+                    check_fn(cx, item.ident, decl, generics, &[]);
+                }
+            },
+            ItemKind::Impl(_, _, _, ref generics, ..) => {
+                let names = generics
+                    .params
+                    .iter()
+                    .filter(|param| matches!(param.kind, GenericParamKind::Type { .. }))
+                    .map(|param| param.ident.to_string())
+                    .collect();
+                self.impl_generics.push(names);
+            },
+            _ => {},
+        }
+    }
+
+    fn check_item_post(&mut self, _: &EarlyContext<'_>, item: &Item) {
+        if let ItemKind::Impl(..) = item.node {
+            self.impl_generics.pop();
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &EarlyContext<'_>, item: &ImplItem) {
+        // the methods of trait implementations can't be `pub`
+        if let ImplItemKind::Method(ref sig, _) = item.node {
+            if matches!(item.vis.node, VisibilityKind::Public) && !in_macro(item.span) {
+                let impl_generics = self.impl_generics.last().map_or(&[][..], |names| &names[..]);
+                check_fn(cx, item.ident, &sig.decl, &item.generics, impl_generics);
+            }
+        }
+    }
+}
+
+fn check_fn(cx: &EarlyContext<'_>, ident: Ident, decl: &FnDecl, generics: &Generics, impl_generics: &[String]) {
+    let mut finder = ImplTraitFinder { spans: Vec::new() };
+    for arg in &decl.inputs {
+        finder.visit_ty(&arg.ty);
+    }
+    let (first, last) = match (finder.spans.first(), finder.spans.last()) {
+        (Some(&first), Some(&last)) => (first, last),
+        _ => return,
+    };
+
+    span_lint_and_then(
+        cx,
+        IMPL_TRAIT_IN_PARAMS,
+        first.to(last),
+        "`impl Trait` used as a parameter type of a public function",
+        |db| {
+            if let Some(sugg) = suggestion(cx, ident, decl, generics, impl_generics, &finder.spans) {
+                let msg = if finder.spans.len() == 1 {
+                    "add a type parameter"
+                } else {
+                    "add type parameters"
+                };
+                db.multipart_suggestion(msg, sugg, Applicability::MaybeIncorrect);
+            }
+        },
+    );
+}
+
+/// Replaces each `impl Trait` in `spans` with a new type parameter, declared
+/// with its bounds in the generics, or in the where-clause if there is one.
+/// The new names differ from `impl_generics`, the type parameters of the
+/// enclosing impl.
+fn suggestion(
+    cx: &EarlyContext<'_>,
+    ident: Ident,
+    decl: &FnDecl,
+    generics: &Generics,
+    impl_generics: &[String],
+    spans: &[Span],
+) -> Option<Vec<(Span, String)>> {
+    // new names must not be used for anything else in the signature, e.g. in a bound
+    let mut signature = ident.span.to(*spans.last()?);
+    if let FunctionRetTy::Ty(ref ty) = decl.output {
+        signature = signature.to(ty.span);
+    }
+    if !generics.where_clause.predicates.is_empty() {
+        signature = signature.to(generics.where_clause.span);
+    }
+    let signature = snippet_opt(cx, signature)?;
+    let mut names = candidate_names().filter(|name| {
+        !impl_generics.contains(name)
+            && !signature
+                .split(|c: char| !c.is_alphanumeric() && c != '_')
+                .any(|word| word == name)
+    });
+
+    let mut sugg = Vec::new();
+    let mut params = Vec::new();
+    let mut predicates = Vec::new();
+    for &span in spans {
+        let name = names.next()?;
+        let bounds = snippet_opt(cx, span)?["impl".len()..].trim_start().to_string();
+        sugg.push((span, name.clone()));
+        if generics.where_clause.predicates.is_empty() {
+            params.push(format!("{}: {}", name, bounds));
+        } else {
+            params.push(name.clone());
+            predicates.push(format!("{}: {}", name, bounds));
+        }
+    }
+
+    if generics.params.is_empty() {
+        sugg.push((ident.span.shrink_to_hi(), format!("<{}>", params.join(", "))));
+    } else {
+        let existing = snippet_opt(cx, generics.span)?;
+        let existing = existing[..existing.len() - 1].trim_end().trim_end_matches(',');
+        sugg.push((generics.span, format!("{}, {}>", existing, params.join(", "))));
+    }
+
+    if !predicates.is_empty() {
+        let span = generics.where_clause.span;
+        let existing = snippet_opt(cx, span)?;
+        let clause = match existing.rfind('\n') {
+            // one predicate per line, as formatted by rustfmt
+            Some(line_start) => {
+                let last_line = &existing[line_start + 1..];
+                let indent = &last_line[..last_line.len() - last_line.trim_start().len()];
+                let mut clause = existing.trim_end_matches(',').to_string();
+                for predicate in predicates {
+                    clause.push_str(&format!(",\n{}{}", indent, predicate));
+                }
+                clause.push(',');
+                clause
+            },
+            None => format!("{}, {}", existing.trim_end_matches(','), predicates.join(", ")),
+        };
+        sugg.push((span, clause));
+    }
+
+    sugg.sort_by_key(|&(span, _)| span.lo());
+    Some(sugg)
+}
+
+/// `T`, `U`, `V`, `W`, then `T1`, `T2`, ...
+fn candidate_names() -> impl Iterator<Item = String> {
+    ["T", "U", "V", "W"]
+        .iter()
+        .map(|&name| name.to_string())
+        .chain((1..).map(|n| format!("T{}", n)))
+}
+
+/// Collects the spans of the `impl Trait` types in a type, not looking into
+/// their bounds.
+struct ImplTraitFinder {
+    spans: Vec<Span>,
+}
+
+impl<'ast> Visitor<'ast> for ImplTraitFinder {
+    fn visit_ty(&mut self, ty: &'ast Ty) {
+        if let TyKind::ImplTrait(..) = ty.node {
+            self.spans.push(ty.span);
+        } else {
+            walk_ty(self, ty);
+        }
+    }
+}
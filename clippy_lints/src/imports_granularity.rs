@@ -0,0 +1,219 @@
+use crate::utils::use_tree::{self, Import, Leaf};
+use crate::utils::{in_macro, snippet_opt, span_lint_and_then};
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_errors::Applicability;
+use syntax::ast;
+use syntax::print::pprust::vis_to_string;
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks that the `use` items of a module follow the
+    /// import style set with the `imports-granularity` option:
+    ///
+    /// * `crate` (the default): one `use` item per crate, like
+    ///   `use std::{fmt, io::{Read, Write}};`
+    /// * `module`: one `use` item per module, like `use std::io::{Read, Write};`
+    /// * `item`: one `use` item per imported item, like `use std::io::Read;`
+    ///
+    /// Only `use` items with the same visibility are merged, and ones with
+    /// attributes are left alone.
+    ///
+    /// **Why is this bad?** A consistent import style makes imports easier
+    /// to find, and keeps merge conflicts in them small.
+    ///
+    /// **Known problems:** The suggestion puts the rewritten imports in the
+    /// place of the first `use` item that has to change.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use std::fmt;
+    /// use std::io::{Read, Write};
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::{fmt, io::{Read, Write}};
+    /// ```
+    pub IMPORTS_GRANULARITY,
+    restriction,
+    "`use` items that aren't merged or split as configured"
+}
+
+/// The values of the `imports-granularity` option.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Granularity {
+    Crate,
+    Module,
+    Item,
+}
+
+impl Granularity {
+    pub fn parse(granularity: &str) -> Result<Self, String> {
+        match granularity {
+            "crate" => Ok(Granularity::Crate),
+            "module" => Ok(Granularity::Module),
+            "item" => Ok(Granularity::Item),
+            _ => Err(format!(
+                "`{}` is not a valid imports granularity, expected `crate`, `module` or `item`",
+                granularity
+            )),
+        }
+    }
+
+    /// The part of the path that imports in the same `use` item share.
+    fn key<'i>(self, import: &'i Import) -> (&'i [String], Option<&'i Leaf>) {
+        match self {
+            Granularity::Crate => (&import.segments[..1], None),
+            // crates imported by their name are kept apart
+            Granularity::Module if import.module().is_empty() => (&import.segments, None),
+            Granularity::Module => (import.module(), None),
+            Granularity::Item => (&import.segments, Some(&import.leaf)),
+        }
+    }
+
+    fn message(self) -> &'static str {
+        match self {
+            Granularity::Crate => "imports from the same crate are not merged into one `use` item",
+            Granularity::Module => "imports are not grouped by module",
+            Granularity::Item => "`use` items import more than one item",
+        }
+    }
+}
+
+pub struct ImportsGranularity {
+    granularity: Granularity,
+}
+
+impl ImportsGranularity {
+    pub fn new(granularity: Granularity) -> Self {
+        Self { granularity }
+    }
+}
+
+impl LintPass for ImportsGranularity {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(IMPORTS_GRANULARITY)
+    }
+
+    fn name(&self) -> &'static str {
+        "ImportsGranularity"
+    }
+}
+
+/// A `use` item with its visibility and imports.
+struct UseItem<'a> {
+    item: &'a ast::Item,
+    vis: String,
+    imports: Vec<Import>,
+}
+
+impl EarlyLintPass for ImportsGranularity {
+    fn check_mod(&mut self, cx: &EarlyContext<'_>, module: &ast::Mod, _: Span, _: ast::NodeId) {
+        let uses: Vec<UseItem<'_>> = module
+            .items
+            .iter()
+            .filter(|item| !in_macro(item.span) && item.attrs.is_empty())
+            .filter_map(|item| match item.node {
+                ast::ItemKind::Use(ref tree) => Some(UseItem {
+                    item,
+                    vis: vis_to_string(&item.vis),
+                    imports: use_tree::flatten(tree),
+                }),
+                _ => None,
+            })
+            .filter(|use_item| !use_item.imports.is_empty())
+            .collect();
+
+        let granularity = self.granularity;
+        let mut items_per_key = FxHashMap::default();
+        for use_item in &uses {
+            let mut keys: Vec<_> = use_item
+                .imports
+                .iter()
+                .map(|import| key(granularity, use_item, import))
+                .collect();
+            keys.dedup();
+            for key in keys {
+                *items_per_key.entry(key).or_insert(0) += 1;
+            }
+        }
+        // a `use` item is fine if its imports belong together, and no other
+        // `use` item has imports that belong with them
+        let wrong: Vec<&UseItem<'_>> = uses
+            .iter()
+            .filter(|use_item| {
+                let first = key(granularity, use_item, &use_item.imports[0]);
+                items_per_key[&first] > 1
+                    || use_item.imports[1..]
+                        .iter()
+                        .any(|import| key(granularity, use_item, import) != first)
+            })
+            .collect();
+        let first = match wrong.first() {
+            Some(first) => first.item,
+            None => return,
+        };
+
+        // group the imports in the order they are written
+        let mut groups: Vec<(Key<'_>, &str, Vec<Import>)> = Vec::new();
+        for use_item in &wrong {
+            for import in &use_item.imports {
+                let import_key = key(granularity, use_item, import);
+                match groups.iter_mut().find(|(group_key, ..)| *group_key == import_key) {
+                    Some((.., imports)) => imports.push(import.clone()),
+                    None => groups.push((import_key, &use_item.vis, vec![import.clone()])),
+                }
+            }
+        }
+        let indent = " ".repeat(cx.sess().source_map().lookup_char_pos(first.span.lo()).col.0);
+        let rewritten = groups
+            .iter()
+            .flat_map(|(_, vis, imports)| {
+                use_tree::build(imports)
+                    .into_iter()
+                    .map(move |tree| format!("{}use {};", vis, tree))
+            })
+            .collect::<Vec<_>>()
+            .join(&format!("\n{}", indent));
+
+        let mut sugg = vec![(first.span, rewritten)];
+        sugg.extend(
+            wrong[1..]
+                .iter()
+                .map(|use_item| (removal_span(cx, module, use_item.item), String::new())),
+        );
+        span_lint_and_then(cx, IMPORTS_GRANULARITY, first.span, granularity.message(), |db| {
+            db.multipart_suggestion("rewrite the imports", sugg, Applicability::MachineApplicable);
+        });
+    }
+}
+
+/// Imports with the same key belong in the same `use` item.
+type Key<'i> = (&'i str, &'i [String], Option<&'i Leaf>);
+
+fn key<'i>(granularity: Granularity, use_item: &'i UseItem<'_>, import: &'i Import) -> Key<'i> {
+    let (path, leaf) = granularity.key(import);
+    (&use_item.vis, path, leaf)
+}
+
+/// The span of `item` with the line break and indentation before it, if
+/// there's nothing else after the previous item.
+fn removal_span(cx: &EarlyContext<'_>, module: &ast::Mod, item: &ast::Item) -> Span {
+    let prev = module
+        .items
+        .iter()
+        .filter(|prev| !in_macro(prev.span) && prev.span.hi() <= item.span.lo())
+        .map(|prev| prev.span.hi())
+        .max();
+    match prev {
+        Some(prev) => {
+            let span = item.span.with_lo(prev);
+            match snippet_opt(cx, span.until(item.span)) {
+                Some(ref between) if between.trim().is_empty() => span,
+                _ => item.span,
+            }
+        },
+        None => item.span,
+    }
+}
@@ -0,0 +1,103 @@
+use crate::utils::stability::StabilityTable;
+use crate::utils::{resolve_node, span_lint};
+use rustc::hir::def_id::{DefId, LOCAL_CRATE};
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use semver::Version;
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for uses of items of the standard library
+    /// that were stabilized after the Rust version set with the `msrv`
+    /// option. Does nothing if no MSRV is set.
+    ///
+    /// **Why is this bad?** The crate doesn't compile with the Rust versions
+    /// it claims to support.
+    ///
+    /// **Known problems:** Only calls and paths are checked, trait impls and
+    /// macros are not. Code that is only compiled on newer versions, e.g.
+    /// behind a `cfg` set by a build script, has to allow the lint.
+    ///
+    /// **Example:**
+    /// ```toml
+    /// # clippy.toml
+    /// msrv = "1.40"
+    /// ```
+    /// ```rust
+    /// let x: Option<u32> = None;
+    /// // `Option::zip` is stable since Rust 1.46
+    /// let _ = x.zip(Some(1));
+    /// ```
+    pub INCOMPATIBLE_MSRV,
+    correctness,
+    "uses of items stabilized after the configured minimum supported Rust version"
+}
+
+pub struct IncompatibleMsrv {
+    msrv: Option<Version>,
+    stability: StabilityTable,
+}
+
+impl IncompatibleMsrv {
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self {
+            msrv,
+            stability: StabilityTable::default(),
+        }
+    }
+
+    fn check(&mut self, cx: &LateContext<'_, '_>, def_id: DefId, span: Span) {
+        if def_id.krate == LOCAL_CRATE || in_external_macro(cx.tcx.sess, span) {
+            return;
+        }
+        let msrv = match self.msrv {
+            Some(ref msrv) => msrv,
+            None => return,
+        };
+        if let Some(since) = self.stability.stable_since(cx.tcx, def_id) {
+            if since > msrv {
+                span_lint(
+                    cx,
+                    INCOMPATIBLE_MSRV,
+                    span,
+                    &format!(
+                        "current MSRV (Minimum Supported Rust Version) is `{}` but this item is stable since `{}`",
+                        msrv, since
+                    ),
+                );
+            }
+        }
+    }
+}
+
+impl LintPass for IncompatibleMsrv {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(INCOMPATIBLE_MSRV)
+    }
+
+    fn name(&self) -> &'static str {
+        "IncompatibleMsrv"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for IncompatibleMsrv {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if self.msrv.is_none() {
+            return;
+        }
+        match expr.node {
+            ExprKind::MethodCall(ref path, ..) => {
+                if let Some(def) = cx.tables.type_dependent_defs().get(expr.hir_id) {
+                    self.check(cx, def.def_id(), path.ident.span);
+                }
+            },
+            ExprKind::Path(ref qpath) => {
+                if let Some(def_id) = resolve_node(cx, qpath, expr.hir_id).opt_def_id() {
+                    self.check(cx, def_id, expr.span);
+                }
+            },
+            _ => {},
+        }
+    }
+}
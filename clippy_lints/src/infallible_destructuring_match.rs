@@ -1,4 +1,4 @@
-use super::utils::{get_arg_name, match_var, remove_blocks, snippet_with_applicability, span_lint_and_sugg};
+use super::utils::{get_arg_name, match_var, remove_blocks, snippet_with_applicability, span_lint_and_sugg_lazy};
 use if_chain::if_chain;
 use rustc::hir::*;
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
@@ -66,21 +66,20 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
             if match_var(body, arg);
 
             then {
-                let mut applicability = Applicability::MachineApplicable;
-                span_lint_and_sugg(
+                span_lint_and_sugg_lazy(
                     cx,
                     INFALLIBLE_DESTRUCTURING_MATCH,
                     local.span,
                     "you seem to be trying to use match to destructure a single infallible pattern. \
                      Consider using `let`",
                     "try this",
-                    format!(
+                    Applicability::MachineApplicable,
+                    |applicability| format!(
                         "let {}({}) = {};",
-                        snippet_with_applicability(cx, variant_name.span, "..", &mut applicability),
-                        snippet_with_applicability(cx, local.pat.span, "..", &mut applicability),
-                        snippet_with_applicability(cx, target.span, "..", &mut applicability),
+                        snippet_with_applicability(cx, variant_name.span, "..", applicability),
+                        snippet_with_applicability(cx, local.pat.span, "..", applicability),
+                        snippet_with_applicability(cx, target.span, "..", applicability),
                     ),
-                    applicability,
                 );
             }
         }
@@ -1,11 +1,22 @@
-use crate::utils::{get_trait_def_id, higher, implements_trait, match_qpath, match_type, paths, span_lint};
+use crate::utils::attrs::get_attr;
+use crate::utils::{
+    def_path_def_ids, get_trait_def_id, higher, implements_trait, match_qpath, match_type, paths, span_lint,
+};
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
 use rustc::hir::*;
-use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintContext, LintPass};
 use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashSet;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for iteration that is guaranteed to be infinite.
     ///
+    /// Besides the infinite iterators of `std`, the functions and methods
+    /// marked with `#[clippy::infinite]` or listed in the `infinite-iterators`
+    /// option are known to return infinite iterators, e.g.
+    /// `infinite-iterators = ["my_crate::gen::naturals"]`.
+    ///
     /// **Why is this bad?** While there may be places where this is acceptable
     /// (e.g. in event streams), in most cases this is simply an error.
     ///
@@ -40,8 +51,23 @@ declare_clippy_lint! {
     "possible infinite iteration"
 }
 
-#[derive(Copy, Clone)]
-pub struct Pass;
+pub struct Pass {
+    /// The paths of the functions and methods from the `infinite-iterators`
+    /// option.
+    infinite_iterators: Vec<String>,
+    /// The functions and methods of `infinite_iterators`, resolved once per
+    /// crate.
+    infinite_fns: FxHashSet<DefId>,
+}
+
+impl Pass {
+    pub fn new(infinite_iterators: &[String]) -> Self {
+        Self {
+            infinite_iterators: infinite_iterators.to_vec(),
+            infinite_fns: FxHashSet::default(),
+        }
+    }
+}
 
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
@@ -54,8 +80,15 @@ impl LintPass for Pass {
 }
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_crate(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        for path in &self.infinite_iterators {
+            let path: Vec<&str> = path.split("::").collect();
+            self.infinite_fns.extend(def_path_def_ids(cx, &path));
+        }
+    }
+
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
-        let (lint, msg) = match complete_infinite_iter(cx, expr) {
+        let (lint, msg) = match complete_infinite_iter(cx, &self.infinite_fns, expr) {
             Infinite => (INFINITE_ITER, "infinite iteration detected"),
             MaybeInfinite => (MAYBE_INFINITE_ITER, "possible infinite iteration detected"),
             Finite => {
@@ -146,16 +179,16 @@ static HEURISTICS: &[(&str, usize, Heuristic, Finiteness)] = &[
     ("scan", 3, First, MaybeInfinite),
 ];
 
-fn is_infinite(cx: &LateContext<'_, '_>, expr: &Expr) -> Finiteness {
+fn is_infinite(cx: &LateContext<'_, '_>, infinite_fns: &FxHashSet<DefId>, expr: &Expr) -> Finiteness {
     match expr.node {
         ExprKind::MethodCall(ref method, _, ref args) => {
             for &(name, len, heuristic, cap) in HEURISTICS.iter() {
                 if method.ident.name == name && args.len() == len {
                     return (match heuristic {
                         Always => Infinite,
-                        First => is_infinite(cx, &args[0]),
-                        Any => is_infinite(cx, &args[0]).or(is_infinite(cx, &args[1])),
-                        All => is_infinite(cx, &args[0]).and(is_infinite(cx, &args[1])),
+                        First => is_infinite(cx, infinite_fns, &args[0]),
+                        Any => is_infinite(cx, infinite_fns, &args[0]).or(is_infinite(cx, infinite_fns, &args[1])),
+                        All => is_infinite(cx, infinite_fns, &args[0]).and(is_infinite(cx, infinite_fns, &args[1])),
                     })
                     .and(cap);
                 }
@@ -163,16 +196,25 @@ fn is_infinite(cx: &LateContext<'_, '_>, expr: &Expr) -> Finiteness {
             if method.ident.name == "flat_map" && args.len() == 2 {
                 if let ExprKind::Closure(_, _, body_id, _, _) = args[1].node {
                     let body = cx.tcx.hir().body(body_id);
-                    return is_infinite(cx, &body.value);
+                    return is_infinite(cx, infinite_fns, &body.value);
                 }
             }
-            Finite
+            cx.tables
+                .type_dependent_defs()
+                .get(expr.hir_id)
+                .map_or(false, |def| is_infinite_fn(cx, infinite_fns, def.def_id()))
+                .into()
         },
-        ExprKind::Block(ref block, _) => block.expr.as_ref().map_or(Finite, |e| is_infinite(cx, e)),
-        ExprKind::Box(ref e) | ExprKind::AddrOf(_, ref e) => is_infinite(cx, e),
+        ExprKind::Block(ref block, _) => block.expr.as_ref().map_or(Finite, |e| is_infinite(cx, infinite_fns, e)),
+        ExprKind::Box(ref e) | ExprKind::AddrOf(_, ref e) => is_infinite(cx, infinite_fns, e),
         ExprKind::Call(ref path, _) => {
             if let ExprKind::Path(ref qpath) = path.node {
-                match_qpath(qpath, &paths::REPEAT).into()
+                (match_qpath(qpath, &paths::REPEAT)
+                    || match cx.tables.qpath_def(qpath, path.hir_id) {
+                        Def::Fn(def_id) | Def::Method(def_id) => is_infinite_fn(cx, infinite_fns, def_id),
+                        _ => false,
+                    })
+                .into()
             } else {
                 Finite
             }
@@ -182,6 +224,15 @@ fn is_infinite(cx: &LateContext<'_, '_>, expr: &Expr) -> Finiteness {
     }
 }
 
+/// Checks whether the function or method `def_id` is marked with
+/// `#[clippy::infinite]` or listed in the `infinite-iterators` option.
+fn is_infinite_fn(cx: &LateContext<'_, '_>, infinite_fns: &FxHashSet<DefId>, def_id: DefId) -> bool {
+    infinite_fns.contains(&def_id)
+        || get_attr(cx.sess(), &cx.tcx.get_attrs(def_id), "infinite")
+            .next()
+            .is_some()
+}
+
 /// the names and argument lengths of methods that *may* exhaust their
 /// iterators
 static POSSIBLY_COMPLETING_METHODS: &[(&str, usize)] = &[
@@ -222,35 +273,37 @@ static INFINITE_COLLECTORS: &[&[&str]] = &[
     &paths::VEC_DEQUE,
 ];
 
-fn complete_infinite_iter(cx: &LateContext<'_, '_>, expr: &Expr) -> Finiteness {
+fn complete_infinite_iter(cx: &LateContext<'_, '_>, infinite_fns: &FxHashSet<DefId>, expr: &Expr) -> Finiteness {
     match expr.node {
         ExprKind::MethodCall(ref method, _, ref args) => {
             for &(name, len) in COMPLETING_METHODS.iter() {
                 if method.ident.name == name && args.len() == len {
-                    return is_infinite(cx, &args[0]);
+                    return is_infinite(cx, infinite_fns, &args[0]);
                 }
             }
             for &(name, len) in POSSIBLY_COMPLETING_METHODS.iter() {
                 if method.ident.name == name && args.len() == len {
-                    return MaybeInfinite.and(is_infinite(cx, &args[0]));
+                    return MaybeInfinite.and(is_infinite(cx, infinite_fns, &args[0]));
                 }
             }
             if method.ident.name == "last" && args.len() == 1 {
                 let not_double_ended = get_trait_def_id(cx, &paths::DOUBLE_ENDED_ITERATOR)
                     .map_or(false, |id| !implements_trait(cx, cx.tables.expr_ty(&args[0]), id, &[]));
                 if not_double_ended {
-                    return is_infinite(cx, &args[0]);
+                    return is_infinite(cx, infinite_fns, &args[0]);
                 }
             } else if method.ident.name == "collect" {
                 let ty = cx.tables.expr_ty(expr);
                 if INFINITE_COLLECTORS.iter().any(|path| match_type(cx, ty, path)) {
-                    return is_infinite(cx, &args[0]);
+                    return is_infinite(cx, infinite_fns, &args[0]);
                 }
             }
         },
         ExprKind::Binary(op, ref l, ref r) => {
             if op.node.is_comparison() {
-                return is_infinite(cx, l).and(is_infinite(cx, r)).and(MaybeInfinite);
+                return is_infinite(cx, infinite_fns, l)
+                    .and(is_infinite(cx, infinite_fns, r))
+                    .and(MaybeInfinite);
             }
         }, // TODO: ExprKind::Loop + Match
         _ => (),
@@ -0,0 +1,121 @@
+use crate::utils::{in_macro, last_line_of_span, snippet_opt, span_lint_and_then};
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast;
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for items declared after a `#[cfg(test)]`
+    /// module.
+    ///
+    /// **Why is this bad?** The tests of a module are expected at its end.
+    /// Items after them are easily overlooked, or mistaken for test helpers.
+    ///
+    /// **Known problems:** Test modules are removed by the configuration when
+    /// not compiling tests, so this is only checked with `cargo clippy --tests`.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// fn main() {}
+    ///
+    /// #[cfg(test)]
+    /// mod tests {
+    ///     // [...]
+    /// }
+    ///
+    /// fn helper() {}
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// fn main() {}
+    ///
+    /// fn helper() {}
+    ///
+    /// #[cfg(test)]
+    /// mod tests {
+    ///     // [...]
+    /// }
+    /// ```
+    pub ITEMS_AFTER_TEST_MODULE,
+    style,
+    "items declared after the test module of a module"
+}
+
+#[derive(Copy, Clone)]
+pub struct ItemsAfterTestModule;
+
+impl LintPass for ItemsAfterTestModule {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(ITEMS_AFTER_TEST_MODULE)
+    }
+
+    fn name(&self) -> &'static str {
+        "ItemsAfterTestModule"
+    }
+}
+
+impl EarlyLintPass for ItemsAfterTestModule {
+    fn check_mod(&mut self, cx: &EarlyContext<'_>, module: &ast::Mod, _: Span, _: ast::NodeId) {
+        // items generated by macros, like the test harness, don't have a place in the file
+        let mut items = module.items.iter().filter(|item| !in_macro(item.span));
+        let test_mod = match items.by_ref().find(|item| is_test_module(item)) {
+            Some(test_mod) => test_mod,
+            None => return,
+        };
+        let after: Vec<_> = items.collect();
+        let last = match after.last() {
+            Some(last) => last,
+            // further test items may follow the test module
+            None => return,
+        };
+        if after.iter().any(|item| is_cfg_test(&item.attrs)) {
+            return;
+        }
+        let test_mod_span = with_outer_attrs(test_mod);
+        let span = with_outer_attrs(after[0]).with_hi(last.span.hi());
+        span_lint_and_then(cx, ITEMS_AFTER_TEST_MODULE, span, "items after a test module", |db| {
+            db.span_note(test_mod.ident.span, "the test module");
+            // comments between the test module and the items move with the items
+            let moved = snippet_opt(cx, test_mod_span.between(last.span.shrink_to_hi()));
+            let indent = snippet_opt(cx, last_line_of_span(cx, test_mod_span.shrink_to_lo()));
+            if let (Some(moved), Some(indent), Some(test_mod)) = (moved, indent, snippet_opt(cx, test_mod_span)) {
+                if !indent.trim().is_empty() {
+                    return;
+                }
+                db.span_suggestion(
+                    test_mod_span.to(last.span),
+                    "move the items to before the test module",
+                    format!("{}\n\n{}{}", moved.trim_start(), indent, test_mod),
+                    Applicability::MachineApplicable,
+                );
+            }
+        });
+    }
+}
+
+/// Whether `item` is a `#[cfg(test)]` module.
+fn is_test_module(item: &ast::Item) -> bool {
+    match item.node {
+        ast::ItemKind::Mod(..) => is_cfg_test(&item.attrs),
+        _ => false,
+    }
+}
+
+/// Whether the attributes contain `#[cfg(test)]`.
+fn is_cfg_test(attrs: &[ast::Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.check_name("cfg")
+            && attr
+                .meta_item_list()
+                .map_or(false, |list| list.len() == 1 && list[0].check_name("test"))
+    })
+}
+
+/// The span of `item` including its outer attributes and doc comments.
+fn with_outer_attrs(item: &ast::Item) -> Span {
+    item.attrs
+        .iter()
+        .filter(|attr| attr.style == ast::AttrStyle::Outer && attr.span.lo() < item.span.lo())
+        .fold(item.span, |span, attr| span.with_lo(span.lo().min(attr.span.lo())))
+}
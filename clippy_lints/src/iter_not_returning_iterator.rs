@@ -0,0 +1,78 @@
+use crate::utils::{get_trait_def_id, implements_trait, in_macro, paths, return_ty, span_lint};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for inherent methods named `iter` or
+    /// `iter_mut` whose return type does not implement `Iterator`, and
+    /// methods named `into_iter` whose return type does not implement
+    /// `IntoIterator`.
+    ///
+    /// **Why is this bad?** Methods with these names are expected to return
+    /// iterators by convention, as on all standard collections. Users will be
+    /// surprised when the result cannot be used in a `for` loop.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Data {}
+    /// impl Data {
+    ///     fn iter(&self) -> u32 {
+    ///         unimplemented!()
+    ///     }
+    /// }
+    /// ```
+    pub ITER_NOT_RETURNING_ITERATOR,
+    pedantic,
+    "methods named `iter` or `iter_mut` that do not return an `Iterator`"
+}
+
+#[derive(Copy, Clone)]
+pub struct IterNotReturningIterator;
+
+impl LintPass for IterNotReturningIterator {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(ITER_NOT_RETURNING_ITERATOR)
+    }
+
+    fn name(&self) -> &'static str {
+        "IterNotReturningIterator"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for IterNotReturningIterator {
+    fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, impl_item: &'tcx ImplItem) {
+        let name = impl_item.ident.name;
+        let (trait_path, trait_name) = if name == "iter" || name == "iter_mut" {
+            (&paths::ITERATOR[..], "Iterator")
+        } else if name == "into_iter" {
+            (&paths::INTO_ITERATOR[..], "IntoIterator")
+        } else {
+            return;
+        };
+        let parent = cx.tcx.hir().get_parent_item(impl_item.hir_id);
+        if_chain! {
+            if let ImplItemKind::Method(ref sig, _) = impl_item.node;
+            if !in_macro(impl_item.span);
+            if let ItemKind::Impl(_, _, _, _, None, _, _) = cx.tcx.hir().expect_item_by_hir_id(parent).node;
+            if let Some(trait_id) = get_trait_def_id(cx, trait_path);
+            // `return_ty` sees through type aliases, and `impl Trait` return
+            // types implement the traits from their bounds
+            if !implements_trait(cx, return_ty(cx, impl_item.hir_id), trait_id, &[]);
+            then {
+                span_lint(
+                    cx,
+                    ITER_NOT_RETURNING_ITERATOR,
+                    sig.decl.output.span(),
+                    &format!(
+                        "this method is named `{}` but its return type does not implement `{}`",
+                        name, trait_name
+                    ),
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use crate::utils::{
+    higher, hir_ancestors, in_macro, is_expn_of, match_type, paths, span_lint, span_lint_and_then, walk_ptrs_ty,
+};
+use matches::matches;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty::Ty;
+use rustc::{declare_tool_lint, lint_array};
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for iteration over a `HashMap` or `HashSet`
+    /// whose order becomes observable, because the items are written to the
+    /// output or collected into an ordered container like a `Vec`.
+    ///
+    /// With the `iter-over-hash-type-all` option, all iteration over these
+    /// types is linted, for projects that need deterministic builds.
+    ///
+    /// **Why is this bad?** The iteration order of hash-based collections
+    /// depends on the hasher, which is randomly seeded by default, so it
+    /// changes from one run to the next.
+    ///
+    /// **Known problems:** Only sinks in the loop body or at the end of the
+    /// iterator chain are found. Results that are sorted afterwards are
+    /// linted all the same.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::collections::HashSet;
+    /// # let set = HashSet::<u32>::new();
+    /// for x in &set {
+    ///     println!("{}", x);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::collections::BTreeSet;
+    /// # let set = BTreeSet::<u32>::new();
+    /// for x in &set {
+    ///     println!("{}", x);
+    /// }
+    /// ```
+    pub ITER_OVER_HASH_TYPE,
+    restriction,
+    "iterating over an unordered hash-based collection where the order is observable"
+}
+
+pub struct IterOverHashType {
+    all: bool,
+}
+
+impl IterOverHashType {
+    pub fn new(all: bool) -> Self {
+        Self { all }
+    }
+}
+
+impl LintPass for IterOverHashType {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(ITER_OVER_HASH_TYPE)
+    }
+
+    fn name(&self) -> &'static str {
+        "IterOverHashType"
+    }
+}
+
+const ITER_METHODS: [&str; 7] = ["iter", "iter_mut", "keys", "values", "values_mut", "into_iter", "drain"];
+
+/// Macros that write their arguments in order, the ones expanding to others
+/// first.
+const OUTPUT_MACROS: [&str; 7] = ["println", "eprintln", "writeln", "print", "eprint", "write", "format"];
+
+/// Methods of ordered containers that add items to them.
+const PUSH_METHODS: [&str; 6] = ["push", "push_back", "push_front", "push_str", "insert", "extend"];
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for IterOverHashType {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        if let Some((_, arg, body)) = higher::for_loop(expr) {
+            if iterates_hash_type(cx, chain_start(cx, arg)) {
+                self.lint(cx, arg.span, find_sink(cx, body));
+            }
+        } else if is_hash_iter_call(cx, expr) {
+            let end = chain_end(cx, expr);
+            // the loop is checked by itself
+            if !is_for_loop_arg(cx, end) {
+                self.lint(cx, expr.span, consumer_sink(cx, end));
+            }
+        }
+    }
+}
+
+impl IterOverHashType {
+    fn lint(&self, cx: &LateContext<'_, '_>, span: Span, sink: Option<(Span, &str)>) {
+        let msg = "iteration over an unordered hash-based type";
+        match sink {
+            Some((sink_span, note)) => span_lint_and_then(cx, ITER_OVER_HASH_TYPE, span, msg, |db| {
+                db.span_note(sink_span, &format!("the iteration order becomes observable: {}", note));
+            }),
+            None if self.all => span_lint(cx, ITER_OVER_HASH_TYPE, span, msg),
+            None => {},
+        }
+    }
+}
+
+fn is_hash_type(cx: &LateContext<'_, '_>, ty: Ty<'_>) -> bool {
+    let ty = walk_ptrs_ty(ty);
+    match_type(cx, ty, &paths::HASHMAP) || match_type(cx, ty, &paths::HASHSET)
+}
+
+/// `map.iter()`, `map.keys()` and the like.
+fn is_hash_iter_call(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::MethodCall(ref path, _, ref args) => {
+            ITER_METHODS.iter().any(|name| path.ident.name == *name)
+                && is_hash_type(cx, cx.tables.expr_ty_adjusted(&args[0]))
+        },
+        _ => false,
+    }
+}
+
+/// Whether `expr` is a hash-based collection or an iterator call on one.
+fn iterates_hash_type(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    is_hash_iter_call(cx, expr) || is_hash_type(cx, cx.tables.expr_ty(expr))
+}
+
+/// The receiver at the start of a method chain like `map.keys().filter(..)`,
+/// stopping at the call iterating a hash-based collection.
+fn chain_start<'e>(cx: &LateContext<'_, '_>, mut expr: &'e Expr) -> &'e Expr {
+    while let ExprKind::MethodCall(_, _, ref args) = expr.node {
+        if is_hash_iter_call(cx, expr) {
+            break;
+        }
+        expr = &args[0];
+    }
+    expr
+}
+
+/// The last method call of the chain starting at `expr`, which consumes the
+/// iterator.
+fn chain_end<'tcx>(cx: &LateContext<'_, 'tcx>, expr: &'tcx Expr) -> &'tcx Expr {
+    let mut end = expr;
+    for (_, node) in hir_ancestors(cx, expr.hir_id) {
+        match node {
+            Node::Expr(parent) => match parent.node {
+                ExprKind::MethodCall(_, _, ref args) if args[0].hir_id == end.hir_id => end = parent,
+                _ => break,
+            },
+            _ => break,
+        }
+    }
+    end
+}
+
+/// Whether `expr` is the iterator of a `for` loop.
+fn is_for_loop_arg(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    let mut ancestors = hir_ancestors(cx, expr.hir_id);
+    match (ancestors.next(), ancestors.next()) {
+        (Some((_, Node::Expr(call))), Some((_, Node::Expr(for_loop)))) => {
+            matches!(call.node, ExprKind::Call(..))
+                && higher::for_loop(for_loop).map_or(false, |(_, arg, _)| arg.hir_id == expr.hir_id)
+        },
+        _ => false,
+    }
+}
+
+/// Where the order becomes observable in the call consuming the iterator.
+fn consumer_sink<'tcx>(cx: &LateContext<'_, 'tcx>, consumer: &'tcx Expr) -> Option<(Span, &'static str)> {
+    match consumer.node {
+        ExprKind::MethodCall(ref path, ..) if path.ident.name == "collect" => {
+            if is_ordered_container(cx, cx.tables.expr_ty(consumer)) {
+                Some((consumer.span, "collected into an ordered container here"))
+            } else {
+                None
+            }
+        },
+        ExprKind::MethodCall(ref path, _, ref args) if path.ident.name == "for_each" && args.len() == 2 => {
+            match args[1].node {
+                ExprKind::Closure(_, _, body_id, ..) => find_sink(cx, &cx.tcx.hir().body(body_id).value),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+fn is_ordered_container(cx: &LateContext<'_, '_>, ty: Ty<'_>) -> bool {
+    [&paths::VEC[..], &paths::VEC_DEQUE, &paths::LINKED_LIST, &paths::STRING]
+        .iter()
+        .any(|path| match_type(cx, ty, path))
+}
+
+/// The first place in `body` where the order of the items becomes
+/// observable, with a note to explain how.
+fn find_sink<'tcx>(cx: &LateContext<'_, 'tcx>, body: &'tcx Expr) -> Option<(Span, &'static str)> {
+    let mut visitor = SinkVisitor { cx, sink: None };
+    visitor.visit_expr(body);
+    visitor.sink
+}
+
+struct SinkVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    sink: Option<(Span, &'static str)>,
+}
+
+impl<'a, 'tcx: 'a> Visitor<'tcx> for SinkVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if self.sink.is_some() {
+            return;
+        }
+        if let Some(span) = OUTPUT_MACROS.iter().find_map(|name| is_expn_of(expr.span, name)) {
+            self.sink = Some((span, "written to the output here"));
+            return;
+        }
+        if let ExprKind::MethodCall(ref path, _, ref args) = expr.node {
+            if PUSH_METHODS.iter().any(|name| path.ident.name == *name)
+                && is_ordered_container(self.cx, walk_ptrs_ty(self.cx.tables.expr_ty_adjusted(&args[0])))
+            {
+                self.sink = Some((expr.span, "added to an ordered container here"));
+                return;
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir())
+    }
+}
@@ -1,11 +1,19 @@
 //! lint when there is a large size difference between variants on an enum
 
-use crate::utils::{snippet_opt, span_lint_and_then};
+use crate::utils::{in_macro, snippet_opt, span_lint_and_then};
+use matches::matches;
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::intravisit::{walk_body, walk_crate, walk_expr, walk_pat, NestedVisitorMap, Visitor};
 use rustc::hir::*;
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
 use rustc::ty::layout::LayoutOf;
+use rustc::ty::{FieldDef, TypeckTables, VariantDef};
 use rustc::{declare_tool_lint, lint_array};
 use rustc_errors::Applicability;
+use syntax::ast::Name;
+use syntax::ptr::P;
+use syntax::source_map::Span;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for large size differences between variants on
@@ -15,7 +23,10 @@ declare_clippy_lint! {
     /// large variant
     /// can penalize the memory layout of that enum.
     ///
-    /// **Known problems:** None.
+    /// **Known problems:** The suggestion boxes the largest field of the variant
+    /// and updates the places in the crate where the variant is constructed.
+    /// Patterns that bind or destructure that field cannot be updated, neither
+    /// can constructions in other crates, so the suggestion may not compile.
     ///
     /// **Example:**
     /// ```rust
@@ -53,7 +64,7 @@ impl LintPass for LargeEnumVariant {
 }
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LargeEnumVariant {
-    fn check_item(&mut self, cx: &LateContext<'_, '_>, item: &Item) {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
         let did = cx.tcx.hir().local_def_id_from_hir_id(item.hir_id);
         if let ItemKind::Enum(ref def, _) = item.node {
             let ty = cx.tcx.type_of(did);
@@ -63,16 +74,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LargeEnumVariant {
             let mut largest_variant: Option<(_, _)> = None;
 
             for (i, variant) in adt.variants.iter().enumerate() {
-                let size: u64 = variant
-                    .fields
-                    .iter()
-                    .filter_map(|f| {
-                        let ty = cx.tcx.type_of(f.did);
-                        // don't count generics by filtering out everything
-                        // that does not have a layout
-                        cx.layout_of(ty).ok().map(|l| l.size.bytes())
-                    })
-                    .sum();
+                let size: u64 = variant.fields.iter().filter_map(|f| field_size(cx, f)).sum();
 
                 let grouped = (size, (i, variant));
 
@@ -92,23 +94,13 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LargeEnumVariant {
                         def.variants[i].span,
                         "large size difference between variants",
                         |db| {
-                            if variant.fields.len() == 1 {
-                                let span = match def.variants[i].node.data {
-                                    VariantData::Struct(ref fields, ..) | VariantData::Tuple(ref fields, ..) => {
-                                        fields[0].ty.span
-                                    },
-                                    VariantData::Unit(..) => unreachable!(),
-                                };
-                                if let Some(snip) = snippet_opt(cx, span) {
-                                    db.span_suggestion(
-                                        span,
-                                        "consider boxing the large fields to reduce the total size of the \
-                                         enum",
-                                        format!("Box<{}>", snip),
-                                        Applicability::MaybeIncorrect,
-                                    );
-                                    return;
-                                }
+                            if let Some((sugg, applicability)) = box_field_sugg(cx, item, &def.variants[i], variant) {
+                                db.multipart_suggestion(
+                                    "consider boxing the large fields to reduce the total size of the enum",
+                                    sugg,
+                                    applicability,
+                                );
+                                return;
                             }
                             db.span_help(
                                 def.variants[i].span,
@@ -122,6 +114,174 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LargeEnumVariant {
     }
 }
 
+fn field_size(cx: &LateContext<'_, '_>, field: &FieldDef) -> Option<u64> {
+    let ty = cx.tcx.type_of(field.did);
+    // don't count generics by filtering out everything
+    // that does not have a layout
+    cx.layout_of(ty).ok().map(|l| l.size.bytes())
+}
+
+/// Builds a suggestion that boxes the largest field of `variant` and wraps the
+/// corresponding argument of every construction of the variant in the crate
+/// in `Box::new`.
+///
+/// The suggestion is only machine applicable if the enum is not exported and
+/// all uses of the variant could be updated.
+fn box_field_sugg<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    item: &'tcx Item,
+    hir_variant: &Variant,
+    variant: &VariantDef,
+) -> Option<(Vec<(Span, String)>, Applicability)> {
+    let (field_idx, _) = variant
+        .fields
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, f)| field_size(cx, f).map(|size| (idx, size)))
+        .max_by_key(|&(_, size)| size)?;
+
+    let ty_span = match hir_variant.node.data {
+        VariantData::Struct(ref fields, ..) | VariantData::Tuple(ref fields, ..) => fields[field_idx].ty.span,
+        VariantData::Unit(..) => return None,
+    };
+    let snip = snippet_opt(cx, ty_span)?;
+
+    let mut visitor = VariantUseVisitor {
+        cx,
+        tables: cx.tables,
+        variant_did: variant.did,
+        field_idx,
+        field_count: variant.fields.len(),
+        field_name: variant.fields[field_idx].ident.name,
+        suggestions: vec![(ty_span, format!("Box<{}>", snip))],
+        fixable: true,
+    };
+    walk_crate(&mut visitor, cx.tcx.hir().krate());
+
+    let node_id = cx.tcx.hir().hir_to_node_id(item.hir_id);
+    let applicability = if visitor.fixable && !cx.access_levels.is_exported(node_id) {
+        Applicability::MachineApplicable
+    } else {
+        Applicability::MaybeIncorrect
+    };
+    Some((visitor.suggestions, applicability))
+}
+
+/// Collects the construction sites of a variant whose field at `field_idx` is
+/// about to be boxed.
+struct VariantUseVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    tables: &'a TypeckTables<'tcx>,
+    variant_did: DefId,
+    field_idx: usize,
+    field_count: usize,
+    field_name: Name,
+    suggestions: Vec<(Span, String)>,
+    /// Set to `false` if a use of the variant cannot be updated automatically.
+    fixable: bool,
+}
+
+impl<'a, 'tcx: 'a> VariantUseVisitor<'a, 'tcx> {
+    fn is_variant(&self, qpath: &QPath, hir_id: HirId) -> bool {
+        match self.tables.qpath_def(qpath, hir_id) {
+            Def::Variant(did) | Def::VariantCtor(did, _) => did == self.variant_did,
+            _ => false,
+        }
+    }
+
+    fn is_variant_expr(&self, expr: &Expr) -> bool {
+        if let ExprKind::Path(ref qpath) = expr.node {
+            self.is_variant(qpath, expr.hir_id)
+        } else {
+            false
+        }
+    }
+
+    fn box_expr(&mut self, expr: &Expr) {
+        match snippet_opt(self.cx, expr.span) {
+            Some(snip) if !in_macro(expr.span) => self.suggestions.push((expr.span, format!("Box::new({})", snip))),
+            _ => self.fixable = false,
+        }
+    }
+
+    /// Returns the sub-pattern matching the boxed field in a tuple variant
+    /// pattern, if it is not covered by `..`.
+    fn tuple_field_pat<'p>(&self, pats: &'p [P<Pat>], ddpos: Option<usize>) -> Option<&'p Pat> {
+        let idx = match ddpos {
+            Some(ddpos) if self.field_idx >= ddpos => {
+                let skipped = self.field_count - pats.len();
+                if self.field_idx < ddpos + skipped {
+                    return None;
+                }
+                self.field_idx - skipped
+            },
+            _ => self.field_idx,
+        };
+        pats.get(idx).map(|pat| &**pat)
+    }
+}
+
+impl<'a, 'tcx: 'a> Visitor<'tcx> for VariantUseVisitor<'a, 'tcx> {
+    fn visit_body(&mut self, body: &'tcx Body) {
+        let prev_tables = self.tables;
+        self.tables = self.cx.tcx.body_tables(body.id());
+        walk_body(self, body);
+        self.tables = prev_tables;
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        match expr.node {
+            ExprKind::Call(ref func, ref args) if self.is_variant_expr(func) => {
+                if let Some(arg) = args.get(self.field_idx) {
+                    self.box_expr(arg);
+                }
+                for arg in args {
+                    self.visit_expr(arg);
+                }
+                return;
+            },
+            ExprKind::Struct(ref qpath, ref fields, _) if self.is_variant(qpath, expr.hir_id) => {
+                if let Some(field) = fields.iter().find(|f| f.ident.name == self.field_name) {
+                    if field.is_shorthand {
+                        self.suggestions
+                            .push((field.span, format!("{0}: Box::new({0})", field.ident.name)));
+                    } else {
+                        self.box_expr(&field.expr);
+                    }
+                }
+            },
+            // the constructor is used as a function, e.g. in `.map(Enum::Variant)`
+            _ if self.is_variant_expr(expr) => self.fixable = false,
+            _ => {},
+        }
+        walk_expr(self, expr);
+    }
+
+    fn visit_pat(&mut self, pat: &'tcx Pat) {
+        // patterns that bind or destructure the boxed field would change meaning
+        let field_pat = match pat.node {
+            PatKind::TupleStruct(ref qpath, ref pats, ddpos) if self.is_variant(qpath, pat.hir_id) => {
+                self.tuple_field_pat(pats, ddpos)
+            },
+            PatKind::Struct(ref qpath, ref fields, _) if self.is_variant(qpath, pat.hir_id) => fields
+                .iter()
+                .find(|f| f.node.ident.name == self.field_name)
+                .map(|f| &*f.node.pat),
+            _ => None,
+        };
+        if let Some(field_pat) = field_pat {
+            if !matches!(field_pat.node, PatKind::Wild) {
+                self.fixable = false;
+            }
+        }
+        walk_pat(self, pat);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::All(&self.cx.tcx.hir())
+    }
+}
+
 fn update_if<T, F>(old: &mut Option<T>, new: T, f: F)
 where
     F: Fn(&T, &T) -> bool,
@@ -0,0 +1,89 @@
+use crate::utils::span_note_and_lint;
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use std::fs;
+use syntax::ast::Mac;
+use syntax::parse::parser::Parser;
+use syntax::source_map::FileName;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for the inclusion of large files via `include_bytes!()`
+    /// and `include_str!()`. The maximum size can be set with the
+    /// `max-include-file-size` option.
+    ///
+    /// **Why is this bad?** Including large files bloats the binary and slows
+    /// down compilation, they should be loaded at runtime instead.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// let included_bytes = include_bytes!("very_large_file.txt");
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// use std::fs;
+    ///
+    /// let string = fs::read_to_string("very_large_file.txt")?;
+    /// ```
+    pub LARGE_INCLUDE_FILE,
+    restriction,
+    "including a large file"
+}
+
+pub struct LargeIncludeFile {
+    max_file_size: u64,
+}
+
+impl LargeIncludeFile {
+    pub fn new(max_file_size: u64) -> Self {
+        Self { max_file_size }
+    }
+}
+
+impl LintPass for LargeIncludeFile {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(LARGE_INCLUDE_FILE)
+    }
+
+    fn name(&self) -> &'static str {
+        "LargeIncludeFile"
+    }
+}
+
+impl EarlyLintPass for LargeIncludeFile {
+    fn check_mac(&mut self, cx: &EarlyContext<'_>, mac: &Mac) {
+        if mac.node.path != "include_bytes" && mac.node.path != "include_str" {
+            return;
+        }
+        let mut parser = Parser::new(&cx.sess.parse_sess, mac.node.tts.clone(), None, false, false);
+        let path = match parser.parse_str().map_err(|mut err| err.cancel()) {
+            Ok((path, _)) => path,
+            Err(_) => return,
+        };
+        // the path is relative to the file containing the invocation
+        let file = match cx.sess.source_map().span_to_filename(mac.span) {
+            FileName::Real(file) => file,
+            _ => return,
+        };
+        let path = match file.parent() {
+            Some(dir) => dir.join(&*path.as_str()),
+            None => return,
+        };
+        if let Ok(metadata) = fs::metadata(path) {
+            if metadata.len() > self.max_file_size {
+                span_note_and_lint(
+                    cx,
+                    LARGE_INCLUDE_FILE,
+                    mac.span,
+                    &format!("attempted to include a large file of {} bytes", metadata.len()),
+                    mac.span,
+                    &format!(
+                        "the configuration allows a maximum size of {} bytes",
+                        self.max_file_size
+                    ),
+                );
+            }
+        }
+    }
+}
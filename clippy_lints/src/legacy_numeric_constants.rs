@@ -0,0 +1,309 @@
+use crate::utils::use_tree::{self, Import, Leaf};
+use crate::utils::{in_macro, msrvs, span_lint_and_sugg, span_lint_and_then};
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use semver::Version;
+use syntax::ast;
+use syntax::source_map::Span;
+use syntax::symbol::keywords;
+use syntax::visit::{self, Visitor};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for the constants of the legacy numeric
+    /// modules, like `std::u32::MAX` or `std::f64::EPSILON`, and for the
+    /// `min_value()` and `max_value()` functions of the integer types. `use`
+    /// items importing the modules or their constants are linted as well.
+    ///
+    /// **Why is this bad?** The associated constants, like `u32::MAX`, are
+    /// shorter and need no import. The legacy modules and functions only
+    /// remain for compatibility. Requires Rust 1.43, the lint respects the
+    /// `msrv` option.
+    ///
+    /// **Known problems:** Only `use` items of modules without attributes are
+    /// checked. After removing the import of a constant, like
+    /// `use std::u32::MAX;`, its uses have to be changed by hand.
+    /// `replace_consts` only suggests the `min_value()` and `max_value()`
+    /// functions for MSRVs older than the associated constants.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// use std::f64;
+    ///
+    /// let max = std::u32::MAX;
+    /// let min = i64::min_value();
+    /// let eps = f64::EPSILON;
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let max = u32::MAX;
+    /// let min = i64::MIN;
+    /// let eps = f64::EPSILON;
+    /// ```
+    pub LEGACY_NUMERIC_CONSTANTS,
+    style,
+    "the constants of the legacy numeric modules, or `min_value()` and `max_value()` of integers"
+}
+
+const INTEGERS: &[&str] = &[
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+];
+
+const FLOATS: &[&str] = &["f32", "f64"];
+
+/// The constants of the float modules. `consts` is a module.
+const FLOAT_CONSTANTS: &[&str] = &[
+    "RADIX",
+    "MANTISSA_DIGITS",
+    "DIGITS",
+    "EPSILON",
+    "MIN",
+    "MIN_POSITIVE",
+    "MAX",
+    "MIN_EXP",
+    "MAX_EXP",
+    "MIN_10_EXP",
+    "MAX_10_EXP",
+    "NAN",
+    "INFINITY",
+    "NEG_INFINITY",
+];
+
+pub struct LegacyNumericConstants {
+    msrv: Option<Version>,
+    /// The float types whose legacy module is used for its `consts`, like in
+    /// `f64::consts::PI`. Imports of these modules are kept.
+    consts_used: FxHashSet<String>,
+}
+
+impl LegacyNumericConstants {
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self {
+            msrv,
+            consts_used: FxHashSet::default(),
+        }
+    }
+}
+
+impl LintPass for LegacyNumericConstants {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(LEGACY_NUMERIC_CONSTANTS)
+    }
+
+    fn name(&self) -> &'static str {
+        "LegacyNumericConstants"
+    }
+}
+
+/// What a `use` item imports from a legacy module.
+#[derive(Copy, Clone, PartialEq)]
+enum Imported {
+    Module,
+    Constant,
+}
+
+impl EarlyLintPass for LegacyNumericConstants {
+    fn check_crate(&mut self, _: &EarlyContext<'_>, krate: &ast::Crate) {
+        // the imports may come before the paths using them
+        visit::walk_crate(
+            &mut ConstsFinder {
+                used: &mut self.consts_used,
+            },
+            krate,
+        );
+    }
+
+    fn check_mod(&mut self, cx: &EarlyContext<'_>, module: &ast::Mod, _: Span, _: ast::NodeId) {
+        if !msrvs::meets(self.msrv.as_ref(), msrvs::NUMERIC_ASSOCIATED_CONSTANTS) {
+            return;
+        }
+        for item in &module.items {
+            let tree = match item.node {
+                ast::ItemKind::Use(ref tree) if !in_macro(item.span) && item.attrs.is_empty() => tree,
+                _ => continue,
+            };
+            // re-exports are part of the API
+            if item.vis.node != ast::VisibilityKind::Inherited {
+                continue;
+            }
+            let imports = use_tree::flatten(tree);
+            let mut imported = Vec::new();
+            let mut kept = Vec::new();
+            for import in imports {
+                match self.imported(&import) {
+                    Some(what) => imported.push(what),
+                    None => kept.push(import),
+                }
+            }
+            if imported.is_empty() {
+                continue;
+            }
+
+            let (help, replacement) = if kept.is_empty() {
+                ("remove the import", String::new())
+            } else {
+                let indent = " ".repeat(cx.sess().source_map().lookup_char_pos(item.span.lo()).col.0);
+                let rewritten = use_tree::build(&kept)
+                    .iter()
+                    .map(|tree| format!("use {};", tree))
+                    .collect::<Vec<_>>()
+                    .join(&format!("\n{}", indent));
+                ("remove the legacy imports", rewritten)
+            };
+            if imported.contains(&Imported::Constant) {
+                span_lint_and_then(
+                    cx,
+                    LEGACY_NUMERIC_CONSTANTS,
+                    item.span,
+                    "importing legacy numeric constants",
+                    |db| {
+                        db.note(
+                            "the imported constants have to be replaced with the associated constants, like `u32::MAX`",
+                        );
+                        db.span_suggestion(item.span, help, replacement, Applicability::MaybeIncorrect);
+                    },
+                );
+            } else {
+                span_lint_and_sugg(
+                    cx,
+                    LEGACY_NUMERIC_CONSTANTS,
+                    item.span,
+                    "importing a legacy numeric module",
+                    help,
+                    replacement,
+                    Applicability::MachineApplicable,
+                );
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &EarlyContext<'_>, expr: &ast::Expr) {
+        if in_macro(expr.span) || !msrvs::meets(self.msrv.as_ref(), msrvs::NUMERIC_ASSOCIATED_CONSTANTS) {
+            return;
+        }
+        match expr.node {
+            ast::ExprKind::Path(None, ref path) => check_constant(cx, path, expr.span),
+            // `u32::max_value()`
+            ast::ExprKind::Call(ref func, ref args) if args.is_empty() => {
+                if let ast::ExprKind::Path(None, ref path) = func.node {
+                    if path.segments.len() != 2 || path.segments.iter().any(|segment| segment.args.is_some()) {
+                        return;
+                    }
+                    let names = names(path);
+                    let constant = match &*names[1] {
+                        "min_value" => "MIN",
+                        "max_value" => "MAX",
+                        _ => return,
+                    };
+                    if INTEGERS.contains(&&*names[0]) {
+                        span_lint_and_sugg(
+                            cx,
+                            LEGACY_NUMERIC_CONSTANTS,
+                            expr.span,
+                            "usage of a legacy numeric method",
+                            "use the associated constant instead",
+                            format!("{}::{}", names[0], constant),
+                            Applicability::MachineApplicable,
+                        );
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn check_pat(&mut self, cx: &EarlyContext<'_>, pat: &ast::Pat, _: &mut bool) {
+        if let ast::PatKind::Path(None, ref path) = pat.node {
+            if !in_macro(pat.span) && msrvs::meets(self.msrv.as_ref(), msrvs::NUMERIC_ASSOCIATED_CONSTANTS) {
+                check_constant(cx, path, pat.span);
+            }
+        }
+    }
+}
+
+impl LegacyNumericConstants {
+    /// What `import` imports from a legacy module, if it can be removed. A
+    /// module imported under another name is still needed for that name.
+    fn imported(&self, import: &Import) -> Option<Imported> {
+        let segments: Vec<&str> = import
+            .segments
+            .iter()
+            .map(String::as_str)
+            .skip_while(|segment| segment.is_empty())
+            .collect();
+        match (&segments[..], &import.leaf) {
+            (&[krate, ty], &Leaf::Item(None))
+                if is_std(krate) && is_legacy_module(ty) && !self.consts_used.contains(ty) =>
+            {
+                Some(Imported::Module)
+            },
+            (&[krate, ty, name], &Leaf::Item(_)) if is_std(krate) && is_legacy_constant(ty, name) => {
+                Some(Imported::Constant)
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Lints `std::u32::MAX` and the like.
+fn check_constant(cx: &EarlyContext<'_>, path: &ast::Path, span: Span) {
+    let names = names(path);
+    if let [ref krate, ref ty, ref name] = names[..] {
+        if is_std(krate) && is_legacy_constant(ty, name) {
+            span_lint_and_sugg(
+                cx,
+                LEGACY_NUMERIC_CONSTANTS,
+                span,
+                "usage of a legacy numeric constant",
+                "use the associated constant instead",
+                format!("{}::{}", ty, name),
+                Applicability::MachineApplicable,
+            );
+        }
+    }
+}
+
+/// The names of the segments of `path`, without a leading `::`.
+fn names(path: &ast::Path) -> Vec<String> {
+    path.segments
+        .iter()
+        .filter(|segment| segment.ident.name != keywords::PathRoot.name())
+        .map(|segment| segment.ident.to_string())
+        .collect()
+}
+
+fn is_std(krate: &str) -> bool {
+    krate == "std" || krate == "core"
+}
+
+fn is_legacy_module(ty: &str) -> bool {
+    INTEGERS.contains(&ty) || FLOATS.contains(&ty)
+}
+
+/// Whether `name` is a constant of the legacy module of `ty` that has an
+/// associated constant of the same name.
+fn is_legacy_constant(ty: &str, name: &str) -> bool {
+    if INTEGERS.contains(&ty) {
+        name == "MIN" || name == "MAX"
+    } else {
+        FLOATS.contains(&ty) && FLOAT_CONSTANTS.contains(&name)
+    }
+}
+
+/// Finds the float modules used for their `consts`.
+struct ConstsFinder<'a> {
+    used: &'a mut FxHashSet<String>,
+}
+
+impl<'a, 'ast> Visitor<'ast> for ConstsFinder<'a> {
+    fn visit_path(&mut self, path: &'ast ast::Path, _: ast::NodeId) {
+        let names = names(path);
+        if names.len() >= 2 && FLOATS.contains(&&*names[0]) && names[1] == "consts" {
+            self.used.insert(names[0].clone());
+        }
+        visit::walk_path(self, path);
+    }
+
+    fn visit_mac(&mut self, _: &'ast ast::Mac) {}
+}
@@ -1,4 +1,6 @@
-use crate::utils::{get_item_name, in_macro, snippet_with_applicability, span_lint, span_lint_and_sugg, walk_ptrs_ty};
+use crate::utils::{
+    get_item_name, in_macro, snippet_with_applicability, span_lint, span_lint_and_sugg_lazy, walk_ptrs_ty,
+};
 use rustc::hir::def_id::DefId;
 use rustc::hir::*;
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
@@ -256,19 +258,20 @@ fn check_len(
         }
 
         if method_name == "len" && args.len() == 1 && has_is_empty(cx, &args[0]) {
-            let mut applicability = Applicability::MachineApplicable;
-            span_lint_and_sugg(
+            span_lint_and_sugg_lazy(
                 cx,
                 LEN_ZERO,
                 span,
                 &format!("length comparison to {}", if compare_to == 0 { "zero" } else { "one" }),
                 "using `is_empty` is clearer and more explicit",
-                format!(
-                    "{}{}.is_empty()",
-                    op,
-                    snippet_with_applicability(cx, args[0].span, "_", &mut applicability)
-                ),
-                applicability,
+                Applicability::MachineApplicable,
+                |applicability| {
+                    format!(
+                        "{}{}.is_empty()",
+                        op,
+                        snippet_with_applicability(cx, args[0].span, "_", applicability)
+                    )
+                },
             );
         }
     }
@@ -19,6 +19,8 @@ extern crate rustc;
 #[allow(unused_extern_crates)]
 extern crate rustc_data_structures;
 #[allow(unused_extern_crates)]
+extern crate rustc_driver;
+#[allow(unused_extern_crates)]
 extern crate rustc_errors;
 #[allow(unused_extern_crates)]
 extern crate rustc_mir;
@@ -33,6 +35,7 @@ extern crate syntax;
 #[allow(unused_extern_crates)]
 extern crate syntax_pos;
 
+use crate::thread_local_initializer_can_be_made_const::ThreadLocalInitializerCanBeMadeConst;
 use toml;
 
 /// Macro used to declare a Clippy lint.
@@ -139,10 +142,13 @@ macro_rules! declare_clippy_lint {
 mod consts;
 #[macro_use]
 mod utils;
+pub mod driver;
 
 // begin lints modules, do not remove this comment, it’s used in `update_lints`
+pub mod absolute_paths;
 pub mod approx_const;
 pub mod arithmetic;
+pub mod arithmetic_side_effects;
 pub mod assertions_on_constants;
 pub mod assign_ops;
 pub mod attrs;
@@ -153,14 +159,19 @@ pub mod booleans;
 pub mod bytecount;
 pub mod cargo_common_metadata;
 pub mod collapsible_if;
+pub mod collection_is_never_read;
 pub mod const_static_lifetime;
 pub mod copies;
 pub mod copy_iterator;
 pub mod cyclomatic_complexity;
 pub mod dbg_macro;
+pub mod default_constructed_unit_structs;
 pub mod default_trait_access;
+pub mod derivable_impls;
 pub mod derive;
+pub mod disallowed_patterns;
 pub mod doc;
+pub mod doc_comments;
 pub mod double_comparison;
 pub mod double_parens;
 pub mod drop_bounds;
@@ -168,6 +179,7 @@ pub mod drop_forget_ref;
 pub mod duration_subsec;
 pub mod else_if_without_else;
 pub mod empty_enum;
+pub mod empty_structs_with_brackets;
 pub mod entry;
 pub mod enum_clike;
 pub mod enum_glob_use;
@@ -181,12 +193,17 @@ pub mod excessive_precision;
 pub mod explicit_write;
 pub mod fallible_impl_from;
 pub mod format;
+pub mod format_push_string;
 pub mod formatting;
 pub mod functions;
 pub mod identity_conversion;
 pub mod identity_op;
 pub mod if_not_else;
+pub mod ignored_unit_patterns;
+pub mod impl_trait_in_params;
 pub mod implicit_return;
+pub mod imports_granularity;
+pub mod incompatible_msrv;
 pub mod indexing_slicing;
 pub mod infallible_destructuring_match;
 pub mod infinite_iter;
@@ -195,75 +212,116 @@ pub mod inline_fn_without_body;
 pub mod int_plus_one;
 pub mod invalid_ref;
 pub mod items_after_statements;
+pub mod items_after_test_module;
+pub mod iter_not_returning_iterator;
+pub mod iter_over_hash_type;
 pub mod large_enum_variant;
+pub mod large_include_file;
+pub mod legacy_numeric_constants;
 pub mod len_zero;
 pub mod let_if_seq;
 pub mod lifetimes;
 pub mod literal_representation;
 pub mod loops;
+pub mod manual_c_str_literals;
+pub mod manual_hash_one;
+pub mod manual_is_ascii_check;
+pub mod manual_retain;
+pub mod manual_slice_size_calculation;
+pub mod manual_string_new;
 pub mod map_clone;
 pub mod map_unit_fn;
 pub mod matches;
 pub mod mem_discriminant;
 pub mod mem_forget;
 pub mod mem_replace;
+pub mod mem_swap;
 pub mod methods;
 pub mod minmax;
 pub mod misc;
 pub mod misc_early;
+pub mod missing_asserts_for_indexing;
 pub mod missing_const_for_fn;
 pub mod missing_doc;
 pub mod missing_inline;
+pub mod missing_trait_methods;
 pub mod multiple_crate_versions;
+pub mod multiple_unsafe_ops_per_block;
+pub mod must_use;
 pub mod mut_mut;
 pub mod mut_reference;
 pub mod mutex_atomic;
 pub mod needless_bool;
 pub mod needless_borrow;
 pub mod needless_borrowed_ref;
+pub mod needless_borrows_for_generic_args;
 pub mod needless_continue;
+pub mod needless_pass_by_ref_mut;
 pub mod needless_pass_by_value;
 pub mod needless_update;
 pub mod neg_cmp_op_on_partial_ord;
 pub mod neg_multiply;
 pub mod new_without_default;
 pub mod no_effect;
+pub mod non_canonical_impls;
 pub mod non_copy_const;
 pub mod non_expressive_names;
+pub mod obfuscated_if_else;
 pub mod ok_if_let;
 pub mod open_options;
 pub mod overflow_check_conditional;
 pub mod panic_unimplemented;
+pub mod partial_pub_fields;
 pub mod partialeq_ne_impl;
+pub mod pathbuf_init_then_push;
 pub mod precedence;
 pub mod ptr;
 pub mod ptr_offset_with_cast;
 pub mod question_mark;
+pub mod question_mark_used;
 pub mod ranges;
+pub mod read_zero_byte_vec;
+pub mod readonly_write_lock;
 pub mod redundant_clone;
 pub mod redundant_field_names;
 pub mod redundant_pattern_matching;
+pub mod redundant_type_annotations;
 pub mod reference;
 pub mod regex;
 pub mod replace_consts;
+pub mod result_large_err;
 pub mod returns;
+pub mod semicolon_outside_block;
 pub mod serde_api;
 pub mod shadow;
+pub mod single_call_fn;
 pub mod slow_vector_initialization;
+pub mod std_instead_of_core;
 pub mod strings;
+pub mod suspicious_operation_groupings;
 pub mod suspicious_trait_impl;
+pub mod suspicious_xor_used_as_pow;
 pub mod swap;
 pub mod temporary_assignment;
+pub mod thread_local_initializer_can_be_made_const;
 pub mod transmute;
 pub mod trivially_copy_pass_by_ref;
+pub mod try_err;
+pub mod tuple_array_conversions;
+pub mod type_conventions;
 pub mod types;
+pub mod unchecked_duration_subtraction;
+pub mod unconditional_recursion;
+pub mod undocumented_unsafe_blocks;
 pub mod unicode;
+pub mod unknown_features;
 pub mod unsafe_removed_from_name;
 pub mod unused_io_amount;
 pub mod unused_label;
 pub mod unwrap;
 pub mod use_self;
 pub mod vec;
+pub mod waker_clone_wake;
 pub mod wildcard_dependencies;
 pub mod write;
 pub mod zero_div_zero;
@@ -282,7 +340,7 @@ mod reexport {
 /// Note that due to the architechture of the compiler, currently `cfg_attr` attributes on crate
 /// level (i.e `#![cfg_attr(...)]`) will still be expanded even when using a pre-expansion pass.
 ///
-/// Used in `./src/driver.rs`.
+/// Used in `driver::register_lints`.
 pub fn register_pre_expansion_lints(
     session: &rustc::session::Session,
     store: &mut rustc::lint::LintStore,
@@ -304,7 +362,21 @@ pub fn register_pre_expansion_lints(
         },
     );
     store.register_pre_expansion_pass(Some(session), true, false, box attrs::CfgAttrPass);
+    store.register_pre_expansion_pass(
+        Some(session),
+        true,
+        false,
+        box unknown_features::UnknownFeatures::default(),
+    );
     store.register_pre_expansion_pass(Some(session), true, false, box dbg_macro::Pass);
+    store.register_pre_expansion_pass(
+        Some(session),
+        true,
+        false,
+        box large_include_file::LargeIncludeFile::new(conf.max_include_file_size),
+    );
+    // must be registered last
+    store.register_pre_expansion_pass(Some(session), true, false, box utils::FlushDiagnostics);
 }
 
 #[doc(hidden)]
@@ -340,17 +412,42 @@ pub fn read_conf(reg: &rustc_plugin::Registry<'_>) -> Conf {
                 }
             });
 
-            let (conf, errors) = utils::conf::read(file_name.as_ref().map(std::convert::AsRef::as_ref));
+            let path = match file_name {
+                Some(path) => path,
+                None => return Conf::default(),
+            };
+            // loading the file through the source map gives the errors spans, and lists the file in
+            // the dep-info of the crate, so that cargo checks the crate again when the file changes
+            let (conf, errors, file) = match reg.sess.source_map().load_file(&path) {
+                Ok(file) => {
+                    let src = file.src.as_ref().expect("the file was just loaded");
+                    let (conf, errors) = utils::conf::read_str_cached(&path, src);
+                    (conf, errors, Some(file))
+                },
+                Err(error) => (Conf::default(), vec![error.into()], None),
+            };
 
-            // all conf errors are non-fatal, we just use the default conf in case of error
-            for error in errors {
-                reg.sess
-                    .struct_err(&format!(
-                        "error reading Clippy's configuration file `{}`: {}",
-                        file_name.as_ref().and_then(|p| p.to_str()).unwrap_or(""),
-                        error
-                    ))
-                    .emit();
+            // all conf errors are non-fatal, we just use the default conf in case of error; they are
+            // reported in one diagnostic, pointing at the first one
+            let span = |error: &utils::conf::Error| error.span(file.as_ref()?);
+            let mut errors = errors.into_iter();
+            if let Some(first) = errors.next() {
+                let message = format!(
+                    "error reading Clippy's configuration file `{}`: {}",
+                    path.display(),
+                    first
+                );
+                let mut db = match span(&first) {
+                    Some(span) => reg.sess.struct_span_err(span, &message),
+                    None => reg.sess.struct_err(&message),
+                };
+                for error in errors {
+                    match span(&error) {
+                        Some(span) => db.span_note(span, &error.to_string()),
+                        None => db.note(&error.to_string()),
+                    };
+                }
+                db.emit();
             }
 
             conf
@@ -367,7 +464,7 @@ pub fn read_conf(reg: &rustc_plugin::Registry<'_>) -> Conf {
 
 /// Register all lints and lint groups with the rustc plugin registry
 ///
-/// Used in `./src/driver.rs`.
+/// Used in `driver::register_lints`.
 #[allow(clippy::too_many_lines)]
 #[rustfmt::skip]
 pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
@@ -419,6 +516,8 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
     );
     // end deprecated lints, do not remove this comment, it’s used in `update_lints`
 
+    // this has to run before all other early passes to silence their lints in generated code
+    reg.register_early_lint_pass(box utils::generated::GeneratedCode);
     reg.register_late_lint_pass(box serde_api::Serde);
     reg.register_early_lint_pass(box utils::internal_lints::Clippy);
     reg.register_late_lint_pass(box utils::internal_lints::CompilerLintFunctions::new());
@@ -426,10 +525,16 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
     reg.register_late_lint_pass(box utils::internal_lints::LintWithoutLintPass::default());
     reg.register_late_lint_pass(box utils::inspector::Pass);
     reg.register_late_lint_pass(box utils::author::Pass);
+    if let Some(pass) = utils::pattern_coverage::PatternCoverage::from_env() {
+        reg.register_early_lint_pass(box pass);
+    }
     reg.register_late_lint_pass(box types::TypePass);
     reg.register_late_lint_pass(box booleans::NonminimalBool);
     reg.register_late_lint_pass(box eq_op::EqOp);
-    reg.register_early_lint_pass(box enum_variants::EnumVariantNames::new(conf.enum_variant_name_threshold));
+    reg.register_early_lint_pass(box enum_variants::EnumVariantNames::new(
+        conf.enum_variant_name_threshold,
+        conf.struct_field_name_threshold,
+    ));
     reg.register_late_lint_pass(box enum_glob_use::EnumGlobUse);
     reg.register_late_lint_pass(box enum_clike::UnportableVariant);
     reg.register_late_lint_pass(box excessive_precision::ExcessivePrecision);
@@ -455,7 +560,16 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
     reg.register_late_lint_pass(box strings::StringAdd);
     reg.register_early_lint_pass(box returns::ReturnPass);
     reg.register_late_lint_pass(box implicit_return::Pass);
-    reg.register_late_lint_pass(box methods::Pass);
+    let msrv = conf.msrv.as_ref().and_then(|msrv| match utils::msrvs::parse(msrv) {
+        Ok(version) => Some(version),
+        Err(error) => {
+            reg.sess
+                .struct_err(&format!("error reading Clippy's configuration file: {}", error))
+                .emit();
+            None
+        },
+    });
+    reg.register_late_lint_pass(box methods::Pass::new(msrv.clone()));
     reg.register_late_lint_pass(box map_clone::Pass);
     reg.register_late_lint_pass(box shadow::Pass);
     reg.register_late_lint_pass(box types::LetPass);
@@ -540,14 +654,14 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
     ));
     reg.register_late_lint_pass(box use_self::UseSelf);
     reg.register_late_lint_pass(box bytecount::ByteCount);
-    reg.register_late_lint_pass(box infinite_iter::Pass);
+    reg.register_late_lint_pass(box infinite_iter::Pass::new(&conf.infinite_iterators));
     reg.register_late_lint_pass(box inline_fn_without_body::Pass);
     reg.register_late_lint_pass(box invalid_ref::InvalidRef);
     reg.register_late_lint_pass(box identity_conversion::IdentityConversion::default());
     reg.register_late_lint_pass(box types::ImplicitHasher);
     reg.register_early_lint_pass(box const_static_lifetime::StaticConst);
     reg.register_late_lint_pass(box fallible_impl_from::FallibleImplFrom);
-    reg.register_late_lint_pass(box replace_consts::ReplaceConsts);
+    reg.register_late_lint_pass(box replace_consts::ReplaceConsts::new(msrv.clone()));
     reg.register_late_lint_pass(box types::UnitArg);
     reg.register_late_lint_pass(box double_comparison::Pass);
     reg.register_late_lint_pass(box question_mark::Pass);
@@ -570,29 +684,139 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
     reg.register_late_lint_pass(box types::RefToMut);
     reg.register_late_lint_pass(box assertions_on_constants::AssertionsOnConstants);
     reg.register_late_lint_pass(box missing_const_for_fn::MissingConstForFn);
+    reg.register_late_lint_pass(box collection_is_never_read::CollectionIsNeverRead::default());
+    reg.register_late_lint_pass(box obfuscated_if_else::ObfuscatedIfElse::new(conf.prefer_bool_then, msrv.clone()));
+    reg.register_late_lint_pass(box result_large_err::ResultLargeErr::new(conf.large_error_threshold));
+    reg.register_late_lint_pass(box read_zero_byte_vec::ReadZeroByteVec);
+    reg.register_late_lint_pass(box mem_swap::MemSwap);
+    reg.register_early_lint_pass(box disallowed_patterns::DisallowedPatterns::new(&conf.disallowed_patterns));
+    reg.register_late_lint_pass(box derivable_impls::DerivableImpls::new(msrv.clone()));
+    reg.register_late_lint_pass(box format_push_string::FormatPushString);
+    reg.register_late_lint_pass(box iter_not_returning_iterator::IterNotReturningIterator);
+    reg.register_late_lint_pass(box must_use::MustUse);
+    reg.register_late_lint_pass(box doc::DocSections);
+    reg.register_early_lint_pass(box suspicious_operation_groupings::SuspiciousOperationGroupings::default());
+    reg.register_late_lint_pass(box type_conventions::TypeConventions);
+    reg.register_late_lint_pass(box std_instead_of_core::StdReexports::default());
+    reg.register_early_lint_pass(box partial_pub_fields::PartialPubFields::new(
+        conf.partial_pub_fields_ignore_doc_hidden,
+    ));
+    reg.register_late_lint_pass(box manual_string_new::ManualStringNew);
+    reg.register_late_lint_pass(box missing_trait_methods::MissingTraitMethods::new(
+        &conf.missing_trait_methods,
+    ));
+    reg.register_late_lint_pass(box arithmetic_side_effects::ArithmeticSideEffects::new(
+        &conf.arithmetic_side_effects_allowed,
+    ));
+    reg.register_early_lint_pass(box empty_structs_with_brackets::EmptyStructsWithBrackets);
+    reg.register_early_lint_pass(box semicolon_outside_block::SemicolonOutsideBlock);
+    reg.register_late_lint_pass(box unchecked_duration_subtraction::UncheckedDurationSubtraction::new(msrv.clone()));
+    reg.register_late_lint_pass(box manual_is_ascii_check::ManualIsAsciiCheck);
+    reg.register_late_lint_pass(box question_mark_used::QuestionMarkUsed);
+    reg.register_late_lint_pass(box try_err::TryErr);
+    reg.register_late_lint_pass(box tuple_array_conversions::TupleArrayConversions::new(msrv.clone()));
+    reg.register_late_lint_pass(box needless_pass_by_ref_mut::NeedlessPassByRefMut::default());
+    reg.register_early_lint_pass(box doc_comments::DocComments::default());
+    reg.register_late_lint_pass(box redundant_type_annotations::RedundantTypeAnnotations::new(
+        conf.redundant_type_annotations_ignore_numeric_literals,
+    ));
+    reg.register_late_lint_pass(box single_call_fn::SingleCallFn::new(conf.single_call_fn_min_body_lines));
+    reg.register_early_lint_pass(box impl_trait_in_params::ImplTraitInParams::default());
+    reg.register_late_lint_pass(box manual_retain::ManualRetain::new(msrv.clone()));
+    reg.register_late_lint_pass(box missing_asserts_for_indexing::MissingAssertsForIndexing);
+    reg.register_late_lint_pass(box readonly_write_lock::ReadonlyWriteLock);
+    reg.register_late_lint_pass(box default_constructed_unit_structs::DefaultConstructedUnitStructs);
+    reg.register_early_lint_pass(box items_after_test_module::ItemsAfterTestModule);
+    reg.register_late_lint_pass(box manual_hash_one::ManualHashOne::new(msrv.clone()));
+    reg.register_late_lint_pass(box iter_over_hash_type::IterOverHashType::new(conf.iter_over_hash_type_all));
+    reg.register_late_lint_pass(box unconditional_recursion::UnconditionalRecursion);
+    reg.register_late_lint_pass(box pathbuf_init_then_push::PathbufInitThenPush);
+    reg.register_late_lint_pass(box waker_clone_wake::WakerCloneWake::new(&conf.clone_then_consume_methods));
+    reg.register_late_lint_pass(box manual_c_str_literals::ManualCStrLiterals::new(msrv.clone()));
+    reg.register_late_lint_pass(box absolute_paths::AbsolutePaths::new(
+        conf.absolute_paths_max_segments,
+        &conf.absolute_paths_allowed_crates,
+    ));
+    match imports_granularity::Granularity::parse(&conf.imports_granularity) {
+        Ok(granularity) => {
+            reg.register_early_lint_pass(box imports_granularity::ImportsGranularity::new(granularity));
+        },
+        Err(error) => {
+            reg.sess
+                .struct_err(&format!("error reading Clippy's configuration file: {}", error))
+                .emit();
+        },
+    }
+    reg.register_late_lint_pass(box ThreadLocalInitializerCanBeMadeConst::new(msrv.clone()));
+    reg.register_late_lint_pass(box incompatible_msrv::IncompatibleMsrv::new(msrv.clone()));
+    reg.register_late_lint_pass(box multiple_unsafe_ops_per_block::MultipleUnsafeOpsPerBlock);
+    reg.register_early_lint_pass(box undocumented_unsafe_blocks::UndocumentedUnsafeBlocks::new(
+        conf.accept_comment_above_attributes,
+        conf.safety_comment_max_blank_lines,
+    ));
+    reg.register_late_lint_pass(box manual_slice_size_calculation::ManualSliceSizeCalculation);
+    reg.register_late_lint_pass(box suspicious_xor_used_as_pow::SuspiciousXorUsedAsPow::new(
+        &conf.suspicious_xor_bases,
+    ));
+    reg.register_late_lint_pass(box needless_borrows_for_generic_args::NeedlessBorrowsForGenericArgs);
+    reg.register_late_lint_pass(box ignored_unit_patterns::IgnoredUnitPatterns::default());
+    reg.register_early_lint_pass(box legacy_numeric_constants::LegacyNumericConstants::new(msrv));
+    reg.register_late_lint_pass(box non_canonical_impls::NonCanonicalImpls);
+    reg.register_late_lint_pass(box utils::explain::ExplainSpan);
+    // must be registered after all other passes
+    reg.register_early_lint_pass(box utils::FlushDiagnostics);
+    reg.register_late_lint_pass(box utils::FlushDiagnostics);
 
     reg.register_lint_group("clippy::restriction", Some("clippy_restriction"), vec![
+        absolute_paths::ABSOLUTE_PATHS,
         arithmetic::FLOAT_ARITHMETIC,
         arithmetic::INTEGER_ARITHMETIC,
+        arithmetic_side_effects::ARITHMETIC_SIDE_EFFECTS,
+        attrs::CFG_NOT_CLIPPY,
         dbg_macro::DBG_MACRO,
+        disallowed_patterns::DISALLOWED_PATTERNS,
         else_if_without_else::ELSE_IF_WITHOUT_ELSE,
+        empty_structs_with_brackets::EMPTY_STRUCTS_WITH_BRACKETS,
+        impl_trait_in_params::IMPL_TRAIT_IN_PARAMS,
         implicit_return::IMPLICIT_RETURN,
+        imports_granularity::IMPORTS_GRANULARITY,
         indexing_slicing::INDEXING_SLICING,
         inherent_impl::MULTIPLE_INHERENT_IMPL,
+        iter_over_hash_type::ITER_OVER_HASH_TYPE,
+        large_include_file::LARGE_INCLUDE_FILE,
         literal_representation::DECIMAL_LITERAL_REPRESENTATION,
+        loops::INFINITE_LOOP,
         matches::WILDCARD_ENUM_MATCH_ARM,
         mem_forget::MEM_FORGET,
         methods::CLONE_ON_REF_PTR,
         methods::OPTION_UNWRAP_USED,
         methods::RESULT_UNWRAP_USED,
+        methods::STRING_LIT_CHARS_ANY,
         methods::WRONG_PUB_SELF_CONVENTION,
         misc::FLOAT_CMP_CONST,
+        missing_asserts_for_indexing::MISSING_ASSERTS_FOR_INDEXING,
         missing_doc::MISSING_DOCS_IN_PRIVATE_ITEMS,
         missing_inline::MISSING_INLINE_IN_PUBLIC_ITEMS,
+        missing_trait_methods::MISSING_TRAIT_METHODS,
+        multiple_unsafe_ops_per_block::MULTIPLE_UNSAFE_OPS_PER_BLOCK,
+        must_use::MUST_USE_CANDIDATE,
         panic_unimplemented::UNIMPLEMENTED,
+        partial_pub_fields::PARTIAL_PUB_FIELDS,
+        pathbuf_init_then_push::PATHBUF_INIT_THEN_PUSH,
+        question_mark_used::QUESTION_MARK_USED,
+        redundant_type_annotations::REDUNDANT_TYPE_ANNOTATIONS,
+        semicolon_outside_block::SEMICOLON_OUTSIDE_BLOCK,
         shadow::SHADOW_REUSE,
         shadow::SHADOW_SAME,
+        single_call_fn::SINGLE_CALL_FN,
+        std_instead_of_core::ALLOC_INSTEAD_OF_CORE,
+        std_instead_of_core::STD_INSTEAD_OF_ALLOC,
+        std_instead_of_core::STD_INSTEAD_OF_CORE,
         strings::STRING_ADD,
+        try_err::TRY_ERR,
+        types::AS_UNDERSCORE,
+        types::CAST_TRY_FROM,
+        undocumented_unsafe_blocks::UNDOCUMENTED_UNSAFE_BLOCKS,
         write::PRINT_STDOUT,
         write::USE_DEBUG,
     ]);
@@ -604,22 +828,35 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         default_trait_access::DEFAULT_TRAIT_ACCESS,
         derive::EXPL_IMPL_CLONE_ON_COPY,
         doc::DOC_MARKDOWN,
+        doc::MISSING_ERRORS_DOC,
+        doc::MISSING_PANICS_DOC,
+        doc_comments::EMPTY_DOC_SECTIONS,
         empty_enum::EMPTY_ENUM,
         enum_glob_use::ENUM_GLOB_USE,
         enum_variants::MODULE_NAME_REPETITIONS,
         enum_variants::PUB_ENUM_VARIANT_NAMES,
+        enum_variants::STRUCT_FIELD_NAMES,
+        format_push_string::FORMAT_PUSH_STRING,
         functions::TOO_MANY_LINES,
         if_not_else::IF_NOT_ELSE,
+        ignored_unit_patterns::IGNORED_UNIT_PATTERNS,
         infinite_iter::MAYBE_INFINITE_ITER,
         items_after_statements::ITEMS_AFTER_STATEMENTS,
+        iter_not_returning_iterator::ITER_NOT_RETURNING_ITERATOR,
         literal_representation::LARGE_DIGIT_GROUPS,
         loops::EXPLICIT_INTO_ITER_LOOP,
         loops::EXPLICIT_ITER_LOOP,
+        loops::MANUAL_TRY_COLLECT,
+        manual_string_new::MANUAL_STRING_NEW,
         matches::SINGLE_MATCH_ELSE,
+        mem_swap::SWAP_PTR_TO_REF,
         methods::FILTER_MAP,
+        methods::JOIN_ABSOLUTE_PATHS,
+        methods::LINES_FILTER_MAP_OK,
         methods::MAP_FLATTEN,
         methods::OPTION_MAP_UNWRAP_OR,
         methods::OPTION_MAP_UNWRAP_OR_ELSE,
+        methods::PERMISSIONS_SET_READONLY_FALSE,
         methods::RESULT_MAP_UNWRAP_OR_ELSE,
         misc::USED_UNDERSCORE_BINDING,
         misc_early::UNSEPARATED_LITERAL_SUFFIX,
@@ -630,12 +867,15 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         replace_consts::REPLACE_CONSTS,
         shadow::SHADOW_UNRELATED,
         strings::STRING_ADD_ASSIGN,
+        suspicious_xor_used_as_pow::SUSPICIOUS_XOR_USED_AS_POW,
+        type_conventions::BUILDER_WITHOUT_BUILD,
         types::CAST_POSSIBLE_TRUNCATION,
         types::CAST_POSSIBLE_WRAP,
         types::CAST_PRECISION_LOSS,
         types::CAST_SIGN_LOSS,
         types::INVALID_UPCAST_COMPARISONS,
         types::LINKEDLIST,
+        unchecked_duration_subtraction::UNCHECKED_DURATION_SUBTRACTION,
         unicode::NON_ASCII_LITERAL,
         unicode::UNICODE_NOT_NFC,
         use_self::USE_SELF,
@@ -654,6 +894,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         assign_ops::ASSIGN_OP_PATTERN,
         assign_ops::MISREFACTORED_ASSIGN_OP,
         attrs::DEPRECATED_CFG_ATTR,
+        attrs::DEPRECATED_CLIPPY_CFG_ATTR,
         attrs::DEPRECATED_SEMVER,
         attrs::UNKNOWN_CLIPPY_LINTS,
         attrs::USELESS_ATTRIBUTE,
@@ -671,16 +912,24 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         copies::IFS_SAME_COND,
         copies::IF_SAME_THEN_ELSE,
         cyclomatic_complexity::CYCLOMATIC_COMPLEXITY,
+        default_constructed_unit_structs::DEFAULT_CONSTRUCTED_UNIT_STRUCTS,
+        derivable_impls::DERIVABLE_IMPLS,
         derive::DERIVE_HASH_XOR_EQ,
+        doc_comments::DOC_COMMENT_IN_MACRO_MATCHER,
+        doc_comments::EMPTY_LINE_AFTER_DOC_COMMENTS,
+        doc_comments::FOUR_FORWARD_SLASHES,
         double_comparison::DOUBLE_COMPARISONS,
         double_parens::DOUBLE_PARENS,
         drop_bounds::DROP_BOUNDS,
         drop_forget_ref::DROP_COPY,
+        drop_forget_ref::DROP_NON_DROP,
         drop_forget_ref::DROP_REF,
         drop_forget_ref::FORGET_COPY,
+        drop_forget_ref::FORGET_NON_DROP,
         drop_forget_ref::FORGET_REF,
         duration_subsec::DURATION_SUBSEC,
         entry::MAP_ENTRY,
+        entry::SET_CONTAINS_OR_INSERT,
         enum_clike::ENUM_CLIKE_UNPORTABLE_VARIANT,
         enum_variants::ENUM_VARIANT_NAMES,
         enum_variants::MODULE_INCEPTION,
@@ -701,13 +950,16 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         functions::TOO_MANY_ARGUMENTS,
         identity_conversion::IDENTITY_CONVERSION,
         identity_op::IDENTITY_OP,
+        incompatible_msrv::INCOMPATIBLE_MSRV,
         indexing_slicing::OUT_OF_BOUNDS_INDEXING,
         infallible_destructuring_match::INFALLIBLE_DESTRUCTURING_MATCH,
         infinite_iter::INFINITE_ITER,
         inline_fn_without_body::INLINE_FN_WITHOUT_BODY,
         int_plus_one::INT_PLUS_ONE,
         invalid_ref::INVALID_REF,
+        items_after_test_module::ITEMS_AFTER_TEST_MODULE,
         large_enum_variant::LARGE_ENUM_VARIANT,
+        legacy_numeric_constants::LEGACY_NUMERIC_CONSTANTS,
         len_zero::LEN_WITHOUT_IS_EMPTY,
         len_zero::LEN_ZERO,
         let_if_seq::USELESS_LET_IF_SEQ,
@@ -732,6 +984,11 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         loops::WHILE_IMMUTABLE_CONDITION,
         loops::WHILE_LET_LOOP,
         loops::WHILE_LET_ON_ITERATOR,
+        manual_c_str_literals::MANUAL_C_STR_LITERALS,
+        manual_hash_one::MANUAL_HASH_ONE,
+        manual_is_ascii_check::MANUAL_IS_ASCII_CHECK,
+        manual_retain::MANUAL_RETAIN,
+        manual_slice_size_calculation::MANUAL_SLICE_SIZE_CALCULATION,
         map_clone::MAP_CLONE,
         map_unit_fn::OPTION_MAP_UNIT_FN,
         map_unit_fn::RESULT_MAP_UNIT_FN,
@@ -743,11 +1000,14 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         matches::SINGLE_MATCH,
         mem_discriminant::MEM_DISCRIMINANT_NON_ENUM,
         mem_replace::MEM_REPLACE_OPTION_WITH_NONE,
+        mem_swap::NOOP_SWAP,
+        mem_swap::SWAP_WITH_TEMPORARY,
         methods::CHARS_LAST_CMP,
         methods::CHARS_NEXT_CMP,
         methods::CLONE_DOUBLE_REF,
         methods::CLONE_ON_COPY,
         methods::EXPECT_FUN_CALL,
+        methods::FILTER_MAP_BOOL_THEN,
         methods::FILTER_NEXT,
         methods::GET_UNWRAP,
         methods::INTO_ITER_ON_ARRAY,
@@ -755,17 +1015,25 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         methods::ITER_CLONED_COLLECT,
         methods::ITER_NTH,
         methods::ITER_SKIP_NEXT,
+        methods::MANUAL_RESULT_OK,
         methods::NEW_RET_NO_SELF,
         methods::OK_EXPECT,
+        methods::OK_UNWRAP_OR,
+        methods::OPTION_MAP_OR_ERR_OK,
         methods::OPTION_MAP_OR_NONE,
         methods::OR_FUN_CALL,
+        methods::REDUNDANT_AS_STR,
         methods::SEARCH_IS_SOME,
+        methods::SEEK_FROM_CURRENT,
+        methods::SEEK_TO_START_INSTEAD_OF_REWIND,
         methods::SHOULD_IMPLEMENT_TRAIT,
         methods::SINGLE_CHAR_PATTERN,
         methods::STRING_EXTEND_CHARS,
         methods::TEMPORARY_CSTRING_AS_PTR,
         methods::UNNECESSARY_FILTER_MAP,
         methods::UNNECESSARY_FOLD,
+        methods::UNNECESSARY_TO_OWNED,
+        methods::UNUSED_ROUNDING,
         methods::USELESS_ASREF,
         methods::WRONG_SELF_CONVENTION,
         minmax::MIN_MAX,
@@ -784,21 +1052,27 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         misc_early::REDUNDANT_CLOSURE_CALL,
         misc_early::UNNEEDED_FIELD_PATTERN,
         misc_early::ZERO_PREFIXED_LITERAL,
+        must_use::DOUBLE_MUST_USE,
+        must_use::MUST_USE_UNIT,
         mut_reference::UNNECESSARY_MUT_PASSED,
         mutex_atomic::MUTEX_ATOMIC,
         needless_bool::BOOL_COMPARISON,
         needless_bool::NEEDLESS_BOOL,
         needless_borrowed_ref::NEEDLESS_BORROWED_REFERENCE,
+        needless_borrows_for_generic_args::NEEDLESS_BORROWS_FOR_GENERIC_ARGS,
         needless_update::NEEDLESS_UPDATE,
         neg_cmp_op_on_partial_ord::NEG_CMP_OP_ON_PARTIAL_ORD,
         neg_multiply::NEG_MULTIPLY,
         new_without_default::NEW_WITHOUT_DEFAULT,
         no_effect::NO_EFFECT,
         no_effect::UNNECESSARY_OPERATION,
+        non_canonical_impls::NON_CANONICAL_CLONE_IMPL,
+        non_canonical_impls::NON_CANONICAL_PARTIAL_ORD_IMPL,
         non_copy_const::BORROW_INTERIOR_MUTABLE_CONST,
         non_copy_const::DECLARE_INTERIOR_MUTABLE_CONST,
         non_expressive_names::JUST_UNDERSCORES_AND_DIGITS,
         non_expressive_names::MANY_SINGLE_CHAR_NAMES,
+        obfuscated_if_else::OBFUSCATED_IF_ELSE,
         ok_if_let::IF_LET_SOME_RESULT,
         open_options::NONSENSICAL_OPEN_OPTIONS,
         overflow_check_conditional::OVERFLOW_CHECK_CONDITIONAL,
@@ -814,6 +1088,8 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         ranges::RANGE_MINUS_ONE,
         ranges::RANGE_PLUS_ONE,
         ranges::RANGE_ZIP_WITH_LEN,
+        read_zero_byte_vec::READ_ZERO_BYTE_VEC,
+        readonly_write_lock::READONLY_WRITE_LOCK,
         redundant_field_names::REDUNDANT_FIELD_NAMES,
         redundant_pattern_matching::REDUNDANT_PATTERN_MATCHING,
         reference::DEREF_ADDROF,
@@ -821,6 +1097,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         regex::INVALID_REGEX,
         regex::REGEX_MACRO,
         regex::TRIVIAL_REGEX,
+        result_large_err::RESULT_LARGE_ERR,
         returns::LET_AND_RETURN,
         returns::NEEDLESS_RETURN,
         returns::UNUSED_UNIT,
@@ -832,6 +1109,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         swap::ALMOST_SWAPPED,
         swap::MANUAL_SWAP,
         temporary_assignment::TEMPORARY_ASSIGNMENT,
+        thread_local_initializer_can_be_made_const::THREAD_LOCAL_INITIALIZER_CAN_BE_MADE_CONST,
         transmute::CROSSPOINTER_TRANSMUTE,
         transmute::TRANSMUTE_BYTES_TO_STR,
         transmute::TRANSMUTE_INT_TO_BOOL,
@@ -842,6 +1120,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         transmute::USELESS_TRANSMUTE,
         transmute::WRONG_TRANSMUTE,
         trivially_copy_pass_by_ref::TRIVIALLY_COPY_PASS_BY_REF,
+        type_conventions::SELF_NAMED_CONSTRUCTORS,
         types::ABSURD_EXTREME_COMPARISONS,
         types::BORROWED_BOX,
         types::BOX_VEC,
@@ -859,11 +1138,13 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         types::UNIT_CMP,
         types::UNNECESSARY_CAST,
         types::VEC_BOX,
+        unconditional_recursion::UNCONDITIONAL_RECURSION,
         unicode::ZERO_WIDTH_SPACE,
         unsafe_removed_from_name::UNSAFE_REMOVED_FROM_NAME,
         unused_io_amount::UNUSED_IO_AMOUNT,
         unused_label::UNUSED_LABEL,
         vec::USELESS_VEC,
+        waker_clone_wake::WAKER_CLONE_WAKE,
         write::PRINTLN_EMPTY_STRING,
         write::PRINT_LITERAL,
         write::PRINT_WITH_NEWLINE,
@@ -876,6 +1157,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
     reg.register_lint_group("clippy::style", Some("clippy_style"), vec![
         assertions_on_constants::ASSERTIONS_ON_CONSTANTS,
         assign_ops::ASSIGN_OP_PATTERN,
+        attrs::DEPRECATED_CLIPPY_CFG_ATTR,
         attrs::UNKNOWN_CLIPPY_LINTS,
         bit_mask::VERBOSE_BIT_MASK,
         blacklisted_name::BLACKLISTED_NAME,
@@ -883,6 +1165,9 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         block_in_if_condition::BLOCK_IN_IF_CONDITION_STMT,
         collapsible_if::COLLAPSIBLE_IF,
         const_static_lifetime::CONST_STATIC_LIFETIME,
+        doc_comments::DOC_COMMENT_IN_MACRO_MATCHER,
+        doc_comments::EMPTY_LINE_AFTER_DOC_COMMENTS,
+        doc_comments::FOUR_FORWARD_SLASHES,
         enum_variants::ENUM_VARIANT_NAMES,
         enum_variants::MODULE_INCEPTION,
         eq_op::OP_REF,
@@ -891,6 +1176,8 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         formatting::SUSPICIOUS_ASSIGNMENT_FORMATTING,
         formatting::SUSPICIOUS_ELSE_FORMATTING,
         infallible_destructuring_match::INFALLIBLE_DESTRUCTURING_MATCH,
+        items_after_test_module::ITEMS_AFTER_TEST_MODULE,
+        legacy_numeric_constants::LEGACY_NUMERIC_CONSTANTS,
         len_zero::LEN_WITHOUT_IS_EMPTY,
         len_zero::LEN_ZERO,
         let_if_seq::USELESS_LET_IF_SEQ,
@@ -900,6 +1187,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         loops::FOR_KV_MAP,
         loops::NEEDLESS_RANGE_LOOP,
         loops::WHILE_LET_ON_ITERATOR,
+        manual_is_ascii_check::MANUAL_IS_ASCII_CHECK,
         map_clone::MAP_CLONE,
         matches::MATCH_BOOL,
         matches::MATCH_OVERLAPPING_ARM,
@@ -908,12 +1196,16 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         matches::SINGLE_MATCH,
         mem_replace::MEM_REPLACE_OPTION_WITH_NONE,
         methods::CHARS_LAST_CMP,
+        methods::FILTER_MAP_BOOL_THEN,
         methods::GET_UNWRAP,
         methods::INTO_ITER_ON_REF,
         methods::ITER_CLONED_COLLECT,
         methods::ITER_SKIP_NEXT,
+        methods::MANUAL_RESULT_OK,
         methods::NEW_RET_NO_SELF,
         methods::OK_EXPECT,
+        methods::OK_UNWRAP_OR,
+        methods::OPTION_MAP_OR_ERR_OK,
         methods::OPTION_MAP_OR_NONE,
         methods::SHOULD_IMPLEMENT_TRAIT,
         methods::STRING_EXTEND_CHARS,
@@ -927,11 +1219,17 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         misc_early::DUPLICATE_UNDERSCORE_ARGUMENT,
         misc_early::MIXED_CASE_HEX_LITERALS,
         misc_early::UNNEEDED_FIELD_PATTERN,
+        must_use::DOUBLE_MUST_USE,
+        must_use::MUST_USE_UNIT,
         mut_reference::UNNECESSARY_MUT_PASSED,
+        needless_borrows_for_generic_args::NEEDLESS_BORROWS_FOR_GENERIC_ARGS,
         neg_multiply::NEG_MULTIPLY,
         new_without_default::NEW_WITHOUT_DEFAULT,
+        non_canonical_impls::NON_CANONICAL_CLONE_IMPL,
+        non_canonical_impls::NON_CANONICAL_PARTIAL_ORD_IMPL,
         non_expressive_names::JUST_UNDERSCORES_AND_DIGITS,
         non_expressive_names::MANY_SINGLE_CHAR_NAMES,
+        obfuscated_if_else::OBFUSCATED_IF_ELSE,
         ok_if_let::IF_LET_SOME_RESULT,
         panic_unimplemented::PANIC_PARAMS,
         ptr::CMP_NULL,
@@ -945,6 +1243,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         returns::NEEDLESS_RETURN,
         returns::UNUSED_UNIT,
         strings::STRING_LIT_AS_BYTES,
+        type_conventions::SELF_NAMED_CONSTRUCTORS,
         types::FN_TO_NUMERIC_CAST,
         types::FN_TO_NUMERIC_CAST_WITH_TRUNCATION,
         types::IMPLICIT_HASHER,
@@ -963,8 +1262,12 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         attrs::DEPRECATED_CFG_ATTR,
         booleans::NONMINIMAL_BOOL,
         cyclomatic_complexity::CYCLOMATIC_COMPLEXITY,
+        default_constructed_unit_structs::DEFAULT_CONSTRUCTED_UNIT_STRUCTS,
+        derivable_impls::DERIVABLE_IMPLS,
         double_comparison::DOUBLE_COMPARISONS,
         double_parens::DOUBLE_PARENS,
+        drop_forget_ref::DROP_NON_DROP,
+        drop_forget_ref::FORGET_NON_DROP,
         duration_subsec::DURATION_SUBSEC,
         eval_order_dependence::DIVERGING_SUB_EXPRESSION,
         eval_order_dependence::EVAL_ORDER_DEPENDENCE,
@@ -979,14 +1282,22 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         loops::EXPLICIT_COUNTER_LOOP,
         loops::MUT_RANGE_BOUND,
         loops::WHILE_LET_LOOP,
+        manual_c_str_literals::MANUAL_C_STR_LITERALS,
+        manual_hash_one::MANUAL_HASH_ONE,
+        manual_slice_size_calculation::MANUAL_SLICE_SIZE_CALCULATION,
         map_unit_fn::OPTION_MAP_UNIT_FN,
         map_unit_fn::RESULT_MAP_UNIT_FN,
         matches::MATCH_AS_REF,
+        mem_swap::SWAP_WITH_TEMPORARY,
         methods::CHARS_NEXT_CMP,
         methods::CLONE_ON_COPY,
         methods::FILTER_NEXT,
+        methods::REDUNDANT_AS_STR,
         methods::SEARCH_IS_SOME,
+        methods::SEEK_FROM_CURRENT,
+        methods::SEEK_TO_START_INSTEAD_OF_REWIND,
         methods::UNNECESSARY_FILTER_MAP,
+        methods::UNUSED_ROUNDING,
         methods::USELESS_ASREF,
         misc::SHORT_CIRCUIT_STATEMENT,
         misc_early::REDUNDANT_CLOSURE_CALL,
@@ -1049,6 +1360,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         erasing_op::ERASING_OP,
         formatting::POSSIBLE_MISSING_COMMA,
         functions::NOT_UNSAFE_PTR_ARG_DEREF,
+        incompatible_msrv::INCOMPATIBLE_MSRV,
         indexing_slicing::OUT_OF_BOUNDS_INDEXING,
         infinite_iter::INFINITE_ITER,
         inline_fn_without_body::INLINE_FN_WITHOUT_BODY,
@@ -1061,6 +1373,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         loops::REVERSE_RANGE_LOOP,
         loops::WHILE_IMMUTABLE_CONDITION,
         mem_discriminant::MEM_DISCRIMINANT_NON_ENUM,
+        mem_swap::NOOP_SWAP,
         methods::CLONE_DOUBLE_REF,
         methods::INTO_ITER_ON_ARRAY,
         methods::TEMPORARY_CSTRING_AS_PTR,
@@ -1073,6 +1386,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         open_options::NONSENSICAL_OPEN_OPTIONS,
         ptr::MUT_FROM_REF,
         ranges::ITERATOR_STEP_BY_ZERO,
+        read_zero_byte_vec::READ_ZERO_BYTE_VEC,
         regex::INVALID_REGEX,
         serde_api::SERDE_API_MISUSE,
         suspicious_trait_impl::SUSPICIOUS_ARITHMETIC_IMPL,
@@ -1083,6 +1397,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         types::CAST_PTR_ALIGNMENT,
         types::CAST_REF_TO_MUT,
         types::UNIT_CMP,
+        unconditional_recursion::UNCONDITIONAL_RECURSION,
         unicode::ZERO_WIDTH_SPACE,
         unused_io_amount::UNUSED_IO_AMOUNT,
     ]);
@@ -1090,49 +1405,128 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
     reg.register_lint_group("clippy::perf", Some("clippy_perf"), vec![
         bytecount::NAIVE_BYTECOUNT,
         entry::MAP_ENTRY,
+        entry::SET_CONTAINS_OR_INSERT,
         escape::BOXED_LOCAL,
         large_enum_variant::LARGE_ENUM_VARIANT,
         loops::MANUAL_MEMCPY,
         loops::NEEDLESS_COLLECT,
         loops::UNUSED_COLLECT,
+        manual_retain::MANUAL_RETAIN,
         methods::EXPECT_FUN_CALL,
         methods::ITER_NTH,
         methods::OR_FUN_CALL,
         methods::SINGLE_CHAR_PATTERN,
+        methods::UNNECESSARY_TO_OWNED,
         misc::CMP_OWNED,
         mutex_atomic::MUTEX_ATOMIC,
+        readonly_write_lock::READONLY_WRITE_LOCK,
+        result_large_err::RESULT_LARGE_ERR,
         slow_vector_initialization::SLOW_VECTOR_INITIALIZATION,
+        thread_local_initializer_can_be_made_const::THREAD_LOCAL_INITIALIZER_CAN_BE_MADE_CONST,
         trivially_copy_pass_by_ref::TRIVIALLY_COPY_PASS_BY_REF,
         types::BOX_VEC,
         vec::USELESS_VEC,
+        waker_clone_wake::WAKER_CLONE_WAKE,
     ]);
 
     reg.register_lint_group("clippy::cargo", Some("clippy_cargo"), vec![
         cargo_common_metadata::CARGO_COMMON_METADATA,
         multiple_crate_versions::MULTIPLE_CRATE_VERSIONS,
+        unknown_features::UNKNOWN_FEATURES,
         wildcard_dependencies::WILDCARD_DEPENDENCIES,
     ]);
 
     reg.register_lint_group("clippy::nursery", Some("clippy_nursery"), vec![
         attrs::EMPTY_LINE_AFTER_OUTER_ATTR,
+        collection_is_never_read::COLLECTION_IS_NEVER_READ,
         fallible_impl_from::FALLIBLE_IMPL_FROM,
         missing_const_for_fn::MISSING_CONST_FOR_FN,
         mutex_atomic::MUTEX_INTEGER,
         needless_borrow::NEEDLESS_BORROW,
+        needless_pass_by_ref_mut::NEEDLESS_PASS_BY_REF_MUT,
         redundant_clone::REDUNDANT_CLONE,
+        suspicious_operation_groupings::SUSPICIOUS_OPERATION_GROUPINGS,
+        tuple_array_conversions::TUPLE_ARRAY_CONVERSIONS,
         unwrap::PANICKING_UNWRAP,
         unwrap::UNNECESSARY_UNWRAP,
     ]);
+
+    utils::generated::init(
+        ["clippy::style", "clippy::pedantic"]
+            .iter()
+            .flat_map(|group| reg.lint_groups[group].0.iter().cloned()),
+        &conf.generated_files,
+    );
+    let override_errors = utils::overrides::init(
+        &conf.overrides,
+        reg.lint_groups
+            .iter()
+            .filter(|(name, _)| name.starts_with("clippy::"))
+            .map(|(name, (lints, _))| (*name, &lints[..])),
+    );
+    for error in override_errors {
+        reg.sess
+            .struct_err(&format!("error reading Clippy's configuration file: {}", error))
+            .emit();
+    }
+    let (custom_groups, group_errors) = utils::custom_groups::expand(
+        &conf.groups,
+        reg.lint_groups
+            .iter()
+            .filter(|(name, _)| name.starts_with("clippy::"))
+            .map(|(name, (lints, _))| (*name, &lints[..])),
+    );
+    for error in group_errors {
+        reg.sess
+            .struct_err(&format!("error reading Clippy's configuration file: {}", error))
+            .emit();
+    }
+    for (name, lints) in custom_groups {
+        // `register_lint_group` takes `&'static Lint`s, and the registry needs `'static` names
+        reg.lint_groups.insert(utils::custom_groups::intern(name), (lints, None));
+    }
 }
 
 /// Register renamed lints.
 ///
-/// Used in `./src/driver.rs`.
+/// Used in `driver::register_lints`.
 pub fn register_renamed(ls: &mut rustc::lint::LintStore) {
     ls.register_renamed("clippy::stutter", "clippy::module_name_repetitions");
     ls.register_renamed("clippy::new_without_default_derive", "clippy::new_without_default");
 }
 
+/// Only emit lints in the given files, see `utils::changed_files`.
+///
+/// Used in `./src/driver.rs`.
+pub fn set_changed_files(files: impl IntoIterator<Item = std::path::PathBuf>) {
+    utils::changed_files::init(files);
+}
+
+/// Report what happened to the lints at a line, see `utils::explain`.
+///
+/// Used in `./src/driver.rs`.
+pub fn set_explain_span(spec: &str) -> Result<(), String> {
+    utils::explain::init(spec)
+}
+
+/// Check that the machine-applicable fixes still parse, see
+/// `utils::fix_dry_run`.
+///
+/// Used in `./src/driver.rs`.
+pub fn set_fix_dry_run() {
+    utils::fix_dry_run::init();
+}
+
+/// Forget the options set by `set_changed_files`, `set_explain_span` and
+/// `set_fix_dry_run`, for processes checking several crates.
+///
+/// Used in `./src/driver.rs`.
+pub fn reset_options() {
+    utils::changed_files::reset();
+    utils::explain::reset();
+    utils::fix_dry_run::reset();
+}
+
 // only exists to let the dogfood integration test works.
 // Don't run clippy as an executable directly
 #[allow(dead_code)]
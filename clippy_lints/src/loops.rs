@@ -27,9 +27,10 @@ use syntax_pos::BytePos;
 
 use crate::utils::paths;
 use crate::utils::{
-    get_enclosing_block, get_parent_expr, has_iter_method, higher, is_integer_literal, is_refutable, last_path_segment,
-    match_trait_method, match_type, match_var, multispan_sugg, snippet, snippet_opt, snippet_with_applicability,
-    span_help_and_lint, span_lint, span_lint_and_sugg, span_lint_and_then, SpanlessEq,
+    get_enclosing_block, get_parent_expr, get_trait_def_id, has_iter_method, higher, hir_ancestors, implements_trait,
+    is_integer_literal, is_refutable, last_path_segment, match_qpath, match_trait_method, match_type, match_var,
+    multispan_sugg, return_ty, snippet, snippet_opt, snippet_ref, snippet_with_applicability, span_help_and_lint,
+    span_lint, span_lint_and_sugg, span_lint_and_then, SpanlessEq,
 };
 
 declare_clippy_lint! {
@@ -439,6 +440,68 @@ declare_clippy_lint! {
     "variables used within while expression are not mutated in the body"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for `for` loops pushing the `Ok` values of a
+    /// fallible expression into a new `Vec`, returning at the first error,
+    /// either with `?` or with a `match`.
+    ///
+    /// **Why is this bad?** Collecting an iterator of `Result`s into a
+    /// `Result<Vec<_>, _>` does the same: it stops at the first error and
+    /// returns it. This says what is meant, and the `Vec` doesn't need to be
+    /// mutable.
+    ///
+    /// **Known problems:** Only loops directly after the declaration of the
+    /// `Vec` are linted.
+    ///
+    /// **Example:**
+    /// ```ignore
+    /// let mut numbers = Vec::new();
+    /// for s in &strings {
+    ///     numbers.push(s.parse::<u32>()?);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```ignore
+    /// let numbers = strings.iter().map(|s| s.parse::<u32>()).collect::<Result<Vec<_>, _>>()?;
+    /// ```
+    pub MANUAL_TRY_COLLECT,
+    pedantic,
+    "pushing the `Ok` values of a loop into a `Vec` when collecting into a `Result` would do"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `loop`s that can't be left, with no
+    /// `break`, `return`, `?` or diverging call like `panic!()`, in functions
+    /// not returning `!`.
+    ///
+    /// **Why is this bad?** A function that never returns should say so with
+    /// a return type of `!`. Otherwise, the loop may be missing its exit
+    /// condition.
+    ///
+    /// **Known problems:** Exits that can't be reached still count. A closure
+    /// that always panics is not seen as an exit when it is called.
+    ///
+    /// **Example:**
+    /// ```ignore
+    /// fn run_forever() {
+    ///     loop {
+    ///         serve();
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```ignore
+    /// fn run_forever() -> ! {
+    ///     loop {
+    ///         serve();
+    ///     }
+    /// }
+    /// ```
+    pub INFINITE_LOOP,
+    restriction,
+    "a `loop` without any exit in a function that doesn't return `!`"
+}
+
 #[derive(Copy, Clone)]
 pub struct Pass;
 
@@ -463,6 +526,8 @@ impl LintPass for Pass {
             NEVER_LOOP,
             MUT_RANGE_BOUND,
             WHILE_IMMUTABLE_CONDITION,
+            MANUAL_TRY_COLLECT,
+            INFINITE_LOOP,
         )
     }
 
@@ -575,20 +640,25 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                             && !is_iterator_used_after_while_let(cx, iter_expr)
                             && !is_nested(cx, expr, &method_args[0]))
                 {
-                    let iterator = snippet(cx, method_args[0].span, "_");
-                    let loop_var = if pat_args.is_empty() {
-                        "_".to_string()
-                    } else {
-                        snippet(cx, pat_args[0].span, "_").into_owned()
-                    };
-                    span_lint_and_sugg(
+                    span_lint_and_then(
                         cx,
                         WHILE_LET_ON_ITERATOR,
                         expr.span,
                         "this loop could be written as a `for` loop",
-                        "try",
-                        format!("for {} in {} {{ .. }}", loop_var, iterator),
-                        Applicability::HasPlaceholders,
+                        |db| {
+                            let iterator = snippet(cx, method_args[0].span, "_");
+                            let loop_var = if pat_args.is_empty() {
+                                "_".to_string()
+                            } else {
+                                snippet(cx, pat_args[0].span, "_").into_owned()
+                            };
+                            db.span_suggestion(
+                                expr.span,
+                                "try",
+                                format!("for {} in {} {{ .. }}", loop_var, iterator),
+                                Applicability::HasPlaceholders,
+                            );
+                        },
                     );
                 }
             }
@@ -599,6 +669,10 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
             check_infinite_loop(cx, cond, expr);
         }
 
+        if let ExprKind::Loop(ref block, _, LoopSource::Loop) = expr.node {
+            check_loop_without_exit(cx, block, expr);
+        }
+
         check_needless_collect(expr, cx);
     }
 
@@ -782,6 +856,231 @@ fn check_for_loop<'a, 'tcx>(
     check_for_loop_over_map_kv(cx, pat, arg, body, expr);
     check_for_mut_range_bound(cx, arg, body);
     detect_manual_memcpy(cx, pat, arg, body, expr);
+    check_for_loop_try_collect(cx, pat, arg, body, expr);
+}
+
+/// Check for the `MANUAL_TRY_COLLECT` lint.
+fn check_for_loop_try_collect<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    pat: &'tcx Pat,
+    arg: &'tcx Expr,
+    body: &'tcx Expr,
+    expr: &'tcx Expr,
+) {
+    // the loop body must be a single `v.push(f(x)?)`, or a `match` pushing `Ok` values and returning
+    // errors
+    let inner = match body.node {
+        ExprKind::Block(ref block, _) => match (&*block.stmts, &block.expr) {
+            ([], Some(inner)) => inner,
+            ([stmt], None) => match stmt.node {
+                StmtKind::Expr(ref inner) | StmtKind::Semi(ref inner) => inner,
+                _ => return,
+            },
+            _ => return,
+        },
+        _ => return,
+    };
+    let (vec, fallible) = match inner.node {
+        ExprKind::MethodCall(..) => match pushed_value(cx, inner) {
+            Some((vec, pushed)) => match try_operand(pushed) {
+                Some(fallible) => (vec, fallible),
+                None => return,
+            },
+            None => return,
+        },
+        ExprKind::Match(ref scrutinee, ref arms, MatchSource::Normal) => match pushed_ok_value(cx, arms) {
+            Some(vec) => (vec, &**scrutinee),
+            None => return,
+        },
+        _ => return,
+    };
+    if !match_type(cx, cx.tables.expr_ty(fallible), &paths::RESULT) {
+        return;
+    }
+    let vec_id = if let Some(vec_id) = var_def_id(cx, vec) {
+        cx.tcx.hir().hir_to_node_id(vec_id)
+    } else {
+        return;
+    };
+    let mut used = LocalUsedVisitor {
+        cx,
+        local: vec_id,
+        used: false,
+    };
+    walk_expr(&mut used, fallible);
+    if used.used {
+        return;
+    }
+
+    // the `Vec` must be created empty right before the loop
+    let block = match get_enclosing_block(cx, expr.hir_id) {
+        Some(block) => block,
+        None => return,
+    };
+    let index = match block.stmts.iter().position(|stmt| match stmt.node {
+        StmtKind::Expr(ref e) | StmtKind::Semi(ref e) => e.hir_id == expr.hir_id,
+        _ => false,
+    }) {
+        Some(index) if index > 0 => index,
+        _ => return,
+    };
+    if_chain! {
+        if let StmtKind::Local(ref local) = block.stmts[index - 1].node;
+        if let PatKind::Binding(BindingAnnotation::Mutable, id, _, ident, None) = local.pat.node;
+        if id == vec_id;
+        if let Some(ref init) = local.init;
+        if is_new_vec(cx, init);
+        if !in_macro(local.span);
+        then {
+            span_lint_and_then(
+                cx,
+                MANUAL_TRY_COLLECT,
+                expr.span,
+                &format!("this loop pushes the `Ok` values into `{}` until an error occurs", ident),
+                |db| {
+                    // the `Vec` is no longer mutable, but may be mutated after the loop
+                    let mut applicability = Applicability::MaybeIncorrect;
+                    let iter = iter_sugg(cx, arg, &mut applicability);
+                    let pat = snippet_with_applicability(cx, pat.span, "_", &mut applicability);
+                    let fallible = snippet_with_applicability(cx, fallible.span, "..", &mut applicability);
+                    let ty = local.ty.as_ref().map_or(String::new(), |ty| {
+                        format!(": {}", snippet_with_applicability(cx, ty.span, "_", &mut applicability))
+                    });
+                    db.span_suggestion(
+                        local.span.to(expr.span),
+                        "collect into a `Result` instead",
+                        format!(
+                            "let {}{} = {}.map(|{}| {}).collect::<Result<Vec<_>, _>>()?;",
+                            ident, ty, iter, pat, fallible
+                        ),
+                        applicability,
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// Matches `v.push(value)` on a `Vec`, returning `v` and the value.
+fn pushed_value<'a>(cx: &LateContext<'_, '_>, expr: &'a Expr) -> Option<(&'a Expr, &'a Expr)> {
+    if_chain! {
+        if let ExprKind::MethodCall(ref method, _, ref args) = expr.node;
+        if method.ident.name == "push" && args.len() == 2;
+        if match_type(cx, cx.tables.expr_ty(&args[0]), &paths::VEC);
+        then {
+            return Some((&args[0], &args[1]));
+        }
+    }
+    None
+}
+
+/// The operand of the `?` operator in `expr`.
+fn try_operand(expr: &Expr) -> Option<&Expr> {
+    if_chain! {
+        if let ExprKind::Match(ref match_arg, _, MatchSource::TryDesugar) = expr.node;
+        if let ExprKind::Call(ref match_fun, ref try_args) = match_arg.node;
+        if let ExprKind::Path(ref match_fun_path) = match_fun.node;
+        if match_qpath(match_fun_path, &paths::TRY_INTO_RESULT);
+        if let [ref operand] = **try_args;
+        then {
+            return Some(operand);
+        }
+    }
+    None
+}
+
+/// Matches the arms `Ok(x) => v.push(x), Err(e) => return Err(e)` in any order,
+/// returning `v`.
+fn pushed_ok_value<'a>(cx: &LateContext<'_, '_>, arms: &'a [Arm]) -> Option<&'a Expr> {
+    if arms.len() != 2 || arms.iter().any(|arm| arm.pats.len() != 1 || arm.guard.is_some()) {
+        return None;
+    }
+    let binding = |arm: &Arm, path: &[&str]| match arm.pats[0].node {
+        PatKind::TupleStruct(ref qpath, ref pats, None) if match_qpath(qpath, &path[1..]) => match **pats {
+            [ref pat] => match pat.node {
+                PatKind::Binding(BindingAnnotation::Unannotated, id, _, _, None) => Some(id),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    };
+    let (ok, err) = if binding(&arms[0], &paths::RESULT_OK).is_some() {
+        (&arms[0], &arms[1])
+    } else {
+        (&arms[1], &arms[0])
+    };
+    let (ok_id, err_id) = (binding(ok, &paths::RESULT_OK)?, binding(err, &paths::RESULT_ERR)?);
+
+    let (vec, pushed) = pushed_value(cx, unwrap_block(&ok.body)?)?;
+    if !same_var(cx, pushed, ok_id) {
+        return None;
+    }
+    if_chain! {
+        if let ExprKind::Ret(Some(ref ret)) = unwrap_block(&err.body)?.node;
+        if let ExprKind::Call(ref fun, ref args) = ret.node;
+        if let ExprKind::Path(ref fun_path) = fun.node;
+        if match_qpath(fun_path, &paths::RESULT_ERR[1..]);
+        if let [ref returned] = **args;
+        if same_var(cx, returned, err_id);
+        then {
+            return Some(vec);
+        }
+    }
+    None
+}
+
+/// The expression of a block consisting of only one expression or statement,
+/// or the expression itself if it isn't a block.
+fn unwrap_block(expr: &Expr) -> Option<&Expr> {
+    if let ExprKind::Block(ref block, _) = expr.node {
+        match (&*block.stmts, &block.expr) {
+            ([], Some(inner)) => Some(inner),
+            ([stmt], None) => match stmt.node {
+                StmtKind::Expr(ref inner) | StmtKind::Semi(ref inner) => Some(inner),
+                _ => None,
+            },
+            _ => None,
+        }
+    } else {
+        Some(expr)
+    }
+}
+
+/// Whether `expr` creates an empty `Vec`, like `Vec::new()`, `vec![]` or
+/// `Vec::with_capacity(n)`.
+fn is_new_vec(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    if let ExprKind::Call(ref fun, _) = expr.node {
+        if let ExprKind::Path(ref qpath) = fun.node {
+            let name = last_path_segment(qpath).ident.name;
+            return (name == "new" || name == "with_capacity") && match_type(cx, cx.tables.expr_ty(expr), &paths::VEC);
+        }
+    }
+    false
+}
+
+/// The iterator a `for` loop over `arg` iterates.
+fn iter_sugg(cx: &LateContext<'_, '_>, arg: &Expr, applicability: &mut Applicability) -> String {
+    match arg.node {
+        ExprKind::AddrOf(mutability, ref inner) => {
+            let method = if mutability == MutMutable { "iter_mut" } else { "iter" };
+            format!(
+                "{}.{}()",
+                sugg::Sugg::hir_with_applicability(cx, inner, "..", applicability).maybe_par(),
+                method
+            )
+        },
+        _ => {
+            let iter = sugg::Sugg::hir_with_applicability(cx, arg, "..", applicability).maybe_par();
+            let is_iterator = get_trait_def_id(cx, &paths::ITERATOR)
+                .map_or(false, |id| implements_trait(cx, cx.tables.expr_ty(arg), id, &[]));
+            if is_iterator {
+                iter.to_string()
+            } else {
+                format!("{}.into_iter()", iter)
+            }
+        },
+    }
 }
 
 fn same_var<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &Expr, var: ast::NodeId) -> bool {
@@ -973,7 +1272,7 @@ fn detect_manual_memcpy<'a, 'tcx>(
         if let PatKind::Binding(_, canonical_id, _, _, _) = pat.node {
             let print_sum = |arg1: &Offset, arg2: &Offset| -> String {
                 match (&arg1.value[..], arg1.negate, &arg2.value[..], arg2.negate) {
-                    ("0", _, "0", _) => "".into(),
+                    ("0", _, "0", _) => String::new(),
                     ("0", _, x, false) | (x, false, "0", false) => x.into(),
                     ("0", _, x, true) | (x, false, "0", true) => format!("-{}", x),
                     (x, false, y, false) => format!("({} + {})", x, y),
@@ -1299,8 +1598,8 @@ fn check_for_loop_reverse_range<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, arg: &'tcx
                 };
 
                 if sup {
-                    let start_snippet = snippet(cx, start.span, "_");
-                    let end_snippet = snippet(cx, end.span, "_");
+                    let start_snippet = snippet_ref(cx, start.span, "_");
+                    let end_snippet = snippet_ref(cx, end.span, "_");
                     let dots = if limits == ast::RangeLimits::Closed {
                         "..."
                     } else {
@@ -2233,19 +2532,15 @@ fn is_loop_nested(cx: &LateContext<'_, '_>, loop_expr: &Expr, iter_expr: &Expr)
     } else {
         return true;
     };
-    loop {
-        let parent = cx.tcx.hir().get_parent_node_by_hir_id(id);
-        if parent == id {
-            return false;
-        }
-        match cx.tcx.hir().find_by_hir_id(parent) {
-            Some(Node::Expr(expr)) => match expr.node {
+    for (parent, node) in hir_ancestors(cx, loop_expr.hir_id) {
+        match node {
+            Node::Expr(expr) => match expr.node {
                 ExprKind::Loop(..) | ExprKind::While(..) => {
                     return true;
                 },
                 _ => (),
             },
-            Some(Node::Block(block)) => {
+            Node::Block(block) => {
                 let mut block_visitor = LoopNestVisitor {
                     hir_id: id,
                     iterator: iter_name,
@@ -2256,13 +2551,14 @@ fn is_loop_nested(cx: &LateContext<'_, '_>, loop_expr: &Expr, iter_expr: &Expr)
                     return false;
                 }
             },
-            Some(Node::Stmt(_)) => (),
+            Node::Stmt(_) => (),
             _ => {
                 return false;
             },
         }
         id = parent;
     }
+    false
 }
 
 #[derive(PartialEq, Eq)]
@@ -2369,6 +2665,114 @@ fn check_infinite_loop<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, cond: &'tcx Expr, e
     }
 }
 
+fn check_loop_without_exit<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, block: &'tcx Block, expr: &'tcx Expr) {
+    // `loop {}` is linted by `EMPTY_LOOP`
+    if block.stmts.is_empty() && block.expr.is_none() {
+        return;
+    }
+    let (fn_id, decl) = match enclosing_fn_decl(cx, expr) {
+        Some(fn_decl) => fn_decl,
+        None => return,
+    };
+    if return_ty(cx, fn_id).is_never() {
+        return;
+    }
+    let mut visitor = LoopExitVisitor {
+        cx,
+        inner_targets: Vec::new(),
+        found: false,
+    };
+    walk_block(&mut visitor, block);
+    if visitor.found {
+        return;
+    }
+    span_lint_and_then(cx, INFINITE_LOOP, expr.span, "infinite loop detected", |db| {
+        if let FunctionRetTy::DefaultReturn(span) = decl.output {
+            // the span is empty, at the start of the body
+            db.span_suggestion(
+                span,
+                "if this is intentional, consider specifying `!` as function return",
+                "-> ! ".to_string(),
+                Applicability::MaybeIncorrect,
+            );
+        } else {
+            db.help("if this is not intended, try adding a `break` or `return` condition in the loop");
+        }
+    });
+}
+
+/// The function or method whose body directly contains `expr`, not through
+/// a closure.
+fn enclosing_fn_decl<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &Expr) -> Option<(HirId, &'tcx FnDecl)> {
+    for (hir_id, node) in hir_ancestors(cx, expr.hir_id) {
+        match node {
+            Node::Item(&Item {
+                node: ItemKind::Fn(ref decl, ..),
+                ..
+            }) => return Some((hir_id, decl)),
+            Node::ImplItem(&ImplItem {
+                node: ImplItemKind::Method(ref sig, _),
+                ..
+            })
+            | Node::TraitItem(&TraitItem {
+                node: TraitItemKind::Method(ref sig, _),
+                ..
+            }) => return Some((hir_id, &sig.decl)),
+            Node::Expr(&Expr {
+                node: ExprKind::Closure(..),
+                ..
+            })
+            | Node::Item(_)
+            | Node::ImplItem(_)
+            | Node::TraitItem(_) => return None,
+            _ => {},
+        }
+    }
+    None
+}
+
+/// Looks for a way out of a loop: a `break` to the loop or further out, a
+/// `return` (which `?` desugars to), or a call that diverges. Closures are
+/// not visited, leaving them doesn't leave the loop.
+struct LoopExitVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    /// The loops and labeled blocks in the loop, breaking to them doesn't
+    /// leave it.
+    inner_targets: Vec<ast::NodeId>,
+    found: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for LoopExitVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if self.found {
+            return;
+        }
+        match expr.node {
+            ExprKind::Loop(..) | ExprKind::While(..) | ExprKind::Block(_, Some(_)) => {
+                self.inner_targets.push(self.cx.tcx.hir().hir_to_node_id(expr.hir_id));
+                walk_expr(self, expr);
+                self.inner_targets.pop();
+                return;
+            },
+            ExprKind::Break(dest, _) => {
+                if let Ok(target) = dest.target_id {
+                    self.found |= !self.inner_targets.contains(&target);
+                }
+            },
+            ExprKind::Ret(_) => self.found = true,
+            ExprKind::Call(..) | ExprKind::MethodCall(..) => {
+                self.found |= self.cx.tables.expr_ty(expr).is_never();
+            },
+            _ => {},
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
+
 /// Collects the set of variables in an expression
 /// Stops analysis if a function call is found
 /// Note: In some cases such as `self`, there are no mutable annotation,
@@ -2455,9 +2859,9 @@ fn check_needless_collect<'a, 'tcx>(expr: &'tcx Expr, cx: &LateContext<'a, 'tcx>
                     });
                 }
                 if method.ident.name == "contains" {
-                    let contains_arg = snippet(cx, args[1].span, "??");
                     let span = shorten_needless_collect_span(expr);
                     span_lint_and_then(cx, NEEDLESS_COLLECT, span, NEEDLESS_COLLECT_MSG, |db| {
+                        let contains_arg = snippet(cx, args[1].span, "??");
                         db.span_suggestion(
                             span,
                             "replace with",
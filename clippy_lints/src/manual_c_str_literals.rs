@@ -0,0 +1,202 @@
+use crate::utils::edition::{Edition, EditionExt};
+use crate::utils::{in_macro, match_def_path, msrvs, paths, resolve_node, span_lint_and_sugg};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use semver::Version;
+use syntax::ast::LitKind;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `CStr`s and `CString`s built from string
+    /// literals, like `CStr::from_bytes_with_nul(b"foo\0").unwrap()` or
+    /// `CString::new("foo").unwrap()`.
+    ///
+    /// **Why is this bad?** A `c"foo"` literal is checked for interior nul
+    /// bytes at compile time, it neither needs the trailing `\0` nor an
+    /// `unwrap`. Requires Rust 1.77, the lint respects the `msrv` option and
+    /// only fires if it is set to 1.77 or later.
+    ///
+    /// **Known problems:** `c""` literals also need the 2021 edition, which
+    /// this compiler can't tell apart from 2018. Crates on the 2018 edition
+    /// get a suggestion that doesn't compile there.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// let s = CStr::from_bytes_with_nul(b"foo\0").unwrap();
+    /// let owned = CString::new("foo").unwrap();
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let s = c"foo";
+    /// let owned = c"foo".to_owned();
+    /// ```
+    pub MANUAL_C_STR_LITERALS,
+    complexity,
+    "creating a `CStr` or `CString` from a string literal instead of using a `c\"\"` literal"
+}
+
+pub struct ManualCStrLiterals {
+    msrv: Option<Version>,
+}
+
+impl ManualCStrLiterals {
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl LintPass for ManualCStrLiterals {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_C_STR_LITERALS)
+    }
+
+    fn name(&self) -> &'static str {
+        "ManualCStrLiterals"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ManualCStrLiterals {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span)
+            || cx.edition() < Edition::Edition2018
+            || !msrvs::meets(self.msrv.as_ref(), msrvs::C_STR_LITERALS)
+        {
+            return;
+        }
+        let sugg = match expr.node {
+            // `CStr::from_bytes_with_nul(b"foo\0").unwrap()`, `CString::new("foo").unwrap()`
+            ExprKind::MethodCall(ref path, _, ref args)
+                if path.ident.name == "unwrap" || path.ident.name == "expect" =>
+            {
+                match literal_call(cx, &args[0]) {
+                    Some((Func::FromBytesWithNul, contents)) => nul_terminated(contents).and_then(c_str_literal),
+                    Some((Func::CStringNew, contents)) => {
+                        c_str_literal(contents).map(|lit| format!("{}.to_owned()", lit))
+                    },
+                    _ => None,
+                }
+            },
+            // `CStr::from_bytes_with_nul_unchecked(b"foo\0")`
+            _ => match literal_call(cx, expr) {
+                Some((Func::FromBytesWithNulUnchecked, contents)) => nul_terminated(contents).and_then(c_str_literal),
+                _ => None,
+            },
+        };
+        if let Some(sugg) = sugg {
+            span_lint_and_sugg(
+                cx,
+                MANUAL_C_STR_LITERALS,
+                expr.span,
+                "manually constructing a nul-terminated string",
+                "use a `c\"\"` literal",
+                sugg,
+                // may be the 2018 edition, see the known problems
+                Applicability::MaybeIncorrect,
+            );
+        }
+    }
+}
+
+/// The functions the lint handles.
+enum Func {
+    FromBytesWithNul,
+    FromBytesWithNulUnchecked,
+    CStringNew,
+}
+
+/// The contents of a string or byte string literal.
+enum Contents {
+    Str(String),
+    Bytes(Vec<u8>),
+}
+
+/// The function called in `expr` and the contents of its string literal
+/// argument, if it is one of the functions the lint handles.
+fn literal_call(cx: &LateContext<'_, '_>, expr: &Expr) -> Option<(Func, Contents)> {
+    let (fun, args) = match expr.node {
+        ExprKind::Call(ref fun, ref args) if args.len() == 1 => (fun, args),
+        _ => return None,
+    };
+    let contents = match args[0].node {
+        ExprKind::Lit(ref lit) => match lit.node {
+            LitKind::Str(ref s, _) => Contents::Str(s.as_str().to_string()),
+            LitKind::ByteStr(ref bytes) => Contents::Bytes(bytes.to_vec()),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let def_id = match fun.node {
+        ExprKind::Path(ref qpath) => resolve_node(cx, qpath, fun.hir_id).opt_def_id()?,
+        _ => return None,
+    };
+    let func = if match_def_path(cx.tcx, def_id, &paths::CSTR_FROM_BYTES_WITH_NUL) {
+        Func::FromBytesWithNul
+    } else if match_def_path(cx.tcx, def_id, &paths::CSTR_FROM_BYTES_WITH_NUL_UNCHECKED) {
+        Func::FromBytesWithNulUnchecked
+    } else if match_def_path(cx.tcx, def_id, &paths::CSTRING_NEW) {
+        Func::CStringNew
+    } else {
+        return None;
+    };
+    Some((func, contents))
+}
+
+/// The byte string without the trailing nul byte the `CStr` functions
+/// require.
+fn nul_terminated(contents: Contents) -> Option<Contents> {
+    match contents {
+        Contents::Bytes(mut bytes) => {
+            if bytes.pop() == Some(0) {
+                Some(Contents::Bytes(bytes))
+            } else {
+                None
+            }
+        },
+        Contents::Str(_) => None,
+    }
+}
+
+/// Encodes `contents` as a `c""` literal. `None` if they contain a nul byte,
+/// which `c""` literals can't.
+fn c_str_literal(contents: Contents) -> Option<String> {
+    let mut out = String::from("c\"");
+    match contents {
+        Contents::Str(s) => {
+            for c in s.chars() {
+                if c.is_ascii() {
+                    escape_ascii(c as u8, &mut out)?;
+                } else {
+                    // `c""` literals are UTF-8 encoded, like `str`s
+                    out.push(c);
+                }
+            }
+        },
+        Contents::Bytes(bytes) => {
+            for byte in bytes {
+                if byte.is_ascii() {
+                    escape_ascii(byte, &mut out)?;
+                } else {
+                    out.push_str(&format!("\\x{:02x}", byte));
+                }
+            }
+        },
+    }
+    out.push('"');
+    Some(out)
+}
+
+/// Pushes `byte` to `out`, escaped if needed. `None` for a nul byte.
+fn escape_ascii(byte: u8, out: &mut String) -> Option<()> {
+    match byte {
+        0 => return None,
+        b'"' => out.push_str("\\\""),
+        b'\\' => out.push_str("\\\\"),
+        b'\n' => out.push_str("\\n"),
+        b'\r' => out.push_str("\\r"),
+        b'\t' => out.push_str("\\t"),
+        b' '..=b'~' => out.push(byte as char),
+        _ => out.push_str(&format!("\\x{:02x}", byte)),
+    }
+    Some(())
+}
@@ -0,0 +1,187 @@
+use crate::utils::{contains_name, in_macro, match_trait_method, msrvs, paths, snippet_opt, span_lint_and_sugg};
+use if_chain::if_chain;
+use matches::matches;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty::adjustment::Adjust;
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use semver::Version;
+use syntax::ast::{Name, NodeId};
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for hashers built from a `BuildHasher` only to
+    /// hash a single value and return the hash.
+    ///
+    /// **Why is this bad?** `BuildHasher::hash_one` does the same in one call,
+    /// without a mutable hasher to keep track of. Requires Rust 1.71, the lint
+    /// respects the `msrv` option.
+    ///
+    /// **Known problems:** Only the three statements in a row are linted.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// let mut hasher = state.build_hasher();
+    /// value.hash(&mut hasher);
+    /// let hash = hasher.finish();
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let hash = state.hash_one(&value);
+    /// ```
+    pub MANUAL_HASH_ONE,
+    complexity,
+    "manual implementation of `BuildHasher::hash_one`"
+}
+
+pub struct ManualHashOne {
+    msrv: Option<Version>,
+}
+
+impl ManualHashOne {
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl LintPass for ManualHashOne {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_HASH_ONE)
+    }
+
+    fn name(&self) -> &'static str {
+        "ManualHashOne"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ManualHashOne {
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        if !msrvs::meets(self.msrv.as_ref(), msrvs::BUILD_HASHER_HASH_ONE) {
+            return;
+        }
+        for (index, window) in block.stmts.windows(2).enumerate() {
+            if_chain! {
+                // let mut hasher = state.build_hasher();
+                if let StmtKind::Local(ref local) = window[0].node;
+                if let PatKind::Binding(BindingAnnotation::Mutable, id, _, ident, None) = local.pat.node;
+                if let Some(ref init) = local.init;
+                if let ExprKind::MethodCall(ref path, _, ref args) = init.node;
+                if path.ident.name == "build_hasher" && args.len() == 1;
+                if match_trait_method(cx, init, &paths::BUILD_HASHER);
+                // value.hash(&mut hasher);
+                if let StmtKind::Semi(ref hash) = window[1].node;
+                if let ExprKind::MethodCall(ref path, _, ref hash_args) = hash.node;
+                if path.ident.name == "hash" && hash_args.len() == 2;
+                if let ExprKind::AddrOf(MutMutable, ref hasher) = hash_args[1].node;
+                if is_local(hasher, id);
+                if match_trait_method(cx, hash, &paths::HASH_TRAIT);
+                // hasher.finish(), with no other uses of the hasher
+                let rest = block.stmts[index + 2..]
+                    .iter()
+                    .filter_map(stmt_expr)
+                    .chain(block.expr.as_ref().map(|expr| &**expr));
+                if let Some(finish) = finish_only_use(cx, id, ident.name, rest);
+                if !in_macro(window[0].span) && !in_macro(finish.span);
+                let span = window[0].span.to(finish.span);
+                if let Some(state) = snippet_opt(cx, args[0].span);
+                if let Some(value) = snippet_opt(cx, hash_args[0].span);
+                if let Some(before_finish) = snippet_opt(cx, next_span(block, index + 2).until(finish.span));
+                then {
+                    // the value is borrowed automatically as receiver of `hash`
+                    let value = match cx.tables.expr_adjustments(&hash_args[0]).first() {
+                        Some(adjustment) if matches!(adjustment.kind, Adjust::Borrow(_)) => format!("&{}", value),
+                        _ => value,
+                    };
+                    span_lint_and_sugg(
+                        cx,
+                        MANUAL_HASH_ONE,
+                        span,
+                        "manual implementation of `BuildHasher::hash_one`",
+                        "try",
+                        format!("{}{}.hash_one({})", before_finish, state, value),
+                        Applicability::MachineApplicable,
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn stmt_expr(stmt: &Stmt) -> Option<&Expr> {
+    match stmt.node {
+        StmtKind::Local(ref local) => local.init.as_ref().map(|init| &**init),
+        StmtKind::Expr(ref expr) | StmtKind::Semi(ref expr) => Some(expr),
+        StmtKind::Item(_) => None,
+    }
+}
+
+/// The span of the statement at `index`, or of the trailing expression after
+/// the statements.
+fn next_span(block: &Block, index: usize) -> Span {
+    block
+        .stmts
+        .get(index)
+        .map(|stmt| stmt.span)
+        .or_else(|| block.expr.as_ref().map(|expr| expr.span))
+        .unwrap_or(block.span)
+}
+
+fn is_local(expr: &Expr, id: NodeId) -> bool {
+    if let ExprKind::Path(QPath::Resolved(None, ref path)) = expr.node {
+        if let Def::Local(local) = path.def {
+            return local == id;
+        }
+    }
+    false
+}
+
+/// Returns the `hasher.finish()` call in the first of `exprs`, if that is the
+/// only use of the hasher in all of them.
+fn finish_only_use<'a, 'tcx: 'a>(
+    cx: &LateContext<'a, 'tcx>,
+    id: NodeId,
+    name: Name,
+    mut exprs: impl Iterator<Item = &'tcx Expr>,
+) -> Option<&'tcx Expr> {
+    let mut visitor = HasherUseVisitor {
+        cx,
+        id,
+        finish: None,
+        other_use: false,
+    };
+    visitor.visit_expr(exprs.next()?);
+    let finish = visitor.finish?;
+    if visitor.other_use || exprs.any(|expr| contains_name(name, expr)) {
+        return None;
+    }
+    Some(finish)
+}
+
+struct HasherUseVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    id: NodeId,
+    finish: Option<&'tcx Expr>,
+    other_use: bool,
+}
+
+impl<'a, 'tcx: 'a> Visitor<'tcx> for HasherUseVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if let ExprKind::MethodCall(ref path, _, ref args) = expr.node {
+            if path.ident.name == "finish" && args.len() == 1 && is_local(&args[0], self.id) && self.finish.is_none() {
+                self.finish = Some(expr);
+                return;
+            }
+        }
+        if is_local(expr, self.id) {
+            self.other_use = true;
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir())
+    }
+}
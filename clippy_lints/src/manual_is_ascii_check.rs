@@ -0,0 +1,222 @@
+use crate::consts::{constant_simple, Constant};
+use crate::utils::sugg::Sugg;
+use crate::utils::{get_parent_expr, higher, in_macro, span_lint_and_sugg, walk_ptrs_ty, SpanlessEq};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::{RangeLimits, UintTy};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for manual checks of common ASCII ranges, like
+    /// `('a'..='z').contains(&c)` or `c >= '0' && c <= '9'`, on `char`s and `u8`s.
+    ///
+    /// **Why is this bad?** The `is_ascii_*` methods of `char` and `u8` state the
+    /// intent directly and can't get the bounds wrong.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let c = 'a';
+    /// assert!(('a'..='z').contains(&c));
+    /// assert!(c >= 'a' && c <= 'z' || c >= 'A' && c <= 'Z');
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let c = 'a';
+    /// assert!(c.is_ascii_lowercase());
+    /// assert!(c.is_ascii_alphabetic());
+    /// ```
+    pub MANUAL_IS_ASCII_CHECK,
+    style,
+    "manually checking for a common ASCII range instead of calling an `is_ascii_*` method"
+}
+
+const DIGIT: (u32, u32) = (b'0' as u32, b'9' as u32);
+const UPPER: (u32, u32) = (b'A' as u32, b'Z' as u32);
+const LOWER: (u32, u32) = (b'a' as u32, b'z' as u32);
+const HEX_UPPER: (u32, u32) = (b'A' as u32, b'F' as u32);
+const HEX_LOWER: (u32, u32) = (b'a' as u32, b'f' as u32);
+
+/// The `is_ascii_*` methods with the ranges they check, sorted by their lower bound.
+const ASCII_CHECKS: [(&[(u32, u32)], &str); 6] = [
+    (&[DIGIT], "is_ascii_digit"),
+    (&[UPPER], "is_ascii_uppercase"),
+    (&[LOWER], "is_ascii_lowercase"),
+    (&[UPPER, LOWER], "is_ascii_alphabetic"),
+    (&[DIGIT, UPPER, LOWER], "is_ascii_alphanumeric"),
+    (&[DIGIT, HEX_UPPER, HEX_LOWER], "is_ascii_hexdigit"),
+];
+
+#[derive(Copy, Clone)]
+pub struct ManualIsAsciiCheck;
+
+impl LintPass for ManualIsAsciiCheck {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_IS_ASCII_CHECK)
+    }
+
+    fn name(&self) -> &'static str {
+        "ManualIsAsciiCheck"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ManualIsAsciiCheck {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        // the operands of `||` are checked together with the whole chain
+        if let Some(parent) = get_parent_expr(cx, expr) {
+            if let ExprKind::Binary(op, _, _) = parent.node {
+                if op.node == BinOpKind::Or {
+                    return;
+                }
+            }
+        }
+
+        let mut operands = Vec::new();
+        flatten_or(expr, &mut operands);
+        let checks: Vec<_> = operands.iter().map(|operand| range_check(cx, operand)).collect();
+        if operands.len() > 1 {
+            if let Some(Some((subject, _))) = checks.first() {
+                let mut ranges = Vec::new();
+                for check in &checks {
+                    match *check {
+                        Some((other, range)) if SpanlessEq::new(cx).ignore_fn().eq_expr(subject, other) => {
+                            ranges.push(range);
+                        },
+                        _ => break,
+                    }
+                }
+                if ranges.len() == checks.len() {
+                    if let Some(method) = ascii_method(ranges) {
+                        lint(cx, expr, subject, method);
+                        return;
+                    }
+                }
+            }
+        }
+        for (operand, check) in operands.iter().zip(checks) {
+            if let Some((subject, range)) = check {
+                if let Some(method) = ascii_method(vec![range]) {
+                    lint(cx, operand, subject, method);
+                }
+            }
+        }
+    }
+}
+
+fn lint(cx: &LateContext<'_, '_>, expr: &Expr, subject: &Expr, method: &str) {
+    let mut applicability = Applicability::MachineApplicable;
+    let subject = Sugg::hir_with_applicability(cx, subject, "..", &mut applicability).maybe_par();
+    span_lint_and_sugg(
+        cx,
+        MANUAL_IS_ASCII_CHECK,
+        expr.span,
+        "manual check for a common ASCII range",
+        "try",
+        format!("{}.{}()", subject, method),
+        applicability,
+    );
+}
+
+/// Collects the operands of a chain of `||`.
+fn flatten_or<'a>(expr: &'a Expr, operands: &mut Vec<&'a Expr>) {
+    match expr.node {
+        ExprKind::Binary(op, ref lhs, ref rhs) if op.node == BinOpKind::Or => {
+            flatten_or(lhs, operands);
+            flatten_or(rhs, operands);
+        },
+        _ => operands.push(expr),
+    }
+}
+
+/// Returns the `is_ascii_*` method checking exactly the given ranges.
+fn ascii_method(mut ranges: Vec<(u32, u32)>) -> Option<&'static str> {
+    ranges.sort();
+    ranges.dedup();
+    ASCII_CHECKS
+        .iter()
+        .find(|(checked, _)| *checked == &ranges[..])
+        .map(|&(_, method)| method)
+}
+
+/// Matches `(lo..=hi).contains(&subject)` and `subject >= lo && subject <= hi`,
+/// with the comparisons in any order, on a `char` or `u8`.
+fn range_check<'a>(cx: &LateContext<'_, '_>, expr: &'a Expr) -> Option<(&'a Expr, (u32, u32))> {
+    let (subject, range) = match expr.node {
+        ExprKind::MethodCall(ref path, _, ref args) if path.ident.name == "contains" && args.len() == 2 => {
+            let range = higher::range(cx, &args[0])?;
+            if range.limits != RangeLimits::Closed {
+                return None;
+            }
+            let subject = match args[1].node {
+                ExprKind::AddrOf(_, ref subject) => subject,
+                _ => &args[1],
+            };
+            (subject, (bound_value(cx, range.start?)?, bound_value(cx, range.end?)?))
+        },
+        ExprKind::Binary(op, ref lhs, ref rhs) if op.node == BinOpKind::And => {
+            let (lhs_subject, lhs_bound) = comparison(cx, lhs)?;
+            let (rhs_subject, rhs_bound) = comparison(cx, rhs)?;
+            if !SpanlessEq::new(cx).ignore_fn().eq_expr(lhs_subject, rhs_subject) {
+                return None;
+            }
+            match (lhs_bound, rhs_bound) {
+                (Bound::Lower(lo), Bound::Upper(hi)) | (Bound::Upper(hi), Bound::Lower(lo)) => (lhs_subject, (lo, hi)),
+                _ => return None,
+            }
+        },
+        _ => return None,
+    };
+    match walk_ptrs_ty(cx.tables.expr_ty(subject)).sty {
+        ty::Char | ty::Uint(UintTy::U8) => Some((subject, range)),
+        _ => None,
+    }
+}
+
+enum Bound {
+    Lower(u32),
+    Upper(u32),
+}
+
+/// Matches a comparison of an expression with a constant, returning the
+/// expression and the bound the comparison puts on it.
+fn comparison<'a>(cx: &LateContext<'_, '_>, expr: &'a Expr) -> Option<(&'a Expr, Bound)> {
+    if let ExprKind::Binary(op, ref lhs, ref rhs) = expr.node {
+        // normalize to `subject op constant`
+        let (subject, op, value) = if let Some(value) = bound_value(cx, rhs) {
+            (lhs, op.node, value)
+        } else {
+            let op = match op.node {
+                BinOpKind::Lt => BinOpKind::Gt,
+                BinOpKind::Le => BinOpKind::Ge,
+                BinOpKind::Gt => BinOpKind::Lt,
+                BinOpKind::Ge => BinOpKind::Le,
+                _ => return None,
+            };
+            (rhs, op, bound_value(cx, lhs)?)
+        };
+        let bound = match op {
+            BinOpKind::Ge => Bound::Lower(value),
+            BinOpKind::Gt => Bound::Lower(value.checked_add(1)?),
+            BinOpKind::Le => Bound::Upper(value),
+            BinOpKind::Lt => Bound::Upper(value.checked_sub(1)?),
+            _ => return None,
+        };
+        return Some((&**subject, bound));
+    }
+    None
+}
+
+/// Evaluates a `char` or integer constant.
+fn bound_value(cx: &LateContext<'_, '_>, expr: &Expr) -> Option<u32> {
+    match constant_simple(cx, cx.tables, expr)? {
+        Constant::Char(c) => Some(c as u32),
+        Constant::Int(value) if value <= 0xff => Some(value as u32),
+        _ => None,
+    }
+}
@@ -0,0 +1,243 @@
+use crate::utils::{
+    apply_edits, get_parent_expr, in_macro, match_trait_method, match_type, method_chain_args, msrvs, paths,
+    snippet_opt, span_lint_and_sugg, SpanlessEq,
+};
+use if_chain::if_chain;
+use matches::matches;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty::adjustment::Adjust;
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use semver::Version;
+use syntax::ast::NodeId;
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for collections reassigned with a filtered copy
+    /// of themselves, like `v = v.into_iter().filter(p).collect()`.
+    ///
+    /// **Why is this bad?** `retain` filters in place, without allocating a
+    /// new collection, and says what is meant.
+    ///
+    /// **Known problems:** `BTreeSet::retain` is only suggested if the `msrv`
+    /// option allows Rust 1.53.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let mut v = vec![1, 2, 3];
+    /// v = v.iter().filter(|&&x| x > 1).cloned().collect();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let mut v = vec![1, 2, 3];
+    /// v.retain(|&x| x > 1);
+    /// ```
+    pub MANUAL_RETAIN,
+    perf,
+    "reassigning a collection with a filtered copy of itself instead of using `retain`"
+}
+
+pub struct ManualRetain {
+    msrv: Option<Version>,
+}
+
+impl ManualRetain {
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+
+    /// Whether `retain` can be suggested for a collection of type `ty`. `chars`
+    /// is whether it is iterated with `chars()`, i.e. must be a `String`.
+    fn has_retain(&self, cx: &LateContext<'_, '_>, ty: rustc::ty::Ty<'_>, chars: bool) -> bool {
+        if chars {
+            match_type(cx, ty, &paths::STRING)
+        } else {
+            match_type(cx, ty, &paths::VEC)
+                || match_type(cx, ty, &paths::VEC_DEQUE)
+                || match_type(cx, ty, &paths::HASHSET)
+                || (match_type(cx, ty, &paths::BTREESET) && msrvs::meets(self.msrv.as_ref(), msrvs::BTREE_SET_RETAIN))
+        }
+    }
+}
+
+impl LintPass for ManualRetain {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_RETAIN)
+    }
+
+    fn name(&self) -> &'static str {
+        "ManualRetain"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ManualRetain {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::Assign(ref target, ref value) = expr.node;
+            if !in_macro(expr.span);
+            if let ExprKind::MethodCall(ref path, _, ref args) = value.node;
+            if path.ident.name == "collect" && match_trait_method(cx, value, &paths::ITERATOR);
+            if let Some((collection, predicate, by_ref)) = filter_chain(&args[0]);
+            if SpanlessEq::new(cx).eq_expr(target, collection);
+            if self.has_retain(cx, cx.tables.expr_ty(target), by_ref == ByRef::Chars);
+            if let Some(target_snippet) = snippet_opt(cx, target.span);
+            if let Some(predicate) = predicate_sugg(cx, predicate, by_ref != ByRef::Same);
+            then {
+                span_lint_and_sugg(
+                    cx,
+                    MANUAL_RETAIN,
+                    expr.span,
+                    "this expression can be written more simply using `.retain()`",
+                    "consider calling `.retain()` instead",
+                    format!("{}.retain({})", target_snippet, predicate),
+                    Applicability::MachineApplicable,
+                );
+            }
+        }
+    }
+}
+
+/// How the items `filter` sees relate to what `retain` passes to the predicate.
+#[derive(Copy, Clone, PartialEq)]
+enum ByRef {
+    /// The same type, e.g. in `v.into_iter().filter(p)`, where both see `&T`.
+    Same,
+    /// One more reference, in `v.iter().filter(p).cloned()`, where `filter`
+    /// sees `&&T`.
+    Extra,
+    /// `s.chars().filter(p)`, where `filter` sees `&char` and `String::retain`
+    /// passes `char`.
+    Chars,
+}
+
+/// Matches the iterators `retain` can replace, returning the collection, the
+/// predicate and how the items of `filter` differ from those of `retain`.
+fn filter_chain(iter: &Expr) -> Option<(&Expr, &Expr, ByRef)> {
+    const CHAINS: [(&[&str], ByRef); 6] = [
+        (&["into_iter", "filter"], ByRef::Same),
+        (&["iter", "cloned", "filter"], ByRef::Same),
+        (&["iter", "copied", "filter"], ByRef::Same),
+        (&["iter", "filter", "cloned"], ByRef::Extra),
+        (&["iter", "filter", "copied"], ByRef::Extra),
+        (&["chars", "filter"], ByRef::Chars),
+    ];
+    CHAINS.iter().find_map(|&(methods, by_ref)| {
+        let args = method_chain_args(iter, methods)?;
+        let filter = methods.iter().position(|&method| method == "filter")?;
+        Some((&args[0][0], &args[filter][1], by_ref))
+    })
+}
+
+/// The predicate for `retain`. If `deref` is set, `retain` passes one
+/// reference less than `filter` did, so the closure parameter loses a `&`, or
+/// the body a dereference.
+fn predicate_sugg(cx: &LateContext<'_, '_>, predicate: &Expr, deref: bool) -> Option<String> {
+    let snippet = snippet_opt(cx, predicate.span)?;
+    if !deref {
+        return Some(snippet);
+    }
+    let (decl, body_id) = match predicate.node {
+        ExprKind::Closure(_, ref decl, body_id, ..) => (decl, body_id),
+        ExprKind::Path(QPath::Resolved(None, ref path)) if path.segments.iter().all(|s| s.ident.name != "x") => {
+            return Some(format!("|x| {}(&x)", snippet));
+        },
+        _ => return None,
+    };
+    let body = cx.tcx.hir().body(body_id);
+    if decl.inputs.len() != 1 || !matches!(decl.inputs[0].node, TyKind::Infer) {
+        return None;
+    }
+
+    let param = &body.arguments[0].pat;
+    let edits = match param.node {
+        // `|&x|` to `|x|`, `x` has the same type
+        PatKind::Ref(ref inner, _) => vec![(param.span, snippet_opt(cx, inner.span)?)],
+        PatKind::Binding(BindingAnnotation::Unannotated, id, _, _, None) => {
+            let mut visitor = DerefVisitor {
+                cx,
+                id,
+                edits: Vec::new(),
+                valid: true,
+            };
+            visitor.visit_expr(&body.value);
+            if !visitor.valid {
+                return None;
+            }
+            visitor.edits
+        },
+        _ => return None,
+    };
+    apply_edits(&snippet, predicate.span, edits)
+}
+
+/// Collects the edits to a closure body for its parameter having one
+/// reference less: `*x` becomes `x`, and `x` becomes `&x` where it isn't
+/// dereferenced automatically.
+struct DerefVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    id: NodeId,
+    edits: Vec<(Span, String)>,
+    /// Whether all uses of the parameter could be rewritten.
+    valid: bool,
+}
+
+impl<'a, 'tcx: 'a> DerefVisitor<'a, 'tcx> {
+    fn is_param(&self, expr: &Expr) -> bool {
+        if let ExprKind::Path(ref qpath) = expr.node {
+            if let Def::Local(id) = self.cx.tables.qpath_def(qpath, expr.hir_id) {
+                return id == self.id;
+            }
+        }
+        false
+    }
+}
+
+impl<'a, 'tcx: 'a> Visitor<'tcx> for DerefVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if !self.is_param(expr) {
+            walk_expr(self, expr);
+            return;
+        }
+        let name = match snippet_opt(self.cx, expr.span) {
+            Some(name) if !in_macro(expr.span) => name,
+            _ => {
+                self.valid = false;
+                return;
+            },
+        };
+        let parent = get_parent_expr(self.cx, expr);
+        if let Some(&Expr {
+            node: ExprKind::Unary(UnDeref, _),
+            span,
+            ..
+        }) = parent
+        {
+            self.edits.push((span, name));
+            return;
+        }
+        // method receivers, fields and indexed expressions are dereferenced as needed
+        if let Some(adjustment) = self.cx.tables.expr_adjustments(expr).first() {
+            if matches!(adjustment.kind, Adjust::Deref(None)) {
+                return;
+            }
+        }
+        let is_receiver = parent.map_or(false, |parent| match parent.node {
+            ExprKind::MethodCall(_, _, ref args) => args[0].hir_id == expr.hir_id,
+            ExprKind::Field(..) => true,
+            ExprKind::Index(ref base, _) => base.hir_id == expr.hir_id,
+            _ => false,
+        });
+        if is_receiver {
+            self.edits.push((expr.span, format!("(&{})", name)));
+        } else {
+            self.edits.push((expr.span, format!("&{}", name)));
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
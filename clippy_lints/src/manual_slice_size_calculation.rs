@@ -0,0 +1,109 @@
+use crate::utils::{
+    in_macro, match_def_path, opt_def_id, paths, same_tys, snippet_with_applicability, span_lint_and_sugg,
+    walk_ptrs_ty_depth,
+};
+use if_chain::if_chain;
+use rustc::hir::{BinOpKind, Expr, ExprKind};
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty::{self, Ty};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `slice.len() * size_of::<T>()`, where `T`
+    /// is the element type of the slice, in either order.
+    ///
+    /// **Why is this bad?** `std::mem::size_of_val(slice)` computes the same
+    /// size, and can't get the element type wrong.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let data: &[i32] = &[1, 2, 3];
+    /// let size = data.len() * std::mem::size_of::<i32>();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let data: &[i32] = &[1, 2, 3];
+    /// let size = std::mem::size_of_val(data);
+    /// ```
+    pub MANUAL_SLICE_SIZE_CALCULATION,
+    complexity,
+    "multiplying the length of a slice by the size of its elements"
+}
+
+pub struct ManualSliceSizeCalculation;
+
+impl LintPass for ManualSliceSizeCalculation {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_SLICE_SIZE_CALCULATION)
+    }
+
+    fn name(&self) -> &'static str {
+        "ManualSliceSizeCalculation"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ManualSliceSizeCalculation {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::Binary(ref op, ref left, ref right) = expr.node;
+            if op.node == BinOpKind::Mul;
+            if !in_macro(expr.span);
+            // parentheses are gone in the HIR, so `(s.len()) * (size_of::<T>())` looks the same
+            if let Some((slice, ptr_depth)) =
+                slice_len(cx, left, right).or_else(|| slice_len(cx, right, left));
+            then {
+                let mut applicability = Applicability::MachineApplicable;
+                let snip = snippet_with_applicability(cx, slice.span, "..", &mut applicability);
+                let arg = match ptr_depth {
+                    0 => format!("&{}", snip),
+                    depth => format!("{}{}", "*".repeat(depth - 1), snip),
+                };
+                span_lint_and_sugg(
+                    cx,
+                    MANUAL_SLICE_SIZE_CALCULATION,
+                    expr.span,
+                    "manual slice size calculation",
+                    "try",
+                    format!("std::mem::size_of_val({})", arg),
+                    applicability,
+                );
+            }
+        }
+    }
+}
+
+/// If `len` is `slice.len()` and `size` is `size_of::<T>()` with `T` the
+/// element type of the slice, returns the slice and the number of references
+/// around it.
+fn slice_len<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, len: &'tcx Expr, size: &'tcx Expr) -> Option<(&'tcx Expr, usize)> {
+    if_chain! {
+        if let ExprKind::MethodCall(ref path, _, ref args) = len.node;
+        if path.ident.name == "len" && args.len() == 1;
+        let (slice_ty, ptr_depth) = walk_ptrs_ty_depth(cx.tables.expr_ty(&args[0]));
+        if let ty::Slice(elem_ty) = slice_ty.sty;
+        if let Some(size_ty) = size_of_ty(cx, size);
+        if same_tys(cx, elem_ty, size_ty);
+        then {
+            return Some((&args[0], ptr_depth));
+        }
+    }
+    None
+}
+
+/// The `T` of a `size_of::<T>()` call.
+fn size_of_ty<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) -> Option<Ty<'tcx>> {
+    if_chain! {
+        if let ExprKind::Call(ref func, ref args) = expr.node;
+        if args.is_empty();
+        if let ExprKind::Path(ref qpath) = func.node;
+        if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, func.hir_id));
+        if match_def_path(cx.tcx, def_id, &paths::MEM_SIZE_OF);
+        then {
+            return Some(cx.tables.node_substs(func.hir_id).type_at(0));
+        }
+    }
+    None
+}
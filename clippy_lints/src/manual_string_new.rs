@@ -0,0 +1,98 @@
+use crate::utils::{in_macro, match_def_path, match_trait_method, match_type, paths, resolve_node, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::LitKind;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of an empty string literal to
+    /// create a `String`, e.g. `"".to_string()`, `"".to_owned()`,
+    /// `String::from("")` or `"".into()`.
+    ///
+    /// **Why is this bad?** `String::new()` is clearer and does not involve
+    /// converting a `&str`.
+    ///
+    /// **Known problems:** `format!("")` is linted by `useless_format`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let a = "".to_string();
+    /// let b: String = "".into();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let a = String::new();
+    /// let b = String::new();
+    /// ```
+    pub MANUAL_STRING_NEW,
+    pedantic,
+    "empty `String` created from an empty string literal instead of `String::new()`"
+}
+
+#[derive(Copy, Clone)]
+pub struct ManualStringNew;
+
+impl LintPass for ManualStringNew {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_STRING_NEW)
+    }
+
+    fn name(&self) -> &'static str {
+        "ManualStringNew"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ManualStringNew {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) || !match_type(cx, cx.tables.expr_ty(expr), &paths::STRING) {
+            return;
+        }
+        let is_manual = match expr.node {
+            // `"".to_string()`, `"".to_owned()`, `"".into()`
+            ExprKind::MethodCall(_, _, ref args) => {
+                args.len() == 1
+                    && is_empty_str(&args[0])
+                    && (match_trait_method(cx, expr, &paths::TO_STRING)
+                        || match_trait_method(cx, expr, &paths::TO_OWNED)
+                        || match_trait_method(cx, expr, &paths::INTO))
+            },
+            // `String::from("")`, `From::from("")`
+            ExprKind::Call(ref fun, ref args) => {
+                if_chain! {
+                    if args.len() == 1 && is_empty_str(&args[0]);
+                    if let ExprKind::Path(ref qpath) = fun.node;
+                    if let Some(def_id) = resolve_node(cx, qpath, fun.hir_id).opt_def_id();
+                    then {
+                        match_def_path(cx.tcx, def_id, &paths::FROM_FROM)
+                    } else {
+                        false
+                    }
+                }
+            },
+            _ => false,
+        };
+        if is_manual {
+            span_lint_and_sugg(
+                cx,
+                MANUAL_STRING_NEW,
+                expr.span,
+                "empty String is being created manually",
+                "consider using",
+                "String::new()".to_string(),
+                Applicability::MachineApplicable,
+            );
+        }
+    }
+}
+
+/// Checks if the expression is the literal `""`.
+fn is_empty_str(expr: &Expr) -> bool {
+    if let ExprKind::Lit(ref lit) = expr.node {
+        if let LitKind::Str(ref s, _) = lit.node {
+            return s.as_str().is_empty();
+        }
+    }
+    false
+}
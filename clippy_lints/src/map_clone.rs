@@ -126,17 +126,13 @@ fn lint_needless_cloning(cx: &LateContext<'_, '_>, root: Span, receiver: Span) {
 }
 
 fn lint(cx: &LateContext<'_, '_>, replace: Span, root: Span) {
-    let mut applicability = Applicability::MachineApplicable;
-    span_lint_and_sugg(
+    span_lint_and_sugg_lazy(
         cx,
         MAP_CLONE,
         replace,
         "You are using an explicit closure for cloning elements",
         "Consider calling the dedicated `cloned` method",
-        format!(
-            "{}.cloned()",
-            snippet_with_applicability(cx, root, "..", &mut applicability)
-        ),
-        applicability,
+        Applicability::MachineApplicable,
+        |applicability| format!("{}.cloned()", snippet_with_applicability(cx, root, "..", applicability)),
     )
 }
@@ -1,4 +1,6 @@
-use crate::utils::{match_def_path, match_qpath, opt_def_id, paths, snippet_with_applicability, span_lint_and_sugg};
+use crate::utils::{
+    match_def_path, match_qpath, opt_def_id, paths, snippet_with_applicability, span_lint_and_sugg_lazy,
+};
 use if_chain::if_chain;
 use rustc::hir::{Expr, ExprKind, MutMutable, QPath};
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
@@ -76,15 +78,19 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MemReplace {
                     _ => return,
                 };
 
-                let mut applicability = Applicability::MachineApplicable;
-                span_lint_and_sugg(
+                span_lint_and_sugg_lazy(
                     cx,
                     MEM_REPLACE_OPTION_WITH_NONE,
                     expr.span,
                     "replacing an `Option` with `None`",
                     "consider `Option::take()` instead",
-                    format!("{}.take()", snippet_with_applicability(cx, replaced_path.span, "", &mut applicability)),
-                    applicability,
+                    Applicability::MachineApplicable,
+                    |applicability| {
+                        format!(
+                            "{}.take()",
+                            snippet_with_applicability(cx, replaced_path.span, "", applicability)
+                        )
+                    },
                 );
             }
         }
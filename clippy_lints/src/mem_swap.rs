@@ -0,0 +1,325 @@
+use crate::utils::place::{is_place, place_aliasing, Aliasing};
+use crate::utils::sugg::Sugg;
+use crate::utils::usage::mutated_variables;
+use crate::utils::{
+    in_macro, match_def_path, opt_def_id, paths, snippet_with_applicability, span_lint_and_sugg,
+    span_lint_and_sugg_lazy, span_lint_and_then,
+};
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::NodeId;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `mem::swap` on references created by
+    /// dereferencing raw pointers, like `mem::swap(&mut *a, &mut *b)`.
+    ///
+    /// **Why is this bad?** The two references must not alias, which is easy to
+    /// get wrong with raw pointers, and if they do it is undefined behavior.
+    /// `ptr::swap` works on the pointers directly and allows them to overlap.
+    ///
+    /// **Known problems:** The pointers may be known not to alias, in which
+    /// case the code is fine.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let mut a = 1;
+    /// # let mut b = 2;
+    /// # let (x, y): (*mut i32, *mut i32) = (&mut a, &mut b);
+    /// unsafe {
+    ///     std::mem::swap(&mut *x, &mut *y);
+    /// }
+    /// ```
+    ///
+    /// Could be written as:
+    ///
+    /// ```rust
+    /// # let mut a = 1;
+    /// # let mut b = 2;
+    /// # let (x, y): (*mut i32, *mut i32) = (&mut a, &mut b);
+    /// unsafe {
+    ///     std::ptr::swap(x, y);
+    /// }
+    /// ```
+    pub SWAP_PTR_TO_REF,
+    pedantic,
+    "call to `mem::swap` with references created from raw pointers"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `mem::swap` calls that swap a place with
+    /// itself, e.g. through pointers cast from the same reference.
+    ///
+    /// **Why is this bad?** The call has no effect. Creating two mutable
+    /// references to the same place is undefined behavior on top of that.
+    ///
+    /// **Known problems:** Places are compared syntactically, so two pointers
+    /// with the same value are only detected if they are derived from the same
+    /// expression.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// let p = &mut x as *mut i32;
+    /// unsafe { std::mem::swap(&mut *p, &mut *p) };
+    /// ```
+    pub NOOP_SWAP,
+    correctness,
+    "`mem::swap` of a place with itself"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `mem::swap` with a temporary value or with a
+    /// variable that was declared just for the swap.
+    ///
+    /// **Why is this bad?** An assignment or `mem::replace` says the same more
+    /// directly.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let mut v = vec![1];
+    /// std::mem::swap(&mut v, &mut Vec::new());
+    ///
+    /// let mut old = vec![2];
+    /// std::mem::swap(&mut v, &mut old);
+    /// ```
+    ///
+    /// Could be written as:
+    ///
+    /// ```rust
+    /// # let mut v = vec![1];
+    /// v = Vec::new();
+    ///
+    /// let old = std::mem::replace(&mut v, vec![2]);
+    /// ```
+    pub SWAP_WITH_TEMPORARY,
+    complexity,
+    "`mem::swap` with a temporary value, which could be an assignment or `mem::replace`"
+}
+
+#[derive(Copy, Clone)]
+pub struct MemSwap;
+
+impl LintPass for MemSwap {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SWAP_PTR_TO_REF, NOOP_SWAP, SWAP_WITH_TEMPORARY)
+    }
+
+    fn name(&self) -> &'static str {
+        "MemSwap"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MemSwap {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        if let Some((left, right)) = mem_swap_args(cx, expr) {
+            if place_aliasing(cx, left, right) == Aliasing::Same {
+                lint_noop(cx, expr);
+            } else if deref_raw_ptr(cx, left).is_some() || deref_raw_ptr(cx, right).is_some() {
+                lint_ptr_to_ref(cx, expr, left, right);
+            } else {
+                lint_temporary(cx, expr, left, right);
+            }
+        }
+    }
+
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        for (index, window) in block.stmts.windows(2).enumerate() {
+            if_chain! {
+                if let StmtKind::Local(ref local) = window[0].node;
+                if let PatKind::Binding(BindingAnnotation::Mutable, canonical_id, _, ident, None) = local.pat.node;
+                if local.ty.is_none();
+                if let Some(ref init) = local.init;
+                if let StmtKind::Semi(ref swap) = window[1].node;
+                if let Some((left, right)) = mem_swap_args(cx, swap);
+                if let Some(other) = swapped_with_local(cx, left, right, canonical_id);
+                then {
+                    // the swap was the reason for `mut`, unless the variable is changed later on
+                    let rest = &block.stmts[index + 2..];
+                    let mutability = if mutated_in(cx, rest, block.expr.as_ref().map(|e| &**e), local.pat.hir_id) {
+                        "mut "
+                    } else {
+                        ""
+                    };
+                    span_lint_and_sugg_lazy(
+                        cx,
+                        SWAP_WITH_TEMPORARY,
+                        window[0].span.to(window[1].span),
+                        "this variable is only declared to be swapped with another place",
+                        "try",
+                        Applicability::MachineApplicable,
+                        |applicability| format!(
+                            "let {}{} = std::mem::replace({}, {});",
+                            mutability,
+                            ident,
+                            snippet_with_applicability(cx, other.span, "..", applicability),
+                            snippet_with_applicability(cx, init.span, "..", applicability),
+                        ),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Whether the local `id` may be changed in `stmts` or `expr`.
+fn mutated_in<'a, 'tcx: 'a>(
+    cx: &'a LateContext<'a, 'tcx>,
+    stmts: &'tcx [Stmt],
+    expr: Option<&'tcx Expr>,
+    id: HirId,
+) -> bool {
+    stmts
+        .iter()
+        .filter_map(|stmt| match stmt.node {
+            StmtKind::Local(ref local) => local.init.as_ref().map(|init| &**init),
+            StmtKind::Expr(ref expr) | StmtKind::Semi(ref expr) => Some(&**expr),
+            StmtKind::Item(_) => None,
+        })
+        .chain(expr)
+        .any(|expr| mutated_variables(expr, cx).map_or(true, |mutated| mutated.contains(&id)))
+}
+
+/// Returns the two arguments of a `mem::swap` call.
+fn mem_swap_args<'e>(cx: &LateContext<'_, '_>, expr: &'e Expr) -> Option<(&'e Expr, &'e Expr)> {
+    if_chain! {
+        if let ExprKind::Call(ref func, ref args) = expr.node;
+        if args.len() == 2;
+        if let ExprKind::Path(ref qpath) = func.node;
+        if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, func.hir_id));
+        if match_def_path(cx.tcx, def_id, &paths::MEM_SWAP);
+        then {
+            return Some((&args[0], &args[1]));
+        }
+    }
+    None
+}
+
+/// If `arg` is `&mut *ptr` with a raw pointer `ptr`, returns `ptr`.
+fn deref_raw_ptr<'e>(cx: &LateContext<'_, '_>, arg: &'e Expr) -> Option<&'e Expr> {
+    if_chain! {
+        if let ExprKind::AddrOf(MutMutable, ref place) = arg.node;
+        if let ExprKind::Unary(UnDeref, ref ptr) = place.node;
+        if let ty::RawPtr(_) = cx.tables.expr_ty(ptr).sty;
+        then {
+            return Some(ptr);
+        }
+    }
+    None
+}
+
+fn lint_noop(cx: &LateContext<'_, '_>, expr: &Expr) {
+    // the borrow checker rejects this unless the references are created from
+    // raw pointers
+    span_lint_and_then(cx, NOOP_SWAP, expr.span, "swapping a place with itself", |db| {
+        db.note("creating two mutable references to the same place is undefined behavior");
+        db.help("this call has no effect, consider removing it");
+    });
+}
+
+fn lint_ptr_to_ref(cx: &LateContext<'_, '_>, expr: &Expr, left: &Expr, right: &Expr) {
+    let msg = "call to `mem::swap` with a parameter derived from a raw pointer";
+    match (deref_raw_ptr(cx, left), deref_raw_ptr(cx, right)) {
+        (Some(left_ptr), Some(right_ptr)) => {
+            let all_mut = [left_ptr, right_ptr]
+                .iter()
+                .all(|ptr| match cx.tables.expr_ty(ptr).sty {
+                    ty::RawPtr(ty::TypeAndMut { mutbl, .. }) => mutbl == MutMutable,
+                    _ => false,
+                });
+            let mut applicability = if all_mut {
+                Applicability::MachineApplicable
+            } else {
+                Applicability::MaybeIncorrect
+            };
+            span_lint_and_then(cx, SWAP_PTR_TO_REF, expr.span, msg, |db| {
+                if place_aliasing(cx, left_ptr, right_ptr) == Aliasing::SameBase {
+                    db.note("both pointers are derived from the same allocation and may overlap");
+                }
+                let sugg = format!(
+                    "std::ptr::swap({}, {})",
+                    snippet_with_applicability(cx, left_ptr.span, "..", &mut applicability),
+                    snippet_with_applicability(cx, right_ptr.span, "..", &mut applicability),
+                );
+                db.span_suggestion(expr.span, "use ptr::swap", sugg, applicability);
+            });
+        },
+        _ => {
+            span_lint_and_then(cx, SWAP_PTR_TO_REF, expr.span, msg, |db| {
+                db.help("use `std::ptr::swap` on the pointers instead");
+            });
+        },
+    }
+}
+
+fn lint_temporary(cx: &LateContext<'_, '_>, expr: &Expr, left: &Expr, right: &Expr) {
+    let (other, temp) = match (temporary(left), temporary(right)) {
+        (None, Some(temp)) => (left, temp),
+        (Some(temp), None) => (right, temp),
+        _ => return,
+    };
+
+    let mut applicability = Applicability::MachineApplicable;
+    let place = match other.node {
+        ExprKind::AddrOf(MutMutable, ref place) => Sugg::hir_with_applicability(cx, place, "..", &mut applicability),
+        _ => Sugg::hir_with_applicability(cx, other, "..", &mut applicability).deref(),
+    };
+    span_lint_and_sugg(
+        cx,
+        SWAP_WITH_TEMPORARY,
+        expr.span,
+        "swapping with a temporary value",
+        "use an assignment instead",
+        format!(
+            "{} = {}",
+            place,
+            snippet_with_applicability(cx, temp.span, "..", &mut applicability),
+        ),
+        applicability,
+    );
+}
+
+/// If `arg` is `&mut value` with a value that is not a place, returns `value`.
+fn temporary(arg: &Expr) -> Option<&Expr> {
+    match arg.node {
+        ExprKind::AddrOf(MutMutable, ref value) if !is_place(value) => Some(value),
+        _ => None,
+    }
+}
+
+/// Checks whether one of the arguments is `&mut local` and returns the other
+/// one.
+fn swapped_with_local<'e>(
+    cx: &LateContext<'_, '_>,
+    left: &'e Expr,
+    right: &'e Expr,
+    local: NodeId,
+) -> Option<&'e Expr> {
+    let is_local = |arg: &Expr| {
+        if_chain! {
+            if let ExprKind::AddrOf(MutMutable, ref inner) = arg.node;
+            if let ExprKind::Path(ref qpath) = inner.node;
+            if let Def::Local(id) = cx.tables.qpath_def(qpath, inner.hir_id);
+            then {
+                return id == local;
+            }
+        }
+        false
+    };
+
+    if is_local(right) && temporary(left).is_none() {
+        Some(left)
+    } else if is_local(left) && temporary(right).is_none() {
+        Some(right)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,557 @@
+//! Declarative rules for lints that only look for a specific chain of method
+//! calls, like `filter(..).next()`.
+//!
+//! Adding such a lint only requires a new entry in `CHAIN_RULES`, the matching
+//! and reporting is shared. Lints that need to inspect the arguments more
+//! closely get their own module with a `lint` function instead.
+//! Rules for a specific API can live in their own module, like
+//! `seek::SEEK_RULES`, and are chained in `check`.
+
+use crate::utils::{explain, msrvs, paths};
+use crate::utils::{
+    match_def_path, match_qpath, match_trait_method, match_type, snippet, span_lint, span_lint_and_sugg,
+    span_lint_and_then, span_note_and_lint, walk_ptrs_ty,
+};
+use matches::matches;
+use rustc::hir;
+use rustc::lint::{LateContext, Lint};
+use rustc::ty::{self, Ty};
+use rustc_errors::Applicability;
+use semver::Version;
+use syntax::ast::LitKind;
+
+use super::lines_filter_map_ok::LINES_RULES;
+use super::option_result_conversions::CONVERSION_RULES;
+use super::redundant_as_str::AS_STR_RULES;
+use super::seek::SEEK_RULES;
+use super::{
+    derefs_to_slice, FILTER_MAP, FILTER_NEXT, ITER_CLONED_COLLECT, ITER_NTH, ITER_SKIP_NEXT, MAP_FLATTEN,
+    SEARCH_IS_SOME, TEMPORARY_CSTRING_AS_PTR,
+};
+
+/// A chain of method calls that is linted.
+pub(super) struct ChainRule {
+    pub lint: &'static &'static Lint,
+    /// The methods of the chain, in call order. At most two, as
+    /// `Pass::check_expr` only collects the last two calls.
+    pub methods: &'static [&'static str],
+    pub receiver: Receiver,
+    /// Conditions on the arguments of the chain. Arguments are numbered across
+    /// all methods in call order, not counting the receivers.
+    pub args: &'static [(usize, ArgPred)],
+    /// Additional condition that cannot be expressed with the fields above,
+    /// given the whole chain and its argument lists.
+    pub check: Option<fn(&LateContext<'_, '_>, &hir::Expr, &[&[hir::Expr]]) -> bool>,
+    /// The Rust version that stabilized the suggested replacement, see
+    /// `utils::msrvs`. The rule is skipped if the `msrv` doesn't allow it, use
+    /// `Fix::Since` for chains that are linted anyway.
+    pub msrv: Option<[u64; 3]>,
+    pub msg: &'static str,
+    pub fix: Fix,
+}
+
+/// Condition on what the chain is called on.
+pub(super) enum Receiver {
+    /// The method at the given position of the chain comes from this trait.
+    TraitMethod(usize, &'static [&'static str]),
+    /// The receiver of the first method has this type.
+    Type(&'static [&'static str]),
+    /// The receiver of the first method is a slice, possibly behind a
+    /// reference or a `Box`, or a reference to a `Vec` or a small array.
+    Slice,
+    /// The receiver of the first method is a `str`, `String` or `Cow<str>`,
+    /// possibly behind references.
+    Str,
+    /// The receiver of the first method is a call of the given function.
+    Call(&'static [&'static str]),
+}
+
+/// Condition on a single argument of the chain.
+pub(super) enum ArgPred {
+    /// The argument is a path to the given item, e.g. `None`.
+    Path(&'static [&'static str]),
+    /// The argument is a call of the given function or tuple variant, with
+    /// arguments matching the given conditions, e.g. `Some(0)`.
+    Call(&'static [&'static str], &'static [ArgPred]),
+    /// The argument is the given integer literal.
+    Int(u128),
+    /// Any argument, e.g. as an argument of `ArgPred::Call`.
+    Any,
+}
+
+/// Additional output of a rule.
+///
+/// Templates may refer to the receiver of the chain with `{recv}` and to the
+/// arguments with `{0}`, `{1}`, ... (numbered like in `ChainRule::args`). The
+/// arguments of an argument that is a call are `{0.0}`, `{0.1}`, ...
+pub(super) enum Fix {
+    None,
+    /// A note on how to rewrite the chain, omitted if it would span several lines.
+    Note(&'static str),
+    /// A suggestion replacing the whole chain.
+    Sugg {
+        help: &'static str,
+        template: &'static str,
+        applicability: Applicability,
+    },
+    /// A suggestion replacing the chain after the slice of a `Receiver::Slice`
+    /// rule, e.g. `.iter().cloned().collect()` of `v.iter().cloned().collect()`.
+    SliceSugg {
+        help: &'static str,
+        replacement: &'static str,
+        applicability: Applicability,
+    },
+    /// A note, and a help pointing at the receiver of the last method.
+    NoteAndHelp {
+        note: &'static str,
+        help: &'static str,
+    },
+    /// `fix` if the `msrv` allows the given Rust version, `fallback` otherwise.
+    Since {
+        version: [u64; 3],
+        fix: &'static Fix,
+        fallback: &'static Fix,
+    },
+}
+
+#[rustfmt::skip]
+pub(super) static CHAIN_RULES: &[ChainRule] = &[
+    ChainRule {
+        lint: &FILTER_NEXT,
+        methods: &["filter", "next"],
+        receiver: Receiver::TraitMethod(1, &paths::ITERATOR),
+        args: &[],
+        check: None,
+        msrv: None,
+        msg: "called `filter(p).next()` on an `Iterator`. This is more succinctly expressed by calling \
+              `.find(p)` instead.",
+        fix: Fix::Note("replace `filter({0}).next()` with `find({0})`"),
+    },
+    ChainRule {
+        lint: &ITER_SKIP_NEXT,
+        methods: &["skip", "next"],
+        receiver: Receiver::TraitMethod(1, &paths::ITERATOR),
+        args: &[],
+        check: None,
+        msrv: None,
+        msg: "called `skip(x).next()` on an iterator. This is more succinctly expressed by calling `nth(x)`",
+        fix: Fix::None,
+    },
+    ChainRule {
+        lint: &ITER_NTH,
+        methods: &["iter", "nth"],
+        receiver: Receiver::Slice,
+        args: &[],
+        check: None,
+        msrv: None,
+        msg: "called `.iter().nth()` on a slice. Calling `.get()` is both faster and more readable",
+        fix: Fix::None,
+    },
+    ChainRule {
+        lint: &ITER_NTH,
+        methods: &["iter", "nth"],
+        receiver: Receiver::Type(&paths::VEC),
+        args: &[],
+        check: None,
+        msrv: None,
+        msg: "called `.iter().nth()` on a Vec. Calling `.get()` is both faster and more readable",
+        fix: Fix::None,
+    },
+    ChainRule {
+        lint: &ITER_NTH,
+        methods: &["iter", "nth"],
+        receiver: Receiver::Type(&paths::VEC_DEQUE),
+        args: &[],
+        check: None,
+        msrv: None,
+        msg: "called `.iter().nth()` on a VecDeque. Calling `.get()` is both faster and more readable",
+        fix: Fix::None,
+    },
+    ChainRule {
+        lint: &ITER_NTH,
+        methods: &["iter_mut", "nth"],
+        receiver: Receiver::Slice,
+        args: &[],
+        check: None,
+        msrv: None,
+        msg: "called `.iter_mut().nth()` on a slice. Calling `.get_mut()` is both faster and more readable",
+        fix: Fix::None,
+    },
+    ChainRule {
+        lint: &ITER_NTH,
+        methods: &["iter_mut", "nth"],
+        receiver: Receiver::Type(&paths::VEC),
+        args: &[],
+        check: None,
+        msrv: None,
+        msg: "called `.iter_mut().nth()` on a Vec. Calling `.get_mut()` is both faster and more readable",
+        fix: Fix::None,
+    },
+    ChainRule {
+        lint: &ITER_NTH,
+        methods: &["iter_mut", "nth"],
+        receiver: Receiver::Type(&paths::VEC_DEQUE),
+        args: &[],
+        check: None,
+        msrv: None,
+        msg: "called `.iter_mut().nth()` on a VecDeque. Calling `.get_mut()` is both faster and more readable",
+        fix: Fix::None,
+    },
+    ChainRule {
+        lint: &MAP_FLATTEN,
+        methods: &["map", "flatten"],
+        receiver: Receiver::TraitMethod(1, &paths::ITERATOR),
+        args: &[],
+        check: None,
+        msrv: None,
+        msg: "called `map(..).flatten()` on an `Iterator`. \
+              This is more succinctly expressed by calling `.flat_map(..)`",
+        fix: Fix::Sugg {
+            help: "try using flat_map instead",
+            template: "{recv}.flat_map({0})",
+            applicability: Applicability::MachineApplicable,
+        },
+    },
+    ChainRule {
+        lint: &FILTER_MAP,
+        methods: &["filter", "map"],
+        receiver: Receiver::TraitMethod(1, &paths::ITERATOR),
+        args: &[],
+        check: None,
+        msrv: None,
+        msg: "called `filter(p).map(q)` on an `Iterator`. \
+              This is more succinctly expressed by calling `.filter_map(..)` instead.",
+        fix: Fix::None,
+    },
+    ChainRule {
+        lint: &FILTER_MAP,
+        methods: &["filter_map", "map"],
+        receiver: Receiver::TraitMethod(1, &paths::ITERATOR),
+        args: &[],
+        check: None,
+        msrv: None,
+        msg: "called `filter_map(p).map(q)` on an `Iterator`. \
+              This is more succinctly expressed by only calling `.filter_map(..)` instead.",
+        fix: Fix::None,
+    },
+    ChainRule {
+        lint: &FILTER_MAP,
+        methods: &["filter", "flat_map"],
+        receiver: Receiver::TraitMethod(1, &paths::ITERATOR),
+        args: &[],
+        check: None,
+        msrv: None,
+        msg: "called `filter(p).flat_map(q)` on an `Iterator`. \
+              This is more succinctly expressed by calling `.flat_map(..)` \
+              and filtering by returning an empty Iterator.",
+        fix: Fix::None,
+    },
+    ChainRule {
+        lint: &FILTER_MAP,
+        methods: &["filter_map", "flat_map"],
+        receiver: Receiver::TraitMethod(1, &paths::ITERATOR),
+        args: &[],
+        check: None,
+        msrv: None,
+        msg: "called `filter_map(p).flat_map(q)` on an `Iterator`. \
+              This is more succinctly expressed by calling `.flat_map(..)` \
+              and filtering by returning an empty Iterator.",
+        fix: Fix::None,
+    },
+    ChainRule {
+        lint: &SEARCH_IS_SOME,
+        methods: &["find", "is_some"],
+        receiver: Receiver::TraitMethod(0, &paths::ITERATOR),
+        args: &[],
+        check: None,
+        msrv: None,
+        msg: "called `is_some()` after searching an `Iterator` with find. This is more succinctly \
+              expressed by calling `any()`.",
+        fix: Fix::Note("replace `find({0}).is_some()` with `any({0})`"),
+    },
+    ChainRule {
+        lint: &SEARCH_IS_SOME,
+        methods: &["position", "is_some"],
+        receiver: Receiver::TraitMethod(0, &paths::ITERATOR),
+        args: &[],
+        check: None,
+        msrv: None,
+        msg: "called `is_some()` after searching an `Iterator` with position. This is more succinctly \
+              expressed by calling `any()`.",
+        fix: Fix::Note("replace `position({0}).is_some()` with `any({0})`"),
+    },
+    ChainRule {
+        lint: &SEARCH_IS_SOME,
+        methods: &["rposition", "is_some"],
+        receiver: Receiver::TraitMethod(0, &paths::ITERATOR),
+        args: &[],
+        check: None,
+        msrv: None,
+        msg: "called `is_some()` after searching an `Iterator` with rposition. This is more succinctly \
+              expressed by calling `any()`.",
+        fix: Fix::Note("replace `rposition({0}).is_some()` with `any({0})`"),
+    },
+    ChainRule {
+        lint: &TEMPORARY_CSTRING_AS_PTR,
+        methods: &["unwrap", "as_ptr"],
+        receiver: Receiver::Call(&paths::CSTRING_NEW),
+        args: &[],
+        check: None,
+        msrv: None,
+        msg: "you are getting the inner pointer of a temporary `CString`",
+        fix: Fix::NoteAndHelp {
+            note: "that pointer will be invalid outside this expression",
+            help: "assign the `CString` to a variable to extend its lifetime",
+        },
+    },
+    ChainRule {
+        lint: &ITER_CLONED_COLLECT,
+        methods: &["cloned", "collect"],
+        receiver: Receiver::Slice,
+        args: &[],
+        check: Some(collects_into_vec),
+        msrv: None,
+        msg: "called `iter().cloned().collect()` on a slice to create a `Vec`. Calling `to_vec()` is both faster and \
+              more readable",
+        fix: Fix::SliceSugg {
+            help: "try",
+            replacement: ".to_vec()",
+            applicability: Applicability::MachineApplicable,
+        },
+    },
+];
+
+/// Checks `expr` against all rules in `CHAIN_RULES` and the API-specific
+/// tables, given the method calls of `expr` from `method_calls`, last to
+/// first. Rules suggesting features newer than `msrv` are skipped.
+pub(super) fn check(
+    cx: &LateContext<'_, '_>,
+    expr: &hir::Expr,
+    method_names: &[&str],
+    arg_lists: &[&[hir::Expr]],
+    msrv: Option<&Version>,
+) {
+    for rule in CHAIN_RULES
+        .iter()
+        .chain(SEEK_RULES)
+        .chain(LINES_RULES)
+        .chain(AS_STR_RULES)
+        .chain(CONVERSION_RULES)
+    {
+        let len = rule.methods.len();
+        if method_names.len() < len || !method_names[..len].iter().rev().eq(rule.methods) {
+            continue;
+        }
+        if rule.msrv.map_or(false, |version| !msrvs::meets(msrv, version)) {
+            explain::reject(cx, rule.lint, expr.span, || {
+                format!("the `msrv` is older than the replacement of `{}`", describe(rule))
+            });
+            continue;
+        }
+        let chain_args: Vec<_> = arg_lists[..len].iter().rev().cloned().collect();
+        check_rule(cx, expr, rule, &chain_args, msrv);
+    }
+}
+
+fn check_rule(
+    cx: &LateContext<'_, '_>,
+    expr: &hir::Expr,
+    rule: &ChainRule,
+    arg_lists: &[&[hir::Expr]],
+    msrv: Option<&Version>,
+) {
+    let recv = &arg_lists[0][0];
+    let args = arg_lists
+        .iter()
+        .flat_map(|arg_list| arg_list[1..].iter())
+        .collect::<Vec<_>>();
+
+    let receiver_matches = match rule.receiver {
+        Receiver::TraitMethod(idx, path) => {
+            // the call of the method at `idx` is the receiver of the next one
+            let method_expr = arg_lists.get(idx + 1).map_or(expr, |next_args| &next_args[0]);
+            match_trait_method(cx, method_expr, path)
+        },
+        Receiver::Type(path) => match_type(cx, cx.tables.expr_ty(recv), path),
+        Receiver::Slice => derefs_to_slice(cx, recv, cx.tables.expr_ty(recv)).is_some(),
+        Receiver::Str => is_string(cx, walk_ptrs_ty(cx.tables.expr_ty(recv))),
+        Receiver::Call(path) => is_call_of(cx, recv, path),
+    };
+    if !receiver_matches {
+        explain::reject(cx, rule.lint, expr.span, || match rule.receiver {
+            Receiver::TraitMethod(idx, path) => format!(
+                "`{}` is not a method of `{}`",
+                rule.methods[idx],
+                path.last().expect("non-empty path")
+            ),
+            Receiver::Type(path) => format!(
+                "the receiver of `{}` is not a `{}`",
+                describe(rule),
+                path.last().expect("non-empty path")
+            ),
+            Receiver::Slice => format!("the receiver of `{}` is not a slice", describe(rule)),
+            Receiver::Str => format!("the receiver of `{}` is not a string", describe(rule)),
+            Receiver::Call(path) => format!(
+                "the receiver of `{}` is not a call of `{}`",
+                describe(rule),
+                path.join("::")
+            ),
+        });
+        return;
+    }
+
+    let failed_arg = rule.args.iter().find(|&&(idx, ref pred)| match args.get(idx) {
+        Some(arg) => !arg_matches(arg, pred),
+        None => true,
+    });
+    if let Some(&(idx, _)) = failed_arg {
+        explain::reject(cx, rule.lint, expr.span, || {
+            format!("argument {} of `{}` doesn't match the rule", idx, describe(rule))
+        });
+        return;
+    }
+    if rule.check.map_or(false, |check| !check(cx, expr, arg_lists)) {
+        explain::reject(cx, rule.lint, expr.span, || {
+            format!("the additional check of the rule for `{}` failed", describe(rule))
+        });
+        return;
+    }
+
+    let mut fix = &rule.fix;
+    while let Fix::Since {
+        version,
+        fix: newer,
+        fallback,
+    } = *fix
+    {
+        fix = if msrvs::meets(msrv, version) { newer } else { fallback };
+    }
+    match *fix {
+        Fix::None | Fix::Since { .. } => span_lint(cx, rule.lint, expr.span, rule.msg),
+        Fix::Note(template) => {
+            let note = render(cx, template, recv, &args);
+            if note.lines().count() <= 1 {
+                span_note_and_lint(cx, rule.lint, expr.span, rule.msg, expr.span, &note);
+            } else {
+                span_lint(cx, rule.lint, expr.span, rule.msg);
+            }
+        },
+        Fix::Sugg {
+            help,
+            template,
+            applicability,
+        } => span_lint_and_sugg(
+            cx,
+            rule.lint,
+            expr.span,
+            rule.msg,
+            help,
+            render(cx, template, recv, &args),
+            applicability,
+        ),
+        Fix::SliceSugg {
+            help,
+            replacement,
+            applicability,
+        } => {
+            let slice = derefs_to_slice(cx, recv, cx.tables.expr_ty(recv));
+            if let Some(span) = slice.and_then(|slice| expr.span.trim_start(slice.span.source_callsite())) {
+                span_lint_and_sugg(
+                    cx,
+                    rule.lint,
+                    span,
+                    rule.msg,
+                    help,
+                    replacement.to_string(),
+                    applicability,
+                );
+            }
+        },
+        Fix::NoteAndHelp { note, help } => {
+            let last_recv = &arg_lists[arg_lists.len() - 1][0];
+            span_lint_and_then(cx, rule.lint, expr.span, rule.msg, |db| {
+                db.note(note);
+                db.span_help(last_recv.span, help);
+            });
+        },
+    }
+}
+
+/// The chain of `rule`, like `filter(..).next(..)`, for `explain`.
+fn describe(rule: &ChainRule) -> String {
+    rule.methods
+        .iter()
+        .map(|method| format!("{}(..)", method))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn is_call_of(cx: &LateContext<'_, '_>, expr: &hir::Expr, path: &[&str]) -> bool {
+    if let hir::ExprKind::Call(ref fun, _) = expr.node {
+        if let hir::ExprKind::Path(ref qpath) = fun.node {
+            return cx
+                .tables
+                .qpath_def(qpath, fun.hir_id)
+                .opt_def_id()
+                .map_or(false, |def_id| match_def_path(cx.tcx, def_id, path));
+        }
+    }
+    false
+}
+
+fn collects_into_vec(cx: &LateContext<'_, '_>, expr: &hir::Expr, _: &[&[hir::Expr]]) -> bool {
+    match_type(cx, cx.tables.expr_ty(expr), &paths::VEC)
+}
+
+fn is_string(cx: &LateContext<'_, '_>, ty: Ty<'_>) -> bool {
+    match ty.sty {
+        ty::Str => true,
+        // `Cow<'a, B>`
+        ty::Adt(_, substs) if match_type(cx, ty, &paths::COW) => matches!(substs.type_at(1).sty, ty::Str),
+        _ => match_type(cx, ty, &paths::STRING),
+    }
+}
+
+fn arg_matches(arg: &hir::Expr, pred: &ArgPred) -> bool {
+    match *pred {
+        ArgPred::Path(path) => {
+            if let hir::ExprKind::Path(ref qpath) = arg.node {
+                match_qpath(qpath, path)
+            } else {
+                false
+            }
+        },
+        ArgPred::Call(path, preds) => {
+            if let hir::ExprKind::Call(ref fun, ref args) = arg.node {
+                args.len() == preds.len()
+                    && arg_matches(fun, &ArgPred::Path(path))
+                    && args.iter().zip(preds).all(|(arg, pred)| arg_matches(arg, pred))
+            } else {
+                false
+            }
+        },
+        ArgPred::Int(value) => {
+            if let hir::ExprKind::Lit(ref lit) = arg.node {
+                if let LitKind::Int(lit_value, _) = lit.node {
+                    return lit_value == value;
+                }
+            }
+            false
+        },
+        ArgPred::Any => true,
+    }
+}
+
+/// Fills in the placeholders of `template` with snippets of the chain.
+fn render(cx: &LateContext<'_, '_>, template: &str, recv: &hir::Expr, args: &[&hir::Expr]) -> String {
+    let mut rendered = template.replace("{recv}", &snippet(cx, recv.span, ".."));
+    for (idx, arg) in args.iter().enumerate() {
+        rendered = rendered.replace(&format!("{{{}}}", idx), &snippet(cx, arg.span, ".."));
+        if let hir::ExprKind::Call(_, ref inner) = arg.node {
+            for (inner_idx, inner) in inner.iter().enumerate() {
+                rendered = rendered.replace(&format!("{{{}.{}}}", idx, inner_idx), &snippet(cx, inner.span, ".."));
+            }
+        }
+    }
+    rendered
+}
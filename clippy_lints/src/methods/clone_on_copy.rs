@@ -0,0 +1,107 @@
+use crate::utils::sugg;
+use crate::utils::{is_copy, span_lint_and_then};
+use rustc::hir;
+use rustc::lint::LateContext;
+use rustc::ty;
+use rustc_errors::Applicability;
+use std::iter;
+
+use super::{MethodCallCtxt, CLONE_DOUBLE_REF, CLONE_ON_COPY};
+
+/// Checks for the `CLONE_ON_COPY` and `CLONE_DOUBLE_REF` lints.
+pub(super) fn lint(cx: &LateContext<'_, '_>, call: &MethodCallCtxt<'_>) {
+    if call.args.len() != 1 || call.name != "clone" {
+        return;
+    }
+    let (expr, arg) = (call.expr, &call.args[0]);
+    let ty = cx.tables.expr_ty(expr);
+    if let ty::Ref(_, inner, _) = call.self_ty.sty {
+        if let ty::Ref(_, innermost, _) = inner.sty {
+            span_lint_and_then(
+                cx,
+                CLONE_DOUBLE_REF,
+                expr.span,
+                "using `clone` on a double-reference; \
+                 this will copy the reference instead of cloning the inner type",
+                |db| {
+                    if let Some(snip) = sugg::Sugg::hir_opt(cx, arg) {
+                        let mut ty = innermost;
+                        let mut n = 0;
+                        while let ty::Ref(_, inner, _) = ty.sty {
+                            ty = inner;
+                            n += 1;
+                        }
+                        let refs: String = iter::repeat('&').take(n + 1).collect();
+                        let derefs: String = iter::repeat('*').take(n).collect();
+                        let explicit = format!("{}{}::clone({})", refs, ty, snip);
+                        db.span_suggestion(
+                            expr.span,
+                            "try dereferencing it",
+                            format!("{}({}{}).clone()", refs, derefs, snip.deref()),
+                            Applicability::MaybeIncorrect,
+                        );
+                        db.span_suggestion(
+                            expr.span,
+                            "or try being explicit about what type to clone",
+                            explicit,
+                            Applicability::MaybeIncorrect,
+                        );
+                    }
+                },
+            );
+            return; // don't report clone_on_copy
+        }
+    }
+
+    if is_copy(cx, ty) {
+        let snip;
+        if let Some(snippet) = sugg::Sugg::hir_opt(cx, arg) {
+            // x.clone() might have dereferenced x, possibly through Deref impls
+            if call.recv_ty == ty {
+                snip = Some(("try removing the `clone` call", format!("{}", snippet)));
+            } else {
+                let parent = cx.tcx.hir().get_parent_node_by_hir_id(expr.hir_id);
+                match cx.tcx.hir().get_by_hir_id(parent) {
+                    hir::Node::Expr(parent) => match parent.node {
+                        // &*x is a nop, &x.clone() is not
+                        hir::ExprKind::AddrOf(..) |
+                        // (*x).func() is useless, x.clone().func() can work in case func borrows mutably
+                        hir::ExprKind::MethodCall(..) => return,
+                        _ => {},
+                    },
+                    hir::Node::Stmt(stmt) => {
+                        if let hir::StmtKind::Local(ref loc) = stmt.node {
+                            if let hir::PatKind::Ref(..) = loc.pat.node {
+                                // let ref y = *x borrows x, let ref y = x.clone() does not
+                                return;
+                            }
+                        }
+                    },
+                    _ => {},
+                }
+
+                let deref_count = cx
+                    .tables
+                    .expr_adjustments(arg)
+                    .iter()
+                    .filter(|adj| {
+                        if let ty::adjustment::Adjust::Deref(_) = adj.kind {
+                            true
+                        } else {
+                            false
+                        }
+                    })
+                    .count();
+                let derefs: String = iter::repeat('*').take(deref_count).collect();
+                snip = Some(("try dereferencing it", format!("{}{}", derefs, snippet)));
+            }
+        } else {
+            snip = None;
+        }
+        span_lint_and_then(cx, CLONE_ON_COPY, expr.span, "using `clone` on a `Copy` type", |db| {
+            if let Some((text, snip)) = snip {
+                db.span_suggestion(expr.span, text, snip, Applicability::Unspecified);
+            }
+        });
+    }
+}
@@ -0,0 +1,51 @@
+use crate::utils::edition::{self, EditionExt};
+use crate::utils::paths;
+use crate::utils::{match_type, snippet, span_lint_and_sugg, walk_ptrs_ty};
+use rustc::hir;
+use rustc::lint::LateContext;
+use rustc::ty;
+use rustc_errors::Applicability;
+
+use super::{MethodCallCtxt, CLONE_ON_REF_PTR};
+
+pub(super) fn lint(cx: &LateContext<'_, '_>, call: &MethodCallCtxt<'_>) {
+    if call.args.len() != 1 || call.name != "clone" {
+        return;
+    }
+    let obj_ty = walk_ptrs_ty(call.recv_ty);
+
+    if let ty::Adt(_, subst) = obj_ty.sty {
+        let caller_type = if match_type(cx, obj_ty, &paths::RC) {
+            "Rc"
+        } else if match_type(cx, obj_ty, &paths::ARC) {
+            "Arc"
+        } else if match_type(cx, obj_ty, &paths::WEAK_RC) || match_type(cx, obj_ty, &paths::WEAK_ARC) {
+            "Weak"
+        } else {
+            return;
+        };
+        let inner_ty = subst.type_at(0);
+        let inner_name = match inner_ty.sty {
+            ty::Dynamic(..) => {
+                let name = inner_ty.to_string();
+                edition::TRAIT_OBJECT.apply(cx.edition(), name.trim_start_matches("dyn "))
+            },
+            _ => inner_ty.to_string(),
+        };
+
+        span_lint_and_sugg(
+            cx,
+            CLONE_ON_REF_PTR,
+            call.expr.span,
+            "using '.clone()' on a ref-counted pointer",
+            "try this",
+            format!(
+                "{}::<{}>::clone(&{})",
+                caller_type,
+                inner_name,
+                snippet(cx, call.args[0].span, "_")
+            ),
+            Applicability::Unspecified, // Sometimes unnecessary ::<_> after Rc/Arc/Weak
+        );
+    }
+}
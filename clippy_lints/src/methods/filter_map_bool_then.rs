@@ -0,0 +1,242 @@
+use crate::utils::{
+    apply_edits, get_parent_expr, is_copy, match_def_path, match_trait_method, snippet_opt, span_lint_and_sugg,
+};
+use crate::utils::{msrvs, paths};
+use matches::matches;
+use rustc::hir;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::lint::LateContext;
+use rustc::ty::adjustment::Adjust;
+use rustc_errors::Applicability;
+use semver::Version;
+use syntax::ast::NodeId;
+use syntax::source_map::Span;
+
+use super::FILTER_MAP_BOOL_THEN;
+
+/// Lints `filter_map(|x| cond(x).then(|| f(x)))`, where `expr` is the
+/// `filter_map` call and `args` its arguments.
+pub(super) fn lint(cx: &LateContext<'_, '_>, expr: &hir::Expr, args: &[hir::Expr], msrv: Option<&Version>) {
+    if !msrvs::meets(msrv, msrvs::BOOL_THEN) || !match_trait_method(cx, expr, &paths::ITERATOR) {
+        return;
+    }
+    let method_span = match expr.node {
+        hir::ExprKind::MethodCall(_, span, _) => span,
+        _ => return,
+    };
+    let (decl, body_id) = match args[1].node {
+        hir::ExprKind::Closure(_, ref decl, body_id, ..) => (decl, body_id),
+        _ => return,
+    };
+    let body = cx.tcx.hir().body(body_id);
+    let (cond, value) = match then_call(cx, peel_block(&body.value)) {
+        Some(then) => then,
+        None => return,
+    };
+    if decl.inputs.len() != 1 || !matches!(decl.inputs[0].node, hir::TyKind::Infer) {
+        return;
+    }
+    let param = &body.arguments[0].pat;
+    let (binding, by_ref) = match param.node {
+        hir::PatKind::Binding(hir::BindingAnnotation::Unannotated, _, _, _, None) => (&**param, false),
+        hir::PatKind::Ref(ref inner, _) => match inner.node {
+            hir::PatKind::Binding(hir::BindingAnnotation::Unannotated, _, _, _, None) => (&**inner, true),
+            _ => return,
+        },
+        _ => return,
+    };
+    let (param_snippet, cond_snippet, value_snippet) = match (
+        snippet_opt(cx, param.span),
+        snippet_opt(cx, cond.span),
+        snippet_opt(cx, value.span),
+    ) {
+        (Some(param), Some(cond), Some(value)) => (param, cond, value),
+        _ => return,
+    };
+
+    // `filter` passes a reference to the item: if the binding is `Copy`, the
+    // pattern can dereference it, otherwise the condition has to use the reference
+    let mut applicability = Applicability::MachineApplicable;
+    let filter = if is_copy(cx, cx.tables.pat_ty(binding)) {
+        format!("|&{}| {}", param_snippet, strip_parens(&cond_snippet))
+    } else if by_ref {
+        return;
+    } else {
+        let id = match binding.node {
+            hir::PatKind::Binding(_, id, ..) => id,
+            _ => return,
+        };
+        let mut visitor = RefParamVisitor {
+            cx,
+            id,
+            edits: Vec::new(),
+            valid: true,
+        };
+        visitor.visit_expr(cond);
+        if !visitor.valid {
+            return;
+        }
+        applicability = Applicability::MaybeIncorrect;
+        match apply_edits(&cond_snippet, cond.span, visitor.edits) {
+            Some(cond) => format!("|{}| {}", param_snippet, strip_parens(&cond)),
+            None => return,
+        }
+    };
+    let map = match mapped_fn(cx, binding, by_ref, value) {
+        Some(f) => f,
+        None => format!("|{}| {}", param_snippet, value_snippet),
+    };
+
+    span_lint_and_sugg(
+        cx,
+        FILTER_MAP_BOOL_THEN,
+        method_span,
+        "usage of `bool::then` in `filter_map`",
+        "use `filter` then `map` instead",
+        format!("filter({}).map({})", filter, map),
+        applicability,
+    );
+}
+
+/// Matches `cond.then(|| value)` of `bool::then`, not of a trait with such a
+/// method, returning `cond` and `value`.
+fn then_call<'a>(cx: &LateContext<'_, '_>, expr: &'a hir::Expr) -> Option<(&'a hir::Expr, &'a hir::Expr)> {
+    if let hir::ExprKind::MethodCall(_, _, ref args) = expr.node {
+        let is_bool_then = cx
+            .tables
+            .type_dependent_defs()
+            .get(expr.hir_id)
+            .map_or(false, |def| match_def_path(cx.tcx, def.def_id(), &paths::BOOL_THEN));
+        if is_bool_then && args.len() == 2 {
+            if let hir::ExprKind::Closure(_, ref decl, body_id, ..) = args[1].node {
+                if decl.inputs.is_empty() {
+                    return Some((&args[0], peel_block(&cx.tcx.hir().body(body_id).value)));
+                }
+            }
+        }
+    }
+    None
+}
+
+/// The expression of a block without statements, or `expr` itself.
+fn peel_block(expr: &hir::Expr) -> &hir::Expr {
+    match expr.node {
+        hir::ExprKind::Block(ref block, _) if block.stmts.is_empty() && block.rules == hir::DefaultBlock => {
+            block.expr.as_ref().map_or(expr, |inner| peel_block(inner))
+        },
+        _ => expr,
+    }
+}
+
+/// `f` for a `value` of `f(x)`, where `x` is the binding of the closure.
+fn mapped_fn(cx: &LateContext<'_, '_>, binding: &hir::Pat, by_ref: bool, value: &hir::Expr) -> Option<String> {
+    if by_ref {
+        return None;
+    }
+    if let hir::ExprKind::Call(ref callee, ref args) = value.node {
+        if let (hir::ExprKind::Path(_), [ref arg]) = (&callee.node, &**args) {
+            if is_binding(binding, arg) && cx.tables.expr_adjustments(arg).is_empty() {
+                return snippet_opt(cx, callee.span);
+            }
+        }
+    }
+    None
+}
+
+/// Whether `expr` is a path to `binding`, also from within a closure.
+fn is_binding(binding: &hir::Pat, expr: &hir::Expr) -> bool {
+    if let (hir::PatKind::Binding(_, id, ..), hir::ExprKind::Path(hir::QPath::Resolved(None, ref path))) =
+        (&binding.node, &expr.node)
+    {
+        if let Def::Local(local) | Def::Upvar(local, ..) = path.def {
+            return local == *id;
+        }
+    }
+    false
+}
+
+/// Whether `expr` is borrowed automatically, e.g. as the receiver of a method
+/// taking `&self`.
+fn is_borrowed(cx: &LateContext<'_, '_>, expr: &hir::Expr) -> bool {
+    cx.tables
+        .expr_adjustments(expr)
+        .first()
+        .map_or(false, |adjustment| matches!(adjustment.kind, Adjust::Borrow(_)))
+}
+
+/// `(cond)` to `cond`, as the condition is the whole body of the closure.
+fn strip_parens(snippet: &str) -> &str {
+    if !snippet.starts_with('(') || !snippet.ends_with(')') {
+        return snippet;
+    }
+    let mut depth = 0;
+    for (i, c) in snippet.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => {},
+        }
+        // the first paren is closed before the end, as in `(a) == (b)`
+        if depth == 0 && i < snippet.len() - 1 {
+            return snippet;
+        }
+    }
+    &snippet[1..snippet.len() - 1]
+}
+
+/// Collects the edits to the condition for its binding becoming a reference:
+/// `&x` becomes `x`. Uses that are dereferenced automatically stay, others
+/// make the rewrite invalid.
+struct RefParamVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    id: NodeId,
+    edits: Vec<(Span, String)>,
+    valid: bool,
+}
+
+impl<'a, 'tcx: 'a> Visitor<'tcx> for RefParamVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr) {
+        let is_param = match expr.node {
+            hir::ExprKind::Path(hir::QPath::Resolved(None, ref path)) => match path.def {
+                Def::Local(id) | Def::Upvar(id, ..) => id == self.id,
+                _ => false,
+            },
+            _ => false,
+        };
+        if !is_param {
+            walk_expr(self, expr);
+            return;
+        }
+        let parent = match get_parent_expr(self.cx, expr) {
+            Some(parent) => parent,
+            None => {
+                self.valid = false;
+                return;
+            },
+        };
+        match parent.node {
+            hir::ExprKind::AddrOf(hir::MutImmutable, _) => match snippet_opt(self.cx, expr.span) {
+                Some(name) => self.edits.push((parent.span, name)),
+                None => self.valid = false,
+            },
+            // method receivers taken by reference and fields that aren't moved are
+            // dereferenced as needed
+            hir::ExprKind::MethodCall(_, _, ref args) if args[0].hir_id == expr.hir_id => {
+                self.valid &= is_borrowed(self.cx, expr);
+            },
+            hir::ExprKind::Field(..) => {
+                self.valid &= is_copy(self.cx, self.cx.tables.expr_ty(parent))
+                    || is_borrowed(self.cx, parent)
+                    || get_parent_expr(self.cx, parent).map_or(false, |field_parent| {
+                        matches!(field_parent.node, hir::ExprKind::AddrOf(..))
+                    });
+            },
+            _ => self.valid = false,
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir())
+    }
+}
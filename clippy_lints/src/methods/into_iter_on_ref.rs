@@ -0,0 +1,55 @@
+use crate::utils::paths;
+use crate::utils::{has_iter_method, match_trait_method, span_lint_and_sugg};
+use rustc::hir;
+use rustc::lint::{LateContext, Lint};
+use rustc::ty;
+use rustc_errors::Applicability;
+
+use super::{MethodCallCtxt, INTO_ITER_ON_ARRAY, INTO_ITER_ON_REF};
+
+fn ty_has_iter_method(
+    cx: &LateContext<'_, '_>,
+    self_ref_ty: ty::Ty<'_>,
+) -> Option<(&'static Lint, &'static str, &'static str)> {
+    if let Some(ty_name) = has_iter_method(cx, self_ref_ty) {
+        let lint = match ty_name {
+            "array" | "PathBuf" => INTO_ITER_ON_ARRAY,
+            _ => INTO_ITER_ON_REF,
+        };
+        let mutbl = match self_ref_ty.sty {
+            ty::Ref(_, _, mutbl) => mutbl,
+            _ => unreachable!(),
+        };
+        let method_name = match mutbl {
+            hir::MutImmutable => "iter",
+            hir::MutMutable => "iter_mut",
+        };
+        Some((lint, ty_name, method_name))
+    } else {
+        None
+    }
+}
+
+pub(super) fn lint(cx: &LateContext<'_, '_>, call: &MethodCallCtxt<'_>) {
+    match call.self_ty.sty {
+        ty::Ref(..) if call.name == "into_iter" => {},
+        _ => return,
+    }
+    if !match_trait_method(cx, call.expr, &paths::INTO_ITERATOR) {
+        return;
+    }
+    if let Some((lint, kind, method_name)) = ty_has_iter_method(cx, call.self_ty) {
+        span_lint_and_sugg(
+            cx,
+            lint,
+            call.method_span,
+            &format!(
+                "this .into_iter() call is equivalent to .{}() and will not move the {}",
+                method_name, kind,
+            ),
+            "call directly",
+            method_name.to_string(),
+            Applicability::MachineApplicable,
+        );
+    }
+}
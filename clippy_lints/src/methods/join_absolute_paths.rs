@@ -0,0 +1,98 @@
+use crate::utils::target::TargetFamily;
+use crate::utils::{match_type, paths, span_lint_and_then, walk_ptrs_ty};
+use rustc::hir;
+use rustc::lint::LateContext;
+use rustc_errors::Applicability;
+use syntax::ast::{LitKind, StrStyle};
+
+use super::{MethodCallCtxt, JOIN_ABSOLUTE_PATHS};
+
+pub(super) fn lint(cx: &LateContext<'_, '_>, call: &MethodCallCtxt<'_>) {
+    if call.name != "join" || call.args.len() != 2 || !match_type(cx, walk_ptrs_ty(call.self_ty), &paths::PATH) {
+        return;
+    }
+    let arg = &call.args[1];
+    let (path, style) = match arg.node {
+        hir::ExprKind::Lit(ref lit) => match lit.node {
+            LitKind::Str(ref path, style) => (path.as_str(), style),
+            _ => return,
+        },
+        _ => return,
+    };
+    let family = TargetFamily::of(cx);
+    if !is_absolute(&path, family) {
+        return;
+    }
+    span_lint_and_then(
+        cx,
+        JOIN_ABSOLUTE_PATHS,
+        arg.span,
+        "argument to `Path::join` is an absolute path",
+        |db| {
+            db.note("joining an absolute path replaces the base path instead of appending to it");
+            db.span_suggestion(
+                call.expr.span,
+                "if the base path should be replaced, use `PathBuf::from`",
+                format!("std::path::PathBuf::from({})", literal(&path, style)),
+                Applicability::MaybeIncorrect,
+            );
+            let relative = path.trim_start_matches(|c| is_separator(c, family));
+            // a path with a drive prefix stays absolute
+            if !is_absolute(relative, family) {
+                db.span_suggestion(
+                    arg.span,
+                    "if the path should be appended, remove the leading separators",
+                    literal(relative, style),
+                    Applicability::MaybeIncorrect,
+                );
+            }
+        },
+    );
+}
+
+fn is_separator(c: char, family: TargetFamily) -> bool {
+    c == '/' || (c == '\\' && family == TargetFamily::Windows)
+}
+
+/// Whether joining `path` replaces the base path on the target. On Windows,
+/// this is the case for a root like `\dir` as well as a prefix like `C:` or
+/// `\\server\share`.
+fn is_absolute(path: &str, family: TargetFamily) -> bool {
+    match path.chars().next() {
+        Some(c) if is_separator(c, family) => true,
+        Some(c) if family == TargetFamily::Windows => c.is_ascii_alphabetic() && path[1..].starts_with(':'),
+        _ => false,
+    }
+}
+
+/// A string literal with the contents `value`, raw if `style` is.
+fn literal(value: &str, style: StrStyle) -> String {
+    match style {
+        StrStyle::Raw(n) => {
+            let hashes = "#".repeat(n as usize);
+            format!("r{0}\"{1}\"{0}", hashes, value)
+        },
+        StrStyle::Cooked => format!("{:?}", value),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn absolute_per_target() {
+        for &family in &[TargetFamily::Unix, TargetFamily::Windows, TargetFamily::Other] {
+            assert!(is_absolute("/usr", family));
+            assert!(!is_absolute("usr", family));
+            assert!(!is_absolute("", family));
+        }
+        assert!(is_absolute(r"\Windows", TargetFamily::Windows));
+        assert!(is_absolute(r"\\server\share", TargetFamily::Windows));
+        assert!(is_absolute(r"C:\Windows", TargetFamily::Windows));
+        assert!(is_absolute("c:", TargetFamily::Windows));
+        assert!(!is_absolute(r"\Windows", TargetFamily::Unix));
+        assert!(!is_absolute(r"C:\Windows", TargetFamily::Unix));
+        assert!(!is_absolute("C", TargetFamily::Windows));
+    }
+}
@@ -0,0 +1,54 @@
+//! Chain rules for `std::io::BufRead::lines`.
+
+use crate::utils::{msrvs, paths};
+use rustc_errors::Applicability;
+
+use super::chain_rules::{ArgPred, ChainRule, Fix, Receiver};
+use super::LINES_FILTER_MAP_OK;
+
+/// `map_while` keeps the lines up to the first error. Older Rust versions
+/// only get the warning.
+const MAP_WHILE: Fix = Fix::Since {
+    version: msrvs::ITER_MAP_WHILE,
+    fix: &Fix::Sugg {
+        help: "stop at the first error with",
+        template: "{recv}.lines().map_while(Result::ok)",
+        applicability: Applicability::MaybeIncorrect,
+    },
+    fallback: &Fix::None,
+};
+
+/// The item type of `lines()` is `io::Result<String>`.
+#[rustfmt::skip]
+pub(super) static LINES_RULES: &[ChainRule] = &[
+    ChainRule {
+        lint: &LINES_FILTER_MAP_OK,
+        methods: &["lines", "filter_map"],
+        receiver: Receiver::TraitMethod(0, &paths::IO_BUF_READ),
+        args: &[(0, ArgPred::Path(&["Result", "ok"]))],
+        check: None,
+        msrv: None,
+        msg: "`filter_map()` on the `lines()` of a reader can run forever if reading repeatedly fails",
+        fix: MAP_WHILE,
+    },
+    ChainRule {
+        lint: &LINES_FILTER_MAP_OK,
+        methods: &["lines", "flat_map"],
+        receiver: Receiver::TraitMethod(0, &paths::IO_BUF_READ),
+        args: &[(0, ArgPred::Path(&["Result", "ok"]))],
+        check: None,
+        msrv: None,
+        msg: "`flat_map()` on the `lines()` of a reader can run forever if reading repeatedly fails",
+        fix: MAP_WHILE,
+    },
+    ChainRule {
+        lint: &LINES_FILTER_MAP_OK,
+        methods: &["lines", "flatten"],
+        receiver: Receiver::TraitMethod(0, &paths::IO_BUF_READ),
+        args: &[],
+        check: None,
+        msrv: None,
+        msg: "`flatten()` on the `lines()` of a reader can run forever if reading repeatedly fails",
+        fix: MAP_WHILE,
+    },
+];
@@ -1,32 +1,72 @@
 use crate::utils::paths;
-use crate::utils::sugg;
 use crate::utils::{
-    get_arg_name, get_parent_expr, get_trait_def_id, has_iter_method, implements_trait, in_macro, is_copy, is_expn_of,
-    is_self, is_self_ty, iter_input_pats, last_path_segment, match_def_path, match_path, match_qpath,
-    match_trait_method, match_type, match_var, method_calls, method_chain_args, remove_blocks, return_ty, same_tys,
-    single_segment_path, snippet, snippet_with_applicability, snippet_with_macro_callsite, span_lint,
-    span_lint_and_sugg, span_lint_and_then, span_note_and_lint, walk_ptrs_ty, walk_ptrs_ty_depth, SpanlessEq,
+    in_macro, is_copy, is_self, is_self_ty, iter_input_pats, match_path, match_qpath, match_type, method_calls,
+    return_ty, same_tys, single_segment_path, span_lint, SpanlessEq,
 };
 use if_chain::if_chain;
-use matches::matches;
 use rustc::hir;
-use rustc::hir::def::Def;
-use rustc::lint::{in_external_macro, LateContext, LateLintPass, Lint, LintArray, LintContext, LintPass};
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
 use rustc::ty::{self, Predicate, Ty};
 use rustc::{declare_tool_lint, lint_array};
-use rustc_errors::Applicability;
-use std::borrow::Cow;
+use semver::Version;
 use std::fmt;
-use std::iter;
-use syntax::ast;
-use syntax::source_map::{BytePos, Span};
+use syntax::source_map::Span;
 use syntax::symbol::LocalInternedString;
 
+mod chain_rules;
+mod chars_cmp;
+mod clone_on_copy;
+mod clone_on_ref_ptr;
+mod expect_fun_call;
+mod filter_map_bool_then;
+mod get_unwrap;
+mod into_iter_on_ref;
+mod join_absolute_paths;
+mod lines_filter_map_ok;
+mod map_unwrap_or_else;
 mod option_map_unwrap_or;
+mod option_result_conversions;
+mod or_fun_call;
+mod permissions_set_readonly_false;
+mod redundant_as_str;
+mod seek;
+mod single_char_pattern;
+mod string_extend_chars;
+mod string_lit_chars_any;
 mod unnecessary_filter_map;
+mod unnecessary_fold;
+mod unnecessary_to_owned;
+mod unused_rounding;
+mod unwrap_used;
+mod useless_asref;
+
+use self::chars_cmp::BinaryExprInfo;
 
 #[derive(Clone)]
-pub struct Pass;
+pub struct Pass {
+    msrv: Option<Version>,
+}
+
+impl Pass {
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
+
+/// A method call, with what most checks need computed once for all of them.
+pub(super) struct MethodCallCtxt<'tcx> {
+    /// The whole call.
+    pub expr: &'tcx hir::Expr,
+    pub name: LocalInternedString,
+    /// The span of the method name and its arguments, without the receiver.
+    pub method_span: Span,
+    /// The receiver, followed by the arguments.
+    pub args: &'tcx [hir::Expr],
+    /// The type of the receiver as written.
+    pub recv_ty: Ty<'tcx>,
+    /// The type of the receiver after auto-referencing and auto-dereferencing.
+    pub self_ty: Ty<'tcx>,
+}
 
 declare_clippy_lint! {
     /// **What it does:** Checks for `.unwrap()` calls on `Option`s.
@@ -236,6 +276,76 @@ declare_clippy_lint! {
     "using `Option.map_or(None, f)`, which is more succinctly expressed as `and_then(f)`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `_.map_or(Err(_), Ok)` and
+    /// `_.map(Ok).unwrap_or(Err(_))` on an `Option`.
+    ///
+    /// **Why is this bad?** Readability, this can be written more concisely as
+    /// `_.ok_or(_)`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let opt = Some(1);
+    /// let res: Result<u32, &str> = opt.map_or(Err("missing"), Ok);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let opt = Some(1);
+    /// let res: Result<u32, &str> = opt.ok_or("missing");
+    /// ```
+    pub OPTION_MAP_OR_ERR_OK,
+    style,
+    "using `Option.map_or(Err(e), Ok)`, which is more succinctly expressed as `ok_or(e)`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `_.map(Some).unwrap_or(None)` on
+    /// a `Result`.
+    ///
+    /// **Why is this bad?** Readability, this can be written more concisely as
+    /// `_.ok()`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let res: Result<u32, ()> = Ok(1);
+    /// let opt = res.map(Some).unwrap_or(None);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let res: Result<u32, ()> = Ok(1);
+    /// let opt = res.ok();
+    /// ```
+    pub MANUAL_RESULT_OK,
+    style,
+    "using `Result.map(Some).unwrap_or(None)`, which is more succinctly expressed as `ok()`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `ok().unwrap_or(_)` and
+    /// `ok().unwrap_or_default()` on a `Result`.
+    ///
+    /// **Why is this bad?** The `Result` has the same methods, converting it
+    /// to an `Option` first is a detour.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let port = "8080".parse::<u16>().ok().unwrap_or(80);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let port = "8080".parse::<u16>().unwrap_or(80);
+    /// ```
+    pub OK_UNWRAP_OR,
+    style,
+    "using `ok().unwrap_or(_)`, which can be called on the `Result` directly"
+}
+
 declare_clippy_lint! {
     /// **What it does:** Checks for usage of `_.filter(_).next()`.
     ///
@@ -458,10 +568,11 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// **What it does:** Checks for string methods that receive a single-character
-    /// `str` as an argument, e.g. `_.split("x")`.
+    /// `str` or a one-element `char` array as an argument, e.g. `_.split("x")` or
+    /// `_.trim_matches(&['x'])`.
     ///
     /// **Why is this bad?** Performing these methods using a `char` is faster than
-    /// using a `str`.
+    /// using a `str` or an array.
     ///
     /// **Known problems:** Does not catch multi-byte unicode characters.
     ///
@@ -775,6 +886,277 @@ declare_clippy_lint! {
     "using `.into_iter()` on a reference"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for `.to_owned()`, `.to_string()`, `.to_vec()`
+    /// and `.clone()` on borrowed arguments passed to functions that only need a
+    /// borrow, e.g. parameters of type `impl AsRef<str>` or `&str`.
+    ///
+    /// **Why is this bad?** The conversion allocates a new value that is only
+    /// borrowed and immediately dropped again.
+    ///
+    /// **Known problems:** Only the signature of the callee is considered, so
+    /// generic parameters with bounds other than `AsRef` and `Borrow` are never
+    /// linted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::path::Path;
+    /// fn exists<P: AsRef<Path>>(path: P) -> bool {
+    ///     path.as_ref().exists()
+    /// }
+    ///
+    /// let name = "foo.txt";
+    /// // Bad
+    /// exists(name.to_owned());
+    /// // Good
+    /// exists(name);
+    /// ```
+    pub UNNECESSARY_TO_OWNED,
+    perf,
+    "unnecessary calls to `to_owned`-like functions"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `round`, `floor` and `ceil` on floats that
+    /// are already whole numbers, i.e. constants without fractional part and
+    /// integers cast to a float.
+    ///
+    /// **Why is this bad?** The call has no effect.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let x = 1u32;
+    /// let a = 3.0_f32.round();
+    /// let b = (x as f64).floor();
+    /// ```
+    ///
+    /// Could be written as:
+    ///
+    /// ```rust
+    /// # let x = 1u32;
+    /// let a = 3.0_f32;
+    /// let b = x as f64;
+    /// ```
+    pub UNUSED_ROUNDING,
+    complexity,
+    "rounding a float that is already a whole number"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `seek(SeekFrom::Current(0))`.
+    ///
+    /// **Why is this bad?** `stream_position()` is clearer. Requires Rust
+    /// 1.51, the lint respects the `msrv` option.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// let pos = file.seek(SeekFrom::Current(0))?;
+    /// ```
+    ///
+    /// Could be written as:
+    ///
+    /// ```rust,ignore
+    /// let pos = file.stream_position()?;
+    /// ```
+    pub SEEK_FROM_CURRENT,
+    complexity,
+    "use of `seek(SeekFrom::Current(0))` instead of `stream_position()`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `seek(SeekFrom::Start(0))` whose result
+    /// is not used.
+    ///
+    /// **Why is this bad?** `rewind()` is clearer. Requires Rust 1.55, the
+    /// lint respects the `msrv` option.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// file.seek(SeekFrom::Start(0))?;
+    /// ```
+    ///
+    /// Could be written as:
+    ///
+    /// ```rust,ignore
+    /// file.rewind()?;
+    /// ```
+    pub SEEK_TO_START_INSTEAD_OF_REWIND,
+    complexity,
+    "use of `seek(SeekFrom::Start(0))` instead of `rewind()`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `lines().filter_map(Result::ok)`,
+    /// `lines().flat_map(Result::ok)` and `lines().flatten()` on a `BufRead`.
+    ///
+    /// **Why is this bad?** Read errors are silently dropped. Worse, if the
+    /// reader keeps failing, e.g. because it is a directory opened as a file,
+    /// `lines()` keeps producing the same error and the loop never ends.
+    /// `map_while(Result::ok)` stops at the first error instead, it is only
+    /// suggested if the `msrv` option allows Rust 1.57.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// for line in BufReader::new(file).lines().filter_map(Result::ok) {
+    ///     println!("{}", line);
+    /// }
+    /// ```
+    ///
+    /// Could be written as:
+    ///
+    /// ```rust,ignore
+    /// for line in BufReader::new(file).lines().map_while(Result::ok) {
+    ///     println!("{}", line);
+    /// }
+    /// ```
+    pub LINES_FILTER_MAP_OK,
+    pedantic,
+    "filtering the errors out of `BufRead::lines()`, which can loop forever"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `std::fs::Permissions::set_readonly(false)`.
+    ///
+    /// **Why is this bad?** On Unix, this sets all write bits of the mode, so
+    /// every user can write to the file, which is rarely intended. The help
+    /// depends on the target: on Unix, `PermissionsExt::set_mode` is
+    /// suggested instead.
+    ///
+    /// **Known problems:** On Windows, the call only clears the read-only
+    /// attribute, which is harmless. Making the file writable by everyone may
+    /// also be intended.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// permissions.set_readonly(false);
+    /// ```
+    ///
+    /// Could be written as:
+    ///
+    /// ```rust,ignore
+    /// use std::os::unix::fs::PermissionsExt;
+    /// permissions.set_mode(0o644);
+    /// ```
+    pub PERMISSIONS_SET_READONLY_FALSE,
+    pedantic,
+    "`Permissions::set_readonly(false)`, which makes a file writable by everyone on Unix"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `filter_map` closures that return
+    /// `bool::then`, like `.filter_map(|x| cond(x).then(|| f(x)))`.
+    ///
+    /// **Why is this bad?** `.filter(|x| cond(x)).map(f)` says the same with
+    /// two simpler steps.
+    ///
+    /// **Known problems:** `filter` passes the items by reference. If they
+    /// aren't `Copy`, the condition is rewritten for that, which is only done
+    /// if it doesn't need the item by value. `bool::then` is stable since Rust
+    /// 1.50, the lint respects the `msrv` option.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// let even_squares = v.iter().filter_map(|&x| (x % 2 == 0).then(|| x * x));
+    /// ```
+    ///
+    /// Could be written as:
+    ///
+    /// ```rust,ignore
+    /// let even_squares = v.iter().filter(|&&x| x % 2 == 0).map(|&x| x * x);
+    /// ```
+    pub FILTER_MAP_BOOL_THEN,
+    style,
+    "using `bool::then` in `filter_map` instead of `filter` and `map`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `"abc".chars().any(|c| c == x)` and
+    /// `['a', 'b', 'c'].contains(&x)`.
+    ///
+    /// **Why is this bad?** `matches!(x, 'a' | 'b' | 'c')` states the set of
+    /// chars directly and compiles to a simple comparison. Requires Rust 1.42,
+    /// the lint respects the `msrv` option.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let c = 'x';
+    /// let is_sign = "+-".chars().any(|s| s == c);
+    /// ```
+    ///
+    /// Could be written as:
+    ///
+    /// ```rust,ignore
+    /// let is_sign = matches!(c, '+' | '-');
+    /// ```
+    pub STRING_LIT_CHARS_ANY,
+    restriction,
+    "checking if a char is in a literal set with `chars().any(..)` or `contains` instead of `matches!`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Path::join` with a string literal that
+    /// is an absolute path on the target: one starting with `/`, or on
+    /// Windows also with `\` or a drive prefix like `C:`.
+    ///
+    /// **Why is this bad?** Joining an absolute path doesn't append it to the
+    /// base path, it replaces the base path, which is rarely intended.
+    ///
+    /// **Known problems:** Only string literals are checked. Replacing the
+    /// base path may also be intended.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::path::Path;
+    /// let config = Path::new("/home/user").join("/.config");
+    /// ```
+    ///
+    /// Could be written as:
+    ///
+    /// ```rust
+    /// # use std::path::Path;
+    /// let config = Path::new("/home/user").join(".config");
+    /// ```
+    pub JOIN_ABSOLUTE_PATHS,
+    pedantic,
+    "`Path::join` with an absolute path, which replaces the base path"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `as_str()` or `as_bytes()` on a `String`,
+    /// `str` or `Cow<str>` right before calling `len()` or `is_empty()`.
+    ///
+    /// **Why is this bad?** The string has these methods itself, and they
+    /// return the same.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let s = String::new();
+    /// let len = s.as_bytes().len();
+    /// ```
+    ///
+    /// Could be written as:
+    ///
+    /// ```rust
+    /// # let s = String::new();
+    /// let len = s.len();
+    /// ```
+    pub REDUNDANT_AS_STR,
+    complexity,
+    "`as_str()` or `as_bytes()` before `len()` or `is_empty()` of a string"
+}
+
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
         lint_array!(
@@ -788,6 +1170,9 @@ impl LintPass for Pass {
             OPTION_MAP_UNWRAP_OR_ELSE,
             RESULT_MAP_UNWRAP_OR_ELSE,
             OPTION_MAP_OR_NONE,
+            OPTION_MAP_OR_ERR_OK,
+            MANUAL_RESULT_OK,
+            OK_UNWRAP_OR,
             OR_FUN_CALL,
             EXPECT_FUN_CALL,
             CHARS_NEXT_CMP,
@@ -812,6 +1197,16 @@ impl LintPass for Pass {
             UNNECESSARY_FILTER_MAP,
             INTO_ITER_ON_ARRAY,
             INTO_ITER_ON_REF,
+            UNNECESSARY_TO_OWNED,
+            UNUSED_ROUNDING,
+            SEEK_FROM_CURRENT,
+            SEEK_TO_START_INSTEAD_OF_REWIND,
+            LINES_FILTER_MAP_OK,
+            PERMISSIONS_SET_READONLY_FALSE,
+            FILTER_MAP_BOOL_THEN,
+            STRING_LIT_CHARS_ANY,
+            JOIN_ABSOLUTE_PATHS,
+            REDUNDANT_AS_STR,
         )
     }
 
@@ -832,59 +1227,45 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
         let method_names: Vec<&str> = method_names.iter().map(std::convert::AsRef::as_ref).collect();
 
         match method_names.as_slice() {
-            ["unwrap", "get"] => lint_get_unwrap(cx, expr, arg_lists[1], false),
-            ["unwrap", "get_mut"] => lint_get_unwrap(cx, expr, arg_lists[1], true),
-            ["unwrap", ..] => lint_unwrap(cx, expr, arg_lists[0]),
-            ["expect", "ok"] => lint_ok_expect(cx, expr, arg_lists[1]),
+            ["unwrap", "get"] => get_unwrap::lint(cx, expr, arg_lists[1], false),
+            ["unwrap", "get_mut"] => get_unwrap::lint(cx, expr, arg_lists[1], true),
+            ["unwrap", ..] => unwrap_used::lint(cx, expr, arg_lists[0]),
             ["unwrap_or", "map"] => option_map_unwrap_or::lint(cx, expr, arg_lists[1], arg_lists[0]),
-            ["unwrap_or_else", "map"] => lint_map_unwrap_or_else(cx, expr, arg_lists[1], arg_lists[0]),
-            ["map_or", ..] => lint_map_or_none(cx, expr, arg_lists[0]),
-            ["next", "filter"] => lint_filter_next(cx, expr, arg_lists[1]),
-            ["map", "filter"] => lint_filter_map(cx, expr, arg_lists[1], arg_lists[0]),
-            ["map", "filter_map"] => lint_filter_map_map(cx, expr, arg_lists[1], arg_lists[0]),
-            ["flat_map", "filter"] => lint_filter_flat_map(cx, expr, arg_lists[1], arg_lists[0]),
-            ["flat_map", "filter_map"] => lint_filter_map_flat_map(cx, expr, arg_lists[1], arg_lists[0]),
-            ["flatten", "map"] => lint_map_flatten(cx, expr, arg_lists[1]),
-            ["is_some", "find"] => lint_search_is_some(cx, expr, "find", arg_lists[1], arg_lists[0]),
-            ["is_some", "position"] => lint_search_is_some(cx, expr, "position", arg_lists[1], arg_lists[0]),
-            ["is_some", "rposition"] => lint_search_is_some(cx, expr, "rposition", arg_lists[1], arg_lists[0]),
-            ["extend", ..] => lint_extend(cx, expr, arg_lists[0]),
-            ["as_ptr", "unwrap"] => lint_cstring_as_ptr(cx, expr, &arg_lists[1][0], &arg_lists[0][0]),
-            ["nth", "iter"] => lint_iter_nth(cx, expr, arg_lists[1], false),
-            ["nth", "iter_mut"] => lint_iter_nth(cx, expr, arg_lists[1], true),
-            ["next", "skip"] => lint_iter_skip_next(cx, expr),
-            ["collect", "cloned"] => lint_iter_cloned_collect(cx, expr, arg_lists[1]),
-            ["as_ref"] => lint_asref(cx, expr, "as_ref", arg_lists[0]),
-            ["as_mut"] => lint_asref(cx, expr, "as_mut", arg_lists[0]),
-            ["fold", ..] => lint_unnecessary_fold(cx, expr, arg_lists[0]),
-            ["filter_map", ..] => unnecessary_filter_map::lint(cx, expr, arg_lists[0]),
+            ["unwrap_or_else", "map"] => map_unwrap_or_else::lint(cx, expr, arg_lists[1], arg_lists[0]),
+            ["extend", ..] => string_extend_chars::lint(cx, expr, arg_lists[0]),
+            ["as_ref"] => useless_asref::lint(cx, expr, "as_ref", arg_lists[0]),
+            ["as_mut"] => useless_asref::lint(cx, expr, "as_mut", arg_lists[0]),
+            ["fold", ..] => unnecessary_fold::lint(cx, expr, arg_lists[0]),
+            ["filter_map", ..] => {
+                unnecessary_filter_map::lint(cx, expr, arg_lists[0]);
+                filter_map_bool_then::lint(cx, expr, arg_lists[0], self.msrv.as_ref());
+            },
             _ => {},
         }
 
-        match expr.node {
-            hir::ExprKind::MethodCall(ref method_call, ref method_span, ref args) => {
-                lint_or_fun_call(cx, expr, *method_span, &method_call.ident.as_str(), args);
-                lint_expect_fun_call(cx, expr, *method_span, &method_call.ident.as_str(), args);
-
-                let self_ty = cx.tables.expr_ty_adjusted(&args[0]);
-                if args.len() == 1 && method_call.ident.name == "clone" {
-                    lint_clone_on_copy(cx, expr, &args[0], self_ty);
-                    lint_clone_on_ref_ptr(cx, expr, &args[0]);
-                }
+        chain_rules::check(cx, expr, &method_names, &arg_lists, self.msrv.as_ref());
 
-                match self_ty.sty {
-                    ty::Ref(_, ty, _) if ty.sty == ty::Str => {
-                        for &(method, pos) in &PATTERN_METHODS {
-                            if method_call.ident.name == method && args.len() > pos {
-                                lint_single_char_pattern(cx, expr, &args[pos]);
-                            }
-                        }
-                    },
-                    ty::Ref(..) if method_call.ident.name == "into_iter" => {
-                        lint_into_iter(cx, expr, self_ty, *method_span);
-                    },
-                    _ => (),
-                }
+        match expr.node {
+            hir::ExprKind::MethodCall(ref method_call, method_span, ref args) => {
+                let call = MethodCallCtxt {
+                    expr,
+                    name: method_call.ident.as_str(),
+                    method_span,
+                    args,
+                    recv_ty: cx.tables.expr_ty(&args[0]),
+                    self_ty: cx.tables.expr_ty_adjusted(&args[0]),
+                };
+                or_fun_call::lint(cx, &call);
+                expect_fun_call::lint(cx, &call);
+                unnecessary_to_owned::lint(cx, &call);
+                unused_rounding::lint(cx, &call);
+                clone_on_copy::lint(cx, &call);
+                clone_on_ref_ptr::lint(cx, &call);
+                single_char_pattern::lint(cx, &call);
+                into_iter_on_ref::lint(cx, &call);
+                permissions_set_readonly_false::lint(cx, &call);
+                join_absolute_paths::lint(cx, &call);
+                string_lit_chars_any::lint(cx, &call, self.msrv.as_ref());
             },
             hir::ExprKind::Binary(op, ref lhs, ref rhs)
                 if op.node == hir::BinOpKind::Eq || op.node == hir::BinOpKind::Ne =>
@@ -895,8 +1276,8 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                     other: rhs,
                     eq: op.node == hir::BinOpKind::Eq,
                 };
-                lint_binary_expr_with_method_call(cx, &mut info);
-            }
+                chars_cmp::lint(cx, &mut info);
+            },
             _ => (),
         }
     }
@@ -1005,693 +1386,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
     }
 }
 
-/// Checks for the `OR_FUN_CALL` lint.
-#[allow(clippy::too_many_lines)]
-fn lint_or_fun_call(cx: &LateContext<'_, '_>, expr: &hir::Expr, method_span: Span, name: &str, args: &[hir::Expr]) {
-    /// Check for `unwrap_or(T::new())` or `unwrap_or(T::default())`.
-    fn check_unwrap_or_default(
-        cx: &LateContext<'_, '_>,
-        name: &str,
-        fun: &hir::Expr,
-        self_expr: &hir::Expr,
-        arg: &hir::Expr,
-        or_has_args: bool,
-        span: Span,
-    ) -> bool {
-        if or_has_args {
-            return false;
-        }
-
-        if name == "unwrap_or" {
-            if let hir::ExprKind::Path(ref qpath) = fun.node {
-                let path = &*last_path_segment(qpath).ident.as_str();
-
-                if ["default", "new"].contains(&path) {
-                    let arg_ty = cx.tables.expr_ty(arg);
-                    let default_trait_id = if let Some(default_trait_id) = get_trait_def_id(cx, &paths::DEFAULT_TRAIT) {
-                        default_trait_id
-                    } else {
-                        return false;
-                    };
-
-                    if implements_trait(cx, arg_ty, default_trait_id, &[]) {
-                        let mut applicability = Applicability::MachineApplicable;
-                        span_lint_and_sugg(
-                            cx,
-                            OR_FUN_CALL,
-                            span,
-                            &format!("use of `{}` followed by a call to `{}`", name, path),
-                            "try this",
-                            format!(
-                                "{}.unwrap_or_default()",
-                                snippet_with_applicability(cx, self_expr.span, "_", &mut applicability)
-                            ),
-                            applicability,
-                        );
-                        return true;
-                    }
-                }
-            }
-        }
-
-        false
-    }
-
-    /// Check for `*or(foo())`.
-    #[allow(clippy::too_many_arguments)]
-    fn check_general_case(
-        cx: &LateContext<'_, '_>,
-        name: &str,
-        method_span: Span,
-        fun_span: Span,
-        self_expr: &hir::Expr,
-        arg: &hir::Expr,
-        or_has_args: bool,
-        span: Span,
-    ) {
-        // (path, fn_has_argument, methods, suffix)
-        let know_types: &[(&[_], _, &[_], _)] = &[
-            (&paths::BTREEMAP_ENTRY, false, &["or_insert"], "with"),
-            (&paths::HASHMAP_ENTRY, false, &["or_insert"], "with"),
-            (&paths::OPTION, false, &["map_or", "ok_or", "or", "unwrap_or"], "else"),
-            (&paths::RESULT, true, &["or", "unwrap_or"], "else"),
-        ];
-
-        // early check if the name is one we care about
-        if know_types.iter().all(|k| !k.2.contains(&name)) {
-            return;
-        }
-
-        // don't lint for constant values
-        let owner_def = cx.tcx.hir().get_parent_did_by_hir_id(arg.hir_id);
-        let promotable = cx.tcx.rvalue_promotable_map(owner_def).contains(&arg.hir_id.local_id);
-        if promotable {
-            return;
-        }
-
-        let self_ty = cx.tables.expr_ty(self_expr);
-
-        let (fn_has_arguments, poss, suffix) = if let Some(&(_, fn_has_arguments, poss, suffix)) =
-            know_types.iter().find(|&&i| match_type(cx, self_ty, i.0))
-        {
-            (fn_has_arguments, poss, suffix)
-        } else {
-            return;
-        };
-
-        if !poss.contains(&name) {
-            return;
-        }
-
-        let sugg: Cow<'_, _> = match (fn_has_arguments, !or_has_args) {
-            (true, _) => format!("|_| {}", snippet_with_macro_callsite(cx, arg.span, "..")).into(),
-            (false, false) => format!("|| {}", snippet_with_macro_callsite(cx, arg.span, "..")).into(),
-            (false, true) => snippet_with_macro_callsite(cx, fun_span, ".."),
-        };
-        let span_replace_word = method_span.with_hi(span.hi());
-        span_lint_and_sugg(
-            cx,
-            OR_FUN_CALL,
-            span_replace_word,
-            &format!("use of `{}` followed by a function call", name),
-            "try this",
-            format!("{}_{}({})", name, suffix, sugg),
-            Applicability::HasPlaceholders,
-        );
-    }
-
-    if args.len() == 2 {
-        match args[1].node {
-            hir::ExprKind::Call(ref fun, ref or_args) => {
-                let or_has_args = !or_args.is_empty();
-                if !check_unwrap_or_default(cx, name, fun, &args[0], &args[1], or_has_args, expr.span) {
-                    check_general_case(
-                        cx,
-                        name,
-                        method_span,
-                        fun.span,
-                        &args[0],
-                        &args[1],
-                        or_has_args,
-                        expr.span,
-                    );
-                }
-            },
-            hir::ExprKind::MethodCall(_, span, ref or_args) => check_general_case(
-                cx,
-                name,
-                method_span,
-                span,
-                &args[0],
-                &args[1],
-                !or_args.is_empty(),
-                expr.span,
-            ),
-            _ => {},
-        }
-    }
-}
-
-/// Checks for the `EXPECT_FUN_CALL` lint.
-#[allow(clippy::too_many_lines)]
-fn lint_expect_fun_call(cx: &LateContext<'_, '_>, expr: &hir::Expr, method_span: Span, name: &str, args: &[hir::Expr]) {
-    // Strip `&`, `as_ref()` and `as_str()` off `arg` until we're left with either a `String` or
-    // `&str`
-    fn get_arg_root<'a>(cx: &LateContext<'_, '_>, arg: &'a hir::Expr) -> &'a hir::Expr {
-        let mut arg_root = arg;
-        loop {
-            arg_root = match &arg_root.node {
-                hir::ExprKind::AddrOf(_, expr) => expr,
-                hir::ExprKind::MethodCall(method_name, _, call_args) => {
-                    if call_args.len() == 1
-                        && (method_name.ident.name == "as_str" || method_name.ident.name == "as_ref")
-                        && {
-                            let arg_type = cx.tables.expr_ty(&call_args[0]);
-                            let base_type = walk_ptrs_ty(arg_type);
-                            base_type.sty == ty::Str || match_type(cx, base_type, &paths::STRING)
-                        }
-                    {
-                        &call_args[0]
-                    } else {
-                        break;
-                    }
-                },
-                _ => break,
-            };
-        }
-        arg_root
-    }
-
-    // Only `&'static str` or `String` can be used directly in the `panic!`. Other types should be
-    // converted to string.
-    fn requires_to_string(cx: &LateContext<'_, '_>, arg: &hir::Expr) -> bool {
-        let arg_ty = cx.tables.expr_ty(arg);
-        if match_type(cx, arg_ty, &paths::STRING) {
-            return false;
-        }
-        if let ty::Ref(ty::ReStatic, ty, ..) = arg_ty.sty {
-            if ty.sty == ty::Str {
-                return false;
-            }
-        };
-        true
-    }
-
-    fn generate_format_arg_snippet(
-        cx: &LateContext<'_, '_>,
-        a: &hir::Expr,
-        applicability: &mut Applicability,
-    ) -> Vec<String> {
-        if let hir::ExprKind::AddrOf(_, ref format_arg) = a.node {
-            if let hir::ExprKind::Match(ref format_arg_expr, _, _) = format_arg.node {
-                if let hir::ExprKind::Tup(ref format_arg_expr_tup) = format_arg_expr.node {
-                    return format_arg_expr_tup
-                        .iter()
-                        .map(|a| snippet_with_applicability(cx, a.span, "..", applicability).into_owned())
-                        .collect();
-                }
-            }
-        };
-
-        unreachable!()
-    }
-
-    fn is_call(node: &hir::ExprKind) -> bool {
-        match node {
-            hir::ExprKind::AddrOf(_, expr) => {
-                is_call(&expr.node)
-            },
-            hir::ExprKind::Call(..)
-            | hir::ExprKind::MethodCall(..)
-            // These variants are debatable or require further examination
-            | hir::ExprKind::If(..)
-            | hir::ExprKind::Match(..)
-            | hir::ExprKind::Block{ .. } => true,
-            _ => false,
-        }
-    }
-
-    if args.len() != 2 || name != "expect" || !is_call(&args[1].node) {
-        return;
-    }
-
-    let receiver_type = cx.tables.expr_ty(&args[0]);
-    let closure_args = if match_type(cx, receiver_type, &paths::OPTION) {
-        "||"
-    } else if match_type(cx, receiver_type, &paths::RESULT) {
-        "|_|"
-    } else {
-        return;
-    };
-
-    let arg_root = get_arg_root(cx, &args[1]);
-
-    let span_replace_word = method_span.with_hi(expr.span.hi());
-
-    let mut applicability = Applicability::MachineApplicable;
-
-    //Special handling for `format!` as arg_root
-    if let hir::ExprKind::Call(ref inner_fun, ref inner_args) = arg_root.node {
-        if is_expn_of(inner_fun.span, "format").is_some() && inner_args.len() == 1 {
-            if let hir::ExprKind::Call(_, format_args) = &inner_args[0].node {
-                let fmt_spec = &format_args[0];
-                let fmt_args = &format_args[1];
-
-                let mut args = vec![snippet(cx, fmt_spec.span, "..").into_owned()];
-
-                args.extend(generate_format_arg_snippet(cx, fmt_args, &mut applicability));
-
-                let sugg = args.join(", ");
-
-                span_lint_and_sugg(
-                    cx,
-                    EXPECT_FUN_CALL,
-                    span_replace_word,
-                    &format!("use of `{}` followed by a function call", name),
-                    "try this",
-                    format!("unwrap_or_else({} panic!({}))", closure_args, sugg),
-                    applicability,
-                );
-
-                return;
-            }
-        }
-    }
-
-    let mut arg_root_snippet: Cow<'_, _> = snippet_with_applicability(cx, arg_root.span, "..", &mut applicability);
-    if requires_to_string(cx, arg_root) {
-        arg_root_snippet.to_mut().push_str(".to_string()");
-    }
-
-    span_lint_and_sugg(
-        cx,
-        EXPECT_FUN_CALL,
-        span_replace_word,
-        &format!("use of `{}` followed by a function call", name),
-        "try this",
-        format!("unwrap_or_else({} {{ panic!({}) }})", closure_args, arg_root_snippet),
-        applicability,
-    );
-}
-
-/// Checks for the `CLONE_ON_COPY` lint.
-fn lint_clone_on_copy(cx: &LateContext<'_, '_>, expr: &hir::Expr, arg: &hir::Expr, arg_ty: Ty<'_>) {
-    let ty = cx.tables.expr_ty(expr);
-    if let ty::Ref(_, inner, _) = arg_ty.sty {
-        if let ty::Ref(_, innermost, _) = inner.sty {
-            span_lint_and_then(
-                cx,
-                CLONE_DOUBLE_REF,
-                expr.span,
-                "using `clone` on a double-reference; \
-                 this will copy the reference instead of cloning the inner type",
-                |db| {
-                    if let Some(snip) = sugg::Sugg::hir_opt(cx, arg) {
-                        let mut ty = innermost;
-                        let mut n = 0;
-                        while let ty::Ref(_, inner, _) = ty.sty {
-                            ty = inner;
-                            n += 1;
-                        }
-                        let refs: String = iter::repeat('&').take(n + 1).collect();
-                        let derefs: String = iter::repeat('*').take(n).collect();
-                        let explicit = format!("{}{}::clone({})", refs, ty, snip);
-                        db.span_suggestion(
-                            expr.span,
-                            "try dereferencing it",
-                            format!("{}({}{}).clone()", refs, derefs, snip.deref()),
-                            Applicability::MaybeIncorrect,
-                        );
-                        db.span_suggestion(
-                            expr.span,
-                            "or try being explicit about what type to clone",
-                            explicit,
-                            Applicability::MaybeIncorrect,
-                        );
-                    }
-                },
-            );
-            return; // don't report clone_on_copy
-        }
-    }
-
-    if is_copy(cx, ty) {
-        let snip;
-        if let Some(snippet) = sugg::Sugg::hir_opt(cx, arg) {
-            // x.clone() might have dereferenced x, possibly through Deref impls
-            if cx.tables.expr_ty(arg) == ty {
-                snip = Some(("try removing the `clone` call", format!("{}", snippet)));
-            } else {
-                let parent = cx.tcx.hir().get_parent_node_by_hir_id(expr.hir_id);
-                match cx.tcx.hir().get_by_hir_id(parent) {
-                    hir::Node::Expr(parent) => match parent.node {
-                        // &*x is a nop, &x.clone() is not
-                        hir::ExprKind::AddrOf(..) |
-                        // (*x).func() is useless, x.clone().func() can work in case func borrows mutably
-                        hir::ExprKind::MethodCall(..) => return,
-                        _ => {},
-                    },
-                    hir::Node::Stmt(stmt) => {
-                        if let hir::StmtKind::Local(ref loc) = stmt.node {
-                            if let hir::PatKind::Ref(..) = loc.pat.node {
-                                // let ref y = *x borrows x, let ref y = x.clone() does not
-                                return;
-                            }
-                        }
-                    },
-                    _ => {},
-                }
-
-                let deref_count = cx
-                    .tables
-                    .expr_adjustments(arg)
-                    .iter()
-                    .filter(|adj| {
-                        if let ty::adjustment::Adjust::Deref(_) = adj.kind {
-                            true
-                        } else {
-                            false
-                        }
-                    })
-                    .count();
-                let derefs: String = iter::repeat('*').take(deref_count).collect();
-                snip = Some(("try dereferencing it", format!("{}{}", derefs, snippet)));
-            }
-        } else {
-            snip = None;
-        }
-        span_lint_and_then(cx, CLONE_ON_COPY, expr.span, "using `clone` on a `Copy` type", |db| {
-            if let Some((text, snip)) = snip {
-                db.span_suggestion(expr.span, text, snip, Applicability::Unspecified);
-            }
-        });
-    }
-}
-
-fn lint_clone_on_ref_ptr(cx: &LateContext<'_, '_>, expr: &hir::Expr, arg: &hir::Expr) {
-    let obj_ty = walk_ptrs_ty(cx.tables.expr_ty(arg));
-
-    if let ty::Adt(_, subst) = obj_ty.sty {
-        let caller_type = if match_type(cx, obj_ty, &paths::RC) {
-            "Rc"
-        } else if match_type(cx, obj_ty, &paths::ARC) {
-            "Arc"
-        } else if match_type(cx, obj_ty, &paths::WEAK_RC) || match_type(cx, obj_ty, &paths::WEAK_ARC) {
-            "Weak"
-        } else {
-            return;
-        };
-
-        span_lint_and_sugg(
-            cx,
-            CLONE_ON_REF_PTR,
-            expr.span,
-            "using '.clone()' on a ref-counted pointer",
-            "try this",
-            format!(
-                "{}::<{}>::clone(&{})",
-                caller_type,
-                subst.type_at(0),
-                snippet(cx, arg.span, "_")
-            ),
-            Applicability::Unspecified, // Sometimes unnecessary ::<_> after Rc/Arc/Weak
-        );
-    }
-}
-
-fn lint_string_extend(cx: &LateContext<'_, '_>, expr: &hir::Expr, args: &[hir::Expr]) {
-    let arg = &args[1];
-    if let Some(arglists) = method_chain_args(arg, &["chars"]) {
-        let target = &arglists[0][0];
-        let self_ty = walk_ptrs_ty(cx.tables.expr_ty(target));
-        let ref_str = if self_ty.sty == ty::Str {
-            ""
-        } else if match_type(cx, self_ty, &paths::STRING) {
-            "&"
-        } else {
-            return;
-        };
-
-        let mut applicability = Applicability::MachineApplicable;
-        span_lint_and_sugg(
-            cx,
-            STRING_EXTEND_CHARS,
-            expr.span,
-            "calling `.extend(_.chars())`",
-            "try this",
-            format!(
-                "{}.push_str({}{})",
-                snippet_with_applicability(cx, args[0].span, "_", &mut applicability),
-                ref_str,
-                snippet_with_applicability(cx, target.span, "_", &mut applicability)
-            ),
-            applicability,
-        );
-    }
-}
-
-fn lint_extend(cx: &LateContext<'_, '_>, expr: &hir::Expr, args: &[hir::Expr]) {
-    let obj_ty = walk_ptrs_ty(cx.tables.expr_ty(&args[0]));
-    if match_type(cx, obj_ty, &paths::STRING) {
-        lint_string_extend(cx, expr, args);
-    }
-}
-
-fn lint_cstring_as_ptr(cx: &LateContext<'_, '_>, expr: &hir::Expr, new: &hir::Expr, unwrap: &hir::Expr) {
-    if_chain! {
-        if let hir::ExprKind::Call(ref fun, ref args) = new.node;
-        if args.len() == 1;
-        if let hir::ExprKind::Path(ref path) = fun.node;
-        if let Def::Method(did) = cx.tables.qpath_def(path, fun.hir_id);
-        if match_def_path(cx.tcx, did, &paths::CSTRING_NEW);
-        then {
-            span_lint_and_then(
-                cx,
-                TEMPORARY_CSTRING_AS_PTR,
-                expr.span,
-                "you are getting the inner pointer of a temporary `CString`",
-                |db| {
-                    db.note("that pointer will be invalid outside this expression");
-                    db.span_help(unwrap.span, "assign the `CString` to a variable to extend its lifetime");
-                });
-        }
-    }
-}
-
-fn lint_iter_cloned_collect<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &hir::Expr, iter_args: &'tcx [hir::Expr]) {
-    if match_type(cx, cx.tables.expr_ty(expr), &paths::VEC) {
-        if let Some(slice) = derefs_to_slice(cx, &iter_args[0], cx.tables.expr_ty(&iter_args[0])) {
-            if let Some(to_replace) = expr.span.trim_start(slice.span.source_callsite()) {
-                span_lint_and_sugg(
-                    cx,
-                    ITER_CLONED_COLLECT,
-                    to_replace,
-                    "called `iter().cloned().collect()` on a slice to create a `Vec`. Calling `to_vec()` is both faster and \
-                     more readable",
-                    "try",
-                    ".to_vec()".to_string(),
-                    Applicability::MachineApplicable,
-                );
-            }
-        }
-    }
-}
-
-fn lint_unnecessary_fold(cx: &LateContext<'_, '_>, expr: &hir::Expr, fold_args: &[hir::Expr]) {
-    fn check_fold_with_op(
-        cx: &LateContext<'_, '_>,
-        fold_args: &[hir::Expr],
-        op: hir::BinOpKind,
-        replacement_method_name: &str,
-        replacement_has_args: bool,
-    ) {
-        if_chain! {
-            // Extract the body of the closure passed to fold
-            if let hir::ExprKind::Closure(_, _, body_id, _, _) = fold_args[2].node;
-            let closure_body = cx.tcx.hir().body(body_id);
-            let closure_expr = remove_blocks(&closure_body.value);
-
-            // Check if the closure body is of the form `acc <op> some_expr(x)`
-            if let hir::ExprKind::Binary(ref bin_op, ref left_expr, ref right_expr) = closure_expr.node;
-            if bin_op.node == op;
-
-            // Extract the names of the two arguments to the closure
-            if let Some(first_arg_ident) = get_arg_name(&closure_body.arguments[0].pat);
-            if let Some(second_arg_ident) = get_arg_name(&closure_body.arguments[1].pat);
-
-            if match_var(&*left_expr, first_arg_ident);
-            if replacement_has_args || match_var(&*right_expr, second_arg_ident);
-
-            then {
-                // Span containing `.fold(...)`
-                let next_point = cx.sess().source_map().next_point(fold_args[0].span);
-                let fold_span = next_point.with_hi(fold_args[2].span.hi() + BytePos(1));
-
-                let mut applicability = Applicability::MachineApplicable;
-                let sugg = if replacement_has_args {
-                    format!(
-                        ".{replacement}(|{s}| {r})",
-                        replacement = replacement_method_name,
-                        s = second_arg_ident,
-                        r = snippet_with_applicability(cx, right_expr.span, "EXPR", &mut applicability),
-                    )
-                } else {
-                    format!(
-                        ".{replacement}()",
-                        replacement = replacement_method_name,
-                    )
-                };
-
-                span_lint_and_sugg(
-                    cx,
-                    UNNECESSARY_FOLD,
-                    fold_span,
-                    // TODO #2371 don't suggest e.g. .any(|x| f(x)) if we can suggest .any(f)
-                    "this `.fold` can be written more succinctly using another method",
-                    "try",
-                    sugg,
-                    applicability,
-                );
-            }
-        }
-    }
-
-    // Check that this is a call to Iterator::fold rather than just some function called fold
-    if !match_trait_method(cx, expr, &paths::ITERATOR) {
-        return;
-    }
-
-    assert!(
-        fold_args.len() == 3,
-        "Expected fold_args to have three entries - the receiver, the initial value and the closure"
-    );
-
-    // Check if the first argument to .fold is a suitable literal
-    match fold_args[1].node {
-        hir::ExprKind::Lit(ref lit) => match lit.node {
-            ast::LitKind::Bool(false) => check_fold_with_op(cx, fold_args, hir::BinOpKind::Or, "any", true),
-            ast::LitKind::Bool(true) => check_fold_with_op(cx, fold_args, hir::BinOpKind::And, "all", true),
-            ast::LitKind::Int(0, _) => check_fold_with_op(cx, fold_args, hir::BinOpKind::Add, "sum", false),
-            ast::LitKind::Int(1, _) => check_fold_with_op(cx, fold_args, hir::BinOpKind::Mul, "product", false),
-            _ => return,
-        },
-        _ => return,
-    };
-}
-
-fn lint_iter_nth<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &hir::Expr, iter_args: &'tcx [hir::Expr], is_mut: bool) {
-    let mut_str = if is_mut { "_mut" } else { "" };
-    let caller_type = if derefs_to_slice(cx, &iter_args[0], cx.tables.expr_ty(&iter_args[0])).is_some() {
-        "slice"
-    } else if match_type(cx, cx.tables.expr_ty(&iter_args[0]), &paths::VEC) {
-        "Vec"
-    } else if match_type(cx, cx.tables.expr_ty(&iter_args[0]), &paths::VEC_DEQUE) {
-        "VecDeque"
-    } else {
-        return; // caller is not a type that we want to lint
-    };
-
-    span_lint(
-        cx,
-        ITER_NTH,
-        expr.span,
-        &format!(
-            "called `.iter{0}().nth()` on a {1}. Calling `.get{0}()` is both faster and more readable",
-            mut_str, caller_type
-        ),
-    );
-}
-
-fn lint_get_unwrap<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &hir::Expr, get_args: &'tcx [hir::Expr], is_mut: bool) {
-    // Note: we don't want to lint `get_mut().unwrap` for HashMap or BTreeMap,
-    // because they do not implement `IndexMut`
-    let mut applicability = Applicability::MachineApplicable;
-    let expr_ty = cx.tables.expr_ty(&get_args[0]);
-    let get_args_str = if get_args.len() > 1 {
-        snippet_with_applicability(cx, get_args[1].span, "_", &mut applicability)
-    } else {
-        return; // not linting on a .get().unwrap() chain or variant
-    };
-    let mut needs_ref;
-    let caller_type = if derefs_to_slice(cx, &get_args[0], expr_ty).is_some() {
-        needs_ref = get_args_str.parse::<usize>().is_ok();
-        "slice"
-    } else if match_type(cx, expr_ty, &paths::VEC) {
-        needs_ref = get_args_str.parse::<usize>().is_ok();
-        "Vec"
-    } else if match_type(cx, expr_ty, &paths::VEC_DEQUE) {
-        needs_ref = get_args_str.parse::<usize>().is_ok();
-        "VecDeque"
-    } else if !is_mut && match_type(cx, expr_ty, &paths::HASHMAP) {
-        needs_ref = true;
-        "HashMap"
-    } else if !is_mut && match_type(cx, expr_ty, &paths::BTREEMAP) {
-        needs_ref = true;
-        "BTreeMap"
-    } else {
-        return; // caller is not a type that we want to lint
-    };
-
-    let mut span = expr.span;
-
-    // Handle the case where the result is immedately dereferenced
-    // by not requiring ref and pulling the dereference into the
-    // suggestion.
-    if_chain! {
-        if needs_ref;
-        if let Some(parent) = get_parent_expr(cx, expr);
-        if let hir::ExprKind::Unary(hir::UnOp::UnDeref, _) = parent.node;
-        then {
-            needs_ref = false;
-            span = parent.span;
-        }
-    }
-
-    let mut_str = if is_mut { "_mut" } else { "" };
-    let borrow_str = if !needs_ref {
-        ""
-    } else if is_mut {
-        "&mut "
-    } else {
-        "&"
-    };
-
-    span_lint_and_sugg(
-        cx,
-        GET_UNWRAP,
-        span,
-        &format!(
-            "called `.get{0}().unwrap()` on a {1}. Using `[]` is more clear and more concise",
-            mut_str, caller_type
-        ),
-        "try this",
-        format!(
-            "{}{}[{}]",
-            borrow_str,
-            snippet_with_applicability(cx, get_args[0].span, "_", &mut applicability),
-            get_args_str
-        ),
-        applicability,
-    );
-}
-
-fn lint_iter_skip_next(cx: &LateContext<'_, '_>, expr: &hir::Expr) {
-    // lint if caller of skip is an Iterator
-    if match_trait_method(cx, expr, &paths::ITERATOR) {
-        span_lint(
-            cx,
-            ITER_SKIP_NEXT,
-            expr.span,
-            "called `skip(x).next()` on an iterator. This is more succinctly expressed by calling `nth(x)`",
-        );
-    }
-}
-
-fn derefs_to_slice<'a, 'tcx>(
-    cx: &LateContext<'a, 'tcx>,
-    expr: &'tcx hir::Expr,
-    ty: Ty<'tcx>,
-) -> Option<&'tcx hir::Expr> {
+fn derefs_to_slice<'e, 'tcx>(cx: &LateContext<'_, 'tcx>, expr: &'e hir::Expr, ty: Ty<'tcx>) -> Option<&'e hir::Expr> {
     fn may_slice(cx: &LateContext<'_, '_>, ty: Ty<'_>) -> bool {
         match ty.sty {
             ty::Slice(_) => true,
@@ -1725,537 +1420,6 @@ fn derefs_to_slice<'a, 'tcx>(
     }
 }
 
-/// lint use of `unwrap()` for `Option`s and `Result`s
-fn lint_unwrap(cx: &LateContext<'_, '_>, expr: &hir::Expr, unwrap_args: &[hir::Expr]) {
-    let obj_ty = walk_ptrs_ty(cx.tables.expr_ty(&unwrap_args[0]));
-
-    let mess = if match_type(cx, obj_ty, &paths::OPTION) {
-        Some((OPTION_UNWRAP_USED, "an Option", "None"))
-    } else if match_type(cx, obj_ty, &paths::RESULT) {
-        Some((RESULT_UNWRAP_USED, "a Result", "Err"))
-    } else {
-        None
-    };
-
-    if let Some((lint, kind, none_value)) = mess {
-        span_lint(
-            cx,
-            lint,
-            expr.span,
-            &format!(
-                "used unwrap() on {} value. If you don't want to handle the {} case gracefully, consider \
-                 using expect() to provide a better panic \
-                 message",
-                kind, none_value
-            ),
-        );
-    }
-}
-
-/// lint use of `ok().expect()` for `Result`s
-fn lint_ok_expect(cx: &LateContext<'_, '_>, expr: &hir::Expr, ok_args: &[hir::Expr]) {
-    // lint if the caller of `ok()` is a `Result`
-    if match_type(cx, cx.tables.expr_ty(&ok_args[0]), &paths::RESULT) {
-        let result_type = cx.tables.expr_ty(&ok_args[0]);
-        if let Some(error_type) = get_error_type(cx, result_type) {
-            if has_debug_impl(error_type, cx) {
-                span_lint(
-                    cx,
-                    OK_EXPECT,
-                    expr.span,
-                    "called `ok().expect()` on a Result value. You can call `expect` directly on the `Result`",
-                );
-            }
-        }
-    }
-}
-
-/// lint use of `map().flatten()` for `Iterators`
-fn lint_map_flatten<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx hir::Expr, map_args: &'tcx [hir::Expr]) {
-    // lint if caller of `.map().flatten()` is an Iterator
-    if match_trait_method(cx, expr, &paths::ITERATOR) {
-        let msg = "called `map(..).flatten()` on an `Iterator`. \
-                   This is more succinctly expressed by calling `.flat_map(..)`";
-        let self_snippet = snippet(cx, map_args[0].span, "..");
-        let func_snippet = snippet(cx, map_args[1].span, "..");
-        let hint = format!("{0}.flat_map({1})", self_snippet, func_snippet);
-        span_lint_and_then(cx, MAP_FLATTEN, expr.span, msg, |db| {
-            db.span_suggestion(
-                expr.span,
-                "try using flat_map instead",
-                hint,
-                Applicability::MachineApplicable,
-            );
-        });
-    }
-}
-
-/// lint use of `map().unwrap_or_else()` for `Option`s and `Result`s
-fn lint_map_unwrap_or_else<'a, 'tcx>(
-    cx: &LateContext<'a, 'tcx>,
-    expr: &'tcx hir::Expr,
-    map_args: &'tcx [hir::Expr],
-    unwrap_args: &'tcx [hir::Expr],
-) {
-    // lint if the caller of `map()` is an `Option`
-    let is_option = match_type(cx, cx.tables.expr_ty(&map_args[0]), &paths::OPTION);
-    let is_result = match_type(cx, cx.tables.expr_ty(&map_args[0]), &paths::RESULT);
-    if is_option || is_result {
-        // lint message
-        let msg = if is_option {
-            "called `map(f).unwrap_or_else(g)` on an Option value. This can be done more directly by calling \
-             `map_or_else(g, f)` instead"
-        } else {
-            "called `map(f).unwrap_or_else(g)` on a Result value. This can be done more directly by calling \
-             `ok().map_or_else(g, f)` instead"
-        };
-        // get snippets for args to map() and unwrap_or_else()
-        let map_snippet = snippet(cx, map_args[1].span, "..");
-        let unwrap_snippet = snippet(cx, unwrap_args[1].span, "..");
-        // lint, with note if neither arg is > 1 line and both map() and
-        // unwrap_or_else() have the same span
-        let multiline = map_snippet.lines().count() > 1 || unwrap_snippet.lines().count() > 1;
-        let same_span = map_args[1].span.ctxt() == unwrap_args[1].span.ctxt();
-        if same_span && !multiline {
-            span_note_and_lint(
-                cx,
-                if is_option {
-                    OPTION_MAP_UNWRAP_OR_ELSE
-                } else {
-                    RESULT_MAP_UNWRAP_OR_ELSE
-                },
-                expr.span,
-                msg,
-                expr.span,
-                &format!(
-                    "replace `map({0}).unwrap_or_else({1})` with `{2}map_or_else({1}, {0})`",
-                    map_snippet,
-                    unwrap_snippet,
-                    if is_result { "ok()." } else { "" }
-                ),
-            );
-        } else if same_span && multiline {
-            span_lint(
-                cx,
-                if is_option {
-                    OPTION_MAP_UNWRAP_OR_ELSE
-                } else {
-                    RESULT_MAP_UNWRAP_OR_ELSE
-                },
-                expr.span,
-                msg,
-            );
-        };
-    }
-}
-
-/// lint use of `_.map_or(None, _)` for `Option`s
-fn lint_map_or_none<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx hir::Expr, map_or_args: &'tcx [hir::Expr]) {
-    if match_type(cx, cx.tables.expr_ty(&map_or_args[0]), &paths::OPTION) {
-        // check if the first non-self argument to map_or() is None
-        let map_or_arg_is_none = if let hir::ExprKind::Path(ref qpath) = map_or_args[1].node {
-            match_qpath(qpath, &paths::OPTION_NONE)
-        } else {
-            false
-        };
-
-        if map_or_arg_is_none {
-            // lint message
-            let msg = "called `map_or(None, f)` on an Option value. This can be done more directly by calling \
-                       `and_then(f)` instead";
-            let map_or_self_snippet = snippet(cx, map_or_args[0].span, "..");
-            let map_or_func_snippet = snippet(cx, map_or_args[2].span, "..");
-            let hint = format!("{0}.and_then({1})", map_or_self_snippet, map_or_func_snippet);
-            span_lint_and_then(cx, OPTION_MAP_OR_NONE, expr.span, msg, |db| {
-                db.span_suggestion(
-                    expr.span,
-                    "try using and_then instead",
-                    hint,
-                    Applicability::MachineApplicable, // snippet
-                );
-            });
-        }
-    }
-}
-
-/// lint use of `filter().next()` for `Iterators`
-fn lint_filter_next<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx hir::Expr, filter_args: &'tcx [hir::Expr]) {
-    // lint if caller of `.filter().next()` is an Iterator
-    if match_trait_method(cx, expr, &paths::ITERATOR) {
-        let msg = "called `filter(p).next()` on an `Iterator`. This is more succinctly expressed by calling \
-                   `.find(p)` instead.";
-        let filter_snippet = snippet(cx, filter_args[1].span, "..");
-        if filter_snippet.lines().count() <= 1 {
-            // add note if not multi-line
-            span_note_and_lint(
-                cx,
-                FILTER_NEXT,
-                expr.span,
-                msg,
-                expr.span,
-                &format!("replace `filter({0}).next()` with `find({0})`", filter_snippet),
-            );
-        } else {
-            span_lint(cx, FILTER_NEXT, expr.span, msg);
-        }
-    }
-}
-
-/// lint use of `filter().map()` for `Iterators`
-fn lint_filter_map<'a, 'tcx>(
-    cx: &LateContext<'a, 'tcx>,
-    expr: &'tcx hir::Expr,
-    _filter_args: &'tcx [hir::Expr],
-    _map_args: &'tcx [hir::Expr],
-) {
-    // lint if caller of `.filter().map()` is an Iterator
-    if match_trait_method(cx, expr, &paths::ITERATOR) {
-        let msg = "called `filter(p).map(q)` on an `Iterator`. \
-                   This is more succinctly expressed by calling `.filter_map(..)` instead.";
-        span_lint(cx, FILTER_MAP, expr.span, msg);
-    }
-}
-
-/// lint use of `filter().map()` for `Iterators`
-fn lint_filter_map_map<'a, 'tcx>(
-    cx: &LateContext<'a, 'tcx>,
-    expr: &'tcx hir::Expr,
-    _filter_args: &'tcx [hir::Expr],
-    _map_args: &'tcx [hir::Expr],
-) {
-    // lint if caller of `.filter().map()` is an Iterator
-    if match_trait_method(cx, expr, &paths::ITERATOR) {
-        let msg = "called `filter_map(p).map(q)` on an `Iterator`. \
-                   This is more succinctly expressed by only calling `.filter_map(..)` instead.";
-        span_lint(cx, FILTER_MAP, expr.span, msg);
-    }
-}
-
-/// lint use of `filter().flat_map()` for `Iterators`
-fn lint_filter_flat_map<'a, 'tcx>(
-    cx: &LateContext<'a, 'tcx>,
-    expr: &'tcx hir::Expr,
-    _filter_args: &'tcx [hir::Expr],
-    _map_args: &'tcx [hir::Expr],
-) {
-    // lint if caller of `.filter().flat_map()` is an Iterator
-    if match_trait_method(cx, expr, &paths::ITERATOR) {
-        let msg = "called `filter(p).flat_map(q)` on an `Iterator`. \
-                   This is more succinctly expressed by calling `.flat_map(..)` \
-                   and filtering by returning an empty Iterator.";
-        span_lint(cx, FILTER_MAP, expr.span, msg);
-    }
-}
-
-/// lint use of `filter_map().flat_map()` for `Iterators`
-fn lint_filter_map_flat_map<'a, 'tcx>(
-    cx: &LateContext<'a, 'tcx>,
-    expr: &'tcx hir::Expr,
-    _filter_args: &'tcx [hir::Expr],
-    _map_args: &'tcx [hir::Expr],
-) {
-    // lint if caller of `.filter_map().flat_map()` is an Iterator
-    if match_trait_method(cx, expr, &paths::ITERATOR) {
-        let msg = "called `filter_map(p).flat_map(q)` on an `Iterator`. \
-                   This is more succinctly expressed by calling `.flat_map(..)` \
-                   and filtering by returning an empty Iterator.";
-        span_lint(cx, FILTER_MAP, expr.span, msg);
-    }
-}
-
-/// lint searching an Iterator followed by `is_some()`
-fn lint_search_is_some<'a, 'tcx>(
-    cx: &LateContext<'a, 'tcx>,
-    expr: &'tcx hir::Expr,
-    search_method: &str,
-    search_args: &'tcx [hir::Expr],
-    is_some_args: &'tcx [hir::Expr],
-) {
-    // lint if caller of search is an Iterator
-    if match_trait_method(cx, &is_some_args[0], &paths::ITERATOR) {
-        let msg = format!(
-            "called `is_some()` after searching an `Iterator` with {}. This is more succinctly \
-             expressed by calling `any()`.",
-            search_method
-        );
-        let search_snippet = snippet(cx, search_args[1].span, "..");
-        if search_snippet.lines().count() <= 1 {
-            // add note if not multi-line
-            span_note_and_lint(
-                cx,
-                SEARCH_IS_SOME,
-                expr.span,
-                &msg,
-                expr.span,
-                &format!(
-                    "replace `{0}({1}).is_some()` with `any({1})`",
-                    search_method, search_snippet
-                ),
-            );
-        } else {
-            span_lint(cx, SEARCH_IS_SOME, expr.span, &msg);
-        }
-    }
-}
-
-/// Used for `lint_binary_expr_with_method_call`.
-#[derive(Copy, Clone)]
-struct BinaryExprInfo<'a> {
-    expr: &'a hir::Expr,
-    chain: &'a hir::Expr,
-    other: &'a hir::Expr,
-    eq: bool,
-}
-
-/// Checks for the `CHARS_NEXT_CMP` and `CHARS_LAST_CMP` lints.
-fn lint_binary_expr_with_method_call(cx: &LateContext<'_, '_>, info: &mut BinaryExprInfo<'_>) {
-    macro_rules! lint_with_both_lhs_and_rhs {
-        ($func:ident, $cx:expr, $info:ident) => {
-            if !$func($cx, $info) {
-                ::std::mem::swap(&mut $info.chain, &mut $info.other);
-                if $func($cx, $info) {
-                    return;
-                }
-            }
-        };
-    }
-
-    lint_with_both_lhs_and_rhs!(lint_chars_next_cmp, cx, info);
-    lint_with_both_lhs_and_rhs!(lint_chars_last_cmp, cx, info);
-    lint_with_both_lhs_and_rhs!(lint_chars_next_cmp_with_unwrap, cx, info);
-    lint_with_both_lhs_and_rhs!(lint_chars_last_cmp_with_unwrap, cx, info);
-}
-
-/// Wrapper fn for `CHARS_NEXT_CMP` and `CHARS_NEXT_CMP` lints.
-fn lint_chars_cmp(
-    cx: &LateContext<'_, '_>,
-    info: &BinaryExprInfo<'_>,
-    chain_methods: &[&str],
-    lint: &'static Lint,
-    suggest: &str,
-) -> bool {
-    if_chain! {
-        if let Some(args) = method_chain_args(info.chain, chain_methods);
-        if let hir::ExprKind::Call(ref fun, ref arg_char) = info.other.node;
-        if arg_char.len() == 1;
-        if let hir::ExprKind::Path(ref qpath) = fun.node;
-        if let Some(segment) = single_segment_path(qpath);
-        if segment.ident.name == "Some";
-        then {
-            let mut applicability = Applicability::MachineApplicable;
-            let self_ty = walk_ptrs_ty(cx.tables.expr_ty_adjusted(&args[0][0]));
-
-            if self_ty.sty != ty::Str {
-                return false;
-            }
-
-            span_lint_and_sugg(
-                cx,
-                lint,
-                info.expr.span,
-                &format!("you should use the `{}` method", suggest),
-                "like this",
-                format!("{}{}.{}({})",
-                        if info.eq { "" } else { "!" },
-                        snippet_with_applicability(cx, args[0][0].span, "_", &mut applicability),
-                        suggest,
-                        snippet_with_applicability(cx, arg_char[0].span, "_", &mut applicability)),
-                applicability,
-            );
-
-            return true;
-        }
-    }
-
-    false
-}
-
-/// Checks for the `CHARS_NEXT_CMP` lint.
-fn lint_chars_next_cmp<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, info: &BinaryExprInfo<'_>) -> bool {
-    lint_chars_cmp(cx, info, &["chars", "next"], CHARS_NEXT_CMP, "starts_with")
-}
-
-/// Checks for the `CHARS_LAST_CMP` lint.
-fn lint_chars_last_cmp<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, info: &BinaryExprInfo<'_>) -> bool {
-    if lint_chars_cmp(cx, info, &["chars", "last"], CHARS_LAST_CMP, "ends_with") {
-        true
-    } else {
-        lint_chars_cmp(cx, info, &["chars", "next_back"], CHARS_LAST_CMP, "ends_with")
-    }
-}
-
-/// Wrapper fn for `CHARS_NEXT_CMP` and `CHARS_LAST_CMP` lints with `unwrap()`.
-fn lint_chars_cmp_with_unwrap<'a, 'tcx>(
-    cx: &LateContext<'a, 'tcx>,
-    info: &BinaryExprInfo<'_>,
-    chain_methods: &[&str],
-    lint: &'static Lint,
-    suggest: &str,
-) -> bool {
-    if_chain! {
-        if let Some(args) = method_chain_args(info.chain, chain_methods);
-        if let hir::ExprKind::Lit(ref lit) = info.other.node;
-        if let ast::LitKind::Char(c) = lit.node;
-        then {
-            let mut applicability = Applicability::MachineApplicable;
-            span_lint_and_sugg(
-                cx,
-                lint,
-                info.expr.span,
-                &format!("you should use the `{}` method", suggest),
-                "like this",
-                format!("{}{}.{}('{}')",
-                        if info.eq { "" } else { "!" },
-                        snippet_with_applicability(cx, args[0][0].span, "_", &mut applicability),
-                        suggest,
-                        c),
-                applicability,
-            );
-
-            return true;
-        }
-    }
-
-    false
-}
-
-/// Checks for the `CHARS_NEXT_CMP` lint with `unwrap()`.
-fn lint_chars_next_cmp_with_unwrap<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, info: &BinaryExprInfo<'_>) -> bool {
-    lint_chars_cmp_with_unwrap(cx, info, &["chars", "next", "unwrap"], CHARS_NEXT_CMP, "starts_with")
-}
-
-/// Checks for the `CHARS_LAST_CMP` lint with `unwrap()`.
-fn lint_chars_last_cmp_with_unwrap<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, info: &BinaryExprInfo<'_>) -> bool {
-    if lint_chars_cmp_with_unwrap(cx, info, &["chars", "last", "unwrap"], CHARS_LAST_CMP, "ends_with") {
-        true
-    } else {
-        lint_chars_cmp_with_unwrap(cx, info, &["chars", "next_back", "unwrap"], CHARS_LAST_CMP, "ends_with")
-    }
-}
-
-/// lint for length-1 `str`s for methods in `PATTERN_METHODS`
-fn lint_single_char_pattern<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, _expr: &'tcx hir::Expr, arg: &'tcx hir::Expr) {
-    if_chain! {
-        if let hir::ExprKind::Lit(lit) = &arg.node;
-        if let ast::LitKind::Str(r, _) = lit.node;
-        if r.as_str().len() == 1;
-        then {
-            let mut applicability = Applicability::MachineApplicable;
-            let snip = snippet_with_applicability(cx, arg.span, "..", &mut applicability);
-            let hint = format!("'{}'", &snip[1..snip.len() - 1]);
-            span_lint_and_sugg(
-                cx,
-                SINGLE_CHAR_PATTERN,
-                arg.span,
-                "single-character string constant used as pattern",
-                "try using a char instead",
-                hint,
-                applicability,
-            );
-        }
-    }
-}
-
-/// Checks for the `USELESS_ASREF` lint.
-fn lint_asref(cx: &LateContext<'_, '_>, expr: &hir::Expr, call_name: &str, as_ref_args: &[hir::Expr]) {
-    // when we get here, we've already checked that the call name is "as_ref" or "as_mut"
-    // check if the call is to the actual `AsRef` or `AsMut` trait
-    if match_trait_method(cx, expr, &paths::ASREF_TRAIT) || match_trait_method(cx, expr, &paths::ASMUT_TRAIT) {
-        // check if the type after `as_ref` or `as_mut` is the same as before
-        let recvr = &as_ref_args[0];
-        let rcv_ty = cx.tables.expr_ty(recvr);
-        let res_ty = cx.tables.expr_ty(expr);
-        let (base_res_ty, res_depth) = walk_ptrs_ty_depth(res_ty);
-        let (base_rcv_ty, rcv_depth) = walk_ptrs_ty_depth(rcv_ty);
-        if base_rcv_ty == base_res_ty && rcv_depth >= res_depth {
-            // allow the `as_ref` or `as_mut` if it is followed by another method call
-            if_chain! {
-                if let Some(parent) = get_parent_expr(cx, expr);
-                if let hir::ExprKind::MethodCall(_, ref span, _) = parent.node;
-                if span != &expr.span;
-                then {
-                    return;
-                }
-            }
-
-            let mut applicability = Applicability::MachineApplicable;
-            span_lint_and_sugg(
-                cx,
-                USELESS_ASREF,
-                expr.span,
-                &format!("this call to `{}` does nothing", call_name),
-                "try this",
-                snippet_with_applicability(cx, recvr.span, "_", &mut applicability).to_string(),
-                applicability,
-            );
-        }
-    }
-}
-
-fn ty_has_iter_method(
-    cx: &LateContext<'_, '_>,
-    self_ref_ty: ty::Ty<'_>,
-) -> Option<(&'static Lint, &'static str, &'static str)> {
-    if let Some(ty_name) = has_iter_method(cx, self_ref_ty) {
-        let lint = match ty_name {
-            "array" | "PathBuf" => INTO_ITER_ON_ARRAY,
-            _ => INTO_ITER_ON_REF,
-        };
-        let mutbl = match self_ref_ty.sty {
-            ty::Ref(_, _, mutbl) => mutbl,
-            _ => unreachable!(),
-        };
-        let method_name = match mutbl {
-            hir::MutImmutable => "iter",
-            hir::MutMutable => "iter_mut",
-        };
-        Some((lint, ty_name, method_name))
-    } else {
-        None
-    }
-}
-
-fn lint_into_iter(cx: &LateContext<'_, '_>, expr: &hir::Expr, self_ref_ty: ty::Ty<'_>, method_span: Span) {
-    if !match_trait_method(cx, expr, &paths::INTO_ITERATOR) {
-        return;
-    }
-    if let Some((lint, kind, method_name)) = ty_has_iter_method(cx, self_ref_ty) {
-        span_lint_and_sugg(
-            cx,
-            lint,
-            method_span,
-            &format!(
-                "this .into_iter() call is equivalent to .{}() and will not move the {}",
-                method_name, kind,
-            ),
-            "call directly",
-            method_name.to_string(),
-            Applicability::MachineApplicable,
-        );
-    }
-}
-
-/// Given a `Result<T, E>` type, return its error type (`E`).
-fn get_error_type<'a>(cx: &LateContext<'_, '_>, ty: Ty<'a>) -> Option<Ty<'a>> {
-    if let ty::Adt(_, substs) = ty.sty {
-        if match_type(cx, ty, &paths::RESULT) {
-            substs.types().nth(1)
-        } else {
-            None
-        }
-    } else {
-        None
-    }
-}
-
-/// This checks whether a given type is known to implement Debug.
-fn has_debug_impl<'a, 'b>(ty: Ty<'a>, cx: &LateContext<'b, 'a>) -> bool {
-    match cx.tcx.lang_items().debug_trait() {
-        Some(debug) => implements_trait(cx, ty, debug, &[]),
-        None => false,
-    }
-}
-
 enum Convention {
     Eq(&'static str),
     StartsWith(&'static str),
@@ -2306,27 +1470,6 @@ const TRAIT_METHODS: [(&str, usize, SelfKind, OutType, &str); 30] = [
     ("sub", 2, SelfKind::Value, OutType::Any, "std::ops::Sub"),
 ];
 
-#[rustfmt::skip]
-const PATTERN_METHODS: [(&str, usize); 17] = [
-    ("contains", 1),
-    ("starts_with", 1),
-    ("ends_with", 1),
-    ("find", 1),
-    ("rfind", 1),
-    ("split", 1),
-    ("rsplit", 1),
-    ("split_terminator", 1),
-    ("rsplit_terminator", 1),
-    ("splitn", 2),
-    ("rsplitn", 2),
-    ("matches", 1),
-    ("rmatches", 1),
-    ("match_indices", 1),
-    ("rmatch_indices", 1),
-    ("trim_start_matches", 1),
-    ("trim_end_matches", 1),
-];
-
 #[derive(Clone, Copy, PartialEq, Debug)]
 enum SelfKind {
     Value,
@@ -0,0 +1,139 @@
+//! Chain rules for converting between `Option` and `Result` by hand.
+//!
+//! Each row is one idiom of the conversion matrix: `Option` to `Result`,
+//! `Result` to `Option`, and the detours over the other type when unwrapping.
+
+use crate::utils::{implements_trait, paths};
+use if_chain::if_chain;
+use rustc::hir;
+use rustc::lint::LateContext;
+use rustc::ty;
+use rustc_errors::Applicability;
+
+use super::chain_rules::{ArgPred, ChainRule, Fix, Receiver};
+use super::{MANUAL_RESULT_OK, OK_EXPECT, OK_UNWRAP_OR, OPTION_MAP_OR_ERR_OK, OPTION_MAP_OR_NONE};
+
+#[rustfmt::skip]
+pub(super) static CONVERSION_RULES: &[ChainRule] = &[
+    // `Option` to `Result`
+    ChainRule {
+        lint: &OPTION_MAP_OR_ERR_OK,
+        methods: &["map_or"],
+        receiver: Receiver::Type(&paths::OPTION),
+        args: &[
+            (0, ArgPred::Call(&paths::RESULT_ERR, &[ArgPred::Any])),
+            (1, ArgPred::Path(&paths::RESULT_OK)),
+        ],
+        check: None,
+        msrv: None,
+        msg: "called `map_or(Err(_), Ok)` on an `Option` value",
+        fix: Fix::Sugg {
+            help: "try using `ok_or` instead",
+            template: "{recv}.ok_or({0.0})",
+            applicability: Applicability::MachineApplicable,
+        },
+    },
+    ChainRule {
+        lint: &OPTION_MAP_OR_ERR_OK,
+        methods: &["map", "unwrap_or"],
+        receiver: Receiver::Type(&paths::OPTION),
+        args: &[
+            (0, ArgPred::Path(&paths::RESULT_OK)),
+            (1, ArgPred::Call(&paths::RESULT_ERR, &[ArgPred::Any])),
+        ],
+        check: None,
+        msrv: None,
+        msg: "called `map(Ok).unwrap_or(Err(_))` on an `Option` value",
+        fix: Fix::Sugg {
+            help: "try using `ok_or` instead",
+            template: "{recv}.ok_or({1.0})",
+            applicability: Applicability::MachineApplicable,
+        },
+    },
+    // `Result` to `Option`, `Result::map_or` is not stable yet
+    ChainRule {
+        lint: &MANUAL_RESULT_OK,
+        methods: &["map", "unwrap_or"],
+        receiver: Receiver::Type(&paths::RESULT),
+        args: &[
+            (0, ArgPred::Path(&paths::OPTION_SOME)),
+            (1, ArgPred::Path(&paths::OPTION_NONE)),
+        ],
+        check: None,
+        msrv: None,
+        msg: "called `map(Some).unwrap_or(None)` on a `Result` value",
+        fix: Fix::Sugg {
+            help: "try using `ok` instead",
+            template: "{recv}.ok()",
+            applicability: Applicability::MachineApplicable,
+        },
+    },
+    // `Option` to `Option`
+    ChainRule {
+        lint: &OPTION_MAP_OR_NONE,
+        methods: &["map_or"],
+        receiver: Receiver::Type(&paths::OPTION),
+        args: &[(0, ArgPred::Path(&paths::OPTION_NONE))],
+        check: None,
+        msrv: None,
+        msg: "called `map_or(None, f)` on an Option value. This can be done more directly by calling \
+              `and_then(f)` instead",
+        fix: Fix::Sugg {
+            help: "try using and_then instead",
+            template: "{recv}.and_then({1})",
+            applicability: Applicability::MachineApplicable,
+        },
+    },
+    // unwrapping a `Result` over an `Option`
+    ChainRule {
+        lint: &OK_EXPECT,
+        methods: &["ok", "expect"],
+        receiver: Receiver::Type(&paths::RESULT),
+        args: &[],
+        check: Some(error_type_is_debug),
+        msrv: None,
+        msg: "called `ok().expect()` on a Result value. You can call `expect` directly on the `Result`",
+        fix: Fix::None,
+    },
+    ChainRule {
+        lint: &OK_UNWRAP_OR,
+        methods: &["ok", "unwrap_or"],
+        receiver: Receiver::Type(&paths::RESULT),
+        args: &[],
+        check: None,
+        msrv: None,
+        msg: "called `ok().unwrap_or(_)` on a `Result` value",
+        fix: Fix::Sugg {
+            help: "call `unwrap_or` on the `Result` directly",
+            template: "{recv}.unwrap_or({0})",
+            applicability: Applicability::MachineApplicable,
+        },
+    },
+    ChainRule {
+        lint: &OK_UNWRAP_OR,
+        methods: &["ok", "unwrap_or_default"],
+        receiver: Receiver::Type(&paths::RESULT),
+        args: &[],
+        check: None,
+        msrv: None,
+        msg: "called `ok().unwrap_or_default()` on a `Result` value",
+        fix: Fix::Sugg {
+            help: "call `unwrap_or_default` on the `Result` directly",
+            template: "{recv}.unwrap_or_default()",
+            applicability: Applicability::MachineApplicable,
+        },
+    },
+];
+
+/// `ok().expect()` is only an improvement if `expect` can be called on the
+/// `Result` itself, which requires the error type to implement `Debug`.
+fn error_type_is_debug(cx: &LateContext<'_, '_>, _: &hir::Expr, arg_lists: &[&[hir::Expr]]) -> bool {
+    if_chain! {
+        if let ty::Adt(_, substs) = cx.tables.expr_ty(&arg_lists[0][0]).sty;
+        if let Some(debug) = cx.tcx.lang_items().debug_trait();
+        then {
+            return implements_trait(cx, substs.type_at(1), debug, &[]);
+        }
+    }
+    false
+}
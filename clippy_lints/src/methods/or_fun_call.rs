@@ -0,0 +1,159 @@
+use crate::utils::paths;
+use crate::utils::{
+    get_trait_def_id, implements_trait, last_path_segment, match_type, snippet_with_applicability,
+    snippet_with_macro_callsite, span_lint_and_sugg,
+};
+use rustc::hir;
+use rustc::lint::LateContext;
+use rustc::ty::Ty;
+use rustc_errors::Applicability;
+use std::borrow::Cow;
+use syntax::source_map::Span;
+
+use super::{MethodCallCtxt, OR_FUN_CALL};
+
+/// Checks for the `OR_FUN_CALL` lint.
+#[allow(clippy::too_many_lines)]
+pub(super) fn lint(cx: &LateContext<'_, '_>, call: &MethodCallCtxt<'_>) {
+    /// Check for `unwrap_or(T::new())` or `unwrap_or(T::default())`.
+    fn check_unwrap_or_default(
+        cx: &LateContext<'_, '_>,
+        name: &str,
+        fun: &hir::Expr,
+        self_expr: &hir::Expr,
+        arg: &hir::Expr,
+        or_has_args: bool,
+        span: Span,
+    ) -> bool {
+        if or_has_args {
+            return false;
+        }
+
+        if name == "unwrap_or" {
+            if let hir::ExprKind::Path(ref qpath) = fun.node {
+                let path = &*last_path_segment(qpath).ident.as_str();
+
+                if ["default", "new"].contains(&path) {
+                    let arg_ty = cx.tables.expr_ty(arg);
+                    let default_trait_id = if let Some(default_trait_id) = get_trait_def_id(cx, &paths::DEFAULT_TRAIT) {
+                        default_trait_id
+                    } else {
+                        return false;
+                    };
+
+                    if implements_trait(cx, arg_ty, default_trait_id, &[]) {
+                        let mut applicability = Applicability::MachineApplicable;
+                        span_lint_and_sugg(
+                            cx,
+                            OR_FUN_CALL,
+                            span,
+                            &format!("use of `{}` followed by a call to `{}`", name, path),
+                            "try this",
+                            format!(
+                                "{}.unwrap_or_default()",
+                                snippet_with_applicability(cx, self_expr.span, "_", &mut applicability)
+                            ),
+                            applicability,
+                        );
+                        return true;
+                    }
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Check for `*or(foo())`.
+    #[allow(clippy::too_many_arguments)]
+    fn check_general_case(
+        cx: &LateContext<'_, '_>,
+        name: &str,
+        method_span: Span,
+        fun_span: Span,
+        self_ty: Ty<'_>,
+        arg: &hir::Expr,
+        or_has_args: bool,
+        span: Span,
+    ) {
+        // (path, fn_has_argument, methods, suffix)
+        let know_types: &[(&[_], _, &[_], _)] = &[
+            (&paths::BTREEMAP_ENTRY, false, &["or_insert"], "with"),
+            (&paths::HASHMAP_ENTRY, false, &["or_insert"], "with"),
+            (&paths::OPTION, false, &["map_or", "ok_or", "or", "unwrap_or"], "else"),
+            (&paths::RESULT, true, &["or", "unwrap_or"], "else"),
+        ];
+
+        // early check if the name is one we care about
+        if know_types.iter().all(|k| !k.2.contains(&name)) {
+            return;
+        }
+
+        // don't lint for constant values
+        let owner_def = cx.tcx.hir().get_parent_did_by_hir_id(arg.hir_id);
+        let promotable = cx.tcx.rvalue_promotable_map(owner_def).contains(&arg.hir_id.local_id);
+        if promotable {
+            return;
+        }
+
+        let (fn_has_arguments, poss, suffix) = if let Some(&(_, fn_has_arguments, poss, suffix)) =
+            know_types.iter().find(|&&i| match_type(cx, self_ty, i.0))
+        {
+            (fn_has_arguments, poss, suffix)
+        } else {
+            return;
+        };
+
+        if !poss.contains(&name) {
+            return;
+        }
+
+        let sugg: Cow<'_, _> = match (fn_has_arguments, !or_has_args) {
+            (true, _) => format!("|_| {}", snippet_with_macro_callsite(cx, arg.span, "..")).into(),
+            (false, false) => format!("|| {}", snippet_with_macro_callsite(cx, arg.span, "..")).into(),
+            (false, true) => snippet_with_macro_callsite(cx, fun_span, ".."),
+        };
+        let span_replace_word = method_span.with_hi(span.hi());
+        span_lint_and_sugg(
+            cx,
+            OR_FUN_CALL,
+            span_replace_word,
+            &format!("use of `{}` followed by a function call", name),
+            "try this",
+            format!("{}_{}({})", name, suffix, sugg),
+            Applicability::HasPlaceholders,
+        );
+    }
+
+    let (expr, name, args) = (call.expr, &*call.name, call.args);
+    if args.len() == 2 {
+        match args[1].node {
+            hir::ExprKind::Call(ref fun, ref or_args) => {
+                let or_has_args = !or_args.is_empty();
+                if !check_unwrap_or_default(cx, name, fun, &args[0], &args[1], or_has_args, expr.span) {
+                    check_general_case(
+                        cx,
+                        name,
+                        call.method_span,
+                        fun.span,
+                        call.recv_ty,
+                        &args[1],
+                        or_has_args,
+                        expr.span,
+                    );
+                }
+            },
+            hir::ExprKind::MethodCall(_, span, ref or_args) => check_general_case(
+                cx,
+                name,
+                call.method_span,
+                span,
+                call.recv_ty,
+                &args[1],
+                !or_args.is_empty(),
+                expr.span,
+            ),
+            _ => {},
+        }
+    }
+}
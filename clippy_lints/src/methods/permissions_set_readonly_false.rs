@@ -0,0 +1,61 @@
+use crate::utils::target::{PerTarget, TargetFamily};
+use crate::utils::{match_type, paths, snippet_with_applicability, span_lint_and_then, walk_ptrs_ty};
+use rustc::hir;
+use rustc::lint::LateContext;
+use rustc_errors::Applicability;
+use syntax::ast::LitKind;
+
+use super::{MethodCallCtxt, PERMISSIONS_SET_READONLY_FALSE};
+
+const NOTES: PerTarget<&str> = PerTarget {
+    unix: "this makes the file writable by every user",
+    windows: "this only clears the read-only attribute on Windows, \
+              but makes the file writable by every user on Unix",
+    other: "on Unix, this makes the file writable by every user",
+};
+
+pub(super) fn lint(cx: &LateContext<'_, '_>, call: &MethodCallCtxt<'_>) {
+    if call.name != "set_readonly"
+        || call.args.len() != 2
+        || !is_false(&call.args[1])
+        || !match_type(cx, walk_ptrs_ty(call.recv_ty), &paths::PERMISSIONS)
+    {
+        return;
+    }
+    span_lint_and_then(
+        cx,
+        PERMISSIONS_SET_READONLY_FALSE,
+        call.expr.span,
+        "call to `set_readonly` with argument `false`",
+        |db| {
+            db.note(NOTES.get(cx));
+            match TargetFamily::of(cx) {
+                TargetFamily::Unix => {
+                    let mut applicability = Applicability::HasPlaceholders;
+                    let recv = snippet_with_applicability(cx, call.args[0].span, "..", &mut applicability);
+                    db.span_suggestion(
+                        call.expr.span,
+                        "set the mode explicitly with `std::os::unix::fs::PermissionsExt`, e.g.",
+                        format!("{}.set_mode(0o644)", recv),
+                        applicability,
+                    );
+                },
+                TargetFamily::Windows => {
+                    db.help("if this code is only built for Windows, allow this lint");
+                },
+                TargetFamily::Other => {
+                    db.help("on Unix, use `std::os::unix::fs::PermissionsExt::set_mode` instead");
+                },
+            }
+        },
+    );
+}
+
+fn is_false(expr: &hir::Expr) -> bool {
+    if let hir::ExprKind::Lit(ref lit) = expr.node {
+        if let LitKind::Bool(false) = lit.node {
+            return true;
+        }
+    }
+    false
+}
@@ -0,0 +1,82 @@
+//! Chain rules for the `as_str` and `as_bytes` conversions of strings.
+
+use crate::utils::walk_ptrs_ty;
+use matches::matches;
+use rustc::hir;
+use rustc::lint::LateContext;
+use rustc::ty;
+use rustc_errors::Applicability;
+use syntax::ast::UintTy;
+
+use super::chain_rules::{ChainRule, Fix, Receiver};
+use super::REDUNDANT_AS_STR;
+
+#[rustfmt::skip]
+pub(super) static AS_STR_RULES: &[ChainRule] = &[
+    ChainRule {
+        lint: &REDUNDANT_AS_STR,
+        methods: &["as_str", "len"],
+        receiver: Receiver::Str,
+        args: &[],
+        check: Some(converts_to_str_or_bytes),
+        msrv: None,
+        msg: "called `len()` on the result of `as_str()`, which is redundant",
+        fix: Fix::Sugg {
+            help: "call `len()` on the string directly",
+            template: "{recv}.len()",
+            applicability: Applicability::MachineApplicable,
+        },
+    },
+    ChainRule {
+        lint: &REDUNDANT_AS_STR,
+        methods: &["as_str", "is_empty"],
+        receiver: Receiver::Str,
+        args: &[],
+        check: Some(converts_to_str_or_bytes),
+        msrv: None,
+        msg: "called `is_empty()` on the result of `as_str()`, which is redundant",
+        fix: Fix::Sugg {
+            help: "call `is_empty()` on the string directly",
+            template: "{recv}.is_empty()",
+            applicability: Applicability::MachineApplicable,
+        },
+    },
+    ChainRule {
+        lint: &REDUNDANT_AS_STR,
+        methods: &["as_bytes", "len"],
+        receiver: Receiver::Str,
+        args: &[],
+        check: Some(converts_to_str_or_bytes),
+        msrv: None,
+        msg: "called `len()` on the result of `as_bytes()`, which is redundant",
+        fix: Fix::Sugg {
+            help: "call `len()` on the string directly",
+            template: "{recv}.len()",
+            applicability: Applicability::MachineApplicable,
+        },
+    },
+    ChainRule {
+        lint: &REDUNDANT_AS_STR,
+        methods: &["as_bytes", "is_empty"],
+        receiver: Receiver::Str,
+        args: &[],
+        check: Some(converts_to_str_or_bytes),
+        msrv: None,
+        msg: "called `is_empty()` on the result of `as_bytes()`, which is redundant",
+        fix: Fix::Sugg {
+            help: "call `is_empty()` on the string directly",
+            template: "{recv}.is_empty()",
+            applicability: Applicability::MachineApplicable,
+        },
+    },
+];
+
+/// The conversion must be the one of `str` or `String`, not a method of the
+/// same name from some trait, whose result may have a different length.
+fn converts_to_str_or_bytes(cx: &LateContext<'_, '_>, _: &hir::Expr, arg_lists: &[&[hir::Expr]]) -> bool {
+    match walk_ptrs_ty(cx.tables.expr_ty(&arg_lists[1][0])).sty {
+        ty::Str => true,
+        ty::Slice(elem) => matches!(elem.sty, ty::Uint(UintTy::U8)),
+        _ => false,
+    }
+}
@@ -0,0 +1,71 @@
+//! Chain rules for `std::io::Seek`.
+
+use crate::utils::{msrvs, paths};
+use matches::matches;
+use rustc::hir::{self, ExprKind, MatchSource, Node, StmtKind};
+use rustc::lint::LateContext;
+use rustc_errors::Applicability;
+
+use super::chain_rules::{ArgPred, ChainRule, Fix, Receiver};
+use super::{SEEK_FROM_CURRENT, SEEK_TO_START_INSTEAD_OF_REWIND};
+
+#[rustfmt::skip]
+pub(super) static SEEK_RULES: &[ChainRule] = &[
+    ChainRule {
+        lint: &SEEK_FROM_CURRENT,
+        methods: &["seek"],
+        receiver: Receiver::TraitMethod(0, &paths::IO_SEEK),
+        args: &[(0, ArgPred::Call(&paths::IO_SEEK_FROM_CURRENT, &[ArgPred::Int(0)]))],
+        check: None,
+        msrv: Some(msrvs::SEEK_STREAM_POSITION),
+        msg: "using `SeekFrom::Current` to start from current position",
+        fix: Fix::Sugg {
+            help: "replace with",
+            template: "{recv}.stream_position()",
+            applicability: Applicability::MachineApplicable,
+        },
+    },
+    ChainRule {
+        lint: &SEEK_TO_START_INSTEAD_OF_REWIND,
+        methods: &["seek"],
+        receiver: Receiver::TraitMethod(0, &paths::IO_SEEK),
+        args: &[(0, ArgPred::Call(&paths::IO_SEEK_FROM_START, &[ArgPred::Int(0)]))],
+        check: Some(position_is_unused),
+        msrv: Some(msrvs::SEEK_REWIND),
+        msg: "used `seek` to go to the start of the stream",
+        fix: Fix::Sugg {
+            help: "replace with",
+            template: "{recv}.rewind()",
+            applicability: Applicability::MachineApplicable,
+        },
+    },
+];
+
+/// `rewind` doesn't return the new position, so the result of `seek` must be
+/// discarded, possibly after `?`.
+fn position_is_unused(cx: &LateContext<'_, '_>, expr: &hir::Expr, _: &[&[hir::Expr]]) -> bool {
+    let map = cx.tcx.hir();
+    let mut id = expr.hir_id;
+    loop {
+        let parent_id = map.get_parent_node_by_hir_id(id);
+        match map.find_by_hir_id(parent_id) {
+            Some(Node::Stmt(stmt)) => {
+                return matches!(stmt.node, StmtKind::Semi(_));
+            },
+            // `Try::into_result(expr)` of the `?` desugaring, continue with the match on it
+            Some(Node::Expr(parent)) => {
+                if let ExprKind::Call(..) = parent.node {
+                    let match_id = map.get_parent_node_by_hir_id(parent_id);
+                    if let Some(Node::Expr(desugared)) = map.find_by_hir_id(match_id) {
+                        if let ExprKind::Match(_, _, MatchSource::TryDesugar) = desugared.node {
+                            id = match_id;
+                            continue;
+                        }
+                    }
+                }
+                return false;
+            },
+            _ => return false,
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use crate::utils::paths;
+use crate::utils::{in_macro, match_type, snippet, span_lint_and_sugg};
+use rustc::hir;
+use rustc::lint::LateContext;
+use rustc::ty;
+use rustc_errors::Applicability;
+use syntax::ast;
+
+use super::{MethodCallCtxt, SINGLE_CHAR_PATTERN};
+
+#[rustfmt::skip]
+const PATTERN_METHODS: [(&str, usize); 18] = [
+    ("contains", 1),
+    ("starts_with", 1),
+    ("ends_with", 1),
+    ("find", 1),
+    ("rfind", 1),
+    ("split", 1),
+    ("rsplit", 1),
+    ("split_terminator", 1),
+    ("rsplit_terminator", 1),
+    ("splitn", 2),
+    ("rsplitn", 2),
+    ("matches", 1),
+    ("rmatches", 1),
+    ("match_indices", 1),
+    ("rmatch_indices", 1),
+    ("trim_start_matches", 1),
+    ("trim_matches", 1),
+    ("trim_end_matches", 1),
+];
+
+/// Checks the pattern arguments of the `str` methods in `PATTERN_METHODS`.
+pub(super) fn lint(cx: &LateContext<'_, '_>, call: &MethodCallCtxt<'_>) {
+    if let ty::Ref(_, ty, _) = call.self_ty.sty {
+        if ty.sty == ty::Str {
+            for &(method, pos) in &PATTERN_METHODS {
+                if call.name == method && call.args.len() > pos {
+                    lint_pattern(cx, &call.args[pos]);
+                }
+            }
+        }
+    }
+}
+
+/// lint for length-1 `str`s and one-element `char` arrays
+fn lint_pattern(cx: &LateContext<'_, '_>, arg: &hir::Expr) {
+    let (msg, hint) = match arg.node {
+        hir::ExprKind::Lit(ref lit) => match lit.node {
+            ast::LitKind::Str(r, style) if r.as_str().len() == 1 => {
+                let snip = snippet(cx, arg.span, "");
+                let hint = match style {
+                    // keep the escapes of the string, except for quotes
+                    ast::StrStyle::Cooked if snip.len() > 2 && snip.starts_with('"') && snip.ends_with('"') => {
+                        match &snip[1..snip.len() - 1] {
+                            "'" => r"'\''".to_string(),
+                            r#"\""# => "'\"'".to_string(),
+                            inner => format!("'{}'", inner),
+                        }
+                    },
+                    _ => char_lit(r.as_str().chars().next().expect("one char")),
+                };
+                ("single-character string constant used as pattern", hint)
+            },
+            _ => return,
+        },
+        _ => {
+            let elem = match one_element_array(cx, arg) {
+                Some(elem) => elem,
+                None => return,
+            };
+            let hint = match elem.node {
+                hir::ExprKind::Lit(ref lit) => match lit.node {
+                    ast::LitKind::Char(_) => snippet(cx, elem.span, "..").into_owned(),
+                    _ => return,
+                },
+                // `b'x' as char`
+                hir::ExprKind::Cast(ref byte, _) => match byte.node {
+                    hir::ExprKind::Lit(ref lit) => match lit.node {
+                        ast::LitKind::Byte(b) => char_lit(char::from(b)),
+                        _ => return,
+                    },
+                    _ => return,
+                },
+                _ => return,
+            };
+            ("single-character array used as pattern", hint)
+        },
+    };
+    let applicability = if in_macro(arg.span) {
+        Applicability::MaybeIncorrect
+    } else {
+        Applicability::MachineApplicable
+    };
+    span_lint_and_sugg(
+        cx,
+        SINGLE_CHAR_PATTERN,
+        arg.span,
+        msg,
+        "try using a char instead",
+        hint,
+        applicability,
+    );
+}
+
+/// Returns the element of a pattern like `&['x'][..]` or `&['x'] as &[char]`.
+fn one_element_array<'a>(cx: &LateContext<'_, '_>, arg: &'a hir::Expr) -> Option<&'a hir::Expr> {
+    let slice = match arg.node {
+        hir::ExprKind::Cast(ref slice, _) => slice,
+        _ => arg,
+    };
+    let array = match slice.node {
+        hir::ExprKind::AddrOf(_, ref array) => match array.node {
+            hir::ExprKind::Index(ref array, ref index)
+                if match_type(cx, cx.tables.expr_ty(index), &paths::RANGE_FULL) =>
+            {
+                array
+            },
+            _ => array,
+        },
+        _ => return None,
+    };
+    match array.node {
+        hir::ExprKind::Array(ref elems) if elems.len() == 1 => Some(&elems[0]),
+        _ => None,
+    }
+}
+
+/// Returns a `char` literal for `c` with the necessary escapes.
+fn char_lit(c: char) -> String {
+    match c {
+        '"' => "'\"'".to_string(),
+        c if c.is_ascii() => format!("'{}'", c.escape_default()),
+        c => format!("'{}'", c.escape_debug()),
+    }
+}
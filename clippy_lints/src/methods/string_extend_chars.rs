@@ -0,0 +1,46 @@
+use crate::utils::paths;
+use crate::utils::{match_type, method_chain_args, snippet_with_applicability, span_lint_and_sugg, walk_ptrs_ty};
+use rustc::hir;
+use rustc::lint::LateContext;
+use rustc::ty;
+use rustc_errors::Applicability;
+
+use super::STRING_EXTEND_CHARS;
+
+fn lint_string_extend(cx: &LateContext<'_, '_>, expr: &hir::Expr, args: &[hir::Expr]) {
+    let arg = &args[1];
+    if let Some(arglists) = method_chain_args(arg, &["chars"]) {
+        let target = &arglists[0][0];
+        let self_ty = walk_ptrs_ty(cx.tables.expr_ty(target));
+        let ref_str = if self_ty.sty == ty::Str {
+            ""
+        } else if match_type(cx, self_ty, &paths::STRING) {
+            "&"
+        } else {
+            return;
+        };
+
+        let mut applicability = Applicability::MachineApplicable;
+        span_lint_and_sugg(
+            cx,
+            STRING_EXTEND_CHARS,
+            expr.span,
+            "calling `.extend(_.chars())`",
+            "try this",
+            format!(
+                "{}.push_str({}{})",
+                snippet_with_applicability(cx, args[0].span, "_", &mut applicability),
+                ref_str,
+                snippet_with_applicability(cx, target.span, "_", &mut applicability)
+            ),
+            applicability,
+        );
+    }
+}
+
+pub(super) fn lint(cx: &LateContext<'_, '_>, expr: &hir::Expr, args: &[hir::Expr]) {
+    let obj_ty = walk_ptrs_ty(cx.tables.expr_ty(&args[0]));
+    if match_type(cx, obj_ty, &paths::STRING) {
+        lint_string_extend(cx, expr, args);
+    }
+}
@@ -0,0 +1,152 @@
+use crate::utils::{
+    contains_name, match_trait_method, match_var, msrvs, paths, snippet_with_applicability, span_lint_and_sugg,
+    walk_ptrs_ty,
+};
+use rustc::hir;
+use rustc::lint::LateContext;
+use rustc::ty;
+use rustc_errors::Applicability;
+use semver::Version;
+use syntax::ast::LitKind;
+
+use super::{MethodCallCtxt, STRING_LIT_CHARS_ANY};
+
+/// Lints `"abc".chars().any(|c| c == x)` and `['a', 'b'].contains(&x)`.
+pub(super) fn lint(cx: &LateContext<'_, '_>, call: &MethodCallCtxt<'_>, msrv: Option<&Version>) {
+    if !msrvs::meets(msrv, msrvs::MATCHES_MACRO) || call.args.len() != 2 {
+        return;
+    }
+    let mut applicability = Applicability::MachineApplicable;
+    let (chars, value, msg) = if call.name == "any" && match_trait_method(cx, call.expr, &paths::ITERATOR) {
+        let chars = match string_lit_chars(&call.args[0]) {
+            Some(chars) => chars,
+            None => return,
+        };
+        let value = match compared_value(cx, &call.args[1]) {
+            Some(value) => value,
+            None => return,
+        };
+        (
+            chars,
+            snippet_with_applicability(cx, value.span, "..", &mut applicability).into_owned(),
+            "usage of `.chars().any(..)` to check if a char matches any from a string literal",
+        )
+    } else if call.name == "contains" && is_slice(call.self_ty) {
+        let chars = match char_array(&call.args[0]) {
+            Some(chars) => chars,
+            None => return,
+        };
+        let value = match call.args[1].node {
+            hir::ExprKind::AddrOf(hir::MutImmutable, ref value) => {
+                snippet_with_applicability(cx, value.span, "..", &mut applicability).into_owned()
+            },
+            _ => format!(
+                "*{}",
+                snippet_with_applicability(cx, call.args[1].span, "..", &mut applicability)
+            ),
+        };
+        (
+            chars,
+            value,
+            "usage of `contains` to check if a char matches any from an array literal",
+        )
+    } else {
+        return;
+    };
+    if chars.is_empty() {
+        return;
+    }
+
+    span_lint_and_sugg(
+        cx,
+        STRING_LIT_CHARS_ANY,
+        call.expr.span,
+        msg,
+        "use `matches!(..)` instead",
+        format!("matches!({}, {})", value, or_pattern(&chars)),
+        applicability,
+    );
+}
+
+/// The distinct chars of `"abc".chars()`, in order.
+fn string_lit_chars(expr: &hir::Expr) -> Option<Vec<char>> {
+    if let hir::ExprKind::MethodCall(ref path, _, ref args) = expr.node {
+        if path.ident.name == "chars" && args.len() == 1 {
+            if let hir::ExprKind::Lit(ref lit) = args[0].node {
+                if let LitKind::Str(ref s, _) = lit.node {
+                    return Some(distinct(s.as_str().chars()));
+                }
+            }
+        }
+    }
+    None
+}
+
+/// The distinct chars of `['a', 'b']`, in order, also behind a `&`.
+fn char_array(expr: &hir::Expr) -> Option<Vec<char>> {
+    match expr.node {
+        hir::ExprKind::AddrOf(hir::MutImmutable, ref inner) => char_array(inner),
+        hir::ExprKind::Array(ref elems) => {
+            let chars = elems
+                .iter()
+                .map(|elem| match elem.node {
+                    hir::ExprKind::Lit(ref lit) => match lit.node {
+                        LitKind::Char(c) => Some(c),
+                        _ => None,
+                    },
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Some(distinct(chars))
+        },
+        _ => None,
+    }
+}
+
+/// `x` of the closure `|c| c == x` or `|c| x == c`, where `x` doesn't use
+/// `c`.
+fn compared_value<'tcx>(cx: &LateContext<'_, 'tcx>, closure: &hir::Expr) -> Option<&'tcx hir::Expr> {
+    let body = match closure.node {
+        hir::ExprKind::Closure(_, _, body_id, ..) => cx.tcx.hir().body(body_id),
+        _ => return None,
+    };
+    let name = match body.arguments[0].pat.node {
+        hir::PatKind::Binding(hir::BindingAnnotation::Unannotated, _, _, ident, None) => ident.name,
+        _ => return None,
+    };
+    if let hir::ExprKind::Binary(op, ref left, ref right) = body.value.node {
+        if op.node == hir::BinOpKind::Eq {
+            if match_var(left, name) && !contains_name(name, right) {
+                return Some(right);
+            }
+            if match_var(right, name) && !contains_name(name, left) {
+                return Some(left);
+            }
+        }
+    }
+    None
+}
+
+fn is_slice(ty: ty::Ty<'_>) -> bool {
+    match walk_ptrs_ty(ty).sty {
+        ty::Slice(_) => true,
+        _ => false,
+    }
+}
+
+/// The chars without repetitions, in order.
+fn distinct(chars: impl IntoIterator<Item = char>) -> Vec<char> {
+    let mut distinct = Vec::new();
+    for c in chars {
+        // a repeated char would be an unreachable pattern
+        if !distinct.contains(&c) {
+            distinct.push(c);
+        }
+    }
+    distinct
+}
+
+/// `'a' | 'b'`, with the chars escaped as needed.
+fn or_pattern(chars: &[char]) -> String {
+    chars.iter().map(|c| format!("{:?}", c)).collect::<Vec<_>>().join(" | ")
+}
@@ -0,0 +1,167 @@
+use crate::utils::paths;
+use crate::utils::{
+    get_parent_expr, get_trait_def_id, implements_trait, match_def_path, match_trait_method, same_tys, snippet_opt,
+    span_lint_and_sugg,
+};
+use if_chain::if_chain;
+use matches::matches;
+use rustc::hir;
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::lint::LateContext;
+use rustc::ty::{self, Ty, TypeFoldable};
+use rustc_errors::Applicability;
+
+use super::{MethodCallCtxt, UNNECESSARY_TO_OWNED};
+
+/// Methods that turn a borrowed receiver into an owned value, with the trait
+/// they have to come from. `to_vec` is an inherent method of slices.
+const OWNING_METHODS: [(&str, &[&str]); 4] = [
+    ("clone", &paths::CLONE_TRAIT),
+    ("to_owned", &paths::TO_OWNED),
+    ("to_string", &paths::TO_STRING),
+    ("to_vec", &paths::SLICE_TO_VEC),
+];
+
+pub(super) fn lint<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, call: &MethodCallCtxt<'tcx>) {
+    let (expr, method_name, args) = (call.expr, &*call.name, call.args);
+    if_chain! {
+        if args.len() == 1;
+        if let Some(&(_, path)) = OWNING_METHODS.iter().find(|&&(name, _)| name == method_name);
+        if is_method_from(cx, expr, method_name, path);
+        // Only a borrowed receiver can be passed on as is, otherwise it would be
+        // moved instead of copied.
+        let receiver_ty = call.recv_ty;
+        if let ty::Ref(..) = receiver_ty.sty;
+        if let Some(parent) = get_parent_expr(cx, expr);
+        then {
+            match parent.node {
+                // `&x.to_string()` where `x: &str` and a `&str` is expected
+                hir::ExprKind::AddrOf(hir::MutImmutable, _) => {
+                    if same_tys(cx, receiver_ty, cx.tables.expr_ty_adjusted(parent)) {
+                        emit(cx, parent, &args[0], method_name);
+                    }
+                },
+                hir::ExprKind::Call(ref func, ref call_args) => {
+                    if_chain! {
+                        if let hir::ExprKind::Path(ref qpath) = func.node;
+                        if let Def::Fn(def_id) | Def::Method(def_id) = cx.tables.qpath_def(qpath, func.hir_id);
+                        if let Some(idx) = call_args.iter().position(|arg| arg.hir_id == expr.hir_id);
+                        if !callee_needs_ownership(cx, def_id, idx, receiver_ty);
+                        then {
+                            emit(cx, expr, &args[0], method_name);
+                        }
+                    }
+                },
+                hir::ExprKind::MethodCall(_, _, ref call_args) => {
+                    let def_id = cx.tables.type_dependent_defs()[parent.hir_id].def_id();
+                    if_chain! {
+                        if let Some(idx) = call_args.iter().position(|arg| arg.hir_id == expr.hir_id);
+                        // the receiver of a method call is adjusted automatically
+                        if idx > 0;
+                        if !callee_needs_ownership(cx, def_id, idx, receiver_ty);
+                        then {
+                            emit(cx, expr, &args[0], method_name);
+                        }
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+}
+
+fn is_method_from(cx: &LateContext<'_, '_>, expr: &hir::Expr, method_name: &str, path: &[&str]) -> bool {
+    if method_name == "to_vec" {
+        let def_id = cx.tables.type_dependent_defs()[expr.hir_id].def_id();
+        match_def_path(cx.tcx, def_id, path)
+    } else {
+        match_trait_method(cx, expr, path)
+    }
+}
+
+/// Checks whether the parameter at `idx` of the function `callee` needs an
+/// owned value, judging from the signature alone.
+///
+/// A parameter does not need ownership if it has a generic type that is only
+/// bounded by borrowing traits (`AsRef`, `Borrow`) which `borrowed_ty` implements
+/// as well, and that does not appear anywhere else in the signature.
+fn callee_needs_ownership<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    callee: DefId,
+    idx: usize,
+    borrowed_ty: Ty<'tcx>,
+) -> bool {
+    let fn_sig = cx.tcx.fn_sig(callee);
+    let fn_sig = cx.tcx.erase_late_bound_regions(&fn_sig);
+    let param_ty = match fn_sig.inputs().get(idx) {
+        Some(&ty) => ty,
+        None => return true,
+    };
+    if !matches!(param_ty.sty, ty::Param(_)) {
+        return true;
+    }
+
+    // `fn f<T: AsRef<str>>(a: T, b: T)` or `fn f<T: AsRef<str>>(a: T) -> T`
+    // tie the parameter to other types
+    let used_elsewhere = fn_sig
+        .inputs_and_output
+        .iter()
+        .enumerate()
+        .any(|(i, ty)| i != idx && ty.walk().any(|inner| inner == param_ty));
+    if used_elsewhere {
+        return true;
+    }
+
+    let borrow_traits = [
+        get_trait_def_id(cx, &paths::ASREF_TRAIT),
+        get_trait_def_id(cx, &paths::BORROW_TRAIT),
+    ];
+    let sized_trait = cx.tcx.lang_items().sized_trait();
+
+    let mut found_bound = false;
+    for (predicate, _) in &cx.tcx.predicates_of(callee).predicates {
+        match predicate {
+            ty::Predicate::Trait(poly_trait_ref) if poly_trait_ref.skip_binder().self_ty() == param_ty => {
+                let trait_id = poly_trait_ref.def_id();
+                if Some(trait_id) == sized_trait {
+                    continue;
+                }
+                let trait_params = &poly_trait_ref.skip_binder().trait_ref.substs[1..];
+                if !borrow_traits.contains(&Some(trait_id))
+                    || trait_params.iter().any(|param| param.has_param_types())
+                    || !implements_trait(cx, borrowed_ty, trait_id, trait_params)
+                {
+                    return true;
+                }
+                found_bound = true;
+            },
+            ty::Predicate::Projection(poly_projection)
+                if poly_projection.skip_binder().projection_ty.self_ty() == param_ty =>
+            {
+                return true;
+            },
+            // `T: 'static` can't be satisfied by most borrows
+            ty::Predicate::TypeOutlives(poly_outlives) if poly_outlives.skip_binder().0 == param_ty => {
+                return true;
+            },
+            _ => {},
+        }
+    }
+
+    !found_bound
+}
+
+fn emit(cx: &LateContext<'_, '_>, expr: &hir::Expr, receiver: &hir::Expr, method_name: &str) {
+    if let Some(snippet) = snippet_opt(cx, receiver.span) {
+        span_lint_and_sugg(
+            cx,
+            UNNECESSARY_TO_OWNED,
+            expr.span,
+            &format!("unnecessary use of `{}`", method_name),
+            "use",
+            snippet,
+            Applicability::MachineApplicable,
+        );
+    }
+}
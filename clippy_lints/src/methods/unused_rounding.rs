@@ -0,0 +1,70 @@
+use crate::consts::{constant_simple, Constant};
+use crate::utils::{get_parent_expr, in_macro, snippet_with_applicability, span_lint_and_sugg};
+use rustc::hir;
+use rustc::lint::LateContext;
+use rustc_errors::Applicability;
+
+use super::{MethodCallCtxt, UNUSED_ROUNDING};
+
+pub(super) fn lint(cx: &LateContext<'_, '_>, call: &MethodCallCtxt<'_>) {
+    let (expr, method_name, args) = (call.expr, &*call.name, call.args);
+    if args.len() != 1 || !["round", "floor", "ceil"].contains(&method_name) || in_macro(expr.span) {
+        return;
+    }
+    let recv = &args[0];
+    if !call.recv_ty.is_floating_point() {
+        return;
+    }
+
+    let msg = if is_whole_number(cx, recv) {
+        format!("used the `{}` method with a whole number float", method_name)
+    } else if is_int_to_float_cast(cx, recv) {
+        format!("used the `{}` method on a float converted from an integer", method_name)
+    } else {
+        return;
+    };
+
+    let mut applicability = Applicability::MachineApplicable;
+    let mut sugg = snippet_with_applicability(cx, recv.span, "..", &mut applicability).into_owned();
+    // the span of the receiver includes its parentheses, which are only needed
+    // if the result is used in another expression
+    let needs_parens = get_parent_expr(cx, expr).map_or(false, |parent| match parent.node {
+        hir::ExprKind::MethodCall(..)
+        | hir::ExprKind::Field(..)
+        | hir::ExprKind::Index(..)
+        | hir::ExprKind::Unary(..)
+        | hir::ExprKind::Binary(..)
+        | hir::ExprKind::Cast(..) => true,
+        _ => false,
+    });
+    if !needs_parens && sugg.starts_with('(') && sugg.ends_with(')') {
+        sugg = sugg[1..sugg.len() - 1].to_string();
+    }
+
+    span_lint_and_sugg(
+        cx,
+        UNUSED_ROUNDING,
+        expr.span,
+        &msg,
+        &format!("remove the `{}`", method_name),
+        sugg,
+        applicability,
+    );
+}
+
+/// Checks whether `expr` is a constant float without fractional part, like
+/// `1.0` or `-(2.)`.
+fn is_whole_number(cx: &LateContext<'_, '_>, expr: &hir::Expr) -> bool {
+    match constant_simple(cx, cx.tables, expr) {
+        Some(Constant::F32(value)) => value.is_finite() && value.fract() == 0.0,
+        Some(Constant::F64(value)) => value.is_finite() && value.fract() == 0.0,
+        _ => false,
+    }
+}
+
+fn is_int_to_float_cast(cx: &LateContext<'_, '_>, expr: &hir::Expr) -> bool {
+    match expr.node {
+        hir::ExprKind::Cast(ref inner, _) => cx.tables.expr_ty(inner).is_integral(),
+        _ => false,
+    }
+}
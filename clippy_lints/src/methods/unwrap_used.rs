@@ -0,0 +1,33 @@
+use crate::utils::paths;
+use crate::utils::{match_type, span_lint, walk_ptrs_ty};
+use rustc::hir;
+use rustc::lint::LateContext;
+
+use super::{OPTION_UNWRAP_USED, RESULT_UNWRAP_USED};
+
+/// lint use of `unwrap()` for `Option`s and `Result`s
+pub(super) fn lint(cx: &LateContext<'_, '_>, expr: &hir::Expr, unwrap_args: &[hir::Expr]) {
+    let obj_ty = walk_ptrs_ty(cx.tables.expr_ty(&unwrap_args[0]));
+
+    let mess = if match_type(cx, obj_ty, &paths::OPTION) {
+        Some((OPTION_UNWRAP_USED, "an Option", "None"))
+    } else if match_type(cx, obj_ty, &paths::RESULT) {
+        Some((RESULT_UNWRAP_USED, "a Result", "Err"))
+    } else {
+        None
+    };
+
+    if let Some((lint, kind, none_value)) = mess {
+        span_lint(
+            cx,
+            lint,
+            expr.span,
+            &format!(
+                "used unwrap() on {} value. If you don't want to handle the {} case gracefully, consider \
+                 using expect() to provide a better panic \
+                 message",
+                kind, none_value
+            ),
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use crate::consts::{constant_simple, Constant};
+use crate::utils::{in_macro, span_lint_and_then, walk_ptrs_ty};
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashSet;
+use syntax::ast::{Ident, NodeId};
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for slices indexed more than once with
+    /// constant indices, without their length being checked first.
+    ///
+    /// **Why is this bad?** Each indexing checks the bounds on its own. An
+    /// `assert!` of the length before indexing lets the compiler elide those
+    /// checks, and documents what the function expects.
+    ///
+    /// **Known problems:** Any comparison of the length of the slice in the
+    /// function counts as a check, wherever it is.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn sum(v: &[u8]) -> u8 {
+    ///     v[0] + v[1] + v[2]
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn sum(v: &[u8]) -> u8 {
+    ///     assert!(v.len() > 2);
+    ///     v[0] + v[1] + v[2]
+    /// }
+    /// ```
+    pub MISSING_ASSERTS_FOR_INDEXING,
+    restriction,
+    "indexing a slice multiple times without asserting its length first"
+}
+
+pub struct MissingAssertsForIndexing;
+
+impl LintPass for MissingAssertsForIndexing {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MISSING_ASSERTS_FOR_INDEXING)
+    }
+
+    fn name(&self) -> &'static str {
+        "MissingAssertsForIndexing"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MissingAssertsForIndexing {
+    fn check_body(&mut self, cx: &LateContext<'a, 'tcx>, body: &'tcx Body) {
+        let mut visitor = IndexVisitor {
+            cx,
+            slices: Vec::new(),
+            checked: FxHashSet::default(),
+        };
+        visitor.visit_expr(&body.value);
+
+        for slice in &visitor.slices {
+            if slice.indexes.len() < 2 || visitor.checked.contains(&slice.id) {
+                continue;
+            }
+            let (first, last) = (slice.indexes[0].0, slice.indexes[slice.indexes.len() - 1].0);
+            let max = slice.indexes.iter().map(|&(_, index)| index).max().unwrap_or(0);
+            span_lint_and_then(
+                cx,
+                MISSING_ASSERTS_FOR_INDEXING,
+                first.to(last),
+                "indexing into a slice multiple times without an `assert`",
+                |db| {
+                    db.help(&format!(
+                        "consider asserting the length before indexing: `assert!({}.len() > {});`",
+                        slice.ident, max
+                    ));
+                    db.note("asserting the length before indexing will elide bounds checks");
+                },
+            );
+        }
+    }
+}
+
+/// The constant indices a local slice is indexed with, in the order they
+/// appear.
+struct IndexedSlice {
+    id: NodeId,
+    ident: Ident,
+    indexes: Vec<(Span, u128)>,
+}
+
+struct IndexVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    slices: Vec<IndexedSlice>,
+    /// The locals whose length is compared with something.
+    checked: FxHashSet<NodeId>,
+}
+
+impl<'a, 'tcx: 'a> IndexVisitor<'a, 'tcx> {
+    fn check_index(&mut self, expr: &Expr, base: &Expr, index: &Expr) {
+        let (id, ident) = match local(base) {
+            Some(local) => local,
+            None => return,
+        };
+        if in_macro(expr.span) {
+            return;
+        }
+        if let ty::Slice(_) = walk_ptrs_ty(self.cx.tables.expr_ty_adjusted(base)).sty {
+            if let Some(Constant::Int(index)) = constant_simple(self.cx, self.cx.tables, index) {
+                match self.slices.iter_mut().find(|slice| slice.id == id) {
+                    Some(slice) => slice.indexes.push((expr.span, index)),
+                    None => self.slices.push(IndexedSlice {
+                        id,
+                        ident,
+                        indexes: vec![(expr.span, index)],
+                    }),
+                }
+            }
+        }
+    }
+
+    fn check_comparison(&mut self, operand: &Expr) {
+        if let ExprKind::MethodCall(ref path, _, ref args) = operand.node {
+            if path.ident.name == "len" && args.len() == 1 {
+                if let Some((id, _)) = local(&args[0]) {
+                    self.checked.insert(id);
+                }
+            }
+        }
+    }
+}
+
+impl<'a, 'tcx: 'a> Visitor<'tcx> for IndexVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        match expr.node {
+            ExprKind::Index(ref base, ref index) => self.check_index(expr, base, index),
+            ExprKind::Binary(op, ref left, ref right) => match op.node {
+                BinOpKind::Eq | BinOpKind::Ne | BinOpKind::Lt | BinOpKind::Le | BinOpKind::Gt | BinOpKind::Ge => {
+                    self.check_comparison(left);
+                    self.check_comparison(right);
+                },
+                _ => {},
+            },
+            _ => {},
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
+
+/// The id and name of the local variable `expr` is a path to.
+fn local(expr: &Expr) -> Option<(NodeId, Ident)> {
+    if let ExprKind::Path(QPath::Resolved(None, ref path)) = expr.node {
+        if let Def::Local(id) = path.def {
+            return Some((id, path.segments.last()?.ident));
+        }
+    }
+    None
+}
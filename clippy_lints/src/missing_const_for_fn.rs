@@ -1,11 +1,10 @@
+use crate::utils::const_eligibility::{self, ConstFeatures};
 use crate::utils::{is_entrypoint_fn, span_lint};
-use if_chain::if_chain;
 use rustc::hir;
 use rustc::hir::intravisit::FnKind;
 use rustc::hir::{Body, Constness, FnDecl, HirId};
 use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintPass};
 use rustc::{declare_tool_lint, lint_array};
-use rustc_mir::transform::qualify_min_const_fn::is_min_const_fn;
 use syntax_pos::Span;
 
 declare_clippy_lint! {
@@ -96,16 +95,14 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MissingConstForFn {
                 }
             },
             FnKind::Method(_, sig, ..) => {
-                if is_trait_method(cx, hir_id) || already_const(sig.header) {
+                if already_const(sig.header) {
                     return;
                 }
             },
             _ => return,
         }
 
-        let mir = cx.tcx.optimized_mir(def_id);
-
-        if let Err((span, err)) = is_min_const_fn(cx.tcx, def_id, &mir) {
+        if let Err((span, err)) = const_eligibility::check(cx, hir_id, ConstFeatures::default()) {
             if cx.tcx.is_min_const_fn(def_id) {
                 cx.tcx.sess.span_err(span, &err);
             }
@@ -115,18 +112,6 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MissingConstForFn {
     }
 }
 
-fn is_trait_method(cx: &LateContext<'_, '_>, hir_id: HirId) -> bool {
-    // Get the implemented trait for the current function
-    let parent_impl = cx.tcx.hir().get_parent_item(hir_id);
-    if_chain! {
-        if parent_impl != hir::CRATE_HIR_ID;
-        if let hir::Node::Item(item) = cx.tcx.hir().get_by_hir_id(parent_impl);
-        if let hir::ItemKind::Impl(_, _, _, _, Some(_trait_ref), _, _) = &item.node;
-        then { return true; }
-    }
-    false
-}
-
 // We don't have to lint on something that's already `const`
 fn already_const(header: hir::FnHeader) -> bool {
     header.constness == Constness::Const
@@ -0,0 +1,134 @@
+use crate::utils::conf::TraitMethods;
+use crate::utils::{def_path_def_ids, in_macro, span_lint};
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for trait implementations that don't override
+    /// a provided method of the trait.
+    ///
+    /// The checked traits and methods can be configured with the
+    /// `missing-trait-methods` option, e.g.
+    /// `missing-trait-methods = [{ path = "std::io::Write", methods = ["write_vectored"] }]`.
+    /// All provided methods of all traits are checked if it is empty.
+    ///
+    /// **Why is this bad?** Some provided methods have a generic
+    /// implementation that is much slower than what the implementor could
+    /// provide, e.g. `io::Write::write_vectored` writes one buffer at a time.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// trait Trait {
+    ///     fn required();
+    ///
+    ///     fn provided() {}
+    /// }
+    ///
+    /// # struct Type;
+    /// impl Trait for Type {
+    ///     fn required() { /* ... */ }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// trait Trait {
+    ///     fn required();
+    ///
+    ///     fn provided() {}
+    /// }
+    ///
+    /// # struct Type;
+    /// impl Trait for Type {
+    ///     fn required() { /* ... */ }
+    ///
+    ///     fn provided() { /* ... */ }
+    /// }
+    /// ```
+    pub MISSING_TRAIT_METHODS,
+    restriction,
+    "trait implementation uses the default implementation of a provided method"
+}
+
+pub struct MissingTraitMethods {
+    config: Vec<TraitMethods>,
+    /// The configured traits with the methods to check, all methods if empty.
+    /// Resolved once per crate.
+    traits: FxHashMap<DefId, Vec<String>>,
+}
+
+impl MissingTraitMethods {
+    pub fn new(config: &[TraitMethods]) -> Self {
+        Self {
+            config: config.to_vec(),
+            traits: FxHashMap::default(),
+        }
+    }
+
+    /// Returns the configured methods of the trait, `None` if the trait is
+    /// not checked. An empty list means all methods are checked.
+    fn methods_for(&self, trait_def_id: DefId) -> Option<&[String]> {
+        if self.config.is_empty() {
+            return Some(&[][..]);
+        }
+        self.traits.get(&trait_def_id).map(|methods| &methods[..])
+    }
+}
+
+impl LintPass for MissingTraitMethods {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MISSING_TRAIT_METHODS)
+    }
+
+    fn name(&self) -> &'static str {
+        "MissingTraitMethods"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MissingTraitMethods {
+    fn check_crate(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        for entry in &self.config {
+            let path: Vec<&str> = entry.path.split("::").collect();
+            for def_id in def_path_def_ids(cx, &path) {
+                self.traits.insert(def_id, entry.methods.clone());
+            }
+        }
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        let impl_items = match item.node {
+            ItemKind::Impl(.., Some(_), _, ref impl_items) => impl_items,
+            _ => return,
+        };
+        if in_macro(item.span) {
+            return;
+        }
+        let impl_def_id = cx.tcx.hir().local_def_id_from_hir_id(item.hir_id);
+        let trait_def_id = match cx.tcx.impl_trait_ref(impl_def_id) {
+            Some(trait_ref) => trait_ref.def_id,
+            None => return,
+        };
+        let methods = match self.methods_for(trait_def_id) {
+            Some(methods) => methods,
+            None => return,
+        };
+        let implemented: FxHashSet<_> = impl_items.iter().map(|item| item.ident.name).collect();
+        for method in cx.tcx.provided_trait_methods(trait_def_id) {
+            let name = method.ident.as_str();
+            let is_checked = methods.is_empty() || methods.iter().any(|m| *m == *name);
+            if !is_checked || implemented.contains(&method.ident.name) {
+                continue;
+            }
+            span_lint(
+                cx,
+                MISSING_TRAIT_METHODS,
+                cx.tcx.def_span(impl_def_id),
+                &format!("missing trait method provided by default: `{}`", name),
+            );
+        }
+    }
+}
@@ -1,5 +1,6 @@
 //! lint on multiple versions of a crate being used
 
+use crate::utils::manifest::Manifest;
 use crate::utils::span_lint;
 use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
 use rustc::{declare_tool_lint, lint_array};
@@ -53,6 +54,14 @@ impl EarlyLintPass for Pass {
             return;
         };
 
+        // the manifests of the workspace, to point at direct dependencies
+        let manifest_paths: Vec<_> = metadata
+            .packages
+            .iter()
+            .filter(|package| metadata.workspace_members.contains(&package.id))
+            .map(|package| package.manifest_path.clone())
+            .collect();
+
         let mut packages = metadata.packages;
         packages.sort_by(|a, b| a.name.cmp(&b.name));
 
@@ -62,10 +71,14 @@ impl EarlyLintPass for Pass {
             if group.len() > 1 {
                 let versions = group.into_iter().map(|p| p.version).join(", ");
 
+                let span = manifest_paths
+                    .iter()
+                    .find_map(|path| Manifest::load(cx, path)?.dependency(&name))
+                    .unwrap_or(DUMMY_SP);
                 span_lint(
                     cx,
                     MULTIPLE_CRATE_VERSIONS,
-                    DUMMY_SP,
+                    span,
                     &format!("multiple versions for dependency `{}`: {}", name, versions),
                 );
             }
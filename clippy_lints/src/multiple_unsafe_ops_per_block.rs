@@ -0,0 +1,175 @@
+use crate::utils::{in_macro, span_lint_and_then, type_is_unsafe_function, walk_ptrs_ty};
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_block, walk_expr, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `unsafe` blocks containing more than one
+    /// unsafe operation: dereferencing a raw pointer, calling an unsafe
+    /// function or method, reading a union field, accessing a mutable or
+    /// extern static, or inline assembly.
+    ///
+    /// **Why is this bad?** Each unsafe operation has its own safety
+    /// requirements. With one operation per block, each block (and its
+    /// `// SAFETY:` comment) justifies exactly one of them, which is easier
+    /// to review.
+    ///
+    /// **Known problems:** Nested `unsafe` blocks are counted on their own.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let ptr = &1 as *const i32;
+    /// # unsafe fn f(_: i32) {}
+    /// unsafe {
+    ///     f(*ptr);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let ptr = &1 as *const i32;
+    /// # unsafe fn f(_: i32) {}
+    /// let value = unsafe { *ptr };
+    /// unsafe {
+    ///     f(value);
+    /// }
+    /// ```
+    pub MULTIPLE_UNSAFE_OPS_PER_BLOCK,
+    restriction,
+    "more than one unsafe operation per `unsafe` block"
+}
+
+pub struct MultipleUnsafeOpsPerBlock;
+
+impl LintPass for MultipleUnsafeOpsPerBlock {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MULTIPLE_UNSAFE_OPS_PER_BLOCK)
+    }
+
+    fn name(&self) -> &'static str {
+        "MultipleUnsafeOpsPerBlock"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MultipleUnsafeOpsPerBlock {
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        if !is_user_unsafe_block(block) || in_macro(block.span) {
+            return;
+        }
+        let mut visitor = UnsafeOpsVisitor { cx, ops: Vec::new() };
+        walk_block(&mut visitor, block);
+        if visitor.ops.len() > 1 {
+            span_lint_and_then(
+                cx,
+                MULTIPLE_UNSAFE_OPS_PER_BLOCK,
+                block.span,
+                &format!(
+                    "this `unsafe` block contains {} unsafe operations, expected only one",
+                    visitor.ops.len()
+                ),
+                |db| {
+                    for (op, span) in visitor.ops {
+                        db.span_note(span, op.note());
+                    }
+                },
+            );
+        }
+    }
+}
+
+fn is_user_unsafe_block(block: &Block) -> bool {
+    match block.rules {
+        BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided) => true,
+        _ => false,
+    }
+}
+
+/// The operations that require an `unsafe` block.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum UnsafeOp {
+    RawPtrDeref,
+    FnCall,
+    MethodCall,
+    UnionField,
+    MutableStatic,
+    ExternStatic,
+    InlineAsm,
+}
+
+impl UnsafeOp {
+    fn note(self) -> &'static str {
+        match self {
+            UnsafeOp::RawPtrDeref => "raw pointer dereference occurs here",
+            UnsafeOp::FnCall => "unsafe function call occurs here",
+            UnsafeOp::MethodCall => "unsafe method call occurs here",
+            UnsafeOp::UnionField => "union field access occurs here",
+            UnsafeOp::MutableStatic => "access of a mutable static occurs here",
+            UnsafeOp::ExternStatic => "access of an extern static occurs here",
+            UnsafeOp::InlineAsm => "inline assembly used here",
+        }
+    }
+}
+
+/// Collects the unsafe operations of a block, in the order they are written.
+struct UnsafeOpsVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    ops: Vec<(UnsafeOp, Span)>,
+}
+
+impl<'a, 'tcx: 'a> UnsafeOpsVisitor<'a, 'tcx> {
+    fn is_union_field(&self, expr: &Expr) -> bool {
+        match expr.node {
+            ExprKind::Field(ref base, _) => match walk_ptrs_ty(self.cx.tables.expr_ty(base)).ty_adt_def() {
+                Some(adt) => adt.is_union(),
+                None => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+impl<'a, 'tcx: 'a> Visitor<'tcx> for UnsafeOpsVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        let tables = self.cx.tables;
+        let op = match expr.node {
+            // nested `unsafe` blocks are checked on their own
+            ExprKind::Block(ref block, _) if is_user_unsafe_block(block) => return,
+            // writing to a union field is safe, only its base is visited
+            ExprKind::Assign(ref lhs, ref rhs) if self.is_union_field(lhs) => {
+                if let ExprKind::Field(ref base, _) = lhs.node {
+                    self.visit_expr(base);
+                }
+                self.visit_expr(rhs);
+                return;
+            },
+            ExprKind::Unary(UnDeref, ref ptr) if tables.expr_ty(ptr).is_unsafe_ptr() => Some(UnsafeOp::RawPtrDeref),
+            ExprKind::Call(ref fun, _) if type_is_unsafe_function(self.cx, tables.expr_ty(fun)) => {
+                Some(UnsafeOp::FnCall)
+            },
+            ExprKind::MethodCall(..) => match tables.type_dependent_defs().get(expr.hir_id) {
+                Some(def) if type_is_unsafe_function(self.cx, self.cx.tcx.type_of(def.def_id())) => {
+                    Some(UnsafeOp::MethodCall)
+                },
+                _ => None,
+            },
+            ExprKind::Field(..) if self.is_union_field(expr) => Some(UnsafeOp::UnionField),
+            ExprKind::Path(ref qpath) => match tables.qpath_def(qpath, expr.hir_id) {
+                Def::Static(_, true) => Some(UnsafeOp::MutableStatic),
+                Def::Static(def_id, false) if self.cx.tcx.is_foreign_item(def_id) => Some(UnsafeOp::ExternStatic),
+                _ => None,
+            },
+            ExprKind::InlineAsm(..) => Some(UnsafeOp::InlineAsm),
+            _ => None,
+        };
+        if let Some(op) = op {
+            self.ops.push((op, expr.span));
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir())
+    }
+}
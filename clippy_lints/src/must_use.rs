@@ -0,0 +1,240 @@
+use crate::utils::{fn_sig_span, in_macro, is_must_use_ty, return_ty, snippet, span_help_and_lint, span_lint_and_then};
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::Attribute;
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `#[must_use]` on functions returning `()`.
+    ///
+    /// **Why is this bad?** There is nothing to use, so the attribute has no
+    /// effect.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// #[must_use]
+    /// fn useless() {}
+    /// ```
+    pub MUST_USE_UNIT,
+    style,
+    "`#[must_use]` attribute on a unit-returning function"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `#[must_use]` without a reason on
+    /// functions returning a type that is already `#[must_use]`.
+    ///
+    /// **Why is this bad?** Unused results of the type are already reported,
+    /// so the attribute has no effect.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// #[must_use]
+    /// fn double_must_use() -> Result<(), ()> {
+    ///     unimplemented!();
+    /// }
+    /// ```
+    pub DOUBLE_MUST_USE,
+    style,
+    "`#[must_use]` attribute on a function returning a `#[must_use]` type"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for public functions that have no side
+    /// effects visible to the caller and are not `#[must_use]`.
+    ///
+    /// **Why is this bad?** Calling such a function without using its result
+    /// is most likely a mistake.
+    ///
+    /// **Known problems:** Functions with `&mut` or raw `*mut` arguments and
+    /// functions assigning to statics are considered to have side effects.
+    /// Side effects through interior mutability or function calls are not
+    /// detected. Most getters are candidates, so existing code has many
+    /// warnings.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// pub fn id<T>(t: T) -> T {
+    ///     t
+    /// }
+    /// ```
+    pub MUST_USE_CANDIDATE,
+    restriction,
+    "function that has no side effects but no `#[must_use]` attribute"
+}
+
+#[derive(Copy, Clone)]
+pub struct MustUse;
+
+impl LintPass for MustUse {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MUST_USE_UNIT, DOUBLE_MUST_USE, MUST_USE_CANDIDATE)
+    }
+
+    fn name(&self) -> &'static str {
+        "MustUse"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MustUse {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if let ItemKind::Fn(ref decl, _, _, body_id) = item.node {
+            check_fn(cx, item.hir_id, item.span, &item.attrs, decl, Some(body_id));
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx ImplItem) {
+        if let ImplItemKind::Method(ref sig, body_id) = item.node {
+            // the attribute belongs on the trait method
+            let parent = cx.tcx.hir().get_parent_item(item.hir_id);
+            if let ItemKind::Impl(_, _, _, _, None, _, _) = cx.tcx.hir().expect_item_by_hir_id(parent).node {
+                check_fn(cx, item.hir_id, item.span, &item.attrs, &sig.decl, Some(body_id));
+            }
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx TraitItem) {
+        if let TraitItemKind::Method(ref sig, _) = item.node {
+            check_fn(cx, item.hir_id, item.span, &item.attrs, &sig.decl, None);
+        }
+    }
+}
+
+/// Checks the `#[must_use]` attribute of a function, or whether the function
+/// should have one if it has a `body`.
+fn check_fn(
+    cx: &LateContext<'_, '_>,
+    hir_id: HirId,
+    span: Span,
+    attrs: &[Attribute],
+    decl: &FnDecl,
+    body: Option<BodyId>,
+) {
+    if in_macro(span) {
+        return;
+    }
+    let sig_span = fn_sig_span(cx, span, decl);
+    let ret_ty = return_ty(cx, hir_id);
+    if let Some(attr) = attrs.iter().find(|attr| attr.check_name("must_use")) {
+        if ret_ty.is_unit() {
+            span_lint_and_then(
+                cx,
+                MUST_USE_UNIT,
+                sig_span,
+                "this unit-returning function has a `#[must_use]` attribute",
+                |db| {
+                    db.span_suggestion(
+                        attr.span,
+                        "remove the attribute",
+                        String::new(),
+                        Applicability::MachineApplicable,
+                    );
+                },
+            );
+        } else if attr.value_str().is_none() && is_must_use_ty(cx, ret_ty) {
+            span_help_and_lint(
+                cx,
+                DOUBLE_MUST_USE,
+                sig_span,
+                "this function has an empty `#[must_use]` attribute, but returns a type already marked as \
+                 `#[must_use]`",
+                "either add some descriptive text or remove the attribute",
+            );
+        }
+        return;
+    }
+
+    let body = match body {
+        Some(body_id) => cx.tcx.hir().body(body_id),
+        None => return,
+    };
+    if ret_ty.is_unit()
+        || ret_ty.is_never()
+        || is_must_use_ty(cx, ret_ty)
+        || !cx.access_levels.is_exported(cx.tcx.hir().hir_to_node_id(hir_id))
+        || has_mutable_arg(cx, hir_id)
+        || mutates_non_local(cx, body)
+    {
+        return;
+    }
+    span_lint_and_then(
+        cx,
+        MUST_USE_CANDIDATE,
+        sig_span,
+        "this function could have a `#[must_use]` attribute",
+        |db| {
+            db.span_suggestion(
+                sig_span,
+                "add the attribute",
+                format!("#[must_use] {}", snippet(cx, sig_span, "..")),
+                Applicability::MachineApplicable,
+            );
+        },
+    );
+}
+
+/// Checks whether any argument of the function allows mutating data of the
+/// caller.
+fn has_mutable_arg(cx: &LateContext<'_, '_>, hir_id: HirId) -> bool {
+    let fn_def_id = cx.tcx.hir().local_def_id_from_hir_id(hir_id);
+    let sig = cx.tcx.fn_sig(fn_def_id);
+    sig.skip_binder().inputs().iter().any(|ty| {
+        ty.walk().any(|ty| match ty.sty {
+            ty::Ref(_, _, MutMutable) | ty::RawPtr(ty::TypeAndMut { mutbl: MutMutable, .. }) => true,
+            _ => false,
+        })
+    })
+}
+
+/// Checks whether the body assigns to or mutably borrows anything but its own
+/// locals.
+fn mutates_non_local<'a, 'tcx>(cx: &'a LateContext<'a, 'tcx>, body: &'tcx Body) -> bool {
+    let mut visitor = MutationVisitor { cx, mutates: false };
+    visitor.visit_expr(&body.value);
+    visitor.mutates
+}
+
+struct MutationVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    mutates: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for MutationVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        match expr.node {
+            ExprKind::Assign(ref place, _)
+            | ExprKind::AssignOp(_, ref place, _)
+            | ExprKind::AddrOf(MutMutable, ref place) => self.mutates |= !is_local_place(place),
+            ExprKind::InlineAsm(..) => self.mutates = true,
+            _ => {},
+        }
+        if !self.mutates {
+            walk_expr(self, expr);
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir())
+    }
+}
+
+/// Checks whether the place is (part of) a local variable.
+fn is_local_place(place: &Expr) -> bool {
+    match place.node {
+        ExprKind::Field(ref base, _) | ExprKind::Index(ref base, _) => is_local_place(base),
+        ExprKind::Path(QPath::Resolved(None, ref path)) => match path.def {
+            Def::Local(..) | Def::Upvar(..) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
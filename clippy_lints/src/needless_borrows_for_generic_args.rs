@@ -0,0 +1,188 @@
+use crate::utils::{in_macro, is_copy, snippet_opt, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::{Expr, ExprKind, MutImmutable, QPath};
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::traits::{Obligation, ObligationCause};
+use rustc::ty::subst::{Kind, SubstsRef};
+use rustc::ty::{self, Ty};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for borrowed arguments of generic functions,
+    /// like `f(&x)` for `fn f(_: impl AsRef<str>)`, where the unborrowed
+    /// value satisfies the bounds of the parameter as well.
+    ///
+    /// The borrow is only removed if the value is `Copy` or a temporary, so
+    /// it can't be used after being moved into the call, and if the type
+    /// parameter isn't used anywhere else in the signature.
+    ///
+    /// **Why is this bad?** The borrow is noise, and the function may have
+    /// to dereference it on every use.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn exists(path: impl AsRef<std::path::Path>) -> bool {
+    ///     path.as_ref().exists()
+    /// }
+    /// exists(&"Cargo.toml");
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # fn exists(path: impl AsRef<std::path::Path>) -> bool {
+    /// #     path.as_ref().exists()
+    /// # }
+    /// exists("Cargo.toml");
+    /// ```
+    pub NEEDLESS_BORROWS_FOR_GENERIC_ARGS,
+    style,
+    "taking a reference of a value that satisfies the generic bounds of the parameter on its own"
+}
+
+pub struct NeedlessBorrowsForGenericArgs;
+
+impl LintPass for NeedlessBorrowsForGenericArgs {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(NEEDLESS_BORROWS_FOR_GENERIC_ARGS)
+    }
+
+    fn name(&self) -> &'static str {
+        "NeedlessBorrowsForGenericArgs"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NeedlessBorrowsForGenericArgs {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        let (def_id, substs, args, first) = match expr.node {
+            ExprKind::Call(ref func, ref args) => {
+                let qpath = match func.node {
+                    ExprKind::Path(ref qpath) => qpath,
+                    _ => return,
+                };
+                // explicit generic arguments would no longer match
+                let has_generic_args = match *qpath {
+                    QPath::Resolved(_, ref path) => path.segments.last().map_or(false, |seg| seg.args.is_some()),
+                    QPath::TypeRelative(_, ref seg) => seg.args.is_some(),
+                };
+                match cx.tables.qpath_def(qpath, func.hir_id) {
+                    Def::Fn(def_id) | Def::Method(def_id) if !has_generic_args => {
+                        (def_id, cx.tables.node_substs(func.hir_id), args, 0)
+                    },
+                    _ => return,
+                }
+            },
+            // the receiver is borrowed automatically
+            ExprKind::MethodCall(ref path, _, ref args) if path.args.is_none() => {
+                match cx.tables.type_dependent_defs().get(expr.hir_id) {
+                    Some(def) => (def.def_id(), cx.tables.node_substs(expr.hir_id), args, 1),
+                    None => return,
+                }
+            },
+            _ => return,
+        };
+
+        for (index, arg) in args.iter().enumerate().skip(first) {
+            if_chain! {
+                if let ExprKind::AddrOf(MutImmutable, ref referent) = arg.node;
+                if !in_macro(arg.span);
+                let referent_ty = cx.tables.expr_ty(referent);
+                if is_copy(cx, referent_ty) || !is_place(referent);
+                if borrow_is_needless(cx, def_id, substs, index, referent_ty);
+                if let Some(snip) = snippet_opt(cx, referent.span);
+                then {
+                    span_lint_and_sugg(
+                        cx,
+                        NEEDLESS_BORROWS_FOR_GENERIC_ARGS,
+                        arg.span,
+                        "the borrowed expression implements the required traits",
+                        "change this to",
+                        snip,
+                        Applicability::MachineApplicable,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Whether `expr` denotes a place, which would be moved out of instead of a
+/// temporary.
+fn is_place(expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::Path(..) | ExprKind::Field(..) | ExprKind::Index(..) | ExprKind::Unary(..) => true,
+        _ => false,
+    }
+}
+
+/// Whether the parameter `index` of `def_id` is a type parameter that only
+/// occurs there in the signature, and all predicates of `def_id` still hold
+/// with `referent_ty` in its place.
+fn borrow_is_needless<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    def_id: DefId,
+    substs: SubstsRef<'tcx>,
+    index: usize,
+    referent_ty: Ty<'tcx>,
+) -> bool {
+    let tcx = cx.tcx;
+    let sig = tcx.fn_sig(def_id);
+    let sig = sig.skip_binder();
+    let param_ty = match sig.inputs().get(index) {
+        Some(ty) => *ty,
+        None => return false,
+    };
+    let param = match param_ty.sty {
+        ty::Param(param) => param,
+        _ => return false,
+    };
+    // the parameters of an impl or trait also occur in its `Self` type
+    if (param.idx as usize) < tcx.generics_of(def_id).parent_count {
+        return false;
+    }
+    let occurs_elsewhere = sig
+        .inputs_and_output
+        .iter()
+        .enumerate()
+        .any(|(i, ty)| i != index && ty.walk().any(|ty| ty == param_ty));
+    if occurs_elsewhere {
+        return false;
+    }
+    // without a bound, like in `drop(&x)`, the borrow is what the call is about
+    let sized_trait = tcx.lang_items().sized_trait();
+    let is_bounded = tcx
+        .predicates_of(def_id)
+        .predicates
+        .iter()
+        .any(|(predicate, _)| match *predicate {
+            ty::Predicate::Trait(ref poly) => {
+                poly.skip_binder().self_ty() == param_ty && Some(poly.def_id()) != sized_trait
+            },
+            _ => false,
+        });
+    if !is_bounded {
+        return false;
+    }
+
+    // ask the trait solver whether the call would still compile
+    let substs = tcx.mk_substs(substs.iter().enumerate().map(|(i, kind)| {
+        if i == param.idx as usize {
+            Kind::from(referent_ty)
+        } else {
+            *kind
+        }
+    }));
+    let predicates = tcx.predicates_of(def_id).instantiate(tcx, substs).predicates;
+    tcx.infer_ctxt().enter(|infcx| {
+        predicates.into_iter().all(|predicate| {
+            let obligation = Obligation::new(ObligationCause::dummy(), cx.param_env, predicate);
+            infcx.predicate_must_hold_modulo_regions(&obligation)
+        })
+    })
+}
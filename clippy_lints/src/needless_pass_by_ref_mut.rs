@@ -0,0 +1,309 @@
+use crate::utils::{get_parent_expr, in_macro, is_self, snippet, span_lint_node_and_then};
+use if_chain::if_chain;
+use matches::matches;
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::intravisit::FnKind;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::middle::expr_use_visitor as euv;
+use rustc::middle::mem_categorization as mc;
+use rustc::ty;
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_errors::Applicability;
+use rustc_target::spec::abi::Abi;
+use syntax_pos::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for arguments taken as `&mut` that are never
+    /// used mutably: not mutated through, not reborrowed mutably, not passed
+    /// on as `&mut` and not captured mutably by a closure.
+    ///
+    /// **Why is this bad?** The function asks for more than it needs, so
+    /// callers need a mutable binding and can't share the value meanwhile.
+    ///
+    /// **Known problems:** Functions that are exported or used other than by
+    /// calling them are skipped, since changing their signature could break
+    /// other code.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn len(v: &mut Vec<u32>) -> usize {
+    ///     v.len()
+    /// }
+    /// let mut v = vec![1];
+    /// len(&mut v);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn len(v: &Vec<u32>) -> usize {
+    ///     v.len()
+    /// }
+    /// let v = vec![1];
+    /// len(&v);
+    /// ```
+    pub NEEDLESS_PASS_BY_REF_MUT,
+    nursery,
+    "using a `&mut` argument when it's not mutated"
+}
+
+#[derive(Default)]
+pub struct NeedlessPassByRefMut {
+    /// Functions with `&mut` arguments that are never used mutably, in the
+    /// order they were checked.
+    fns: Vec<(DefId, HirId, Vec<NeedlessArg>)>,
+    /// Functions used other than by calling them, e.g. as `map(f)`.
+    used_as_value: FxHashSet<DefId>,
+    /// The `&mut expr` arguments of calls, by the called function and the
+    /// index of the argument.
+    mut_args: FxHashMap<(DefId, usize), Vec<(Span, String)>>,
+}
+
+/// An argument taken as `&mut` that can be taken as `&`.
+struct NeedlessArg {
+    index: usize,
+    span: Span,
+    sugg: String,
+}
+
+impl LintPass for NeedlessPassByRefMut {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(NEEDLESS_PASS_BY_REF_MUT)
+    }
+
+    fn name(&self) -> &'static str {
+        "NeedlessPassByRefMut"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NeedlessPassByRefMut {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        hir_id: HirId,
+    ) {
+        if in_macro(span) || cx.access_levels.is_exported(cx.tcx.hir().hir_to_node_id(hir_id)) {
+            return;
+        }
+        match kind {
+            FnKind::ItemFn(.., header, _, _) if header.abi == Abi::Rust => {},
+            FnKind::Method(..) => {},
+            _ => return,
+        }
+        // the signature of trait methods and their implementations is fixed
+        if let Some(Node::Item(item)) = cx
+            .tcx
+            .hir()
+            .find_by_hir_id(cx.tcx.hir().get_parent_node_by_hir_id(hir_id))
+        {
+            if matches!(
+                item.node,
+                ItemKind::Impl(_, _, _, _, Some(_), _, _) | ItemKind::Trait(..)
+            ) {
+                return;
+            }
+        }
+
+        let fn_def_id = cx.tcx.hir().local_def_id_from_hir_id(hir_id);
+        let fn_sig = cx.tcx.fn_sig(fn_def_id);
+        let fn_sig = cx.tcx.erase_late_bound_regions(&fn_sig);
+
+        let mut candidates = Vec::new();
+        for (index, ((input, &ty), arg)) in decl.inputs.iter().zip(fn_sig.inputs()).zip(&body.arguments).enumerate() {
+            if_chain! {
+                if let ty::Ref(_, _, MutMutable) = ty.sty;
+                if !is_self(arg);
+                if let PatKind::Binding(_, _, canonical_id, ..) = arg.pat.node;
+                if let TyKind::Rptr(ref lifetime, ref mut_ty) = input.node;
+                then {
+                    let sugg = if lifetime.is_elided() {
+                        format!("&{}", snippet(cx, mut_ty.ty.span, "_"))
+                    } else {
+                        format!("&{} {}", snippet(cx, lifetime.span, "'_"), snippet(cx, mut_ty.ty.span, "_"))
+                    };
+                    candidates.push((canonical_id, NeedlessArg { index, span: input.span, sugg }));
+                }
+            }
+        }
+        if candidates.is_empty() {
+            return;
+        }
+
+        let mut ctx = MutablyUsedVariables::default();
+        let region_scope_tree = &cx.tcx.region_scope_tree(fn_def_id);
+        euv::ExprUseVisitor::new(&mut ctx, cx.tcx, cx.param_env, region_scope_tree, cx.tables, None).consume_body(body);
+
+        let args: Vec<_> = candidates
+            .into_iter()
+            .filter(|(id, _)| !ctx.mutably_used.contains(id))
+            .map(|(_, arg)| arg)
+            .collect();
+        if !args.is_empty() {
+            self.fns.push((fn_def_id, hir_id, args));
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        match expr.node {
+            ExprKind::Call(ref callee, ref args) => {
+                if let ExprKind::Path(ref qpath) = callee.node {
+                    if let Some(def_id) = local_fn(cx.tables.qpath_def(qpath, callee.hir_id)) {
+                        self.record_mut_args(cx, def_id, args.iter().enumerate());
+                    }
+                }
+            },
+            ExprKind::MethodCall(_, _, ref args) => {
+                let def = cx.tables.type_dependent_defs().get(expr.hir_id).cloned();
+                if let Some(def_id) = def.and_then(local_fn) {
+                    self.record_mut_args(cx, def_id, args.iter().enumerate());
+                }
+            },
+            ExprKind::Path(ref qpath) => {
+                if let Some(def_id) = local_fn(cx.tables.qpath_def(qpath, expr.hir_id)) {
+                    let is_callee = match get_parent_expr(cx, expr) {
+                        Some(parent) => {
+                            matches!(parent.node, ExprKind::Call(ref callee, _) if callee.hir_id == expr.hir_id)
+                        },
+                        None => false,
+                    };
+                    if !is_callee {
+                        self.used_as_value.insert(def_id);
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        for (def_id, hir_id, args) in &self.fns {
+            if self.used_as_value.contains(def_id) {
+                continue;
+            }
+            for arg in args {
+                span_lint_node_and_then(
+                    cx,
+                    NEEDLESS_PASS_BY_REF_MUT,
+                    *hir_id,
+                    arg.span,
+                    "this argument is a mutable reference, but not used mutably",
+                    |db| {
+                        db.span_suggestion(
+                            arg.span,
+                            "consider changing to",
+                            arg.sugg.clone(),
+                            Applicability::MaybeIncorrect,
+                        );
+                        for (span, sugg) in self.mut_args.get(&(*def_id, arg.index)).into_iter().flatten() {
+                            db.span_suggestion(
+                                *span,
+                                &format!("change `{}` to", snippet(cx, *span, "..")),
+                                sugg.clone(),
+                                Applicability::MaybeIncorrect,
+                            );
+                        }
+                    },
+                );
+            }
+        }
+    }
+}
+
+impl NeedlessPassByRefMut {
+    /// Records the `&mut expr` arguments of a call to a local function, to
+    /// suggest `&expr` at the call sites too.
+    fn record_mut_args<'e>(
+        &mut self,
+        cx: &LateContext<'_, '_>,
+        def_id: DefId,
+        args: impl Iterator<Item = (usize, &'e Expr)>,
+    ) {
+        for (index, arg) in args {
+            if let ExprKind::AddrOf(MutMutable, ref inner) = arg.node {
+                if !in_macro(arg.span) {
+                    self.mut_args
+                        .entry((def_id, index))
+                        .or_insert_with(Vec::new)
+                        .push((arg.span, format!("&{}", snippet(cx, inner.span, ".."))));
+                }
+            }
+        }
+    }
+}
+
+fn local_fn(def: Def) -> Option<DefId> {
+    match def {
+        Def::Fn(def_id) | Def::Method(def_id) if def_id.is_local() => Some(def_id),
+        _ => None,
+    }
+}
+
+/// Collects the local variables that are used mutably.
+#[derive(Default)]
+struct MutablyUsedVariables {
+    mutably_used: FxHashSet<HirId>,
+}
+
+impl MutablyUsedVariables {
+    fn add(&mut self, cmt: &mc::cmt_<'_>) {
+        if let Some(id) = base_local(cmt) {
+            self.mutably_used.insert(id);
+        }
+    }
+}
+
+/// The local variable a place is based on, looking through dereferences,
+/// fields and enum variants.
+fn base_local(cmt: &mc::cmt_<'_>) -> Option<HirId> {
+    match cmt.cat {
+        mc::Categorization::Local(id) => Some(id),
+        mc::Categorization::Deref(ref base, _)
+        | mc::Categorization::Interior(ref base, _)
+        | mc::Categorization::Downcast(ref base, _) => base_local(base),
+        _ => None,
+    }
+}
+
+impl<'tcx> euv::Delegate<'tcx> for MutablyUsedVariables {
+    // moving the reference somewhere else, we don't know what happens with it there
+    fn consume(&mut self, _: HirId, _: Span, cmt: &mc::cmt_<'tcx>, mode: euv::ConsumeMode) {
+        if let euv::ConsumeMode::Move(_) = mode {
+            self.add(cmt);
+        }
+    }
+
+    fn matched_pat(&mut self, _: &Pat, _: &mc::cmt_<'tcx>, _: euv::MatchMode) {}
+
+    fn consume_pat(&mut self, _: &Pat, cmt: &mc::cmt_<'tcx>, mode: euv::ConsumeMode) {
+        if let euv::ConsumeMode::Move(_) = mode {
+            self.add(cmt);
+        }
+    }
+
+    // mutable reborrows, including the autoref of `&mut self` methods, and
+    // closures capturing the reference to mutate through it
+    fn borrow(
+        &mut self,
+        _: HirId,
+        _: Span,
+        cmt: &mc::cmt_<'tcx>,
+        _: ty::Region<'_>,
+        kind: ty::BorrowKind,
+        _: euv::LoanCause,
+    ) {
+        if kind != ty::BorrowKind::ImmBorrow {
+            self.add(cmt);
+        }
+    }
+
+    fn mutate(&mut self, _: HirId, _: Span, cmt: &mc::cmt_<'tcx>, _: euv::MutateMode) {
+        self.add(cmt);
+    }
+
+    fn decl_without_init(&mut self, _: HirId, _: Span) {}
+}
@@ -0,0 +1,244 @@
+use crate::utils::paths;
+use crate::utils::{
+    get_trait_def_id, implements_trait, in_macro, is_automatically_derived, is_copy, match_def_path, match_path,
+    match_qpath, opt_def_id, span_lint_and_then,
+};
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, Lint, LintArray, LintPass};
+use rustc::ty::TypeckTables;
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Clone` implementations on `Copy` types
+    /// whose `clone` method does anything but return `*self`.
+    ///
+    /// **Why is this bad?** Copying and cloning a `Copy` type is expected to
+    /// have the same result, and generic code may use either of them.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// #[derive(Copy)]
+    /// struct Meters(u32);
+    ///
+    /// impl Clone for Meters {
+    ///     fn clone(&self) -> Self {
+    ///         Meters(self.0)
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// #[derive(Copy)]
+    /// struct Meters(u32);
+    ///
+    /// impl Clone for Meters {
+    ///     fn clone(&self) -> Self {
+    ///         *self
+    ///     }
+    /// }
+    /// ```
+    pub NON_CANONICAL_CLONE_IMPL,
+    style,
+    "implementing `Clone` on a `Copy` type with a body other than `*self`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `PartialOrd` implementations on `Ord`
+    /// types whose `partial_cmp` method does anything but return
+    /// `Some(self.cmp(other))`.
+    ///
+    /// **Why is this bad?** The two traits have to agree on the order. With
+    /// `partial_cmp` delegating to `cmp`, there is only one comparison to
+    /// keep right.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::cmp::Ordering;
+    /// #[derive(PartialEq, Eq)]
+    /// struct Version(u32);
+    ///
+    /// impl Ord for Version {
+    ///     fn cmp(&self, other: &Self) -> Ordering {
+    ///         self.0.cmp(&other.0)
+    ///     }
+    /// }
+    ///
+    /// impl PartialOrd for Version {
+    ///     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    ///         self.0.partial_cmp(&other.0)
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::cmp::Ordering;
+    /// # #[derive(PartialEq, Eq)]
+    /// # struct Version(u32);
+    /// # impl Ord for Version {
+    /// #     fn cmp(&self, other: &Self) -> Ordering {
+    /// #         self.0.cmp(&other.0)
+    /// #     }
+    /// # }
+    /// impl PartialOrd for Version {
+    ///     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    ///         Some(self.cmp(other))
+    ///     }
+    /// }
+    /// ```
+    pub NON_CANONICAL_PARTIAL_ORD_IMPL,
+    style,
+    "implementing `PartialOrd` on an `Ord` type with a body other than `Some(self.cmp(other))`"
+}
+
+pub struct NonCanonicalImpls;
+
+impl LintPass for NonCanonicalImpls {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(NON_CANONICAL_CLONE_IMPL, NON_CANONICAL_PARTIAL_ORD_IMPL)
+    }
+
+    fn name(&self) -> &'static str {
+        "NonCanonicalImpls"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NonCanonicalImpls {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if let ItemKind::Impl(_, _, _, _, Some(ref trait_ref), _, ref impl_items) = item.node {
+            if in_macro(item.span) || is_automatically_derived(&item.attrs) {
+                return;
+            }
+            let impl_def_id = cx.tcx.hir().local_def_id_from_hir_id(item.hir_id);
+            let ty = cx.tcx.type_of(impl_def_id);
+            let method = |name: &str| {
+                impl_items
+                    .iter()
+                    .map(|impl_item_ref| cx.tcx.hir().impl_item(impl_item_ref.id))
+                    .find(|impl_item| impl_item.ident.name == name)
+                    .and_then(|impl_item| match impl_item.node {
+                        ImplItemKind::Method(_, body_id) => Some(cx.tcx.hir().body(body_id)),
+                        _ => None,
+                    })
+            };
+
+            if match_path(&trait_ref.path, &paths::CLONE_TRAIT) {
+                if_chain! {
+                    if is_copy(cx, ty);
+                    if let Some(body) = method("clone");
+                    if !is_canonical_clone(cx.tcx.body_tables(body.id()), body);
+                    then {
+                        emit(cx, NON_CANONICAL_CLONE_IMPL, body, "clone", Some("*self".to_string()));
+                    }
+                }
+            } else if match_path(&trait_ref.path, &paths::PARTIAL_ORD) {
+                if_chain! {
+                    // `impl PartialOrd<Other> for Foo` can't delegate to `Ord`
+                    if let Some(impl_trait_ref) = cx.tcx.impl_trait_ref(impl_def_id);
+                    if impl_trait_ref.substs.type_at(1) == ty;
+                    if let Some(ord) = get_trait_def_id(cx, &paths::ORD);
+                    if implements_trait(cx, ty, ord, &[]);
+                    if let Some(body) = method("partial_cmp");
+                    if !is_canonical_partial_cmp(cx, cx.tcx.body_tables(body.id()), body);
+                    then {
+                        // a pattern instead of a name can't be referred to
+                        let canonical = match body.arguments[1].pat.node {
+                            PatKind::Binding(_, _, ident, None) => Some(format!("Some(self.cmp({}))", ident)),
+                            _ => None,
+                        };
+                        emit(cx, NON_CANONICAL_PARTIAL_ORD_IMPL, body, "partial_cmp", canonical);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns the only expression of a function body without statements.
+fn body_expr(expr: &Expr) -> Option<&Expr> {
+    match expr.node {
+        ExprKind::Block(ref block, _) if block.stmts.is_empty() => block.expr.as_ref().map(|e| &**e),
+        _ => None,
+    }
+}
+
+/// Checks whether `expr` is the variable bound by the argument `arg` of
+/// `body`.
+fn is_argument(tables: &TypeckTables<'_>, body: &Body, arg: usize, expr: &Expr) -> bool {
+    if_chain! {
+        if let ExprKind::Path(ref qpath) = expr.node;
+        if let Def::Local(id) = tables.qpath_def(qpath, expr.hir_id);
+        if let PatKind::Binding(_, binding_id, _, _, None) = body.arguments[arg].pat.node;
+        then {
+            id == binding_id
+        } else {
+            false
+        }
+    }
+}
+
+/// Checks whether the body of `clone` is `*self`.
+fn is_canonical_clone(tables: &TypeckTables<'_>, body: &Body) -> bool {
+    match body_expr(&body.value) {
+        Some(&Expr {
+            node: ExprKind::Unary(UnDeref, ref inner),
+            ..
+        }) => is_argument(tables, body, 0, inner),
+        _ => false,
+    }
+}
+
+/// Checks whether the body of `partial_cmp` is `Some(self.cmp(other))` or
+/// `Some(Ord::cmp(self, other))`.
+fn is_canonical_partial_cmp(cx: &LateContext<'_, '_>, tables: &TypeckTables<'_>, body: &Body) -> bool {
+    if_chain! {
+        if let Some(expr) = body_expr(&body.value);
+        if let ExprKind::Call(ref some, ref some_args) = expr.node;
+        if let ExprKind::Path(ref some_path) = some.node;
+        if match_qpath(some_path, &paths::OPTION_SOME) && some_args.len() == 1;
+        then {
+            let cmp = &some_args[0];
+            let (def_id, args) = match cmp.node {
+                ExprKind::MethodCall(_, _, ref args) => {
+                    (tables.type_dependent_defs().get(cmp.hir_id).map(Def::def_id), args)
+                },
+                ExprKind::Call(ref func, ref args) => match func.node {
+                    ExprKind::Path(ref qpath) => (opt_def_id(tables.qpath_def(qpath, func.hir_id)), args),
+                    _ => return false,
+                },
+                _ => return false,
+            };
+            def_id.map_or(false, |def_id| match_def_path(cx.tcx, def_id, &paths::ORD_CMP))
+                && args.len() == 2
+                && is_argument(tables, body, 0, &args[0])
+                && is_argument(tables, body, 1, &args[1])
+        } else {
+            false
+        }
+    }
+}
+
+/// Lints the body of `method`, suggesting the `canonical` expression if it
+/// can be written in terms of the arguments.
+fn emit(cx: &LateContext<'_, '_>, lint: &'static Lint, body: &Body, method: &str, canonical: Option<String>) {
+    let msg = format!("non-canonical implementation of `{}`", method);
+    span_lint_and_then(cx, lint, body.value.span, &msg, |db| match canonical {
+        Some(canonical) => {
+            db.span_suggestion(
+                body.value.span,
+                "change this to",
+                format!("{{ {} }}", canonical),
+                Applicability::MaybeIncorrect,
+            );
+        },
+        None => {
+            db.help("bind the other argument to a name and return `Some(self.cmp(other))`");
+        },
+    });
+}
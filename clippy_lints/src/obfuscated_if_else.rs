@@ -0,0 +1,184 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::{
+    in_macro, match_def_path, match_qpath, match_type, method_chain_args, msrvs, paths, snippet_with_applicability,
+    span_lint_and_sugg,
+};
+use if_chain::if_chain;
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use semver::Version;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usages of `.then(|| ..).unwrap_or(..)` and
+    /// `.then_some(..).unwrap_or(..)` on booleans.
+    ///
+    /// The direction of the lint can be reversed with the `prefer-bool-then`
+    /// configuration option. It then checks for `if cond { Some(a) } else { None }`
+    /// and suggests `cond.then(|| a)` instead, for code bases that prefer
+    /// combinators. As `bool::then` is only stable since Rust 1.50, this
+    /// direction needs the `msrv` option to be set to 1.50 or later, the lint
+    /// keeps the default direction otherwise.
+    ///
+    /// **Why is this bad?** An `if`/`else` expression is easier to read than
+    /// building an `Option` only to unwrap it right away.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// let x = true;
+    /// x.then(|| 0).unwrap_or(1);
+    /// ```
+    ///
+    /// Could be written as:
+    ///
+    /// ```rust
+    /// let x = true;
+    /// if x { 0 } else { 1 };
+    /// ```
+    pub OBFUSCATED_IF_ELSE,
+    style,
+    "use of `.then(..).unwrap_or(..)` on a `bool`, which can be written as `if .. { .. } else { .. }`"
+}
+
+pub struct ObfuscatedIfElse {
+    prefer_bool_then: bool,
+    msrv: Option<Version>,
+}
+
+impl ObfuscatedIfElse {
+    pub fn new(prefer_bool_then: bool, msrv: Option<Version>) -> Self {
+        Self { prefer_bool_then, msrv }
+    }
+}
+
+impl LintPass for ObfuscatedIfElse {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(OBFUSCATED_IF_ELSE)
+    }
+
+    fn name(&self) -> &'static str {
+        "ObfuscatedIfElse"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ObfuscatedIfElse {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+
+        if self.prefer_bool_then && msrvs::meets(self.msrv.as_ref(), msrvs::BOOL_THEN) {
+            check_if_else(cx, expr);
+        } else {
+            check_then_unwrap_or(cx, expr, "then", &paths::BOOL_THEN);
+            check_then_unwrap_or(cx, expr, "then_some", &paths::BOOL_THEN_SOME);
+        }
+    }
+}
+
+/// Checks for `cond.then(|| a).unwrap_or(b)` and `cond.then_some(a).unwrap_or(b)`,
+/// where `then_path` is the path of the `bool` method.
+fn check_then_unwrap_or(cx: &LateContext<'_, '_>, expr: &Expr, then_method: &str, then_path: &[&str]) {
+    if_chain! {
+        if let Some(args) = method_chain_args(expr, &[then_method, "unwrap_or"]);
+        let (then_args, unwrap_or_args) = (args[0], args[1]);
+        // not methods of the same names from other traits
+        if is_method(cx, &unwrap_or_args[0], |def_id| match_def_path(cx.tcx, def_id, then_path));
+        if is_method(cx, expr, |def_id| cx.tcx.trait_of_item(def_id).is_none());
+        if match_type(cx, cx.tables.expr_ty(&unwrap_or_args[0]), &paths::OPTION);
+        then {
+            let then_value = if then_method == "then" {
+                match then_args[1].node {
+                    ExprKind::Closure(_, ref decl, body_id, ..) if decl.inputs.is_empty() => {
+                        &cx.tcx.hir().body(body_id).value
+                    },
+                    _ => return,
+                }
+            } else {
+                &then_args[1]
+            };
+
+            let mut applicability = Applicability::MachineApplicable;
+            let sugg = format!(
+                "if {} {{ {} }} else {{ {} }}",
+                snippet_with_applicability(cx, then_args[0].span, "..", &mut applicability),
+                snippet_with_applicability(cx, then_value.span, "..", &mut applicability),
+                snippet_with_applicability(cx, unwrap_or_args[1].span, "..", &mut applicability),
+            );
+
+            span_lint_and_sugg(
+                cx,
+                OBFUSCATED_IF_ELSE,
+                expr.span,
+                "this method chain can be written more clearly with `if .. else ..`",
+                "try",
+                sugg,
+                applicability,
+            );
+        }
+    }
+}
+
+/// Checks for `if cond { Some(a) } else { None }`, the reverse direction.
+fn check_if_else(cx: &LateContext<'_, '_>, expr: &Expr) {
+    if_chain! {
+        if let ExprKind::If(ref cond, ref then, Some(ref els)) = expr.node;
+        if let Some(some_arg) = block_value(then).and_then(option_some_arg);
+        if let Some(none) = block_value(els);
+        if let ExprKind::Path(ref none_path) = none.node;
+        if match_qpath(none_path, &paths::OPTION_NONE);
+        then {
+            let mut applicability = Applicability::MachineApplicable;
+            let cond = Sugg::hir_with_applicability(cx, cond, "..", &mut applicability).maybe_par();
+            let sugg = format!(
+                "{}.then(|| {})",
+                cond,
+                snippet_with_applicability(cx, some_arg.span, "..", &mut applicability),
+            );
+
+            span_lint_and_sugg(
+                cx,
+                OBFUSCATED_IF_ELSE,
+                expr.span,
+                "this `if .. else ..` can be written with `bool::then`",
+                "try",
+                sugg,
+                applicability,
+            );
+        }
+    }
+}
+
+/// Whether the method called by the method call `expr` satisfies `pred`.
+fn is_method(cx: &LateContext<'_, '_>, expr: &Expr, pred: impl FnOnce(DefId) -> bool) -> bool {
+    cx.tables
+        .type_dependent_defs()
+        .get(expr.hir_id)
+        .map_or(false, |def| pred(def.def_id()))
+}
+
+/// Returns the only expression of a block without statements.
+fn block_value(expr: &Expr) -> Option<&Expr> {
+    match expr.node {
+        ExprKind::Block(ref block, _) if block.stmts.is_empty() => block.expr.as_ref().map(|e| &**e),
+        _ => None,
+    }
+}
+
+/// Returns `a` if `expr` is `Some(a)`.
+fn option_some_arg(expr: &Expr) -> Option<&Expr> {
+    if_chain! {
+        if let ExprKind::Call(ref func, ref args) = expr.node;
+        if let ExprKind::Path(ref func_path) = func.node;
+        if match_qpath(func_path, &paths::OPTION_SOME);
+        if args.len() == 1;
+        then {
+            return Some(&args[0]);
+        }
+    }
+    None
+}
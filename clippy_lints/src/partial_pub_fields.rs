@@ -0,0 +1,111 @@
+use crate::utils::{in_macro, span_help_and_lint};
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use syntax::ast::*;
+use syntax::attr;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for structs with some, but not all fields
+    /// declared `pub`.
+    ///
+    /// **Why is this bad?** Users cannot construct the struct or use
+    /// functional update syntax, but can access part of its internals. This
+    /// usually means the API boundary of the type is not settled yet.
+    ///
+    /// Fields marked `#[doc(hidden)]` are ignored if the
+    /// `partial-pub-fields-ignore-doc-hidden` option is set.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// pub struct Color {
+    ///     pub r: u8,
+    ///     pub g: u8,
+    ///     b: u8,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// pub struct Color {
+    ///     r: u8,
+    ///     g: u8,
+    ///     b: u8,
+    /// }
+    ///
+    /// impl Color {
+    ///     pub fn r(&self) -> u8 {
+    ///         self.r
+    ///     }
+    /// }
+    /// ```
+    pub PARTIAL_PUB_FIELDS,
+    restriction,
+    "struct with both `pub` and private fields"
+}
+
+pub struct PartialPubFields {
+    ignore_doc_hidden: bool,
+}
+
+impl PartialPubFields {
+    pub fn new(ignore_doc_hidden: bool) -> Self {
+        Self { ignore_doc_hidden }
+    }
+}
+
+impl LintPass for PartialPubFields {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(PARTIAL_PUB_FIELDS)
+    }
+
+    fn name(&self) -> &'static str {
+        "PartialPubFields"
+    }
+}
+
+impl EarlyLintPass for PartialPubFields {
+    fn check_item(&mut self, cx: &EarlyContext<'_>, item: &Item) {
+        let fields = match item.node {
+            ItemKind::Struct(VariantData::Struct(ref fields, _), _)
+            | ItemKind::Struct(VariantData::Tuple(ref fields, _), _) => fields,
+            _ => return,
+        };
+        if in_macro(item.span) {
+            return;
+        }
+        let mut fields = fields
+            .iter()
+            .filter(|field| !(self.ignore_doc_hidden && is_doc_hidden(&field.attrs)));
+        let first_is_pub = match fields.next() {
+            Some(field) => is_pub(field),
+            None => return,
+        };
+        if let Some(field) = fields.find(|field| is_pub(field) != first_is_pub) {
+            span_help_and_lint(
+                cx,
+                PARTIAL_PUB_FIELDS,
+                field.span,
+                "mixed usage of pub and non-pub fields",
+                "consider making all fields private and adding accessors, or making all fields public and \
+                 marking the struct `#[non_exhaustive]`",
+            );
+        }
+    }
+}
+
+fn is_pub(field: &StructField) -> bool {
+    match field.vis.node {
+        VisibilityKind::Public => true,
+        _ => false,
+    }
+}
+
+fn is_doc_hidden(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.check_name("doc")
+            && attr
+                .meta_item_list()
+                .map_or(false, |list| attr::list_contains_name(&list, "hidden"))
+    })
+}
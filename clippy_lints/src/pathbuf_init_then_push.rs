@@ -0,0 +1,170 @@
+use crate::utils::{
+    contains_name, in_macro, match_def_path, match_type, paths, resolve_node, snippet_with_applicability,
+    span_lint_and_sugg, walk_ptrs_ty,
+};
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty::{self, Ty};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::{Name, NodeId};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `PathBuf::new()` followed by calls to
+    /// `push` on the new path.
+    ///
+    /// **Why is this bad?** `PathBuf::from` and `join` build the path in one
+    /// expression, which reads better and doesn't need a mutable binding.
+    ///
+    /// **Known problems:** The suggestion keeps the binding mutable, as it
+    /// may be changed later on.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// let mut path = PathBuf::new();
+    /// path.push("target");
+    /// path.push("debug");
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// let path = PathBuf::from("target").join("debug");
+    /// ```
+    pub PATHBUF_INIT_THEN_PUSH,
+    restriction,
+    "`push` calls on a `PathBuf` right after it was created"
+}
+
+#[derive(Copy, Clone)]
+pub struct PathbufInitThenPush;
+
+impl LintPass for PathbufInitThenPush {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(PATHBUF_INIT_THEN_PUSH)
+    }
+
+    fn name(&self) -> &'static str {
+        "PathbufInitThenPush"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for PathbufInitThenPush {
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        for (index, stmt) in block.stmts.iter().enumerate() {
+            if_chain! {
+                // let mut path = PathBuf::new();
+                if let StmtKind::Local(ref local) = stmt.node;
+                if let PatKind::Binding(BindingAnnotation::Mutable, id, _, ident, None) = local.pat.node;
+                if let Some(ref init) = local.init;
+                if let Some(new_fn) = pathbuf_new(cx, init);
+                // path.push(a); path.push(b);
+                let pushed = pushed_values(cx, &block.stmts[index + 1..], id, ident.name);
+                if !pushed.is_empty();
+                if !in_macro(stmt.span) && pushed.iter().all(|arg| !in_macro(arg.span));
+                then {
+                    let mut applicability = Applicability::MachineApplicable;
+                    let binding = snippet_with_applicability(cx, stmt.span.until(init.span), "..", &mut applicability);
+                    // keep the path to `PathBuf` as written, it may be qualified or an alias
+                    let new_fn = snippet_with_applicability(cx, new_fn.span, "..", &mut applicability);
+                    let from_fn = if new_fn.ends_with("new") {
+                        format!("{}from", &new_fn[..new_fn.len() - "new".len()])
+                    } else {
+                        applicability = Applicability::MaybeIncorrect;
+                        "PathBuf::from".to_string()
+                    };
+                    let mut sugg = format!(
+                        "{}{}({})",
+                        binding,
+                        from_fn,
+                        snippet_with_applicability(cx, pushed[0].span, "..", &mut applicability)
+                    );
+                    for arg in &pushed[1..] {
+                        sugg.push_str(&format!(
+                            ".join({})",
+                            snippet_with_applicability(cx, arg.span, "..", &mut applicability)
+                        ));
+                    }
+                    sugg.push(';');
+                    let span = stmt.span.to(block.stmts[index + pushed.len()].span);
+                    span_lint_and_sugg(
+                        cx,
+                        PATHBUF_INIT_THEN_PUSH,
+                        span,
+                        "calls to `push` right after creating a `PathBuf`",
+                        "use `PathBuf::from` and `join` instead",
+                        sugg,
+                        applicability,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// The callee of `expr`, if it is a `PathBuf::new()` call.
+fn pathbuf_new<'e>(cx: &LateContext<'_, '_>, expr: &'e Expr) -> Option<&'e Expr> {
+    if_chain! {
+        if let ExprKind::Call(ref fun, ref args) = expr.node;
+        if args.is_empty();
+        if let ExprKind::Path(ref qpath) = fun.node;
+        if let Some(def_id) = resolve_node(cx, qpath, fun.hir_id).opt_def_id();
+        if match_def_path(cx.tcx, def_id, &paths::PATH_BUF_NEW);
+        then {
+            Some(fun)
+        } else {
+            None
+        }
+    }
+}
+
+/// The arguments of the `path.push(arg);` statements at the start of `stmts`.
+fn pushed_values<'tcx>(cx: &LateContext<'_, 'tcx>, stmts: &'tcx [Stmt], id: NodeId, name: Name) -> Vec<&'tcx Expr> {
+    let mut pushed = Vec::new();
+    for stmt in stmts {
+        match pushed_value(cx, stmt, id, name) {
+            Some(arg) => pushed.push(arg),
+            None => break,
+        }
+    }
+    pushed
+}
+
+/// The argument of `path.push(arg);`, if `arg` can be passed to
+/// `PathBuf::from` and doesn't use the path itself.
+fn pushed_value<'tcx>(cx: &LateContext<'_, 'tcx>, stmt: &'tcx Stmt, id: NodeId, name: Name) -> Option<&'tcx Expr> {
+    if_chain! {
+        if let StmtKind::Semi(ref expr) = stmt.node;
+        if let ExprKind::MethodCall(ref path, _, ref args) = expr.node;
+        if path.ident.name == "push" && args.len() == 2;
+        if let ExprKind::Path(ref qpath) = args[0].node;
+        if let Def::Local(local) = resolve_node(cx, qpath, args[0].hir_id);
+        if local == id;
+        if match_type(cx, walk_ptrs_ty(cx.tables.expr_ty_adjusted(&args[0])), &paths::PATH_BUF);
+        if !contains_name(name, &args[1]);
+        if is_path_like(cx, cx.tables.expr_ty(&args[1]));
+        then {
+            Some(&args[1])
+        } else {
+            None
+        }
+    }
+}
+
+/// Whether `PathBuf::from` takes a value of type `ty`: string and path types,
+/// by value or by reference.
+fn is_path_like(cx: &LateContext<'_, '_>, ty: Ty<'_>) -> bool {
+    let ty = walk_ptrs_ty(ty);
+    ty.sty == ty::Str
+        || [
+            &paths::STRING[..],
+            &paths::PATH,
+            &paths::PATH_BUF,
+            &paths::OS_STRING,
+            &paths::OS_STR,
+        ]
+        .iter()
+        .any(|path| match_type(cx, ty, path))
+}
@@ -1,4 +1,4 @@
-use crate::utils::{in_macro, snippet_with_applicability, span_lint_and_sugg};
+use crate::utils::{in_macro, snippet_with_applicability, span_lint_and_sugg_lazy};
 use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
 use rustc::{declare_tool_lint, lint_array};
 use rustc_errors::Applicability;
@@ -48,51 +48,32 @@ impl EarlyLintPass for Precedence {
         }
 
         if let ExprKind::Binary(Spanned { node: op, .. }, ref left, ref right) = expr.node {
-            let span_sugg = |expr: &Expr, sugg, appl| {
-                span_lint_and_sugg(
+            if !is_bit_op(op) {
+                return;
+            }
+            let (paren_left, paren_right) = (is_arith_expr(left), is_arith_expr(right));
+            if paren_left || paren_right {
+                span_lint_and_sugg_lazy(
                     cx,
                     PRECEDENCE,
                     expr.span,
                     "operator precedence can trip the unwary",
                     "consider parenthesizing your expression",
-                    sugg,
-                    appl,
+                    Applicability::MachineApplicable,
+                    |applicability| {
+                        let mut operand = |expr: &Expr, paren| {
+                            let snip = snippet_with_applicability(cx, expr.span, "..", applicability);
+                            if paren {
+                                format!("({})", snip)
+                            } else {
+                                snip.into_owned()
+                            }
+                        };
+                        let left = operand(left, paren_left);
+                        let right = operand(right, paren_right);
+                        format!("{} {} {}", left, op.to_string(), right)
+                    },
                 );
-            };
-
-            if !is_bit_op(op) {
-                return;
-            }
-            let mut applicability = Applicability::MachineApplicable;
-            match (is_arith_expr(left), is_arith_expr(right)) {
-                (true, true) => {
-                    let sugg = format!(
-                        "({}) {} ({})",
-                        snippet_with_applicability(cx, left.span, "..", &mut applicability),
-                        op.to_string(),
-                        snippet_with_applicability(cx, right.span, "..", &mut applicability)
-                    );
-                    span_sugg(expr, sugg, applicability);
-                },
-                (true, false) => {
-                    let sugg = format!(
-                        "({}) {} {}",
-                        snippet_with_applicability(cx, left.span, "..", &mut applicability),
-                        op.to_string(),
-                        snippet_with_applicability(cx, right.span, "..", &mut applicability)
-                    );
-                    span_sugg(expr, sugg, applicability);
-                },
-                (false, true) => {
-                    let sugg = format!(
-                        "{} {} ({})",
-                        snippet_with_applicability(cx, left.span, "..", &mut applicability),
-                        op.to_string(),
-                        snippet_with_applicability(cx, right.span, "..", &mut applicability)
-                    );
-                    span_sugg(expr, sugg, applicability);
-                },
-                (false, false) => (),
             }
         }
 
@@ -102,18 +83,16 @@ impl EarlyLintPass for Precedence {
                     if let ExprKind::Lit(ref lit) = slf.node {
                         match lit.node {
                             LitKind::Int(..) | LitKind::Float(..) | LitKind::FloatUnsuffixed(..) => {
-                                let mut applicability = Applicability::MachineApplicable;
-                                span_lint_and_sugg(
+                                span_lint_and_sugg_lazy(
                                     cx,
                                     PRECEDENCE,
                                     expr.span,
                                     "unary minus has lower precedence than method call",
                                     "consider adding parentheses to clarify your intent",
-                                    format!(
-                                        "-({})",
-                                        snippet_with_applicability(cx, rhs.span, "..", &mut applicability)
-                                    ),
-                                    applicability,
+                                    Applicability::MachineApplicable,
+                                    |applicability| {
+                                        format!("-({})", snippet_with_applicability(cx, rhs.span, "..", applicability))
+                                    },
                                 );
                             },
                             _ => (),
@@ -0,0 +1,61 @@
+use crate::utils::{in_macro, span_help_and_lint};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for expressions that use the question mark operator.
+    ///
+    /// **Why is this bad?** Some codebases require errors to be handled with
+    /// explicit `match` or `if let` expressions, so that every early return
+    /// and error conversion is visible.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```ignore
+    /// let result = expr?;
+    /// ```
+    ///
+    /// Could be written:
+    ///
+    /// ```ignore
+    /// let result = match expr {
+    ///     Ok(value) => value,
+    ///     Err(err) => return Err(err.into()),
+    /// };
+    /// ```
+    pub QUESTION_MARK_USED,
+    restriction,
+    "complains if the question mark operator is used"
+}
+
+#[derive(Copy, Clone)]
+pub struct QuestionMarkUsed;
+
+impl LintPass for QuestionMarkUsed {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(QUESTION_MARK_USED)
+    }
+
+    fn name(&self) -> &'static str {
+        "QuestionMarkUsed"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for QuestionMarkUsed {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if let ExprKind::Match(_, _, MatchSource::TryDesugar) = expr.node {
+            if in_macro(expr.span) {
+                return;
+            }
+            span_help_and_lint(
+                cx,
+                QUESTION_MARK_USED,
+                expr.span,
+                "question mark operator was used",
+                "consider using a `match` or `if let` expression instead",
+            );
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use crate::utils::{match_qpath, match_trait_method, paths, snippet, span_lint_and_sugg_lazy, span_lint_and_then};
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::NodeId;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Read::read` and `Read::read_exact` calls on
+    /// a freshly created `Vec`, before anything changed its length.
+    ///
+    /// **Why is this bad?** Reading into a `Vec` only fills its current length,
+    /// not its capacity. An empty vector reads zero bytes, even if it was created
+    /// with `Vec::with_capacity`.
+    ///
+    /// **Known problems:** Only uses in the same block as the declaration are
+    /// checked. Any use of the vector other than querying or reserving its
+    /// capacity is assumed to change its length.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// let mut data = Vec::with_capacity(len);
+    /// r.read_exact(&mut data)?;
+    /// ```
+    ///
+    /// Could be written as:
+    ///
+    /// ```rust,ignore
+    /// let mut data = Vec::with_capacity(len);
+    /// data.resize(len, 0);
+    /// r.read_exact(&mut data)?;
+    /// ```
+    pub READ_ZERO_BYTE_VEC,
+    correctness,
+    "reading into an empty `Vec`, which reads zero bytes"
+}
+
+/// Methods that neither read from the vector nor change its length.
+const CAPACITY_METHODS: [&str; 6] = [
+    "capacity",
+    "is_empty",
+    "len",
+    "reserve",
+    "reserve_exact",
+    "shrink_to_fit",
+];
+
+#[derive(Copy, Clone)]
+pub struct ReadZeroByteVec;
+
+impl LintPass for ReadZeroByteVec {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(READ_ZERO_BYTE_VEC)
+    }
+
+    fn name(&self) -> &'static str {
+        "ReadZeroByteVec"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ReadZeroByteVec {
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        for (idx, stmt) in block.stmts.iter().enumerate() {
+            if_chain! {
+                if let StmtKind::Local(ref local) = stmt.node;
+                if let PatKind::Binding(BindingAnnotation::Mutable, canonical_id, _, ident, None) = local.pat.node;
+                if let Some(ref init) = local.init;
+                if let Some(len) = empty_vec_init(init);
+                then {
+                    for next_stmt in &block.stmts[idx + 1..] {
+                        let mut visitor = VecUseVisitor {
+                            cx,
+                            id: canonical_id,
+                            found: None,
+                        };
+                        visitor.visit_stmt(next_stmt);
+                        match visitor.found {
+                            Some(VecUse::Read(read_expr, method)) => {
+                                emit(cx, &ident.as_str(), len, next_stmt, read_expr, method);
+                                break;
+                            },
+                            Some(VecUse::Other) => break,
+                            None => {},
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Checks whether `expr` is `Vec::new()` or `Vec::with_capacity(len)`. Returns
+/// the capacity in the latter case.
+fn empty_vec_init(expr: &Expr) -> Option<Option<&Expr>> {
+    if_chain! {
+        if let ExprKind::Call(ref func, ref args) = expr.node;
+        if let ExprKind::Path(ref path) = func.node;
+        then {
+            if match_qpath(path, &["Vec", "new"]) && args.is_empty() {
+                return Some(None);
+            }
+            if match_qpath(path, &["Vec", "with_capacity"]) && args.len() == 1 {
+                return Some(Some(&args[0]));
+            }
+        }
+    }
+    None
+}
+
+fn emit(cx: &LateContext<'_, '_>, name: &str, len: Option<&Expr>, stmt: &Stmt, read_expr: &Expr, method: &str) {
+    let msg = "reading zero byte data to `Vec`";
+    if let Some(len) = len {
+        span_lint_and_sugg_lazy(
+            cx,
+            READ_ZERO_BYTE_VEC,
+            stmt.span,
+            msg,
+            "try",
+            Applicability::MaybeIncorrect,
+            |_| {
+                format!(
+                    "{}.resize({}, 0); {}",
+                    name,
+                    snippet(cx, len.span, ".."),
+                    snippet(cx, stmt.span, "..")
+                )
+            },
+        );
+    } else if let (ExprKind::MethodCall(_, _, ref args), "read") = (&read_expr.node, method) {
+        span_lint_and_sugg_lazy(
+            cx,
+            READ_ZERO_BYTE_VEC,
+            read_expr.span,
+            msg,
+            "to read everything, try",
+            Applicability::MaybeIncorrect,
+            |_| format!("{}.read_to_end(&mut {})", snippet(cx, args[0].span, ".."), name),
+        );
+    } else {
+        span_lint_and_then(cx, READ_ZERO_BYTE_VEC, read_expr.span, msg, |db| {
+            db.help(&format!(
+                "resize `{}` to the number of bytes to read first, e.g. with `vec![0; len]`",
+                name
+            ));
+        });
+    }
+}
+
+enum VecUse<'tcx> {
+    /// The vector is read into with the given method.
+    Read(&'tcx Expr, &'static str),
+    /// Any other use, which might change the length.
+    Other,
+}
+
+/// Finds the first use of the vector in a statement.
+struct VecUseVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    id: NodeId,
+    found: Option<VecUse<'tcx>>,
+}
+
+impl<'a, 'tcx> VecUseVisitor<'a, 'tcx> {
+    fn is_vec(&self, expr: &Expr) -> bool {
+        if let ExprKind::Path(ref qpath) = expr.node {
+            if let Def::Local(id) = self.cx.tables.qpath_def(qpath, expr.hir_id) {
+                return id == self.id;
+            }
+        }
+        false
+    }
+
+    fn is_vec_mut_ref(&self, expr: &Expr) -> bool {
+        if let ExprKind::AddrOf(MutMutable, ref inner) = expr.node {
+            self.is_vec(inner)
+        } else {
+            false
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for VecUseVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if self.found.is_some() {
+            return;
+        }
+        if let ExprKind::MethodCall(ref path, _, ref args) = expr.node {
+            if self.is_vec(&args[0]) && CAPACITY_METHODS.iter().any(|&name| path.ident.name == name) {
+                for arg in &args[1..] {
+                    self.visit_expr(arg);
+                }
+                return;
+            }
+            if_chain! {
+                if args.len() == 2 && self.is_vec_mut_ref(&args[1]);
+                if let Some(&method) = ["read", "read_exact"].iter().find(|&&name| path.ident.name == name);
+                if match_trait_method(self.cx, expr, &paths::IO_READ);
+                then {
+                    self.visit_expr(&args[0]);
+                    if self.found.is_none() {
+                        self.found = Some(VecUse::Read(expr, method));
+                    }
+                    return;
+                }
+            }
+        }
+        if self.is_vec(expr) {
+            self.found = Some(VecUse::Other);
+            return;
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir())
+    }
+}
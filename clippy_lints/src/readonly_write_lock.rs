@@ -0,0 +1,108 @@
+use crate::utils::usage::is_only_read_through;
+use crate::utils::{
+    contains_name, in_macro, match_type, paths, snippet_with_applicability, span_lint_and_sugg, walk_ptrs_ty,
+};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ptr::P;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for guards of `RwLock::write` that are only
+    /// read through.
+    ///
+    /// **Why is this bad?** A write lock excludes all other readers and
+    /// writers, while any number of readers can hold a read lock at once.
+    ///
+    /// **Known problems:** Only guards bound with `let` and unwrapped right
+    /// away are checked, and only if all their uses follow in the same block.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let lock = std::sync::RwLock::new(1);
+    /// let value = lock.write().unwrap();
+    /// println!("{}", *value);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let lock = std::sync::RwLock::new(1);
+    /// let value = lock.read().unwrap();
+    /// println!("{}", *value);
+    /// ```
+    pub READONLY_WRITE_LOCK,
+    perf,
+    "acquiring a write lock when a read lock would do"
+}
+
+pub struct ReadonlyWriteLock;
+
+impl LintPass for ReadonlyWriteLock {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(READONLY_WRITE_LOCK)
+    }
+
+    fn name(&self) -> &'static str {
+        "ReadonlyWriteLock"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ReadonlyWriteLock {
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        for (index, stmt) in block.stmts.iter().enumerate() {
+            if_chain! {
+                if let StmtKind::Local(ref local) = stmt.node;
+                if let PatKind::Binding(BindingAnnotation::Unannotated, _, _, ident, None) = local.pat.node;
+                if let Some(ref init) = local.init;
+                if let Some((write, lock)) = write_guard(cx, init);
+                if !in_macro(local.span);
+                // a guard that is never used may be held to exclude other threads
+                if uses_after(&block.stmts[index + 1..], &block.expr).any(|expr| contains_name(ident.name, expr));
+                if uses_after(&block.stmts[index + 1..], &block.expr)
+                    .all(|expr| is_only_read_through(cx, local.pat.hir_id, expr));
+                then {
+                    let mut applicability = Applicability::MachineApplicable;
+                    span_lint_and_sugg(
+                        cx,
+                        READONLY_WRITE_LOCK,
+                        write.span,
+                        "this write lock is used only for reading",
+                        "consider using a read lock instead",
+                        format!("{}.read()", snippet_with_applicability(cx, lock.span, "..", &mut applicability)),
+                        applicability,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Matches `lock.write().unwrap()` and `lock.write().expect(..)` on an
+/// `RwLock`, returning the `write` call and the lock.
+fn write_guard<'a>(cx: &LateContext<'_, '_>, expr: &'a Expr) -> Option<(&'a Expr, &'a Expr)> {
+    if_chain! {
+        if let ExprKind::MethodCall(ref unwrap, _, ref unwrap_args) = expr.node;
+        if unwrap.ident.name == "unwrap" || unwrap.ident.name == "expect";
+        let write = &unwrap_args[0];
+        if let ExprKind::MethodCall(ref path, _, ref args) = write.node;
+        if path.ident.name == "write" && args.len() == 1;
+        if match_type(cx, walk_ptrs_ty(cx.tables.expr_ty_adjusted(&args[0])), &paths::RWLOCK);
+        then {
+            return Some((write, &args[0]));
+        }
+    }
+    None
+}
+
+/// The expressions of the statements and the trailing expression of a block.
+fn uses_after<'a>(stmts: &'a [Stmt], expr: &'a Option<P<Expr>>) -> impl Iterator<Item = &'a Expr> {
+    stmts
+        .iter()
+        .filter_map(|stmt| match stmt.node {
+            StmtKind::Local(ref local) => local.init.as_ref().map(|init| &**init),
+            StmtKind::Expr(ref expr) | StmtKind::Semi(ref expr) => Some(&**expr),
+            StmtKind::Item(_) => None,
+        })
+        .chain(expr.as_ref().map(|expr| &**expr))
+}
@@ -0,0 +1,138 @@
+use crate::utils::{in_macro, span_lint_and_sugg};
+use matches::matches;
+use rustc::hir::def::{CtorKind, Def};
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty::{self, TypeFoldable};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::{LitIntType, LitKind};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for type annotations on `let` bindings whose
+    /// initializer already makes the type evident: a call of a function or
+    /// method with a concrete return type (e.g. `Foo::new()`), a constructor
+    /// of a non-generic struct or enum, or a literal with a definite type,
+    /// like `"str"`, `true` or `1u8`.
+    ///
+    /// **Why is this bad?** The annotation repeats what the right-hand side
+    /// already says.
+    ///
+    /// **Known problems:** Annotations that change the type, e.g. by
+    /// coercing, are not linted. Numeric literals with a suffix can be
+    /// ignored with the `redundant-type-annotations-ignore-numeric-literals`
+    /// option.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let s: String = String::new();
+    /// let b: bool = true;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let s = String::new();
+    /// let b = true;
+    /// ```
+    pub REDUNDANT_TYPE_ANNOTATIONS,
+    restriction,
+    "type annotations on `let` bindings whose type is evident from the initializer"
+}
+
+pub struct RedundantTypeAnnotations {
+    ignore_numeric_literals: bool,
+}
+
+impl RedundantTypeAnnotations {
+    pub fn new(ignore_numeric_literals: bool) -> Self {
+        Self {
+            ignore_numeric_literals,
+        }
+    }
+
+    /// Whether the type of `expr` is evident without looking at its context.
+    fn is_evident(&self, cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+        match expr.node {
+            ExprKind::Lit(ref lit) => match lit.node {
+                LitKind::Str(..) | LitKind::Char(_) | LitKind::Bool(_) => true,
+                LitKind::Int(_, LitIntType::Signed(_))
+                | LitKind::Int(_, LitIntType::Unsigned(_))
+                | LitKind::Float(..) => !self.ignore_numeric_literals,
+                _ => false,
+            },
+            ExprKind::Call(ref callee, _) => match callee.node {
+                ExprKind::Path(ref qpath) => match cx.tables.qpath_def(qpath, callee.hir_id) {
+                    Def::Fn(def_id)
+                    | Def::Method(def_id)
+                    | Def::StructCtor(def_id, _)
+                    | Def::VariantCtor(def_id, _) => has_concrete_return_type(cx, def_id),
+                    _ => false,
+                },
+                _ => false,
+            },
+            ExprKind::MethodCall(..) => match cx.tables.type_dependent_defs().get(expr.hir_id) {
+                Some(&Def::Method(def_id)) => has_concrete_return_type(cx, def_id),
+                _ => false,
+            },
+            ExprKind::Struct(..) => is_non_generic_adt(cx, expr),
+            ExprKind::Path(ref qpath) => match cx.tables.qpath_def(qpath, expr.hir_id) {
+                Def::StructCtor(_, CtorKind::Const) | Def::VariantCtor(_, CtorKind::Const) => {
+                    is_non_generic_adt(cx, expr)
+                },
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+impl LintPass for RedundantTypeAnnotations {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(REDUNDANT_TYPE_ANNOTATIONS)
+    }
+
+    fn name(&self) -> &'static str {
+        "RedundantTypeAnnotations"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for RedundantTypeAnnotations {
+    fn check_local(&mut self, cx: &LateContext<'a, 'tcx>, local: &'tcx Local) {
+        let (ty, init) = match (&local.ty, &local.init) {
+            (Some(ty), Some(init)) => (ty, init),
+            _ => return,
+        };
+        if in_macro(local.span) || matches!(ty.node, TyKind::Infer) || !self.is_evident(cx, init) {
+            return;
+        }
+        // the annotation is only redundant if the initializer has the annotated type on its own,
+        // without a coercion
+        let binding_ty = cx.tcx.erase_regions(&cx.tables.pat_ty(&local.pat));
+        let init_ty = cx.tcx.erase_regions(&cx.tables.expr_ty(init));
+        if binding_ty == init_ty && cx.tables.expr_adjustments(init).is_empty() {
+            span_lint_and_sugg(
+                cx,
+                REDUNDANT_TYPE_ANNOTATIONS,
+                ty.span.with_lo(local.pat.span.hi()),
+                "redundant type annotation",
+                "remove it",
+                String::new(),
+                Applicability::MachineApplicable,
+            );
+        }
+    }
+}
+
+/// Whether the declared return type of a function doesn't depend on its type
+/// parameters, `Self` or associated types.
+fn has_concrete_return_type(cx: &LateContext<'_, '_>, def_id: DefId) -> bool {
+    let output = *cx.tcx.fn_sig(def_id).output().skip_binder();
+    !output.has_param_types() && !output.has_self_ty() && !output.has_projections()
+}
+
+fn is_non_generic_adt(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    match cx.tables.expr_ty(expr).sty {
+        ty::Adt(_, substs) => substs.is_empty(),
+        _ => false,
+    }
+}
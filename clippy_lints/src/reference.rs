@@ -1,4 +1,4 @@
-use crate::utils::{snippet_with_applicability, span_lint_and_sugg};
+use crate::utils::{snippet_with_applicability, span_lint_and_sugg_lazy};
 use if_chain::if_chain;
 use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
 use rustc::{declare_tool_lint, lint_array};
@@ -49,15 +49,14 @@ impl EarlyLintPass for Pass {
             if let ExprKind::Unary(UnOp::Deref, ref deref_target) = e.node;
             if let ExprKind::AddrOf(_, ref addrof_target) = without_parens(deref_target).node;
             then {
-                let mut applicability = Applicability::MachineApplicable;
-                span_lint_and_sugg(
+                span_lint_and_sugg_lazy(
                     cx,
                     DEREF_ADDROF,
                     e.span,
                     "immediately dereferencing a reference",
                     "try this",
-                    format!("{}", snippet_with_applicability(cx, addrof_target.span, "_", &mut applicability)),
-                    applicability,
+                    Applicability::MachineApplicable,
+                    |applicability| snippet_with_applicability(cx, addrof_target.span, "_", applicability).to_string(),
                 );
             }
         }
@@ -101,15 +100,14 @@ impl EarlyLintPass for DerefPass {
             if let ExprKind::Paren(ref parened) = object.node;
             if let ExprKind::AddrOf(_, ref inner) = parened.node;
             then {
-                let mut applicability = Applicability::MachineApplicable;
-                span_lint_and_sugg(
+                span_lint_and_sugg_lazy(
                     cx,
                     REF_IN_DEREF,
                     object.span,
                     "Creating a reference that is immediately dereferenced.",
                     "try this",
-                    snippet_with_applicability(cx, inner.span, "_", &mut applicability).to_string(),
-                    applicability,
+                    Applicability::MachineApplicable,
+                    |applicability| snippet_with_applicability(cx, inner.span, "_", applicability).to_string(),
                 );
             }
         }
@@ -1,10 +1,11 @@
-use crate::utils::{match_def_path, span_lint_and_sugg};
+use crate::utils::{match_def_path, msrvs, span_lint_and_sugg};
 use if_chain::if_chain;
 use rustc::hir;
 use rustc::hir::def::Def;
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
 use rustc::{declare_tool_lint, lint_array};
 use rustc_errors::Applicability;
+use semver::Version;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for usage of `ATOMIC_X_INIT`, `ONCE_INIT`, and
@@ -12,7 +13,9 @@ declare_clippy_lint! {
     ///
     /// **Why is this bad?** `const fn`s exist
     ///
-    /// **Known problems:** None.
+    /// **Known problems:** If the `msrv` option allows Rust 1.43, the integer
+    /// constants are left to `legacy_numeric_constants`, which suggests the
+    /// associated constants instead.
     ///
     /// **Example:**
     /// ```rust
@@ -29,7 +32,15 @@ declare_clippy_lint! {
     "Lint usages of standard library `const`s that could be replaced by `const fn`s"
 }
 
-pub struct ReplaceConsts;
+pub struct ReplaceConsts {
+    msrv: Option<Version>,
+}
+
+impl ReplaceConsts {
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
 
 impl LintPass for ReplaceConsts {
     fn get_lints(&self) -> LintArray {
@@ -47,7 +58,10 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ReplaceConsts {
             if let hir::ExprKind::Path(ref qp) = expr.node;
             if let Def::Const(def_id) = cx.tables.qpath_def(qp, expr.hir_id);
             then {
-                for &(const_path, repl_snip) in REPLACEMENTS {
+                // otherwise `legacy_numeric_constants` suggests the associated constants
+                let integers = !msrvs::meets(self.msrv.as_ref(), msrvs::NUMERIC_ASSOCIATED_CONSTANTS);
+                let replacements = REPLACEMENTS.iter().chain(INTEGER_REPLACEMENTS.iter().filter(|_| integers));
+                for &(const_path, repl_snip) in replacements {
                     if match_def_path(cx.tcx, def_id, const_path) {
                         span_lint_and_sugg(
                             cx,
@@ -69,6 +83,10 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ReplaceConsts {
 const REPLACEMENTS: &[(&[&str], &str)] = &[
     // Once
     (&["core", "sync", "ONCE_INIT"], "Once::new()"),
+];
+
+/// Superseded by the associated constants since Rust 1.43.
+const INTEGER_REPLACEMENTS: &[(&[&str], &str)] = &[
     // Min
     (&["core", "isize", "MIN"], "isize::min_value()"),
     (&["core", "i8", "MIN"], "i8::min_value()"),
@@ -0,0 +1,171 @@
+use crate::utils::{in_macro, match_type, paths, span_lint_and_then};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty::layout::LayoutOf;
+use rustc::ty::{self, Ty};
+use rustc::{declare_tool_lint, lint_array};
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for functions that return `Result` with an
+    /// unusually large `Err` variant.
+    ///
+    /// **Why is this bad?** A `Result` is at least as large as its `Err` variant.
+    /// A large error type makes every call site pay for moving it around, even
+    /// on the happy path.
+    ///
+    /// **Known problems:** The size of generic error types cannot be computed,
+    /// so those are never linted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// pub enum ParseError {
+    ///     UnexpectedEof,
+    ///     Other([u8; 512]),
+    /// }
+    ///
+    /// pub fn parse() -> Result<(), ParseError> {
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// Could be written as:
+    ///
+    /// ```rust
+    /// pub enum ParseError {
+    ///     UnexpectedEof,
+    ///     Other(Box<[u8; 512]>),
+    /// }
+    ///
+    /// pub fn parse() -> Result<(), ParseError> {
+    ///     Ok(())
+    /// }
+    /// ```
+    pub RESULT_LARGE_ERR,
+    perf,
+    "function returning `Result` with large `Err` type"
+}
+
+#[derive(Copy, Clone)]
+pub struct ResultLargeErr {
+    large_error_threshold: u64,
+}
+
+impl ResultLargeErr {
+    pub fn new(large_error_threshold: u64) -> Self {
+        Self { large_error_threshold }
+    }
+}
+
+impl LintPass for ResultLargeErr {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(RESULT_LARGE_ERR)
+    }
+
+    fn name(&self) -> &'static str {
+        "ResultLargeErr"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ResultLargeErr {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if let ItemKind::Fn(ref decl, ..) = item.node {
+            self.check_fn_sig(cx, decl, item.hir_id);
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx ImplItem) {
+        if let ImplItemKind::Method(ref sig, _) = item.node {
+            // don't lint trait impls, the signature is dictated by the trait
+            let parent_id = cx.tcx.hir().get_parent_item(item.hir_id);
+            if let Some(Node::Item(parent)) = cx.tcx.hir().find_by_hir_id(parent_id) {
+                if let ItemKind::Impl(_, _, _, _, Some(_), _, _) = parent.node {
+                    return;
+                }
+            }
+            self.check_fn_sig(cx, &sig.decl, item.hir_id);
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx TraitItem) {
+        if let TraitItemKind::Method(ref sig, _) = item.node {
+            self.check_fn_sig(cx, &sig.decl, item.hir_id);
+        }
+    }
+}
+
+impl ResultLargeErr {
+    fn check_fn_sig<'a, 'tcx>(self, cx: &LateContext<'a, 'tcx>, decl: &FnDecl, hir_id: HirId) {
+        let span = decl.output.span();
+        if in_macro(span) {
+            return;
+        }
+
+        let def_id = cx.tcx.hir().local_def_id_from_hir_id(hir_id);
+        let fn_sig = cx.tcx.fn_sig(def_id);
+        let ret_ty = cx.tcx.erase_late_bound_regions(&fn_sig.output());
+        if !match_type(cx, ret_ty, &paths::RESULT) {
+            return;
+        }
+        let err_ty = match ret_ty.sty {
+            ty::Adt(_, substs) => substs.type_at(1),
+            _ => return,
+        };
+
+        if let Ok(layout) = cx.layout_of(err_ty) {
+            let size = layout.size.bytes();
+            if size > self.large_error_threshold {
+                emit(cx, span, err_ty, size);
+            }
+        }
+    }
+}
+
+fn emit<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, span: Span, err_ty: Ty<'tcx>, size: u64) {
+    span_lint_and_then(
+        cx,
+        RESULT_LARGE_ERR,
+        span,
+        "the `Err`-variant returned from this function is very large",
+        |db| {
+            db.span_label(span, format!("the `Err`-variant is at least {} bytes", size));
+            if let Some(breakdown) = variant_sizes(cx, err_ty) {
+                db.note(&breakdown);
+            }
+            db.help(&format!(
+                "try reducing the size of `{}`, for example by boxing large elements or replacing it with `Box<{}>`",
+                err_ty, err_ty
+            ));
+        },
+    );
+}
+
+/// For an enum error type, lists the variants from the largest to the smallest
+/// together with the size of their fields.
+fn variant_sizes<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: Ty<'tcx>) -> Option<String> {
+    let (adt, substs) = match ty.sty {
+        ty::Adt(adt, substs) if adt.is_enum() && adt.variants.len() > 1 => (adt, substs),
+        _ => return None,
+    };
+
+    let mut sizes = adt
+        .variants
+        .iter()
+        .map(|variant| {
+            let size: u64 = variant
+                .fields
+                .iter()
+                .filter_map(|f| cx.layout_of(f.ty(cx.tcx, substs)).ok().map(|l| l.size.bytes()))
+                .sum();
+            (variant.ident, size)
+        })
+        .collect::<Vec<_>>();
+    sizes.sort_by(|a, b| b.1.cmp(&a.1));
+
+    let list = sizes
+        .iter()
+        .map(|(ident, size)| format!("`{}` ({} bytes)", ident, size))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Some(format!("variant sizes of `{}`: {}", ty, list))
+}
@@ -0,0 +1,89 @@
+use crate::utils::ast_pattern::{Pattern, Value};
+use crate::utils::{in_macro, span_lint_and_then};
+use lazy_static::lazy_static;
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for blocks used as statements whose last
+    /// statement ends with a semicolon, e.g. `unsafe { f(x); }`.
+    ///
+    /// **Why is this bad?** For consistency, the semicolon can be put after
+    /// the block, like after other expressions used as statements.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # fn f(_: u32) {}
+    /// # let x = 0;
+    /// unsafe { f(x); }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # fn f(_: u32) {}
+    /// # let x = 0;
+    /// unsafe { f(x) };
+    /// ```
+    pub SEMICOLON_OUTSIDE_BLOCK,
+    restriction,
+    "add a semicolon outside the block"
+}
+
+lazy_static! {
+    static ref BLOCK_WITH_SEMI: Pattern =
+        Pattern::parse("Expr(Block_(Block(_*, Semi(_#last_expr)#last))#block)").expect("invalid pattern");
+}
+
+#[derive(Copy, Clone)]
+pub struct SemicolonOutsideBlock;
+
+impl LintPass for SemicolonOutsideBlock {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SEMICOLON_OUTSIDE_BLOCK)
+    }
+
+    fn name(&self) -> &'static str {
+        "SemicolonOutsideBlock"
+    }
+}
+
+impl EarlyLintPass for SemicolonOutsideBlock {
+    fn check_stmt(&mut self, cx: &EarlyContext<'_>, stmt: &ast::Stmt) {
+        if in_macro(stmt.span) {
+            return;
+        }
+        let captures = match BLOCK_WITH_SEMI.captures(Value::Stmt(stmt)) {
+            Some(captures) => captures,
+            None => return,
+        };
+        let (block, last, last_expr) = match (
+            captures.expr("block"),
+            captures.stmt("last"),
+            captures.expr("last_expr"),
+        ) {
+            (Some(block), Some(last), Some(last_expr)) => (block, last, last_expr),
+            _ => return,
+        };
+        // the `;` is not written out for statements from macros
+        if in_macro(last.span) || in_macro(last_expr.span) {
+            return;
+        }
+        let semi_span = last.span.with_lo(last_expr.span.hi());
+        span_lint_and_then(
+            cx,
+            SEMICOLON_OUTSIDE_BLOCK,
+            block.span,
+            "consider moving the `;` outside the block for consistent formatting",
+            |db| {
+                db.multipart_suggestion(
+                    "put the `;` here",
+                    vec![(semi_span, String::new()), (block.span.shrink_to_hi(), ";".to_string())],
+                    Applicability::MachineApplicable,
+                );
+            },
+        );
+    }
+}
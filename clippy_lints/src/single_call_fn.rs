@@ -0,0 +1,139 @@
+use crate::utils::call_index::CallIndex;
+use crate::utils::{in_macro, is_entrypoint_fn, snippet_opt, span_lint_node_and_then};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use syntax::ast::Attribute;
+use syntax_pos::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for private functions and methods that are
+    /// called exactly once in the crate.
+    ///
+    /// **Why is this bad?** Some codebases prefer to inline code that is only
+    /// used once, so that it can be read where it runs. Functions with short
+    /// bodies can be skipped with the `single-call-fn-min-body-lines` option.
+    ///
+    /// **Known problems:** Functions marked `#[inline]`, tests, trait
+    /// methods and functions used other than by calling them (e.g. as
+    /// `map(f)`) are not linted. Calls from other crates, e.g. from
+    /// integration tests, are not seen, so only functions that aren't
+    /// exported are linted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn greeting() -> String {
+    ///     format!("Hello, {}!", "world")
+    /// }
+    ///
+    /// fn main() {
+    ///     println!("{}", greeting());
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn main() {
+    ///     println!("{}", format!("Hello, {}!", "world"));
+    /// }
+    /// ```
+    pub SINGLE_CALL_FN,
+    restriction,
+    "checks for functions that are only called once"
+}
+
+pub struct SingleCallFn {
+    min_body_lines: u64,
+    /// The functions that may be called only once with the spans of their
+    /// names and definitions, in the order they were checked.
+    candidates: Vec<(HirId, Span, Span)>,
+}
+
+impl SingleCallFn {
+    pub fn new(min_body_lines: u64) -> Self {
+        Self {
+            min_body_lines,
+            candidates: Vec::new(),
+        }
+    }
+
+    fn check_fn_item(
+        &mut self,
+        cx: &LateContext<'_, '_>,
+        hir_id: HirId,
+        ident_span: Span,
+        span: Span,
+        attrs: &[Attribute],
+        body_id: BodyId,
+    ) {
+        let def_id = cx.tcx.hir().local_def_id_from_hir_id(hir_id);
+        if in_macro(span)
+            || cx.access_levels.is_exported(cx.tcx.hir().hir_to_node_id(hir_id))
+            || is_entrypoint_fn(cx, def_id)
+            || attrs
+                .iter()
+                .any(|attr| ["inline", "test", "no_mangle"].iter().any(|&name| attr.name() == name))
+        {
+            return;
+        }
+        let body = cx.tcx.hir().body(body_id);
+        let lines = snippet_opt(cx, body.value.span).map_or(0, |body| body.lines().count());
+        if lines as u64 >= self.min_body_lines {
+            self.candidates.push((hir_id, ident_span, span));
+        }
+    }
+}
+
+impl LintPass for SingleCallFn {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SINGLE_CALL_FN)
+    }
+
+    fn name(&self) -> &'static str {
+        "SingleCallFn"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for SingleCallFn {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if let ItemKind::Fn(_, _, _, body_id) = item.node {
+            self.check_fn_item(cx, item.hir_id, item.ident.span, item.span, &item.attrs, body_id);
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx ImplItem) {
+        if let ImplItemKind::Method(_, body_id) = item.node {
+            // the methods of trait implementations are called through the trait
+            let def_id = cx.tcx.hir().local_def_id_from_hir_id(item.hir_id);
+            let impl_id = cx.tcx.associated_item(def_id).container.id();
+            if cx.tcx.impl_trait_ref(impl_id).is_none() {
+                self.check_fn_item(cx, item.hir_id, item.ident.span, item.span, &item.attrs, body_id);
+            }
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        if self.candidates.is_empty() {
+            return;
+        }
+        let index = CallIndex::new(cx);
+        for &(hir_id, ident_span, span) in &self.candidates {
+            let def_id = cx.tcx.hir().local_def_id_from_hir_id(hir_id);
+            match index.calls(def_id) {
+                // recursive calls don't count as uses
+                [call] if !index.is_used_as_value(def_id) && !span.contains(*call) => {
+                    span_lint_node_and_then(
+                        cx,
+                        SINGLE_CALL_FN,
+                        hir_id,
+                        ident_span,
+                        "this function is only used once",
+                        |db| {
+                            db.span_note(*call, "used here");
+                        },
+                    );
+                },
+                _ => {},
+            }
+        }
+    }
+}
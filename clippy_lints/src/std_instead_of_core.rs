@@ -0,0 +1,156 @@
+use crate::utils::{in_macro, path_to_def, span_lint_and_sugg};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, Lint, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use syntax::source_map::Span;
+use syntax::symbol::keywords;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for paths starting with `std` to items that
+    /// are defined in `core`.
+    ///
+    /// **Why is this bad?** Crates that want to support `no_std` have to use
+    /// `core` paths where possible. This lint helps to migrate step by step.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use std::hash::Hasher;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use core::hash::Hasher;
+    /// ```
+    pub STD_INSTEAD_OF_CORE,
+    restriction,
+    "type is imported from std when available in core"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for paths starting with `std` to items that
+    /// are defined in `alloc`.
+    ///
+    /// **Why is this bad?** Crates that want to support `no_std` with an
+    /// allocator have to use `alloc` paths where possible.
+    ///
+    /// **Known problems:** The suggestion only compiles if the crate has
+    /// `extern crate alloc;`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use std::vec::Vec;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # extern crate alloc;
+    /// use alloc::vec::Vec;
+    /// ```
+    pub STD_INSTEAD_OF_ALLOC,
+    restriction,
+    "type is imported from std when available in alloc"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for paths starting with `alloc` to items that
+    /// are defined in `core`.
+    ///
+    /// **Why is this bad?** Crates that want to support `no_std` without an
+    /// allocator have to use `core` paths where possible.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # extern crate alloc;
+    /// use alloc::slice::from_ref;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use core::slice::from_ref;
+    /// ```
+    pub ALLOC_INSTEAD_OF_CORE,
+    restriction,
+    "type is imported from alloc when available in core"
+}
+
+#[derive(Default)]
+pub struct StdReexports {
+    /// Spans of the crate names already linted. A `use` item with a list of
+    /// paths is lowered to one item per path, all sharing the crate name.
+    linted: FxHashSet<Span>,
+}
+
+impl LintPass for StdReexports {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(STD_INSTEAD_OF_CORE, STD_INSTEAD_OF_ALLOC, ALLOC_INSTEAD_OF_CORE)
+    }
+
+    fn name(&self) -> &'static str {
+        "StdReexports"
+    }
+}
+
+/// Maps the crate a path is written with and the crate defining the item to
+/// the lint to emit.
+fn lint_for(written: &str, defined: &str) -> Option<&'static Lint> {
+    match (written, defined) {
+        ("std", "core") => Some(STD_INSTEAD_OF_CORE),
+        ("std", "alloc") => Some(STD_INSTEAD_OF_ALLOC),
+        ("alloc", "core") => Some(ALLOC_INSTEAD_OF_CORE),
+        _ => None,
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for StdReexports {
+    fn check_path(&mut self, cx: &LateContext<'a, 'tcx>, path: &'tcx Path, _: HirId) {
+        let def_id = match path.def.opt_def_id() {
+            Some(def_id) => def_id,
+            None => return,
+        };
+        let segments: Vec<&PathSegment> = path
+            .segments
+            .iter()
+            .filter(|segment| segment.ident.name != keywords::PathRoot.name())
+            .collect();
+        let krate = match segments.first() {
+            Some(krate) if segments.len() > 1 => krate.ident,
+            _ => return,
+        };
+        if in_macro(path.span) || self.linted.contains(&krate.span) {
+            return;
+        }
+        let defined = cx.tcx.crate_name(def_id.krate).as_str();
+        let lint = match lint_for(&krate.as_str(), &defined) {
+            Some(lint) => lint,
+            None => return,
+        };
+
+        // only lint if the item is reachable under the same path in the
+        // defining crate, e.g. not for `std::os::raw::c_int`
+        let names: Vec<_> = segments.iter().map(|segment| segment.ident.as_str()).collect();
+        let rewritten: Vec<&str> = Some(&*defined)
+            .into_iter()
+            .chain(names[1..].iter().map(|name| &**name))
+            .collect();
+        if path_to_def(cx, &rewritten).and_then(|def| def.opt_def_id()) != Some(def_id) {
+            return;
+        }
+        self.linted.insert(krate.span);
+        span_lint_and_sugg(
+            cx,
+            lint,
+            krate.span,
+            &format!("used import from `{}` instead of `{}`", krate, defined),
+            &format!("consider importing the item from `{}`", defined),
+            defined.to_string(),
+            if &*defined == "alloc" {
+                Applicability::MaybeIncorrect
+            } else {
+                Applicability::MachineApplicable
+            },
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use crate::utils::{in_macro, span_lint_and_sugg};
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use syntax::ast::*;
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for chains of `&&`, `||` or `+` whose operands
+    /// compare or combine the same fields of two values, except for one operand
+    /// that differs, e.g. `a.x == b.x && a.y == a.y`.
+    ///
+    /// **Why is this bad?** The odd operand is most likely a copy-paste
+    /// mistake.
+    ///
+    /// **Known problems:** Only operands of the form `a.field OP b.field` are
+    /// compared. There may be good reasons to break the pattern.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Vec3 {
+    ///     x: f64,
+    ///     y: f64,
+    ///     z: f64,
+    /// }
+    ///
+    /// fn eq(a: &Vec3, b: &Vec3) -> bool {
+    ///     a.x == b.x && a.y == b.y && a.z == a.z
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # struct Vec3 { x: f64, y: f64, z: f64 }
+    /// fn eq(a: &Vec3, b: &Vec3) -> bool {
+    ///     a.x == b.x && a.y == b.y && a.z == b.z
+    /// }
+    /// ```
+    pub SUSPICIOUS_OPERATION_GROUPINGS,
+    nursery,
+    "groupings of binary operations that look suspiciously like typos"
+}
+
+#[derive(Default)]
+pub struct SuspiciousOperationGroupings {
+    /// The sub-chains of the already checked chains, which are skipped.
+    sub_chains: FxHashSet<NodeId>,
+}
+
+impl LintPass for SuspiciousOperationGroupings {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SUSPICIOUS_OPERATION_GROUPINGS)
+    }
+
+    fn name(&self) -> &'static str {
+        "SuspiciousOperationGroupings"
+    }
+}
+
+impl EarlyLintPass for SuspiciousOperationGroupings {
+    fn check_expr(&mut self, cx: &EarlyContext<'_>, expr: &Expr) {
+        let chain_op = match expr.node {
+            ExprKind::Binary(op, _, _) if [BinOpKind::And, BinOpKind::Or, BinOpKind::Add].contains(&op.node) => op.node,
+            _ => return,
+        };
+        if self.sub_chains.remove(&expr.id) || in_macro(expr.span) {
+            return;
+        }
+
+        let mut operands = Vec::new();
+        flatten(chain_op, expr, &mut operands, &mut self.sub_chains);
+        self.sub_chains.remove(&expr.id);
+        let operands: Vec<_> = operands.into_iter().filter_map(FieldOp::from_expr).collect();
+        check_operands(cx, &operands);
+    }
+}
+
+/// Collects the operands of a chain of `op` and the ids of the chain and its sub-chains.
+fn flatten<'a>(op: BinOpKind, expr: &'a Expr, operands: &mut Vec<&'a Expr>, sub_chains: &mut FxHashSet<NodeId>) {
+    match expr.node {
+        ExprKind::Binary(expr_op, ref lhs, ref rhs) if expr_op.node == op => {
+            sub_chains.insert(expr.id);
+            flatten(op, lhs, operands, sub_chains);
+            flatten(op, rhs, operands, sub_chains);
+        },
+        ExprKind::Paren(ref inner) => flatten(op, inner, operands, sub_chains),
+        _ => operands.push(expr),
+    }
+}
+
+/// An operand of the form `base.field OP base.field`.
+struct FieldOp {
+    span: Span,
+    op: BinOpKind,
+    lhs: (Ident, Ident),
+    rhs: (Ident, Ident),
+}
+
+impl FieldOp {
+    fn from_expr(expr: &Expr) -> Option<Self> {
+        match expr.node {
+            ExprKind::Binary(op, ref lhs, ref rhs) => Some(Self {
+                span: expr.span,
+                op: op.node,
+                lhs: field_access(lhs)?,
+                rhs: field_access(rhs)?,
+            }),
+            ExprKind::Paren(ref inner) => Self::from_expr(inner),
+            _ => None,
+        }
+    }
+
+    /// The two bases, if both sides access the same field.
+    fn parallel_bases(&self) -> Option<(Ident, Ident)> {
+        if self.lhs.1.name == self.rhs.1.name && self.lhs.0.name != self.rhs.0.name {
+            Some((self.lhs.0, self.rhs.0))
+        } else {
+            None
+        }
+    }
+}
+
+/// Matches `base.field` where `base` is a single identifier.
+fn field_access(expr: &Expr) -> Option<(Ident, Ident)> {
+    if let ExprKind::Field(ref base, field) = expr.node {
+        if let ExprKind::Path(None, ref path) = base.node {
+            if let [ref segment] = *path.segments {
+                return Some((segment.ident, field));
+            }
+        }
+    }
+    None
+}
+
+fn check_operands(cx: &EarlyContext<'_>, operands: &[FieldOp]) {
+    if operands.len() < 3 {
+        return;
+    }
+    // the operator and bases shared by all but one operand
+    let (op, (base_l, base_r)) = match operands[..2]
+        .iter()
+        .find_map(|operand| operand.parallel_bases().map(|bases| (operand.op, bases)))
+    {
+        Some(pattern) => pattern,
+        None => return,
+    };
+    let is_parallel = |operand: &FieldOp| {
+        operand.op == op
+            && operand
+                .parallel_bases()
+                .map_or(false, |(l, r)| l.name == base_l.name && r.name == base_r.name)
+    };
+    let mut odd = operands.iter().filter(|operand| !is_parallel(operand));
+    let odd = match (odd.next(), odd.next()) {
+        (Some(odd), None) if odd.op == op => odd,
+        _ => return,
+    };
+    let is_base = |ident: Ident| ident.name == base_l.name || ident.name == base_r.name;
+    if !is_base(odd.lhs.0) || !is_base(odd.rhs.0) {
+        return;
+    }
+
+    let is_compared = |field: Ident| {
+        operands
+            .iter()
+            .any(|operand| is_parallel(operand) && operand.lhs.1.name == field.name)
+    };
+    let field = if odd.lhs.1.name == odd.rhs.1.name {
+        // `a.x == a.x`
+        odd.lhs.1
+    } else {
+        // `a.x == b.y` where only one of the fields is not compared yet
+        match (is_compared(odd.lhs.1), is_compared(odd.rhs.1)) {
+            (false, true) => odd.lhs.1,
+            (true, false) => odd.rhs.1,
+            _ => return,
+        }
+    };
+    if is_compared(field) {
+        return;
+    }
+    span_lint_and_sugg(
+        cx,
+        SUSPICIOUS_OPERATION_GROUPINGS,
+        odd.span,
+        "this sequence of operators looks suspiciously like a bug",
+        "did you mean",
+        format!("{}.{} {} {}.{}", base_l, field, op.to_string(), base_r, field),
+        Applicability::MaybeIncorrect,
+    );
+}
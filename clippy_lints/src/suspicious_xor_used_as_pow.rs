@@ -0,0 +1,100 @@
+use crate::utils::{in_macro, snippet_opt, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc::hir::{BinOpKind, Expr, ExprKind};
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::LitKind;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `a ^ b` where both operands are decimal
+    /// integer literals and `a` is one of the bases of the
+    /// `suspicious-xor-bases` option, 2 and 10 by default.
+    ///
+    /// **Why is this bad?** `^` is the bitwise XOR operator, not
+    /// exponentiation: `2 ^ 8` is 10, not 256. Bit patterns are usually
+    /// written in hexadecimal or binary, which isn't linted.
+    ///
+    /// **Known problems:** The XOR may be intended.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let kib = 2 ^ 10;
+    /// let million = 10 ^ 6;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let kib = 1 << 10;
+    /// let million = 10_i32.pow(6);
+    /// ```
+    pub SUSPICIOUS_XOR_USED_AS_POW,
+    pedantic,
+    "XOR of integer literals that looks like exponentiation"
+}
+
+pub struct SuspiciousXorUsedAsPow {
+    bases: Vec<u64>,
+}
+
+impl SuspiciousXorUsedAsPow {
+    pub fn new(bases: &[u64]) -> Self {
+        Self { bases: bases.to_vec() }
+    }
+}
+
+impl LintPass for SuspiciousXorUsedAsPow {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SUSPICIOUS_XOR_USED_AS_POW)
+    }
+
+    fn name(&self) -> &'static str {
+        "SuspiciousXorUsedAsPow"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for SuspiciousXorUsedAsPow {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::Binary(ref op, ref left, ref right) = expr.node;
+            if op.node == BinOpKind::BitXor;
+            if !in_macro(expr.span);
+            if let Some((base, base_snip)) = decimal_literal(cx, left);
+            if self.bases.iter().any(|&b| u128::from(b) == base);
+            if let Some((exponent, _)) = decimal_literal(cx, right);
+            then {
+                // the suffix of the base, e.g. `u8` in `2_u8`
+                let suffix = base_snip.trim_start_matches(|c: char| c.is_ascii_digit() || c == '_');
+                let sugg = match (base, suffix.is_empty()) {
+                    (2, true) => format!("1 << {}", exponent),
+                    (2, false) => format!("1{} << {}", suffix, exponent),
+                    // `pow` can't be called on a literal of unknown type
+                    (_, true) => format!("{}_{}.pow({})", base, cx.tables.expr_ty(left), exponent),
+                    (_, false) => format!("{}{}.pow({})", base, suffix, exponent),
+                };
+                span_lint_and_sugg(
+                    cx,
+                    SUSPICIOUS_XOR_USED_AS_POW,
+                    expr.span,
+                    "`^` is not the exponentiation operator",
+                    "did you mean to write",
+                    sugg,
+                    Applicability::MaybeIncorrect,
+                );
+            }
+        }
+    }
+}
+
+/// The value and the snippet of an integer literal written in decimal.
+fn decimal_literal(cx: &LateContext<'_, '_>, expr: &Expr) -> Option<(u128, String)> {
+    if_chain! {
+        if let ExprKind::Lit(ref lit) = expr.node;
+        if let LitKind::Int(value, _) = lit.node;
+        if let Some(snip) = snippet_opt(cx, expr.span);
+        if !snip.starts_with("0x") && !snip.starts_with("0b") && !snip.starts_with("0o");
+        then {
+            return Some((value, snip));
+        }
+    }
+    None
+}
@@ -0,0 +1,104 @@
+use crate::utils::const_eligibility::{self, ConstFeatures};
+use crate::utils::{in_macro, is_expn_of, msrvs, snippet_with_applicability, span_lint_and_sugg};
+use rustc::hir::intravisit::FnKind;
+use rustc::hir::{Body, Constness, ExprKind, FnDecl, HirId};
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use semver::Version;
+use syntax_pos::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `thread_local!` initializers that could
+    /// be written in the `const { ... }` form.
+    ///
+    /// **Why is this bad?** A `const` initializer is evaluated at compile
+    /// time, which spares the lazy initialization and its check on every
+    /// access. Requires Rust 1.59, the lint respects the `msrv` option.
+    ///
+    /// **Known problems:** Like `missing_const_for_fn`, the lint only knows
+    /// about the `const fn` features of the compiler it runs with.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// thread_local! {
+    ///     static BUF: String = String::new();
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// thread_local! {
+    ///     static BUF: String = const { String::new() };
+    /// }
+    /// ```
+    pub THREAD_LOCAL_INITIALIZER_CAN_BE_MADE_CONST,
+    perf,
+    "`thread_local!` initializers that could be `const`"
+}
+
+pub struct ThreadLocalInitializerCanBeMadeConst {
+    msrv: Option<Version>,
+}
+
+impl ThreadLocalInitializerCanBeMadeConst {
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl LintPass for ThreadLocalInitializerCanBeMadeConst {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(THREAD_LOCAL_INITIALIZER_CAN_BE_MADE_CONST)
+    }
+
+    fn name(&self) -> &'static str {
+        "ThreadLocalInitializerCanBeMadeConst"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ThreadLocalInitializerCanBeMadeConst {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        hir_id: HirId,
+    ) {
+        if !msrvs::meets(self.msrv.as_ref(), msrvs::THREAD_LOCAL_CONST_INIT) {
+            return;
+        }
+        // `thread_local!` wraps the initializer in `fn __init() -> T { init }`
+        match kind {
+            FnKind::ItemFn(ident, _, header, ..)
+                if ident.name == "__init" && header.constness == Constness::NotConst => {},
+            _ => return,
+        }
+        if is_expn_of(span, "thread_local").is_none() {
+            return;
+        }
+        let init = match body.value.node {
+            ExprKind::Block(ref block, _) if block.stmts.is_empty() => match block.expr {
+                Some(ref init) if !in_macro(init.span) => init,
+                _ => return,
+            },
+            _ => return,
+        };
+        if const_eligibility::check(cx, hir_id, ConstFeatures::default()).is_ok() {
+            let mut applicability = Applicability::MachineApplicable;
+            span_lint_and_sugg(
+                cx,
+                THREAD_LOCAL_INITIALIZER_CAN_BE_MADE_CONST,
+                init.span,
+                "initializer for `thread_local` value can be made `const`",
+                "replace with",
+                format!(
+                    "const {{ {} }}",
+                    snippet_with_applicability(cx, init.span, "..", &mut applicability)
+                ),
+                applicability,
+            );
+        }
+    }
+}
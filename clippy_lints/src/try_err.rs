@@ -0,0 +1,126 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::{in_macro, match_qpath, paths, same_tys, span_lint_and_then};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty::Ty;
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usages of `Err(x)?`.
+    ///
+    /// **Why is this bad?** The `?` operator is designed to allow calls that
+    /// can fail to be easily chained. For example, `foo()?.bar()` or
+    /// `foo(bar()?)`. Because `Err(x)?` can't be used that way (it will
+    /// always return), it is more clear to write `return Err(x)`. If the error
+    /// type of the function differs from the type of `x`, the conversion `?`
+    /// does with `From` is spelled out as `x.into()`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn foo(fail: bool) -> Result<i32, String> {
+    ///     if fail {
+    ///         Err("failed")?;
+    ///     }
+    ///     Ok(0)
+    /// }
+    /// ```
+    /// Could be written:
+    ///
+    /// ```rust
+    /// fn foo(fail: bool) -> Result<i32, String> {
+    ///     if fail {
+    ///         return Err("failed".into());
+    ///     }
+    ///     Ok(0)
+    /// }
+    /// ```
+    pub TRY_ERR,
+    restriction,
+    "return errors explicitly rather than hiding them behind a `?`"
+}
+
+#[derive(Copy, Clone)]
+pub struct TryErr;
+
+impl LintPass for TryErr {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(TRY_ERR)
+    }
+
+    fn name(&self) -> &'static str {
+        "TryErr"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for TryErr {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        // Looks for a structure like this:
+        // match ::std::ops::Try::into_result(Err(5)) {
+        //     ::std::result::Result::Err(err) =>
+        //         #[allow(unreachable_code)]
+        //         return ::std::ops::Try::from_error(::std::convert::From::from(err)),
+        //     ::std::result::Result::Ok(val) =>
+        //         #[allow(unreachable_code)]
+        //         val,
+        // };
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprKind::Match(ref match_arg, ref arms, MatchSource::TryDesugar) = expr.node;
+            if let ExprKind::Call(ref match_fun, ref try_args) = match_arg.node;
+            if let ExprKind::Path(ref match_fun_path) = match_fun.node;
+            if match_qpath(match_fun_path, &paths::TRY_INTO_RESULT);
+            if let Some(ref try_arg) = try_args.get(0);
+            if let ExprKind::Call(ref err_fun, ref err_args) = try_arg.node;
+            if let Some(ref err_arg) = err_args.get(0);
+            if let ExprKind::Path(ref err_fun_path) = err_fun.node;
+            if match_qpath(err_fun_path, &paths::RESULT_ERR);
+            if let Some(return_ty) = arms.iter().find_map(|arm| err_return_type(cx, arm));
+            then {
+                let err_ty = cx.tables.expr_ty(err_arg);
+                span_lint_and_then(
+                    cx,
+                    TRY_ERR,
+                    expr.span,
+                    "returning an `Err(_)` with the `?` operator",
+                    |db| {
+                        let mut applicability = Applicability::MachineApplicable;
+                        let mut err = Sugg::hir_with_applicability(cx, err_arg, "..", &mut applicability);
+                        let converts = !same_tys(cx, err_ty, return_ty);
+                        if converts {
+                            err = err.convert_into();
+                        }
+                        let sugg = Sugg::NonParen(format!("Err({})", err).into()).make_return();
+                        db.span_suggestion(expr.span, "try this", sugg.to_string(), applicability);
+                        if converts {
+                            db.note(&format!(
+                                "the `?` operator converts `{}` into `{}` with `From`",
+                                err_ty, return_ty
+                            ));
+                        }
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Finds the error type returned by the `Err` arm of the `?` desugaring, which
+/// is the argument type of `Try::from_error`.
+fn err_return_type<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, arm: &'tcx Arm) -> Option<Ty<'tcx>> {
+    if_chain! {
+        if let ExprKind::Ret(Some(ref err_ret)) = arm.body.node;
+        if let ExprKind::Call(ref from_error_path, ref from_error_args) = err_ret.node;
+        if let ExprKind::Path(ref from_error_fn) = from_error_path.node;
+        if match_qpath(from_error_fn, &paths::TRY_FROM_ERROR);
+        if let Some(from_error_arg) = from_error_args.get(0);
+        then {
+            Some(cx.tables.expr_ty(from_error_arg))
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use crate::consts::{constant_simple, Constant};
+use crate::utils::{in_constant, in_macro, msrvs, snippet, span_help_and_lint, SpanlessEq};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
+use rustc::{declare_tool_lint, lint_array};
+use semver::Version;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for tuples built from the elements of an array in
+    /// order, like `(arr[0], arr[1])`, and arrays built from the fields of a
+    /// tuple, like `[t.0, t.1]`.
+    ///
+    /// **Why is this bad?** Since Rust 1.71, arrays and tuples of up to 12
+    /// elements of the same type convert into each other with `From`/`Into`,
+    /// which is shorter and can't mix up the elements.
+    /// The lint respects the `msrv` option.
+    ///
+    /// **Known problems:** `.into()` needs the target type to be known, which
+    /// may require a type annotation.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let arr = [1, 2];
+    /// let t = (arr[0], arr[1]);
+    /// let back = [t.0, t.1];
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let arr = [1, 2];
+    /// let t: (i32, i32) = arr.into();
+    /// let back: [i32; 2] = t.into();
+    /// ```
+    pub TUPLE_ARRAY_CONVERSIONS,
+    nursery,
+    "converting between arrays and tuples element by element instead of with `From`/`Into`"
+}
+
+/// The largest arrays and tuples converting into each other.
+const MAX_LENGTH: usize = 12;
+
+pub struct TupleArrayConversions {
+    msrv: Option<Version>,
+}
+
+impl TupleArrayConversions {
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl LintPass for TupleArrayConversions {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(TUPLE_ARRAY_CONVERSIONS)
+    }
+
+    fn name(&self) -> &'static str {
+        "TupleArrayConversions"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for TupleArrayConversions {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span)
+            || in_constant(cx, expr.hir_id)
+            || !msrvs::meets(self.msrv.as_ref(), msrvs::TUPLE_ARRAY_CONVERSIONS)
+        {
+            return;
+        }
+        let (source, msg) = match expr.node {
+            ExprKind::Tup(ref elements) => (
+                array_elements_in_order(cx, elements),
+                "it looks like you're trying to convert an array to a tuple",
+            ),
+            ExprKind::Array(ref elements) => (
+                tuple_fields_in_order(cx, elements),
+                "it looks like you're trying to convert a tuple to an array",
+            ),
+            _ => return,
+        };
+        if let Some(source) = source {
+            let source = snippet(cx, source.span, "..");
+            span_help_and_lint(
+                cx,
+                TUPLE_ARRAY_CONVERSIONS,
+                expr.span,
+                msg,
+                &format!(
+                    "use `{}.into()` instead, or `<{}>::from({})` if type annotations are needed",
+                    source,
+                    cx.tables.expr_ty(expr),
+                    source
+                ),
+            );
+        }
+    }
+}
+
+/// Checks if `elements` are `arr[0], arr[1], ..` for all elements of the array
+/// `arr`, and returns `arr`.
+fn array_elements_in_order<'a>(cx: &LateContext<'_, '_>, elements: &'a [Expr]) -> Option<&'a Expr> {
+    let mut source = None;
+    for (i, element) in elements.iter().enumerate() {
+        let (base, index) = match element.node {
+            ExprKind::Index(ref base, ref index) => (base, index),
+            _ => return None,
+        };
+        match constant_simple(cx, cx.tables, index) {
+            Some(Constant::Int(n)) if n == i as u128 => {},
+            _ => return None,
+        }
+        if !same_source(cx, &mut source, base) {
+            return None;
+        }
+    }
+    let source = source?;
+    match cx.tables.expr_ty(source).sty {
+        ty::Array(_, len) if len.assert_usize(cx.tcx) == Some(elements.len() as u64) => {},
+        _ => return None,
+    }
+    Some(source).filter(|_| elements.len() <= MAX_LENGTH)
+}
+
+/// Checks if `elements` are `t.0, t.1, ..` for all fields of the tuple `t`,
+/// and returns `t`.
+fn tuple_fields_in_order<'a>(cx: &LateContext<'_, '_>, elements: &'a [Expr]) -> Option<&'a Expr> {
+    let mut source = None;
+    for (i, element) in elements.iter().enumerate() {
+        let base = match element.node {
+            ExprKind::Field(ref base, ident) if ident.as_str().parse::<usize>() == Ok(i) => base,
+            _ => return None,
+        };
+        if !same_source(cx, &mut source, base) {
+            return None;
+        }
+    }
+    let source = source?;
+    match cx.tables.expr_ty(source).sty {
+        ty::Tuple(fields) if fields.len() == elements.len() => {},
+        _ => return None,
+    }
+    Some(source).filter(|_| elements.len() <= MAX_LENGTH)
+}
+
+/// Records the expression the first element is taken from in `source`, and
+/// checks that the others are taken from the same one.
+fn same_source<'a>(cx: &LateContext<'_, '_>, source: &mut Option<&'a Expr>, base: &'a Expr) -> bool {
+    match *source {
+        Some(first) => SpanlessEq::new(cx).ignore_fn().eq_expr(first, base),
+        None => {
+            *source = Some(base);
+            true
+        },
+    }
+}
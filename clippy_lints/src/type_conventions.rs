@@ -0,0 +1,143 @@
+use crate::utils::{in_macro, span_help_and_lint, span_lint};
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
+use rustc::{declare_tool_lint, lint_array};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for constructors named after their type, e.g.
+    /// `Foo::foo()`.
+    ///
+    /// **Why is this bad?** Constructors are called `new` by convention.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Foo {}
+    ///
+    /// impl Foo {
+    ///     pub fn foo() -> Foo {
+    ///         Foo {}
+    ///     }
+    /// }
+    /// ```
+    pub SELF_NAMED_CONSTRUCTORS,
+    style,
+    "method without `self` that returns `Self` and is named after the type"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for types whose name ends with `Builder` that
+    /// have no `build` method.
+    ///
+    /// **Why is this bad?** Builders are expected to be finished with
+    /// `build()`, other names make the API harder to discover.
+    ///
+    /// **Known problems:** The `build` method can only be found in inherent
+    /// impls.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # struct Config;
+    /// pub struct ConfigBuilder {}
+    ///
+    /// impl ConfigBuilder {
+    ///     pub fn finish(self) -> Config {
+    ///         Config
+    ///     }
+    /// }
+    /// ```
+    pub BUILDER_WITHOUT_BUILD,
+    pedantic,
+    "`*Builder` type without a `build` method"
+}
+
+#[derive(Copy, Clone)]
+pub struct TypeConventions;
+
+impl LintPass for TypeConventions {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SELF_NAMED_CONSTRUCTORS, BUILDER_WITHOUT_BUILD)
+    }
+
+    fn name(&self) -> &'static str {
+        "TypeConventions"
+    }
+}
+
+/// A type and the methods of its inherent impls.
+struct TypeMethods<'a> {
+    def_id: DefId,
+    name: &'a str,
+    methods: Vec<ty::AssociatedItem>,
+}
+
+/// The naming conventions, keyed by a suffix of the type name. An empty
+/// suffix applies to all types.
+const CONVENTIONS: [(&str, fn(&LateContext<'_, '_>, &TypeMethods<'_>)); 2] =
+    [("", check_self_named_constructors), ("Builder", check_builder)];
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for TypeConventions {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        match item.node {
+            ItemKind::Struct(..) | ItemKind::Enum(..) | ItemKind::Union(..) => {},
+            _ => return,
+        }
+        if in_macro(item.span) {
+            return;
+        }
+        let def_id = cx.tcx.hir().local_def_id_from_hir_id(item.hir_id);
+        let name = item.ident.as_str();
+        let ty = TypeMethods {
+            def_id,
+            name: &name,
+            methods: cx
+                .tcx
+                .inherent_impls(def_id)
+                .iter()
+                .flat_map(|&impl_id| cx.tcx.associated_items(impl_id))
+                .filter(|item| item.kind == ty::AssociatedKind::Method)
+                .collect(),
+        };
+        for (suffix, check) in &CONVENTIONS {
+            if name.ends_with(suffix) {
+                check(cx, &ty);
+            }
+        }
+    }
+}
+
+fn check_self_named_constructors(cx: &LateContext<'_, '_>, ty: &TypeMethods<'_>) {
+    for method in &ty.methods {
+        let method_name = method.ident.as_str();
+        // `FooBar::foo_bar`
+        if method.method_has_self_argument || !method_name.replace('_', "").eq_ignore_ascii_case(ty.name) {
+            continue;
+        }
+        let ret_ty = cx.tcx.fn_sig(method.def_id).output().skip_binder();
+        if let ty::Adt(adt, _) = ret_ty.sty {
+            if adt.did == ty.def_id {
+                span_lint(
+                    cx,
+                    SELF_NAMED_CONSTRUCTORS,
+                    cx.tcx.def_span(method.def_id),
+                    &format!("constructor `{}` has the same name as the type", method_name),
+                );
+            }
+        }
+    }
+}
+
+fn check_builder(cx: &LateContext<'_, '_>, ty: &TypeMethods<'_>) {
+    if ty.name != "Builder" && !ty.methods.iter().any(|method| method.ident.name == "build") {
+        span_help_and_lint(
+            cx,
+            BUILDER_WITHOUT_BUILD,
+            cx.tcx.def_span(ty.def_id),
+            &format!("builder type `{}` has no `build` method", ty.name),
+            "consider adding a `build` method that returns the built value",
+        );
+    }
+}
@@ -1,6 +1,7 @@
 #![allow(clippy::default_hash_types)]
 
 use crate::consts::{constant, Constant};
+use crate::utils::edition::{self, EditionExt};
 use crate::utils::paths;
 use crate::utils::{
     clip, comparisons, differing_macro_contexts, higher, in_constant, in_macro, int_bits, last_path_segment,
@@ -405,18 +406,23 @@ fn check_ty_rptr(cx: &LateContext<'_, '_>, hir_ty: &hir::Ty, is_local: bool, lt:
                         ""
                     };
                     let mut applicability = Applicability::MachineApplicable;
+                    let mut inner_snippet = snippet_with_applicability(cx, inner.span, "..", &mut applicability).to_string();
+                    if let TyKind::TraitObject(ref bounds, ref object_lt) = inner.node {
+                        if !inner_snippet.starts_with("dyn ") {
+                            inner_snippet = edition::TRAIT_OBJECT.apply(cx.edition(), &inner_snippet);
+                        }
+                        // `&Trait + Send` would parse as `(&Trait) + Send`
+                        if bounds.len() > 1 || !object_lt.is_elided() {
+                            inner_snippet = format!("({})", inner_snippet);
+                        }
+                    }
                     span_lint_and_sugg(
                         cx,
                         BORROWED_BOX,
                         hir_ty.span,
                         "you seem to be trying to use `&Box<T>`. Consider using just `&T`",
                         "try",
-                        format!(
-                            "&{}{}{}",
-                            ltopt,
-                            mutopt,
-                            &snippet_with_applicability(cx, inner.span, "..", &mut applicability)
-                        ),
+                        format!("&{}{}{}", ltopt, mutopt, inner_snippet),
                         Applicability::Unspecified,
                     );
                     return; // don't recurse into the type
@@ -892,6 +898,65 @@ declare_clippy_lint! {
     "casting a function pointer to a numeric type not wide enough to store the address"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for `as _` casts, where the target type is
+    /// inferred.
+    ///
+    /// **Why is this bad?** The cast silently changes its meaning if the
+    /// inferred type changes, e.g. when the signature of a called function is
+    /// changed, and the reader has to look elsewhere to know what it does.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn foo(n: usize) {}
+    /// let n: u16 = 256;
+    /// foo(n as _);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn foo(n: usize) {}
+    /// let n: u16 = 256;
+    /// foo(n as usize);
+    /// ```
+    pub AS_UNDERSCORE,
+    restriction,
+    "detects `as _` conversion"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for casts between integer types that are not
+    /// lossless on all targets, i.e. that `From` doesn't cover, and suggests
+    /// `TryFrom` instead.
+    ///
+    /// **Why is this bad?** `as` silently truncates, wraps or drops the sign
+    /// of values that don't fit into the target type. `TryFrom` returns an
+    /// error instead, and makes it clear whether a lossy conversion is
+    /// intended.
+    ///
+    /// **Known problems:** The suggestion changes the type of the expression
+    /// to a `Result` and needs `std::convert::TryFrom` to be imported. Casts
+    /// that can't lose any value on the compilation target, like `u32 as
+    /// usize` on 64-bit targets, are linted too, as they are lossy on some
+    /// other target. Casts whose truncation is intended are linted as well.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let x = 300u32;
+    /// let y = x as u8;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::convert::TryFrom;
+    /// # let x = 300u32;
+    /// let y = u8::try_from(x);
+    /// ```
+    pub CAST_TRY_FROM,
+    restriction,
+    "integer casts using `as` that may be lossy and could use `TryFrom`"
+}
+
 /// Returns the size in bits of an integral type.
 /// Will return 0 if the type is not an int or uint variant
 fn int_ty_to_nbits(typ: Ty<'_>, tcx: TyCtxt<'_, '_, '_>) -> u64 {
@@ -1105,6 +1170,67 @@ fn check_lossless(cx: &LateContext<'_, '_>, expr: &Expr, op: &Expr, cast_from: T
     }
 }
 
+fn check_try_from(cx: &LateContext<'_, '_>, expr: &Expr, op: &Expr, cast_from: Ty<'_>, cast_to: Ty<'_>) {
+    // `TryFrom` is not usable in consts/statics
+    if cast_from.sty == cast_to.sty || in_constant(cx, expr.hir_id) {
+        return;
+    }
+    let is_lossless = |from_nbits, to_nbits| {
+        if cast_from.is_signed() == cast_to.is_signed() {
+            from_nbits <= to_nbits
+        } else {
+            !cast_from.is_signed() && from_nbits < to_nbits
+        }
+    };
+    let lossless_on_target = is_lossless(int_ty_to_nbits(cast_from, cx.tcx), int_ty_to_nbits(cast_to, cx.tcx));
+    // `From` is only implemented if the conversion is lossless for pointers
+    // from 16 up to 128 bits wide
+    let (from_max_nbits, to_min_nbits) = (
+        if is_isize_or_usize(cast_from) {
+            128
+        } else {
+            int_ty_to_nbits(cast_from, cx.tcx)
+        },
+        if is_isize_or_usize(cast_to) {
+            16
+        } else {
+            int_ty_to_nbits(cast_to, cx.tcx)
+        },
+    );
+    if is_lossless(from_max_nbits, to_min_nbits) {
+        return;
+    }
+    // The suggestion returns a `Result` either way. For a cast that is lossless
+    // on this target, unwrapping it keeps the behavior, while a lossy cast needs
+    // a decision on what to do with the values that `as` would have changed.
+    let (msg, mut applicability) = if lossless_on_target {
+        (
+            format!("casting {} to {} may be lossy on other targets", cast_from, cast_to),
+            Applicability::MaybeIncorrect,
+        )
+    } else {
+        (
+            format!("casting {} to {} may lose information", cast_from, cast_to),
+            Applicability::Unspecified,
+        )
+    };
+    let snip = snippet_with_applicability(cx, op.span, "..", &mut applicability);
+    let sugg = if should_strip_parens(op, &snip) {
+        &snip[1..snip.len() - 1]
+    } else {
+        &snip
+    };
+    span_lint_and_sugg(
+        cx,
+        CAST_TRY_FROM,
+        expr.span,
+        &msg,
+        "if this is intended to be fallible, use",
+        format!("{}::try_from({})", cast_to, sugg),
+        applicability,
+    );
+}
+
 impl LintPass for CastPass {
     fn get_lints(&self) -> LintArray {
         lint_array!(
@@ -1117,6 +1243,8 @@ impl LintPass for CastPass {
             CAST_PTR_ALIGNMENT,
             FN_TO_NUMERIC_CAST,
             FN_TO_NUMERIC_CAST_WITH_TRUNCATION,
+            AS_UNDERSCORE,
+            CAST_TRY_FROM,
         )
     }
 
@@ -1144,9 +1272,22 @@ fn is_c_void(tcx: TyCtxt<'_, '_, '_>, ty: Ty<'_>) -> bool {
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CastPass {
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
-        if let ExprKind::Cast(ref ex, _) = expr.node {
+        if let ExprKind::Cast(ref ex, ref cast_ty) = expr.node {
             let (cast_from, cast_to) = (cx.tables.expr_ty(ex), cx.tables.expr_ty(expr));
             lint_fn_to_numeric_cast(cx, expr, ex, cast_from, cast_to);
+            if let TyKind::Infer = cast_ty.node {
+                if !in_macro(expr.span) {
+                    span_lint_and_sugg(
+                        cx,
+                        AS_UNDERSCORE,
+                        cast_ty.span,
+                        "using `as _` conversion",
+                        "consider giving the type explicitly",
+                        cast_to.to_string(),
+                        Applicability::MachineApplicable,
+                    );
+                }
+            }
             if let ExprKind::Lit(ref lit) = ex.node {
                 use syntax::ast::{LitIntType, LitKind};
                 match lit.node {
@@ -1202,6 +1343,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CastPass {
                         check_loss_of_sign(cx, expr, ex, cast_from, cast_to);
                         check_truncation_and_wrapping(cx, expr, cast_from, cast_to);
                         check_lossless(cx, expr, ex, cast_from, cast_to);
+                        check_try_from(cx, expr, ex, cast_from, cast_to);
                     },
                     (false, false) => {
                         if let (&ty::Float(FloatTy::F64), &ty::Float(FloatTy::F32)) = (&cast_from.sty, &cast_to.sty) {
@@ -1757,24 +1899,23 @@ impl FullInt {
 
 impl PartialEq for FullInt {
     fn eq(&self, other: &Self) -> bool {
-        self.partial_cmp(other).expect("partial_cmp only returns Some(_)") == Ordering::Equal
+        self.cmp(other) == Ordering::Equal
     }
 }
 
 impl PartialOrd for FullInt {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(match (self, other) {
-            (&FullInt::S(s), &FullInt::S(o)) => s.cmp(&o),
-            (&FullInt::U(s), &FullInt::U(o)) => s.cmp(&o),
-            (&FullInt::S(s), &FullInt::U(o)) => Self::cmp_s_u(s, o),
-            (&FullInt::U(s), &FullInt::S(o)) => Self::cmp_s_u(o, s).reverse(),
-        })
+        Some(self.cmp(other))
     }
 }
 impl Ord for FullInt {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other)
-            .expect("partial_cmp for FullInt can never return None")
+        match (self, other) {
+            (&FullInt::S(s), &FullInt::S(o)) => s.cmp(&o),
+            (&FullInt::U(s), &FullInt::U(o)) => s.cmp(&o),
+            (&FullInt::S(s), &FullInt::U(o)) => Self::cmp_s_u(s, o),
+            (&FullInt::U(s), &FullInt::S(o)) => Self::cmp_s_u(o, s).reverse(),
+        }
     }
 }
 
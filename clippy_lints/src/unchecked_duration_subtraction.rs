@@ -0,0 +1,138 @@
+use crate::consts::constant_simple;
+use crate::utils::sugg::Sugg;
+use crate::utils::{in_constant, in_macro, match_type, msrvs, paths, snippet_with_applicability, span_lint_and_then};
+use rustc::hir::def::Def;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use semver::Version;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Instant - Duration` and `Duration - Duration`
+    /// subtractions where at least one operand is not a constant.
+    ///
+    /// **Why is this bad?** The subtraction panics if the result would be
+    /// before the earliest representable `Instant` or below zero. Use
+    /// `checked_sub` to handle that case, or `saturating_sub` for durations.
+    /// `Duration::saturating_sub` requires Rust 1.53, the lint respects the
+    /// `msrv` option.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::time::{Duration, Instant};
+    /// let time_passed = Instant::now() - Duration::from_secs(5);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::time::{Duration, Instant};
+    /// let time_passed = Instant::now().checked_sub(Duration::from_secs(5));
+    /// ```
+    pub UNCHECKED_DURATION_SUBTRACTION,
+    pedantic,
+    "subtracting a `Duration` from an `Instant` or `Duration`, which can panic"
+}
+
+pub struct UncheckedDurationSubtraction {
+    msrv: Option<Version>,
+}
+
+impl UncheckedDurationSubtraction {
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl LintPass for UncheckedDurationSubtraction {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNCHECKED_DURATION_SUBTRACTION)
+    }
+
+    fn name(&self) -> &'static str {
+        "UncheckedDurationSubtraction"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UncheckedDurationSubtraction {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) || in_constant(cx, expr.hir_id) {
+            return;
+        }
+        let (lhs, rhs) = match expr.node {
+            ExprKind::Binary(op, ref lhs, ref rhs) if op.node == BinOpKind::Sub => (lhs, rhs),
+            _ => return,
+        };
+        let lhs_ty = cx.tables.expr_ty(lhs);
+        let (is_duration, target) = if match_type(cx, lhs_ty, &paths::INSTANT) {
+            (false, "an `Instant`")
+        } else if match_type(cx, lhs_ty, &paths::DURATION) {
+            (true, "a `Duration`")
+        } else {
+            return;
+        };
+        if !match_type(cx, cx.tables.expr_ty(rhs), &paths::DURATION)
+            || (is_const_duration(cx, lhs) && is_const_duration(cx, rhs))
+        {
+            return;
+        }
+
+        let msg = format!("unchecked subtraction of a `Duration` from {}", target);
+        span_lint_and_then(cx, UNCHECKED_DURATION_SUBTRACTION, expr.span, &msg, |db| {
+            let mut applicability = Applicability::MachineApplicable;
+            let lhs = Sugg::hir_with_applicability(cx, lhs, "..", &mut applicability).maybe_par();
+            let rhs = snippet_with_applicability(cx, rhs.span, "..", &mut applicability);
+            db.span_suggestion(
+                expr.span,
+                "use `checked_sub` to handle the underflow",
+                format!("{}.checked_sub({})", lhs, rhs),
+                Applicability::MaybeIncorrect,
+            );
+            if is_duration && msrvs::meets(self.msrv.as_ref(), msrvs::DURATION_SATURATING_SUB) {
+                db.span_suggestion(
+                    expr.span,
+                    "or stop at zero with",
+                    format!("{}.saturating_sub({})", lhs, rhs),
+                    applicability,
+                );
+            }
+        });
+    }
+}
+
+/// Checks if `expr` is a constant `Duration`, i.e. a path to a constant or a
+/// constructor like `Duration::from_secs(1)` with constant arguments.
+fn is_const_duration(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::Path(ref qpath) => match cx.tables.qpath_def(qpath, expr.hir_id) {
+            Def::Const(..) | Def::AssociatedConst(..) => true,
+            _ => false,
+        },
+        ExprKind::Call(ref fun, ref args) => {
+            if let ExprKind::Path(ref qpath) = fun.node {
+                is_assoc_fn_of(qpath, "Duration")
+                    && args.iter().all(|arg| constant_simple(cx, cx.tables, arg).is_some())
+            } else {
+                false
+            }
+        },
+        _ => false,
+    }
+}
+
+/// Checks if `qpath` is an associated function of `ty_name`, like `Duration::new`.
+fn is_assoc_fn_of(qpath: &QPath, ty_name: &str) -> bool {
+    match *qpath {
+        QPath::TypeRelative(ref ty, _) => match ty.node {
+            TyKind::Path(QPath::Resolved(None, ref path)) => path
+                .segments
+                .last()
+                .map_or(false, |segment| segment.ident.name == ty_name),
+            _ => false,
+        },
+        QPath::Resolved(_, ref path) => {
+            path.segments.len() >= 2 && path.segments[path.segments.len() - 2].ident.name == ty_name
+        },
+    }
+}
@@ -0,0 +1,297 @@
+use crate::utils::{hir_ancestors, match_def_path, paths, resolve_node, span_lint_and_then, walk_ptrs_ty};
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty::Ty;
+use rustc::{declare_tool_lint, lint_array};
+use syntax::ast::{Name, NodeId};
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for trait implementations whose methods call
+    /// themselves on `self` on every path, like `PartialEq::eq` comparing
+    /// `self == other`, `Display::fmt` formatting `self` or
+    /// `Default::default` calling `Self::default()`.
+    ///
+    /// **Why is this bad?** The method never returns, it overflows the stack.
+    /// The compiler's own lint does not see through operators, `to_string`
+    /// or the formatting macros, which all call back into the implementation.
+    ///
+    /// **Known problems:** Calls in branches, loops and closures are not
+    /// checked, even if every branch recurses.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// struct Id(u32);
+    ///
+    /// impl PartialEq for Id {
+    ///     fn eq(&self, other: &Self) -> bool {
+    ///         self == other
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// impl PartialEq for Id {
+    ///     fn eq(&self, other: &Self) -> bool {
+    ///         self.0 == other.0
+    ///     }
+    /// }
+    /// ```
+    pub UNCONDITIONAL_RECURSION,
+    correctness,
+    "trait method implementations that call themselves on `self`"
+}
+
+#[derive(Copy, Clone)]
+pub struct UnconditionalRecursion;
+
+impl LintPass for UnconditionalRecursion {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNCONDITIONAL_RECURSION)
+    }
+
+    fn name(&self) -> &'static str {
+        "UnconditionalRecursion"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnconditionalRecursion {
+    fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, impl_item: &'tcx ImplItem) {
+        let parent = cx.tcx.hir().get_parent_item(impl_item.hir_id);
+        let impl_def_id = cx.tcx.hir().local_def_id_from_hir_id(parent);
+        if_chain! {
+            if let ImplItemKind::Method(_, body_id) = impl_item.node;
+            if let Some(trait_ref) = cx.tcx.impl_trait_ref(impl_def_id);
+            then {
+                let body = cx.tcx.hir().body(body_id);
+                let self_id = body.arguments.first().and_then(|arg| match arg.pat.node {
+                    PatKind::Binding(_, id, _, ident, None) if ident.name == "self" => Some(id),
+                    _ => None,
+                });
+                let method_id = cx.tcx.hir().local_def_id_from_hir_id(impl_item.hir_id);
+                let mut visitor = RecursionVisitor {
+                    cx,
+                    trait_id: trait_ref.def_id,
+                    name: impl_item.ident.name,
+                    method_id,
+                    trait_tys: trait_ref
+                        .substs
+                        .types()
+                        .map(|ty| cx.tcx.erase_regions(&walk_ptrs_ty(ty)))
+                        .collect(),
+                    self_id,
+                    call: None,
+                };
+                visitor.visit_expr(&body.value);
+                if let Some(call) = visitor.call {
+                    span_lint_and_then(
+                        cx,
+                        UNCONDITIONAL_RECURSION,
+                        cx.tcx.def_span(method_id),
+                        "function cannot return without recursing",
+                        |db| {
+                            db.span_note(call, "recursive call site");
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Looks for calls back into the implemented method that are made whenever
+/// the body runs.
+struct RecursionVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    trait_id: DefId,
+    name: Name,
+    method_id: DefId,
+    /// The `Self` type and the type parameters of the implemented trait.
+    trait_tys: Vec<Ty<'tcx>>,
+    /// The `self` argument, if the method has one.
+    self_id: Option<NodeId>,
+    call: Option<Span>,
+}
+
+impl<'a, 'tcx: 'a> RecursionVisitor<'a, 'tcx> {
+    /// Whether `def_id`, used at `hir_id`, resolves to the implemented method
+    /// for the same type and trait parameters, like `PartialEq<Self>` and not
+    /// `PartialEq<Other>`.
+    fn is_recursive(&self, def_id: DefId, hir_id: HirId) -> bool {
+        if def_id == self.method_id {
+            return true;
+        }
+        self.cx.tcx.trait_of_item(def_id) == Some(self.trait_id)
+            && self.cx.tcx.associated_item(def_id).ident.name == self.name
+            && self.is_impl_trait_ref(hir_id)
+    }
+
+    /// Whether the trait item at `hir_id` is used with the `Self` type and
+    /// type parameters of the implementation, behind any references.
+    fn is_impl_trait_ref(&self, hir_id: HirId) -> bool {
+        let tys = self.cx.tables.node_substs(hir_id).types().collect::<Vec<_>>();
+        tys.len() >= self.trait_tys.len()
+            && tys
+                .iter()
+                .zip(&self.trait_tys)
+                .all(|(&ty, &trait_ty)| self.cx.tcx.erase_regions(&walk_ptrs_ty(ty)) == trait_ty)
+    }
+
+    /// Whether the `Self` type the trait item at `hir_id` is used with is the
+    /// implementing type, behind any references.
+    fn is_self_ty(&self, hir_id: HirId) -> bool {
+        self.cx
+            .tables
+            .node_substs(hir_id)
+            .types()
+            .next()
+            .map_or(false, |ty| self.cx.tcx.erase_regions(&walk_ptrs_ty(ty)) == self.trait_tys[0])
+    }
+
+    /// Whether the method at `def_id` is called on `self`, or takes no `self`
+    /// at all.
+    fn is_self_receiver(&self, def_id: DefId, receiver: Option<&Expr>) -> bool {
+        if !self.cx.tcx.associated_item(def_id).method_has_self_argument {
+            return true;
+        }
+        receiver.map_or(false, |receiver| self.is_self(receiver))
+    }
+
+    fn is_self(&self, expr: &Expr) -> bool {
+        self.self_path(expr).is_some()
+    }
+
+    /// The `self` path in `expr`, behind any borrows or dereferences.
+    fn self_path<'e>(&self, expr: &'e Expr) -> Option<&'e Expr> {
+        match expr.node {
+            ExprKind::AddrOf(_, ref inner) | ExprKind::Unary(UnOp::UnDeref, ref inner) => self.self_path(inner),
+            ExprKind::Path(ref qpath) => match resolve_node(self.cx, qpath, expr.hir_id) {
+                Def::Local(id) if Some(id) == self.self_id => Some(expr),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// `self.to_string()` in `Display::fmt`, which formats `self` again.
+    fn is_display_to_string(&self, def_id: DefId, hir_id: HirId) -> bool {
+        match_def_path(self.cx.tcx, self.trait_id, &paths::DISPLAY_TRAIT)
+            && match_def_path(self.cx.tcx, def_id, &paths::TO_STRING_METHOD)
+            && self.is_self_ty(hir_id)
+    }
+
+    /// For a formatting function like `Display::fmt` passed to
+    /// `ArgumentV1::new(__arg0, Display::fmt)` by the formatting macros, the
+    /// `self` if that is the argument it formats.
+    fn formatted_self(&self, fmt: &Expr) -> Option<&'tcx Expr> {
+        let mut ancestors = hir_ancestors(self.cx, fmt.hir_id);
+        let arg_id = match ancestors.next() {
+            Some((_, Node::Expr(call))) => match call.node {
+                ExprKind::Call(_, ref args) if args.len() == 2 && args[1].hir_id == fmt.hir_id => match args[0].node {
+                    ExprKind::Path(ref qpath) => match resolve_node(self.cx, qpath, args[0].hir_id) {
+                        Def::Local(id) => id,
+                        _ => return None,
+                    },
+                    _ => return None,
+                },
+                _ => return None,
+            },
+            _ => return None,
+        };
+        // `match (&self,) { (__arg0,) => [..] }`
+        ancestors.find_map(|(_, node)| {
+            if_chain! {
+                if let Node::Expr(expr) = node;
+                if let ExprKind::Match(ref args, ref arms, _) = expr.node;
+                if let ExprKind::Tup(ref values) = args.node;
+                if arms.len() == 1 && arms[0].pats.len() == 1;
+                if let PatKind::Tuple(ref pats, None) = arms[0].pats[0].node;
+                if let Some(index) = pats.iter().position(|pat| match pat.node {
+                    PatKind::Binding(_, id, ..) => id == arg_id,
+                    _ => false,
+                });
+                then {
+                    self.self_path(&values[index])
+                } else {
+                    None
+                }
+            }
+        })
+    }
+}
+
+impl<'a, 'tcx: 'a> Visitor<'tcx> for RecursionVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if self.call.is_some() {
+            return;
+        }
+        match expr.node {
+            // only the parts that are always evaluated
+            ExprKind::If(ref cond, ..) => self.visit_expr(cond),
+            ExprKind::Match(ref scrutinee, ref arms, _) => {
+                self.visit_expr(scrutinee);
+                if arms.len() == 1 {
+                    self.visit_expr(&arms[0].body);
+                }
+            },
+            ExprKind::Binary(op, ref left, _) if op.node == BinOpKind::And || op.node == BinOpKind::Or => {
+                self.visit_expr(left);
+            },
+            ExprKind::While(..) | ExprKind::Loop(..) | ExprKind::Closure(..) => {},
+            // `self == other` resolves to `PartialEq::eq`
+            ExprKind::Binary(_, ref left, ref right) => {
+                if let Some(def) = self.cx.tables.type_dependent_defs().get(expr.hir_id) {
+                    let def_id = def.def_id();
+                    if self.is_recursive(def_id, expr.hir_id) && (self.is_self(left) || self.is_self(right)) {
+                        self.call = Some(expr.span);
+                        return;
+                    }
+                }
+                walk_expr(self, expr);
+            },
+            ExprKind::MethodCall(_, _, ref args) => {
+                if let Some(def) = self.cx.tables.type_dependent_defs().get(expr.hir_id) {
+                    let def_id = def.def_id();
+                    if (self.is_recursive(def_id, expr.hir_id) || self.is_display_to_string(def_id, expr.hir_id))
+                        && self.is_self_receiver(def_id, args.first())
+                    {
+                        self.call = Some(expr.span);
+                        return;
+                    }
+                }
+                walk_expr(self, expr);
+            },
+            // `Self::default()` or `PartialEq::eq(self, other)`
+            ExprKind::Call(ref callee, ref args) => {
+                if let ExprKind::Path(ref qpath) = callee.node {
+                    if let Def::Method(def_id) = resolve_node(self.cx, qpath, callee.hir_id) {
+                        if self.is_recursive(def_id, callee.hir_id) && self.is_self_receiver(def_id, args.first()) {
+                            self.call = Some(expr.span);
+                            return;
+                        }
+                    }
+                }
+                walk_expr(self, expr);
+            },
+            // `write!(f, "{}", self)` passes `Display::fmt` as a value
+            ExprKind::Path(ref qpath) => {
+                if let Def::Method(def_id) = resolve_node(self.cx, qpath, expr.hir_id) {
+                    if self.is_recursive(def_id, expr.hir_id) {
+                        if let Some(arg) = self.formatted_self(expr) {
+                            self.call = Some(arg.span);
+                        }
+                    }
+                }
+            },
+            _ => walk_expr(self, expr),
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
@@ -0,0 +1,120 @@
+use crate::utils::comments::CommentCache;
+use crate::utils::{in_macro, span_help_and_lint};
+use if_chain::if_chain;
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use syntax::ast::{BlockCheckMode, Expr, ExprKind, Item, ItemKind, UnsafeSource, Unsafety};
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `unsafe` blocks and impls without a
+    /// `// SAFETY:` comment on the lines before them. For a block inside a
+    /// statement, like `let x = unsafe { .. };`, the comment goes before the
+    /// statement.
+    ///
+    /// Attribute lines between the comment and the code are accepted unless
+    /// `accept-comment-above-attributes` is `false`, blank lines only up to
+    /// `safety-comment-max-blank-lines` (0 by default).
+    ///
+    /// **Why is this bad?** The comment explains why the unsafe code is
+    /// sound, and lets reviewers check that it still is after changes.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let ptr = &1 as *const i32;
+    /// let value = unsafe { *ptr };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let ptr = &1 as *const i32;
+    /// // SAFETY: `ptr` points to a live `i32`
+    /// let value = unsafe { *ptr };
+    /// ```
+    pub UNDOCUMENTED_UNSAFE_BLOCKS,
+    restriction,
+    "`unsafe` blocks and impls without a `// SAFETY:` comment"
+}
+
+pub struct UndocumentedUnsafeBlocks {
+    accept_comment_above_attributes: bool,
+    max_blank_lines: usize,
+    comments: CommentCache,
+}
+
+impl UndocumentedUnsafeBlocks {
+    pub fn new(accept_comment_above_attributes: bool, max_blank_lines: u64) -> Self {
+        Self {
+            accept_comment_above_attributes,
+            max_blank_lines: max_blank_lines as usize,
+            comments: CommentCache::default(),
+        }
+    }
+
+    /// Whether a safety comment precedes the line of `span`.
+    fn has_safety_comment(&mut self, cx: &EarlyContext<'_>, span: Span) -> bool {
+        let comments = match self.comments.get(cx, span) {
+            Some(comments) => comments,
+            // without the source, there's nothing to check
+            None => return true,
+        };
+        let start = comments.line_code_start(span.lo());
+        let skip = if self.accept_comment_above_attributes {
+            comments.attribute_lines_before(start)
+        } else {
+            Vec::new()
+        };
+        let preceding = comments.preceding_with_blank_lines(span.with_lo(start), &skip, self.max_blank_lines);
+        preceding
+            .iter()
+            .any(|comment| comments.text(comment).to_ascii_uppercase().contains("SAFETY:"))
+    }
+}
+
+impl LintPass for UndocumentedUnsafeBlocks {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNDOCUMENTED_UNSAFE_BLOCKS)
+    }
+
+    fn name(&self) -> &'static str {
+        "UndocumentedUnsafeBlocks"
+    }
+}
+
+impl EarlyLintPass for UndocumentedUnsafeBlocks {
+    fn check_expr(&mut self, cx: &EarlyContext<'_>, expr: &Expr) {
+        if_chain! {
+            if let ExprKind::Block(ref block, _) = expr.node;
+            if let BlockCheckMode::Unsafe(UnsafeSource::UserProvided) = block.rules;
+            if !in_macro(block.span);
+            if !self.has_safety_comment(cx, block.span);
+            then {
+                span_help_and_lint(
+                    cx,
+                    UNDOCUMENTED_UNSAFE_BLOCKS,
+                    block.span,
+                    "unsafe block missing a safety comment",
+                    "consider adding a safety comment on the preceding line",
+                );
+            }
+        }
+    }
+
+    fn check_item(&mut self, cx: &EarlyContext<'_>, item: &Item) {
+        if_chain! {
+            if let ItemKind::Impl(Unsafety::Unsafe, ..) = item.node;
+            if !in_macro(item.span);
+            if !self.has_safety_comment(cx, item.span);
+            then {
+                span_help_and_lint(
+                    cx,
+                    UNDOCUMENTED_UNSAFE_BLOCKS,
+                    item.span,
+                    "unsafe impl missing a safety comment",
+                    "consider adding a safety comment on the preceding line",
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use crate::utils::{span_lint, span_lint_and_sugg};
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use std::env;
+use std::path::Path;
+use syntax::ast::{Attribute, Crate, NestedMetaItem};
+use syntax::source_map::DUMMY_SP;
+use syntax::util::lev_distance::lev_distance;
+
+use cargo_metadata;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `feature = ".."` conditions in `cfg` and
+    /// `cfg_attr` attributes naming features that the `Cargo.toml` of the
+    /// crate doesn't define.
+    ///
+    /// **Why is this bad?** The condition is always false, which is most
+    /// likely a misspelled feature name.
+    ///
+    /// **Known problems:** Crate level inner attributes are not checked.
+    ///
+    /// **Example:**
+    /// ```toml
+    /// [features]
+    /// serde = []
+    /// ```
+    /// ```rust
+    /// #[cfg(feature = "sedre")]
+    /// fn serialize() {}
+    /// ```
+    pub UNKNOWN_FEATURES,
+    cargo,
+    "`cfg` conditions on features the crate doesn't define"
+}
+
+#[derive(Default)]
+pub struct UnknownFeatures {
+    /// The features of the crate, including its optional dependencies.
+    /// `None` until read, or if the metadata couldn't be read.
+    features: Option<FxHashSet<String>>,
+}
+
+impl LintPass for UnknownFeatures {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNKNOWN_FEATURES)
+    }
+
+    fn name(&self) -> &'static str {
+        "UnknownFeatures"
+    }
+}
+
+impl EarlyLintPass for UnknownFeatures {
+    fn check_crate(&mut self, cx: &EarlyContext<'_>, _: &Crate) {
+        let mut command = cargo_metadata::MetadataCommand::new();
+        // cargo sets the directory of the package it builds, which may be a
+        // workspace member below the current directory
+        if let Some(dir) = env::var_os("CARGO_MANIFEST_DIR") {
+            command.manifest_path(Path::new(&dir).join("Cargo.toml"));
+        }
+        let metadata = if let Ok(metadata) = command.no_deps().exec() {
+            metadata
+        } else {
+            span_lint(cx, UNKNOWN_FEATURES, DUMMY_SP, "could not read cargo metadata");
+            return;
+        };
+        // cargo tells the compiler which package it builds
+        let name = env::var("CARGO_PKG_NAME").ok();
+        let package = metadata
+            .packages
+            .iter()
+            .find(|package| Some(&package.name) == name.as_ref())
+            .or_else(|| metadata.packages.first());
+        if let Some(package) = package {
+            let mut features: FxHashSet<String> = package.features.keys().cloned().collect();
+            features.extend(
+                package
+                    .dependencies
+                    .iter()
+                    .filter(|dep| dep.optional)
+                    .map(|dep| dep.name.clone()),
+            );
+            // set by `cargo clippy`
+            features.insert("cargo-clippy".to_string());
+            self.features = Some(features);
+        }
+    }
+
+    fn check_attribute(&mut self, cx: &EarlyContext<'_>, attr: &Attribute) {
+        let features = match self.features {
+            Some(ref features) => features,
+            None => return,
+        };
+        if attr.name() != "cfg" && attr.name() != "cfg_attr" {
+            return;
+        }
+        if let Some(predicate) = attr.meta_item_list().as_ref().and_then(|items| items.first()) {
+            check_predicate(cx, features, predicate);
+        }
+    }
+}
+
+fn check_predicate(cx: &EarlyContext<'_>, features: &FxHashSet<String>, item: &NestedMetaItem) {
+    let meta = match item.meta_item() {
+        Some(meta) => meta,
+        None => return,
+    };
+    if meta.name() == "any" || meta.name() == "all" || meta.name() == "not" {
+        for inner in meta.meta_item_list().unwrap_or(&[]) {
+            check_predicate(cx, features, inner);
+        }
+        return;
+    }
+    let feature = match meta.value_str() {
+        Some(feature) if meta.name() == "feature" => feature.as_str(),
+        _ => return,
+    };
+    if features.contains(&*feature) {
+        return;
+    }
+    let msg = format!("unknown feature `{}`", feature);
+    match similar_feature(features, &feature) {
+        Some(similar) => span_lint_and_sugg(
+            cx,
+            UNKNOWN_FEATURES,
+            item.span,
+            &msg,
+            "there is a feature with a similar name",
+            format!("feature = \"{}\"", similar),
+            Applicability::MaybeIncorrect,
+        ),
+        None => span_lint(cx, UNKNOWN_FEATURES, item.span, &msg),
+    }
+}
+
+/// The known feature closest to a misspelled `name`.
+fn similar_feature<'f>(features: &'f FxHashSet<String>, name: &str) -> Option<&'f str> {
+    let max_dist = std::cmp::max(name.len(), 3) / 3;
+    features
+        .iter()
+        .map(|feature| (feature, lev_distance(feature, name)))
+        .filter(|&(_, dist)| dist <= max_dist)
+        .min_by_key(|&(feature, dist)| (dist, feature))
+        .map(|(feature, _)| &feature[..])
+}
@@ -0,0 +1,82 @@
+//! Iteration over the ancestors of a HIR node.
+//!
+//! Use `hir_ancestors` instead of looping over `get_parent_node_by_hir_id` by hand.
+
+use rustc::hir::map::Map;
+use rustc::hir::*;
+use rustc::lint::LateContext;
+
+/// Iterator over the parents of a HIR node, from the innermost to the outermost.
+///
+/// Each item is the `HirId` of the parent together with its `Node`. The node the
+/// iterator was created with is not part of the iteration.
+pub struct HirAncestors<'a, 'tcx: 'a> {
+    map: &'a Map<'tcx>,
+    current: HirId,
+}
+
+/// Returns an iterator over the ancestors of the node with the given `HirId`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let in_loop = hir_ancestors(cx, expr.hir_id).any(|(_, node)| match node {
+///     Node::Expr(e) => matches!(e.node, ExprKind::Loop(..) | ExprKind::While(..)),
+///     _ => false,
+/// });
+/// ```
+pub fn hir_ancestors<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, hir_id: HirId) -> HirAncestors<'a, 'tcx> {
+    HirAncestors {
+        map: cx.tcx.hir(),
+        current: hir_id,
+    }
+}
+
+impl<'a, 'tcx> Iterator for HirAncestors<'a, 'tcx> {
+    type Item = (HirId, Node<'tcx>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let parent_id = self.map.get_parent_node_by_hir_id(self.current);
+        if parent_id == self.current {
+            return None;
+        }
+        self.current = parent_id;
+        self.map.find_by_hir_id(parent_id).map(|node| (parent_id, node))
+    }
+}
+
+impl<'a, 'tcx> HirAncestors<'a, 'tcx> {
+    /// Returns `true` if the node is inside a constant context, i.e. a `const`
+    /// or `static` item or a `const fn` item. Only the innermost item is
+    /// considered, so array lengths in function bodies and `const fn` methods
+    /// don't count.
+    pub fn is_in_const_context(self) -> bool {
+        for (_, node) in self {
+            match node {
+                Node::Item(&Item {
+                    node: ItemKind::Const(..),
+                    ..
+                })
+                | Node::Item(&Item {
+                    node: ItemKind::Static(..),
+                    ..
+                })
+                | Node::TraitItem(&TraitItem {
+                    node: TraitItemKind::Const(..),
+                    ..
+                })
+                | Node::ImplItem(&ImplItem {
+                    node: ImplItemKind::Const(..),
+                    ..
+                }) => return true,
+                Node::Item(&Item {
+                    node: ItemKind::Fn(_, header, ..),
+                    ..
+                }) => return header.constness == Constness::Const,
+                Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) | Node::ForeignItem(_) => return false,
+                _ => {},
+            }
+        }
+        false
+    }
+}
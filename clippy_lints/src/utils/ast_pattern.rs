@@ -0,0 +1,1079 @@
+//! A runtime interpreter for a subset of the `pattern!` syntax.
+//!
+//! The `pattern!` macro compiles patterns into matching functions, so they
+//! cannot come from a configuration file. This module parses the same syntax
+//! at runtime and matches it against a simplified view of the AST, in which
+//! every node has a kind name and a list of children:
+//!
+//! | node               | children                                     |
+//! |--------------------|----------------------------------------------|
+//! | `Block`            | the statements                               |
+//! | `Local`            | the initializer (optional)                   |
+//! | `Expr`, `Semi`     | the expression                               |
+//! | `Block_`           | the block (`ExprKind::Block`)                |
+//! | `If`               | condition, block, else branch (optional)     |
+//! | `IfLet`            | block, else branch (optional)                |
+//! | `While`, `WhileLet`, `ForLoop` | condition/iterator, block        |
+//! | `Loop`, `TryBlock`, `Async` | the block                           |
+//! | `Call`             | callee, arguments                            |
+//! | `MethodCall`       | receiver, arguments                          |
+//! | `Match`            | scrutinee, arm bodies                        |
+//! | `Path`             | the path (not for qualified paths)           |
+//! | `Lit`, `Continue`, `Mac` | none                                   |
+//! | `Item` (statement) | the item                                     |
+//! | `Fn`               | visibility, generics, body                   |
+//! | `Struct`, `Union`  | visibility, generics, fields                 |
+//! | `Enum`             | visibility, generics, variants               |
+//! | `Variant`          | fields                                       |
+//! | `Brace`, `Tuple`   | the fields (`Unit` has none)                 |
+//! | `Field`            | visibility                                   |
+//! | `Impl`             | generics, trait path (optional), items       |
+//! | `Trait`            | visibility, generics, items                  |
+//! | `Method`           | visibility, generics, body                   |
+//! | `Const`            | visibility, value                            |
+//! | `Type`             | visibility                                   |
+//! | `Mod`              | visibility, items                            |
+//! | `Pub`, `Crate`, `Inherited` | none (visibilities)                 |
+//! | `Restricted`       | the path of `pub(super)` or `pub(in path)`   |
+//! | `Generics`         | the parameters: `Lifetime`, `Type`, `Const`  |
+//!
+//! Trait items have no visibility, and their body or value is optional. The
+//! trait path of an `Impl` is matched by a path pattern, e.g.
+//! `Impl(_, "std::default::Default", _*)`, or by `()` for inherent impls.
+//! Other kinds of items only match `_`.
+//!
+//! The remaining expression kinds are named like their `ExprKind` variant and
+//! have their sub-expressions as children. Optional children that are absent
+//! only match `()` or `p?`.
+//!
+//! Like in `pattern!`, the scrutinee of an `if let` is not one of its
+//! children.
+//!
+//! Kinds that are not in the table or among these expression kinds are
+//! rejected when parsing, with a suggestion if one is spelled similarly. So
+//! are lists of children that can't have the number of children of the kind,
+//! e.g. `If(_, _)`.
+//!
+//! Supported syntax: `_` (any node), `()` (an absent child), `Kind(p, ..)`,
+//! `Kind` (any children), `"a::b"` (a path), `p | q`, `p?`, `p*` (zero or more
+//! children), `p#name` and the groups `(p)` and `(p, q, ..)`. `Pattern::captures`
+//! returns the nodes matched by named patterns.
+//!
+//! The postfix operators bind tightest, then `|`, then `,`: `Kind(p, q | r*)`
+//! has the two children `p` and `q | (r*)`. A group of several patterns matches
+//! that many consecutive children, so `Kind((p, q) | r)` has either the two
+//! children `p, q` or the single child `r`, and `Kind((p, q)*)` has any number
+//! of `p, q` pairs.
+//!
+//! Paths are matched textually, as names are not resolved yet: a path matches
+//! if it is a suffix of the pattern's path. `Path("std::mem::drop")` matches
+//! `drop`, `mem::drop` and `::std::mem::drop`, whether or not they refer to
+//! `std::mem::drop`. The `std`, `core` and `alloc` crates are interchangeable.
+//!
+//! For the same reason, there are no type predicates like
+//! `MethodCall(_#recv : ty(Mutex<_>), _*)`: they need the type check
+//! results of a late pass, while the patterns are matched on the AST. They are
+//! rejected when parsing, and type conditions stay in the lints using the
+//! captures.
+
+use std::fmt;
+use syntax::ast::{
+    self, ExprKind, GenericParamKind, ImplItemKind, ItemKind, StmtKind, TraitItemKind, VariantData, VisibilityKind,
+};
+use syntax::ptr::P;
+use syntax::symbol::keywords;
+use syntax::util::lev_distance::lev_distance;
+
+/// The node kinds the interpreter knows, see the table above.
+const KINDS: [&str; 60] = [
+    "AddrOf",
+    "Array",
+    "Assign",
+    "AssignOp",
+    "Async",
+    "Binary",
+    "Block",
+    "Block_",
+    "Box",
+    "Brace",
+    "Break",
+    "Call",
+    "Cast",
+    "Closure",
+    "Const",
+    "Continue",
+    "Crate",
+    "Enum",
+    "Expr",
+    "Field",
+    "Fn",
+    "ForLoop",
+    "Generics",
+    "If",
+    "IfLet",
+    "Impl",
+    "Index",
+    "Inherited",
+    "Item",
+    "Lifetime",
+    "Lit",
+    "Local",
+    "Loop",
+    "Mac",
+    "Match",
+    "Method",
+    "MethodCall",
+    "Mod",
+    "Paren",
+    "Path",
+    "Pub",
+    "Range",
+    "Repeat",
+    "Restricted",
+    "Ret",
+    "Semi",
+    "Struct",
+    "Trait",
+    "Try",
+    "TryBlock",
+    "Tup",
+    "Tuple",
+    "Type",
+    "Unary",
+    "Union",
+    "Unit",
+    "Variant",
+    "While",
+    "WhileLet",
+    "Yield",
+];
+
+/// The minimum and maximum number of children of the nodes of a kind, see the
+/// table above. The maximum is `None` if there is none.
+fn arity(kind: &str) -> (usize, Option<usize>) {
+    match kind {
+        "Lit" | "Continue" | "Mac" | "Pub" | "Crate" | "Inherited" | "Unit" | "Lifetime" => (0, Some(0)),
+        "Path" | "Type" => (0, Some(1)),
+        "Const" => (0, Some(2)),
+        "Box" | "Paren" | "Unary" | "AddrOf" | "Try" | "Field" | "Cast" | "Closure" | "Break" | "Ret" | "Yield"
+        | "Loop" | "Block_" | "TryBlock" | "Async" | "Local" | "Expr" | "Semi" | "Item" | "Variant" | "Restricted" => {
+            (1, Some(1))
+        },
+        "Binary" | "Assign" | "AssignOp" | "Index" | "Repeat" | "Range" | "IfLet" | "While" | "WhileLet"
+        | "ForLoop" => (2, Some(2)),
+        "Method" => (2, Some(3)),
+        "If" | "Fn" | "Union" => (3, Some(3)),
+        "Call" | "MethodCall" | "Match" | "Mod" | "Struct" => (1, None),
+        "Enum" | "Impl" | "Trait" => (2, None),
+        _ => (0, None),
+    }
+}
+
+/// The minimum and maximum number of consecutive children matched by the
+/// patterns of a list of children. The maximum is `None` if there is none.
+fn width(pats: &[Pattern]) -> (usize, Option<usize>) {
+    pats.iter().fold((0, Some(0)), |(min, max), pat| {
+        let (pat_min, pat_max) = match *pat {
+            Pattern::Repeat(_) => (0, None),
+            Pattern::Seq(ref pats) => width(pats),
+            Pattern::Alt(ref pats) => pats.iter().map(|pat| width(std::slice::from_ref(pat))).fold(
+                (std::usize::MAX, Some(0)),
+                |(min, max), (pat_min, pat_max)| {
+                    (
+                        min.min(pat_min),
+                        max.and_then(|max| pat_max.map(|pat_max| max.max(pat_max))),
+                    )
+                },
+            ),
+            _ => (1, Some(1)),
+        };
+        (min + pat_min, max.and_then(|max| pat_max.map(|pat_max| max + pat_max)))
+    })
+}
+
+fn describe_count((min, max): (usize, Option<usize>)) -> String {
+    match max {
+        Some(max) if max == min => min.to_string(),
+        Some(max) => format!("{} to {}", min, max),
+        None => format!("at least {}", min),
+    }
+}
+
+/// A parsed pattern.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Pattern {
+    /// `_`
+    Any,
+    /// `()`
+    Absent,
+    /// `Kind(p, ..)`, or `Kind` without a list of children.
+    Node(String, Option<Vec<Pattern>>),
+    /// `"a::b"`, the segments of a path.
+    Path(Vec<String>),
+    /// `p | q`
+    Alt(Vec<Pattern>),
+    /// `p?`
+    Opt(Box<Pattern>),
+    /// `p*`
+    Repeat(Box<Pattern>),
+    /// `p#name`
+    Named(Box<Pattern>, String),
+    /// `(p, q, ..)`, consecutive children.
+    Seq(Vec<Pattern>),
+}
+
+/// An AST node as seen by the interpreter.
+#[derive(Copy, Clone)]
+pub enum Value<'a> {
+    Expr(&'a ast::Expr),
+    Block(&'a ast::Block),
+    Stmt(&'a ast::Stmt),
+    Path(&'a ast::Path),
+    Item(&'a ast::Item),
+    ImplItem(&'a ast::ImplItem),
+    TraitItem(&'a ast::TraitItem),
+    Variant(&'a ast::Variant),
+    /// The fields of a struct or variant.
+    Fields(&'a ast::VariantData),
+    Field(&'a ast::StructField),
+    Vis(&'a ast::Visibility),
+    Generics(&'a ast::Generics),
+    GenericParam(&'a ast::GenericParam),
+    /// An optional child that is not there, e.g. the else branch of an `if`.
+    Absent,
+}
+
+impl Pattern {
+    /// Parses a pattern, returning a description of the problem if it is
+    /// malformed.
+    pub fn parse(src: &str) -> Result<Self, ParseError> {
+        let mut parser = Parser { src, pos: 0 };
+        let pat = parser.alt()?;
+        parser.skip_ws();
+        if parser.pos < src.len() {
+            return Err(parser.error("expected one of `|`, `?`, `*`, `#` or the end of the pattern"));
+        }
+        Ok(pat)
+    }
+
+    pub fn matches(&self, value: Value<'_>) -> bool {
+        self.match_state(value).is_some()
+    }
+
+    /// Like `matches`, but returns the branches of the `p | q` alternations
+    /// used by the match, as `(alternation, branch)` indices. Alternations
+    /// are numbered in the order they appear in the pattern.
+    pub fn match_alternatives(&self, value: Value<'_>) -> Option<Vec<(usize, usize)>> {
+        self.match_state(value).map(|state| state.used)
+    }
+
+    /// Like `matches`, but returns the nodes matched by the `p#name`
+    /// patterns.
+    pub fn captures<'p, 'a>(&'p self, value: Value<'a>) -> Option<Captures<'p, 'a>> {
+        self.match_state(value).map(|state| Captures(state.captures))
+    }
+
+    fn match_state<'p, 'a>(&'p self, value: Value<'a>) -> Option<State<'p, 'a>> {
+        let mut state = State {
+            used: Vec::new(),
+            captures: Vec::new(),
+        };
+        if self.matches_at(value, 0, &mut state) {
+            Some(state)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of branches of each alternation in the pattern.
+    pub fn alternations(&self) -> Vec<usize> {
+        let mut alternations = Vec::new();
+        self.collect_alternations(&mut alternations);
+        alternations
+    }
+
+    fn collect_alternations(&self, alternations: &mut Vec<usize>) {
+        match *self {
+            Pattern::Any | Pattern::Absent | Pattern::Path(_) => {},
+            Pattern::Opt(ref pat) | Pattern::Repeat(ref pat) | Pattern::Named(ref pat, _) => {
+                pat.collect_alternations(alternations)
+            },
+            Pattern::Alt(ref pats) => {
+                alternations.push(pats.len());
+                for pat in pats {
+                    pat.collect_alternations(alternations);
+                }
+            },
+            Pattern::Node(_, Some(ref pats)) | Pattern::Seq(ref pats) => {
+                for pat in pats {
+                    pat.collect_alternations(alternations);
+                }
+            },
+            Pattern::Node(_, None) => {},
+        }
+    }
+
+    /// Whether the pattern can match a number of children other than one,
+    /// when it is among the children of a node.
+    fn is_group(&self) -> bool {
+        match *self {
+            Pattern::Seq(_) | Pattern::Repeat(_) => true,
+            Pattern::Alt(ref pats) => pats.iter().any(Pattern::is_group),
+            _ => false,
+        }
+    }
+
+    /// The number of alternations in the pattern.
+    fn count_alternations(&self) -> usize {
+        match *self {
+            Pattern::Any | Pattern::Absent | Pattern::Path(_) => 0,
+            Pattern::Opt(ref pat) | Pattern::Repeat(ref pat) | Pattern::Named(ref pat, _) => pat.count_alternations(),
+            Pattern::Alt(ref pats) => 1 + pats.iter().map(Pattern::count_alternations).sum::<usize>(),
+            Pattern::Node(_, Some(ref pats)) | Pattern::Seq(ref pats) => {
+                pats.iter().map(Pattern::count_alternations).sum()
+            },
+            Pattern::Node(_, None) => 0,
+        }
+    }
+
+    /// Matches `value`, pushing the used branches and captured nodes to
+    /// `state`. `first` is the index of the first alternation in this
+    /// pattern. On failure, `state` may contain branches and nodes that were
+    /// tried, callers that backtrack truncate it.
+    fn matches_at<'p, 'a>(&'p self, value: Value<'a>, first: usize, state: &mut State<'p, 'a>) -> bool {
+        let absent = match value {
+            Value::Absent => true,
+            _ => false,
+        };
+        match *self {
+            Pattern::Any => !absent,
+            Pattern::Absent => absent,
+            Pattern::Opt(ref pat) => absent || pat.matches_at(value, first, state),
+            Pattern::Repeat(ref pat) => pat.matches_at(value, first, state),
+            Pattern::Named(ref pat, ref name) => {
+                let matches = pat.matches_at(value, first, state);
+                if matches {
+                    state.captures.push((name.as_str(), value));
+                }
+                matches
+            },
+            Pattern::Alt(ref pats) => {
+                let len = state.len();
+                let mut next = first + 1;
+                for (branch, pat) in pats.iter().enumerate() {
+                    if pat.matches_at(value, next, state) {
+                        state.used.push((first, branch));
+                        return true;
+                    }
+                    state.truncate(len);
+                    next += pat.count_alternations();
+                }
+                false
+            },
+            Pattern::Path(ref segments) => match value {
+                Value::Path(path) => path_matches(path, segments),
+                _ => false,
+            },
+            Pattern::Node(ref name, ref children) => {
+                let (kind, values) = match view(value) {
+                    Some(view) => view,
+                    None => return false,
+                };
+                kind == name
+                    && children
+                        .as_ref()
+                        .map_or(true, |pats| matches_seq(&numbered(pats, first), &values, state))
+            },
+            Pattern::Seq(ref pats) => matches_seq(&numbered(pats, first), &[value], state),
+        }
+    }
+}
+
+/// The nodes matched by the `p#name` patterns, see `Pattern::captures`.
+pub struct Captures<'p, 'a>(Vec<(&'p str, Value<'a>)>);
+
+impl<'p, 'a> Captures<'p, 'a> {
+    /// Returns the node matched by `p#name`. If the pattern is repeated, the
+    /// last match is returned.
+    pub fn get(&self, name: &str) -> Option<Value<'a>> {
+        self.0.iter().rev().find(|(n, _)| *n == name).map(|(_, value)| *value)
+    }
+
+    pub fn expr(&self, name: &str) -> Option<&'a ast::Expr> {
+        match self.get(name)? {
+            Value::Expr(expr) => Some(expr),
+            _ => None,
+        }
+    }
+
+    pub fn stmt(&self, name: &str) -> Option<&'a ast::Stmt> {
+        match self.get(name)? {
+            Value::Stmt(stmt) => Some(stmt),
+            _ => None,
+        }
+    }
+}
+
+/// The branches and nodes of a match in progress.
+struct State<'p, 'a> {
+    used: Vec<(usize, usize)>,
+    captures: Vec<(&'p str, Value<'a>)>,
+}
+
+impl<'p, 'a> State<'p, 'a> {
+    fn len(&self) -> (usize, usize) {
+        (self.used.len(), self.captures.len())
+    }
+
+    fn truncate(&mut self, (used, captures): (usize, usize)) {
+        self.used.truncate(used);
+        self.captures.truncate(captures);
+    }
+}
+
+/// Pairs each of `pats` with the index of its first alternation, counting
+/// from `first`.
+fn numbered(pats: &[Pattern], first: usize) -> Vec<(&Pattern, usize)> {
+    let mut next = first;
+    pats.iter()
+        .map(|pat| {
+            let numbered = (pat, next);
+            next += pat.count_alternations();
+            numbered
+        })
+        .collect()
+}
+
+/// Matches a list of patterns, each with the index of its first alternation,
+/// against a list of children. `p*` consumes any number of children, and
+/// groups and alternations of groups as many as they contain.
+fn matches_seq<'p, 'a>(pats: &[(&'p Pattern, usize)], values: &[Value<'a>], state: &mut State<'p, 'a>) -> bool {
+    let (&(pat, first), rest) = match pats.split_first() {
+        Some(split) => split,
+        None => return values.is_empty(),
+    };
+    let len = state.len();
+    match *pat {
+        Pattern::Repeat(ref inner) => {
+            if matches_seq(rest, values, state) {
+                return true;
+            }
+            // every repetition consumes at least one child, and only groups
+            // consume more
+            let max = if inner.is_group() {
+                values.len()
+            } else {
+                values.len().min(1)
+            };
+            for end in 1..=max {
+                state.truncate(len);
+                if matches_seq(&[(&**inner, first)], &values[..end], state) && matches_seq(pats, &values[end..], state)
+                {
+                    return true;
+                }
+            }
+            false
+        },
+        Pattern::Seq(ref items) => {
+            let mut pats = numbered(items, first);
+            pats.extend_from_slice(rest);
+            matches_seq(&pats, values, state)
+        },
+        Pattern::Alt(ref branches) if pat.is_group() => {
+            let mut next = first + 1;
+            for (branch, pat) in branches.iter().enumerate() {
+                let mut pats = vec![(pat, next)];
+                pats.extend_from_slice(rest);
+                if matches_seq(&pats, values, state) {
+                    state.used.push((first, branch));
+                    return true;
+                }
+                state.truncate(len);
+                next += pat.count_alternations();
+            }
+            false
+        },
+        _ => !values.is_empty() && pat.matches_at(values[0], first, state) && matches_seq(rest, &values[1..], state),
+    }
+}
+
+/// Checks whether the written `path` is a suffix of the pattern's path.
+fn path_matches(path: &ast::Path, pattern: &[String]) -> bool {
+    let written: Vec<_> = path
+        .segments
+        .iter()
+        .filter(|segment| segment.ident.name != keywords::PathRoot.name())
+        .map(|segment| segment.ident.as_str())
+        .collect();
+    let is_std = |name: &str| name == "std" || name == "core" || name == "alloc";
+    !written.is_empty()
+        && written.len() <= pattern.len()
+        && written
+            .iter()
+            .rev()
+            .zip(pattern.iter().rev())
+            .all(|(w, p)| **w == **p || (is_std(w) && is_std(p)))
+}
+
+fn opt(expr: &Option<P<ast::Expr>>) -> Value<'_> {
+    expr.as_ref().map_or(Value::Absent, |e| Value::Expr(e))
+}
+
+fn exprs(exprs: &[P<ast::Expr>]) -> impl Iterator<Item = Value<'_>> {
+    exprs.iter().map(|e| Value::Expr(e))
+}
+
+/// Returns the kind name and children of a node. Expressions the interpreter
+/// doesn't know about have an empty kind name, so only `_` matches them.
+fn view(value: Value<'_>) -> Option<(&'static str, Vec<Value<'_>>)> {
+    let view = match value {
+        Value::Absent | Value::Path(_) => return None,
+        Value::Block(block) => ("Block", block.stmts.iter().map(Value::Stmt).collect()),
+        Value::Item(item) => {
+            let (vis, generics) = (Value::Vis(&item.vis), Value::Generics);
+            match item.node {
+                ItemKind::Fn(_, _, ref g, ref body) => ("Fn", vec![vis, generics(g), Value::Block(body)]),
+                ItemKind::Struct(ref data, ref g) => ("Struct", vec![vis, generics(g), Value::Fields(data)]),
+                ItemKind::Union(ref data, ref g) => ("Union", vec![vis, generics(g), Value::Fields(data)]),
+                ItemKind::Enum(ref def, ref g) => (
+                    "Enum",
+                    vec![vis, generics(g)]
+                        .into_iter()
+                        .chain(def.variants.iter().map(Value::Variant))
+                        .collect(),
+                ),
+                ItemKind::Impl(_, _, _, ref g, ref trait_ref, _, ref items) => (
+                    "Impl",
+                    vec![
+                        generics(g),
+                        trait_ref.as_ref().map_or(Value::Absent, |t| Value::Path(&t.path)),
+                    ]
+                    .into_iter()
+                    .chain(items.iter().map(Value::ImplItem))
+                    .collect(),
+                ),
+                ItemKind::Trait(_, _, ref g, _, ref items) => (
+                    "Trait",
+                    vec![vis, generics(g)]
+                        .into_iter()
+                        .chain(items.iter().map(Value::TraitItem))
+                        .collect(),
+                ),
+                ItemKind::Mod(ref module) => (
+                    "Mod",
+                    Some(vis)
+                        .into_iter()
+                        .chain(module.items.iter().map(|item| Value::Item(item)))
+                        .collect(),
+                ),
+                _ => ("", vec![]),
+            }
+        },
+        Value::ImplItem(item) => {
+            let vis = Value::Vis(&item.vis);
+            match item.node {
+                ImplItemKind::Method(_, ref body) => {
+                    ("Method", vec![vis, Value::Generics(&item.generics), Value::Block(body)])
+                },
+                ImplItemKind::Const(_, ref e) => ("Const", vec![vis, Value::Expr(e)]),
+                ImplItemKind::Type(_) => ("Type", vec![vis]),
+                _ => ("", vec![]),
+            }
+        },
+        Value::TraitItem(item) => match item.node {
+            TraitItemKind::Method(_, ref body) => (
+                "Method",
+                vec![
+                    Value::Generics(&item.generics),
+                    body.as_ref().map_or(Value::Absent, |body| Value::Block(body)),
+                ],
+            ),
+            TraitItemKind::Const(_, ref e) => ("Const", vec![opt(e)]),
+            TraitItemKind::Type(..) => ("Type", vec![]),
+            TraitItemKind::Macro(_) => ("", vec![]),
+        },
+        Value::Variant(variant) => ("Variant", vec![Value::Fields(&variant.node.data)]),
+        Value::Fields(data) => match *data {
+            VariantData::Struct(ref fields, _) => ("Brace", fields.iter().map(Value::Field).collect()),
+            VariantData::Tuple(ref fields, _) => ("Tuple", fields.iter().map(Value::Field).collect()),
+            VariantData::Unit(_) => ("Unit", vec![]),
+        },
+        Value::Field(field) => ("Field", vec![Value::Vis(&field.vis)]),
+        Value::Vis(vis) => match vis.node {
+            VisibilityKind::Public => ("Pub", vec![]),
+            VisibilityKind::Crate(_) => ("Crate", vec![]),
+            VisibilityKind::Restricted { ref path, .. } => ("Restricted", vec![Value::Path(path)]),
+            VisibilityKind::Inherited => ("Inherited", vec![]),
+        },
+        Value::Generics(generics) => ("Generics", generics.params.iter().map(Value::GenericParam).collect()),
+        Value::GenericParam(param) => match param.kind {
+            GenericParamKind::Lifetime => ("Lifetime", vec![]),
+            GenericParamKind::Type { .. } => ("Type", vec![]),
+            GenericParamKind::Const { .. } => ("Const", vec![]),
+        },
+        Value::Stmt(stmt) => match stmt.node {
+            StmtKind::Local(ref local) => ("Local", vec![opt(&local.init)]),
+            StmtKind::Item(ref item) => ("Item", vec![Value::Item(item)]),
+            StmtKind::Expr(ref e) => ("Expr", vec![Value::Expr(e)]),
+            StmtKind::Semi(ref e) => ("Semi", vec![Value::Expr(e)]),
+            StmtKind::Mac(_) => ("Mac", vec![]),
+        },
+        Value::Expr(expr) => match expr.node {
+            ExprKind::Lit(_) => ("Lit", vec![]),
+            ExprKind::Path(None, ref path) => ("Path", vec![Value::Path(path)]),
+            ExprKind::Path(Some(_), _) => ("Path", vec![]),
+            ExprKind::Continue(_) => ("Continue", vec![]),
+            ExprKind::Mac(_) => ("Mac", vec![]),
+            ExprKind::Box(ref e) => ("Box", vec![Value::Expr(e)]),
+            ExprKind::Paren(ref e) => ("Paren", vec![Value::Expr(e)]),
+            ExprKind::Unary(_, ref e) => ("Unary", vec![Value::Expr(e)]),
+            ExprKind::AddrOf(_, ref e) => ("AddrOf", vec![Value::Expr(e)]),
+            ExprKind::Try(ref e) => ("Try", vec![Value::Expr(e)]),
+            ExprKind::Field(ref e, _) => ("Field", vec![Value::Expr(e)]),
+            ExprKind::Cast(ref e, _) => ("Cast", vec![Value::Expr(e)]),
+            ExprKind::Type(ref e, _) => ("Type", vec![Value::Expr(e)]),
+            ExprKind::Binary(_, ref l, ref r) => ("Binary", vec![Value::Expr(l), Value::Expr(r)]),
+            ExprKind::Assign(ref l, ref r) => ("Assign", vec![Value::Expr(l), Value::Expr(r)]),
+            ExprKind::AssignOp(_, ref l, ref r) => ("AssignOp", vec![Value::Expr(l), Value::Expr(r)]),
+            ExprKind::Index(ref l, ref r) => ("Index", vec![Value::Expr(l), Value::Expr(r)]),
+            ExprKind::Repeat(ref e, ref count) => ("Repeat", vec![Value::Expr(e), Value::Expr(&count.value)]),
+            ExprKind::Range(ref start, ref end, _) => ("Range", vec![opt(start), opt(end)]),
+            ExprKind::Break(_, ref e) => ("Break", vec![opt(e)]),
+            ExprKind::Ret(ref e) => ("Ret", vec![opt(e)]),
+            ExprKind::Yield(ref e) => ("Yield", vec![opt(e)]),
+            ExprKind::Array(ref es) => ("Array", exprs(es).collect()),
+            ExprKind::Tup(ref es) => ("Tup", exprs(es).collect()),
+            ExprKind::MethodCall(_, ref args) => ("MethodCall", exprs(args).collect()),
+            ExprKind::Call(ref func, ref args) => {
+                ("Call", Some(Value::Expr(func)).into_iter().chain(exprs(args)).collect())
+            },
+            ExprKind::Struct(_, ref fields, ref base) => (
+                "Struct",
+                fields
+                    .iter()
+                    .map(|f| Value::Expr(&f.expr))
+                    .chain(Some(opt(base)))
+                    .collect(),
+            ),
+            ExprKind::Match(ref e, ref arms) => (
+                "Match",
+                Some(Value::Expr(e))
+                    .into_iter()
+                    .chain(arms.iter().map(|arm| Value::Expr(&arm.body)))
+                    .collect(),
+            ),
+            ExprKind::Closure(.., ref body, _) => ("Closure", vec![Value::Expr(body)]),
+            ExprKind::If(ref cond, ref then, ref else_) => {
+                ("If", vec![Value::Expr(cond), Value::Block(then), opt(else_)])
+            },
+            ExprKind::IfLet(_, _, ref then, ref else_) => ("IfLet", vec![Value::Block(then), opt(else_)]),
+            ExprKind::While(ref cond, ref body, _) => ("While", vec![Value::Expr(cond), Value::Block(body)]),
+            ExprKind::WhileLet(_, ref e, ref body, _) => ("WhileLet", vec![Value::Expr(e), Value::Block(body)]),
+            ExprKind::ForLoop(_, ref e, ref body, _) => ("ForLoop", vec![Value::Expr(e), Value::Block(body)]),
+            ExprKind::Loop(ref body, _) => ("Loop", vec![Value::Block(body)]),
+            ExprKind::Block(ref block, _) => ("Block_", vec![Value::Block(block)]),
+            ExprKind::TryBlock(ref block) => ("TryBlock", vec![Value::Block(block)]),
+            ExprKind::Async(_, _, ref block) => ("Async", vec![Value::Block(block)]),
+            _ => ("", vec![]),
+        },
+    };
+    Some(view)
+}
+
+/// A malformed pattern.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    /// The byte range of the offending token in the pattern.
+    pub start: usize,
+    pub end: usize,
+    pub msg: String,
+    /// The node kind that was probably meant, for a misspelled one.
+    pub suggestion: Option<&'static str>,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "at position {}: {}", self.start, self.msg)?;
+        if let Some(suggestion) = self.suggestion {
+            write!(f, ", did you mean `{}`?", suggestion)?;
+        }
+        Ok(())
+    }
+}
+
+/// The known node kind closest to a misspelled `name`.
+fn similar_kind(name: &str) -> Option<&'static str> {
+    let max_dist = std::cmp::max(name.len(), 3) / 3;
+    KINDS
+        .iter()
+        .find(|kind| kind.eq_ignore_ascii_case(name))
+        .or_else(|| {
+            KINDS
+                .iter()
+                .map(|kind| (kind, lev_distance(kind, name)))
+                .filter(|&(_, dist)| dist <= max_dist)
+                .min_by_key(|&(_, dist)| dist)
+                .map(|(kind, _)| kind)
+        })
+        .cloned()
+}
+
+/// A recursive descent parser for patterns.
+///
+/// ```text
+/// alt     = postfix ("|" postfix)*
+/// postfix = primary ("?" | "*" | "#" ident)*
+/// primary = "_" | "(" ")" | "(" alt ("," alt)* ")" | string | ident ("(" (alt ("," alt)*)? ")")?
+/// string  = '"' ident ("::" ident)* '"'
+/// ```
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_ws(&mut self) {
+        let rest = &self.src[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Consumes `c` if it is the next non-whitespace character.
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_ws();
+        if self.src[self.pos..].starts_with(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// The length of the identifier at the current position.
+    fn ident_len(&self) -> usize {
+        let rest = &self.src[self.pos..];
+        rest.find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or_else(|| rest.len())
+    }
+
+    /// An error about the token at the current position.
+    fn error(&self, msg: &str) -> ParseError {
+        let len = match self.ident_len() {
+            0 => self.src[self.pos..].chars().next().map_or(0, char::len_utf8),
+            len => len,
+        };
+        ParseError {
+            start: self.pos,
+            end: self.pos + len,
+            msg: msg.to_string(),
+            suggestion: None,
+        }
+    }
+
+    fn ident(&mut self) -> Result<&'a str, ParseError> {
+        self.skip_ws();
+        let len = self.ident_len();
+        if len == 0 {
+            return Err(self.error("expected one of `_`, `(`, `\"` or a node kind"));
+        }
+        let ident = &self.src[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(ident)
+    }
+
+    fn alt(&mut self) -> Result<Pattern, ParseError> {
+        let mut alts = vec![self.postfix()?];
+        while self.eat('|') {
+            alts.push(self.postfix()?);
+        }
+        Ok(if alts.len() == 1 {
+            alts.remove(0)
+        } else {
+            Pattern::Alt(alts)
+        })
+    }
+
+    fn postfix(&mut self) -> Result<Pattern, ParseError> {
+        self.skip_ws();
+        let start = self.pos;
+        let mut pat = self.primary()?;
+        loop {
+            let is_seq = match pat {
+                Pattern::Seq(_) => true,
+                _ => false,
+            };
+            let group_error = |msg: &str| ParseError {
+                start,
+                end: start + 1,
+                msg: msg.to_string(),
+                suggestion: None,
+            };
+            pat = if self.eat('?') {
+                if is_seq {
+                    return Err(group_error("`?` applies to a single child, not to this group"));
+                }
+                Pattern::Opt(Box::new(pat))
+            } else if self.eat('*') {
+                Pattern::Repeat(Box::new(pat))
+            } else if self.eat('#') {
+                if is_seq {
+                    return Err(group_error("this group has several children to name"));
+                }
+                let name = self.ident()?.to_string();
+                Pattern::Named(Box::new(pat), name)
+            } else if self.src[self.pos..].starts_with(':') {
+                return Err(self.error("type predicates are not supported, patterns are matched before type checking"));
+            } else {
+                return Ok(pat);
+            };
+        }
+    }
+
+    /// Parses the patterns of a group or of the children of a node after the
+    /// opening parenthesis at `open`, up to the closing one.
+    fn list(&mut self, open: usize, what: &str) -> Result<Vec<Pattern>, ParseError> {
+        let mut pats = vec![self.alt()?];
+        loop {
+            if self.eat(',') {
+                pats.push(self.alt()?);
+            } else if self.eat(')') {
+                return Ok(pats);
+            } else {
+                self.skip_ws();
+                return Err(self.error(&format!(
+                    "expected one of `,`, `|` or `)` in the {} opened at position {}",
+                    what, open
+                )));
+            }
+        }
+    }
+
+    fn primary(&mut self) -> Result<Pattern, ParseError> {
+        self.skip_ws();
+        let open = self.pos;
+        if self.eat('(') {
+            if self.eat(')') {
+                return Ok(Pattern::Absent);
+            }
+            let mut pats = self.list(open, "group")?;
+            return Ok(if pats.len() == 1 {
+                pats.remove(0)
+            } else {
+                Pattern::Seq(pats)
+            });
+        }
+        if self.eat('"') {
+            let start = self.pos;
+            let len = match self.src[start..].find('"') {
+                Some(len) => len,
+                None => {
+                    self.pos = open;
+                    return Err(self.error("unterminated path"));
+                },
+            };
+            self.pos += len + 1;
+            let segments: Vec<String> = self.src[start..start + len].split("::").map(str::to_string).collect();
+            if segments
+                .iter()
+                .any(|segment| segment.is_empty() || !segment.chars().all(|c| c.is_alphanumeric() || c == '_'))
+            {
+                return Err(ParseError {
+                    start,
+                    end: start + len,
+                    msg: "invalid path".to_string(),
+                    suggestion: None,
+                });
+            }
+            return Ok(Pattern::Path(segments));
+        }
+        let start = self.pos;
+        match self.ident()? {
+            "_" => Ok(Pattern::Any),
+            name if !KINDS.contains(&name) => Err(ParseError {
+                start,
+                end: self.pos,
+                msg: format!("unknown node kind `{}`", name),
+                suggestion: similar_kind(name),
+            }),
+            name => {
+                self.skip_ws();
+                let open = self.pos;
+                let children = if self.eat('(') {
+                    if self.eat(')') {
+                        Some(Vec::new())
+                    } else {
+                        Some(self.list(open, &format!("children of `{}`", name))?)
+                    }
+                } else {
+                    None
+                };
+                if let Some(ref pats) = children {
+                    let (min, max) = arity(name);
+                    let (pats_min, pats_max) = width(pats);
+                    if max.map_or(false, |max| pats_min > max) || pats_max.map_or(false, |pats_max| pats_max < min) {
+                        return Err(ParseError {
+                            start,
+                            end: self.pos,
+                            msg: format!(
+                                "wrong number of children for `{}`: expected {}, found {}",
+                                name,
+                                describe_count((min, max)),
+                                describe_count((pats_min, pats_max))
+                            ),
+                            suggestion: None,
+                        });
+                    }
+                }
+                Ok(Pattern::Node(name.to_string(), children))
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Pattern::*;
+    use super::*;
+
+    fn node(name: &str, children: Vec<Pattern>) -> Pattern {
+        Node(name.to_string(), Some(children))
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Pattern::parse("If(_#check, Block(Expr(_) | Semi(_)), ())"),
+            Ok(node(
+                "If",
+                vec![
+                    Named(Box::new(Any), "check".to_string()),
+                    node(
+                        "Block",
+                        vec![Alt(vec![node("Expr", vec![Any]), node("Semi", vec![Any])])]
+                    ),
+                    Absent,
+                ]
+            ))
+        );
+        assert_eq!(
+            Pattern::parse("Call(Path, _*)"),
+            Ok(node(
+                "Call",
+                vec![Node("Path".to_string(), None), Repeat(Box::new(Any))]
+            ))
+        );
+        assert_eq!(
+            Pattern::parse(r#"Call(Path("std::mem::drop"), _)"#),
+            Ok(node(
+                "Call",
+                vec![
+                    node(
+                        "Path",
+                        vec![Path(vec!["std".to_string(), "mem".to_string(), "drop".to_string()])]
+                    ),
+                    Any
+                ]
+            ))
+        );
+        assert!(Pattern::parse(r#"Path("std::")"#).is_err());
+        assert!(Pattern::parse(r#"Path("std"#).is_err());
+        assert_eq!(Pattern::parse("Loop(_?)"), Ok(node("Loop", vec![Opt(Box::new(Any))])));
+        assert!(Pattern::parse("If(_,").is_err());
+        assert!(Pattern::parse("Lit Lit").is_err());
+    }
+
+    #[test]
+    fn parse_groups() {
+        let lit = || Node("Lit".to_string(), None);
+        let path = || Node("Path".to_string(), None);
+        let call = || Node("Call".to_string(), None);
+        // `|` binds tighter than `,`, postfix operators tighter than `|`
+        assert_eq!(
+            Pattern::parse("Tup(Lit, Path | Call*)"),
+            Ok(node("Tup", vec![lit(), Alt(vec![path(), Repeat(Box::new(call()))])]))
+        );
+        assert_eq!(
+            Pattern::parse("Tup((Lit, Path) | Call)"),
+            Ok(node("Tup", vec![Alt(vec![Seq(vec![lit(), path()]), call()])]))
+        );
+        assert_eq!(
+            Pattern::parse("Tup((Lit, Path | Call)*)"),
+            Ok(node(
+                "Tup",
+                vec![Repeat(Box::new(Seq(vec![lit(), Alt(vec![path(), call()])])))]
+            ))
+        );
+        assert_eq!(Pattern::parse("(Lit | Path)"), Ok(Alt(vec![lit(), path()])));
+        assert!(Pattern::parse("Tup((Lit, Path)?)").is_err());
+        assert!(Pattern::parse("Tup((Lit, Path)#pair)").is_err());
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |src| Pattern::parse(src).unwrap_err().to_string();
+        assert_eq!(
+            error("Tup(Lit Path | Call)"),
+            "at position 8: expected one of `,`, `|` or `)` in the children of `Tup` opened at position 3"
+        );
+        assert_eq!(
+            error("Tup((Lit, Path | Call)"),
+            "at position 22: expected one of `,`, `|` or `)` in the children of `Tup` opened at position 3"
+        );
+        assert_eq!(
+            error("Tup(Lit, )"),
+            "at position 9: expected one of `_`, `(`, `\"` or a node kind"
+        );
+        assert_eq!(
+            error("Lit Lit"),
+            "at position 4: expected one of `|`, `?`, `*`, `#` or the end of the pattern"
+        );
+        assert_eq!(
+            Pattern::parse("Call(Pth, _)"),
+            Err(ParseError {
+                start: 5,
+                end: 8,
+                msg: "unknown node kind `Pth`".to_string(),
+                suggestion: Some("Path"),
+            })
+        );
+        assert_eq!(
+            error("methodcall"),
+            "at position 0: unknown node kind `methodcall`, did you mean `MethodCall`?"
+        );
+        assert_eq!(error("Foo"), "at position 0: unknown node kind `Foo`");
+        assert_eq!(
+            error("Expr(If(_, _))"),
+            "at position 5: wrong number of children for `If`: expected 3, found 2"
+        );
+        assert_eq!(
+            error("IfLet(_, _, _?)"),
+            "at position 0: wrong number of children for `IfLet`: expected 2, found 3"
+        );
+        assert_eq!(
+            error("Call()"),
+            "at position 0: wrong number of children for `Call`: expected at least 1, found 0"
+        );
+        assert_eq!(
+            error("Loop((_, _), _*)"),
+            "at position 0: wrong number of children for `Loop`: expected 1, found at least 2"
+        );
+        assert_eq!(
+            error("MethodCall(_#recv : ty(Mutex<_>))"),
+            "at position 18: type predicates are not supported, patterns are matched before type checking"
+        );
+        assert!(Pattern::parse("IfLet(_, _?)").is_ok());
+        assert!(Pattern::parse("Method(_, (_, _) | _)").is_ok());
+    }
+
+    #[test]
+    fn alternations() {
+        let pat = Pattern::parse("If(_, Block(Expr(Lit | Path) | Semi(_)), _?) | Loop(Block(_*) | _)").unwrap();
+        assert_eq!(pat.alternations(), vec![2, 2, 2, 2]);
+        assert_eq!(pat.count_alternations(), 4);
+        assert_eq!(
+            Pattern::parse("Call(Path, _*)").unwrap().alternations(),
+            Vec::<usize>::new()
+        );
+        let pat = Pattern::parse("Tup(((Lit | Path), Call) | Ret, Lit | Path)").unwrap();
+        assert_eq!(pat.alternations(), vec![2, 2, 2]);
+    }
+}
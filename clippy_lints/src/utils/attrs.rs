@@ -17,6 +17,8 @@ pub const BUILTIN_ATTRIBUTES: &[(&str, DeprecationStatus)] = &[
     ("author", DeprecationStatus::None),
     ("cyclomatic_complexity", DeprecationStatus::None),
     ("dump", DeprecationStatus::None),
+    ("generated", DeprecationStatus::None),
+    ("infinite", DeprecationStatus::None),
 ];
 
 pub struct LimitStack {
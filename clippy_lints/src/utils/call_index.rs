@@ -0,0 +1,103 @@
+//! An index of the uses of the local functions of a crate, for lints that
+//! need to know all call sites of a function, e.g. to judge whether changing
+//! its signature is local.
+
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::intravisit::{walk_body, walk_crate, walk_expr, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::LateContext;
+use rustc::ty::TypeckTables;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use syntax_pos::Span;
+
+/// The calls of the local functions and methods of a crate.
+#[derive(Default)]
+pub struct CallIndex {
+    /// The call expressions, by the called function.
+    calls: FxHashMap<DefId, Vec<Span>>,
+    /// Functions used other than by calling them, e.g. as `map(f)`.
+    used_as_value: FxHashSet<DefId>,
+}
+
+impl CallIndex {
+    /// Visits all bodies of the crate. This is expensive, so it should only be
+    /// done once, e.g. in `check_crate_post`, and only if there is something
+    /// to look up.
+    pub fn new<'a, 'tcx>(cx: &'a LateContext<'a, 'tcx>) -> Self {
+        let mut visitor = CallVisitor {
+            cx,
+            tables: cx.tables,
+            index: Self::default(),
+        };
+        walk_crate(&mut visitor, cx.tcx.hir().krate());
+        visitor.index
+    }
+
+    /// The calls of a function, in the order they appear in the crate.
+    pub fn calls(&self, def_id: DefId) -> &[Span] {
+        self.calls.get(&def_id).map_or(&[][..], Vec::as_slice)
+    }
+
+    /// Whether a function is used other than by calling it, so that its
+    /// calls aren't all of its uses.
+    pub fn is_used_as_value(&self, def_id: DefId) -> bool {
+        self.used_as_value.contains(&def_id)
+    }
+}
+
+fn local_fn(def: Def) -> Option<DefId> {
+    match def {
+        Def::Fn(def_id) | Def::Method(def_id) if def_id.is_local() => Some(def_id),
+        _ => None,
+    }
+}
+
+struct CallVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    tables: &'a TypeckTables<'tcx>,
+    index: CallIndex,
+}
+
+impl<'a, 'tcx: 'a> Visitor<'tcx> for CallVisitor<'a, 'tcx> {
+    fn visit_body(&mut self, body: &'tcx Body) {
+        let prev_tables = self.tables;
+        self.tables = self.cx.tcx.body_tables(body.id());
+        walk_body(self, body);
+        self.tables = prev_tables;
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        match expr.node {
+            ExprKind::Call(ref callee, ref args) => {
+                if let ExprKind::Path(ref qpath) = callee.node {
+                    if let Some(def_id) = local_fn(self.tables.qpath_def(qpath, callee.hir_id)) {
+                        self.index.calls.entry(def_id).or_insert_with(Vec::new).push(expr.span);
+                        // the callee isn't a use as a value
+                        for arg in args {
+                            self.visit_expr(arg);
+                        }
+                        return;
+                    }
+                }
+            },
+            ExprKind::MethodCall(..) => {
+                let def = self.tables.type_dependent_defs().get(expr.hir_id).cloned();
+                if let Some(def_id) = def.and_then(local_fn) {
+                    self.index.calls.entry(def_id).or_insert_with(Vec::new).push(expr.span);
+                }
+            },
+            ExprKind::Path(ref qpath) => {
+                if let Some(def_id) = local_fn(self.tables.qpath_def(qpath, expr.hir_id)) {
+                    self.index.used_as_value.insert(def_id);
+                }
+            },
+            _ => {},
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::All(&self.cx.tcx.hir())
+    }
+}
@@ -0,0 +1,63 @@
+//! Support for only reporting lints in changed files, see `--dirty-only` and
+//! `--changed-files` in `cargo clippy --help`.
+//!
+//! `cargo clippy` passes the changed files to the driver in the
+//! `CLIPPY_CHANGED_FILES` environment variable. The whole crate is still
+//! checked, but lints whose primary span is in another file are not emitted.
+
+use lazy_static::lazy_static;
+use rustc::lint::LintContext;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use syntax::source_map::{FileName, Span};
+
+lazy_static! {
+    static ref CHANGED_FILES: Mutex<Option<ChangedFiles>> = Mutex::new(None);
+}
+
+struct ChangedFiles {
+    /// The canonicalized paths of the changed files.
+    files: FxHashSet<PathBuf>,
+    /// Whether a path from the source map is one of the changed files.
+    cache: FxHashMap<PathBuf, bool>,
+}
+
+/// Sets the changed files. Lints are emitted everywhere if this is never
+/// called.
+pub fn init(files: impl IntoIterator<Item = PathBuf>) {
+    *CHANGED_FILES.lock().expect("no threading here") = Some(ChangedFiles {
+        files: files.into_iter().collect(),
+        cache: FxHashMap::default(),
+    });
+}
+
+/// Forgets the changed files, emitting lints everywhere again.
+pub fn reset() {
+    *CHANGED_FILES.lock().expect("no threading here") = None;
+}
+
+/// Checks whether `span` is outside of the changed files. Spans from macro
+/// expansions are attributed to the outermost macro call.
+pub fn is_unchanged<'a, T: LintContext<'a>>(cx: &T, span: Span) -> bool {
+    let mut changed_files = CHANGED_FILES.lock().expect("no threading here");
+    let ChangedFiles {
+        ref files,
+        ref mut cache,
+    } = match *changed_files {
+        Some(ref mut changed_files) => changed_files,
+        None => return false,
+    };
+    match cx.sess().source_map().span_to_filename(span.source_callsite()) {
+        FileName::Real(path) => {
+            if let Some(&changed) = cache.get(&path) {
+                return !changed;
+            }
+            let changed = fs::canonicalize(&path).map_or(false, |canonical| files.contains(&canonical));
+            cache.insert(path, changed);
+            !changed
+        },
+        _ => true,
+    }
+}
@@ -0,0 +1,403 @@
+//! Finding the comments in source code, which the AST doesn't contain (except
+//! for doc comments, as attributes without the exact source text).
+//!
+//! `scan` finds the comments of a piece of source code, skipping string and
+//! character literals. `CommentCache` scans each source file once for lint
+//! passes looking at the comments around many items, and locates the comments
+//! preceding a piece of code. `blank_lines` finds the blank lines separating
+//! attributes and doc comments from their item.
+
+use rustc::lint::LintContext;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::sync::Lrc;
+use std::rc::Rc;
+use syntax::ast::AttrStyle;
+use syntax::source_map::{BytePos, Span, DUMMY_SP};
+use syntax_pos::Pos;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CommentKind {
+    /// `// ..`, until the end of the line
+    Line,
+    /// `/* .. */`, possibly nested
+    Block,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Comment {
+    pub kind: CommentKind,
+    /// `Outer` for `///` and `/**`, `Inner` for `//!` and `/*!`, `None` for
+    /// comments that aren't doc comments, including `////` and `/***`.
+    pub doc: Option<AttrStyle>,
+    /// The byte offset of the comment in the scanned source.
+    pub start: usize,
+    /// The byte offset after the comment, excluding the newline ending a line
+    /// comment.
+    pub end: usize,
+}
+
+/// Finds the comments in `src`. Unterminated comments and literals extend to
+/// the end of `src`.
+pub fn scan(src: &str) -> Vec<Comment> {
+    let bytes = src.as_bytes();
+    let mut comments = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                let end = src[i..].find('\n').map_or(src.len(), |len| i + len);
+                let doc = match &bytes[i + 2..end] {
+                    [b'/', b'/', ..] => None,
+                    [b'/', ..] => Some(AttrStyle::Outer),
+                    [b'!', ..] => Some(AttrStyle::Inner),
+                    _ => None,
+                };
+                comments.push(Comment {
+                    kind: CommentKind::Line,
+                    doc,
+                    start: i,
+                    end,
+                });
+                i = end;
+            },
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                let end = block_comment_end(bytes, i);
+                let doc = match &bytes[i + 2..end] {
+                    [b'*', b'*', ..] | [b'*', b'/'] => None,
+                    [b'*', ..] => Some(AttrStyle::Outer),
+                    [b'!', ..] => Some(AttrStyle::Inner),
+                    _ => None,
+                };
+                comments.push(Comment {
+                    kind: CommentKind::Block,
+                    doc,
+                    start: i,
+                    end,
+                });
+                i = end;
+            },
+            b'"' => i = string_end(bytes, i + 1),
+            b'r' | b'b' if starts_token(bytes, i) => i = raw_string_end(bytes, i).unwrap_or(i + 1),
+            b'\'' => i = char_end(src, i),
+            _ => i += 1,
+        }
+    }
+    comments
+}
+
+/// Whether a literal prefix at `i` isn't the continuation of an identifier.
+fn starts_token(bytes: &[u8], i: usize) -> bool {
+    i == 0 || !(bytes[i - 1].is_ascii_alphanumeric() || bytes[i - 1] == b'_')
+}
+
+fn block_comment_end(bytes: &[u8], start: usize) -> usize {
+    let mut depth = 0;
+    let mut i = start;
+    while i + 1 < bytes.len() {
+        match &bytes[i..i + 2] {
+            b"/*" => {
+                depth += 1;
+                i += 2;
+            },
+            b"*/" => {
+                depth -= 1;
+                i += 2;
+                if depth == 0 {
+                    return i;
+                }
+            },
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// The end of a string literal whose contents start at `i`.
+fn string_end(bytes: &[u8], mut i: usize) -> usize {
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return i + 1,
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// The end of a raw string literal `r#".."#` or `br#".."#` starting at `i`,
+/// or `None` if there is none.
+fn raw_string_end(bytes: &[u8], mut i: usize) -> Option<usize> {
+    if bytes[i] == b'b' {
+        i += 1;
+    }
+    if bytes.get(i) != Some(&b'r') {
+        return None;
+    }
+    i += 1;
+    let hashes = bytes[i..].iter().take_while(|&&b| b == b'#').count();
+    i += hashes;
+    if bytes.get(i) != Some(&b'"') {
+        return None;
+    }
+    i += 1;
+    while i < bytes.len() {
+        if bytes[i] == b'"' && bytes[i + 1..].iter().take(hashes).filter(|&&b| b == b'#').count() == hashes {
+            return Some(i + 1 + hashes);
+        }
+        i += 1;
+    }
+    Some(bytes.len())
+}
+
+/// The end of a character literal starting at `i`, or `i + 1` for the quote
+/// of a lifetime or label.
+fn char_end(src: &str, i: usize) -> usize {
+    let rest = &src[i + 1..];
+    let mut chars = rest.char_indices();
+    match chars.next() {
+        Some((_, '\\')) => {
+            // skip the escaped character, then find the closing quote
+            chars.next();
+            chars
+                .find(|&(_, c)| c == '\'')
+                .map_or(src.len(), |(offset, _)| i + 2 + offset)
+        },
+        Some((_, c)) if rest[c.len_utf8()..].starts_with('\'') => i + 2 + c.len_utf8(),
+        _ => i + 1,
+    }
+}
+
+/// The blank lines within `span`, each from its start up to the start of the
+/// next line. Lines in block comments don't count.
+pub fn blank_lines<'a, T: LintContext<'a>>(cx: &T, span: Span) -> Vec<Span> {
+    let src = match cx.sess().source_map().span_to_snippet(span) {
+        Ok(src) => src,
+        Err(_) => return Vec::new(),
+    };
+    let comments = scan(&src);
+    let pos = |offset| span.lo() + BytePos::from_usize(offset);
+    let mut blank = Vec::new();
+    // the first line continues the code before `span`
+    let mut line_start = match src.find('\n') {
+        Some(newline) => newline + 1,
+        None => return blank,
+    };
+    while let Some(len) = src[line_start..].find('\n') {
+        let line_end = line_start + len + 1;
+        let in_comment = comments.iter().any(|c| c.start < line_end && line_start < c.end);
+        if !in_comment && src[line_start..line_end].trim().is_empty() {
+            blank.push(span.with_lo(pos(line_start)).with_hi(pos(line_end)));
+        }
+        line_start = line_end;
+    }
+    blank
+}
+
+/// The comments of a source file.
+pub struct FileComments {
+    start_pos: BytePos,
+    src: Lrc<String>,
+    comments: Vec<Comment>,
+}
+
+impl FileComments {
+    pub fn span(&self, comment: &Comment) -> Span {
+        DUMMY_SP
+            .with_lo(self.start_pos + BytePos::from_usize(comment.start))
+            .with_hi(self.start_pos + BytePos::from_usize(comment.end))
+    }
+
+    pub fn text(&self, comment: &Comment) -> &str {
+        &self.src[comment.start..comment.end]
+    }
+
+    fn offset(&self, pos: BytePos) -> usize {
+        (pos - self.start_pos).to_usize()
+    }
+
+    /// The comments within `span`.
+    pub fn within(&self, span: Span) -> impl Iterator<Item = &Comment> {
+        let (lo, hi) = (self.offset(span.lo()), self.offset(span.hi()));
+        let first = self
+            .comments
+            .iter()
+            .position(|c| c.start >= lo)
+            .unwrap_or(self.comments.len());
+        self.comments[first..].iter().take_while(move |c| c.end <= hi)
+    }
+
+    /// The comments on their own lines directly before `span`, separated from
+    /// it and from each other only by whitespace without blank lines and by
+    /// the spans in `skip` (e.g. the attributes of an item), nearest first.
+    pub fn preceding(&self, span: Span, skip: &[Span]) -> Vec<&Comment> {
+        self.preceding_with_blank_lines(span, skip, 0)
+    }
+
+    /// Like `preceding`, but allows up to `max_blank_lines` blank lines
+    /// between the comments and `span`.
+    pub fn preceding_with_blank_lines(&self, span: Span, skip: &[Span], max_blank_lines: usize) -> Vec<&Comment> {
+        let file_end = self.start_pos + BytePos::from_usize(self.src.len());
+        let skip: Vec<_> = skip
+            .iter()
+            .filter(|s| self.start_pos <= s.lo() && s.hi() <= file_end)
+            .map(|s| (self.offset(s.lo()), self.offset(s.hi())))
+            .collect();
+        let is_blank = |lo: usize, hi: usize| {
+            let mut newlines = 0;
+            for (i, c) in self.src[lo..hi].char_indices() {
+                if skip.iter().any(|&(s_lo, s_hi)| s_lo <= lo + i && lo + i < s_hi) {
+                    newlines = 0;
+                } else if c == '\n' {
+                    newlines += 1;
+                    if newlines > 1 + max_blank_lines {
+                        return false;
+                    }
+                } else if !c.is_whitespace() {
+                    return false;
+                }
+            }
+            true
+        };
+
+        let mut pos = self.offset(span.lo());
+        let before = self.comments.iter().take_while(|c| c.end <= pos).count();
+        let mut found = Vec::new();
+        for comment in self.comments[..before].iter().rev() {
+            let line_start = self.src[..comment.start].rfind('\n').map_or(0, |i| i + 1);
+            if !is_blank(comment.end, pos) || !self.src[line_start..comment.start].trim().is_empty() {
+                break;
+            }
+            found.push(comment);
+            pos = comment.start;
+        }
+        found
+    }
+
+    /// The start of the code on the line of `pos`, after its indentation.
+    pub fn line_code_start(&self, pos: BytePos) -> BytePos {
+        let offset = self.offset(pos);
+        let line_start = self.src[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let indent = self.src[line_start..offset]
+            .bytes()
+            .take_while(|&b| b == b' ' || b == b'\t')
+            .count();
+        self.start_pos + BytePos::from_usize(line_start + indent)
+    }
+
+    /// The lines directly before the line of `pos` that each consist of an
+    /// attribute, like `#[allow(unused)]`, nearest first.
+    pub fn attribute_lines_before(&self, pos: BytePos) -> Vec<Span> {
+        let mut lines = Vec::new();
+        let mut line_start = self.src[..self.offset(pos)].rfind('\n').map_or(0, |i| i + 1);
+        while line_start > 0 {
+            let prev_start = self.src[..line_start - 1].rfind('\n').map_or(0, |i| i + 1);
+            let line = self.src[prev_start..line_start - 1].trim();
+            if !(line.starts_with("#[") && line.ends_with(']')) {
+                break;
+            }
+            lines.push(
+                DUMMY_SP
+                    .with_lo(self.start_pos + BytePos::from_usize(prev_start))
+                    .with_hi(self.start_pos + BytePos::from_usize(line_start)),
+            );
+            line_start = prev_start;
+        }
+        lines
+    }
+}
+
+/// Scans each source file at most once, for lint passes looking at the
+/// comments of many items.
+#[derive(Default)]
+pub struct CommentCache {
+    files: FxHashMap<BytePos, Rc<FileComments>>,
+}
+
+impl CommentCache {
+    /// The comments of the source file containing `span`, or `None` if its
+    /// source isn't available.
+    pub fn get<'a, T: LintContext<'a>>(&mut self, cx: &T, span: Span) -> Option<Rc<FileComments>> {
+        let file = cx.sess().source_map().lookup_source_file(span.lo());
+        if let Some(comments) = self.files.get(&file.start_pos) {
+            return Some(Rc::clone(comments));
+        }
+        let src = Lrc::clone(file.src.as_ref()?);
+        let comments = Rc::new(FileComments {
+            start_pos: file.start_pos,
+            comments: scan(&src),
+            src,
+        });
+        self.files.insert(file.start_pos, Rc::clone(&comments));
+        Some(comments)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{scan, CommentKind, FileComments};
+    use rustc_data_structures::sync::Lrc;
+    use syntax::ast::AttrStyle;
+    use syntax::source_map::{BytePos, DUMMY_SP};
+    use syntax_pos::Pos;
+
+    fn comments(src: &str) -> Vec<(&str, CommentKind, Option<AttrStyle>)> {
+        scan(src)
+            .into_iter()
+            .map(|c| (&src[c.start..c.end], c.kind, c.doc))
+            .collect()
+    }
+
+    #[test]
+    fn test_doc_styles() {
+        let src = "/// a\n//! b\n//// c\n// d\n/** e */ /*! f */ /*** g */ /**/";
+        let kinds: Vec<_> = comments(src).into_iter().map(|(_, _, doc)| doc).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                Some(AttrStyle::Outer),
+                Some(AttrStyle::Inner),
+                None,
+                None,
+                Some(AttrStyle::Outer),
+                Some(AttrStyle::Inner),
+                None,
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nested_block_comment() {
+        assert_eq!(
+            comments("a /* b /* c */ d */ e"),
+            vec![("/* b /* c */ d */", CommentKind::Block, None)]
+        );
+    }
+
+    #[test]
+    fn test_literals() {
+        let src = r####"let s = "// no"; let r = r#"/* "no" */"#; let c = '"'; let q = '\''; // yes
+fn f<'a>(x: &'a str) -> char { '/' } /* yes */ let b = br"//";"####;
+        let texts: Vec<_> = comments(src).into_iter().map(|(text, _, _)| text).collect();
+        assert_eq!(texts, vec!["// yes", "/* yes */"]);
+    }
+
+    #[test]
+    fn test_preceding_lines() {
+        let src = "// SAFETY: a\n\n#[allow(x)]\n    let y = 1;\n";
+        let file = FileComments {
+            start_pos: BytePos(0),
+            src: Lrc::new(src.to_string()),
+            comments: scan(src),
+        };
+        let code = BytePos::from_usize(src.find("let").unwrap());
+        let start = file.line_code_start(code + BytePos(4));
+        assert_eq!(start, code);
+        let skip = file.attribute_lines_before(start);
+        assert_eq!(skip.len(), 1);
+        let span = DUMMY_SP.with_lo(start).with_hi(start);
+        assert!(file.preceding_with_blank_lines(span, &skip, 0).is_empty());
+        assert_eq!(file.preceding_with_blank_lines(span, &skip, 1).len(), 1);
+        assert!(file.preceding_with_blank_lines(span, &[], 1).is_empty());
+    }
+}
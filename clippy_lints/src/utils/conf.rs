@@ -3,11 +3,16 @@
 #![deny(clippy::missing_docs_in_private_items)]
 
 use lazy_static::lazy_static;
+use serde_derive::Deserialize;
+use std::collections::hash_map::DefaultHasher;
 use std::default::Default;
+use std::hash::{Hash, Hasher};
 use std::io::Read;
 use std::sync::Mutex;
 use std::{env, fmt, fs, io, path};
+use syntax::source_map::BytePos;
 use syntax::{ast, source_map};
+use syntax_pos::Pos;
 use toml;
 
 /// Get the configuration file from arguments.
@@ -39,19 +44,100 @@ pub fn file_from_args(
 pub enum Error {
     /// An I/O error.
     Io(io::Error),
-    /// Not valid toml or doesn't fit the expected conf format
-    Toml(String),
+    /// Not valid toml or doesn't fit the expected conf format, with the
+    /// location of the error in the file if it is known
+    Toml(String, Option<Location>),
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
         match *self {
             Error::Io(ref err) => err.fmt(f),
-            Error::Toml(ref err) => err.fmt(f),
+            Error::Toml(ref err, _) => err.fmt(f),
         }
     }
 }
 
+impl Error {
+    /// The location of the error in the configuration file, if it is known.
+    pub fn location(&self) -> Option<&Location> {
+        match *self {
+            Error::Io(_) => None,
+            Error::Toml(_, ref location) => location.as_ref(),
+        }
+    }
+
+    /// The span of the error in the configuration file, which must be the
+    /// loaded `file`, if its location is known.
+    pub fn span(&self, file: &source_map::SourceFile) -> Option<source_map::Span> {
+        let (lo, hi) = self.location()?.find(file.src.as_ref()?)?;
+        Some(
+            source_map::DUMMY_SP
+                .with_lo(file.start_pos + BytePos::from_usize(lo))
+                .with_hi(file.start_pos + BytePos::from_usize(hi)),
+        )
+    }
+}
+
+/// Where an error is in a configuration file.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Location {
+    /// A 0-based line and byte column.
+    LineCol(usize, usize),
+    /// The line setting a top-level key, e.g. `blacklisted-names`, or the
+    /// header of its table.
+    Key(String),
+}
+
+impl Location {
+    /// Finds the location in `src`, as the byte range of the erroneous
+    /// character or key.
+    pub fn find(&self, src: &str) -> Option<(usize, usize)> {
+        match *self {
+            Location::LineCol(line, col) => {
+                let start = src.split('\n').take(line).map(|line| line.len() + 1).sum::<usize>() + col;
+                if start <= src.len() {
+                    Some((start, start))
+                } else {
+                    None
+                }
+            },
+            Location::Key(ref key) => {
+                let mut start = 0;
+                for line in src.split('\n') {
+                    // `key = ..` or a table header `[key]` or `[[key]]`
+                    let rest = line.trim_start().trim_start_matches('[');
+                    let offset = start + line.len() - rest.len();
+                    if rest.starts_with(key.as_str()) {
+                        let after = rest[key.len()..].trim_start();
+                        if after.starts_with('=') || after.starts_with(']') {
+                            return Some((offset, offset + key.len()));
+                        }
+                    }
+                    start += line.len() + 1;
+                }
+                None
+            },
+        }
+    }
+}
+
+/// Identifies the contents of a configuration file, so that long-running
+/// processes checking several crates (e.g. IDE back-ends) can tell whether
+/// the configuration changed between runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Fingerprint(u64);
+
+impl Fingerprint {
+    /// The fingerprint of the configuration file at `path` with `contents`, or
+    /// of the default configuration if there is no file.
+    pub fn new(file: Option<(&path::Path, &str)>) -> Self {
+        let mut hasher = DefaultHasher::new();
+        file.hash(&mut hasher);
+        Fingerprint(hasher.finish())
+    }
+}
+
 impl From<io::Error> for Error {
     fn from(e: io::Error) -> Self {
         Error::Io(e)
@@ -60,6 +146,63 @@ impl From<io::Error> for Error {
 
 lazy_static! {
     static ref ERRORS: Mutex<Vec<Error>> = Mutex::new(Vec::new());
+    /// The last configuration read without errors by `read_str_cached`.
+    static ref CACHE: Mutex<Option<(Fingerprint, Conf)>> = Mutex::new(None);
+}
+
+/// A pattern from the `disallowed-patterns` option.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DisallowedPattern {
+    /// The pattern in `pattern!` syntax.
+    pub pattern: String,
+    /// The message to emit when the pattern matches.
+    pub message: String,
+    /// The level of the lint for this pattern, `allow`, `warn`, `deny` or
+    /// `forbid`. The level of `disallowed_patterns` if not given.
+    #[serde(default)]
+    pub level: Option<String>,
+}
+
+/// An entry of the `overrides` option.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Override {
+    /// Globs of the files the override applies to.
+    pub paths: Vec<String>,
+    /// Lints and lint groups to allow in these files.
+    #[serde(default)]
+    pub allow: Vec<String>,
+    /// Lints and lint groups to warn about in these files.
+    #[serde(default)]
+    pub warn: Vec<String>,
+    /// Lints and lint groups to deny in these files.
+    #[serde(default)]
+    pub deny: Vec<String>,
+}
+
+/// An entry of the `missing-trait-methods` option.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TraitMethods {
+    /// The path of the trait, e.g. `std::io::Write`.
+    pub path: String,
+    /// The provided methods that have to be implemented. All provided methods
+    /// if empty.
+    #[serde(default)]
+    pub methods: Vec<String>,
+}
+
+/// An entry of the `clone-then-consume-methods` option.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CloneConsumeMethod {
+    /// The path of the type, e.g. `core::task::wake::Waker`.
+    pub path: String,
+    /// The method taking the value, e.g. `wake`.
+    pub method: String,
+    /// The method doing the same on a reference, e.g. `wake_by_ref`.
+    pub replacement: String,
 }
 
 macro_rules! define_Conf {
@@ -68,7 +211,7 @@ macro_rules! define_Conf {
         mod helpers {
             use serde_derive::Deserialize;
             /// Type used to store lint configuration.
-            #[derive(Deserialize)]
+            #[derive(Clone, Deserialize)]
             #[serde(rename_all="kebab-case", deny_unknown_fields)]
             pub struct Conf {
                 $(#[$doc] #[serde(default=$rust_name_str)] #[serde(with=$rust_name_str)]
@@ -86,7 +229,12 @@ macro_rules! define_Conf {
                         type T = define_Conf!(TY $($ty)+);
                         Ok(T::deserialize(deserializer).unwrap_or_else(|e| {
                             crate::utils::conf::ERRORS.lock().expect("no threading here")
-                                                        .push(crate::utils::conf::Error::Toml(e.to_string()));
+                                                        .push(crate::utils::conf::Error::Toml(
+                                                            e.to_string(),
+                                                            Some(crate::utils::conf::Location::Key(
+                                                                $rust_name_str.replace('_', "-"),
+                                                            )),
+                                                        ));
                             super::$rust_name()
                         }))
                     }
@@ -103,7 +251,7 @@ macro_rules! define_Conf {
     (TY $ty: ty) => { $ty };
 
     // provide a nicer syntax to declare the default value of `Vec<String>` variables
-    (DEFAULT Vec<String>, $e: expr) => { $e.iter().map(|&e| e.to_owned()).collect() };
+    (DEFAULT Vec<String>, $e: expr) => { $e.iter().map(|&e: &&str| e.to_owned()).collect() };
     (DEFAULT $ty: ty, $e: expr) => { $e };
 }
 
@@ -140,6 +288,8 @@ define_Conf! {
     (too_large_for_stack, "too_large_for_stack", 200 => u64),
     /// Lint: ENUM_VARIANT_NAMES. The minimum number of enum variants for the lints about variant names to trigger
     (enum_variant_name_threshold, "enum_variant_name_threshold", 3 => u64),
+    /// Lint: STRUCT_FIELD_NAMES. The minimum number of struct fields for the lint about a common prefix or postfix to trigger
+    (struct_field_name_threshold, "struct_field_name_threshold", 3 => u64),
     /// Lint: LARGE_ENUM_VARIANT. The maximum size of a enum's variant to avoid box suggestion
     (enum_variant_size_threshold, "enum_variant_size_threshold", 200 => u64),
     /// Lint: VERBOSE_BIT_MASK. The maximum allowed size of a bit mask before suggesting to use 'trailing_zeros'
@@ -150,6 +300,50 @@ define_Conf! {
     (trivial_copy_size_limit, "trivial_copy_size_limit", None => Option<u64>),
     /// Lint: TOO_MANY_LINES. The maximum number of lines a function or method can have
     (too_many_lines_threshold, "too_many_lines_threshold", 100 => u64),
+    /// Lint: OBFUSCATED_IF_ELSE. Reverse the lint to suggest `bool::then` for `if .. { Some(..) } else { None }`, if the `msrv` is at least 1.50, and keep the default direction otherwise
+    (prefer_bool_then, "prefer_bool_then", false => bool),
+    /// Lint: ITER_OVER_HASH_TYPE. Lint every iteration over a `HashMap` or `HashSet`, not only the ones where the order becomes observable
+    (iter_over_hash_type_all, "iter_over_hash_type_all", false => bool),
+    /// Lint: RESULT_LARGE_ERR. The maximum size of the `Err`-variant in a `Result` returned from a function
+    (large_error_threshold, "large_error_threshold", 128 => u64),
+    /// Lint: DISALLOWED_PATTERNS. The patterns to lint, as a list of `{ pattern = "..", message = "..", level = ".." }` tables, where `level` is optional
+    (disallowed_patterns, "disallowed_patterns", Vec::new() => Vec<crate::utils::conf::DisallowedPattern>),
+    /// Lint: STYLE, PEDANTIC. Globs of generated files, in which style and pedantic lints are not emitted
+    (generated_files, "generated_files", [] => Vec<String>),
+    /// Lint: ALL. Lint levels for files matching globs, as a list of `{ paths = [..], allow = [..], warn = [..], deny = [..] }` tables
+    (overrides, "overrides", Vec::new() => Vec<crate::utils::conf::Override>),
+    /// Lint: PARTIAL_PUB_FIELDS. Whether to ignore fields marked `#[doc(hidden)]`
+    (partial_pub_fields_ignore_doc_hidden, "partial_pub_fields_ignore_doc_hidden", false => bool),
+    /// Lint: MISSING_TRAIT_METHODS. The traits to lint, as a list of `{ path = "..", methods = [..] }` tables. All traits if empty
+    (missing_trait_methods, "missing_trait_methods", Vec::new() => Vec<crate::utils::conf::TraitMethods>),
+    /// Lint: ALL. Custom lint groups, as a table of group names to lists of lints and groups. A `-` prefix removes lints
+    (groups, "groups", std::collections::BTreeMap::new() => std::collections::BTreeMap<String, Vec<String>>),
+    /// Lint: SEEK_FROM_CURRENT, SEEK_TO_START_INSTEAD_OF_REWIND, LINES_FILTER_MAP_OK, MANUAL_RETAIN, MANUAL_C_STR_LITERALS, THREAD_LOCAL_INITIALIZER_CAN_BE_MADE_CONST, INCOMPATIBLE_MSRV, LEGACY_NUMERIC_CONSTANTS, REPLACE_CONSTS, OBFUSCATED_IF_ELSE, DERIVABLE_IMPLS. The minimum supported Rust version, e.g. `1.40`. Features stabilized later are not suggested, by default those newer than the Rust version Clippy is built for
+    (msrv, "msrv", None => Option<String>),
+    /// Lint: ARITHMETIC_SIDE_EFFECTS. The paths of the types whose arithmetic is not linted, a type name without a path matches any type with that name
+    (arithmetic_side_effects_allowed, "arithmetic_side_effects_allowed", Vec::new() => Vec<String>),
+    /// Lint: LARGE_INCLUDE_FILE. The maximum size (in bytes) of a file included with `include_bytes!()` or `include_str!()`
+    (max_include_file_size, "max_include_file_size", 1_000_000 => u64),
+    /// Lint: REDUNDANT_TYPE_ANNOTATIONS. Whether to ignore bindings initialized with numeric literals with a suffix, like `1u8`
+    (redundant_type_annotations_ignore_numeric_literals, "redundant_type_annotations_ignore_numeric_literals", false => bool),
+    /// Lint: SINGLE_CALL_FN. The minimum number of lines of the body of a function for it to be linted
+    (single_call_fn_min_body_lines, "single_call_fn_min_body_lines", 0 => u64),
+    /// Lint: WAKER_CLONE_WAKE. More methods to lint when called on a clone, as a list of `{ path = "..", method = "..", replacement = ".." }` tables
+    (clone_then_consume_methods, "clone_then_consume_methods", Vec::new() => Vec<crate::utils::conf::CloneConsumeMethod>),
+    /// Lint: ABSOLUTE_PATHS. The maximum number of segments of a path starting at a crate root
+    (absolute_paths_max_segments, "absolute_paths_max_segments", 2 => u64),
+    /// Lint: ABSOLUTE_PATHS. The crates whose paths are not linted, `crate` for the current crate
+    (absolute_paths_allowed_crates, "absolute_paths_allowed_crates", [] => Vec<String>),
+    /// Lint: IMPORTS_GRANULARITY. How imports are merged into `use` items: `crate`, `module` or `item`
+    (imports_granularity, "imports_granularity", "crate".to_string() => String),
+    /// Lint: UNDOCUMENTED_UNSAFE_BLOCKS. Whether a safety comment may be separated from the `unsafe` block or impl by attribute lines
+    (accept_comment_above_attributes, "accept_comment_above_attributes", true => bool),
+    /// Lint: UNDOCUMENTED_UNSAFE_BLOCKS. The number of blank lines allowed between a safety comment and the `unsafe` block or impl
+    (safety_comment_max_blank_lines, "safety_comment_max_blank_lines", 0 => u64),
+    /// Lint: SUSPICIOUS_XOR_USED_AS_POW. The bases of `a ^ b` that are taken for an attempt at exponentiation
+    (suspicious_xor_bases, "suspicious_xor_bases", vec![2, 10] => Vec<u64>),
+    /// Lint: INFINITE_ITER, MAYBE_INFINITE_ITER. The paths of more functions and methods returning infinite iterators, like `my_crate::gen::naturals`
+    (infinite_iterators, "infinite_iterators", Vec::new() => Vec<String>),
 }
 
 impl Default for Conf {
@@ -225,15 +419,52 @@ pub fn read(path: Option<&path::Path>) -> (Conf, Vec<Error>) {
         Err(err) => return default(vec![err.into()]),
     };
 
+    read_str(&file)
+}
+
+/// Like `read_str`, but reuses the configuration of the last call if the file
+/// at `path` has the same contents, for processes checking many crates of a
+/// workspace. Files with errors are read every time, to report the errors.
+pub fn read_str_cached(path: &path::Path, file: &str) -> (Conf, Vec<Error>) {
+    let fingerprint = Fingerprint::new(Some((path, file)));
+    let mut cache = CACHE.lock().expect("no threading -> mutex always safe");
+    if let Some((cached, ref conf)) = *cache {
+        if cached == fingerprint {
+            return (conf.clone(), Vec::new());
+        }
+    }
+    let (conf, errors) = read_str(file);
+    if errors.is_empty() {
+        *cache = Some((fingerprint, conf.clone()));
+    }
+    (conf, errors)
+}
+
+/// Read the contents of a `toml` configuration file.
+///
+/// In case of error, the function tries to continue as much as possible.
+pub fn read_str(file: &str) -> (Conf, Vec<Error>) {
     assert!(ERRORS.lock().expect("no threading -> mutex always safe").is_empty());
-    match toml::from_str(&file) {
+    match toml::from_str(file) {
         Ok(toml) => (
             toml,
             ERRORS.lock().expect("no threading -> mutex always safe").split_off(0),
         ),
         Err(e) => {
             let mut errors = ERRORS.lock().expect("no threading -> mutex always safe").split_off(0);
-            errors.push(Error::Toml(e.to_string()));
+            let message = e.to_string();
+            // serde reports unknown keys without a position
+            let location = e
+                .line_col()
+                .map(|(line, col)| Location::LineCol(line, col))
+                .or_else(|| {
+                    let key = message
+                        .split('`')
+                        .nth(1)
+                        .filter(|_| message.starts_with("unknown field `"))?;
+                    Some(Location::Key(key.to_string()))
+                });
+            errors.push(Error::Toml(message, location));
             default(errors)
         },
     }
@@ -0,0 +1,47 @@
+//! Whether a function body could be evaluated at compile time, for the lints
+//! suggesting to make something `const`.
+
+use if_chain::if_chain;
+use rustc::hir::{self, HirId};
+use rustc::lint::LateContext;
+use rustc_mir::transform::qualify_min_const_fn::is_min_const_fn;
+use std::borrow::Cow;
+use syntax_pos::Span;
+
+/// The `const fn` features to assume on top of the stable `min_const_fn`
+/// subset. The default assumes none of them.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConstFeatures {
+    /// Methods of trait impls can be `const` (`const_trait_impl`).
+    pub trait_impls: bool,
+}
+
+/// Why a body can't be `const`: the offending span and a description.
+pub type Ineligible = (Span, Cow<'static, str>);
+
+/// Checks whether the body of the local function `hir_id` could be `const`
+/// with `features`.
+pub fn check(cx: &LateContext<'_, '_>, hir_id: HirId, features: ConstFeatures) -> Result<(), Ineligible> {
+    let def_id = cx.tcx.hir().local_def_id_from_hir_id(hir_id);
+    if !features.trait_impls && is_trait_impl_method(cx, hir_id) {
+        return Err((
+            cx.tcx.def_span(def_id),
+            Cow::Borrowed("methods of trait impls can't be `const`"),
+        ));
+    }
+    let mir = cx.tcx.optimized_mir(def_id);
+    is_min_const_fn(cx.tcx, def_id, mir)
+}
+
+fn is_trait_impl_method(cx: &LateContext<'_, '_>, hir_id: HirId) -> bool {
+    let parent_impl = cx.tcx.hir().get_parent_item(hir_id);
+    if_chain! {
+        if parent_impl != hir::CRATE_HIR_ID;
+        if let hir::Node::Item(item) = cx.tcx.hir().get_by_hir_id(parent_impl);
+        if let hir::ItemKind::Impl(_, _, _, _, Some(_), _, _) = item.node;
+        then {
+            return true;
+        }
+    }
+    false
+}
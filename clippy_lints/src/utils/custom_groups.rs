@@ -0,0 +1,92 @@
+//! Custom lint groups from the `groups` configuration.
+//!
+//! ```toml
+//! [groups]
+//! team-strict = ["clippy::pedantic", "-clippy::module_name_repetitions", "clippy::option_unwrap_used"]
+//! ```
+//!
+//! Each group is registered as `clippy::<name>`, so it can be used like the
+//! built-in groups, e.g. `#![warn(clippy::team_strict)]` or
+//! `cargo clippy -- -W clippy::team_strict`. The entries are lints or
+//! built-in groups, applied in order. A `-` prefix removes the lints again.
+
+use crate::utils::overrides::normalize_name;
+use lazy_static::lazy_static;
+use rustc::lint::LintId;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+lazy_static! {
+    static ref NAMES: Mutex<FxHashSet<&'static str>> = Mutex::new(FxHashSet::default());
+}
+
+/// A `'static` name for a custom group, as the lint registry needs. Each name
+/// is leaked once per process, not once per checked crate, for processes
+/// checking many crates, like IDE back-ends using `driver::run`.
+pub fn intern(name: String) -> &'static str {
+    let mut names = NAMES.lock().expect("no threading here");
+    if let Some(&name) = names.get(&*name) {
+        return name;
+    }
+    let name = Box::leak(name.into_boxed_str());
+    names.insert(name);
+    name
+}
+
+/// Expands the custom groups of the `groups` configuration, given all Clippy
+/// lint groups. Returns the names and lints of the custom groups, and an
+/// error message for each invalid group or entry.
+pub fn expand<'a>(
+    custom: &BTreeMap<String, Vec<String>>,
+    groups: impl Iterator<Item = (&'a str, &'a [LintId])>,
+) -> (Vec<(String, Vec<LintId>)>, Vec<String>) {
+    let groups: FxHashMap<&str, &[LintId]> = groups.collect();
+    let lints: FxHashMap<String, LintId> = groups
+        .values()
+        .flat_map(|lints| lints.iter())
+        .collect::<FxHashSet<_>>()
+        .into_iter()
+        .map(|&lint| (lint.to_string(), lint))
+        .collect();
+
+    let mut errors = Vec::new();
+    let mut expanded = Vec::new();
+    for (group, entries) in custom {
+        let group = normalize_name(group);
+        if groups.contains_key(&*group) || lints.contains_key(&group) {
+            errors.push(format!(
+                "custom lint group `{}` in `groups` has the name of a lint or group",
+                group
+            ));
+            continue;
+        }
+        let mut group_lints: Vec<LintId> = Vec::new();
+        for entry in entries {
+            let (remove, name) = if entry.starts_with('-') {
+                (true, normalize_name(&entry[1..]))
+            } else {
+                (false, normalize_name(entry))
+            };
+            let entry_lints = if let Some(lints) = groups.get(&*name) {
+                lints.to_vec()
+            } else if let Some(&lint) = lints.get(&name) {
+                vec![lint]
+            } else {
+                errors.push(format!("unknown lint or lint group `{}` in group `{}`", name, group));
+                continue;
+            };
+            if remove {
+                group_lints.retain(|lint| !entry_lints.contains(lint));
+            } else {
+                for lint in entry_lints {
+                    if !group_lints.contains(&lint) {
+                        group_lints.push(lint);
+                    }
+                }
+            }
+        }
+        expanded.push((group, group_lints));
+    }
+    (expanded, errors)
+}
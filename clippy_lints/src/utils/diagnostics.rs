@@ -1,9 +1,18 @@
 //! Clippy wrappers around rustc's diagnostic functions.
 
-use rustc::hir::HirId;
-use rustc::lint::{LateContext, Lint, LintContext};
-use rustc_errors::{Applicability, CodeSuggestion, Substitution, SubstitutionPart, SuggestionStyle};
+use crate::utils::{add_use_if_missing, changed_files, explain, fix_dry_run, generated, overrides, Import};
+use rustc::hir::{self, HirId};
+use rustc::lint::{
+    EarlyContext, EarlyLintPass, LateContext, LateLintPass, Level, Lint, LintArray, LintContext, LintPass,
+};
+use rustc::lint_array;
+use rustc_errors::{
+    Applicability, CodeSuggestion, Diagnostic, DiagnosticId, Handler, Substitution, SubstitutionPart, SuggestionStyle,
+};
+use std::cell::RefCell;
 use std::env;
+use std::mem;
+use syntax::ast;
 use syntax::errors::DiagnosticBuilder;
 use syntax::source_map::Span;
 
@@ -12,11 +21,40 @@ struct DiagnosticWrapper<'a>(DiagnosticBuilder<'a>);
 
 impl<'a> Drop for DiagnosticWrapper<'a> {
     fn drop(&mut self) {
-        self.0.emit();
+        if self.0.cancelled() {
+            return;
+        }
+        QUEUE.with(|queue| queue.borrow_mut().push((*self.0).clone()));
+        self.0.cancel();
     }
 }
 
 impl<'a> DiagnosticWrapper<'a> {
+    /// Applies the level set by the `overrides` configuration.
+    fn override_level<'b, T: LintContext<'b>>(self, cx: &T, lint: &'static Lint, sp: Span) -> Self {
+        let level = overrides::level(cx, lint, sp);
+        self.set_level(cx, level)
+    }
+
+    /// Changes the level of the lint to `level`, if the lint is emitted.
+    fn set_level<'b, T: LintContext<'b>>(mut self, cx: &T, level: Option<Level>) -> Self {
+        if self.0.cancelled() {
+            return self;
+        }
+        let deny_warnings = cx
+            .sess()
+            .opts
+            .lint_opts
+            .iter()
+            .any(|(name, level)| name == "warnings" && *level >= Level::Deny);
+        match level {
+            Some(Level::Warn) if !deny_warnings => self.0.level = rustc_errors::Level::Warning,
+            Some(Level::Deny) | Some(Level::Forbid) => self.0.level = rustc_errors::Level::Error,
+            _ => {},
+        }
+        self
+    }
+
     fn docs_link(&mut self, lint: &'static Lint) {
         if env::var("CLIPPY_DISABLE_DOCS_LINKS").is_err() {
             self.0.help(&format!(
@@ -31,6 +69,140 @@ impl<'a> DiagnosticWrapper<'a> {
     }
 }
 
+/// How to emit two lints with the same primary span.
+enum Overlap {
+    /// Only the first lint is emitted.
+    Supersede,
+    /// The second lint is added to the first one as a note, with its
+    /// suggestions.
+    Merge,
+}
+
+/// Lints that give overlapping advice when emitted at the same span, the
+/// more specific one first.
+const OVERLAPS: [(&str, &str, Overlap); 4] = [
+    (
+        "clippy::arithmetic_side_effects",
+        "clippy::integer_arithmetic",
+        Overlap::Supersede,
+    ),
+    (
+        "clippy::cast_possible_truncation",
+        "clippy::cast_try_from",
+        Overlap::Merge,
+    ),
+    ("clippy::cast_possible_wrap", "clippy::cast_try_from", Overlap::Merge),
+    ("clippy::cast_sign_loss", "clippy::cast_try_from", Overlap::Merge),
+];
+
+thread_local! {
+    /// The diagnostics of the current item, emitted by `FlushDiagnostics`.
+    static QUEUE: RefCell<Vec<Diagnostic>> = RefCell::new(Vec::new());
+}
+
+fn lint_name(diag: &Diagnostic) -> Option<&str> {
+    match diag.code {
+        Some(DiagnosticId::Lint(ref name)) => Some(name),
+        _ => None,
+    }
+}
+
+/// Applies `OVERLAPS` to the diagnostics with the same primary span, keeping
+/// the order of the remaining ones.
+fn resolve_overlaps(diags: Vec<Diagnostic>) -> Vec<Diagnostic> {
+    let mut diags: Vec<_> = diags.into_iter().map(Some).collect();
+    for i in 0..diags.len() {
+        let overlap = diags[i].as_ref().and_then(|diag| {
+            diags.iter().enumerate().find_map(|(j, first)| {
+                let first = first
+                    .as_ref()
+                    .filter(|first| i != j && first.span.primary_span() == diag.span.primary_span())?;
+                let names = (lint_name(first)?, lint_name(diag)?);
+                OVERLAPS
+                    .iter()
+                    .find(|(f, s, _)| (*f, *s) == names)
+                    .map(|(_, _, overlap)| (j, overlap))
+            })
+        });
+        if let Some((j, overlap)) = overlap {
+            let (other, first) = match (diags[i].take(), diags[j].as_mut()) {
+                (Some(other), Some(first)) => (other, first),
+                _ => continue,
+            };
+            // don't let an error become a warning
+            if other.is_error() && !first.is_error() {
+                first.level = other.level;
+            }
+            if let Overlap::Merge = overlap {
+                first.note(&other.message());
+                first.children.extend(other.children);
+                first.suggestions.extend(other.suggestions);
+            }
+        }
+    }
+    diags.into_iter().flatten().collect()
+}
+
+fn flush(handler: &Handler) {
+    let diags = QUEUE.with(|queue| mem::replace(&mut *queue.borrow_mut(), Vec::new()));
+    for diag in resolve_overlaps(diags) {
+        fix_dry_run::record(&diag);
+        DiagnosticBuilder::new_diagnostic(handler, diag).emit();
+    }
+}
+
+/// Emits the diagnostics of the other passes after each item, see
+/// `OVERLAPS`. Must be registered after all other passes.
+pub struct FlushDiagnostics;
+
+impl LintPass for FlushDiagnostics {
+    fn get_lints(&self) -> LintArray {
+        lint_array!()
+    }
+
+    fn name(&self) -> &'static str {
+        "FlushDiagnostics"
+    }
+}
+
+impl EarlyLintPass for FlushDiagnostics {
+    fn check_item_post(&mut self, cx: &EarlyContext<'_>, _: &ast::Item) {
+        flush(cx.sess().diagnostic());
+    }
+
+    fn check_crate_post(&mut self, cx: &EarlyContext<'_>, _: &ast::Crate) {
+        flush(cx.sess().diagnostic());
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for FlushDiagnostics {
+    fn check_item_post(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx hir::Item) {
+        flush(cx.sess().diagnostic());
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx hir::Crate) {
+        flush(cx.sess().diagnostic());
+        fix_dry_run::report(cx);
+    }
+}
+
+/// Checks whether `lint` is not emitted at `sp`, because the span is in
+/// generated code or an unchanged file, or the `overrides` configuration
+/// allows the lint there. Records the lint for `--clippy-explain-span`.
+fn is_silenced<'a, T: LintContext<'a>>(cx: &T, lint: &'static Lint, sp: Span) -> bool {
+    let silenced = if generated::is_silenced(cx, lint, sp) {
+        Some("it is in generated code")
+    } else if changed_files::is_unchanged(cx, sp) {
+        Some("the file is not changed")
+    } else if overrides::level(cx, lint, sp) == Some(Level::Allow) {
+        Some("the `overrides` configuration allows it")
+    } else {
+        None
+    };
+    explain::record(cx, lint, sp, silenced);
+    silenced.is_some()
+}
+
 /// Emit a basic lint message with a `msg` and a `span`.
 ///
 /// This is the most primitive of our lint emission methods and can
@@ -49,7 +221,25 @@ impl<'a> DiagnosticWrapper<'a> {
 ///    |     ^^^^^^^^^^^^^^^^^^^^^^^
 /// ```
 pub fn span_lint<'a, T: LintContext<'a>>(cx: &T, lint: &'static Lint, sp: Span, msg: &str) {
-    DiagnosticWrapper(cx.struct_span_lint(lint, sp, msg)).docs_link(lint);
+    if is_silenced(cx, lint, sp) {
+        return;
+    }
+    DiagnosticWrapper(cx.struct_span_lint(lint, sp, msg))
+        .override_level(cx, lint, sp)
+        .docs_link(lint);
+}
+
+/// Same as `span_lint`, but emits the lint at `level` instead of its own
+/// level, unless it is allowed. Used for lints whose configuration sets
+/// different levels for different cases.
+pub fn span_lint_at_level<'a, T: LintContext<'a>>(cx: &T, lint: &'static Lint, sp: Span, msg: &str, level: Level) {
+    if is_silenced(cx, lint, sp) {
+        return;
+    }
+    DiagnosticWrapper(cx.struct_span_lint(lint, sp, msg))
+        .override_level(cx, lint, sp)
+        .set_level(cx, Some(level))
+        .docs_link(lint);
 }
 
 /// Same as `span_lint` but with an extra `help` message.
@@ -77,7 +267,10 @@ pub fn span_help_and_lint<'a, 'tcx: 'a, T: LintContext<'tcx>>(
     msg: &str,
     help: &str,
 ) {
-    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, span, msg));
+    if is_silenced(cx, lint, span) {
+        return;
+    }
+    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, span, msg)).override_level(cx, lint, span);
     db.0.help(help);
     db.docs_link(lint);
 }
@@ -111,7 +304,10 @@ pub fn span_note_and_lint<'a, 'tcx: 'a, T: LintContext<'tcx>>(
     note_span: Span,
     note: &str,
 ) {
-    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, span, msg));
+    if is_silenced(cx, lint, span) {
+        return;
+    }
+    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, span, msg)).override_level(cx, lint, span);
     if note_span == span {
         db.0.note(note);
     } else {
@@ -120,6 +316,10 @@ pub fn span_note_and_lint<'a, 'tcx: 'a, T: LintContext<'tcx>>(
     db.docs_link(lint);
 }
 
+/// Emit a lint and let `f` add suggestions and notes to it.
+///
+/// `f` is not called if the lint is allowed, so expensive work like reading
+/// snippets should be done there.
 pub fn span_lint_and_then<'a, 'tcx: 'a, T: LintContext<'tcx>, F>(
     cx: &'a T,
     lint: &'static Lint,
@@ -129,13 +329,23 @@ pub fn span_lint_and_then<'a, 'tcx: 'a, T: LintContext<'tcx>, F>(
 ) where
     F: for<'b> FnOnce(&mut DiagnosticBuilder<'b>),
 {
-    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, sp, msg));
-    f(&mut db.0);
+    if is_silenced(cx, lint, sp) {
+        return;
+    }
+    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, sp, msg)).override_level(cx, lint, sp);
+    if !db.0.cancelled() {
+        f(&mut db.0);
+    }
     db.docs_link(lint);
 }
 
 pub fn span_lint_node(cx: &LateContext<'_, '_>, lint: &'static Lint, node: HirId, sp: Span, msg: &str) {
-    DiagnosticWrapper(cx.tcx.struct_span_lint_hir(lint, node, sp, msg)).docs_link(lint);
+    if is_silenced(cx, lint, sp) {
+        return;
+    }
+    DiagnosticWrapper(cx.tcx.struct_span_lint_hir(lint, node, sp, msg))
+        .override_level(cx, lint, sp)
+        .docs_link(lint);
 }
 
 pub fn span_lint_node_and_then(
@@ -146,8 +356,13 @@ pub fn span_lint_node_and_then(
     msg: &str,
     f: impl FnOnce(&mut DiagnosticBuilder<'_>),
 ) {
-    let mut db = DiagnosticWrapper(cx.tcx.struct_span_lint_hir(lint, node, sp, msg));
-    f(&mut db.0);
+    if is_silenced(cx, lint, sp) {
+        return;
+    }
+    let mut db = DiagnosticWrapper(cx.tcx.struct_span_lint_hir(lint, node, sp, msg)).override_level(cx, lint, sp);
+    if !db.0.cancelled() {
+        f(&mut db.0);
+    }
     db.docs_link(lint);
 }
 
@@ -180,6 +395,74 @@ pub fn span_lint_and_sugg<'a, 'tcx: 'a, T: LintContext<'tcx>>(
     });
 }
 
+/// Like `span_lint_and_sugg`, but `sugg` is only called if the lint is
+/// emitted. It can lower the `applicability` it is given, e.g. with
+/// `snippet_with_applicability`.
+///
+/// ```rust,ignore
+/// span_lint_and_sugg_lazy(cx, LEN_ZERO, span, msg, help, Applicability::MachineApplicable, |app| {
+///     format!("{}.is_empty()", snippet_with_applicability(cx, recv.span, "_", app))
+/// });
+/// ```
+pub fn span_lint_and_sugg_lazy<'a, 'tcx: 'a, T: LintContext<'tcx>>(
+    cx: &'a T,
+    lint: &'static Lint,
+    sp: Span,
+    msg: &str,
+    help: &str,
+    applicability: Applicability,
+    sugg: impl FnOnce(&mut Applicability) -> String,
+) {
+    span_lint_and_then(cx, lint, sp, msg, |db| {
+        let mut applicability = applicability;
+        let sugg = sugg(&mut applicability);
+        db.span_suggestion(sp, help, sugg, applicability);
+    });
+}
+
+/// Like `span_lint_and_sugg`, but also suggests importing the item at `import`
+/// if it is not in scope at `node`, e.g. a trait whose methods `sugg` uses.
+///
+/// ```ignore
+/// error: `format!(..)` appended to existing `String`
+///   --> $DIR/format_push_string.rs:5:5
+///    |
+/// LL |     string += &format!("{:?}", 1234);
+///    |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+/// help: consider using `write!` to avoid the extra allocation
+///    |
+/// LL | use std::fmt::Write;
+/// LL | fn main() {
+/// LL |     let mut string = String::new();
+/// LL |     let _ = write!(string, "{:?}", 1234);
+///    |
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn span_lint_and_sugg_with_import(
+    cx: &LateContext<'_, '_>,
+    lint: &'static Lint,
+    node: HirId,
+    sp: Span,
+    msg: &str,
+    help: &str,
+    sugg: String,
+    import: &[&str],
+    applicability: Applicability,
+) {
+    span_lint_and_then(cx, lint, sp, msg, |db| match add_use_if_missing(cx, node, import) {
+        Some(Import::InScope(_)) => {
+            db.span_suggestion(sp, help, sugg, applicability);
+        },
+        Some(Import::Missing(use_span, use_item)) => {
+            db.multipart_suggestion(help, vec![(use_span, use_item), (sp, sugg)], applicability);
+        },
+        None => {
+            db.span_suggestion(sp, help, sugg, applicability);
+            db.note(&format!("`{}` has to be imported", import.join("::")));
+        },
+    });
+}
+
 /// Create a suggestion made from several `span → replacement`.
 ///
 /// Note: in the JSON format (used by `compiletest_rs`), the help message will
@@ -0,0 +1,55 @@
+//! Support for suggestions that depend on the edition of the checked crate,
+//! like `dyn Trait`, which is only accepted without warnings since Rust 2018.
+//!
+//! Trait objects are the only such syntax in clippy's suggestions so far, in
+//! `borrowed_box` and `clone_on_ref_ptr`: inclusive ranges are accepted in all
+//! editions, and try blocks and `let`-`else` aren't stable yet.
+
+use rustc::lint::LintContext;
+pub use syntax_pos::edition::Edition;
+
+/// Gives lint contexts access to the edition of the checked crate.
+pub trait EditionExt {
+    fn edition(&self) -> Edition;
+}
+
+impl<'tcx, T: LintContext<'tcx>> EditionExt for T {
+    fn edition(&self) -> Edition {
+        self.sess().edition()
+    }
+}
+
+/// Replacement templates by the edition they are used from, in ascending
+/// order, with `{}` standing for the replaced code. Lints declare them as
+/// constants, like [`TRAIT_OBJECT`].
+pub struct EditionTemplates(pub &'static [(Edition, &'static str)]);
+
+impl EditionTemplates {
+    /// The template of the latest edition not after `edition`.
+    pub fn template(&self, edition: Edition) -> &'static str {
+        self.0
+            .iter()
+            .rev()
+            .find(|&&(since, _)| since <= edition)
+            .map_or("{}", |&(_, template)| template)
+    }
+
+    pub fn apply(&self, edition: Edition, code: &str) -> String {
+        self.template(edition).replacen("{}", code, 1)
+    }
+}
+
+/// A trait object type, given the bounds.
+pub const TRAIT_OBJECT: EditionTemplates =
+    EditionTemplates(&[(Edition::Edition2015, "{}"), (Edition::Edition2018, "dyn {}")]);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn templates() {
+        assert_eq!(TRAIT_OBJECT.apply(Edition::Edition2015, "Fn()"), "Fn()");
+        assert_eq!(TRAIT_OBJECT.apply(Edition::Edition2018, "Fn()"), "dyn Fn()");
+    }
+}
@@ -0,0 +1,238 @@
+//! Support for `--clippy-explain-span=<file>:<line>`, which reports what
+//! happened to the Clippy lints at a line, to help with reports of lints that
+//! didn't fire.
+//!
+//! Every lint triggered at the line is listed, with the reason it was not
+//! emitted if it wasn't: its level, generated code, an unchanged file or the
+//! `overrides` configuration. Lints that were not triggered are listed with
+//! the first check that rejected the code, if they report it with `reject`,
+//! like the method chain rules do. For the others, only whether the code
+//! comes from a macro expansion is reported.
+
+use crate::utils::in_macro;
+use lazy_static::lazy_static;
+use rustc::hir::intravisit::{walk_expr, walk_impl_item, walk_item, walk_trait_item, NestedVisitorMap, Visitor};
+use rustc::hir::{self, HirId, CRATE_HIR_ID};
+use rustc::lint::{LateContext, LateLintPass, Level, Lint, LintArray, LintContext, LintPass, LintSource};
+use rustc::lint_array;
+use std::path::PathBuf;
+use std::ptr;
+use std::sync::Mutex;
+use syntax::source_map::{FileName, SourceMap, Span};
+
+lazy_static! {
+    static ref EXPLAIN: Mutex<Option<Explain>> = Mutex::new(None);
+}
+
+struct Explain {
+    /// The argument of the option, for the report.
+    spec: String,
+    file: PathBuf,
+    line: usize,
+    /// The lints triggered at the line, with the reason they were silenced
+    /// if they were.
+    triggered: Vec<(&'static Lint, Option<&'static str>)>,
+    /// The lints that checked the line, with the first check that failed.
+    rejected: Vec<(&'static Lint, String)>,
+}
+
+impl Explain {
+    /// Whether `span` is on the explained line. Spans from macro expansions
+    /// are attributed to the outermost macro call.
+    fn is_on_line(&self, source_map: &SourceMap, span: Span) -> bool {
+        let loc = source_map.lookup_char_pos(span.source_callsite().lo());
+        loc.line == self.line
+            && match loc.file.name {
+                FileName::Real(ref path) => path.ends_with(&self.file),
+                _ => false,
+            }
+    }
+}
+
+/// Parses `<file>:<line>` and sets the line to explain.
+pub fn init(spec: &str) -> Result<(), String> {
+    let colon = spec
+        .rfind(':')
+        .ok_or_else(|| format!("expected `<file>:<line>`, found `{}`", spec))?;
+    let line = spec[colon + 1..]
+        .parse()
+        .ok()
+        .filter(|&line| line > 0)
+        .ok_or_else(|| format!("invalid line number in `{}`", spec))?;
+    *EXPLAIN.lock().expect("no threading here") = Some(Explain {
+        spec: spec.to_string(),
+        file: PathBuf::from(&spec[..colon]),
+        line,
+        triggered: Vec::new(),
+        rejected: Vec::new(),
+    });
+    Ok(())
+}
+
+/// Forgets the line to explain, if it wasn't reported yet.
+pub fn reset() {
+    *EXPLAIN.lock().expect("no threading here") = None;
+}
+
+/// Records that `lint` was triggered at `span`, and the reason it is
+/// silenced if it is. Called for every lint Clippy tries to emit.
+pub fn record<'a, T: LintContext<'a>>(cx: &T, lint: &'static Lint, span: Span, silenced: Option<&'static str>) {
+    if let Some(ref mut explain) = *EXPLAIN.lock().expect("no threading here") {
+        if explain.is_on_line(cx.sess().source_map(), span)
+            && !explain.triggered.iter().any(|&(triggered, _)| ptr::eq(triggered, lint))
+        {
+            explain.triggered.push((lint, silenced));
+        }
+    }
+}
+
+/// Records that `lint` checked the code at `span` and didn't lint it, because
+/// the condition described by `check` didn't hold, e.g. "the receiver is not
+/// an `Option`". Only the first failed check of each lint is kept.
+pub fn reject<'a, T, F>(cx: &T, lint: &'static Lint, span: Span, check: F)
+where
+    T: LintContext<'a>,
+    F: FnOnce() -> String,
+{
+    if let Some(ref mut explain) = *EXPLAIN.lock().expect("no threading here") {
+        if explain.is_on_line(cx.sess().source_map(), span)
+            && !explain.rejected.iter().any(|&(rejected, _)| ptr::eq(rejected, lint))
+        {
+            explain.rejected.push((lint, check()));
+        }
+    }
+}
+
+/// Reports the explained line after all lints have run.
+pub struct ExplainSpan;
+
+impl LintPass for ExplainSpan {
+    fn get_lints(&self) -> LintArray {
+        lint_array!()
+    }
+
+    fn name(&self) -> &'static str {
+        "ExplainSpan"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ExplainSpan {
+    fn check_crate_post(&mut self, cx: &LateContext<'a, 'tcx>, krate: &'tcx hir::Crate) {
+        let explain = match EXPLAIN.lock().expect("no threading here").take() {
+            Some(explain) => explain,
+            None => return,
+        };
+        let mut finder = NodeFinder {
+            cx,
+            explain: &explain,
+            found: None,
+        };
+        hir::intravisit::walk_crate(&mut finder, krate);
+
+        let msg = format!("explaining the Clippy lints at `{}`", explain.spec);
+        let mut db = cx.sess().diagnostic().struct_note_without_error(&msg);
+        let (hir_id, span) = match finder.found {
+            Some(found) => found,
+            None => {
+                db.note("no code was found at this line, check the path and the line number");
+                db.emit();
+                return;
+            },
+        };
+        db.set_span(span);
+        if in_macro(span) {
+            db.note("this code comes from a macro expansion, which most lints don't check");
+        }
+        for &(lint, silenced) in &explain.triggered {
+            let name = lint.name_lower();
+            if let Some(reason) = silenced {
+                db.note(&format!("`{}` was triggered, but not emitted because {}", name, reason));
+                continue;
+            }
+            match cx.tcx.lint_level_at_node(lint, hir_id) {
+                (Level::Allow, LintSource::Default) => {
+                    db.note(&format!("`{}` was triggered, but is allowed by default", name));
+                },
+                (Level::Allow, LintSource::Node(_, attr_span, ..)) => {
+                    db.span_note(
+                        attr_span,
+                        &format!("`{}` was triggered, but is allowed by this attribute", name),
+                    );
+                },
+                (Level::Allow, LintSource::CommandLine(flag)) => {
+                    db.note(&format!(
+                        "`{}` was triggered, but is allowed by `-A {}` on the command line",
+                        name, flag
+                    ));
+                },
+                _ => {
+                    db.note(&format!("`{}` was triggered and emitted", name));
+                },
+            }
+        }
+        for &(lint, ref check) in &explain.rejected {
+            if !explain.triggered.iter().any(|&(triggered, _)| ptr::eq(triggered, lint)) {
+                db.note(&format!("`{}` checked this code, but {}", lint.name_lower(), check));
+            }
+        }
+        if explain.triggered.is_empty() {
+            db.note("no Clippy lint was triggered at this line");
+            db.help(
+                "most lints don't record which of their conditions didn't match, so this can't tell why they didn't \
+                 fire",
+            );
+        }
+        db.emit();
+    }
+}
+
+/// Finds the outermost expression or item starting on the explained line.
+struct NodeFinder<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    explain: &'a Explain,
+    found: Option<(HirId, Span)>,
+}
+
+impl<'a, 'tcx: 'a> NodeFinder<'a, 'tcx> {
+    /// Checks a node, returning whether to look into it.
+    fn check(&mut self, hir_id: HirId, span: Span) -> bool {
+        if self.found.is_some() {
+            return false;
+        }
+        if hir_id != CRATE_HIR_ID && self.explain.is_on_line(self.cx.sess().source_map(), span) {
+            self.found = Some((hir_id, span));
+            return false;
+        }
+        true
+    }
+}
+
+impl<'a, 'tcx: 'a> Visitor<'tcx> for NodeFinder<'a, 'tcx> {
+    fn visit_item(&mut self, item: &'tcx hir::Item) {
+        if self.check(item.hir_id, item.span) {
+            walk_item(self, item);
+        }
+    }
+
+    fn visit_impl_item(&mut self, item: &'tcx hir::ImplItem) {
+        if self.check(item.hir_id, item.span) {
+            walk_impl_item(self, item);
+        }
+    }
+
+    fn visit_trait_item(&mut self, item: &'tcx hir::TraitItem) {
+        if self.check(item.hir_id, item.span) {
+            walk_trait_item(self, item);
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr) {
+        if self.check(expr.hir_id, expr.span) {
+            walk_expr(self, expr);
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::All(&self.cx.tcx.hir())
+    }
+}
@@ -0,0 +1,324 @@
+//! Support for `cargo clippy --fix-dry-run`, which checks that the fixes
+//! Clippy suggests still parse, without touching any file.
+//!
+//! `cargo clippy` sets the `CLIPPY_FIX_DRY_RUN` environment variable for the
+//! driver. Every emitted diagnostic is recorded, and after the crate is
+//! checked its machine-applicable fixes are applied to the sources in memory
+//! like rustfix would: per file, in order, leaving out fixes that overlap an
+//! earlier one. If a fixed file doesn't parse, each of its fixes is tried on
+//! its own to find the broken ones. The report also counts the suggestions of
+//! every lint by applicability.
+
+use lazy_static::lazy_static;
+use rustc::lint::LintContext;
+use rustc_data_structures::sync::Lrc;
+use rustc_errors::emitter::Emitter;
+use rustc_errors::{Applicability, Diagnostic, DiagnosticBuilder, DiagnosticId, Handler};
+use std::collections::BTreeMap;
+use std::mem;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use syntax::parse::parser::Parser;
+use syntax::parse::{self, ParseSess};
+use syntax::source_map::{FileName, FilePathMapping, SourceMap, Span, DUMMY_SP};
+
+lazy_static! {
+    static ref DRY_RUN: Mutex<Option<DryRun>> = Mutex::new(None);
+}
+
+#[derive(Default)]
+struct DryRun {
+    /// The number of suggestions of each lint, by applicability.
+    counts: BTreeMap<String, Counts>,
+    /// The machine-applicable fixes, in the order they were emitted.
+    fixes: Vec<Fix>,
+}
+
+#[derive(Default)]
+struct Counts {
+    machine_applicable: usize,
+    maybe_incorrect: usize,
+    has_placeholders: usize,
+    unspecified: usize,
+}
+
+impl Counts {
+    fn add(&mut self, applicability: Applicability) {
+        match applicability {
+            Applicability::MachineApplicable => self.machine_applicable += 1,
+            Applicability::MaybeIncorrect => self.maybe_incorrect += 1,
+            Applicability::HasPlaceholders => self.has_placeholders += 1,
+            Applicability::Unspecified => self.unspecified += 1,
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.machine_applicable += other.machine_applicable;
+        self.maybe_incorrect += other.maybe_incorrect;
+        self.has_placeholders += other.has_placeholders;
+        self.unspecified += other.unspecified;
+    }
+
+    fn total(&self) -> usize {
+        self.machine_applicable + self.maybe_incorrect + self.has_placeholders + self.unspecified
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "{} machine-applicable, {} maybe incorrect, {} with placeholders, {} unspecified",
+            self.machine_applicable, self.maybe_incorrect, self.has_placeholders, self.unspecified
+        )
+    }
+}
+
+/// A machine-applicable suggestion.
+struct Fix {
+    lint: String,
+    /// The primary span of the diagnostic, for the report.
+    span: Span,
+    parts: Vec<(Span, String)>,
+}
+
+/// A replacement of the bytes `start..end` of a file.
+struct Part {
+    start: usize,
+    end: usize,
+    replacement: String,
+}
+
+/// Starts recording the suggestions of the emitted diagnostics.
+pub fn init() {
+    *DRY_RUN.lock().expect("no threading here") = Some(DryRun::default());
+}
+
+/// Stops recording, dropping what wasn't reported yet.
+pub fn reset() {
+    *DRY_RUN.lock().expect("no threading here") = None;
+}
+
+/// Records the suggestions of `diag`. Called for every diagnostic Clippy
+/// emits.
+pub fn record(diag: &Diagnostic) {
+    let mut dry_run = DRY_RUN.lock().expect("no threading here");
+    let dry_run = match *dry_run {
+        Some(ref mut dry_run) => dry_run,
+        None => return,
+    };
+    let lint = match diag.code {
+        Some(DiagnosticId::Lint(ref name)) => name.clone(),
+        _ => return,
+    };
+    for sugg in &diag.suggestions {
+        dry_run.counts.entry(lint.clone()).or_default().add(sugg.applicability);
+        // like rustfix, only the first alternative is applied
+        if let (Applicability::MachineApplicable, Some(subst)) = (sugg.applicability, sugg.substitutions.first()) {
+            dry_run.fixes.push(Fix {
+                lint: lint.clone(),
+                span: diag.span.primary_span().unwrap_or(DUMMY_SP),
+                parts: subst
+                    .parts
+                    .iter()
+                    .map(|part| (part.span, part.snippet.clone()))
+                    .collect(),
+            });
+        }
+    }
+}
+
+/// Reports the applicability counts and the fixes that break the code.
+/// Called once all lints have run.
+pub fn report<'a, T: LintContext<'a>>(cx: &T) {
+    let dry_run = match DRY_RUN.lock().expect("no threading here").take() {
+        Some(dry_run) => dry_run,
+        None => return,
+    };
+    let handler = cx.sess().diagnostic();
+
+    let mut total = Counts::default();
+    for counts in dry_run.counts.values() {
+        total.merge(counts);
+    }
+    let mut db = handler.struct_note_without_error(&format!(
+        "fix dry run: {} suggestions, {}",
+        total.total(),
+        total.describe()
+    ));
+    for (lint, counts) in &dry_run.counts {
+        db.note(&format!("`{}`: {}", lint, counts.describe()));
+    }
+    db.emit();
+
+    let mut files: BTreeMap<PathBuf, (Lrc<String>, Vec<(&Fix, Vec<Part>)>)> = BTreeMap::new();
+    let mut skipped = 0;
+    for fix in &dry_run.fixes {
+        match locate(cx.sess().source_map(), fix) {
+            Some((path, src, parts)) => files
+                .entry(path)
+                .or_insert_with(|| (src, Vec::new()))
+                .1
+                .push((fix, parts)),
+            None => skipped += 1,
+        }
+    }
+    if skipped > 0 {
+        handler.note_without_error(&format!(
+            "fix dry run: {} fixes were skipped, their spans are not all in one source file",
+            skipped
+        ));
+    }
+
+    for (path, (src, fixes)) in &files {
+        let accepted = non_overlapping(fixes.iter().map(|(_, parts)| &parts[..]));
+        let all_parts: Vec<&Part> = accepted.iter().flat_map(|&i| &fixes[i].1).collect();
+        if parse_errors(path, apply(src, &all_parts)).is_empty() {
+            continue;
+        }
+        let mut found = false;
+        for &i in &accepted {
+            let (fix, parts) = &fixes[i];
+            let errors = parse_errors(path, apply(src, &parts.iter().collect::<Vec<_>>()));
+            if errors.is_empty() {
+                continue;
+            }
+            found = true;
+            let mut db = handler.struct_span_warn(
+                fix.span,
+                &format!("applying the fix of `{}` would produce invalid code", fix.lint),
+            );
+            for error in errors {
+                db.note(&format!("the fixed code fails to parse: {}", error));
+            }
+            db.emit();
+        }
+        if !found {
+            handler.warn(&format!(
+                "applying all fixes to `{}` would produce invalid code, but each fix alone is fine",
+                path.display()
+            ));
+        }
+    }
+}
+
+/// The file of all parts of `fix`, with its source and the byte ranges of
+/// the parts in it.
+fn locate(source_map: &SourceMap, fix: &Fix) -> Option<(PathBuf, Lrc<String>, Vec<Part>)> {
+    let mut file = None;
+    let mut parts = Vec::new();
+    for &(span, ref replacement) in &fix.parts {
+        let start = source_map.lookup_byte_offset(span.lo());
+        let end = source_map.lookup_byte_offset(span.hi());
+        if !Lrc::ptr_eq(&start.sf, &end.sf) || file.as_ref().map_or(false, |sf| !Lrc::ptr_eq(sf, &start.sf)) {
+            return None;
+        }
+        parts.push(Part {
+            start: start.pos.0 as usize,
+            end: end.pos.0 as usize,
+            replacement: replacement.clone(),
+        });
+        file = Some(start.sf);
+    }
+    let file = file?;
+    match (&file.name, &file.src) {
+        (FileName::Real(path), Some(src)) => Some((path.clone(), src.clone(), parts)),
+        _ => None,
+    }
+}
+
+/// The indices of the fixes whose parts don't overlap the parts of an
+/// earlier fix, or each other.
+fn non_overlapping<'p>(fixes: impl Iterator<Item = &'p [Part]>) -> Vec<usize> {
+    let mut taken: Vec<&Part> = Vec::new();
+    let mut accepted = Vec::new();
+    for (i, parts) in fixes.enumerate() {
+        let overlaps = parts.iter().enumerate().any(|(j, part)| {
+            taken.iter().any(|other| overlap(part, other)) || parts[..j].iter().any(|other| overlap(part, other))
+        });
+        if !overlaps {
+            taken.extend(parts);
+            accepted.push(i);
+        }
+    }
+    accepted
+}
+
+fn overlap(a: &Part, b: &Part) -> bool {
+    // two insertions at the same position conflict too
+    (a.start < b.end && b.start < a.end) || a.start == b.start
+}
+
+/// Applies non-overlapping `parts` to `src`.
+fn apply(src: &str, parts: &[&Part]) -> String {
+    let mut parts = parts.to_vec();
+    parts.sort_by_key(|part| part.start);
+    let mut fixed = String::with_capacity(src.len());
+    let mut pos = 0;
+    for part in parts {
+        fixed.push_str(&src[pos..part.start]);
+        fixed.push_str(&part.replacement);
+        pos = part.end;
+    }
+    fixed.push_str(&src[pos..]);
+    fixed
+}
+
+/// Parses `src` as a module, returning the messages of the syntax errors.
+/// Out-of-line modules are not loaded.
+fn parse_errors(path: &Path, src: String) -> Vec<String> {
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let handler = Handler::with_emitter(false, None, Box::new(ErrorCollector(errors.clone())));
+    let sess = ParseSess::with_span_handler(handler, Lrc::new(SourceMap::new(FilePathMapping::empty())));
+    let file = sess
+        .source_map()
+        .new_source_file(FileName::Real(path.to_path_buf()), src);
+    // lexer errors are fatal, and unwind after being emitted
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+        let stream = parse::source_file_to_stream(&sess, file, None);
+        if let Err(mut db) = Parser::new(&sess, stream, None, false, false).parse_crate_mod() {
+            db.emit();
+        }
+    }));
+    let mut collected = errors.lock().expect("no threading here");
+    mem::replace(&mut *collected, Vec::new())
+}
+
+/// Collects the messages of the errors emitted while parsing.
+struct ErrorCollector(Arc<Mutex<Vec<String>>>);
+
+impl Emitter for ErrorCollector {
+    fn emit(&mut self, db: &DiagnosticBuilder<'_>) {
+        if db.is_error() {
+            self.0.lock().expect("no threading here").push(db.message());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn part(start: usize, end: usize, replacement: &str) -> Part {
+        Part {
+            start,
+            end,
+            replacement: replacement.to_string(),
+        }
+    }
+
+    #[test]
+    fn apply_non_overlapping_fixes() {
+        let src = "let x = a + b;";
+        let fixes = [
+            vec![part(8, 9, "c")],
+            // overlaps the first fix
+            vec![part(8, 13, "d")],
+            vec![part(12, 13, "e"), part(0, 0, "// ")],
+            // same insertion point as an earlier part
+            vec![part(0, 0, "/* */ ")],
+        ];
+        let accepted = non_overlapping(fixes.iter().map(|parts| &parts[..]));
+        assert_eq!(accepted, [0, 2]);
+        let parts: Vec<&Part> = accepted.iter().flat_map(|&i| &fixes[i]).collect();
+        assert_eq!(apply(src, &parts), "// let x = c + e;");
+    }
+}
@@ -0,0 +1,168 @@
+//! Support for generated code.
+//!
+//! Items marked with `#[clippy::generated]` and files matching one of the
+//! `generated-files` globs from the configuration are considered generated.
+//! Style and pedantic lints are not emitted in generated code, since there is
+//! usually nothing the user can do about them. All other lints, most
+//! importantly correctness lints, are still emitted.
+
+use crate::utils::attrs::get_attr;
+use lazy_static::lazy_static;
+use rustc::lint::{EarlyContext, EarlyLintPass, Lint, LintArray, LintContext, LintId, LintPass};
+use rustc::lint_array;
+use rustc_data_structures::fx::FxHashSet;
+use std::sync::Mutex;
+use syntax::ast;
+use syntax::source_map::{FileName, Span};
+use syntax::visit::{self, Visitor};
+
+lazy_static! {
+    static ref GENERATED: Mutex<Generated> = Mutex::new(Generated::default());
+}
+
+#[derive(Default)]
+struct Generated {
+    /// The lints that are not emitted in generated code.
+    silenced: FxHashSet<LintId>,
+    /// Globs of generated files.
+    globs: Vec<String>,
+    /// Spans of items marked as generated.
+    spans: Vec<Span>,
+}
+
+/// Sets the lints that are not emitted in generated code and the globs of
+/// generated files. Called once when registering the lints.
+pub fn init(silenced: impl IntoIterator<Item = LintId>, globs: &[String]) {
+    let mut generated = GENERATED.lock().expect("no threading here");
+    generated.silenced = silenced.into_iter().collect();
+    generated.globs = globs.to_vec();
+}
+
+/// Checks whether `lint` should not be emitted at `span`, because the span is
+/// in generated code.
+pub fn is_silenced<'a, T: LintContext<'a>>(cx: &T, lint: &'static Lint, span: Span) -> bool {
+    let generated = GENERATED.lock().expect("no threading here");
+    if !generated.silenced.contains(&LintId::of(lint)) {
+        return false;
+    }
+    if generated
+        .spans
+        .iter()
+        .any(|generated_span| generated_span.contains(span))
+    {
+        return true;
+    }
+    file_matches_any(cx, &generated.globs, span)
+}
+
+/// Checks whether the path of the file containing `span` matches one of the
+/// globs.
+pub fn file_matches_any<'a, T: LintContext<'a>>(cx: &T, globs: &[String], span: Span) -> bool {
+    if globs.is_empty() {
+        return false;
+    }
+    match cx.sess().source_map().span_to_filename(span) {
+        FileName::Real(path) => {
+            let path = path.to_string_lossy().replace('\\', "/");
+            globs.iter().any(|glob| glob_matches(glob.as_bytes(), path.as_bytes()))
+        },
+        _ => false,
+    }
+}
+
+/// Matches a path against a glob with `?`, `*` (anything but `/`) and `**`
+/// (anything).
+fn glob_matches(glob: &[u8], path: &[u8]) -> bool {
+    match glob {
+        [] => path.is_empty(),
+        [b'*', b'*', rest..] => (0..=path.len()).any(|i| glob_matches(rest, &path[i..])),
+        [b'*', rest..] => {
+            let segment_len = path.iter().position(|&c| c == b'/').unwrap_or_else(|| path.len());
+            (0..=segment_len).any(|i| glob_matches(rest, &path[i..]))
+        },
+        [b'?', rest..] => !path.is_empty() && path[0] != b'/' && glob_matches(rest, &path[1..]),
+        [c, rest..] => path.first() == Some(c) && glob_matches(rest, &path[1..]),
+    }
+}
+
+/// Collects the spans of all items marked with `#[clippy::generated]` before
+/// any lint is run.
+pub struct GeneratedCode;
+
+impl LintPass for GeneratedCode {
+    fn get_lints(&self) -> LintArray {
+        lint_array!()
+    }
+
+    fn name(&self) -> &'static str {
+        "GeneratedCode"
+    }
+}
+
+impl EarlyLintPass for GeneratedCode {
+    fn check_crate(&mut self, cx: &EarlyContext<'_>, krate: &ast::Crate) {
+        let mut collector = GeneratedCollector { cx, spans: Vec::new() };
+        if is_generated(cx, &krate.attrs) {
+            collector.spans.push(krate.span);
+        }
+        visit::walk_crate(&mut collector, krate);
+        GENERATED.lock().expect("no threading here").spans = collector.spans;
+    }
+}
+
+fn is_generated(cx: &EarlyContext<'_>, attrs: &[ast::Attribute]) -> bool {
+    get_attr(cx.sess(), attrs, "generated").next().is_some()
+}
+
+struct GeneratedCollector<'a, 'b> {
+    cx: &'a EarlyContext<'b>,
+    spans: Vec<Span>,
+}
+
+impl<'a, 'b, 'ast> Visitor<'ast> for GeneratedCollector<'a, 'b> {
+    fn visit_item(&mut self, item: &'ast ast::Item) {
+        if is_generated(self.cx, &item.attrs) {
+            self.spans.push(item.span);
+            // the contents of out-of-line modules are not part of the item span
+            if let ast::ItemKind::Mod(ref module) = item.node {
+                self.spans.push(module.inner);
+            }
+        } else {
+            visit::walk_item(self, item);
+        }
+    }
+
+    fn visit_impl_item(&mut self, item: &'ast ast::ImplItem) {
+        if is_generated(self.cx, &item.attrs) {
+            self.spans.push(item.span);
+        } else {
+            visit::walk_impl_item(self, item);
+        }
+    }
+
+    fn visit_trait_item(&mut self, item: &'ast ast::TraitItem) {
+        if is_generated(self.cx, &item.attrs) {
+            self.spans.push(item.span);
+        } else {
+            visit::walk_trait_item(self, item);
+        }
+    }
+
+    fn visit_mac(&mut self, _: &'ast ast::Mac) {}
+}
+
+#[cfg(test)]
+mod test {
+    use super::glob_matches;
+
+    #[test]
+    fn globs() {
+        let matches = |glob: &str, path: &str| glob_matches(glob.as_bytes(), path.as_bytes());
+        assert!(matches("src/generated.rs", "src/generated.rs"));
+        assert!(matches("src/*.rs", "src/generated.rs"));
+        assert!(!matches("src/*.rs", "src/proto/generated.rs"));
+        assert!(matches("src/**/*.rs", "src/proto/generated.rs"));
+        assert!(matches("**/generated_?.rs", "/tmp/build/generated_1.rs"));
+        assert!(!matches("src/*.rs", "src/generated.txt"));
+    }
+}
@@ -0,0 +1,108 @@
+//! Adding `use` items to suggestions.
+
+use crate::utils::{get_trait_def_id, hir_ancestors, in_macro, match_def_path};
+use rustc::hir::def::Def;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LintContext};
+use std::iter;
+use syntax::source_map::Span;
+use syntax::symbol::Symbol;
+
+/// How an item can be referred to, see `add_use_if_missing`.
+pub enum Import {
+    /// The item is already imported under this name, e.g. `Wr` for
+    /// `use std::fmt::Write as Wr;`. This is `_` for traits imported with
+    /// `as _`.
+    InScope(Symbol),
+    /// The item is not imported. Inserting the string at the span adds a
+    /// `use` item to the module.
+    Missing(Span, String),
+}
+
+/// Checks whether the item at `path` is imported in the module containing
+/// `hir_id` and computes the `use` item to insert if it isn't.
+///
+/// `core` and `alloc` paths are imported from `std`. If the name is already
+/// taken in the module, traits are imported `as _`. Returns `None` if the item
+/// cannot be imported, e.g. because the module only contains macro generated
+/// items.
+///
+/// Imports in block scope are not detected.
+pub fn add_use_if_missing(cx: &LateContext<'_, '_>, hir_id: HirId, path: &[&str]) -> Option<Import> {
+    let module = hir_ancestors(cx, hir_id).find_map(|(_, node)| match node {
+        Node::Item(item) => match item.node {
+            ItemKind::Mod(ref module) => Some(module),
+            _ => None,
+        },
+        Node::Crate => Some(&cx.tcx.hir().krate().module),
+        _ => None,
+    })?;
+    let items: Vec<&Item> = module
+        .item_ids
+        .iter()
+        .map(|item_id| cx.tcx.hir().expect_item(item_id.id))
+        .collect();
+
+    let (name, module_path) = path.split_last()?;
+    let mut name_taken = false;
+    for item in &items {
+        if let ItemKind::Use(ref use_path, kind) = item.node {
+            match kind {
+                UseKind::Single => {
+                    if let Some(def_id) = use_path.def.opt_def_id() {
+                        if match_def_path(cx.tcx, def_id, path) {
+                            return Some(Import::InScope(item.ident.name));
+                        }
+                    }
+                },
+                UseKind::Glob => {
+                    if let Def::Mod(def_id) = use_path.def {
+                        if match_def_path(cx.tcx, def_id, module_path) {
+                            return Some(Import::InScope(Symbol::intern(name)));
+                        }
+                    }
+                },
+                UseKind::ListStem => continue,
+            }
+        }
+        name_taken |= item.ident.name == *name;
+    }
+
+    // insert before the other imports, or before the first item
+    let first = items
+        .iter()
+        .filter(|item| !in_macro(item.span))
+        .find(|item| match item.node {
+            ItemKind::Use(..) => true,
+            _ => false,
+        })
+        .or_else(|| items.iter().find(|item| !in_macro(item.span)))?;
+    let span = first
+        .attrs
+        .iter()
+        .map(|attr| attr.span)
+        .chain(iter::once(first.span))
+        .min_by_key(|span| span.lo())?
+        .shrink_to_lo();
+
+    let rename = if name_taken {
+        if get_trait_def_id(cx, path).is_none() {
+            return None;
+        }
+        " as _"
+    } else {
+        ""
+    };
+    let use_path = match path[0] {
+        "core" | "alloc" => iter::once("std")
+            .chain(path[1..].iter().cloned())
+            .collect::<Vec<_>>()
+            .join("::"),
+        _ => path.join("::"),
+    };
+    let indent = " ".repeat(cx.sess().source_map().lookup_char_pos(span.lo()).col.0);
+    Some(Import::Missing(
+        span,
+        format!("use {}{};\n{}", use_path, rename, indent),
+    ))
+}
@@ -0,0 +1,162 @@
+//! Spans pointing into `Cargo.toml` files, for the lints of the `cargo`
+//! group.
+//!
+//! Manifests are loaded through the source map like `clippy.toml`, which
+//! also lists them in the dep-info of the crate, so that cargo checks the
+//! crate again when they change. Only table headers and the keys of their
+//! entries are located, by scanning the lines of the file.
+
+use rustc::lint::{EarlyContext, LintContext};
+use rustc_data_structures::sync::Lrc;
+use std::path::Path;
+use syntax::source_map::{BytePos, SourceFile, Span, DUMMY_SP};
+use syntax_pos::Pos;
+
+/// The tables of dependencies, which may also be nested in `target.*`.
+const DEPENDENCY_TABLES: [&str; 3] = ["dependencies", "dev-dependencies", "build-dependencies"];
+
+/// A table header or a key of an entry in a manifest.
+#[derive(Clone, Debug, PartialEq)]
+struct Key {
+    /// The table, e.g. `package` or `target.'cfg(unix)'.dependencies`.
+    table: String,
+    /// The key of the entry, `None` for the header of the table.
+    key: Option<String>,
+    /// The byte range of the key or the table name.
+    start: usize,
+    end: usize,
+}
+
+pub struct Manifest {
+    file: Lrc<SourceFile>,
+    keys: Vec<Key>,
+}
+
+impl Manifest {
+    /// Loads the manifest at `path`. `None` if it can't be read.
+    pub fn load(cx: &EarlyContext<'_>, path: &Path) -> Option<Self> {
+        let file = cx.sess().source_map().load_file(path).ok()?;
+        let keys = parse(file.src.as_ref()?);
+        Some(Self { file, keys })
+    }
+
+    /// The name in the header of `table`, e.g. `package` in `[package]`.
+    pub fn table(&self, table: &str) -> Option<Span> {
+        self.find(|key| key.table == table && key.key.is_none())
+    }
+
+    /// The key of the entry `key` in `table`.
+    pub fn entry(&self, table: &str, key: &str) -> Option<Span> {
+        self.find(|k| k.table == table && k.key.as_ref().map_or(false, |k| k == key))
+    }
+
+    /// The entry of the dependency `name` in any of the dependency tables,
+    /// or the name in its `[dependencies.name]` header.
+    pub fn dependency(&self, name: &str) -> Option<Span> {
+        self.find(|key| match key.key {
+            Some(ref key_name) => key_name == name && is_dependency_table(&key.table),
+            None => key.table.rfind('.').map_or(false, |dot| {
+                &key.table[dot + 1..] == name && is_dependency_table(&key.table[..dot])
+            }),
+        })
+    }
+
+    /// `found`, or else an empty span at the start of the manifest, which
+    /// still points the user to the right file.
+    pub fn span(&self, found: Option<Span>) -> Span {
+        found.unwrap_or_else(|| DUMMY_SP.with_lo(self.file.start_pos).with_hi(self.file.start_pos))
+    }
+
+    fn find(&self, pred: impl Fn(&Key) -> bool) -> Option<Span> {
+        self.keys.iter().find(|key| pred(key)).map(|key| {
+            DUMMY_SP
+                .with_lo(self.file.start_pos + BytePos::from_usize(key.start))
+                .with_hi(self.file.start_pos + BytePos::from_usize(key.end))
+        })
+    }
+}
+
+fn is_dependency_table(table: &str) -> bool {
+    let last = table.rsplit('.').next().unwrap_or(table);
+    DEPENDENCY_TABLES.contains(&last)
+}
+
+/// Finds the table headers and the keys of the entries in `src`. Multi-line
+/// values like arrays are skipped as long as their lines don't look like
+/// entries themselves.
+fn parse(src: &str) -> Vec<Key> {
+    let mut keys = Vec::new();
+    let mut table = String::new();
+    let mut start = 0;
+    for line in src.split('\n') {
+        let trimmed = line.trim();
+        let offset = start + line.len() - line.trim_start().len();
+        if trimmed.starts_with('[') {
+            // `[table]` or `[[table]]`
+            let name = trimmed.trim_start_matches('[');
+            if let Some(end) = name.find(']') {
+                let name_offset = offset + trimmed.len() - name.len();
+                table = name[..end].split('.').map(str::trim).collect::<Vec<_>>().join(".");
+                keys.push(Key {
+                    table: table.clone(),
+                    key: None,
+                    start: name_offset,
+                    end: name_offset + end,
+                });
+            }
+        } else if let Some(eq) = trimmed.find('=') {
+            let key = trimmed[..eq].trim_end();
+            let unquoted = key.trim_matches('"');
+            if !key.is_empty() && !key.starts_with('#') {
+                keys.push(Key {
+                    table: table.clone(),
+                    key: Some(unquoted.to_string()),
+                    start: offset,
+                    end: offset + key.len(),
+                });
+            }
+        }
+        start += line.len() + 1;
+    }
+    keys
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_manifest() {
+        let src = "[package]\nname = \"foo\"\n\n[dependencies]\n  regex = \"*\"\n\"serde\" = { version = \"1\" }\n\n\
+                   [target.'cfg(unix)'. dev-dependencies.libc]\nversion = \"0.2\"\n";
+        let keys = parse(src);
+        let found: Vec<_> = keys
+            .iter()
+            .map(|key| {
+                (
+                    &key.table[..],
+                    key.key.as_ref().map(String::as_str),
+                    &src[key.start..key.end],
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("package", None, "package"),
+                ("package", Some("name"), "name"),
+                ("dependencies", None, "dependencies"),
+                ("dependencies", Some("regex"), "regex"),
+                ("dependencies", Some("serde"), "\"serde\""),
+                (
+                    "target.'cfg(unix)'.dev-dependencies.libc",
+                    None,
+                    "target.'cfg(unix)'. dev-dependencies.libc"
+                ),
+                ("target.'cfg(unix)'.dev-dependencies.libc", Some("version"), "version"),
+            ]
+        );
+        assert!(is_dependency_table("target.'cfg(unix)'.dev-dependencies"));
+        assert!(!is_dependency_table("package"));
+    }
+}
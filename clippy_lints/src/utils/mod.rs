@@ -18,31 +18,56 @@ use rustc::ty::{
 use rustc_data_structures::sync::Lrc;
 use rustc_errors::Applicability;
 use std::borrow::Cow;
+use std::fmt;
 use std::mem;
 use syntax::ast::{self, LitKind};
 use syntax::attr;
 use syntax::source_map::{Span, DUMMY_SP};
 use syntax::symbol;
 use syntax::symbol::{keywords, Symbol};
+use syntax_pos::Pos;
 
+mod ancestors;
+pub mod ast_pattern;
 pub mod attrs;
 pub mod author;
+pub mod call_index;
 pub mod camel_case;
+pub mod comments;
+pub mod changed_files;
 pub mod comparisons;
 pub mod conf;
+pub mod const_eligibility;
 pub mod constants;
+pub mod custom_groups;
 mod diagnostics;
+pub mod edition;
+pub mod explain;
+pub mod fix_dry_run;
+pub mod generated;
 pub mod higher;
 mod hir_utils;
+mod imports;
 pub mod inspector;
 pub mod internal_lints;
+pub mod manifest;
+pub mod msrvs;
+pub mod naming;
+pub mod overrides;
 pub mod paths;
+pub mod pattern_coverage;
+pub mod place;
 pub mod ptr;
+pub mod stability;
 pub mod sugg;
+pub mod target;
 pub mod usage;
+pub mod use_tree;
+pub use self::ancestors::{hir_ancestors, HirAncestors};
 pub use self::attrs::*;
 pub use self::diagnostics::*;
 pub use self::hir_utils::{SpanlessEq, SpanlessHash};
+pub use self::imports::{add_use_if_missing, Import};
 
 /// Returns true if the two spans come from differing expansions (i.e. one is
 /// from a macro and one
@@ -61,31 +86,7 @@ pub fn differing_macro_contexts(lhs: Span, rhs: Span) -> bool {
 /// }
 /// ```
 pub fn in_constant(cx: &LateContext<'_, '_>, id: HirId) -> bool {
-    let parent_id = cx.tcx.hir().get_parent_item(id);
-    match cx.tcx.hir().get_by_hir_id(parent_id) {
-        Node::Item(&Item {
-            node: ItemKind::Const(..),
-            ..
-        })
-        | Node::TraitItem(&TraitItem {
-            node: TraitItemKind::Const(..),
-            ..
-        })
-        | Node::ImplItem(&ImplItem {
-            node: ImplItemKind::Const(..),
-            ..
-        })
-        | Node::AnonConst(_)
-        | Node::Item(&Item {
-            node: ItemKind::Static(..),
-            ..
-        }) => true,
-        Node::Item(&Item {
-            node: ItemKind::Fn(_, header, ..),
-            ..
-        }) => header.constness == Constness::Const,
-        _ => false,
-    }
+    hir_ancestors(cx, id).is_in_const_context()
 }
 
 /// Returns true if this `expn_info` was expanded by any macro.
@@ -279,6 +280,72 @@ pub fn get_trait_def_id(cx: &LateContext<'_, '_>, path: &[&str]) -> Option<DefId
     }
 }
 
+/// Resolves a path from the configuration, like `std::iter::Iterator` or
+/// `my_crate::Type::new`, to the items it names.
+///
+/// Paths into other crates are resolved through their modules like in a `use`
+/// item, so `std::iter::Iterator` names the re-exported trait of `core`. The
+/// last segment may also name an inherent associated item of a type. Paths
+/// into the local crate name the items defined at that path.
+pub fn def_path_def_ids(cx: &LateContext<'_, '_>, path: &[&str]) -> Vec<DefId> {
+    let (krate, segments) = match path.split_first() {
+        Some(split) => split,
+        None => return Vec::new(),
+    };
+    if cx.tcx.crate_name(LOCAL_CRATE) == *krate {
+        let hir_krate = cx.tcx.hir().krate();
+        return hir_krate
+            .items
+            .values()
+            .map(|item| item.hir_id)
+            .chain(hir_krate.trait_items.values().map(|item| item.hir_id))
+            .chain(hir_krate.impl_items.values().map(|item| item.hir_id))
+            .map(|hir_id| cx.tcx.hir().local_def_id_from_hir_id(hir_id))
+            .filter(|&def_id| get_def_path(cx.tcx, def_id) == path)
+            .collect();
+    }
+    let mut def_ids: Vec<DefId> = cx
+        .tcx
+        .crates()
+        .iter()
+        .filter(|&&krate_num| cx.tcx.crate_name(krate_num) == *krate)
+        .map(|&krate_num| DefId {
+            krate: krate_num,
+            index: CRATE_DEF_INDEX,
+        })
+        .collect();
+    for segment in segments {
+        def_ids = def_ids
+            .into_iter()
+            .flat_map(|def_id| children_named(cx, def_id, segment))
+            .collect();
+    }
+    def_ids
+}
+
+/// The items named `name` in the module, enum or trait `def_id`, or among the
+/// inherent associated items of the type `def_id`.
+fn children_named(cx: &LateContext<'_, '_>, def_id: DefId, name: &str) -> Vec<DefId> {
+    let mut children: Vec<DefId> = cx
+        .tcx
+        .item_children(def_id)
+        .iter()
+        .filter(|child| child.ident.name == name)
+        .filter_map(|child| child.def.opt_def_id())
+        .collect();
+    if let Some(Def::Struct(_)) | Some(Def::Enum(_)) | Some(Def::Union(_)) = cx.tcx.describe_def(def_id) {
+        for &impl_id in cx.tcx.inherent_impls(def_id).iter() {
+            children.extend(
+                cx.tcx
+                    .associated_item_def_ids(impl_id)
+                    .iter()
+                    .filter(|&&item_id| cx.tcx.associated_item(item_id).ident.name == name),
+            );
+        }
+    }
+    children
+}
+
 /// Check whether a type implements a trait.
 /// See also `get_trait_def_id`.
 pub fn implements_trait<'a, 'tcx>(
@@ -301,6 +368,33 @@ pub fn implements_trait<'a, 'tcx>(
         .enter(|infcx| infcx.predicate_must_hold_modulo_regions(&obligation))
 }
 
+/// Checks whether values of this type have to be used, because the type or one
+/// of its traits is marked `#[must_use]`.
+pub fn is_must_use_ty<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: Ty<'tcx>) -> bool {
+    let has_attr = |did| attr::contains_name(&cx.tcx.get_attrs(did), "must_use");
+    match ty.sty {
+        ty::Adt(adt, _) => has_attr(adt.did),
+        ty::Foreign(did) => has_attr(did),
+        ty::Slice(ty) | ty::Array(ty, _) => is_must_use_ty(cx, ty),
+        ty::Tuple(tys) => tys.iter().any(|ty| is_must_use_ty(cx, ty)),
+        ty::Opaque(did, _) => cx.tcx.predicates_of(did).predicates.iter().any(|(predicate, _)| {
+            if let ty::Predicate::Trait(ref poly_trait_predicate) = predicate {
+                has_attr(poly_trait_predicate.skip_binder().trait_ref.def_id)
+            } else {
+                false
+            }
+        }),
+        ty::Dynamic(ref binder, _) => binder.skip_binder().iter().any(|predicate| {
+            if let ty::ExistentialPredicate::Trait(ref trait_ref) = predicate {
+                has_attr(trait_ref.def_id)
+            } else {
+                false
+            }
+        }),
+        _ => false,
+    }
+}
+
 /// Check whether this type implements Drop.
 pub fn has_drop<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: Ty<'tcx>) -> bool {
     match ty.ty_adt_def() {
@@ -309,6 +403,13 @@ pub fn has_drop<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: Ty<'tcx>) -> bool {
     }
 }
 
+/// Checks whether dropping a value of this type runs any code, i.e. whether
+/// the type or one of its fields implements `Drop`. Type parameters are
+/// assumed to need dropping.
+pub fn has_drop_glue<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: Ty<'tcx>) -> bool {
+    ty.needs_drop(cx.tcx, cx.param_env)
+}
+
 /// Resolve the definition of a node from its `HirId`.
 pub fn resolve_node(cx: &LateContext<'_, '_>, qpath: &QPath, id: HirId) -> def::Def {
     cx.tables.qpath_def(qpath, id)
@@ -469,6 +570,81 @@ pub fn snippet_opt<'a, T: LintContext<'a>>(cx: &T, span: Span) -> Option<String>
     cx.sess().source_map().span_to_snippet(span).ok()
 }
 
+/// Replaces the spans in `edits`, which must be in `span` and not overlap, in
+/// the `snippet` of `span`.
+pub fn apply_edits(snippet: &str, span: Span, mut edits: Vec<(Span, String)>) -> Option<String> {
+    edits.sort_by_key(|&(span, _)| span.lo());
+    let mut result = String::new();
+    let mut pos = span.lo();
+    for (edit, replacement) in edits {
+        if edit.lo() < pos || span.hi() < edit.hi() {
+            return None;
+        }
+        result.push_str(snippet.get((pos - span.lo()).to_usize()..(edit.lo() - span.lo()).to_usize())?);
+        result.push_str(&replacement);
+        pos = edit.hi();
+    }
+    result.push_str(snippet.get((pos - span.lo()).to_usize()..)?);
+    Some(result)
+}
+
+/// A snippet that is only read from the source map when it is displayed or
+/// `get` is called, see `snippet_ref`.
+pub struct SnippetRef<'a, T> {
+    cx: &'a T,
+    span: Span,
+    default: &'a str,
+}
+
+impl<'a, T> Clone for SnippetRef<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for SnippetRef<'a, T> {}
+
+impl<'a, 'b, T: LintContext<'b>> SnippetRef<'a, T> {
+    /// Reads the snippet, like `snippet`.
+    pub fn get(&self) -> Cow<'a, str> {
+        snippet(self.cx, self.span, self.default)
+    }
+
+    /// Reads the snippet and adapts the applicability level, like
+    /// `snippet_with_applicability`.
+    pub fn get_with_applicability(&self, applicability: &mut Applicability) -> Cow<'a, str> {
+        snippet_with_applicability(self.cx, self.span, self.default, applicability)
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<'a, 'b, T: LintContext<'b>> fmt::Display for SnippetRef<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.get())
+    }
+}
+
+/// Like `snippet`, but returns a handle that reads the snippet only when it is
+/// used.
+///
+/// Looking up snippets is expensive compared to most checks. Lints should
+/// create the handles up front, but only format them in the closure of
+/// `span_lint_and_then`, which is not called if the lint is allowed.
+///
+/// # Example
+/// ```rust,ignore
+/// let recv = snippet_ref(cx, args[0].span, "..");
+/// span_lint_and_then(cx, LINT, expr.span, "message", |db| {
+///     db.span_suggestion(expr.span, "try", format!("{}.len()", recv), applicability);
+/// });
+/// ```
+pub fn snippet_ref<'a, 'b, T: LintContext<'b>>(cx: &'a T, span: Span, default: &'a str) -> SnippetRef<'a, T> {
+    SnippetRef { cx, span, default }
+}
+
 /// Convert a span (from a block) to a code snippet if available, otherwise use
 /// default.
 /// This trims the code of indentation, except for the first line. Use it for
@@ -701,6 +877,15 @@ pub fn is_direct_expn_of(span: Span, name: &str) -> Option<Span> {
     }
 }
 
+/// Returns the span of a function signature up to and including the return
+/// type, given the span of the function item.
+pub fn fn_sig_span(cx: &LateContext<'_, '_>, item_span: Span, decl: &FnDecl) -> Span {
+    // a default return has an empty span at the start of the next token
+    cx.sess()
+        .source_map()
+        .span_until_char(item_span.with_hi(decl.output.span().hi()), '{')
+}
+
 /// Convenience function to get the return type of a function
 pub fn return_ty<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, fn_item: hir::HirId) -> Ty<'tcx> {
     let fn_def_id = cx.tcx.hir().local_def_id_from_hir_id(fn_item);
@@ -0,0 +1,69 @@
+//! Support for the `msrv` option: the minimum supported Rust version of the
+//! checked crate. Lints don't suggest features that were stabilized later.
+
+use semver::Version;
+
+/// The Rust version of the toolchain Clippy is built for, assumed if no MSRV
+/// is set.
+pub const CURRENT: [u64; 3] = [1, 35, 0];
+
+/// `matches!`
+pub const MATCHES_MACRO: [u64; 3] = [1, 42, 0];
+/// The associated constants of the numeric types, like `i32::MAX`
+pub const NUMERIC_ASSOCIATED_CONSTANTS: [u64; 3] = [1, 43, 0];
+/// `bool::then`
+pub const BOOL_THEN: [u64; 3] = [1, 50, 0];
+/// `#[default]` on enum variants, for `#[derive(Default)]`
+pub const DEFAULT_ENUM_ATTRIBUTE: [u64; 3] = [1, 62, 0];
+/// `Seek::stream_position`
+pub const SEEK_STREAM_POSITION: [u64; 3] = [1, 51, 0];
+/// `Seek::rewind`
+pub const SEEK_REWIND: [u64; 3] = [1, 55, 0];
+/// `Duration::saturating_sub`
+pub const DURATION_SATURATING_SUB: [u64; 3] = [1, 53, 0];
+/// `BTreeSet::retain`
+pub const BTREE_SET_RETAIN: [u64; 3] = [1, 53, 0];
+/// `Iterator::map_while`
+pub const ITER_MAP_WHILE: [u64; 3] = [1, 57, 0];
+/// `BuildHasher::hash_one`
+pub const BUILD_HASHER_HASH_ONE: [u64; 3] = [1, 71, 0];
+/// `const { ... }` initializers in `thread_local!`
+pub const THREAD_LOCAL_CONST_INIT: [u64; 3] = [1, 59, 0];
+/// `From` conversions between arrays and tuples
+pub const TUPLE_ARRAY_CONVERSIONS: [u64; 3] = [1, 71, 0];
+/// `c"..."` literals
+pub const C_STR_LITERALS: [u64; 3] = [1, 77, 0];
+
+/// Parses the `msrv` option, e.g. `1.40` or `1.40.0`.
+pub fn parse(msrv: &str) -> Result<Version, String> {
+    let full = if msrv.matches('.').count() == 1 {
+        format!("{}.0", msrv)
+    } else {
+        msrv.to_string()
+    };
+    Version::parse(&full).map_err(|_| format!("`{}` is not a valid Rust version", msrv))
+}
+
+/// Checks whether a feature stabilized in `version` can be used, i.e. if the
+/// MSRV, or the current Rust version if none is set, is at least `version`.
+pub fn meets(msrv: Option<&Version>, version: [u64; 3]) -> bool {
+    let msrv = msrv.map_or(CURRENT, |msrv| [msrv.major, msrv.minor, msrv.patch]);
+    msrv >= version
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_and_compare() {
+        assert_eq!(parse("1.40"), Ok(Version::new(1, 40, 0)));
+        assert_eq!(parse("1.40.1"), Ok(Version::new(1, 40, 1)));
+        assert!(parse("1").is_err());
+        assert!(parse("latest").is_err());
+        assert!(meets(None, CURRENT));
+        assert!(!meets(None, SEEK_REWIND));
+        assert!(meets(Some(&Version::new(1, 55, 0)), SEEK_REWIND));
+        assert!(!meets(Some(&Version::new(1, 54, 3)), SEEK_REWIND));
+    }
+}
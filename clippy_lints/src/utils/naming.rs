@@ -0,0 +1,106 @@
+//! Helpers for the lints on names repeating the name of their container,
+//! like enum variants or struct fields.
+
+/// Returns the number of chars that match from the start
+pub fn partial_match(pre: &str, name: &str) -> usize {
+    let mut name_iter = name.chars();
+    let _ = name_iter.next_back(); // make sure the name is never fully matched
+    pre.chars().zip(name_iter).take_while(|&(l, r)| l == r).count()
+}
+
+/// Returns the number of chars that match from the end
+pub fn partial_rmatch(post: &str, name: &str) -> usize {
+    let mut name_iter = name.chars();
+    let _ = name_iter.next(); // make sure the name is never fully matched
+    post.chars()
+        .rev()
+        .zip(name_iter.rev())
+        .take_while(|&(l, r)| l == r)
+        .count()
+}
+
+/// Turns `snake_case` into `SnakeCase`, other names are returned unchanged.
+pub fn to_camel_case(item_name: &str) -> String {
+    let mut s = String::new();
+    let mut up = true;
+    for c in item_name.chars() {
+        if c.is_uppercase() {
+            // we only turn snake case text into CamelCase
+            return item_name.to_string();
+        }
+        if c == '_' {
+            up = true;
+            continue;
+        }
+        if up {
+            up = false;
+            s.extend(c.to_uppercase());
+        } else {
+            s.push(c);
+        }
+    }
+    s
+}
+
+/// Turns `CamelCase` into `camel_case`. Runs of capitals like in `HTTPServer`
+/// are kept together as one word.
+pub fn to_snake_case(item_name: &str) -> String {
+    let mut s = String::new();
+    let mut prev_lower = false;
+    for c in item_name.chars() {
+        if c.is_uppercase() {
+            if prev_lower {
+                s.push('_');
+            }
+            s.extend(c.to_lowercase());
+            prev_lower = false;
+        } else {
+            s.push(c);
+            prev_lower = c.is_lowercase() || c.is_numeric();
+        }
+    }
+    s
+}
+
+/// The longest run of words that all `snake_case` `names` start with,
+/// leaving at least one word in every name. Empty if there is none.
+pub fn common_snake_prefix(names: &[&str]) -> String {
+    common_words(names, false)
+}
+
+/// The longest run of words that all `snake_case` `names` end with, leaving
+/// at least one word in every name. Empty if there is none.
+pub fn common_snake_postfix(names: &[&str]) -> String {
+    common_words(names, true)
+}
+
+fn common_words(names: &[&str], from_end: bool) -> String {
+    let words = names
+        .iter()
+        .map(|name| {
+            let mut words = name.split('_').collect::<Vec<_>>();
+            if from_end {
+                words.reverse();
+            }
+            words
+        })
+        .collect::<Vec<_>>();
+    let first = match words.first() {
+        Some(first) => first,
+        None => return String::new(),
+    };
+    let len = words.iter().fold(first.len() - 1, |len, other| {
+        let matching = first
+            .iter()
+            .zip(other)
+            .take_while(|&(l, r)| !l.is_empty() && l == r)
+            .count();
+        // a name can't consist of the common words only
+        len.min(matching).min(other.len() - 1)
+    });
+    let mut common = first[..len].to_vec();
+    if from_end {
+        common.reverse();
+    }
+    common.join("_")
+}
@@ -0,0 +1,108 @@
+//! Per-path lint levels from the `overrides` configuration.
+//!
+//! ```toml
+//! [[overrides]]
+//! paths = ["tests/**", "benches/**"]
+//! allow = ["clippy::pedantic"]
+//! warn = ["clippy::needless_pass_by_value"]
+//! ```
+//!
+//! An override applies to the files matching one of its `paths` globs. Later
+//! overrides take precedence over earlier ones, and within an override a lint
+//! named explicitly takes precedence over its groups. Overrides only change
+//! the level of lints that would be emitted; they cannot enable lints that are
+//! allowed by default or in the code.
+
+use crate::utils::conf;
+use crate::utils::generated::file_matches_any;
+use lazy_static::lazy_static;
+use rustc::lint::{Level, Lint, LintContext, LintId};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use std::sync::Mutex;
+use syntax::source_map::Span;
+
+lazy_static! {
+    static ref OVERRIDES: Mutex<Vec<Override>> = Mutex::new(Vec::new());
+}
+
+struct Override {
+    /// Globs of the files the override applies to.
+    globs: Vec<String>,
+    /// Levels of lints named explicitly.
+    lints: FxHashMap<LintId, Level>,
+    /// Levels of lints from the named groups.
+    groups: FxHashMap<LintId, Level>,
+}
+
+impl Override {
+    fn level(&self, lint: LintId) -> Option<Level> {
+        self.lints.get(&lint).or_else(|| self.groups.get(&lint)).cloned()
+    }
+}
+
+/// Resolves the lint and group names of the `overrides` configuration, given
+/// all Clippy lint groups. Called once when registering the lints. Returns an
+/// error message for each unknown name.
+pub fn init<'a>(overrides: &[conf::Override], groups: impl Iterator<Item = (&'a str, &'a [LintId])>) -> Vec<String> {
+    let groups: FxHashMap<&str, &[LintId]> = groups.collect();
+    let lints: FxHashMap<String, LintId> = groups
+        .values()
+        .flat_map(|lints| lints.iter())
+        .collect::<FxHashSet<_>>()
+        .into_iter()
+        .map(|&lint| (lint.to_string(), lint))
+        .collect();
+
+    let mut errors = Vec::new();
+    let mut resolved = Vec::new();
+    for entry in overrides {
+        let mut resolved_entry = Override {
+            globs: entry.paths.clone(),
+            lints: FxHashMap::default(),
+            groups: FxHashMap::default(),
+        };
+        for (names, level) in &[
+            (&entry.allow, Level::Allow),
+            (&entry.warn, Level::Warn),
+            (&entry.deny, Level::Deny),
+        ] {
+            for name in names.iter() {
+                let name = normalize_name(name);
+                if let Some(group) = groups.get(&*name) {
+                    resolved_entry.groups.extend(group.iter().map(|&lint| (lint, *level)));
+                } else if let Some(&lint) = lints.get(&name) {
+                    resolved_entry.lints.insert(lint, *level);
+                } else {
+                    errors.push(format!("unknown lint or lint group `{}` in `overrides`", name));
+                }
+            }
+        }
+        resolved.push(resolved_entry);
+    }
+    *OVERRIDES.lock().expect("no threading here") = resolved;
+    errors
+}
+
+/// Normalizes a lint or group name from the configuration, e.g.
+/// `needless-return` to `clippy::needless_return`.
+pub fn normalize_name(name: &str) -> String {
+    let name = name.replace('-', "_");
+    if name.starts_with("clippy::") {
+        name
+    } else {
+        format!("clippy::{}", name)
+    }
+}
+
+/// Returns the level the `overrides` configuration sets for `lint` at
+/// `span`, if any.
+pub fn level<'a, T: LintContext<'a>>(cx: &T, lint: &'static Lint, span: Span) -> Option<Level> {
+    let overrides = OVERRIDES.lock().expect("no threading here");
+    let lint = LintId::of(lint);
+    overrides
+        .iter()
+        .rev()
+        .filter_map(|entry| entry.level(lint).map(|level| (entry, level)))
+        .find(|(entry, _)| file_matches_any(cx, &entry.globs, span))
+        .map(|(_, level)| level)
+}
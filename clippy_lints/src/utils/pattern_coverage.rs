@@ -0,0 +1,220 @@
+//! Coverage of the alternatives of `pattern!` definitions.
+//!
+//! A pattern like `Expr(If(..)) | Semi(If(..))` has one branch per
+//! alternative, and nothing checks that the UI tests exercise all of them.
+//! If the `CLIPPY_PATTERN_COVERAGE` environment variable is set, the
+//! `pattern!` definitions of the lint modules are matched against all
+//! expressions, statements, blocks and items with the `ast_pattern` interpreter, and
+//! the branches used by the matches are appended to the file it names.
+//! `util/dev pattern_coverage <file>` then reports the branches that were
+//! never used.
+//!
+//! The file has one line per alternation of each pattern,
+//! `alternation <pattern> <index> <branches>`, and one line per used branch,
+//! `used <pattern> <index> <branch>`.
+
+use crate::utils::ast_pattern::{Pattern, Value};
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintContext, LintPass};
+use rustc::lint_array;
+use rustc_data_structures::fx::FxHashSet;
+use std::env;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use syntax::ast;
+
+/// The lint modules with `pattern!` definitions.
+const SOURCES: [(&str, &str); 1] = [("collapsible_if", include_str!("../collapsible_if.rs"))];
+
+/// A `pattern!` definition.
+struct Definition {
+    /// `module::name`
+    name: String,
+    /// The node kind the pattern matches, e.g. `Expr`.
+    kind: String,
+    pattern: Pattern,
+}
+
+pub struct PatternCoverage {
+    file: PathBuf,
+    definitions: Vec<Definition>,
+    /// Definitions that the interpreter can't parse, reported once per crate.
+    errors: Vec<String>,
+    /// `(definition, alternation, branch)`
+    used: FxHashSet<(usize, usize, usize)>,
+}
+
+impl PatternCoverage {
+    /// Returns the pass if `CLIPPY_PATTERN_COVERAGE` is set.
+    pub fn from_env() -> Option<Self> {
+        let file = PathBuf::from(env::var_os("CLIPPY_PATTERN_COVERAGE")?);
+        let mut definitions = Vec::new();
+        let mut errors = Vec::new();
+        for &(module, src) in &SOURCES {
+            for (name, kind, pattern) in parse_definitions(src) {
+                let name = format!("{}::{}", module, name);
+                match Pattern::parse(pattern) {
+                    Ok(pattern) => definitions.push(Definition {
+                        name,
+                        kind: kind.to_string(),
+                        pattern,
+                    }),
+                    Err(e) => errors.push(format!("cannot measure the coverage of `{}`: {}", name, e)),
+                }
+            }
+        }
+        Some(Self {
+            file,
+            definitions,
+            errors,
+            used: FxHashSet::default(),
+        })
+    }
+
+    fn check(&mut self, kind: &str, value: Value<'_>) {
+        for (index, definition) in self.definitions.iter().enumerate() {
+            if definition.kind != kind {
+                continue;
+            }
+            if let Some(used) = definition.pattern.match_alternatives(value) {
+                self.used.extend(
+                    used.into_iter()
+                        .map(|(alternation, branch)| (index, alternation, branch)),
+                );
+            }
+        }
+    }
+}
+
+/// Returns the name, node kind and pattern of the `pattern!{ name: Kind = .. }`
+/// definitions in `src`.
+fn parse_definitions(src: &str) -> Vec<(&str, &str, &str)> {
+    src.split("pattern!")
+        .skip(1)
+        .filter_map(|rest| {
+            let rest = rest.trim_start();
+            if !rest.starts_with('{') {
+                return None;
+            }
+            let body = &rest[1..rest.find('}')?];
+            let colon = body.find(':')?;
+            let eq = body.find('=')?;
+            if eq < colon {
+                return None;
+            }
+            Some((body[..colon].trim(), body[colon + 1..eq].trim(), body[eq + 1..].trim()))
+        })
+        .collect()
+}
+
+impl LintPass for PatternCoverage {
+    fn get_lints(&self) -> LintArray {
+        lint_array!()
+    }
+
+    fn name(&self) -> &'static str {
+        "PatternCoverage"
+    }
+}
+
+impl EarlyLintPass for PatternCoverage {
+    fn check_crate(&mut self, cx: &EarlyContext<'_>, _: &ast::Crate) {
+        for error in &self.errors {
+            cx.sess().warn(error);
+        }
+    }
+
+    fn check_expr(&mut self, _: &EarlyContext<'_>, expr: &ast::Expr) {
+        self.check("Expr", Value::Expr(expr));
+    }
+
+    fn check_block(&mut self, _: &EarlyContext<'_>, block: &ast::Block) {
+        self.check("Block", Value::Block(block));
+    }
+
+    fn check_stmt(&mut self, _: &EarlyContext<'_>, stmt: &ast::Stmt) {
+        self.check("Stmt", Value::Stmt(stmt));
+    }
+
+    fn check_item(&mut self, _: &EarlyContext<'_>, item: &ast::Item) {
+        self.check("Item", Value::Item(item));
+    }
+
+    fn check_crate_post(&mut self, cx: &EarlyContext<'_>, _: &ast::Crate) {
+        let mut out = String::new();
+        for definition in &self.definitions {
+            for (alternation, branches) in definition.pattern.alternations().into_iter().enumerate() {
+                out.push_str(&format!(
+                    "alternation {} {} {}\n",
+                    definition.name, alternation, branches
+                ));
+            }
+        }
+        for &(index, alternation, branch) in &self.used {
+            out.push_str(&format!(
+                "used {} {} {}\n",
+                self.definitions[index].name, alternation, branch
+            ));
+        }
+        // the UI tests run in parallel, so the whole output is written at once
+        let written = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.file)
+            .and_then(|mut file| file.write_all(out.as_bytes()));
+        if let Err(e) = written {
+            cx.sess().warn(&format!(
+                "cannot write pattern coverage to `{}`: {}",
+                self.file.display(),
+                e
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use syntax::parse::{parse_expr_from_source_str, ParseSess};
+    use syntax::source_map::{FileName, FilePathMapping};
+
+    #[test]
+    fn definitions() {
+        let src = "use pattern::pattern;\n\npattern!{\n    pat_a: Expr =\n        If(_, _, ()) | Loop(_)\n}\n\
+                   // pattern! is mentioned here\npattern! { pat_b: Stmt = Semi(_) }";
+        assert_eq!(
+            parse_definitions(src),
+            vec![
+                ("pat_a", "Expr", "If(_, _, ()) | Loop(_)"),
+                ("pat_b", "Stmt", "Semi(_)")
+            ]
+        );
+        for &(_, src) in &SOURCES {
+            for (name, _, pattern) in parse_definitions(src) {
+                assert!(Pattern::parse(pattern).is_ok(), "cannot parse `{}`", name);
+            }
+        }
+    }
+
+    #[test]
+    fn if_let_alternatives() {
+        syntax::with_globals(|| {
+            let sess = ParseSess::new(FilePathMapping::empty());
+            let src = "if let Some(x) = a {} else { if let Some(y) = b {} }";
+            let expr = parse_expr_from_source_str(FileName::Custom("test".to_string()), src.to_string(), &sess)
+                .map_err(|mut e| e.cancel())
+                .expect("cannot parse the expression");
+            let (_, _, pattern) = parse_definitions(SOURCES[0].1)
+                .into_iter()
+                .find(|&(name, _, _)| name == "pat_if_else")
+                .expect("`collapsible_if::pat_if_else` is gone");
+            let mut used = Pattern::parse(pattern)
+                .unwrap()
+                .match_alternatives(Value::Expr(&expr))
+                .expect("the pattern doesn't match");
+            used.sort();
+            // the `IfLet` branch, then `Expr(..)` and `IfLet` in its else block
+            assert_eq!(used, vec![(0, 1), (4, 0), (5, 1)]);
+        });
+    }
+}
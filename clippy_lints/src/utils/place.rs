@@ -0,0 +1,96 @@
+//! Syntactic approximation of whether two place expressions refer to the same
+//! memory.
+//!
+//! This does not look at the values of pointers or indices, so it can only
+//! tell that two places are definitely the same or that they are derived from
+//! the same base expression, never that they are disjoint.
+
+use crate::utils::SpanlessEq;
+use rustc::hir::*;
+use rustc::lint::LateContext;
+
+/// How two places relate to each other, see `place_aliasing`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Aliasing {
+    /// Both expressions refer to the same place, e.g. `*p` and `*(p as *mut _)`.
+    Same,
+    /// The places are derived from the same base, e.g. `v[i]` and `v[j]` or
+    /// `*p` and `*p.add(1)`. They may or may not overlap.
+    SameBase,
+    /// Nothing is known about the two places.
+    Unknown,
+}
+
+/// Compares two place expressions, looking through borrows, dereferences,
+/// casts and `unsafe` blocks.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // `Aliasing::Same`
+/// place_aliasing(cx, &mut *(x as *mut u32), &mut x);
+/// ```
+pub fn place_aliasing(cx: &LateContext<'_, '_>, left: &Expr, right: &Expr) -> Aliasing {
+    let left = strip_place(left);
+    let right = strip_place(right);
+    if SpanlessEq::new(cx).ignore_fn().eq_expr(left, right) {
+        Aliasing::Same
+    } else if SpanlessEq::new(cx).eq_expr(place_base(left), place_base(right)) {
+        Aliasing::SameBase
+    } else {
+        Aliasing::Unknown
+    }
+}
+
+/// Removes borrows, dereferences, casts and single-expression blocks around a
+/// place, which do not change the memory it refers to.
+pub fn strip_place(mut expr: &Expr) -> &Expr {
+    loop {
+        expr = match expr.node {
+            ExprKind::AddrOf(_, ref inner) | ExprKind::Unary(UnDeref, ref inner) | ExprKind::Cast(ref inner, _) => {
+                inner
+            },
+            ExprKind::Block(ref block, _) if block.stmts.is_empty() && block.expr.is_some() => {
+                block.expr.as_ref().expect("checked above")
+            },
+            _ => return expr,
+        };
+    }
+}
+
+/// Returns the expression a place is projected from, e.g. `v` for `v[i].x`.
+/// Pointer offsets with `add`, `sub` and `offset` are considered projections
+/// as well.
+fn place_base(expr: &Expr) -> &Expr {
+    let mut expr = strip_place(expr);
+    loop {
+        expr = match expr.node {
+            ExprKind::Field(ref base, _) | ExprKind::Index(ref base, _) => strip_place(base),
+            ExprKind::MethodCall(ref path, _, ref args)
+                if [
+                    "add",
+                    "sub",
+                    "offset",
+                    "wrapping_add",
+                    "wrapping_sub",
+                    "wrapping_offset",
+                ]
+                .iter()
+                .any(|&name| path.ident.name == name) =>
+            {
+                strip_place(&args[0])
+            },
+            _ => return expr,
+        };
+    }
+}
+
+/// Checks whether `expr` denotes a place (an lvalue) rather than a temporary
+/// value.
+pub fn is_place(expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::Path(..) | ExprKind::Unary(UnDeref, _) => true,
+        ExprKind::Field(ref base, _) | ExprKind::Index(ref base, _) => is_place(base),
+        _ => false,
+    }
+}
@@ -0,0 +1,39 @@
+//! The Rust versions the items of the standard library were stabilized in,
+//! for checking them against the `msrv` option.
+
+use crate::utils::msrvs;
+use rustc::hir::def_id::DefId;
+use rustc::ty::TyCtxt;
+use rustc_data_structures::fx::FxHashMap;
+use semver::Version;
+use syntax::attr::StabilityLevel;
+
+/// The versions items were stabilized in, read from their
+/// `#[stable(since = "..")]` attributes the first time they are looked up.
+#[derive(Default)]
+pub struct StabilityTable {
+    versions: FxHashMap<DefId, Option<Version>>,
+}
+
+impl StabilityTable {
+    /// The version `def_id` was stabilized in. `None` for unstable items and
+    /// ones without stability attributes, like the items of the checked crate.
+    pub fn stable_since(&mut self, tcx: TyCtxt<'_, '_, '_>, def_id: DefId) -> Option<&Version> {
+        self.versions
+            .entry(def_id)
+            .or_insert_with(|| match tcx.lookup_stability(def_id)?.level {
+                StabilityLevel::Stable { since } => msrvs::parse(&since.as_str()).ok(),
+                StabilityLevel::Unstable { .. } => None,
+            })
+            .as_ref()
+    }
+
+    /// Checks whether `def_id` can be used with `msrv`, i.e. if no MSRV is
+    /// set, the item has no stable version or it was stabilized in time.
+    pub fn meets(&mut self, tcx: TyCtxt<'_, '_, '_>, msrv: Option<&Version>, def_id: DefId) -> bool {
+        match (msrv, self.stable_since(tcx, def_id)) {
+            (Some(msrv), Some(since)) => msrv >= since,
+            _ => true,
+        }
+    }
+}
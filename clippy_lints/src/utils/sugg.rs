@@ -219,6 +219,12 @@ impl<'a> Sugg<'a> {
         make_unop("&mut *", self)
     }
 
+    /// Convenience method to create the `<expr>.into()` suggestion, spelling out
+    /// the `From` conversion the `?` operator does implicitly.
+    pub fn convert_into(self) -> Sugg<'static> {
+        Sugg::NonParen(Cow::Owned(format!("{}.into()", self.maybe_par())))
+    }
+
     /// Convenience method to transform suggestion into a return call
     pub fn make_return(self) -> Sugg<'static> {
         Sugg::NonParen(Cow::Owned(format!("return {}", self)))
@@ -619,4 +625,11 @@ mod test {
     fn blockify_transforms_sugg_into_a_block() {
         assert_eq!("{ function_call() }", SUGGESTION.blockify().to_string());
     }
+
+    #[test]
+    fn convert_into_adds_parens_if_needed() {
+        assert_eq!("function_call().into()", SUGGESTION.convert_into().to_string());
+        let sugg = Sugg::MaybeParen(Cow::Borrowed("*error"));
+        assert_eq!("(*error).into()", sugg.convert_into().to_string());
+    }
 }
@@ -0,0 +1,49 @@
+//! Support for lints whose advice depends on the compilation target, e.g.
+//! because they suggest platform specific APIs.
+
+use rustc::lint::LintContext;
+
+/// The family of the compilation target, like in `#[cfg(unix)]` and
+/// `#[cfg(windows)]`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TargetFamily {
+    Unix,
+    Windows,
+    Other,
+}
+
+impl TargetFamily {
+    pub fn of<'tcx, T: LintContext<'tcx>>(cx: &T) -> Self {
+        match cx
+            .sess()
+            .target
+            .target
+            .options
+            .target_family
+            .as_ref()
+            .map(String::as_str)
+        {
+            Some("unix") => TargetFamily::Unix,
+            Some("windows") => TargetFamily::Windows,
+            _ => TargetFamily::Other,
+        }
+    }
+}
+
+/// A value for each target family, e.g. the help text of a lint.
+pub struct PerTarget<T> {
+    pub unix: T,
+    pub windows: T,
+    pub other: T,
+}
+
+impl<T> PerTarget<T> {
+    /// Returns the value for the target of the current compilation.
+    pub fn get<'tcx, C: LintContext<'tcx>>(&self, cx: &C) -> &T {
+        match TargetFamily::of(cx) {
+            TargetFamily::Unix => &self.unix,
+            TargetFamily::Windows => &self.windows,
+            TargetFamily::Other => &self.other,
+        }
+    }
+}
@@ -1,11 +1,15 @@
 use rustc::lint::LateContext;
 
+use crate::utils::get_parent_expr;
+use matches::matches;
 use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
 use rustc::hir::*;
 use rustc::middle::expr_use_visitor::*;
 use rustc::middle::mem_categorization::cmt_;
 use rustc::middle::mem_categorization::Categorization;
 use rustc::ty;
+use rustc::ty::adjustment::Adjust;
 use rustc_data_structures::fx::FxHashSet;
 use syntax::source_map::Span;
 
@@ -37,6 +41,55 @@ pub fn is_potentially_mutated<'a, 'tcx: 'a>(
     mutated_variables(expr, cx).map_or(true, |mutated| mutated.contains(&cx.tcx.hir().node_to_hir_id(id)))
 }
 
+/// Checks whether the local variable `id` is only read through in `expr`:
+/// every use dereferences it, explicitly or by auto-deref, and nothing is
+/// mutated through it. Uses that move or borrow the variable itself, e.g. to
+/// pass a guard to a function, don't count as reads.
+pub fn is_only_read_through<'a, 'tcx: 'a>(cx: &'a LateContext<'a, 'tcx>, id: HirId, expr: &'tcx Expr) -> bool {
+    if mutated_variables(expr, cx).map_or(true, |mutated| mutated.contains(&id)) {
+        return false;
+    }
+    let mut visitor = DerefUseVisitor {
+        cx,
+        id,
+        only_deref: true,
+    };
+    visitor.visit_expr(expr);
+    visitor.only_deref
+}
+
+struct DerefUseVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    id: HirId,
+    only_deref: bool,
+}
+
+impl<'a, 'tcx: 'a> Visitor<'tcx> for DerefUseVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if let ExprKind::Path(QPath::Resolved(None, ref path)) = expr.node {
+            if let Def::Local(id) | Def::Upvar(id, ..) = path.def {
+                if self.cx.tcx.hir().node_to_hir_id(id) == self.id {
+                    let explicit = get_parent_expr(self.cx, expr)
+                        .map_or(false, |parent| matches!(parent.node, ExprKind::Unary(UnDeref, _)));
+                    let auto = self
+                        .cx
+                        .tables
+                        .expr_adjustments(expr)
+                        .first()
+                        .map_or(false, |adjustment| matches!(adjustment.kind, Adjust::Deref(Some(_))));
+                    self.only_deref &= explicit || auto;
+                    return;
+                }
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir())
+    }
+}
+
 struct MutVarsDelegate {
     used_mutably: FxHashSet<HirId>,
     skip: bool,
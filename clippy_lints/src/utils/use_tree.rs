@@ -0,0 +1,172 @@
+//! Flattening `use` trees into single imports and building them up again,
+//! for the lints on the import style.
+
+use syntax::ast::{UseTree, UseTreeKind};
+use syntax::symbol::keywords;
+
+/// What an import brings into scope.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Leaf {
+    /// The item at the path, with its new name if it is renamed.
+    Item(Option<String>),
+    /// All items of the module at the path.
+    Glob,
+}
+
+/// A single path of a `use` tree, like `a::b::C` for the `C` in
+/// `use a::{b::{C, D}, E};`. A leading `::` is an empty first segment.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Import {
+    pub segments: Vec<String>,
+    pub leaf: Leaf,
+}
+
+impl Import {
+    /// The path of the module the import is made from, empty for a crate
+    /// imported by its name.
+    pub fn module(&self) -> &[String] {
+        match self.leaf {
+            Leaf::Item(_) => &self.segments[..self.segments.len() - 1],
+            Leaf::Glob => &self.segments,
+        }
+    }
+}
+
+/// The imports of `tree`, in the order they are written.
+pub fn flatten(tree: &UseTree) -> Vec<Import> {
+    let mut imports = Vec::new();
+    flatten_into(tree, &mut Vec::new(), &mut imports);
+    imports
+}
+
+fn flatten_into(tree: &UseTree, prefix: &mut Vec<String>, imports: &mut Vec<Import>) {
+    let len = prefix.len();
+    prefix.extend(tree.prefix.segments.iter().map(|segment| {
+        if segment.ident.name == keywords::PathRoot.name() {
+            String::new()
+        } else {
+            segment.ident.to_string()
+        }
+    }));
+    match tree.kind {
+        UseTreeKind::Simple(rename, ..) => {
+            let mut segments = prefix.clone();
+            // the `self` of `a::{self, B}` is `a`
+            if segments.len() > 1 && segments.last().map_or(false, |last| last == "self") {
+                segments.pop();
+            }
+            imports.push(Import {
+                segments,
+                leaf: Leaf::Item(rename.map(|rename| rename.to_string())),
+            });
+        },
+        UseTreeKind::Glob => imports.push(Import {
+            segments: prefix.clone(),
+            leaf: Leaf::Glob,
+        }),
+        UseTreeKind::Nested(ref trees) => {
+            for (tree, _) in trees {
+                flatten_into(tree, prefix, imports);
+            }
+        },
+    }
+    prefix.truncate(len);
+}
+
+/// Builds the smallest `use` trees importing `imports`, one per first
+/// segment, e.g. `a::{b::{C, D}, E}` for `a::b::C`, `a::b::D` and `a::E`.
+/// Imports keep the order they are given in, duplicates are dropped.
+pub fn build(imports: &[Import]) -> Vec<String> {
+    let mut roots = Vec::new();
+    for import in imports {
+        insert(&mut roots, &import.segments, &import.leaf);
+    }
+    roots.iter().map(Node::render).collect()
+}
+
+/// A path segment with the imports ending at it, and the longer paths
+/// continuing from it.
+struct Node {
+    name: String,
+    leaves: Vec<Leaf>,
+    children: Vec<Node>,
+}
+
+fn insert(nodes: &mut Vec<Node>, segments: &[String], leaf: &Leaf) {
+    let (name, rest) = match segments.split_first() {
+        Some(split) => split,
+        None => return,
+    };
+    let index = match nodes.iter().position(|node| node.name == *name) {
+        Some(index) => index,
+        None => {
+            nodes.push(Node {
+                name: name.clone(),
+                leaves: Vec::new(),
+                children: Vec::new(),
+            });
+            nodes.len() - 1
+        },
+    };
+    let node = &mut nodes[index];
+    if !rest.is_empty() {
+        insert(&mut node.children, rest, leaf);
+    } else if !node.leaves.contains(leaf) {
+        node.leaves.push(leaf.clone());
+    }
+}
+
+impl Node {
+    fn render(&self) -> String {
+        if self.children.is_empty() && self.leaves.len() == 1 {
+            return match self.leaves[0] {
+                Leaf::Item(None) => self.name.clone(),
+                Leaf::Item(Some(ref rename)) => format!("{} as {}", self.name, rename),
+                Leaf::Glob => format!("{}::*", self.name),
+            };
+        }
+        let mut inner: Vec<String> = self
+            .leaves
+            .iter()
+            .map(|leaf| match *leaf {
+                Leaf::Item(None) => "self".to_string(),
+                Leaf::Item(Some(ref rename)) => format!("self as {}", rename),
+                Leaf::Glob => "*".to_string(),
+            })
+            .collect();
+        inner.extend(self.children.iter().map(Node::render));
+        if inner.len() == 1 {
+            format!("{}::{}", self.name, inner[0])
+        } else {
+            format!("{}::{{{}}}", self.name, inner.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn import(path: &str, leaf: Leaf) -> Import {
+        Import {
+            segments: path.split("::").map(str::to_string).collect(),
+            leaf,
+        }
+    }
+
+    #[test]
+    fn build_trees() {
+        let imports = [
+            import("a::b::C", Leaf::Item(None)),
+            import("a::b::D", Leaf::Item(Some("E".to_string()))),
+            import("a::b", Leaf::Item(None)),
+            import("a::f", Leaf::Glob),
+            import("a::b::C", Leaf::Item(None)),
+            import("g::H", Leaf::Item(None)),
+            import("::i::J", Leaf::Item(None)),
+        ];
+        assert_eq!(build(&imports), ["a::{b::{self, C, D as E}, f::*}", "g::H", "::i::J"]);
+        assert_eq!(imports[2].module(), ["a".to_string()]);
+        assert_eq!(imports[3].module(), ["a".to_string(), "f".to_string()]);
+    }
+}
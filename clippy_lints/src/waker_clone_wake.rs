@@ -0,0 +1,154 @@
+use crate::utils::conf::CloneConsumeMethod;
+use crate::utils::{get_def_path, in_macro, snippet_with_applicability, span_lint_and_sugg, walk_ptrs_ty};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `cx.waker().clone().wake()`, and other
+    /// values that are cloned only to be consumed by a method that has a
+    /// by-reference counterpart, like `s.to_owned().as_str()`.
+    ///
+    /// More methods can be configured with the `clone-then-consume-methods`
+    /// option, e.g. `clone-then-consume-methods = [{ path = "my_crate::Handle",
+    /// method = "close", replacement = "close_ref" }]`.
+    ///
+    /// **Why is this bad?** The clone is thrown away right after, calling the
+    /// by-reference method on the original does the same without it. Cloning
+    /// a `Waker` can be as expensive as an allocation.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::task::Context;
+    /// # fn f(cx: &mut Context<'_>) {
+    /// cx.waker().clone().wake();
+    /// # }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::task::Context;
+    /// # fn f(cx: &mut Context<'_>) {
+    /// cx.waker().wake_by_ref();
+    /// # }
+    /// ```
+    pub WAKER_CLONE_WAKE,
+    perf,
+    "cloning a value only to call a method taking it by value that has a by-reference counterpart"
+}
+
+/// The methods linted without configuration, as the path of the type, the
+/// consuming method and its by-reference counterpart.
+const METHODS: [(&str, &str, &str); 4] = [
+    ("core::task::wake::Waker", "wake", "wake_by_ref"),
+    ("alloc::string::String", "as_str", "as_str"),
+    ("alloc::vec::Vec", "as_slice", "as_slice"),
+    ("std::path::PathBuf", "as_path", "as_path"),
+];
+
+pub struct WakerCloneWake {
+    /// The type paths with the consuming method and its replacement.
+    methods: Vec<(Vec<String>, String, String)>,
+}
+
+impl WakerCloneWake {
+    pub fn new(config: &[CloneConsumeMethod]) -> Self {
+        let split = |path: &str| path.split("::").map(str::to_string).collect();
+        Self {
+            methods: METHODS
+                .iter()
+                .map(|&(path, method, replacement)| (split(path), method.to_string(), replacement.to_string()))
+                .chain(
+                    config
+                        .iter()
+                        .map(|entry| (split(&entry.path), entry.method.clone(), entry.replacement.clone())),
+                )
+                .collect(),
+        }
+    }
+
+    /// The replacement of `method` called on a clone of the type at
+    /// `def_path`, if it is linted.
+    fn replacement(&self, def_path: &[&str], method: &str) -> Option<&str> {
+        let is_std = |name: &str| name == "std" || name == "core" || name == "alloc";
+        self.methods
+            .iter()
+            .find(|(path, consume, _)| {
+                consume == method
+                    && path.len() == def_path.len()
+                    && path
+                        .iter()
+                        .zip(def_path)
+                        .all(|(p, d)| p == d || (is_std(p) && is_std(d)))
+            })
+            .map(|(_, _, replacement)| &replacement[..])
+    }
+}
+
+impl LintPass for WakerCloneWake {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(WAKER_CLONE_WAKE)
+    }
+
+    fn name(&self) -> &'static str {
+        "WakerCloneWake"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for WakerCloneWake {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        let (method, args) = match expr.node {
+            ExprKind::MethodCall(ref path, _, ref args) => (path.ident.as_str(), args),
+            _ => return,
+        };
+        let receiver = match args[0].node {
+            ExprKind::MethodCall(ref path, _, ref clone_args)
+                if (path.ident.name == "clone" || path.ident.name == "to_owned") && clone_args.len() == 1 =>
+            {
+                &clone_args[0]
+            },
+            _ => return,
+        };
+        // only clones of the same type, `str::to_owned` makes a `String`
+        let ty = cx.tables.expr_ty(&args[0]);
+        if walk_ptrs_ty(cx.tables.expr_ty(receiver)) != ty {
+            return;
+        }
+        let adt = match ty.sty {
+            ty::Adt(adt, _) => adt,
+            _ => return,
+        };
+        let def_path = get_def_path(cx.tcx, adt.did);
+        if let Some(replacement) = self.replacement(&def_path, &method) {
+            let mut applicability = Applicability::MachineApplicable;
+            let args = args[1..]
+                .iter()
+                .map(|arg| snippet_with_applicability(cx, arg.span, "..", &mut applicability))
+                .collect::<Vec<_>>();
+            span_lint_and_sugg(
+                cx,
+                WAKER_CLONE_WAKE,
+                expr.span,
+                &format!(
+                    "cloning a `{}` only to call `{}` on it",
+                    def_path.last().expect("paths are never empty"),
+                    method
+                ),
+                &format!("use `{}` instead", replacement),
+                format!(
+                    "{}.{}({})",
+                    snippet_with_applicability(cx, receiver.span, "..", &mut applicability),
+                    replacement,
+                    args.join(", ")
+                ),
+                applicability,
+            );
+        }
+    }
+}
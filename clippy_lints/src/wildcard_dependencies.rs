@@ -1,3 +1,4 @@
+use crate::utils::manifest::Manifest;
 use crate::utils::span_lint;
 use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
 use rustc::{declare_tool_lint, lint_array};
@@ -48,7 +49,9 @@ impl EarlyLintPass for Pass {
             return;
         };
 
-        for dep in &metadata.packages[0].dependencies {
+        let package = &metadata.packages[0];
+        let manifest = Manifest::load(cx, &package.manifest_path);
+        for dep in &package.dependencies {
             // VersionReq::any() does not work
             if_chain! {
                 if let Ok(wildcard_ver) = semver::VersionReq::parse("*");
@@ -56,10 +59,13 @@ impl EarlyLintPass for Pass {
                 if !source.starts_with("git");
                 if dep.req == wildcard_ver;
                 then {
+                    let span = manifest
+                        .as_ref()
+                        .map_or(DUMMY_SP, |manifest| manifest.span(manifest.dependency(&dep.name)));
                     span_lint(
                         cx,
                         WILDCARD_DEPENDENCIES,
-                        DUMMY_SP,
+                        span,
                         &format!("wildcard dependency for `{}`", dep.name),
                     );
                 }
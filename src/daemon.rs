@@ -0,0 +1,298 @@
+//! A long-running `clippy-driver` for tools that check a workspace again and
+//! again, like editors checking on every save.
+//!
+//! `clippy-driver --clippy-daemon=<socket>` listens on a Unix socket. The
+//! `clippy-driver`s that `cargo clippy --daemon=<socket>` starts forward the
+//! crates they would check to it, and only print what it reports. The daemon
+//! looks up the sysroot once, and parses `clippy.toml` again only when it
+//! changes. Everything tied to a compiler session, like the lint passes and
+//! the resolved paths, is still set up for every crate, since the compiler
+//! has no way to keep it across sessions.
+//!
+//! The protocol is JSON-RPC 2.0, one message per line. `check` takes the
+//! arguments, working directory and environment of a `clippy-driver`
+//! invocation, and returns its exit code and diagnostics. `shutdown` stops the
+//! daemon. Requests are handled one at a time, as the compiler reads the
+//! environment and the working directory of the process.
+
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+use std::io::{self, BufRead, BufReader, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+use super::output::Output;
+
+#[derive(Deserialize, Serialize)]
+struct Request {
+    jsonrpc: String,
+    #[serde(default)]
+    id: Value,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Deserialize, Serialize)]
+struct Response {
+    jsonrpc: String,
+    id: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    result: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<ResponseError>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct ResponseError {
+    code: i64,
+    message: String,
+}
+
+/// The parameters of `check`.
+#[derive(Deserialize, Serialize)]
+struct Check {
+    /// The arguments of `clippy-driver`, starting with the program name.
+    args: Vec<String>,
+    cwd: PathBuf,
+    env: Vec<(String, String)>,
+}
+
+/// The result of `check`.
+#[derive(Deserialize, Serialize)]
+struct CheckResult {
+    exit_code: i32,
+    /// The diagnostics, as they would be printed on stderr.
+    stderr: String,
+}
+
+impl Response {
+    fn result(id: Value, result: Value) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            id,
+            result: Some(result),
+            error: None,
+        }
+    }
+
+    fn error(id: Value, code: i64, message: String) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            id,
+            result: None,
+            error: Some(ResponseError { code, message }),
+        }
+    }
+}
+
+/// Serves requests on the Unix socket at `path` until a `shutdown` request.
+///
+/// `check` runs the compiler with the arguments of a `clippy-driver`
+/// invocation, writing the diagnostics to the given output, and returns the
+/// exit code.
+pub fn serve<F>(path: &Path, mut check: F) -> io::Result<()>
+where
+    F: FnMut(Vec<String>, Box<dyn Write + Send>) -> i32,
+{
+    let listener = socket::bind(path)?;
+    for stream in listener.incoming() {
+        // a client failing doesn't concern the other clients
+        let result = stream.and_then(|stream| {
+            let reader = BufReader::new(stream.try_clone()?);
+            handle_connection(reader, stream, &mut check)
+        });
+        match result {
+            Ok(true) => break,
+            Ok(false) => {},
+            Err(err) => eprintln!("clippy daemon: {}", err),
+        }
+    }
+    fs::remove_file(path)
+}
+
+/// Sends the `clippy-driver` invocation with `args` to the daemon listening at
+/// `path`, prints the diagnostics and returns the exit code.
+pub fn forward(path: &Path, args: &[String]) -> io::Result<i32> {
+    let stream = socket::connect(path)?;
+    let check = Check {
+        args: args.to_vec(),
+        cwd: env::current_dir()?,
+        // the compiler only reads variables with valid names and values
+        env: env::vars_os()
+            .filter_map(|(key, value)| Some((key.into_string().ok()?, value.into_string().ok()?)))
+            .collect(),
+    };
+    let request = Request {
+        jsonrpc: "2.0".to_string(),
+        id: Value::from(1),
+        method: "check".to_string(),
+        params: serde_json::to_value(check)?,
+    };
+    writeln!(&stream, "{}", serde_json::to_string(&request)?)?;
+
+    let mut line = String::new();
+    BufReader::new(&stream).read_line(&mut line)?;
+    let response: Response = serde_json::from_str(&line)?;
+    if let Some(error) = response.error {
+        return Err(io::Error::new(io::ErrorKind::Other, error.message));
+    }
+    let result: CheckResult = serde_json::from_value(response.result.unwrap_or_default())?;
+    io::stderr().write_all(result.stderr.as_bytes())?;
+    Ok(result.exit_code)
+}
+
+/// Answers the requests of one client. Returns whether the daemon should shut
+/// down.
+fn handle_connection<F>(reader: impl BufRead, mut writer: impl Write, check: &mut F) -> io::Result<bool>
+where
+    F: FnMut(Vec<String>, Box<dyn Write + Send>) -> i32,
+{
+    for line in reader.lines() {
+        let (response, shutdown) = handle_request(&line?, check);
+        writeln!(writer, "{}", serde_json::to_string(&response)?)?;
+        if shutdown {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Answers one request, with the error codes of the JSON-RPC specification.
+/// Returns whether the daemon should shut down.
+fn handle_request<F>(line: &str, check: &mut F) -> (Response, bool)
+where
+    F: FnMut(Vec<String>, Box<dyn Write + Send>) -> i32,
+{
+    let request: Request = match serde_json::from_str(line) {
+        Ok(request) => request,
+        Err(err) => return (Response::error(Value::Null, -32700, err.to_string()), false),
+    };
+    match &*request.method {
+        "check" => {
+            let params = match serde_json::from_value(request.params) {
+                Ok(params) => params,
+                Err(err) => return (Response::error(request.id, -32602, err.to_string()), false),
+            };
+            let response = match run_check(params, check) {
+                Ok(result) => Response::result(
+                    request.id,
+                    serde_json::to_value(result).expect("the result is serializable"),
+                ),
+                Err(err) => Response::error(request.id, -32000, err.to_string()),
+            };
+            (response, false)
+        },
+        "shutdown" => (Response::result(request.id, Value::Null), true),
+        method => (
+            Response::error(request.id, -32601, format!("unknown method `{}`", method)),
+            false,
+        ),
+    }
+}
+
+/// Runs `check` in the working directory and environment of the request,
+/// restoring the ones of the daemon afterwards.
+fn run_check<F>(params: Check, check: &mut F) -> io::Result<CheckResult>
+where
+    F: FnMut(Vec<String>, Box<dyn Write + Send>) -> i32,
+{
+    let daemon_cwd = env::current_dir()?;
+    let daemon_env: Vec<_> = env::vars_os().collect();
+    env::set_current_dir(&params.cwd)?;
+    set_env(params.env);
+
+    let output = Output::default();
+    let diagnostics = Box::new(output.clone());
+    // the compiler reports internal errors on stderr by itself
+    let exit_code = panic::catch_unwind(AssertUnwindSafe(|| check(params.args, diagnostics))).unwrap_or(101);
+
+    set_env(daemon_env);
+    env::set_current_dir(daemon_cwd)?;
+    Ok(CheckResult {
+        exit_code,
+        stderr: output.take(),
+    })
+}
+
+/// Replaces all environment variables with `vars`.
+fn set_env<K: AsRef<std::ffi::OsStr>, V: AsRef<std::ffi::OsStr>>(vars: Vec<(K, V)>) {
+    for (key, _) in env::vars_os() {
+        env::remove_var(key);
+    }
+    for (key, value) in vars {
+        env::set_var(key, value);
+    }
+}
+
+#[cfg(unix)]
+mod socket {
+    use std::io;
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::path::Path;
+
+    pub fn bind(path: &Path) -> io::Result<UnixListener> {
+        UnixListener::bind(path)
+    }
+
+    pub fn connect(path: &Path) -> io::Result<UnixStream> {
+        UnixStream::connect(path)
+    }
+}
+
+#[cfg(not(unix))]
+mod socket {
+    use std::io;
+    use std::net::{TcpListener, TcpStream};
+    use std::path::Path;
+
+    fn unsupported() -> io::Error {
+        io::Error::new(io::ErrorKind::Other, "the Clippy daemon needs Unix sockets")
+    }
+
+    pub fn bind(_: &Path) -> io::Result<TcpListener> {
+        Err(unsupported())
+    }
+
+    pub fn connect(_: &Path) -> io::Result<TcpStream> {
+        Err(unsupported())
+    }
+}
+
+#[test]
+fn test_handle_connection() {
+    let cwd = env::current_dir().unwrap();
+    let requests = format!(
+        "{}\n{}\n{}\n{}\n",
+        r#"{"jsonrpc":"2.0","id":1,"method":"check","params":{"args":["clippy-driver","lib.rs"],"cwd":"/","env":[["CLIPPY_TEST_VAR","1"]]}}"#,
+        r#"{"jsonrpc":"2.0","id":2,"method":"build"}"#,
+        r#"{"jsonrpc":"2.0","id":3,"method":"shutdown"}"#,
+        r#"{"jsonrpc":"2.0","id":4,"method":"check"}"#,
+    );
+    let mut responses = Vec::new();
+    let mut check = |args: Vec<String>, mut output: Box<dyn Write + Send>| {
+        assert_eq!(args, ["clippy-driver", "lib.rs"]);
+        assert_eq!(env::current_dir().unwrap(), Path::new("/"));
+        assert_eq!(env::var("CLIPPY_TEST_VAR").as_ref().map(String::as_str), Ok("1"));
+        writeln!(output, "warning: lint").unwrap();
+        1
+    };
+
+    let shutdown = handle_connection(requests.as_bytes(), &mut responses, &mut check).unwrap();
+    assert!(shutdown);
+    assert_eq!(env::current_dir().unwrap(), cwd);
+    assert!(env::var_os("CLIPPY_TEST_VAR").is_none());
+    let responses: Vec<Response> = String::from_utf8(responses)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(responses.len(), 3);
+    let result: CheckResult = serde_json::from_value(responses[0].result.clone().unwrap()).unwrap();
+    assert_eq!(result.exit_code, 1);
+    assert_eq!(result.stderr, "warning: lint\n");
+    assert_eq!(responses[1].error.as_ref().unwrap().code, -32601);
+    assert_eq!(responses[2].id, Value::from(3));
+    assert!(responses[2].error.is_none());
+}
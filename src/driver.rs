@@ -6,15 +6,22 @@
 // FIXME: switch to something more ergonomic here, once available.
 // (currently there is no way to opt into sysroot crates w/o `extern crate`)
 #[allow(unused_extern_crates)]
-extern crate rustc_driver;
+extern crate rustc;
 #[allow(unused_extern_crates)]
-extern crate rustc_plugin;
+extern crate rustc_driver;
+use self::rustc::session::{CompileResult, Session};
 use self::rustc_driver::{driver::CompileController, Compilation};
 
 use std::convert::TryInto;
+use std::env;
+use std::io::{self, Write};
 use std::path::Path;
 use std::process::{exit, Command};
 
+mod daemon;
+mod feature_sets;
+mod output;
+
 fn show_version() {
     println!(env!("CARGO_PKG_VERSION"));
 }
@@ -59,143 +66,223 @@ fn test_arg_value() {
     assert_eq!(arg_value(&args, "--foo", |_| true), None);
 }
 
-#[allow(clippy::too_many_lines)]
+/// Removes `--clippy-explain-span=<file>:<line>` from the arguments, returning
+/// its value. The option is for Clippy only, rustc would reject it.
+fn take_explain_span(args: &mut Vec<String>) -> Option<String> {
+    const OPTION: &str = "--clippy-explain-span=";
+    let index = args.iter().position(|arg| arg.starts_with(OPTION))?;
+    Some(args.remove(index)[OPTION.len()..].to_owned())
+}
+
+#[test]
+fn test_take_explain_span() {
+    let mut args: Vec<_> = ["--foo", "--clippy-explain-span=src/lib.rs:3", "--bar"]
+        .iter()
+        .map(std::string::ToString::to_string)
+        .collect();
+
+    assert_eq!(take_explain_span(&mut args), Some("src/lib.rs:3".to_owned()));
+    assert_eq!(args, ["--foo", "--bar"]);
+    assert_eq!(take_explain_span(&mut args), None);
+}
+
+/// Looks for the sysroot, from the most specific to this invocation to the
+/// least:
+/// - runtime environment
+///    - SYSROOT
+///    - RUSTUP_HOME, MULTIRUST_HOME, RUSTUP_TOOLCHAIN, MULTIRUST_TOOLCHAIN
+/// - sysroot from rustc in the path
+/// - compile-time environment
+///
+/// A `--sysroot` on the command line takes precedence over all of them.
+fn find_sys_root() -> String {
+    env::var("SYSROOT")
+        .ok()
+        .or_else(|| {
+            let home = option_env!("RUSTUP_HOME").or(option_env!("MULTIRUST_HOME"));
+            let toolchain = option_env!("RUSTUP_TOOLCHAIN").or(option_env!("MULTIRUST_TOOLCHAIN"));
+            home.and_then(|home| toolchain.map(|toolchain| format!("{}/toolchains/{}", home, toolchain)))
+        })
+        .or_else(|| {
+            Command::new("rustc")
+                .arg("--print")
+                .arg("sysroot")
+                .output()
+                .ok()
+                .and_then(|out| String::from_utf8(out.stdout).ok())
+                .map(|s| s.trim().to_owned())
+        })
+        .or_else(|| option_env!("SYSROOT").map(String::from))
+        .expect("need to specify SYSROOT env var during clippy compilation, or use rustup or multirust")
+}
+
+/// Whether the crate is checked with Clippy: dependencies are built but not
+/// linted, and the final crate is linted but not built.
+fn clippy_enabled(orig_args: &[String]) -> bool {
+    env::var("CLIPPY_TESTS").ok().map_or(false, |val| val == "true")
+        || arg_value(orig_args, "--emit", |val| val.split(',').any(|e| e == "metadata")).is_some()
+}
+
+/// Turns the arguments of a `clippy-driver` invocation into the arguments of
+/// the compiler, and sets the options of Clippy if the crate is checked with
+/// it. Returns the arguments, and whether Clippy is enabled.
+fn compiler_args(mut orig_args: Vec<String>, sys_root: impl FnOnce() -> String) -> Result<(Vec<String>, bool), String> {
+    if orig_args
+        .get(1)
+        .map_or(false, |arg| Path::new(arg).file_stem() == Some("rustc".as_ref()))
+    {
+        // we still want to be able to invoke it normally though
+        orig_args.remove(1);
+    }
+    // this conditional check for the --sysroot flag is there so users can call
+    // `clippy_driver` directly
+    // without having to pass --sysroot or anything
+    let mut args: Vec<String> = if arg_value(&orig_args, "--sysroot", |_| true).is_some() {
+        orig_args.clone()
+    } else {
+        orig_args
+            .clone()
+            .into_iter()
+            .chain(Some("--sysroot".to_owned()))
+            .chain(Some(sys_root()))
+            .collect()
+    };
+
+    let clippy_enabled = clippy_enabled(&orig_args);
+    if clippy_enabled {
+        args.extend_from_slice(&[
+            "--cfg".to_owned(),
+            "clippy".to_owned(),
+            "--cfg".to_owned(),
+            r#"feature="cargo-clippy""#.to_owned(),
+        ]);
+        if let Ok(extra_args) = env::var("CLIPPY_ARGS") {
+            args.extend(extra_args.split("__CLIPPY_HACKERY__").filter_map(|s| {
+                if s.is_empty() {
+                    None
+                } else {
+                    Some(s.to_string())
+                }
+            }));
+        }
+
+        // the daemon checks many crates
+        clippy_lints::reset_options();
+        if let Some(changed_files) = env::var_os("CLIPPY_CHANGED_FILES") {
+            clippy_lints::set_changed_files(env::split_paths(&changed_files));
+        }
+        if env::var_os("CLIPPY_FIX_DRY_RUN").is_some() {
+            clippy_lints::set_fix_dry_run();
+        }
+        if let Some(spec) = take_explain_span(&mut args) {
+            clippy_lints::set_explain_span(&spec).map_err(|err| format!("invalid `--clippy-explain-span`: {}", err))?;
+        }
+    }
+    Ok((args, clippy_enabled))
+}
+
+/// Runs the compiler, with the Clippy lints if `clippy_enabled`, writing the
+/// diagnostics to `diagnostics` or to stderr if it's `None`.
+fn run_compiler(
+    args: &[String],
+    clippy_enabled: bool,
+    diagnostics: Option<Box<dyn Write + Send>>,
+) -> (CompileResult, Option<Session>) {
+    let mut controller = if clippy_enabled {
+        clippy_lints::driver::controller()
+    } else {
+        CompileController::basic()
+    };
+    controller.compilation_done.stop = Compilation::Stop;
+
+    rustc_driver::run_compiler(args, Box::new(controller), None, diagnostics)
+}
+
+/// Checks the crate of a `clippy-driver` invocation, writing the diagnostics
+/// to `diagnostics` or to stderr if it's `None`. Returns the exit code.
+fn check(orig_args: Vec<String>, sys_root: &dyn Fn() -> String, diagnostics: Option<Box<dyn Write + Send>>) -> i32 {
+    match compiler_args(orig_args, sys_root) {
+        Ok((args, clippy_enabled)) => rustc_driver::run(move || run_compiler(&args, clippy_enabled, diagnostics))
+            .try_into()
+            .expect("exit code too large"),
+        Err(err) => {
+            let mut diagnostics = diagnostics.unwrap_or_else(|| Box::new(io::stderr()));
+            let _ = writeln!(diagnostics, "error: {}", err);
+            1
+        },
+    }
+}
+
+/// Like `check`, but once per feature set if `cargo clippy --feature-set`
+/// asked for it, see `feature_sets`. Dependencies are not linted, so they
+/// are checked once.
+fn check_feature_sets(
+    orig_args: Vec<String>,
+    sys_root: &dyn Fn() -> String,
+    diagnostics: Option<Box<dyn Write + Send>>,
+) -> i32 {
+    let is_linted = clippy_enabled(&orig_args) && arg_value(&orig_args, "--cap-lints", |_| true).is_none();
+    match feature_sets::from_env() {
+        Some(ref sets) if is_linted => {
+            let mut out = diagnostics.unwrap_or_else(|| Box::new(io::stderr()));
+            feature_sets::check_each(
+                &orig_args,
+                sets,
+                |args, diagnostics| check(args, sys_root, Some(diagnostics)),
+                &mut out,
+            )
+        },
+        _ => check(orig_args, sys_root, diagnostics),
+    }
+}
+
+/// Serves the crates forwarded by other `clippy-driver`s, see `daemon`.
+fn serve(socket: &Path) -> i32 {
+    let sys_root = find_sys_root();
+    let result = daemon::serve(socket, |orig_args, diagnostics| {
+        check_feature_sets(orig_args, &|| sys_root.clone(), Some(diagnostics))
+    });
+    match result {
+        Ok(()) => 0,
+        Err(err) => {
+            eprintln!("error: the Clippy daemon at `{}` failed: {}", socket.display(), err);
+            1
+        },
+    }
+}
+
 pub fn main() {
     rustc_driver::init_rustc_env_logger();
-    exit(
-        rustc_driver::run(move || {
-            use std::env;
 
-            if std::env::args().any(|a| a == "--version" || a == "-V") {
-                show_version();
-                exit(0);
-            }
+    if env::args().any(|a| a == "--version" || a == "-V") {
+        show_version();
+        exit(0);
+    }
 
-            let mut orig_args: Vec<String> = env::args().collect();
-
-            // Get the sysroot, looking from most specific to this invocation to the least:
-            // - command line
-            // - runtime environment
-            //    - SYSROOT
-            //    - RUSTUP_HOME, MULTIRUST_HOME, RUSTUP_TOOLCHAIN, MULTIRUST_TOOLCHAIN
-            // - sysroot from rustc in the path
-            // - compile-time environment
-            let sys_root_arg = arg_value(&orig_args, "--sysroot", |_| true);
-            let have_sys_root_arg = sys_root_arg.is_some();
-            let sys_root = sys_root_arg
-                .map(std::string::ToString::to_string)
-                .or_else(|| std::env::var("SYSROOT").ok())
-                .or_else(|| {
-                    let home = option_env!("RUSTUP_HOME").or(option_env!("MULTIRUST_HOME"));
-                    let toolchain = option_env!("RUSTUP_TOOLCHAIN").or(option_env!("MULTIRUST_TOOLCHAIN"));
-                    home.and_then(|home| toolchain.map(|toolchain| format!("{}/toolchains/{}", home, toolchain)))
-                })
-                .or_else(|| {
-                    Command::new("rustc")
-                        .arg("--print")
-                        .arg("sysroot")
-                        .output()
-                        .ok()
-                        .and_then(|out| String::from_utf8(out.stdout).ok())
-                        .map(|s| s.trim().to_owned())
-                })
-                .or_else(|| option_env!("SYSROOT").map(String::from))
-                .expect("need to specify SYSROOT env var during clippy compilation, or use rustup or multirust");
-
-            // Setting RUSTC_WRAPPER causes Cargo to pass 'rustc' as the first argument.
-            // We're invoking the compiler programmatically, so we ignore this/
-            if orig_args.len() <= 1 {
-                std::process::exit(1);
-            }
-            if Path::new(&orig_args[1]).file_stem() == Some("rustc".as_ref()) {
-                // we still want to be able to invoke it normally though
-                orig_args.remove(1);
-            }
-            // this conditional check for the --sysroot flag is there so users can call
-            // `clippy_driver` directly
-            // without having to pass --sysroot or anything
-            let mut args: Vec<String> = if have_sys_root_arg {
-                orig_args.clone()
-            } else {
-                orig_args
-                    .clone()
-                    .into_iter()
-                    .chain(Some("--sysroot".to_owned()))
-                    .chain(Some(sys_root))
-                    .collect()
-            };
-
-            // this check ensures that dependencies are built but not linted and the final
-            // crate is
-            // linted but not built
-            let clippy_enabled = env::var("CLIPPY_TESTS").ok().map_or(false, |val| val == "true")
-                || arg_value(&orig_args, "--emit", |val| val.split(',').any(|e| e == "metadata")).is_some();
-
-            if clippy_enabled {
-                args.extend_from_slice(&["--cfg".to_owned(), r#"feature="cargo-clippy""#.to_owned()]);
-                if let Ok(extra_args) = env::var("CLIPPY_ARGS") {
-                    args.extend(extra_args.split("__CLIPPY_HACKERY__").filter_map(|s| {
-                        if s.is_empty() {
-                            None
-                        } else {
-                            Some(s.to_string())
-                        }
-                    }));
-                }
-            }
+    let orig_args: Vec<String> = env::args().collect();
 
-            let mut controller = CompileController::basic();
-            if clippy_enabled {
-                controller.after_parse.callback = Box::new(move |state| {
-                    let mut registry = rustc_plugin::registry::Registry::new(
-                        state.session,
-                        state
-                            .krate
-                            .as_ref()
-                            .expect(
-                                "at this compilation stage \
-                                 the crate must be parsed",
-                            )
-                            .span,
-                    );
-                    registry.args_hidden = Some(Vec::new());
-
-                    let conf = clippy_lints::read_conf(&registry);
-                    clippy_lints::register_plugins(&mut registry, &conf);
-
-                    let rustc_plugin::registry::Registry {
-                        early_lint_passes,
-                        late_lint_passes,
-                        lint_groups,
-                        llvm_passes,
-                        attributes,
-                        ..
-                    } = registry;
-                    let sess = &state.session;
-                    let mut ls = sess.lint_store.borrow_mut();
-                    for pass in early_lint_passes {
-                        ls.register_early_pass(Some(sess), true, false, pass);
-                    }
-                    for pass in late_lint_passes {
-                        ls.register_late_pass(Some(sess), true, pass);
-                    }
-
-                    for (name, (to, deprecated_name)) in lint_groups {
-                        ls.register_group(Some(sess), true, name, deprecated_name, to);
-                    }
-                    clippy_lints::register_pre_expansion_lints(sess, &mut ls, &conf);
-                    clippy_lints::register_renamed(&mut ls);
-
-                    sess.plugin_llvm_passes.borrow_mut().extend(llvm_passes);
-                    sess.plugin_attributes.borrow_mut().extend(attributes);
-                });
+    if let Some(socket) = arg_value(&orig_args, "--clippy-daemon", |_| true) {
+        exit(serve(Path::new(socket)));
+    }
+
+    // Setting RUSTC_WRAPPER causes Cargo to pass 'rustc' as the first argument.
+    // We're invoking the compiler programmatically, so we ignore this/
+    if orig_args.len() <= 1 {
+        exit(1);
+    }
+
+    if let Some(socket) = env::var_os("CLIPPY_DAEMON") {
+        if clippy_enabled(&orig_args) {
+            match daemon::forward(Path::new(&socket), &orig_args) {
+                Ok(code) => exit(code),
+                Err(err) => eprintln!(
+                    "warning: could not check the crate in the Clippy daemon at `{}`, checking it here: {}",
+                    Path::new(&socket).display(),
+                    err
+                ),
             }
-            controller.compilation_done.stop = Compilation::Stop;
+        }
+    }
 
-            let args = args;
-            rustc_driver::run_compiler(&args, Box::new(controller), None, None)
-        })
-        .try_into()
-        .expect("exit code too large"),
-    )
+    exit(check_feature_sets(orig_args, &find_sys_root, None))
 }
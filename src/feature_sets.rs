@@ -0,0 +1,437 @@
+//! Checking a crate once per feature set, for `cargo clippy --feature-set`.
+//!
+//! Code behind `#[cfg(feature = "..")]` is invisible to the lints unless the
+//! feature is enabled, so a single check misses lints, and checks with
+//! different features report different ones. `cargo clippy` builds the
+//! package with the union of the sets, so that all optional dependencies are
+//! available, and passes the sets in `CLIPPY_FEATURE_SETS`.
+//!
+//! For each crate that is linted, the driver expands the sets with the
+//! `[features]` table of its manifest and runs one compiler session per set,
+//! with the `feature` cfgs of the set instead of the ones of Cargo and JSON
+//! diagnostics. These sessions write their outputs to a scratch directory,
+//! and each set keeps its incremental state in a directory of its own.
+//! The session with the features of Cargo runs last and writes the real
+//! outputs, as the crates depending on this one need its metadata; if these
+//! features are not one of the sets, they are checked as an additional set.
+//!
+//! The diagnostics of all sessions are then merged: a diagnostic reported
+//! with every set is printed once, as usual, and one reported with some sets
+//! only gets a note naming them.
+
+use serde_derive::Deserialize;
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{self, Write};
+use std::path::Path;
+use std::{env, fs, process};
+
+use super::arg_value;
+use super::output::Output;
+
+/// The enabled features of a session.
+pub type FeatureSet = BTreeSet<String>;
+
+/// The sets of `CLIPPY_FEATURE_SETS`, if `cargo clippy --feature-set` asked
+/// for them.
+pub fn from_env() -> Option<Vec<FeatureSet>> {
+    env::var("CLIPPY_FEATURE_SETS").ok().map(|sets| parse(&sets))
+}
+
+/// Parses sets separated by `;`, whose features are separated by `,` or
+/// spaces like in `cargo --features`. An empty set is the crate without any
+/// feature.
+fn parse(sets: &str) -> Vec<FeatureSet> {
+    sets.split(';')
+        .map(|set| {
+            set.split(|c| c == ',' || c == ' ')
+                .filter(|feature| !feature.is_empty())
+                .map(str::to_owned)
+                .collect()
+        })
+        .collect()
+}
+
+/// Checks the crate of the `clippy-driver` invocation `orig_args` once per
+/// set, writing the merged diagnostics to `out`. `check` runs a compiler
+/// session for the arguments of an invocation, writing its diagnostics to
+/// the given sink, and returns its exit code.
+pub fn check_each<F>(orig_args: &[String], sets: &[FeatureSet], mut check: F, out: &mut dyn Write) -> i32
+where
+    F: FnMut(Vec<String>, Box<dyn Write + Send>) -> i32,
+{
+    let table = match manifest_features() {
+        Ok(table) => table,
+        Err(err) => {
+            let _ = writeln!(out, "error: could not check the feature sets: {}", err);
+            return 1;
+        },
+    };
+    let mut expanded: Vec<FeatureSet> = Vec::new();
+    for set in sets {
+        let set = expand(set, &table);
+        if !expanded.contains(&set) {
+            expanded.push(set);
+        }
+    }
+    let cargo_set = cargo_features(orig_args);
+    expanded.retain(|set| *set != cargo_set);
+    expanded.push(cargo_set);
+
+    let scratch = env::temp_dir().join(format!("clippy-feature-sets-{}", process::id()));
+    if let Err(err) = fs::create_dir_all(&scratch) {
+        let _ = writeln!(out, "error: could not create `{}`: {}", scratch.display(), err);
+        return 1;
+    }
+    let mut merged = Merged::default();
+    let mut code = 0;
+    for (index, set) in expanded.iter().enumerate() {
+        let is_last = index + 1 == expanded.len();
+        let out_dir = if is_last { None } else { Some(scratch.as_path()) };
+        let output = Output::default();
+        let session_code = check(session_args(orig_args, set, out_dir), Box::new(output.clone()));
+        if code == 0 {
+            code = session_code;
+        }
+        merged.add(index, &output.take(), is_last);
+    }
+    let _ = fs::remove_dir_all(&scratch);
+
+    if let Err(err) = merged.write(&expanded, is_json(orig_args), out) {
+        eprintln!("error: could not write the diagnostics: {}", err);
+        return 1;
+    }
+    code
+}
+
+/// The `[features]` table of the manifest of the crate Cargo is building.
+fn manifest_features() -> Result<BTreeMap<String, Vec<String>>, String> {
+    #[derive(Deserialize)]
+    struct Manifest {
+        #[serde(default)]
+        features: BTreeMap<String, Vec<String>>,
+    }
+
+    let dir = env::var_os("CARGO_MANIFEST_DIR").ok_or("`CARGO_MANIFEST_DIR` is not set")?;
+    let path = Path::new(&dir).join("Cargo.toml");
+    let contents = fs::read_to_string(&path).map_err(|err| format!("could not read `{}`: {}", path.display(), err))?;
+    let manifest: Manifest =
+        toml::from_str(&contents).map_err(|err| format!("could not parse `{}`: {}", path.display(), err))?;
+    Ok(manifest.features)
+}
+
+/// Adds the features that the features of `set` enable according to
+/// `table`. Features of dependencies, like `serde/std`, don't change the
+/// cfgs of the crate.
+fn expand(set: &FeatureSet, table: &BTreeMap<String, Vec<String>>) -> FeatureSet {
+    let mut expanded = FeatureSet::new();
+    let mut pending: Vec<&str> = set.iter().map(String::as_str).collect();
+    while let Some(feature) = pending.pop() {
+        if expanded.insert(feature.to_owned()) {
+            if let Some(enabled) = table.get(feature) {
+                pending.extend(enabled.iter().map(String::as_str).filter(|f| !f.contains('/')));
+            }
+        }
+    }
+    expanded
+}
+
+/// The feature of a `feature="<name>"` cfg.
+fn feature_cfg(cfg: &str) -> Option<&str> {
+    const PREFIX: &str = "feature=\"";
+    if cfg.len() > PREFIX.len() && cfg.starts_with(PREFIX) && cfg.ends_with('"') {
+        Some(&cfg[PREFIX.len()..cfg.len() - 1])
+    } else {
+        None
+    }
+}
+
+/// The features Cargo enabled, from the `--cfg feature="<name>"` arguments.
+fn cargo_features(args: &[String]) -> FeatureSet {
+    args.windows(2)
+        .filter(|pair| pair[0] == "--cfg")
+        .filter_map(|pair| feature_cfg(&pair[1]))
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Whether the diagnostics are printed as JSON, for Cargo.
+fn is_json(args: &[String]) -> bool {
+    arg_value(args, "--error-format", |format| format == "json").is_some()
+}
+
+/// `args` with the `feature` cfgs of `set` instead of the ones of Cargo, JSON
+/// diagnostics, and, if `out_dir` is given, the outputs written to it and
+/// the incremental state kept apart from the one of the other sets.
+fn session_args(args: &[String], set: &FeatureSet, out_dir: Option<&Path>) -> Vec<String> {
+    let mut session = Vec::with_capacity(args.len());
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--cfg" | "--error-format" | "--out-dir" | "-C" => {
+                let value = match args.next() {
+                    Some(value) => value,
+                    None => break,
+                };
+                match (arg.as_str(), out_dir) {
+                    ("--cfg", _) if feature_cfg(value).is_some() => {},
+                    ("--error-format", _) => {},
+                    ("--out-dir", Some(dir)) => {
+                        session.push(arg.clone());
+                        session.push(dir.display().to_string());
+                    },
+                    ("-C", Some(_)) if value.starts_with(INCREMENTAL) => {
+                        session.push(arg.clone());
+                        session.push(incremental_arg(&value[INCREMENTAL.len()..], set));
+                    },
+                    _ => {
+                        session.push(arg.clone());
+                        session.push(value.clone());
+                    },
+                }
+            },
+            _ if arg.starts_with("--error-format=") => {},
+            _ if out_dir.is_some() && arg.starts_with("-C") && arg[2..].starts_with(INCREMENTAL) => {
+                session.push(format!("-C{}", incremental_arg(&arg[2 + INCREMENTAL.len()..], set)));
+            },
+            _ => session.push(arg.clone()),
+        }
+    }
+    session.push("--error-format=json".to_owned());
+    for feature in set {
+        session.push("--cfg".to_owned());
+        session.push(format!("feature=\"{}\"", feature));
+    }
+    session
+}
+
+const INCREMENTAL: &str = "incremental=";
+
+/// The `incremental=<dir>` codegen option for `set`, with a subdirectory of
+/// Cargo's `dir` that is the same for `set` in every build.
+fn incremental_arg(dir: &str, set: &FeatureSet) -> String {
+    let subdir = format!("clippy-features-{}", set.iter().cloned().collect::<Vec<_>>().join(","));
+    format!("{}{}", INCREMENTAL, Path::new(dir).join(subdir).display())
+}
+
+/// The diagnostics of all sessions, in the order they were first reported.
+#[derive(Default)]
+struct Merged {
+    /// The diagnostics, with the indices of the sessions that reported them.
+    diagnostics: Vec<(Value, Vec<usize>)>,
+    /// The other output of the last session, like artifact notifications.
+    /// The ones of the other sessions point into the scratch directory.
+    other: Vec<String>,
+}
+
+impl Merged {
+    fn add(&mut self, session: usize, output: &str, is_last: bool) {
+        for line in output.lines() {
+            let diag: Value = serde_json::from_str(line).unwrap_or(Value::Null);
+            if diag.get("message").is_none() {
+                if is_last {
+                    self.other.push(line.to_owned());
+                }
+            } else if !is_abort_message(&diag) {
+                match self.diagnostics.iter_mut().find(|(other, _)| *other == diag) {
+                    Some((_, sessions)) => {
+                        if !sessions.contains(&session) {
+                            sessions.push(session);
+                        }
+                    },
+                    None => self.diagnostics.push((diag, vec![session])),
+                }
+            }
+        }
+    }
+
+    /// Writes the diagnostics as JSON or as rendered by rustc, with a note on
+    /// those that only some of `sets` reported.
+    fn write(self, sets: &[FeatureSet], json: bool, out: &mut dyn Write) -> io::Result<()> {
+        for line in &self.other {
+            writeln!(out, "{}", line)?;
+        }
+        let mut errors = 0;
+        for (mut diag, sessions) in self.diagnostics {
+            if sessions.len() < sets.len() {
+                let sets: Vec<_> = sessions.iter().map(|&index| describe(&sets[index])).collect();
+                add_note(
+                    &mut diag,
+                    &format!("only reported with the feature sets {}", sets.join(", ")),
+                );
+            }
+            if diag["level"] == "error" {
+                errors += 1;
+            }
+            emit(&diag, json, out)?;
+        }
+        // the sessions counted their own errors
+        if errors > 0 {
+            let message = format!(
+                "aborting due to {} previous error{}",
+                errors,
+                if errors == 1 { "" } else { "s" }
+            );
+            let diag = json!({
+                "message": message,
+                "code": null,
+                "level": "error",
+                "spans": [],
+                "children": [],
+                "rendered": format!("error: {}\n\n", message),
+            });
+            emit(&diag, json, out)?;
+        }
+        Ok(())
+    }
+}
+
+fn is_abort_message(diag: &Value) -> bool {
+    diag["message"]
+        .as_str()
+        .map_or(false, |message| message.starts_with("aborting due to"))
+        && diag["spans"].as_array().map_or(false, Vec::is_empty)
+}
+
+fn describe(set: &FeatureSet) -> String {
+    format!("[{}]", set.iter().cloned().collect::<Vec<_>>().join(", "))
+}
+
+/// Adds a note to the children and the rendered text of `diag`.
+fn add_note(diag: &mut Value, note: &str) {
+    if let Some(children) = diag["children"].as_array_mut() {
+        children.push(json!({
+            "message": note,
+            "code": null,
+            "level": "note",
+            "spans": [],
+            "children": [],
+            "rendered": null,
+        }));
+    }
+    let rendered = diag["rendered"].as_str().map(|rendered| {
+        let text = rendered.trim_end_matches('\n');
+        format!("{}\n   = note: {}{}", text, note, &rendered[text.len()..])
+    });
+    if let Some(rendered) = rendered {
+        diag["rendered"] = Value::from(rendered);
+    }
+}
+
+fn emit(diag: &Value, json: bool, out: &mut dyn Write) -> io::Result<()> {
+    if json {
+        writeln!(out, "{}", diag)
+    } else {
+        write!(out, "{}", diag["rendered"].as_str().unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn set(features: &[&str]) -> FeatureSet {
+        features.iter().map(|&feature| feature.to_owned()).collect()
+    }
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|&arg| arg.to_owned()).collect()
+    }
+
+    #[test]
+    fn parse_sets() {
+        assert_eq!(parse(""), [set(&[])]);
+        assert_eq!(parse("a,b;;c d"), [set(&["a", "b"]), set(&[]), set(&["c", "d"])]);
+    }
+
+    #[test]
+    fn expand_features() {
+        let mut table = BTreeMap::new();
+        table.insert("default".to_owned(), vec!["std".to_owned()]);
+        table.insert("full".to_owned(), vec!["default".to_owned(), "serde/derive".to_owned()]);
+        assert_eq!(expand(&set(&["full"]), &table), set(&["full", "default", "std"]));
+        assert_eq!(expand(&set(&["serde"]), &table), set(&["serde"]));
+    }
+
+    #[test]
+    fn replace_feature_cfgs() {
+        let cargo = args(&[
+            "clippy-driver",
+            "rustc",
+            "--error-format=json",
+            "--cfg",
+            r#"feature="a""#,
+            "--cfg",
+            "debug_assertions",
+            "--out-dir",
+            "target/debug/deps",
+            "-C",
+            "incremental=target/debug/incremental",
+            "--cfg",
+            r#"feature="b""#,
+        ]);
+        assert_eq!(cargo_features(&cargo), set(&["a", "b"]));
+        assert!(is_json(&cargo));
+        assert_eq!(
+            session_args(&cargo, &set(&["c"]), Some(Path::new("/tmp/scratch"))),
+            args(&[
+                "clippy-driver",
+                "rustc",
+                "--cfg",
+                "debug_assertions",
+                "--out-dir",
+                "/tmp/scratch",
+                "-C",
+                "incremental=target/debug/incremental/clippy-features-c",
+                "--error-format=json",
+                "--cfg",
+                r#"feature="c""#,
+            ])
+        );
+        assert_eq!(
+            session_args(&cargo, &set(&["a", "b"]), None),
+            args(&[
+                "clippy-driver",
+                "rustc",
+                "--cfg",
+                "debug_assertions",
+                "--out-dir",
+                "target/debug/deps",
+                "-C",
+                "incremental=target/debug/incremental",
+                "--error-format=json",
+                "--cfg",
+                r#"feature="a""#,
+                "--cfg",
+                r#"feature="b""#,
+            ])
+        );
+    }
+
+    #[test]
+    fn merge_diagnostics() {
+        let diag = |message: &str| {
+            json!({
+                "message": message,
+                "level": "warning",
+                "spans": [],
+                "children": [],
+                "rendered": format!("warning: {}\n\n", message),
+            })
+            .to_string()
+        };
+        let abort = r#"{"message":"aborting due to previous error","level":"error","spans":[],"children":[]}"#;
+        let mut merged = Merged::default();
+        merged.add(0, &format!("{}\n{}\n", diag("both"), diag("first")), false);
+        merged.add(1, &format!("{}\n{}\n{}\n", diag("second"), diag("both"), abort), true);
+
+        let mut out = Vec::new();
+        merged.write(&[set(&[]), set(&["a"])], false, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "warning: both\n\n\
+             warning: first\n   = note: only reported with the feature sets []\n\n\
+             warning: second\n   = note: only reported with the feature sets [a]\n\n"
+        );
+    }
+}
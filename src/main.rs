@@ -14,8 +14,41 @@ Common options:
     -h, --help               Print this message
     -V, --version            Print version info and exit
 
+Changed files:
+    --dirty-only[=<rev>]     Only report lints in files that differ from the
+                             git revision <rev> (default: HEAD), including
+                             untracked files
+    --changed-files=<file>   Only report lints in the files listed in <file>,
+                             one path per line
+
+The whole crate is still compiled and checked. Cargo does not rerun Clippy on
+crates that are up to date, so `touch` a file of the crate to recheck it.
+
+Fixes:
+    --fix-dry-run            Apply the machine-applicable fixes in memory and
+                             report the ones that would produce invalid code,
+                             along with the number of suggestions of each
+                             lint by applicability. No file is changed
+
+Feature sets:
+    --feature-set=<features> Check the package once with each of the given
+                             sets of features, which can be repeated, and
+                             merge the diagnostics. The sets are separated
+                             like in `--features`, default features are only
+                             enabled if a set lists `default`
+
+Daemon:
+    --daemon=<socket>        Check the crates in the Clippy daemon listening on
+                             the Unix socket <socket>, which is started with
+                             `clippy-driver --clippy-daemon=<socket>`
+
 Other options are the same as `cargo check`.
 
+To see which lints were triggered at a line and why they were not reported,
+pass its location after `--`:
+
+    cargo clippy -- --clippy-explain-span=src/lib.rs:12
+
 To allow or deny a lint from the command line you can use `cargo clippy --`
 with:
 
@@ -60,14 +93,67 @@ where
     I: Iterator<Item = String>,
 {
     let mut args = vec!["check".to_owned()];
+    let mut changed_files = None;
+    let mut daemon = None;
+    let mut fix_dry_run = None;
+    let mut feature_sets = Vec::new();
 
-    for arg in old_args.by_ref() {
+    while let Some(arg) = old_args.next() {
         if arg == "--" {
             break;
         }
-        args.push(arg);
+        if arg.starts_with("--daemon=") {
+            daemon = Some(("CLIPPY_DAEMON", arg["--daemon=".len()..].to_owned()));
+            continue;
+        }
+        if arg.starts_with("--feature-set=") {
+            feature_sets.push(arg["--feature-set=".len()..].to_owned());
+            continue;
+        }
+        if arg == "--fix-dry-run" {
+            fix_dry_run = Some(("CLIPPY_FIX_DRY_RUN", "1"));
+            continue;
+        }
+        match ChangedFiles::from_arg(&arg, || old_args.next()) {
+            Some(files) => changed_files = Some(files),
+            None => args.push(arg),
+        }
     }
 
+    let changed_files = match changed_files.map(|files| files.list()).transpose() {
+        Ok(files) => files,
+        Err(err) => {
+            eprintln!("error: could not determine the changed files: {}", err);
+            return Err(1);
+        },
+    };
+    let changed_files = match changed_files.map(std::env::join_paths).transpose() {
+        Ok(files) => files.map(|files| ("CLIPPY_CHANGED_FILES", files)),
+        Err(err) => {
+            eprintln!("error: could not pass the changed files to the driver: {}", err);
+            return Err(1);
+        },
+    };
+
+    // the package is built with all features of the sets, so that all
+    // optional dependencies are available, and the driver checks each set
+    let feature_sets = if feature_sets.is_empty() {
+        None
+    } else {
+        let mut all_features: Vec<&str> = feature_sets
+            .iter()
+            .flat_map(|set| set.split(|c| c == ',' || c == ' '))
+            .filter(|feature| !feature.is_empty())
+            .collect();
+        all_features.sort();
+        all_features.dedup();
+        args.push("--no-default-features".to_owned());
+        if !all_features.is_empty() {
+            args.push(format!("--features={}", all_features.join(" ")));
+        }
+        Some(("CLIPPY_FEATURE_SETS", feature_sets.join(";")))
+    };
+
     let clippy_args: String = old_args.map(|arg| format!("{}__CLIPPY_HACKERY__", arg)).collect();
 
     let mut path = std::env::current_exe()
@@ -102,6 +188,10 @@ where
         .env("RUSTC_WRAPPER", path)
         .env("CLIPPY_ARGS", clippy_args)
         .envs(target_dir)
+        .envs(changed_files)
+        .envs(daemon)
+        .envs(fix_dry_run)
+        .envs(feature_sets)
         .spawn()
         .expect("could not run cargo")
         .wait()
@@ -113,3 +203,87 @@ where
         Err(exit_status.code().unwrap_or(-1))
     }
 }
+
+/// The files to report lints in, see `--dirty-only` and `--changed-files`.
+#[derive(Debug, PartialEq)]
+enum ChangedFiles {
+    /// Files that differ from a git revision.
+    Dirty(String),
+    /// Files listed in a file.
+    Listed(String),
+}
+
+impl ChangedFiles {
+    /// Parses `--dirty-only[=<rev>]` and `--changed-files[=]<file>`, taking the
+    /// next argument from `next` if needed.
+    fn from_arg(arg: &str, next: impl FnOnce() -> Option<String>) -> Option<Self> {
+        if arg == "--dirty-only" {
+            Some(ChangedFiles::Dirty("HEAD".to_owned()))
+        } else if arg.starts_with("--dirty-only=") {
+            Some(ChangedFiles::Dirty(arg["--dirty-only=".len()..].to_owned()))
+        } else if arg.starts_with("--changed-files=") {
+            Some(ChangedFiles::Listed(arg["--changed-files=".len()..].to_owned()))
+        } else if arg == "--changed-files" {
+            next().map(ChangedFiles::Listed)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the absolute paths of the existing changed files.
+    fn list(self) -> std::io::Result<Vec<std::path::PathBuf>> {
+        let (root, files) = match self {
+            ChangedFiles::Dirty(rev) => {
+                let root = git(&["rev-parse", "--show-toplevel"])?;
+                let mut files = git(&["diff", "--name-only", &rev])?;
+                files.push_str(&git(&["ls-files", "--others", "--exclude-standard", "--full-name"])?);
+                (std::path::PathBuf::from(root.trim()), files)
+            },
+            ChangedFiles::Listed(list) => (std::env::current_dir()?, std::fs::read_to_string(list)?),
+        };
+        Ok(files
+            .lines()
+            .filter(|file| !file.is_empty())
+            // deleted files cannot be canonicalized and have no lints
+            .filter_map(|file| std::fs::canonicalize(root.join(file)).ok())
+            .collect())
+    }
+}
+
+/// Runs a git command and returns its output.
+fn git(args: &[&str]) -> std::io::Result<String> {
+    let output = std::process::Command::new("git").args(args).output()?;
+    if !output.status.success() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            format!(
+                "`git {}` failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        ));
+    }
+    String::from_utf8(output.stdout).map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+}
+
+#[test]
+fn test_changed_files_arg() {
+    let none = || None;
+    assert_eq!(ChangedFiles::from_arg("--release", none), None);
+    assert_eq!(
+        ChangedFiles::from_arg("--dirty-only", none),
+        Some(ChangedFiles::Dirty("HEAD".to_owned()))
+    );
+    assert_eq!(
+        ChangedFiles::from_arg("--dirty-only=origin/master", none),
+        Some(ChangedFiles::Dirty("origin/master".to_owned()))
+    );
+    assert_eq!(
+        ChangedFiles::from_arg("--changed-files=files.txt", none),
+        Some(ChangedFiles::Listed("files.txt".to_owned()))
+    );
+    assert_eq!(
+        ChangedFiles::from_arg("--changed-files", || Some("files.txt".to_owned())),
+        Some(ChangedFiles::Listed("files.txt".to_owned()))
+    );
+}
@@ -0,0 +1,31 @@
+//! A sink for the diagnostics of a compiler session that runs in the driver.
+
+use std::io::{self, Write};
+use std::mem;
+use std::sync::{Arc, Mutex};
+
+/// Collects the diagnostics the compiler writes from its own thread.
+#[derive(Clone, Default)]
+pub struct Output(Arc<Mutex<Vec<u8>>>);
+
+impl Output {
+    /// Takes the diagnostics written so far.
+    pub fn take(&self) -> String {
+        let bytes = mem::replace(&mut *self.0.lock().expect("the compiler is done"), Vec::new());
+        String::from_utf8_lossy(&bytes).into_owned()
+    }
+}
+
+impl Write for Output {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        self.0
+            .lock()
+            .expect("only the compiler writes")
+            .extend_from_slice(bytes);
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+#![warn(clippy::absolute_paths)]
+
+mod helpers {
+    pub mod nested {
+        pub fn f() {}
+    }
+}
+
+fn main() {
+    let _ = std::f64::consts::PI;
+    // at most three segments
+    let _ = std::env::var("HOME");
+    // allowed crate
+    crate::helpers::nested::f();
+}
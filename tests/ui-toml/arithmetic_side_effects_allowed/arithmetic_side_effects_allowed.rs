@@ -0,0 +1,49 @@
+#![warn(clippy::arithmetic_side_effects)]
+
+use std::ops::Add;
+
+#[derive(Clone, Copy)]
+struct Point(u32, u32);
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point(self.0.wrapping_add(other.0), self.1.wrapping_add(other.1))
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Meters(u32);
+
+impl Add for Meters {
+    type Output = Meters;
+
+    fn add(self, other: Meters) -> Meters {
+        Meters(self.0.saturating_add(other.0))
+    }
+}
+
+fn main() {
+    let p = Point(1, 2);
+    let _ = p + p;
+    let m = Meters(1);
+    let _ = m + m;
+    let f = units::Feet(1);
+    let _ = f + f;
+}
+
+mod units {
+    use std::ops::Add;
+
+    #[derive(Clone, Copy)]
+    pub struct Feet(pub u32);
+
+    impl Add for Feet {
+        type Output = Feet;
+
+        fn add(self, other: Feet) -> Feet {
+            Feet(self.0.saturating_add(other.0))
+        }
+    }
+}
@@ -0,0 +1,51 @@
+#![warn(clippy::filter_map_bool_then)]
+
+fn double(x: u32) -> u32 {
+    x * 2
+}
+
+fn is_short(s: &str) -> bool {
+    s.len() < 3
+}
+
+fn consume(s: String) -> bool {
+    s.is_empty()
+}
+
+fn main() {
+    let v = vec![1u32, 2, 3, 4];
+    // `Copy` items, the pattern of `filter` dereferences them
+    let _: Vec<_> = v.iter().filter_map(|&x| (x % 2 == 0).then(|| x * x)).collect();
+    let _: Vec<_> = v.iter().cloned().filter_map(|x| (x > 1).then(|| double(x))).collect();
+
+    let words = vec![String::from("a"), String::new()];
+    let _: Vec<_> = words.iter().filter_map(|w| (!w.is_empty()).then(|| w.len())).collect();
+
+    // the condition borrows the item, which `filter` passes by reference
+    let _: Vec<_> = words
+        .clone()
+        .into_iter()
+        .filter_map(|w| is_short(&w).then(|| w.to_uppercase()))
+        .collect();
+    // the item is moved into the value
+    let _: Vec<_> = words
+        .clone()
+        .into_iter()
+        .filter_map(|w| w.is_empty().then(|| w + "!"))
+        .collect();
+
+    // not linted: the condition moves the item
+    let _: Vec<_> = words.into_iter().filter_map(|w| consume(w).then(|| 1)).collect();
+    // not linted: the closure has statements
+    let _: Vec<_> = v
+        .iter()
+        .filter_map(|&x| {
+            let y = x + 1;
+            (y > 2).then(|| y)
+        })
+        .collect();
+    // not linted: the parameter has a type
+    let _: Vec<_> = v.iter().cloned().filter_map(|x: u32| (x > 1).then(|| x)).collect();
+    // not linted: not `bool::then`
+    let _: Vec<_> = v.iter().filter_map(|&x| Some(x).filter(|&x| x > 1)).collect();
+}
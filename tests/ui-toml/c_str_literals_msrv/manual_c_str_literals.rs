@@ -0,0 +1,20 @@
+// edition:2018
+#![warn(clippy::manual_c_str_literals)]
+
+use std::ffi::{CStr, CString};
+
+fn main() {
+    let _ = CStr::from_bytes_with_nul(b"foo\0").unwrap();
+    let _ = CStr::from_bytes_with_nul(b"say \"hi\"\n\xff\0").expect("nul-terminated");
+    let _ = unsafe { CStr::from_bytes_with_nul_unchecked(b"foo\0") };
+    let _ = CString::new("grüße\t").unwrap();
+
+    // interior nul bytes
+    let _ = CStr::from_bytes_with_nul(b"fo\0o\0").unwrap();
+    let _ = CString::new("fo\0o").unwrap();
+    // no trailing nul byte
+    let _ = CStr::from_bytes_with_nul(b"foo").unwrap();
+    // not a literal
+    let bytes = b"foo\0";
+    let _ = CStr::from_bytes_with_nul(bytes).unwrap();
+}
@@ -0,0 +1,8 @@
+#![warn(clippy::manual_c_str_literals)]
+
+use std::ffi::CStr;
+
+// `c""` literals aren't available in the 2015 edition
+fn main() {
+    let _ = CStr::from_bytes_with_nul(b"foo\0").unwrap();
+}
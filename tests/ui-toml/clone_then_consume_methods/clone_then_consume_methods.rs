@@ -0,0 +1,24 @@
+#![warn(clippy::waker_clone_wake)]
+
+mod handle {
+    #[derive(Clone)]
+    pub struct Handle;
+
+    impl Handle {
+        pub fn close(self) {}
+
+        pub fn close_ref(&self) {}
+
+        pub fn into_inner(self) {}
+    }
+}
+
+use handle::Handle;
+
+fn main() {
+    let handle = Handle;
+    handle.clone().close();
+    // not configured
+    handle.clone().into_inner();
+    handle.close();
+}
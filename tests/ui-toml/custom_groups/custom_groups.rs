@@ -0,0 +1,13 @@
+#![warn(clippy::team_strict)]
+#![allow(dead_code)]
+
+pub mod foo {
+    // `module_name_repetitions` is removed from the group
+    pub struct FooBar;
+}
+
+fn main() {
+    let x = 1u8;
+    let _ = x as u32;
+    let _ = Some(1u8).unwrap();
+}
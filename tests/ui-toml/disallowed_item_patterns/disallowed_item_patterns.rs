@@ -0,0 +1,49 @@
+#![warn(clippy::disallowed_patterns)]
+#![allow(dead_code)]
+
+pub enum Never {}
+
+pub struct Exhaustive {
+    pub a: u32,
+    pub b: u32,
+    _priv: (),
+}
+
+#[derive(Clone)]
+pub struct Config {
+    size: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { size: 0 }
+    }
+}
+
+trait Convert<T> {
+    fn convert(&self) -> T;
+}
+
+mod inner {
+    pub(super) fn f() {}
+    pub(crate) fn g() {}
+}
+
+// not linted
+enum PrivateNever {}
+
+pub enum Nonempty {
+    A,
+}
+
+pub struct Private {
+    a: u32,
+}
+
+impl<T> Convert<T> for Option<T> {
+    fn convert(&self) -> T {
+        unimplemented!()
+    }
+}
+
+fn main() {}
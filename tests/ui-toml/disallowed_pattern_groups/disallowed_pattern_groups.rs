@@ -0,0 +1,22 @@
+#![warn(clippy::disallowed_patterns)]
+
+fn binding() -> u32 {
+    let x = 1;
+    x
+}
+
+fn pairs(a: u32, b: u32, c: u32, d: u32) -> u32 {
+    a + b + c + d
+}
+
+fn triple(a: u32, b: u32, c: u32) -> u32 {
+    a + b + c
+}
+
+fn main() {
+    let _ = pairs(1, 2, 3, 4);
+
+    // not linted
+    let _ = triple(1, 2, 3);
+    let _ = pairs(binding(), 2, 3, 4);
+}
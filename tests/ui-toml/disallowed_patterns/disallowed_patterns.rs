@@ -0,0 +1,48 @@
+#![warn(clippy::disallowed_patterns)]
+
+fn f(x: bool) -> u32 {
+    if x {
+        return 1;
+    }
+    2
+}
+
+fn main() {
+    let x = true;
+    if x {}
+
+    // not linted
+    if x {
+    } else {
+        println!();
+    }
+    let _ = f(x);
+    leak(vec![]);
+    if_let(Some(1));
+}
+
+fn leak(v: Vec<u32>) {
+    std::mem::forget(v.clone());
+    mem::forget(v.clone());
+    ::std::mem::forget(v.clone());
+
+    // not linted
+    std::mem::drop(v.clone());
+    other::forget(v);
+}
+
+mod other {
+    pub fn forget(_: Vec<u32>) {}
+}
+
+use std::mem;
+
+fn if_let(x: Option<u32>) {
+    if let Some(1) = x {}
+
+    // not linted
+    if let Some(1) = x {
+    } else {
+        println!();
+    }
+}
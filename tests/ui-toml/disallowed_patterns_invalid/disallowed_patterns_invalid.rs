@@ -0,0 +1,3 @@
+#![warn(clippy::disallowed_patterns)]
+
+fn main() {}
@@ -0,0 +1,10 @@
+#![warn(clippy::imports_granularity)]
+#![allow(unused_imports)]
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::io::Write;
+use std::sync::{atomic::AtomicBool, Arc};
+use std::{fmt, iter};
+
+fn main() {}
@@ -0,0 +1,8 @@
+#![warn(clippy::imports_granularity)]
+#![allow(unused_imports)]
+
+use std::collections::HashMap;
+use std::io::{self, Read as _};
+use std::{fmt, iter::*};
+
+fn main() {}
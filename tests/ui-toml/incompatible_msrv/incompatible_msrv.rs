@@ -0,0 +1,10 @@
+#![warn(clippy::incompatible_msrv)]
+
+fn main() {
+    let x = Some(3);
+    let _ = x.filter(|x| *x > 1);
+    let _ = x.is_some();
+    let _ = 2u32.checked_pow(3);
+    let _ = u32::to_be_bytes(1);
+    let _ = std::iter::repeat(1).take(2);
+}
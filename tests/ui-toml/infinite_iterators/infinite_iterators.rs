@@ -0,0 +1,38 @@
+#![deny(clippy::infinite_iter)]
+#![allow(clippy::new_without_default)]
+
+pub struct Counter(u64);
+
+impl Iterator for Counter {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        self.0 += 1;
+        Some(self.0)
+    }
+}
+
+impl Counter {
+    pub fn new() -> Self {
+        Counter(0)
+    }
+}
+
+mod gen {
+    pub fn naturals() -> super::Counter {
+        super::Counter(0)
+    }
+
+    pub fn odds() -> impl Iterator<Item = u64> {
+        naturals().filter(|x| x % 2 == 1)
+    }
+}
+
+fn main() {
+    gen::naturals().count(); // infinite iter
+    Counter::new().map(|x| x + 1).max(); // infinite iter
+    gen::naturals().zip(Counter::new()).count(); // infinite iter
+    gen::naturals().take_while(|x| *x < 10).count(); // ok, maybe infinite
+    gen::odds().count(); // not listed
+    std::iter::repeat_with(|| 1).count(); // infinite iter, listed as a re-export
+}
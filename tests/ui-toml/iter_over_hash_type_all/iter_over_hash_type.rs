@@ -0,0 +1,13 @@
+#![warn(clippy::iter_over_hash_type)]
+
+use std::collections::HashSet;
+
+fn main() {
+    let set: HashSet<u32> = HashSet::new();
+    let mut sum = 0;
+    for x in &set {
+        sum += x;
+    }
+    let _ = set.iter().max();
+    let _ = sum;
+}
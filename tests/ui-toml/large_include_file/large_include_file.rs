@@ -0,0 +1,9 @@
+#![warn(clippy::large_include_file)]
+
+const TOO_BIG_BYTES: &[u8; 684] = include_bytes!("too_big.txt");
+const TOO_BIG_STR: &str = include_str!("too_big.txt");
+
+const SMALL_BYTES: &[u8; 28] = include_bytes!("small.txt");
+const SMALL_STR: &str = include_str!("small.txt");
+
+fn main() {}
@@ -0,0 +1,10 @@
+#![warn(clippy::legacy_numeric_constants)]
+
+use std::u32;
+
+// the associated constants are not stable in Rust 1.42
+fn main() {
+    let _ = u32::MAX;
+    let _ = std::i64::MIN;
+    let _ = u8::max_value();
+}
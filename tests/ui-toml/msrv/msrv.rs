@@ -0,0 +1,41 @@
+#![warn(
+    clippy::seek_from_current,
+    clippy::seek_to_start_instead_of_rewind,
+    clippy::lines_filter_map_ok,
+    clippy::tuple_array_conversions,
+    clippy::manual_retain,
+    clippy::thread_local_initializer_can_be_made_const,
+    clippy::manual_hash_one
+)]
+
+use std::cell::Cell;
+use std::collections::hash_map::RandomState;
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::io::{self, BufRead, BufReader, Seek, SeekFrom};
+
+// `stream_position`, `rewind`, `map_while`, `hash_one`, the array/tuple
+// conversions and `BTreeSet::retain` are not stable in Rust 1.50, neither are
+// `const` `thread_local!` initializers
+thread_local! {
+    static COUNTER: Cell<u32> = Cell::new(0);
+}
+
+fn main() -> io::Result<()> {
+    let mut f = File::open("foo.txt")?;
+    let _ = f.seek(SeekFrom::Current(0))?;
+    f.seek(SeekFrom::Start(0))?;
+    // linted, without suggesting `map_while`
+    let _ = BufReader::new(f).lines().flatten().count();
+    let arr = [1, 2];
+    let _ = (arr[0], arr[1]);
+    let mut set: BTreeSet<i32> = (1..4).collect();
+    set = set.into_iter().filter(|x| *x > 1).collect();
+    drop(set);
+    COUNTER.with(|c| c.set(1));
+    let mut hasher = RandomState::new().build_hasher();
+    1.hash(&mut hasher);
+    let _ = hasher.finish();
+    Ok(())
+}
@@ -0,0 +1,9 @@
+fn needless_return() -> bool {
+    return true;
+}
+
+fn main() {
+    let v = vec![1];
+    let _ = v.len() == 0;
+    let _ = needless_return();
+}
@@ -0,0 +1,15 @@
+#![warn(clippy::partial_pub_fields)]
+#![allow(dead_code)]
+
+pub struct Hidden {
+    pub value: u32,
+    #[doc(hidden)]
+    __private: (),
+}
+
+pub struct NotHidden {
+    pub value: u32,
+    private: (),
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#![warn(clippy::obfuscated_if_else)]
+
+fn main() {
+    let x = true;
+    let y = false;
+    let _ = if x { Some(1) } else { None };
+    let _ = if x && y { Some("a") } else { None };
+
+    // not linted
+    let _ = if x { Some(1) } else { Some(2) };
+    let _ = if x { None } else { Some(1) };
+}
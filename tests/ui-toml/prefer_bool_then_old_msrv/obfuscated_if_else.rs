@@ -0,0 +1,9 @@
+#![warn(clippy::obfuscated_if_else)]
+
+// `bool::then` is too recent for the `msrv`, the lint keeps the default
+// direction
+fn main() {
+    let x = true;
+    let _ = if x { Some(1) } else { None };
+    let _ = x.then(|| 1).unwrap_or(2);
+}
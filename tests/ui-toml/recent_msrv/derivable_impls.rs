@@ -0,0 +1,15 @@
+#![warn(clippy::derivable_impls)]
+#![allow(dead_code)]
+
+enum Kind {
+    A,
+    B(u8),
+}
+
+impl Default for Kind {
+    fn default() -> Self {
+        Kind::A
+    }
+}
+
+fn main() {}
@@ -0,0 +1,40 @@
+#![warn(clippy::legacy_numeric_constants)]
+#![allow(unused_imports)]
+
+use std::f64;
+use std::i64;
+use std::{fmt, u16::MAX};
+
+mod floats {
+    use std::f32;
+
+    pub fn pi() -> f32 {
+        f32::consts::PI
+    }
+}
+
+macro_rules! max {
+    () => {
+        std::u8::MAX
+    };
+}
+
+fn main() {
+    let _ = std::u32::MAX;
+    let _ = core::i8::MIN;
+    let _ = ::std::f64::EPSILON;
+    let _ = u64::max_value();
+    let _ = isize::min_value();
+    let _ = [0; std::u8::MAX as usize];
+    match 0u16 {
+        std::u16::MAX => {},
+        _ => {},
+    }
+    let _ = max!();
+
+    // ok
+    let _ = std::f64::consts::E;
+    let _ = u32::pow(2, 3);
+    let _ = MAX;
+    let _: fmt::Result = Ok(());
+}
@@ -0,0 +1,23 @@
+#![warn(clippy::lines_filter_map_ok)]
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+
+fn main() -> io::Result<()> {
+    let f = File::open("/")?;
+    BufReader::new(f).lines().filter_map(Result::ok).for_each(|_| ());
+    let f = File::open("/")?;
+    BufReader::new(f).lines().flat_map(Result::ok).for_each(|_| ());
+    let f = File::open("/")?;
+    BufReader::new(f).lines().flatten().for_each(|_| ());
+
+    // not `Result::ok`
+    let f = File::open("/")?;
+    BufReader::new(f)
+        .lines()
+        .filter_map(|line| line.ok()?.parse::<u32>().ok())
+        .for_each(|_| ());
+    // not the lines of a reader
+    io::stdin().lock().split(b',').flatten().for_each(|_| ());
+    Ok(())
+}
@@ -0,0 +1,40 @@
+#![warn(clippy::manual_hash_one)]
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash, Hasher};
+
+fn returned(s: &RandomState, value: u32) -> u64 {
+    let mut hasher = s.build_hasher();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn bound(s: &RandomState, value: u32) -> u64 {
+    let mut hasher = s.build_hasher();
+    value.hash(&mut hasher);
+    let hash = hasher.finish();
+    hash
+}
+
+fn used_after(s: &RandomState, value: u32) -> u64 {
+    let mut hasher = s.build_hasher();
+    value.hash(&mut hasher);
+    let hash = hasher.finish();
+    value.hash(&mut hasher);
+    hash ^ hasher.finish()
+}
+
+fn hashed_twice(s: &RandomState, value: u32) -> u64 {
+    let mut hasher = s.build_hasher();
+    value.hash(&mut hasher);
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn main() {
+    let s = RandomState::new();
+    returned(&s, 1);
+    bound(&s, 1);
+    used_after(&s, 1);
+    hashed_twice(&s, 1);
+}
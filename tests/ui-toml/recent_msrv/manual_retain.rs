@@ -0,0 +1,10 @@
+#![warn(clippy::manual_retain)]
+
+use std::collections::BTreeSet;
+
+fn main() {
+    // `BTreeSet::retain` is stable since Rust 1.53
+    let mut b: BTreeSet<i32> = (1..4).collect();
+    b = b.into_iter().filter(|x| *x > 1).collect();
+    drop(b);
+}
@@ -0,0 +1,10 @@
+#![allow(clippy::legacy_numeric_constants)]
+#![warn(clippy::replace_consts)]
+
+use std::sync::ONCE_INIT;
+
+fn main() {
+    let _ = ONCE_INIT;
+    // left to `legacy_numeric_constants`
+    let _ = std::u32::MAX;
+}
@@ -0,0 +1,15 @@
+#![warn(clippy::seek_from_current)]
+
+use std::fs::File;
+use std::io::{self, Seek, SeekFrom, Write};
+
+fn main() -> io::Result<()> {
+    let mut f = File::create("foo.txt")?;
+    f.write_all(b"Hi!")?;
+    let _ = f.seek(SeekFrom::Current(0))?;
+    let _ = f.seek(io::SeekFrom::Current(0))?;
+
+    // not the current position
+    f.seek(SeekFrom::Current(1))?;
+    Ok(())
+}
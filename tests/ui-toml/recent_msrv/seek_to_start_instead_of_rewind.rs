@@ -0,0 +1,17 @@
+#![warn(clippy::seek_to_start_instead_of_rewind)]
+
+use std::fs::File;
+use std::io::{self, Seek, SeekFrom, Write};
+
+fn main() -> io::Result<()> {
+    let mut f = File::create("foo.txt")?;
+    f.write_all(b"Hi!")?;
+    f.seek(SeekFrom::Start(0))?;
+    f.seek(SeekFrom::Start(0)).unwrap();
+
+    // the position is used
+    let _pos = f.seek(SeekFrom::Start(0))?;
+    // not the start
+    f.seek(SeekFrom::Start(1))?;
+    Ok(())
+}
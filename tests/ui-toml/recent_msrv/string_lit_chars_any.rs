@@ -0,0 +1,16 @@
+#![warn(clippy::string_lit_chars_any)]
+
+fn main() {
+    let c = 'x';
+    let _ = "abc".chars().any(|x| x == c);
+    let _ = "'\n\"".chars().any(|x| c == x);
+    let _ = ['a', 'b', 'a'].contains(&c);
+    let r = &c;
+    let _ = ['a', 'b'].contains(r);
+
+    // not linted
+    let s = "abc";
+    let _ = s.chars().any(|x| x == c);
+    let _ = "abc".chars().any(|x| x == c || x == 'd');
+    let _ = ['a', 'b'].iter().any(|&x| x == c);
+}
@@ -0,0 +1,24 @@
+#![warn(clippy::thread_local_initializer_can_be_made_const)]
+
+use std::cell::{Cell, RefCell};
+
+fn non_const() -> u32 {
+    42
+}
+
+thread_local! {
+    static COUNTER: Cell<u32> = Cell::new(0);
+    static BUF: RefCell<Option<String>> = RefCell::new(None);
+}
+
+thread_local! {
+    static NOT_CONST: Cell<u32> = Cell::new(non_const());
+    static HEAP: RefCell<Vec<u32>> = RefCell::new(vec![1, 2]);
+}
+
+fn main() {
+    COUNTER.with(|c| c.set(c.get() + 1));
+    BUF.with(|buf| *buf.borrow_mut() = Some(String::new()));
+    NOT_CONST.with(|c| c.set(0));
+    HEAP.with(|heap| heap.borrow_mut().clear());
+}
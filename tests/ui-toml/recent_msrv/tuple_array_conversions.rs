@@ -0,0 +1,31 @@
+#![warn(clippy::tuple_array_conversions)]
+
+fn main() {
+    let arr = [1, 2];
+    let t = (1, 2);
+    let one = [1];
+
+    // should lint
+    let _: (i32, i32) = (arr[0], arr[1]);
+    let _ = (one[0],);
+    let _: [i32; 2] = [t.0, t.1];
+
+    // should not lint
+    let s = [String::new(), String::new()];
+    let _ = (s[0].clone(), s[1].clone());
+    let _ = (arr[1], arr[0]);
+    let _ = (arr[0], arr[0]);
+    let _ = [t.1, t.0];
+    let _ = [t.0, t.0];
+    let long = [1, 2, 3];
+    let _ = (long[0], long[1]);
+    let other = [3, 4];
+    let _ = (arr[0], other[1]);
+    let r = &arr;
+    let _ = (r[0], r[1]);
+    let _ = [t.0];
+    let big = [0u8; 13];
+    let _ = (
+        big[0], big[1], big[2], big[3], big[4], big[5], big[6], big[7], big[8], big[9], big[10], big[11], big[12],
+    );
+}
@@ -0,0 +1,9 @@
+#![warn(clippy::unchecked_duration_subtraction)]
+
+use std::time::{Duration, Instant};
+
+fn main() {
+    let elapsed = Instant::now().elapsed();
+    // `Duration::saturating_sub` is stable since Rust 1.53
+    let _ = elapsed - Duration::from_secs(1);
+}
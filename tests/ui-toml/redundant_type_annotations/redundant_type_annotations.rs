@@ -0,0 +1,8 @@
+#![warn(clippy::redundant_type_annotations)]
+#![allow(unused_variables)]
+
+fn main() {
+    let x: u8 = 1u8;
+    let f: f32 = 1.0f32;
+    let b: bool = true;
+}
@@ -0,0 +1,12 @@
+#![warn(clippy::single_call_fn)]
+
+fn short() {}
+
+fn long() {
+    println!("long");
+}
+
+fn main() {
+    short();
+    long();
+}
@@ -0,0 +1,9 @@
+#![warn(clippy::struct_field_names)]
+#![allow(dead_code)]
+
+struct Limits {
+    max_width: u32,
+    max_height: u32,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#![warn(clippy::suspicious_xor_used_as_pow)]
+
+fn main() {
+    let _ = 3 ^ 4;
+    // 2 is not one of the configured bases
+    let _ = 2 ^ 8;
+}
@@ -0,0 +1,25 @@
+#![feature(custom_inner_attributes)]
+#![rustfmt::skip]
+#![warn(clippy::undocumented_unsafe_blocks)]
+
+fn attributes(ptr: *const u32) -> u32 {
+    // SAFETY: the caller passes a valid pointer
+    #[allow(unused_variables)]
+    let unused = unsafe { *ptr };
+    0
+}
+
+fn separated(ptr: *const u32) -> u32 {
+    // SAFETY: the caller passes a valid pointer
+
+    unsafe { *ptr }
+}
+
+fn too_far(ptr: *const u32) -> u32 {
+    // SAFETY: the caller passes a valid pointer
+
+
+    unsafe { *ptr }
+}
+
+fn main() {}
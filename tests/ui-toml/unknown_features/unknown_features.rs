@@ -0,0 +1,19 @@
+#![warn(clippy::unknown_features)]
+#![allow(dead_code, clippy::deprecated_clippy_cfg_attr)]
+
+#[cfg(feature = "serde")]
+fn known() {}
+
+#[cfg(feature = "serd")]
+fn misspelled() {}
+
+#[cfg_attr(all(unix, not(feature = "drive")), inline)]
+fn nested() {}
+
+#[cfg(feature = "tokio")]
+fn unknown() {}
+
+#[cfg(feature = "cargo-clippy")]
+fn set_by_cargo_clippy() {}
+
+fn main() {}
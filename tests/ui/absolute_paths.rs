@@ -0,0 +1,22 @@
+#![warn(clippy::absolute_paths)]
+
+use std::collections::HashMap;
+
+mod helpers {
+    pub mod nested {
+        pub fn f() {}
+    }
+}
+
+fn main() {
+    let _ = std::env::var("HOME");
+    let _: std::collections::HashSet<u8> = Default::default();
+    let _ = ::std::f64::consts::PI;
+    crate::helpers::nested::f();
+
+    // not starting at a crate root
+    helpers::nested::f();
+    self::helpers::nested::f();
+    let _: HashMap<u8, u8> = HashMap::new();
+    let _ = String::new();
+}
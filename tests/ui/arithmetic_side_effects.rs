@@ -0,0 +1,43 @@
+#![warn(clippy::arithmetic_side_effects)]
+#![allow(clippy::identity_op, clippy::no_effect, clippy::unnecessary_operation)]
+
+use std::num::Wrapping;
+
+const SIZE: usize = 4 * 1024;
+
+fn unbounded(mut a: u32, b: u32, c: i32) {
+    a + b;
+    a - 1;
+    a * 2;
+    a / b;
+    a % b;
+    a << b;
+    -c;
+    c / -1;
+    a += 1;
+    a - b * 2;
+}
+
+fn bounded(mut a: u32, c: i32) {
+    a % 4;
+    a / 2;
+    a + 0;
+    a * 1;
+    0 * a;
+    a << 3;
+    a >>= 31;
+    c / 2;
+    c % -4;
+    1 + 2;
+    -1;
+    let _ = SIZE;
+}
+
+fn allowed(a: Wrapping<u32>, b: f64, s: String) {
+    a + a;
+    b * 2.0;
+    -b;
+    s + "!";
+}
+
+fn main() {}
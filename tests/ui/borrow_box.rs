@@ -71,6 +71,14 @@ impl<'a> Test12 for Test11<'a> {
     }
 }
 
+pub fn test13(foo: &Box<Fn()>) {
+    foo()
+}
+
+pub fn test14(foo: &Box<Fn() + Send>) {
+    foo()
+}
+
 fn main() {
     test1(&mut Box::new(false));
     test2();
@@ -0,0 +1,18 @@
+// edition:2018
+
+#![deny(clippy::borrowed_box)]
+#![allow(bare_trait_objects)]
+
+pub fn dyn_syntax(foo: &Box<dyn Fn()>) {
+    foo()
+}
+
+pub fn bare(foo: &Box<Fn()>) {
+    foo()
+}
+
+pub fn bounds(foo: &Box<Fn() + Send>) {
+    foo()
+}
+
+fn main() {}
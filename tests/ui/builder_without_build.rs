@@ -0,0 +1,46 @@
+#![warn(clippy::builder_without_build)]
+#![allow(dead_code, clippy::new_without_default)]
+
+pub struct Config {
+    verbose: bool,
+}
+
+pub struct ConfigBuilder {
+    verbose: bool,
+}
+
+impl ConfigBuilder {
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
+
+    pub fn finish(self) -> Config {
+        Config { verbose: self.verbose }
+    }
+}
+
+pub struct RequestBuilder;
+
+impl RequestBuilder {
+    pub fn build(self) -> Config {
+        Config { verbose: false }
+    }
+}
+
+// `build` in a second impl block
+pub struct ResponseBuilder;
+
+impl ResponseBuilder {
+    pub fn new() -> Self {
+        ResponseBuilder
+    }
+}
+
+impl ResponseBuilder {
+    pub fn build(self) -> Config {
+        Config { verbose: true }
+    }
+}
+
+fn main() {}
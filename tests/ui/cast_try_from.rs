@@ -0,0 +1,28 @@
+#![warn(clippy::cast_try_from)]
+#![allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss,
+    clippy::cast_lossless
+)]
+
+fn main() {
+    let x = 300u32;
+    let _ = x as u8;
+    let _ = x as i32;
+    let _ = (x + 1) as u16;
+    let _ = -1i64 as u64;
+    let _ = x as usize;
+    let _ = 1usize as u64;
+
+    // lossless on all targets, linted by `cast_lossless`
+    let _ = x as u64;
+    let _ = x as i64;
+    let _ = 1u8 as usize;
+    // the same type
+    let _ = x as u32;
+    // not integers
+    let _ = x as f64;
+}
+
+const _C: u8 = 300u32 as u8;
@@ -0,0 +1,33 @@
+#![warn(clippy::cfg_not_clippy)]
+#![allow(dead_code)]
+
+#[cfg(not(clippy))]
+fn hidden() {}
+
+#[cfg(not(clippy))]
+mod hidden_module {}
+
+struct S;
+
+impl S {
+    #[cfg(not(clippy))]
+    fn hidden_method() {}
+}
+
+#[cfg(all(test, not(clippy)))]
+fn hidden_in_tests() {}
+
+// checked by Clippy on unix
+#[cfg(any(unix, not(clippy)))]
+fn unix_or_not_clippy() {}
+
+#[cfg(not(all(clippy, unix)))]
+fn not_clippy_on_unix() {}
+
+#[cfg(clippy)]
+fn only_checked() {}
+
+#[cfg_attr(not(clippy), inline)]
+fn not_hidden() {}
+
+fn main() {}
@@ -1,3 +1,5 @@
+#![allow(clippy::non_canonical_clone_impl)]
+
 use std::fmt;
 use std::marker::PhantomData;
 
@@ -0,0 +1,17 @@
+// edition:2018
+
+#![deny(clippy::clone_on_ref_ptr)]
+#![allow(bare_trait_objects)]
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+pub fn dyn_syntax(x: Arc<dyn Fn()>) {
+    let _ = x.clone();
+}
+
+pub fn bare(x: Rc<Fn() + Send>) {
+    let _ = x.clone();
+}
+
+fn main() {}
@@ -0,0 +1,80 @@
+#![warn(clippy::collection_is_never_read)]
+#![allow(unused)]
+
+use std::collections::{HashMap, HashSet};
+
+fn not_a_collection() {
+    let mut x = 10;
+    x += 1;
+}
+
+fn no_access_at_all() {
+    let mut x = HashMap::<usize, usize>::new();
+}
+
+fn write_only() {
+    let mut x = Vec::new();
+    x.push(1);
+    x.push(2);
+}
+
+fn write_with_assignment() {
+    let mut x = HashSet::new();
+    x = HashSet::new();
+    x.insert(1);
+}
+
+fn read_by_iteration() {
+    let mut x = Vec::new();
+    x.push(1);
+    for i in &x {}
+}
+
+fn read_by_method() {
+    let mut x = Vec::new();
+    x.push(1);
+    let _ = x.len();
+}
+
+fn insert_result_is_read() {
+    let mut x = HashSet::new();
+    if x.insert(1) {}
+}
+
+fn returned() -> Vec<i32> {
+    let mut x = Vec::new();
+    x.push(1);
+    x
+}
+
+fn read_in_closure() {
+    let mut x = Vec::new();
+    x.push(1);
+    let f = || x.is_empty();
+}
+
+fn extend_trait() {
+    let mut x = String::new();
+    x.extend(vec!['a', 'b']);
+    x.push_str("c");
+}
+
+struct Foo {
+    never_read: Vec<i32>,
+    read: Vec<i32>,
+    pub public: Vec<i32>,
+}
+
+impl Foo {
+    fn fill(&mut self) {
+        self.never_read.push(1);
+        self.read.push(1);
+        self.public.push(1);
+    }
+
+    fn get(&self) -> usize {
+        self.read.len()
+    }
+}
+
+fn main() {}
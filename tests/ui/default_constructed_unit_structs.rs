@@ -0,0 +1,39 @@
+// run-rustfix
+
+#![warn(clippy::default_constructed_unit_structs)]
+
+use std::marker::PhantomData;
+
+#[derive(Default)]
+struct Unit;
+
+#[derive(Default)]
+struct Tuple(u32);
+
+#[derive(Default)]
+struct Generic<T> {
+    marker: PhantomData<T>,
+}
+
+type UnitAlias = Unit;
+
+impl Unit {
+    fn new() -> Self {
+        Self::default()
+    }
+}
+
+fn main() {
+    let _ = PhantomData::<u32>::default();
+    let _: PhantomData<i32> = PhantomData::default();
+    let _ = Unit::default();
+    let _ = Unit::new();
+
+    // no lint
+    let _ = Tuple::default();
+    let _: Unit = Default::default();
+    let _ = <Unit>::default();
+    let _ = Generic::<u8>::default();
+    let _ = String::default();
+    let _ = UnitAlias::default();
+}
@@ -0,0 +1,22 @@
+#![warn(clippy::deprecated_clippy_cfg_attr, clippy::cfg_not_clippy)]
+#![allow(dead_code)]
+
+#[cfg_attr(feature = "cargo-clippy", allow(clippy::needless_return))]
+fn deprecated() {}
+
+#[cfg(not(feature = "cargo-clippy"))]
+fn deprecated_and_hidden() {}
+
+#[cfg(all(unix, not(clippy)))]
+fn hidden() {}
+
+#[cfg(any(feature = "cargo-clippy", test))]
+fn nested() {}
+
+#[cfg(clippy)]
+fn only_checked() {}
+
+#[cfg_attr(not(clippy), inline)]
+fn not_hidden() {}
+
+fn main() {}
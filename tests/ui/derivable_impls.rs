@@ -0,0 +1,82 @@
+#![allow(dead_code)]
+
+struct FooDefault {
+    a: bool,
+    b: i32,
+    c: String,
+    d: Option<u8>,
+    e: (u8, f64),
+}
+
+impl Default for FooDefault {
+    fn default() -> Self {
+        Self {
+            a: false,
+            b: 0,
+            c: Default::default(),
+            d: None,
+            e: (0, 0.0),
+        }
+    }
+}
+
+struct TupleDefault(bool, i32, u64);
+impl Default for TupleDefault {
+    fn default() -> Self {
+        TupleDefault(false, 0, u64::default())
+    }
+}
+
+struct Unit;
+impl Default for Unit {
+    fn default() -> Self {
+        Unit
+    }
+}
+
+// not linted
+
+struct NotDefault {
+    a: i32,
+}
+
+impl Default for NotDefault {
+    fn default() -> Self {
+        Self { a: 1 }
+    }
+}
+
+struct Generic<T>(T);
+
+impl<T: Default> Default for Generic<T> {
+    fn default() -> Self {
+        Generic(T::default())
+    }
+}
+
+struct GenericU8<T>(T);
+
+impl Default for GenericU8<u8> {
+    fn default() -> Self {
+        GenericU8(0)
+    }
+}
+
+// needs `#[default]`, see `tests/ui-toml/recent_msrv/derivable_impls.rs`
+enum Kind {
+    A,
+    B(u8),
+}
+
+impl Default for Kind {
+    fn default() -> Self {
+        Kind::A
+    }
+}
+
+#[derive(Default)]
+struct Derived {
+    a: u32,
+}
+
+fn main() {}
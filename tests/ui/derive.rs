@@ -1,5 +1,5 @@
 #![feature(untagged_unions)]
-#![allow(dead_code)]
+#![allow(dead_code, clippy::non_canonical_clone_impl)]
 #![warn(clippy::expl_impl_clone_on_copy)]
 
 use std::hash::{Hash, Hasher};
@@ -0,0 +1,39 @@
+#![warn(clippy::doc_comment_in_macro_matcher)]
+
+macro_rules! answer {
+    (
+        /// The answer.
+        $name:ident
+    ) => {
+        const $name: u32 = 42;
+    };
+    (/** The question. */ $name:ident ?) => {
+        const $name: &str = "?";
+    };
+    // no lint, a plain comment
+    ($name:ident, $value:expr) => {
+        /// Doc comments in the transcriber document the expanded items.
+        const $name: u32 = $value;
+    };
+}
+
+macro_rules! documented {
+    // no lint, the attributes of the input are matched with a fragment
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        struct $name;
+    };
+}
+
+answer!(
+    /// The answer.
+    ANSWER
+);
+answer!(/** The question. */ QUESTION ?);
+answer!(OTHER, 1);
+documented!(
+    /// A unit struct.
+    Unit
+);
+
+fn main() {}
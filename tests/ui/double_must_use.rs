@@ -0,0 +1,28 @@
+#![warn(clippy::double_must_use)]
+
+#[must_use]
+pub fn must_use_result() -> Result<(), ()> {
+    unimplemented!();
+}
+
+#[must_use]
+pub fn must_use_tuple() -> (Result<(), ()>, u8) {
+    unimplemented!();
+}
+
+#[must_use = "With note"]
+pub fn must_use_with_note() -> Result<(), ()> {
+    unimplemented!();
+}
+
+#[must_use]
+pub fn must_use_value() -> u32 {
+    0
+}
+
+fn main() {
+    let _ = must_use_result();
+    let _ = must_use_tuple();
+    let _ = must_use_with_note();
+    let _ = must_use_value();
+}
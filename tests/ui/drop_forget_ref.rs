@@ -1,4 +1,5 @@
 #![warn(clippy::drop_ref, clippy::forget_ref)]
+#![allow(clippy::drop_non_drop, clippy::forget_non_drop)]
 #![allow(clippy::toplevel_ref_arg, clippy::similar_names, clippy::needless_pass_by_value)]
 
 use std::mem::{drop, forget};
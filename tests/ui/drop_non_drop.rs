@@ -0,0 +1,30 @@
+#![warn(clippy::drop_non_drop, clippy::forget_non_drop)]
+
+use std::mem::{drop, forget};
+
+struct Foo {
+    x: u8,
+}
+
+struct Bar;
+
+impl Drop for Bar {
+    fn drop(&mut self) {}
+}
+
+struct Baz {
+    bar: Bar,
+}
+
+fn main() {
+    drop(Foo { x: 42 });
+    forget(Foo { x: 42 });
+
+    // has a `Drop` impl
+    drop(Bar);
+    forget(Bar);
+
+    // has a field with a `Drop` impl
+    drop(Baz { bar: Bar });
+    forget(Baz { bar: Bar });
+}
@@ -0,0 +1,52 @@
+#![warn(clippy::empty_doc_sections)]
+#![allow(dead_code)]
+
+/// Frobs the input.
+///
+/// # Errors
+///
+/// # Panics
+///
+/// Panics if `x` is zero.
+pub fn frob(x: u32) -> Result<u32, ()> {
+    assert!(x != 0);
+    Ok(x)
+}
+
+/// Returns the answer.
+///
+/// # Examples
+///
+pub fn answer() -> u32 {
+    42
+}
+
+/// A section with subsections isn't empty.
+///
+/// # Examples
+///
+/// ## Basic
+///
+/// `let x = 1;`
+///
+/// Code blocks can contain lines looking like headings:
+///
+/// ```
+/// # let x = 1;
+/// ```
+pub fn subsections() {}
+
+pub mod module {
+    //! A module.
+    //!
+    //! # Safety
+    //!
+}
+
+/// No lint, the documentation isn't written with `///` only.
+///
+/// # Examples
+#[doc = ""]
+pub fn mixed() {}
+
+fn main() {}
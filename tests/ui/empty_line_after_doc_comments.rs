@@ -0,0 +1,42 @@
+#![warn(clippy::empty_line_after_doc_comments)]
+#![allow(dead_code)]
+#![feature(custom_inner_attributes)]
+#![rustfmt::skip]
+
+/// Returns the answer.
+
+fn answer() -> u32 {
+    42
+}
+
+/// A struct.
+/// With two lines.
+
+#[derive(Clone)]
+struct S {
+    /// A field.
+
+    field: u32,
+}
+
+trait T {
+    /** A method. */
+
+    fn method(&self);
+}
+
+/// Documented.
+// A comment.
+fn commented() {}
+
+/// Documented.
+#[inline]
+fn attributed() {}
+
+/// A block comment in between.
+/*
+
+*/
+fn block() {}
+
+fn main() {}
@@ -1,5 +1,5 @@
 #![warn(clippy::empty_line_after_outer_attr)]
-#![allow(clippy::assertions_on_constants)]
+#![allow(clippy::assertions_on_constants, clippy::empty_line_after_doc_comments)]
 #![feature(custom_inner_attributes)]
 #![rustfmt::skip]
 
@@ -131,3 +131,14 @@ fn divergent(_: u8) -> ! {
 fn generic<T>(_: T) -> u8 {
     0
 }
+
+fn generic_ret<T: Default>(_: u8) -> T {
+    T::default()
+}
+
+fn test_redundant_closures_with_generics_and_qualified_paths() {
+    // `T` is inferred from the use of the closure
+    let e: Option<u32> = Some(1u8).map(|a| generic_ret(a));
+    let e = Some(&[1u8, 2][..]).map(|s| s.len());
+    let e = Some(&[1u8, 2][..]).map(<[u8]>::len);
+}
@@ -1,6 +1,7 @@
 // run-rustfix
 
 #![warn(clippy::expect_fun_call)]
+#![allow(clippy::empty_line_after_doc_comments)]
 
 /// Checks implementation of the `EXPECT_FUN_CALL` lint
 
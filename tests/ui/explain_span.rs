@@ -0,0 +1,11 @@
+// compile-flags: --clippy-explain-span=explain_span.rs:8
+
+#![allow(dead_code)]
+
+#[allow(clippy::needless_return)]
+fn f(x: u32) -> bool {
+    // triggered, but allowed by the attribute above
+    return x == 1;
+}
+
+fn main() {}
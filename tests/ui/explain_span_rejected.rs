@@ -0,0 +1,18 @@
+// compile-flags: --clippy-explain-span=explain_span_rejected.rs:17
+
+struct NotIterator;
+
+impl NotIterator {
+    fn filter(self, _: fn(&u32) -> bool) -> Self {
+        self
+    }
+
+    fn next(self) -> Option<u32> {
+        None
+    }
+}
+
+fn main() {
+    // `filter_next` only checks `Iterator`s
+    let _ = NotIterator.filter(|_| true).next();
+}
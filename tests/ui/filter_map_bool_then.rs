@@ -0,0 +1,52 @@
+#![warn(clippy::filter_map_bool_then)]
+
+// `bool::then` is not available on all toolchains, the `then` of a trait is
+// not linted
+trait BoolExt {
+    fn then<T, F: FnOnce() -> T>(self, f: F) -> Option<T>;
+}
+
+impl BoolExt for bool {
+    fn then<T, F: FnOnce() -> T>(self, f: F) -> Option<T> {
+        if self {
+            Some(f())
+        } else {
+            None
+        }
+    }
+}
+
+fn double(x: u32) -> u32 {
+    x * 2
+}
+
+fn is_short(s: &str) -> bool {
+    s.len() < 3
+}
+
+fn consume(s: String) -> bool {
+    s.is_empty()
+}
+
+fn main() {
+    let v = vec![1u32, 2, 3, 4];
+    let _: Vec<_> = v.iter().filter_map(|&x| (x % 2 == 0).then(|| x * x)).collect();
+    let _: Vec<_> = v.iter().cloned().filter_map(|x| (x > 1).then(|| double(x))).collect();
+
+    let words = vec![String::from("a"), String::new()];
+    let _: Vec<_> = words.iter().filter_map(|w| (!w.is_empty()).then(|| w.len())).collect();
+    let _: Vec<_> = words
+        .clone()
+        .into_iter()
+        .filter_map(|w| is_short(&w).then(|| w.to_uppercase()))
+        .collect();
+
+    let _: Vec<_> = words.into_iter().filter_map(|w| consume(w).then(|| 1)).collect();
+    let _: Vec<_> = v
+        .iter()
+        .filter_map(|&x| {
+            let y = x + 1;
+            (y > 2).then(|| y)
+        })
+        .collect();
+}
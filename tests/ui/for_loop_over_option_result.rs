@@ -1,4 +1,5 @@
 #![warn(clippy::for_loop_over_option, clippy::for_loop_over_result)]
+#![allow(clippy::empty_line_after_doc_comments)]
 
 /// Tests for_loop_over_result and for_loop_over_option
 
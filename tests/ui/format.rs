@@ -57,4 +57,6 @@ fn main() {
     format!("{}", 42.to_string());
     let x = std::path::PathBuf::from("/bar/foo/qux");
     format!("{}", x.display().to_string());
+
+    format!("");
 }
@@ -0,0 +1,20 @@
+#![warn(clippy::format_push_string)]
+
+fn main() {
+    let mut string = String::new();
+    string += &format!("{:?}", 1234);
+    string.push_str(&format!("{:?}", 5678));
+
+    // not linted
+    string += "abc";
+    string.push_str(&String::from("x"));
+}
+
+mod imported {
+    use std::fmt::Write;
+
+    pub fn push(string: &mut String) {
+        *string += &format!("{}", 1);
+        let _ = write!(string, "{}", 2);
+    }
+}
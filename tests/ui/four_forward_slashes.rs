@@ -0,0 +1,48 @@
+#![warn(clippy::four_forward_slashes)]
+#![allow(dead_code)]
+
+//// whoops
+fn a() {}
+
+//// whoops
+#[allow(dead_code)]
+fn b() {}
+
+//// whoops
+//// two borked comments!
+#[inline]
+fn c() {}
+
+/// a doc comment
+//// and one that isn't
+struct D {
+    //// a field
+    field: u32,
+}
+
+trait E {
+    //// a method
+    fn e();
+}
+
+impl E for D {
+    //// an implementation
+    fn e() {}
+}
+
+// no lint, not directly before an item
+//// about the function below
+
+fn f() {}
+
+// no lint
+///// five slashes
+fn g() {}
+
+fn h() {} //// no lint, not on its own line
+
+fn main() {
+    let _s = "
+//// no lint, in a string
+";
+}
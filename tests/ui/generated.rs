@@ -0,0 +1,16 @@
+#![warn(clippy::eq_op, clippy::needless_return)]
+#![allow(dead_code)]
+
+#[clippy::generated]
+mod generated {
+    pub fn f(x: u32) -> bool {
+        // correctness lints are still emitted
+        return x == x;
+    }
+}
+
+fn g(x: u32) -> u32 {
+    return x;
+}
+
+fn main() {}
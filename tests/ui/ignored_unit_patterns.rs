@@ -0,0 +1,30 @@
+// run-rustfix
+
+#![warn(clippy::ignored_unit_patterns)]
+#![allow(clippy::redundant_pattern_matching, clippy::single_match)]
+
+fn run() -> Result<(), String> {
+    Ok(())
+}
+
+trait Handler {
+    fn handle(&self, _: ());
+}
+
+impl Handler for () {
+    fn handle(&self, _: ()) {}
+}
+
+fn main() {
+    match run() {
+        Ok(_) => {},
+        Err(_) => {},
+    }
+    if let Ok(_) = run() {}
+    let _ = run().map(|_| 1);
+    for _ in vec![(); 2] {}
+
+    // ok
+    let _ = run();
+    if let Err(_) = run() {}
+}
@@ -0,0 +1,63 @@
+#![warn(clippy::impl_trait_in_params)]
+#![allow(dead_code, unused_variables)]
+
+use std::fmt::Display;
+
+pub struct U;
+
+pub fn display(x: impl Display) {}
+
+pub fn two(x: impl Display, y: impl Iterator<Item = u32> + Clone) {}
+
+pub fn existing<T: Clone>(x: T, y: &impl Display) {}
+
+pub fn taken_name<T>(x: T, y: impl Into<U>) {}
+
+pub fn with_where<T>(x: T, y: impl Display)
+where
+    T: Clone,
+{
+}
+
+pub struct S;
+
+impl S {
+    pub fn method(&self, x: impl Display) {}
+
+    fn private_method(&self, x: impl Display) {}
+}
+
+pub struct Wrapper<T>(T);
+
+impl<T> Wrapper<T> {
+    pub fn with_impl_param(&self, x: impl Display) {}
+}
+
+// not public
+fn private(x: impl Display) {}
+
+pub(crate) fn crate_visible(x: impl Display) {}
+
+// return position
+pub fn returned() -> impl Display {
+    1
+}
+
+pub trait Trait {
+    fn required(&self, x: impl Display);
+}
+
+// the signature comes from the trait
+impl Trait for S {
+    fn required(&self, x: impl Display) {}
+}
+
+macro_rules! public_fn {
+    () => {
+        pub fn from_macro(x: impl Display) {}
+    };
+}
+
+public_fn!();
+
+fn main() {}
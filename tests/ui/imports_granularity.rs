@@ -0,0 +1,29 @@
+// run-rustfix
+
+#![warn(clippy::imports_granularity)]
+#![allow(unused_imports)]
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{Read, Write};
+
+mod merged {
+    use std::{fmt, io::Read};
+    // different visibility
+    pub use std::io::Write;
+    // attributes
+    #[cfg(test)]
+    use std::collections::HashMap;
+}
+
+mod nested {
+    use self::inner::Item;
+    use std::io::Read;
+    use std::io::{self, Write};
+
+    mod inner {
+        pub struct Item;
+    }
+}
+
+fn main() {}
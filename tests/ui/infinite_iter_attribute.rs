@@ -0,0 +1,38 @@
+#![deny(clippy::infinite_iter)]
+#![allow(clippy::new_without_default)]
+
+struct Counter(u64);
+
+impl Iterator for Counter {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        self.0 += 1;
+        Some(self.0)
+    }
+}
+
+#[clippy::infinite]
+fn naturals() -> Counter {
+    Counter(0)
+}
+
+impl Counter {
+    #[clippy::infinite]
+    fn new() -> Self {
+        Counter(0)
+    }
+
+    #[clippy::infinite]
+    fn evens(&self) -> impl Iterator<Item = u64> {
+        Counter(self.0).map(|x| x * 2)
+    }
+}
+
+fn main() {
+    naturals().count(); // infinite iter
+    Counter::new().map(|x| x + 1).sum::<u64>(); // infinite iter
+    Counter(0).evens().skip(1).max(); // infinite iter
+    naturals().take(10).count(); // ok
+    Counter(0).count(); // not marked
+}
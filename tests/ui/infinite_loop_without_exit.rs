@@ -0,0 +1,60 @@
+#![warn(clippy::infinite_loop)]
+#![allow(dead_code, clippy::never_loop)]
+
+fn do_something() {}
+
+fn no_exit() {
+    loop {
+        do_something();
+    }
+}
+
+fn inner_break() -> u32 {
+    loop {
+        loop {
+            break;
+        }
+    }
+}
+
+fn closure_return() {
+    loop {
+        let _ = || return;
+    }
+}
+
+// not linted
+
+fn never() -> ! {
+    loop {
+        do_something();
+    }
+}
+
+fn outer_break() {
+    'outer: loop {
+        loop {
+            break 'outer;
+        }
+    }
+}
+
+fn try_exit() -> Result<(), std::num::ParseIntError> {
+    loop {
+        "1".parse::<u32>()?;
+    }
+}
+
+fn panics() {
+    loop {
+        panic!();
+    }
+}
+
+fn in_closure() {
+    let _ = || loop {
+        do_something();
+    };
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+// run-rustfix
+// compile-flags: --test
+
+#![warn(clippy::items_after_test_module)]
+#![allow(dead_code)]
+
+mod last {
+    fn before() {}
+
+    #[cfg(test)]
+    mod tests {}
+}
+
+mod only_tests_after {
+    #[cfg(test)]
+    mod tests {}
+
+    #[cfg(test)]
+    fn helper() {}
+}
+
+fn main() {}
+
+#[cfg(test)]
+mod tests {}
+
+fn helper() {}
@@ -0,0 +1,39 @@
+#![warn(clippy::iter_not_returning_iterator)]
+
+struct Data {
+    inner: Vec<u32>,
+}
+
+type Iter<'a> = std::slice::Iter<'a, u32>;
+
+impl Data {
+    fn iter(&self) -> Iter<'_> {
+        self.inner.iter()
+    }
+
+    fn iter_mut(&mut self) -> impl Iterator<Item = &mut u32> {
+        self.inner.iter_mut()
+    }
+
+    fn into_iter(self) -> Vec<u32> {
+        self.inner
+    }
+}
+
+struct Counter(u32);
+
+impl Counter {
+    fn iter(&self) -> u32 {
+        self.0
+    }
+
+    fn iter_mut(&mut self) -> &mut Vec<u32> {
+        unimplemented!()
+    }
+
+    fn into_iter(self) -> u32 {
+        self.0
+    }
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+#![warn(clippy::iter_over_hash_type)]
+
+use std::collections::{HashMap, HashSet};
+
+fn main() {
+    let set: HashSet<u32> = HashSet::new();
+    let map: HashMap<u32, u32> = HashMap::new();
+
+    for x in &set {
+        println!("{}", x);
+    }
+    let mut list = Vec::new();
+    for (k, _) in map.iter().filter(|&(_, v)| *v > 0) {
+        list.push(*k);
+    }
+    let _: Vec<_> = map.keys().collect();
+    map.values().for_each(|v| print!("{}", v));
+
+    // the order is not observable
+    let mut sum = 0;
+    for x in &set {
+        sum += x;
+    }
+    let _: HashSet<_> = map.keys().collect();
+    let _ = set.iter().max();
+    let _ = list.len() as u32 + sum;
+}
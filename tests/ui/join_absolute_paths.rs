@@ -0,0 +1,16 @@
+#![warn(clippy::join_absolute_paths)]
+
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let base = Path::new("/usr");
+    let _ = base.join("/sh");
+    let buf = PathBuf::from("/usr");
+    let _ = buf.join(r"//bin");
+
+    // ok
+    let _ = base.join("bin");
+    // only absolute on Windows
+    let _ = base.join(r"C:\Windows");
+    let _ = base.join("\\bin");
+}
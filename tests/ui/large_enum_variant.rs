@@ -51,4 +51,32 @@ enum LargeEnumOk {
     LargeB([i32; 8001]),
 }
 
+enum Constructed {
+    A(i32),
+    B(i32, [i32; 8000]),
+}
+fn construct() -> Constructed {
+    Constructed::B(1, [0; 8000])
+}
+
+enum ConstructedStruct {
+    A(i32),
+    B { x: i32, y: [i32; 8000] },
+}
+fn construct_struct(y: [i32; 8000]) -> ConstructedStruct {
+    ConstructedStruct::B { x: 1, y }
+}
+
+enum Matched {
+    A(i32),
+    B(i32, [i32; 8000]),
+}
+
+fn matched(m: Matched) -> i32 {
+    match m {
+        Matched::B(x, _) => x,
+        Matched::A(x) => x,
+    }
+}
+
 fn main() {}
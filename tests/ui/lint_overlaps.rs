@@ -0,0 +1,16 @@
+#![warn(
+    clippy::integer_arithmetic,
+    clippy::arithmetic_side_effects,
+    clippy::cast_possible_truncation,
+    clippy::cast_try_from
+)]
+
+fn main() {
+    let (a, b) = (1u32, 2u32);
+    // only `arithmetic_side_effects`
+    let _ = a + b;
+    // `cast_try_from` is added to `cast_possible_truncation`
+    let _ = a as u8;
+    // only `cast_try_from`
+    let _ = a as i32;
+}
@@ -0,0 +1,29 @@
+// run-rustfix
+
+#![warn(clippy::manual_is_ascii_check)]
+#![allow(clippy::nonminimal_bool)]
+
+fn main() {
+    let c = 'x';
+    let b = b'x';
+
+    // should lint
+    let _ = ('a'..='z').contains(&c);
+    let _ = ('A'..='Z').contains(&c);
+    let _ = (b'0'..=b'9').contains(&b);
+    let _ = c >= '0' && c <= '9';
+    let _ = 'a' <= c && c < '{';
+    let _ = b <= b'Z' && b >= b'A';
+    let _ = c >= 'a' && c <= 'z' || c >= 'A' && c <= 'Z';
+    let _ = ('a'..='z').contains(&c) || ('A'..='Z').contains(&c) || ('0'..='9').contains(&c);
+    let _ = ('0'..='9').contains(&c) || ('a'..='f').contains(&c) || ('A'..='F').contains(&c);
+    let _ = c == '_' || ('a'..='z').contains(&c);
+
+    // should not lint
+    let _ = ('a'..='y').contains(&c);
+    let _ = ('a'..'z').contains(&c);
+    let _ = c.is_ascii_digit() || ('a'..='f').contains(&c);
+    let _ = c >= 'a' || c <= 'z';
+    let n = 5u32;
+    let _ = (48..=57).contains(&n);
+}
@@ -0,0 +1,38 @@
+#![warn(clippy::manual_retain)]
+#![allow(unused_assignments)]
+
+use std::collections::{HashSet, VecDeque};
+
+fn is_big(x: &&i32) -> bool {
+    **x > 1
+}
+
+fn main() {
+    let mut v = vec![1, 2, 3];
+    v = v.into_iter().filter(|x| *x > 1).collect();
+    v = v.iter().cloned().filter(|x| *x > 1).collect();
+    // `filter` sees `&&i32`, `retain` passes `&i32`
+    v = v.iter().filter(|&&x| x > 1).cloned().collect();
+    v = v.iter().filter(|x| **x > 1).cloned().collect();
+    v = v.iter().filter(|x| x.is_positive()).cloned().collect();
+    v = v.iter().filter(|x| x != &&2).cloned().collect();
+    v = v.iter().filter(is_big).cloned().collect();
+
+    // `filter` sees `&char`, `String::retain` passes `char`
+    let mut s = String::from("a1b2");
+    s = s.chars().filter(|c| c.is_alphabetic()).collect();
+    s = s.chars().filter(|&c| c != 'a').collect();
+    s = s.chars().filter(|c| *c != 'a' && c != &'b').collect();
+
+    let mut d: VecDeque<i32> = (1..4).collect();
+    d = d.into_iter().filter(|x| *x > 1).collect();
+    let mut h: HashSet<i32> = (1..4).collect();
+    h = h.iter().filter(|&&x| x > 1).cloned().collect();
+
+    // no lint
+    let w = vec![1, 2];
+    v = w.iter().filter(|&&x| x > 1).cloned().collect();
+    let _: Vec<i32> = v.iter().filter(|&&x| x > 1).cloned().collect();
+    v = v.iter().map(|x| x + 1).collect();
+    v = v.iter().filter(|x: &&i32| **x > 1).cloned().collect();
+}
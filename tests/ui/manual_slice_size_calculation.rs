@@ -0,0 +1,26 @@
+// run-rustfix
+
+#![warn(clippy::manual_slice_size_calculation)]
+#![allow(unused)]
+
+use std::mem::{size_of, size_of_val};
+
+fn main() {
+    let v: Vec<i32> = vec![1, 2, 3];
+    let s: &[i32] = &v;
+
+    let _ = s.len() * size_of::<i32>();
+    let _ = size_of::<i32>() * s.len();
+    let _ = (s.len()) * (size_of::<i32>());
+    let _ = v[..].len() * std::mem::size_of::<i32>();
+
+    // ok
+    let _ = s.len() * size_of::<u32>();
+    let _ = v.len() * size_of::<i32>();
+    let _ = s.len() * 4;
+    let _ = size_of_val(s);
+}
+
+fn generic<T>(s: &[T]) -> usize {
+    s.len() * size_of::<T>()
+}
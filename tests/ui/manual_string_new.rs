@@ -0,0 +1,17 @@
+// run-rustfix
+
+#![warn(clippy::manual_string_new)]
+
+fn main() {
+    // should lint
+    let _ = "".to_string();
+    let _ = "".to_owned();
+    let _: String = "".into();
+    let _ = String::from("");
+    let _: String = From::from("");
+
+    // should not lint
+    let _ = "foo".to_string();
+    let _: &str = "".into();
+    let _ = String::new();
+}
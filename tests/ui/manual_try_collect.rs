@@ -0,0 +1,88 @@
+#![warn(clippy::manual_try_collect)]
+
+fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    s.parse()
+}
+
+fn question_mark(strings: &[&str]) -> Result<Vec<u32>, std::num::ParseIntError> {
+    let mut numbers = Vec::new();
+    for s in strings {
+        numbers.push(parse(s)?);
+    }
+    Ok(numbers)
+}
+
+fn with_match(strings: Vec<&str>) -> Result<Vec<u32>, std::num::ParseIntError> {
+    let mut numbers: Vec<u32> = Vec::with_capacity(strings.len());
+    for s in strings {
+        match parse(s) {
+            Ok(n) => numbers.push(n),
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(numbers)
+}
+
+fn range() -> Result<Vec<u32>, std::num::ParseIntError> {
+    let mut numbers = vec![];
+    for i in 0..10 {
+        numbers.push(parse(&i.to_string())?);
+    }
+    Ok(numbers)
+}
+
+// no lint
+fn other_work(strings: &[&str]) -> Result<Vec<u32>, std::num::ParseIntError> {
+    let mut numbers = Vec::new();
+    for s in strings {
+        numbers.push(parse(s)?);
+        println!("{}", s);
+    }
+    Ok(numbers)
+}
+
+fn not_empty(strings: &[&str]) -> Result<Vec<u32>, std::num::ParseIntError> {
+    let mut numbers = vec![0];
+    for s in strings {
+        numbers.push(parse(s)?);
+    }
+    Ok(numbers)
+}
+
+fn not_directly_before(strings: &[&str]) -> Result<Vec<u32>, std::num::ParseIntError> {
+    let mut numbers = Vec::new();
+    println!("parsing");
+    for s in strings {
+        numbers.push(parse(s)?);
+    }
+    Ok(numbers)
+}
+
+fn uses_vec(strings: &[&str]) -> Result<Vec<usize>, std::num::ParseIntError> {
+    let mut numbers = Vec::new();
+    for s in strings {
+        numbers.push(parse(s).map(|_| numbers.len())?);
+    }
+    Ok(numbers)
+}
+
+fn skips_errors(strings: &[&str]) -> Vec<u32> {
+    let mut numbers = Vec::new();
+    for s in strings {
+        match parse(s) {
+            Ok(n) => numbers.push(n),
+            Err(_) => continue,
+        }
+    }
+    numbers
+}
+
+fn option(strings: &[&str]) -> Option<Vec<u32>> {
+    let mut numbers = Vec::new();
+    for s in strings {
+        numbers.push(parse(s).ok()?);
+    }
+    Some(numbers)
+}
+
+fn main() {}
@@ -1,6 +1,6 @@
 #![feature(exclusive_range_pattern)]
 #![warn(clippy::match_overlapping_arm)]
-#![allow(clippy::redundant_pattern_matching)]
+#![allow(clippy::redundant_pattern_matching, clippy::empty_line_after_doc_comments)]
 
 /// Tests for match_overlapping_arm
 
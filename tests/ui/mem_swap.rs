@@ -0,0 +1,45 @@
+#![warn(clippy::swap_ptr_to_ref, clippy::noop_swap, clippy::swap_with_temporary)]
+
+use std::mem;
+
+fn ptrs(x: *mut i32, y: *mut i32, z: *const i32) {
+    unsafe {
+        mem::swap(&mut *x, &mut *y);
+        mem::swap(&mut *x, &mut *x.add(1));
+        mem::swap(&mut *x, &mut *(z as *mut i32));
+    }
+}
+
+fn same_place(x: *mut i32, r: &mut i32) {
+    unsafe {
+        mem::swap(&mut *x, &mut *x);
+        mem::swap(&mut *(r as *mut i32), &mut *(r as *mut i32));
+    }
+}
+
+fn temporaries(mut v: Vec<i32>, w: &mut Vec<i32>) -> (Vec<i32>, Vec<i32>) {
+    mem::swap(&mut v, &mut Vec::new());
+    mem::swap(&mut Vec::with_capacity(1), w);
+
+    let mut old = Vec::with_capacity(2);
+    mem::swap(w, &mut old);
+    let mut old2 = Vec::new();
+    mem::swap(&mut old2, &mut v);
+    (old, old2)
+}
+
+// not linted
+fn ok(a: &mut i32, b: &mut i32, mut c: i32) {
+    mem::swap(a, b);
+    mem::swap(a, &mut c);
+}
+
+// still needs to be mutable
+fn changed_later(v: &mut Vec<i32>) -> Vec<i32> {
+    let mut tmp = Vec::new();
+    mem::swap(v, &mut tmp);
+    tmp.push(1);
+    tmp
+}
+
+fn main() {}
@@ -12,7 +12,10 @@
     clippy::default_trait_access,
     clippy::use_self,
     clippy::new_ret_no_self,
-    clippy::useless_format
+    clippy::useless_format,
+    clippy::iter_not_returning_iterator,
+    clippy::must_use_candidate,
+    clippy::manual_string_new
 )]
 
 #[macro_use]
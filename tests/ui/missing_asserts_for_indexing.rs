@@ -0,0 +1,42 @@
+#![warn(clippy::missing_asserts_for_indexing)]
+
+fn sum(v: &[u8]) -> u8 {
+    v[0] + v[1] + v[2]
+}
+
+fn swap_ends(v: &mut [u8]) {
+    v[0] ^= v[3];
+    v[3] ^= v[0];
+    v[0] ^= v[3];
+}
+
+// no lint
+fn asserted(v: &[u8]) -> u8 {
+    assert!(v.len() > 2);
+    v[0] + v[1] + v[2]
+}
+
+fn checked(v: &[u8]) -> u8 {
+    if v.len() < 3 {
+        return 0;
+    }
+    v[0] + v[1] + v[2]
+}
+
+fn once(v: &[u8]) -> u8 {
+    v[0]
+}
+
+fn array(v: [u8; 3]) -> u8 {
+    v[0] + v[1] + v[2]
+}
+
+fn vec(v: Vec<u8>) -> u8 {
+    v[0] + v[1] + v[2]
+}
+
+fn dynamic(v: &[u8], i: usize) -> u8 {
+    v[i] + v[i + 1]
+}
+
+fn main() {}
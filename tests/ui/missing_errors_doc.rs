@@ -0,0 +1,41 @@
+#![warn(clippy::missing_errors_doc)]
+#![allow(dead_code)]
+
+use std::io;
+
+/// This needs to be documented
+pub fn err() -> Result<(), ()> {
+    Err(())
+}
+
+/// This needs to be documented
+pub fn propagate(s: &str) -> Result<u32, std::num::ParseIntError> {
+    let i: u32 = s.parse()?;
+    Ok(i + 1)
+}
+
+/// This needs to be documented
+pub fn call() -> io::Result<String> {
+    std::fs::read_to_string("file")
+}
+
+/// This is documented
+///
+/// # Errors
+///
+/// Always returns an error
+pub fn documented() -> Result<(), ()> {
+    Err(())
+}
+
+/// This is okay because it never returns an `Err`
+pub fn always_ok() -> Result<(), ()> {
+    Ok(())
+}
+
+/// This is okay because it is private
+fn private() -> Result<(), ()> {
+    Err(())
+}
+
+fn main() {}
@@ -0,0 +1,48 @@
+#![warn(clippy::missing_panics_doc)]
+
+/// This needs to be documented
+pub fn unwrap() -> i32 {
+    let result = Err("Hi");
+    result.unwrap()
+}
+
+/// This needs to be documented
+pub fn panic() {
+    panic!("This function panics")
+}
+
+/// This needs to be documented
+pub fn assert(x: usize) {
+    assert!(x > 0);
+}
+
+/// This needs to be documented
+pub fn index(v: &[u32]) -> u32 {
+    v[0]
+}
+
+/// This is documented
+///
+/// # Panics
+///
+/// Panics if the result is an `Err`
+pub fn documented() -> i32 {
+    let result = Err("Hi");
+    result.unwrap()
+}
+
+/// This is okay because it is private
+fn private() -> i32 {
+    let result = Err("Hi");
+    result.unwrap()
+}
+
+/// This is okay because `unreachable!` and `debug_assert!` are not reported
+pub fn unreachable_and_debug_assert(x: usize) {
+    debug_assert!(x > 0);
+    if x == 0 {
+        unreachable!();
+    }
+}
+
+fn main() {}
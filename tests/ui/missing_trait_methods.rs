@@ -0,0 +1,35 @@
+#![warn(clippy::missing_trait_methods)]
+
+trait Trait {
+    fn required(&self);
+
+    fn provided(&self) {}
+
+    fn other_provided(&self) {}
+}
+
+struct Partial;
+
+impl Trait for Partial {
+    fn required(&self) {}
+
+    fn provided(&self) {}
+}
+
+struct Missing;
+
+impl Trait for Missing {
+    fn required(&self) {}
+}
+
+struct Complete;
+
+impl Trait for Complete {
+    fn required(&self) {}
+
+    fn provided(&self) {}
+
+    fn other_provided(&self) {}
+}
+
+fn main() {}
@@ -0,0 +1,38 @@
+#![warn(
+    clippy::seek_from_current,
+    clippy::seek_to_start_instead_of_rewind,
+    clippy::manual_hash_one,
+    clippy::manual_retain,
+    clippy::string_lit_chars_any,
+    clippy::tuple_array_conversions,
+    clippy::thread_local_initializer_can_be_made_const
+)]
+
+use std::cell::Cell;
+use std::collections::hash_map::RandomState;
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::io::{self, Seek, SeekFrom};
+
+// without an `msrv`, features newer than the toolchain are not suggested
+thread_local! {
+    static COUNTER: Cell<u32> = Cell::new(0);
+}
+
+fn main() -> io::Result<()> {
+    let mut f = File::open("foo.txt")?;
+    let _ = f.seek(SeekFrom::Current(0))?;
+    f.seek(SeekFrom::Start(0))?;
+    let mut hasher = RandomState::new().build_hasher();
+    1.hash(&mut hasher);
+    let _ = hasher.finish();
+    let mut set: BTreeSet<i32> = (1..4).collect();
+    set = set.into_iter().filter(|x| *x > 1).collect();
+    drop(set);
+    let _ = "abc".chars().any(|x| x == 'b');
+    let arr = [1, 2];
+    let _ = (arr[0], arr[1]);
+    COUNTER.with(|c| c.set(1));
+    Ok(())
+}
@@ -0,0 +1,51 @@
+#![warn(clippy::multiple_unsafe_ops_per_block)]
+#![allow(dead_code, unused_unsafe)]
+
+static mut COUNTER: u32 = 0;
+
+union U {
+    int: u32,
+    float: f32,
+}
+
+struct S;
+
+impl S {
+    unsafe fn method(&self) {}
+}
+
+unsafe fn f(_: u32) {}
+
+fn one_op(ptr: *const u32) -> u32 {
+    unsafe { *ptr }
+}
+
+fn two_ops(ptr: *const u32) {
+    unsafe {
+        f(*ptr);
+    }
+}
+
+fn many_ops(u: U, s: &S) {
+    unsafe {
+        COUNTER += 1;
+        let _ = u.float;
+        s.method();
+    }
+}
+
+fn union_write(mut u: U) -> u32 {
+    unsafe {
+        u.int = 1;
+        u.int
+    }
+}
+
+fn nested(ptr: *const u32) {
+    unsafe {
+        let x = *ptr;
+        unsafe { f(x) };
+    }
+}
+
+fn main() {}
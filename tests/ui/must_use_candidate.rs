@@ -0,0 +1,63 @@
+#![warn(clippy::must_use_candidate)]
+#![allow(dead_code)]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+pub struct Counter(u32);
+
+impl Counter {
+    pub fn get(&self) -> u32 {
+        self.0
+    }
+
+    pub fn bump(&mut self) -> u32 {
+        self.0 += 1;
+        self.0
+    }
+
+    fn private(&self) -> u32 {
+        self.0
+    }
+}
+
+pub fn pure(i: u8) -> u8 {
+    i
+}
+
+pub fn with_locals(mut v: Vec<u8>) -> Vec<u8> {
+    v[0] = 1;
+    v
+}
+
+#[must_use]
+pub fn already_marked(i: u8) -> u8 {
+    i
+}
+
+pub fn returns_result() -> Result<(), ()> {
+    Ok(())
+}
+
+pub fn unit(_i: u8) {}
+
+pub fn mut_arg(v: &mut Vec<u8>) -> usize {
+    v.push(1);
+    v.len()
+}
+
+static mut COUNT: usize = 0;
+
+pub fn mutates_static() -> usize {
+    unsafe {
+        COUNT += 1;
+        COUNT
+    }
+}
+
+pub static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+pub fn interior_mutability() -> usize {
+    COUNTER.fetch_add(1, Ordering::SeqCst)
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+#![warn(clippy::must_use_unit)]
+#![allow(clippy::unused_unit)]
+
+#[must_use]
+pub fn must_use_default() {}
+
+#[must_use]
+pub fn must_use_unit() -> () {}
+
+#[must_use = "with note"]
+pub fn must_use_with_note() {}
+
+#[must_use]
+pub fn must_use_value() -> u32 {
+    0
+}
+
+fn main() {
+    must_use_default();
+    must_use_unit();
+    must_use_with_note();
+    let _ = must_use_value();
+}
@@ -0,0 +1,36 @@
+// run-rustfix
+
+#![warn(clippy::needless_borrows_for_generic_args)]
+
+use std::fmt::Display;
+use std::path::Path;
+
+fn show(x: impl Display) -> String {
+    x.to_string()
+}
+
+fn exists<P: AsRef<Path>>(path: P) -> bool {
+    path.as_ref().exists()
+}
+
+fn same<T: Display>(_: T, _: T) {}
+
+fn ret<T: Display>(x: T) -> T {
+    x
+}
+
+fn main() {
+    let n = 5;
+    let _ = show(&n);
+    let _ = exists(&"Cargo.toml");
+    let _ = exists(&String::from("Cargo.toml"));
+    let _ = "abc".starts_with(&"a");
+
+    // ok
+    let s = String::from("Cargo.toml");
+    let _ = exists(&s);
+    let _ = s.len();
+    same(&n, &n);
+    let _ = ret(&n);
+    let _ = exists::<&str>(&"Cargo.toml");
+}
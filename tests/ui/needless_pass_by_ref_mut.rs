@@ -0,0 +1,92 @@
+#![warn(clippy::needless_pass_by_ref_mut)]
+#![allow(clippy::ptr_arg)]
+
+// should lint
+fn len(v: &mut Vec<u32>) -> usize {
+    v.len()
+}
+
+fn read<'a>(s: &'a mut String) -> &'a str {
+    s.as_str()
+}
+
+fn read_in_closure(v: &mut Vec<u32>) -> usize {
+    let f = || v.len();
+    f()
+}
+
+struct Bar;
+
+impl Bar {
+    fn len(&mut self, v: &mut Vec<u32>) -> usize {
+        v.len()
+    }
+}
+
+// should not lint
+fn push(v: &mut Vec<u32>) {
+    v.push(1);
+}
+
+fn assign(x: &mut u32) {
+    *x = 1;
+}
+
+fn field(p: &mut (u32, u32)) {
+    p.0 += 1;
+}
+
+fn pass_on(v: &mut Vec<u32>) {
+    push(v);
+}
+
+fn reborrow(v: &mut Vec<u32>) {
+    let w = &mut *v;
+    w.clear();
+}
+
+fn moved(v: &mut Vec<u32>) -> &mut Vec<u32> {
+    v
+}
+
+fn mutate_in_closure(v: &mut Vec<u32>) {
+    let mut f = || v.push(1);
+    f();
+}
+
+fn used_as_value(v: &mut Vec<u32>) -> usize {
+    v.len()
+}
+
+pub fn exported(v: &mut Vec<u32>) -> usize {
+    v.len()
+}
+
+trait Foo {
+    fn foo(&mut self, v: &mut Vec<u32>) -> usize;
+}
+
+impl Foo for u32 {
+    fn foo(&mut self, v: &mut Vec<u32>) -> usize {
+        v.len()
+    }
+}
+
+fn main() {
+    let mut v = vec![];
+    len(&mut v);
+    let mut s = String::new();
+    read(&mut s);
+    read_in_closure(&mut v);
+    push(&mut v);
+    assign(&mut 0);
+    field(&mut (0, 0));
+    pass_on(&mut v);
+    reborrow(&mut v);
+    moved(&mut v);
+    mutate_in_closure(&mut v);
+    let _: Vec<_> = vec![vec![1]].iter_mut().map(used_as_value).collect();
+    exported(&mut v);
+    0u32.foo(&mut v);
+    Bar.len(&mut v);
+}
@@ -0,0 +1,50 @@
+#![warn(clippy::non_canonical_clone_impl)]
+
+#[derive(Copy)]
+struct A(u32);
+
+impl Clone for A {
+    fn clone(&self) -> Self {
+        A(self.0)
+    }
+}
+
+#[derive(Copy)]
+struct B<T>(T);
+
+impl<T: Copy> Clone for B<T> {
+    fn clone(&self) -> Self {
+        B(self.0)
+    }
+}
+
+// ok
+#[derive(Copy)]
+struct C(u32);
+
+impl Clone for C {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+// not `Copy`
+struct D(u32);
+
+impl Clone for D {
+    fn clone(&self) -> Self {
+        D(self.0)
+    }
+}
+
+// only `Copy` if `T` is
+#[derive(Copy)]
+struct E<T>(T);
+
+impl<T: Clone> Clone for E<T> {
+    fn clone(&self) -> Self {
+        E(self.0.clone())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+#![warn(clippy::obfuscated_if_else)]
+
+// methods of the same names on other types are not linted
+struct Flag(bool);
+
+impl Flag {
+    fn then<T, F: FnOnce() -> T>(self, f: F) -> Option<T> {
+        if self.0 {
+            Some(f())
+        } else {
+            None
+        }
+    }
+}
+
+fn main() {
+    let x = true;
+    let _ = x.then(|| "a").unwrap_or("b");
+    let _ = x.then_some("a").unwrap_or("b");
+    let _ = "a".is_empty().then(|| 1).unwrap_or(2);
+
+    // not linted
+    let _ = x.then(|| "a");
+    let _ = Some("a").unwrap_or("b");
+    let _ = if x { Some(1) } else { None };
+    let _ = Flag(x).then(|| "a").unwrap_or("b");
+}
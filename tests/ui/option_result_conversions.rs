@@ -0,0 +1,18 @@
+#![warn(clippy::option_map_or_err_ok, clippy::manual_result_ok, clippy::ok_unwrap_or)]
+
+fn main() {
+    let opt: Option<u32> = Some(1);
+    let res: Result<u32, &str> = Ok(1);
+
+    let _: Result<u32, &str> = opt.map_or(Err("missing"), Ok);
+    let _: Result<u32, &str> = opt.map(Ok).unwrap_or(Err("missing"));
+    let _ = res.map(Some).unwrap_or(None);
+    let _ = res.ok().unwrap_or(0);
+    let _ = res.ok().unwrap_or_default();
+
+    // ok
+    let _: Result<u32, &str> = opt.map_or(Err("missing"), |x| Ok(x + 1));
+    let _: Result<u32, ()> = opt.map(Ok).unwrap_or(Ok(0));
+    let _ = res.map(Some).unwrap_or(Some(0));
+    let _ = res.ok().map(|x| x + 1);
+}
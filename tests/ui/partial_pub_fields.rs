@@ -0,0 +1,41 @@
+#![warn(clippy::partial_pub_fields)]
+#![allow(dead_code)]
+
+pub struct FileSet {
+    files: Vec<String>,
+    pub paths: Vec<String>,
+    pub len: usize,
+}
+
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    b: u8,
+}
+
+pub struct Point(i32, pub i32);
+
+pub struct Hidden {
+    pub value: u32,
+    #[doc(hidden)]
+    __private: (),
+}
+
+// no lint below
+
+pub struct AllPub {
+    pub a: u32,
+    pub b: u32,
+}
+
+pub struct AllPrivate {
+    a: u32,
+    b: u32,
+}
+
+pub struct CrateVisible {
+    pub(crate) a: u32,
+    b: u32,
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+#![warn(clippy::pathbuf_init_then_push)]
+
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let mut path = PathBuf::new();
+    path.push("target");
+
+    let dir = String::from("debug");
+    let mut typed: PathBuf = PathBuf::new();
+    typed.push("target");
+    typed.push(&dir);
+    typed.push(Path::new("build"));
+
+    // only the pushes before the path is used
+    let mut used = PathBuf::new();
+    used.push("a");
+    used.push(used.join("b"));
+
+    let mut qualified = std::path::PathBuf::new();
+    qualified.push("a");
+
+    // not right after the creation
+    let mut later = PathBuf::new();
+    println!("{}", later.display());
+    later.push("a");
+
+    let mut unpushed = PathBuf::new();
+    unpushed.set_extension("rs");
+
+    println!("{:?} {:?} {:?} {:?} {:?}", path, typed, used, qualified, unpushed);
+}
@@ -0,0 +1,14 @@
+#![warn(clippy::permissions_set_readonly_false)]
+
+use std::fs::File;
+
+fn main() -> std::io::Result<()> {
+    let mut permissions = File::open("foo.txt")?.metadata()?.permissions();
+    permissions.set_readonly(false);
+
+    // fine
+    permissions.set_readonly(true);
+    let readonly = permissions.readonly();
+    permissions.set_readonly(readonly);
+    Ok(())
+}
@@ -0,0 +1,24 @@
+#![allow(unreachable_code)]
+#![warn(clippy::question_mark_used)]
+
+fn other_function() -> Option<i32> {
+    Some(32)
+}
+
+fn my_function() -> Option<i32> {
+    other_function()?;
+    None
+}
+
+fn explicit_function() -> Result<i32, String> {
+    let value = match "32".parse::<i32>() {
+        Ok(value) => value,
+        Err(err) => return Err(err.to_string()),
+    };
+    Ok(value)
+}
+
+fn main() {
+    my_function();
+    explicit_function().unwrap();
+}
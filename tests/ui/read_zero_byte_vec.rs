@@ -0,0 +1,35 @@
+#![warn(clippy::read_zero_byte_vec)]
+#![allow(clippy::unused_io_amount)]
+
+use std::io::{self, Read};
+
+fn test(mut r: impl Read) -> io::Result<()> {
+    let mut data = Vec::with_capacity(20);
+    r.read_exact(&mut data)?;
+
+    let mut data = Vec::new();
+    r.read(&mut data)?;
+
+    let mut data = Vec::new();
+    r.read_exact(&mut data)?;
+
+    let mut data = Vec::with_capacity(20);
+    data.reserve(10);
+    r.read(&mut data)?;
+
+    // not linted
+    let mut data = Vec::with_capacity(20);
+    data.resize(20, 0);
+    r.read_exact(&mut data)?;
+
+    let mut data = vec![0; 20];
+    r.read(&mut data)?;
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&[1, 2, 3]);
+    r.read(&mut data)?;
+
+    Ok(())
+}
+
+fn main() {}
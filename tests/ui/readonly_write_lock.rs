@@ -0,0 +1,33 @@
+// run-rustfix
+
+#![warn(clippy::readonly_write_lock)]
+
+use std::sync::{Arc, RwLock};
+
+fn print(lock: &RwLock<Vec<u32>>) {
+    let numbers = lock.write().unwrap();
+    println!("{:?}", *numbers);
+}
+
+fn len(lock: Arc<RwLock<Vec<u32>>>) -> usize {
+    let numbers = lock.write().expect("poisoned");
+    numbers.len()
+}
+
+// no lint
+fn push(lock: &RwLock<Vec<u32>>) {
+    let mut numbers = lock.write().unwrap();
+    numbers.push(1);
+}
+
+fn exclusive(lock: &RwLock<u32>) {
+    let _guard = lock.write().unwrap();
+    println!("nobody else holds the lock");
+}
+
+fn moved(lock: &RwLock<u32>) {
+    let guard = lock.write().unwrap();
+    std::mem::drop(guard);
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+#![warn(clippy::redundant_as_str)]
+
+use std::borrow::Cow;
+
+fn main() {
+    let s = String::from("foo");
+    let r = &s;
+    let slice = "bar";
+    let cow: Cow<str> = Cow::Borrowed("baz");
+
+    let _ = s.as_str().len();
+    let _ = r.as_str().is_empty();
+    let _ = s.as_bytes().len();
+    let _ = slice.as_bytes().is_empty();
+    let _ = cow.as_bytes().len();
+
+    // ok
+    let _ = s.len();
+    let _ = s.as_bytes().first();
+    let _ = vec![1u8].as_slice().len();
+}
@@ -0,0 +1,63 @@
+// run-rustfix
+
+#![warn(clippy::redundant_type_annotations)]
+#![allow(dead_code, unused_variables)]
+
+#[derive(Default)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    fn new() -> Self {
+        Self { x: 0, y: 0 }
+    }
+
+    fn origin() -> Point {
+        Point { x: 0, y: 0 }
+    }
+
+    fn norm(&self) -> f64 {
+        f64::from(self.x * self.x + self.y * self.y).sqrt()
+    }
+}
+
+struct Wrapper<T>(T);
+
+#[derive(Clone, Copy)]
+enum Color {
+    Red,
+    Green,
+}
+
+fn make_string() -> String {
+    String::new()
+}
+
+fn main() {
+    let s: String = make_string();
+    let p: Point = Point::new();
+    let p: Point = Point::origin();
+    let n: f64 = Point::origin().norm();
+    let p: Point = Point { x: 1, y: 2 };
+    let c: Color = Color::Red;
+    let s: &str = "hello";
+    let b: bool = true;
+    let x: u8 = 1u8;
+
+    // no lint, the type depends on the context
+    let p: Point = Default::default();
+    let v: Vec<u8> = Vec::new();
+    let w: Wrapper<u8> = Wrapper(1);
+    let o: Option<u8> = None;
+    let x: u32 = 1;
+    let f: f32 = 1.0;
+    let s: String = "hello".into();
+    let it: std::vec::IntoIter<u8> = vec![1u8].into_iter();
+    let x: u64 = u64::from(1u8);
+
+    // no lint, the annotation changes the type
+    let s: &dyn std::fmt::Debug = &"hello";
+    let bytes: &[u8] = b"hello";
+}
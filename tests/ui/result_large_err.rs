@@ -0,0 +1,55 @@
+#![warn(clippy::result_large_err)]
+
+pub enum SmallError {
+    Eof,
+    Other(u64),
+}
+
+pub enum LargeError {
+    Eof,
+    Io(u32),
+    Other([u8; 512]),
+}
+
+pub struct LargeStructError([u8; 256]);
+
+pub fn small_err() -> Result<(), SmallError> {
+    Ok(())
+}
+
+pub fn large_err() -> Result<(), LargeError> {
+    Ok(())
+}
+
+pub fn large_struct_err() -> Result<(), LargeStructError> {
+    Ok(())
+}
+
+pub fn boxed_err() -> Result<(), Box<LargeError>> {
+    Ok(())
+}
+
+pub fn generic_err<E>() -> Result<(), E> {
+    unimplemented!()
+}
+
+pub struct Parser;
+
+impl Parser {
+    pub fn parse(&self) -> Result<(), LargeStructError> {
+        Ok(())
+    }
+}
+
+pub trait Parse {
+    fn parse_trait(&self) -> Result<(), LargeStructError>;
+}
+
+// the trait is linted, not its implementations
+impl Parse for Parser {
+    fn parse_trait(&self) -> Result<(), LargeStructError> {
+        Ok(())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#![warn(clippy::result_unwrap_used)]
+
+fn main() {
+    let res: Result<u8, ()> = Ok(0);
+    let _ = res.unwrap();
+    let _ = res.expect("the value is always `Ok`");
+    let _ = Some(0).unwrap();
+}
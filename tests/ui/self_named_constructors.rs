@@ -0,0 +1,47 @@
+#![warn(clippy::self_named_constructors)]
+#![allow(dead_code, clippy::new_without_default)]
+
+pub struct ShouldSpawn;
+pub struct ShouldNotSpawn;
+
+impl ShouldSpawn {
+    pub fn should_spawn() -> ShouldSpawn {
+        ShouldSpawn
+    }
+
+    fn should_not_spawn() -> ShouldNotSpawn {
+        ShouldNotSpawn
+    }
+}
+
+impl ShouldNotSpawn {
+    pub fn new() -> ShouldNotSpawn {
+        ShouldNotSpawn
+    }
+
+    // takes `self`
+    pub fn should_not_spawn(self) -> ShouldNotSpawn {
+        self
+    }
+}
+
+pub enum Mode {
+    Fast,
+}
+
+impl Mode {
+    pub fn mode() -> Self {
+        Mode::Fast
+    }
+}
+
+pub struct Wrapper;
+
+impl Wrapper {
+    // does not return `Self`
+    pub fn wrapper() -> u32 {
+        0
+    }
+}
+
+fn main() {}
@@ -0,0 +1,41 @@
+// run-rustfix
+
+#![warn(clippy::semicolon_outside_block)]
+#![allow(clippy::let_unit_value)]
+
+unsafe fn get_unit() {}
+
+fn unit() {}
+
+macro_rules! m {
+    () => {
+        unit();
+    };
+}
+
+fn main() {
+    unsafe { get_unit(); }
+    {
+        unit();
+    }
+    {
+        let _ = 1;
+        unit();
+    }
+
+    // no semicolon inside
+    unsafe { get_unit() };
+    {
+        unit()
+    };
+    // used as a value
+    let _ = unsafe { get_unit(); };
+    // ends with a `let`
+    {
+        let _y = 0;
+    }
+    // from a macro
+    {
+        m!()
+    }
+}
@@ -0,0 +1,48 @@
+#![allow(unused)]
+#![warn(clippy::set_contains_or_insert)]
+
+use std::collections::{BTreeSet, HashSet};
+
+struct Registry {
+    names: HashSet<&'static str>,
+    other: HashSet<&'static str>,
+}
+
+fn main() {
+    let mut set = HashSet::new();
+    let value = 5;
+    if !set.contains(&value) {
+        set.insert(value);
+    }
+
+    let mut ordered = BTreeSet::new();
+    if !ordered.contains(&value) {
+        ordered.insert(value);
+        println!("inserted {}", value);
+    }
+
+    let mut registry = Registry {
+        names: HashSet::new(),
+        other: HashSet::new(),
+    };
+    let name = "x";
+    if !registry.names.contains(&name) {
+        registry.names.insert(name);
+    }
+
+    // different receivers
+    if !registry.names.contains(&name) {
+        registry.other.insert(name);
+    }
+
+    // different values
+    if !set.contains(&value) {
+        set.insert(value + 1);
+    }
+
+    if set.contains(&value) {
+        println!("present");
+    } else {
+        set.insert(value);
+    }
+}
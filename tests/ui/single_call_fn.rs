@@ -0,0 +1,68 @@
+#![warn(clippy::single_call_fn)]
+#![allow(dead_code)]
+
+fn c() {
+    println!("really");
+    println!("long");
+    println!("function...");
+}
+
+fn d() {
+    c();
+}
+
+fn a() {}
+
+fn b() {
+    a();
+}
+
+fn e() {
+    b();
+    b();
+}
+
+struct S;
+
+impl S {
+    fn method(&self) -> u32 {
+        42
+    }
+}
+
+trait T {
+    fn t(&self);
+}
+
+impl T for S {
+    fn t(&self) {}
+}
+
+// no lint, called more than once or not only called
+fn used_twice() {}
+fn used_as_value() -> u32 {
+    1
+}
+
+// no lint, recursive calls don't count
+fn recursive(n: u32) -> u32 {
+    if n == 0 {
+        0
+    } else {
+        recursive(n - 1)
+    }
+}
+
+#[inline]
+fn inlined() {}
+
+fn main() {
+    used_twice();
+    used_twice();
+    let _ = Some(1).map(|_| used_as_value()).unwrap_or_else(used_as_value);
+    let _ = S.method();
+    S.t();
+    inlined();
+    e();
+    d();
+}
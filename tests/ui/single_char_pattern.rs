@@ -51,4 +51,15 @@ fn main() {
     // Issue #3204
     const S: &str = "#";
     x.find(S);
+
+    x.trim_matches("x");
+    // Quotes and raw strings
+    x.split("'");
+    x.split("\"");
+    x.split(r"\");
+    // One-element arrays
+    x.trim_matches(&['x'][..]);
+    x.split(&['\''] as &[char]);
+    x.split(&[b'\\' as char][..]);
+    x.split(&['a', 'b'][..]);
 }
@@ -0,0 +1,34 @@
+#![feature(alloc)]
+#![warn(clippy::std_instead_of_core, clippy::std_instead_of_alloc, clippy::alloc_instead_of_core)]
+#![allow(unused_imports)]
+
+extern crate alloc;
+
+fn std_instead_of_core() {
+    use std::hash::Hasher;
+    use ::std::hash::Hash;
+    use std::fmt::{Debug, Display};
+
+    let _ = std::cell::Cell::new(8u32);
+    let _ = std::mem::replace(&mut 1, 2);
+
+    // not linted: defined in std
+    use std::collections::HashMap;
+    use std::fmt;
+    let _ = std::env::args();
+}
+
+fn std_instead_of_alloc() {
+    use std::vec::Vec;
+    let _ = std::rc::Rc::new(1);
+}
+
+fn alloc_instead_of_core() {
+    use alloc::slice::from_ref;
+}
+
+fn main() {
+    std_instead_of_core();
+    std_instead_of_alloc();
+    alloc_instead_of_core();
+}
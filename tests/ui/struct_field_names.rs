@@ -0,0 +1,35 @@
+#![warn(clippy::struct_field_names)]
+#![allow(dead_code)]
+
+struct Data {
+    data_id: u32,
+    name: String,
+    size_data: usize,
+}
+
+struct Config {
+    max_width: u32,
+    max_height: u32,
+    max_depth: u32,
+}
+
+struct Sizes {
+    file_size: u64,
+    block_size: u64,
+    page_size: u64,
+}
+
+// no error, threshold is 3 fields by default
+struct Limits {
+    max_width: u32,
+    max_height: u32,
+}
+
+// no error, the field names still differ
+struct Point {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+fn main() {}
@@ -0,0 +1,48 @@
+#![warn(clippy::suspicious_operation_groupings)]
+#![allow(clippy::eq_op)]
+
+struct Vec3 {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+fn eq(a: &Vec3, b: &Vec3) -> bool {
+    a.x == b.x && a.y == b.y && a.z == a.z
+}
+
+fn eq_mismatched_field(a: &Vec3, b: &Vec3) -> bool {
+    a.x == b.x && a.y == b.z && a.z == b.z
+}
+
+fn dot(a: &Vec3, b: &Vec3) -> f64 {
+    a.x * b.x + a.y * b.y + a.z * b.x
+}
+
+fn any_less(a: &Vec3, b: &Vec3) -> bool {
+    a.x < b.x || (a.y < b.y) || a.y < b.z
+}
+
+fn nested_chain(a: &Vec3, b: &Vec3) -> bool {
+    a.x == b.x && a.y == b.y && a.x * b.x + a.y * b.y + a.z * b.x > 0.0
+}
+
+// no lint below
+
+fn correct_eq(a: &Vec3, b: &Vec3) -> bool {
+    a.x == b.x && a.y == b.y && a.z == b.z
+}
+
+fn short_chain(a: &Vec3, b: &Vec3) -> bool {
+    a.x == b.x && a.y == a.y
+}
+
+fn other_values(a: &Vec3, b: &Vec3, c: &Vec3) -> bool {
+    a.x == b.x && a.y == b.y && c.z == c.z
+}
+
+fn different_operators(a: &Vec3, b: &Vec3) -> bool {
+    a.x == b.x && a.y == b.y && a.z < a.z
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#![warn(clippy::suspicious_xor_used_as_pow)]
+
+fn main() {
+    let _ = 2 ^ 8;
+    let _ = 10 ^ 6;
+    let _ = 2u8 ^ 3;
+    let _ = 10_u64 ^ 3;
+
+    // ok
+    let _ = 3 ^ 8;
+    let _ = 0x2 ^ 8;
+    let x = 4;
+    let _ = 2 ^ x;
+}
@@ -0,0 +1,20 @@
+#![warn(clippy::unchecked_duration_subtraction)]
+
+use std::time::{Duration, Instant};
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+fn main() {
+    let start = Instant::now();
+    let elapsed = start.elapsed();
+
+    let _ = start - Duration::from_secs(1);
+    let _ = elapsed - TIMEOUT;
+    let _ = Instant::now() - elapsed;
+
+    // constant operands
+    let _ = TIMEOUT - Duration::from_millis(100);
+    // `Instant - Instant`
+    let _ = Instant::now() - start;
+    let _ = start.checked_sub(elapsed);
+}
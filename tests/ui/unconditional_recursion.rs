@@ -0,0 +1,116 @@
+#![allow(dead_code, unconditional_recursion)]
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+struct Eq1(u32);
+
+impl PartialEq for Eq1 {
+    fn eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+struct Eq2(u32);
+
+impl PartialEq for Eq2 {
+    fn eq(&self, other: &Self) -> bool {
+        self.eq(other)
+    }
+
+    fn ne(&self, other: &Self) -> bool {
+        !(self.0 == other.0)
+    }
+}
+
+struct Name(String);
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+struct Label(String);
+
+impl fmt::Display for Label {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_string())
+    }
+}
+
+struct Key(u32);
+
+impl Hash for Key {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hash(state);
+    }
+}
+
+struct Config {
+    size: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+// no recursion
+
+struct Fields(u32, String);
+
+impl PartialEq for Fields {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0 && self.1 == other.1
+    }
+}
+
+impl fmt::Display for Fields {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.0, self.1)
+    }
+}
+
+impl Hash for Fields {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+struct Tree(Option<Box<Tree>>);
+
+impl PartialEq for Tree {
+    fn eq(&self, other: &Self) -> bool {
+        // the children are different values
+        match (&self.0, &other.0) {
+            (Some(left), Some(right)) => **left == **right,
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+struct Wrapper(Config);
+
+impl Default for Wrapper {
+    fn default() -> Self {
+        Wrapper(Config {
+            size: Default::default(),
+        })
+    }
+}
+
+struct Outer {
+    inner: Fields,
+}
+
+impl PartialEq<Outer> for Fields {
+    fn eq(&self, other: &Outer) -> bool {
+        // `PartialEq<Fields>`, not the implemented `PartialEq<Outer>`
+        self == &other.inner
+    }
+}
+
+fn main() {}
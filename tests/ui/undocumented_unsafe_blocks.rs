@@ -0,0 +1,38 @@
+#![warn(clippy::undocumented_unsafe_blocks)]
+#![allow(dead_code)]
+
+struct S(*const u32);
+
+// SAFETY: `S` only reads through the pointer
+unsafe impl Send for S {}
+
+unsafe impl Sync for S {}
+
+fn documented(ptr: *const u32) -> u32 {
+    // SAFETY: the caller passes a valid pointer
+    let value = unsafe { *ptr };
+    /* SAFETY: same as above */
+    value + unsafe { *ptr }
+}
+
+fn undocumented(ptr: *const u32) -> u32 {
+    // reads the value
+    let value = unsafe { *ptr };
+
+    unsafe { *ptr + value }
+}
+
+fn attributes(ptr: *const u32) -> u32 {
+    // SAFETY: the caller passes a valid pointer
+    #[allow(unused_variables)]
+    let unused = unsafe { *ptr };
+    0
+}
+
+fn separated(ptr: *const u32) -> u32 {
+    // SAFETY: the caller passes a valid pointer
+
+    unsafe { *ptr }
+}
+
+fn main() {}
@@ -115,3 +115,7 @@ mod many_derefs {
         let _: E = *****a;
     }
 }
+
+fn trait_object(x: Arc<SomeTrait>) {
+    let _ = x.clone();
+}
@@ -0,0 +1,49 @@
+#![warn(clippy::unnecessary_to_owned)]
+
+use std::borrow::Borrow;
+use std::fmt::Display;
+use std::path::Path;
+
+fn require_str(_: &str) {}
+fn require_slice<T>(_: &[T]) {}
+fn require_as_ref_str<T: AsRef<str>>(_: T) {}
+fn require_as_ref_path<P: AsRef<Path>>(_: P) {}
+fn require_borrow_str<T: Borrow<str>>(_: T) {}
+fn require_string(_: String) {}
+fn require_display<T: Display>(_: T) {}
+fn require_as_ref_returned<T: AsRef<str>>(x: T) -> T {
+    x
+}
+
+struct X;
+
+impl X {
+    fn require_as_ref_str<T: AsRef<str>>(&self, _: T) {}
+}
+
+fn main() {
+    let s = "x";
+    let string = String::from("x");
+    let string_ref = &string;
+    let slice = &[1, 2, 3][..];
+
+    require_str(&s.to_string());
+    require_str(&s.to_owned());
+    require_slice(&slice.to_vec());
+    require_as_ref_str(s.to_owned());
+    require_as_ref_str(s.to_string());
+    require_as_ref_str(string_ref.clone());
+    require_as_ref_path(s.to_owned());
+    require_borrow_str(s.to_owned());
+    X.require_as_ref_str(s.to_string());
+
+    // these need an owned value
+    require_string(s.to_owned());
+    require_display(s.to_owned());
+    let _ = require_as_ref_returned(s.to_owned());
+    require_as_ref_str(string.clone());
+    require_str(&string.to_string());
+    require_static_as_ref_str(string_ref.clone());
+}
+
+fn require_static_as_ref_str<T: AsRef<str> + 'static>(_: T) {}
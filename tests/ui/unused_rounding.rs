@@ -0,0 +1,14 @@
+#![warn(clippy::unused_rounding)]
+
+fn main() {
+    let x = 5u32;
+    let _ = 1f32.ceil();
+    let _ = 1.0f64.floor();
+    let _ = (-2.0_f64).round();
+    let _ = (x as f64).round();
+    let _ = (3.0 + 4.0_f64).floor().abs();
+
+    // not linted
+    let _ = 1.5f64.round();
+    let _ = (x as f64 / 2.0).floor();
+}
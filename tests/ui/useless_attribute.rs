@@ -1,4 +1,5 @@
 #![warn(clippy::useless_attribute)]
+#![allow(clippy::deprecated_clippy_cfg_attr)]
 
 #[allow(dead_code)]
 #[cfg_attr(feature = "cargo-clippy", allow(dead_code))]
@@ -0,0 +1,31 @@
+// run-rustfix
+
+#![warn(clippy::waker_clone_wake)]
+#![allow(clippy::ptr_arg, clippy::redundant_clone, clippy::redundant_as_str)]
+
+use std::path::PathBuf;
+use std::task::{Context, Waker};
+
+fn wake(cx: &mut Context<'_>, waker: &Waker) {
+    cx.waker().clone().wake();
+    waker.clone().wake();
+
+    // already by reference
+    cx.waker().wake_by_ref();
+    // the clone is kept
+    let clone = waker.clone();
+    clone.wake();
+}
+
+fn by_ref(s: &String, v: Vec<u8>, path: &PathBuf, slice: &str) {
+    let _ = s.to_owned().as_str().len();
+    let _ = v.clone().as_slice().len();
+    let _ = path.clone().as_path().exists();
+
+    // `str::to_owned` makes a `String`
+    let _ = slice.to_owned().as_str().len();
+    // not a by-reference counterpart
+    let _ = s.clone().into_bytes();
+}
+
+fn main() {}